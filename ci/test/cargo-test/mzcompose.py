@@ -30,6 +30,7 @@ from materialize.mzbuild import (
 from materialize.mzcompose.composition import Composition, WorkflowArgumentParser
 from materialize.mzcompose.service import Service as MzComposeService
 from materialize.mzcompose.services.azurite import Azurite
+from materialize.mzcompose.services.fake_gcs_server import FakeGcsServer
 from materialize.mzcompose.services.foundationdb import FoundationDB
 from materialize.mzcompose.services.kafka import Kafka
 from materialize.mzcompose.services.metadata_store import CockroachOrPostgresMetadata
@@ -75,6 +76,13 @@ SERVICES = [
         ports=["40111:10000"],
         allow_host_ports=True,
     ),
+    FakeGcsServer(
+        # Tests run on the host, so resumable upload URLs handed out by the
+        # emulator need to point at the host port.
+        external_url="http://localhost:40112",
+        ports=["40112:4443"],
+        allow_host_ports=True,
+    ),
     MzComposeService(
         "clusterd", {"mzbuild": "clusterd"}
     ),  # Only to download the binary
@@ -127,6 +135,7 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         "foundationdb",
         "minio",
         "azurite",
+        "fake-gcs-server",
     )
     # Heads up: this intentionally runs on the host rather than in a Docker
     # image. See database-issues#3739.
@@ -155,6 +164,9 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_S3_BUCKET="mz-test-persist-1d-lifecycle-delete",
         MZ_S3_UPLOADER_TEST_S3_BUCKET="mz-test-1d-lifecycle-delete",
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_CONTAINER="mz-test-azure",
+        MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET="mz-test-gcs",
+        MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_ENDPOINT="http://localhost:40112",
+        MZ_GCS_UPLOADER_TEST_ENDPOINT="http://localhost:40112",
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_POSTGRES_URL=metadata_backend_url,
         FDB_CLUSTER_FILE=fdb_cluster_file.name,
     )
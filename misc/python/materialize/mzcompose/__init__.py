@@ -83,10 +83,15 @@ def get_minimal_system_parameters(
         "enable_continual_task_transform": "true",
         "enable_copy_to_expr": "true",
         "enable_copy_from_remote": "true",
+        "enable_create_function": "true",
+        "enable_sequences": "true",
+        "enable_table_check_constraints": "true",
+        "enable_wasm_functions": "true",
         "enable_create_table_from_source": "true",
         "enable_eager_delta_joins": "true",
         "enable_envelope_debezium_in_subscribe": "true",
         "enable_expressions_in_limit_syntax": "true",
+        "enable_gcs_connection": "true",
         "enable_iceberg_sink": "true",
         "enable_introspection_subscribes": "true",
         "enable_kafka_sink_partition_by": "true",
@@ -109,6 +114,7 @@ def get_minimal_system_parameters(
         "grpc_client_http2_keep_alive_timeout": "5s",
         "ore_overflowing_behavior": "panic",
         "unsafe_enable_table_keys": "true",
+        "unsafe_enable_unauthenticated_gcs_connections": "true",
         "with_0dt_deployment_max_wait": "1800s",
         # End of list (ordered by name)
     }
@@ -495,6 +501,7 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "memory_limiter_usage_bias",
     "memory_limiter_burst_factor",
     "compute_server_maintenance_interval",
+    "compute_arrangement_spill_threshold_bytes",
     "compute_dataflow_max_inflight_bytes_cc",
     "compute_flat_map_fuel",
     "compute_temporal_bucketing_summary",
@@ -550,6 +557,8 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "persist_stats_collection_enabled",
     "persist_stats_filter_enabled",
     "persist_stats_budget_bytes",
+    "persist_stats_bloom_filter_enabled",
+    "persist_stats_bloom_filter_max_bytes",
     "persist_stats_untrimmable_columns_equals",
     "persist_stats_untrimmable_columns_prefix",
     "persist_stats_untrimmable_columns_suffix",
@@ -566,6 +575,8 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "persist_gc_fallback_threshold_ms",
     "persist_compaction_minimum_timeout",
     "persist_compaction_check_process_flag",
+    "persist_compaction_service_enabled",
+    "persist_compaction_service_connect_timeout",
     "balancerd_sigterm_connection_wait",
     "balancerd_sigterm_listen_wait",
     "balancerd_inject_proxy_protocol_header_http",
@@ -576,6 +587,7 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "balancerd_sentry_filters",
     "persist_enable_s3_lgalloc_cc_sizes",
     "persist_enable_arrow_lgalloc_cc_sizes",
+    "persist_gcs_resumable_upload_threshold_bytes",
     "controller_past_generation_replica_cleanup_retry_interval",
     "wallclock_lag_recording_interval",
     "wallclock_lag_histogram_period_interval",
@@ -0,0 +1,56 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+
+from materialize.mzcompose.service import Service
+
+
+class FakeGcsServer(Service):
+    """An emulator for the Google Cloud Storage JSON API."""
+
+    def __init__(
+        self,
+        name: str = "fake-gcs-server",
+        image: str = "fsouza/fake-gcs-server:1.52.2",
+        port: int = 4443,
+        # The URL clients use to reach the emulator. Resumable upload sessions
+        # hand out this URL, so it must be reachable from wherever the client
+        # runs.
+        external_url: str | None = None,
+        ports: list[int | str] | None = None,
+        allow_host_ports: bool = False,
+    ) -> None:
+        if external_url is None:
+            external_url = f"http://{name}:{port}"
+        if ports is None:
+            ports = [port]
+        super().__init__(
+            name=name,
+            config={
+                "image": image,
+                "init": True,
+                "command": [
+                    "-scheme",
+                    "http",
+                    "-port",
+                    str(port),
+                    "-external-url",
+                    external_url,
+                    "-backend",
+                    "memory",
+                ],
+                "ports": ports,
+                "allow_host_ports": allow_host_ports,
+                "healthcheck": {
+                    "test": f"wget -q -O /dev/null http://localhost:{port}/storage/v1/b",
+                    "interval": "1s",
+                    "start_period": "30s",
+                },
+            },
+        )
@@ -1581,6 +1581,8 @@ class FlipFlagsAction(Action):
         ]
         self.flags_with_values["enable_case_literal_transform"] = BOOLEAN_FLAG_VALUES
         self.flags_with_values["enable_cast_elimination"] = BOOLEAN_FLAG_VALUES
+        self.flags_with_values["enable_cost_based_join_ordering"] = BOOLEAN_FLAG_VALUES
+        self.flags_with_values["enable_native_outer_join"] = BOOLEAN_FLAG_VALUES
         self.flags_with_values["enable_upsert_v2"] = BOOLEAN_FLAG_VALUES
 
         # If you are adding a new config flag in Materialize, consider using it
@@ -72,7 +72,7 @@ use mz_sql::names::{
     PUBLIC_ROLE_NAME, PartialItemName, QualifiedItemName, QualifiedSchemaName,
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
-use mz_sql::plan::{Plan, PlanNotice, StatementDesc};
+use mz_sql::plan::{Plan, PlanNotice, SequenceOptions, StatementDesc};
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::user::{MZ_SYSTEM_ROLE_ID, SUPPORT_USER, SYSTEM_USER};
@@ -600,6 +600,39 @@ impl Catalog {
             .err_into()
     }
 
+    /// Allocate `count` many values from the sequence `id`. See
+    /// [`DurableCatalogState::allocate_sequence_values`].
+    pub async fn allocate_sequence_values(
+        &self,
+        id: CatalogItemId,
+        options: SequenceOptions,
+        count: usize,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<Vec<i64>, Error> {
+        self.storage()
+            .await
+            .allocate_sequence_values(id, options, count, commit_ts)
+            .await
+            .maybe_terminate("allocating sequence values")
+            .err_into()
+    }
+
+    /// Overwrite the durable state of the sequence `id`. See
+    /// [`DurableCatalogState::set_sequence_state`].
+    pub async fn set_sequence_state(
+        &self,
+        id: CatalogItemId,
+        state: u64,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        self.storage()
+            .await
+            .set_sequence_state(id, state, commit_ts)
+            .await
+            .maybe_terminate("setting sequence state")
+            .err_into()
+    }
+
     pub async fn allocate_user_id_for_test(&self) -> Result<(CatalogItemId, GlobalId), Error> {
         let commit_ts = self.storage().await.current_upper().await;
         self.allocate_user_id(commit_ts).await
@@ -1440,6 +1473,7 @@ pub(crate) fn comment_id_to_audit_object_type(id: CommentObjectId) -> ObjectType
         CommentObjectId::Connection(_) => ObjectType::Connection,
         CommentObjectId::Type(_) => ObjectType::Type,
         CommentObjectId::Secret(_) => ObjectType::Secret,
+        CommentObjectId::Sequence(_) => ObjectType::Sequence,
         CommentObjectId::Role(_) => ObjectType::Role,
         CommentObjectId::Database(_) => ObjectType::Database,
         CommentObjectId::Schema(_) => ObjectType::Schema,
@@ -1478,6 +1512,7 @@ pub(crate) fn system_object_type_to_audit_object_type(
             mz_sql::catalog::ObjectType::Func => ObjectType::Func,
             mz_sql::catalog::ObjectType::ContinualTask => ObjectType::ContinualTask,
             mz_sql::catalog::ObjectType::NetworkPolicy => ObjectType::NetworkPolicy,
+            mz_sql::catalog::ObjectType::Sequence => ObjectType::Sequence,
         },
         SystemObjectType::System => ObjectType::System,
     }
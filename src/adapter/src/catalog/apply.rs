@@ -31,8 +31,8 @@ use mz_catalog::durable::objects::{
 use mz_catalog::durable::{CatalogError, SystemObjectMapping};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
-    CatalogEntry, CatalogItem, Cluster, ClusterReplica, Database, Func, Index, Log, NetworkPolicy,
-    Role, RoleAuth, Schema, Source, StateDiff, StateUpdate, StateUpdateKind, Table,
+    CatalogEntry, CatalogItem, Cluster, ClusterReplica, Database, Func, FuncDefinition, Index, Log,
+    NetworkPolicy, Role, RoleAuth, Schema, Source, StateDiff, StateUpdate, StateUpdateKind, Table,
     TableDataSource, TemporaryItem, Type, UpdateFrom,
 };
 use mz_compute_types::config::ComputeReplicaConfig;
@@ -778,8 +778,11 @@ impl CatalogState {
                             },
                         ),
                         is_retained_metrics_object: table.is_retained_metrics_object,
+                        storage_compression: None,
                         data_source: TableDataSource::TableWrites {
                             defaults: vec![Expr::null(); table.desc.arity()],
+                            checks: vec![],
+                            uniques: vec![],
                         },
                     }),
                     MZ_SYSTEM_ROLE_ID,
@@ -887,8 +890,10 @@ impl CatalogState {
                     oid,
                     name.clone(),
                     CatalogItem::Func(Func {
-                        inner: func.inner,
+                        create_sql: None,
+                        inner: FuncDefinition::Builtin(func.inner),
                         global_id,
+                        resolved_ids: ResolvedIds::empty(),
                     }),
                     MZ_SYSTEM_ROLE_ID,
                     PrivilegeMap::default(),
@@ -2076,21 +2081,14 @@ impl CatalogState {
             &metadata.name().qualifiers.schema_spec,
             conn_id,
         );
-        if metadata.item_type() == CatalogItemType::Type {
-            schema
-                .types
-                .remove(&metadata.name().item)
-                .expect("catalog out of sync");
-        } else {
-            // Functions would need special handling, but we don't yet support
-            // dropping functions.
-            assert_ne!(metadata.item_type(), CatalogItemType::Func);
-
-            schema
-                .items
-                .remove(&metadata.name().item)
-                .expect("catalog out of sync");
+        let names = match metadata.item_type() {
+            CatalogItemType::Type => &mut schema.types,
+            CatalogItemType::Func => &mut schema.functions,
+            _ => &mut schema.items,
         };
+        names
+            .remove(&metadata.name().item)
+            .expect("catalog out of sync");
 
         if !id.is_system() {
             if let Some(cluster_id) = metadata.item().cluster_id() {
@@ -2171,6 +2169,7 @@ impl CatalogState {
             cluster_id,
             is_retained_metrics_object: false,
             custom_logical_compaction_window: None,
+            spill_to_disk: false,
             optimized_plan: None,
             physical_plan: None,
             dataflow_metainfo: None,
@@ -2383,6 +2382,8 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         let mut connections = Vec::new();
         let mut sources = Vec::new();
         let mut tables = Vec::new();
+        // N.B. Sequences can be owned by tables.
+        let mut sequences = Vec::new();
         let mut derived_items = Vec::new();
         let mut sinks = Vec::new();
         let mut continual_tasks = Vec::new();
@@ -2395,6 +2396,7 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
                 CatalogItemType::Connection => connections.push(update),
                 CatalogItemType::Source => sources.push(update),
                 CatalogItemType::Table => tables.push(update),
+                CatalogItemType::Sequence => sequences.push(update),
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
                 | CatalogItemType::Index => derived_items.push(update),
@@ -2416,6 +2418,7 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             &mut secrets,
             &mut sources,
             &mut tables,
+            &mut sequences,
             &mut sinks,
             &mut continual_tasks,
         ] {
@@ -2429,6 +2432,7 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             .chain(connections)
             .chain(sources)
             .chain(tables)
+            .chain(sequences)
             .chain(derived_items)
             .chain(sinks)
             .chain(continual_tasks)
@@ -2453,6 +2457,8 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         let mut connections = Vec::new();
         let mut sources = Vec::new();
         let mut tables = Vec::new();
+        // N.B. Sequences can be owned by tables.
+        let mut sequences = Vec::new();
         let mut derived_items = Vec::new();
         let mut sinks = Vec::new();
         let mut continual_tasks = Vec::new();
@@ -2465,6 +2471,7 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
                 CatalogItemType::Connection => connections.push(update),
                 CatalogItemType::Source => sources.push(update),
                 CatalogItemType::Table => tables.push(update),
+                CatalogItemType::Sequence => sequences.push(update),
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
                 | CatalogItemType::Index => derived_items.push(update),
@@ -2481,6 +2488,7 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             &mut connections,
             &mut sources,
             &mut tables,
+            &mut sequences,
             &mut derived_items,
             &mut sinks,
             &mut continual_tasks,
@@ -2495,6 +2503,7 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             .chain(connections)
             .chain(sources)
             .chain(tables)
+            .chain(sequences)
             .chain(derived_items)
             .chain(sinks)
             .chain(continual_tasks)
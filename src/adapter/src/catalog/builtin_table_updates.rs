@@ -33,7 +33,7 @@ use mz_catalog::durable::SourceReferences;
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, ClusterVariant, Connection, ContinualTask, DataSourceDesc, Func,
-    Index, MaterializedView, Sink, Table, TableDataSource, Type, View,
+    FuncDefinition, Index, MaterializedView, Sink, Table, TableDataSource, Type, View,
 };
 use mz_controller::clusters::{
     ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ReplicaLocation,
@@ -645,9 +645,9 @@ impl CatalogState {
                 self.pack_type_update(id, oid, schema_id, name, owner_id, privileges, ty, diff)
             }
             CatalogItem::Func(func) => {
-                self.pack_func_update(id, schema_id, name, owner_id, func, diff)
+                self.pack_func_update(id, oid, schema_id, name, owner_id, func, diff)
             }
-            CatalogItem::Secret(_) => vec![],
+            CatalogItem::Secret(_) | CatalogItem::Sequence(_) => vec![],
             CatalogItem::Connection(connection) => {
                 self.pack_connection_update(id, connection, diff)
             }
@@ -668,7 +668,7 @@ impl CatalogState {
         if let Some(desc) = entry.relation_desc_latest() {
             let defaults = match entry.item() {
                 CatalogItem::Table(Table {
-                    data_source: TableDataSource::TableWrites { defaults },
+                    data_source: TableDataSource::TableWrites { defaults, .. },
                     ..
                 }) => Some(defaults),
                 _ => None,
@@ -1045,7 +1045,8 @@ impl CatalogState {
             | ConnectionDetails::Postgres(_)
             | ConnectionDetails::MySql(_)
             | ConnectionDetails::SqlServer(_)
-            | ConnectionDetails::IcebergCatalog(_) => (),
+            | ConnectionDetails::IcebergCatalog(_)
+            | ConnectionDetails::Gcs(_) => (),
         };
         updates
     }
@@ -1649,20 +1650,52 @@ impl CatalogState {
     fn pack_func_update(
         &self,
         id: CatalogItemId,
+        oid: u32,
         schema_id: &SchemaSpecifier,
         name: &str,
         owner_id: &RoleId,
         func: &Func,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
-        let mut updates = vec![];
-        for func_impl_details in func.inner.func_impls() {
-            let arg_type_ids = func_impl_details
-                .arg_typs
-                .iter()
-                .map(|typ| self.get_system_type(typ).id().to_string())
-                .collect::<Vec<_>>();
+        // Each implementation of the function is described by its OID, the IDs of its argument,
+        // variadic argument, and return types, and whether it returns a set.
+        let type_id = |typ: &str| self.get_system_type(typ).id().to_string();
+        let func_impls: Vec<_> = match &func.inner {
+            FuncDefinition::Builtin(inner) => inner
+                .func_impls()
+                .into_iter()
+                .map(|details| {
+                    (
+                        details.oid,
+                        details
+                            .arg_typs
+                            .iter()
+                            .map(|typ| type_id(*typ))
+                            .collect::<Vec<_>>(),
+                        details.variadic_typ.map(type_id),
+                        details.return_typ.map(type_id),
+                        details.return_is_set,
+                    )
+                })
+                .collect(),
+            // Functions defined with `CREATE FUNCTION` have exactly one implementation, which is
+            // identified by the OID of the catalog entry.
+            FuncDefinition::Sql(function) => vec![(
+                oid,
+                function
+                    .param_type_ids
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>(),
+                None,
+                Some(function.return_type_id.to_string()),
+                function.returns_set,
+            )],
+        };
 
+        let mut updates = vec![];
+        for (impl_oid, arg_type_ids, variadic_type_id, return_type_id, return_is_set) in func_impls
+        {
             let mut row = Row::default();
             row.packer()
                 .try_push_array(
@@ -1681,33 +1714,23 @@ impl CatalogState {
                 &*MZ_FUNCTIONS,
                 Row::pack_slice(&[
                     Datum::String(&id.to_string()),
-                    Datum::UInt32(func_impl_details.oid),
+                    Datum::UInt32(impl_oid),
                     Datum::String(&schema_id.to_string()),
                     Datum::String(name),
                     arg_type_ids,
-                    Datum::from(
-                        func_impl_details
-                            .variadic_typ
-                            .map(|typ| self.get_system_type(typ).id().to_string())
-                            .as_deref(),
-                    ),
-                    Datum::from(
-                        func_impl_details
-                            .return_typ
-                            .map(|typ| self.get_system_type(typ).id().to_string())
-                            .as_deref(),
-                    ),
-                    func_impl_details.return_is_set.into(),
+                    Datum::from(variadic_type_id.as_deref()),
+                    Datum::from(return_type_id.as_deref()),
+                    return_is_set.into(),
                     Datum::String(&owner_id.to_string()),
                 ]),
                 diff,
             ));
 
-            if let mz_sql::func::Func::Aggregate(_) = func.inner {
+            if let FuncDefinition::Builtin(mz_sql::func::Func::Aggregate(_)) = func.inner {
                 updates.push(BuiltinTableUpdate::row(
                     &*MZ_AGGREGATES,
                     Row::pack_slice(&[
-                        Datum::UInt32(func_impl_details.oid),
+                        Datum::UInt32(impl_oid),
                         // TODO(database-issues#1064): Support ordered-set aggregate functions.
                         Datum::String("n"),
                         Datum::Int16(0),
@@ -2030,6 +2053,7 @@ impl CatalogState {
             | CommentObjectId::Index(global_id)
             | CommentObjectId::Func(global_id)
             | CommentObjectId::Connection(global_id)
+            | CommentObjectId::Sequence(global_id)
             | CommentObjectId::Secret(global_id)
             | CommentObjectId::Type(global_id)
             | CommentObjectId::ContinualTask(global_id) => global_id.to_string(),
@@ -273,6 +273,7 @@ impl CatalogState {
                 | CommentObjectId::Func(item_id)
                 | CommentObjectId::Connection(item_id)
                 | CommentObjectId::Type(item_id)
+                | CommentObjectId::Sequence(item_id)
                 | CommentObjectId::Secret(item_id)
                 | CommentObjectId::ContinualTask(item_id) => {
                     let entry = self.entry_by_id.get(&item_id);
@@ -552,7 +553,13 @@ impl CatalogState {
                         )
                         | Statement::CreateTable(ast::CreateTableStatement { name, .. })
                         | Statement::CreateType(ast::CreateTypeStatement { name, .. })
-                        | Statement::CreateSecret(ast::CreateSecretStatement { name, .. }) => {
+                        | Statement::CreateFunction(ast::CreateFunctionStatement {
+                            name, ..
+                        })
+                        | Statement::CreateSecret(ast::CreateSecretStatement { name, .. })
+                        | Statement::CreateSequence(ast::CreateSequenceStatement {
+                            name, ..
+                        }) => {
                             let [db_component, schema_component, item_component] = &name.0[..]
                             else {
                                 let name =
@@ -932,6 +932,7 @@ fn add_new_remove_old_builtin_items_migration(
             | CatalogItemType::Index
             | CatalogItemType::Type
             | CatalogItemType::Func
+            | CatalogItemType::Sequence
             | CatalogItemType::Secret
             | CatalogItemType::Connection
             | CatalogItemType::ContinualTask => continue,
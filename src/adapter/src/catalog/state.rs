@@ -31,9 +31,9 @@ use mz_catalog::expr_cache::LocalExpressions;
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogCollectionEntry, CatalogEntry, CatalogItem, Cluster, ClusterReplica, CommentsMap,
-    Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    NetworkPolicy, Role, RoleAuth, Schema, Secret, Sink, Source, SourceReferences, Table,
-    TableDataSource, Type, View,
+    Connection, DataSourceDesc, Database, DefaultPrivileges, Func, FuncDefinition, Index,
+    MaterializedView, NetworkPolicy, Role, RoleAuth, Schema, Secret, Sequence, Sink, Source,
+    SourceReferences, Table, TableDataSource, Type, View,
 };
 use mz_controller::clusters::{
     ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ReplicaAllocation, ReplicaLocation,
@@ -76,9 +76,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreateSecretPlan, CreateSequencePlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, Params, Plan, PlanContext, TableCheck,
 };
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
@@ -388,6 +388,28 @@ impl CatalogState {
         self.for_sessionless_user(MZ_SYSTEM_ROLE_ID)
     }
 
+    /// Returns a catalog that resolves names like a session of the system
+    /// user that has the given `database` and `search_path` set. This allows
+    /// replanning statements that were executed by other sessions.
+    pub fn for_system_session_with_search_path(
+        &self,
+        database: &str,
+        search_path: &[String],
+    ) -> ConnCatalog<'_> {
+        let mut catalog = self.for_system_session();
+        let database = self.database_by_name.get(database).cloned();
+        catalog.search_path = search_path
+            .iter()
+            .filter_map(|schema| {
+                self.resolve_schema(database.as_ref(), None, schema, &SYSTEM_CONN_ID)
+                    .ok()
+            })
+            .map(|schema| (schema.name().database.clone(), schema.id().clone()))
+            .collect();
+        catalog.database = database;
+        catalog
+    }
+
     /// Returns an iterator over the deduplicated identifiers of all
     /// objects this catalog entry transitively depends on (where
     /// "depends on" is meant in the sense of [`CatalogItem::uses`], rather than
@@ -454,6 +476,7 @@ impl CatalogState {
             | CatalogItem::Source(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Secret(_) => (),
         }
     }
@@ -1155,10 +1178,17 @@ impl CatalogState {
                     custom_logical_compaction_window: custom_logical_compaction_window
                         .or(table.compaction_window),
                     is_retained_metrics_object,
+                    storage_compression: table.storage_compression,
                     data_source: match table.data_source {
-                        mz_sql::plan::TableDataSource::TableWrites { defaults } => {
-                            TableDataSource::TableWrites { defaults }
-                        }
+                        mz_sql::plan::TableDataSource::TableWrites {
+                            defaults,
+                            checks,
+                            uniques,
+                        } => TableDataSource::TableWrites {
+                            defaults,
+                            checks,
+                            uniques,
+                        },
                         mz_sql::plan::TableDataSource::DataSource {
                             desc: data_source_desc,
                             timeline,
@@ -1433,6 +1463,7 @@ impl CatalogState {
                     non_null_assertions: materialized_view.non_null_assertions,
                     custom_logical_compaction_window: materialized_view.compaction_window,
                     refresh_schedule: materialized_view.refresh_schedule,
+                    storage_compression: materialized_view.storage_compression,
                     initial_as_of,
                     optimized_plan: None,
                     physical_plan: None,
@@ -1458,6 +1489,7 @@ impl CatalogState {
                 custom_logical_compaction_window: custom_logical_compaction_window
                     .or(index.compaction_window),
                 is_retained_metrics_object,
+                spill_to_disk: index.spill_to_disk,
                 optimized_plan: None,
                 physical_plan: None,
                 dataflow_metainfo: None,
@@ -1497,10 +1529,24 @@ impl CatalogState {
                     resolved_ids,
                 })
             }
+            Plan::CreateFunction(CreateFunctionPlan { function, .. }) => CatalogItem::Func(Func {
+                create_sql: Some(function.create_sql.clone()),
+                inner: FuncDefinition::Sql(function),
+                global_id,
+                resolved_ids,
+            }),
             Plan::CreateSecret(CreateSecretPlan { secret, .. }) => CatalogItem::Secret(Secret {
                 create_sql: secret.create_sql,
                 global_id,
             }),
+            Plan::CreateSequence(CreateSequencePlan { sequence, .. }) => {
+                CatalogItem::Sequence(Sequence {
+                    create_sql: sequence.create_sql,
+                    global_id,
+                    options: sequence.options,
+                    resolved_ids,
+                })
+            }
             Plan::CreateConnection(CreateConnectionPlan {
                 connection:
                     mz_sql::plan::Connection {
@@ -1880,6 +1926,7 @@ impl CatalogState {
             | CatalogItemType::View
             | CatalogItemType::MaterializedView
             | CatalogItemType::Index
+            | CatalogItemType::Sequence
             | CatalogItemType::Secret
             | CatalogItemType::Connection
             | CatalogItemType::ContinualTask => schema.items[builtin.name()],
@@ -2696,6 +2743,7 @@ impl CatalogState {
             | CommentObjectId::Func(id)
             | CommentObjectId::Connection(id)
             | CommentObjectId::Type(id)
+            | CommentObjectId::Sequence(id)
             | CommentObjectId::Secret(id)
             | CommentObjectId::ContinualTask(id) => Some(*id),
             CommentObjectId::Role(_)
@@ -2726,6 +2774,7 @@ impl CatalogState {
             | CommentObjectId::Func(id)
             | CommentObjectId::Connection(id)
             | CommentObjectId::Type(id)
+            | CommentObjectId::Sequence(id)
             | CommentObjectId::Secret(id)
             | CommentObjectId::ContinualTask(id) => {
                 let item = self.get_entry(&id);
@@ -2779,6 +2828,7 @@ impl ConnectionResolver for CatalogState {
             MySql(conn) => MySql(conn.into_inline_connection(self)),
             SqlServer(conn) => SqlServer(conn.into_inline_connection(self)),
             IcebergCatalog(conn) => IcebergCatalog(conn.into_inline_connection(self)),
+            Gcs(conn) => Gcs(conn),
         }
     }
 }
@@ -2804,6 +2854,15 @@ impl OptimizerCatalog for CatalogState {
     ) -> Box<dyn Iterator<Item = (GlobalId, &Index)> + '_> {
         Box::new(CatalogState::get_indexes_on(self, id, cluster))
     }
+    fn get_table_checks(&self, id: &GlobalId) -> &[TableCheck] {
+        match self
+            .try_get_entry_by_global_id(id)
+            .map(|entry| entry.item())
+        {
+            Some(CatalogItem::Table(table)) if table.global_id_writes() == *id => table.checks(),
+            _ => &[],
+        }
+    }
 }
 
 impl OptimizerCatalog for Catalog {
@@ -2830,6 +2889,10 @@ impl OptimizerCatalog for Catalog {
     ) -> Box<dyn Iterator<Item = (GlobalId, &Index)> + '_> {
         Box::new(self.state.get_indexes_on(id, cluster))
     }
+
+    fn get_table_checks(&self, id: &GlobalId) -> &[TableCheck] {
+        OptimizerCatalog::get_table_checks(&self.state, id)
+    }
 }
 
 impl Catalog {
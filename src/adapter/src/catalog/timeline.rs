@@ -98,6 +98,7 @@ impl Catalog {
                         | CatalogItem::Sink(_)
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_)
+                        | CatalogItem::Sequence(_)
                         | CatalogItem::Secret(_)
                         | CatalogItem::Connection(_)
                         | CatalogItem::Log(_) => {}
@@ -244,6 +245,7 @@ impl Catalog {
                     CatalogItem::Sink(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::Sequence(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::Connection(_) => {}
                 }
@@ -40,6 +40,7 @@ use mz_ore::collections::HashSet;
 use mz_ore::instrument;
 use mz_ore::now::EpochMillis;
 use mz_persist_types::ShardId;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap, merge_mz_acl_items};
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::optimize::OptimizerFeatures;
@@ -103,6 +104,11 @@ pub enum Op {
         value: Option<Value>,
         interval: Duration,
     },
+    AlterStorageCompression {
+        id: CatalogItemId,
+        value: Option<Value>,
+        compression: Option<CompressionFormat>,
+    },
     AlterRole {
         id: RoleId,
         name: String,
@@ -617,6 +623,7 @@ impl Catalog {
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::Sequence(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::Connection(_)
                     | CatalogItem::ContinualTask(_) => {}
@@ -902,6 +909,36 @@ impl Catalog {
 
                 Self::log_update(state, &id);
             }
+            Op::AlterStorageCompression {
+                id,
+                value,
+                compression,
+            } => {
+                let entry = state.get_entry(&id);
+                if id.is_system() {
+                    let name = entry.name();
+                    let full_name =
+                        state.resolve_full_name(name, session.map(|session| session.conn_id()));
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::ReadOnlyItem(
+                        full_name.to_string(),
+                    ))));
+                }
+
+                let mut new_entry = entry.clone();
+                new_entry
+                    .item
+                    .update_storage_compression(value, compression)
+                    .map_err(|_| {
+                        AdapterError::Catalog(Error::new(ErrorKind::Internal(
+                            "planner should have rejected invalid alter storage compression item type"
+                                .to_string(),
+                        )))
+                    })?;
+
+                tx.update_item(id, new_entry.into())?;
+
+                Self::log_update(state, &id);
+            }
             Op::AlterRole {
                 id,
                 name,
@@ -1382,6 +1419,7 @@ impl Catalog {
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::Sequence(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::Connection(_) => (),
                 }
@@ -1498,6 +1536,9 @@ impl Catalog {
                         &temporary_oids,
                         versions,
                     )?;
+                    if let CatalogItem::Sequence(sequence) = &item {
+                        tx.insert_sequence_state(id, sequence.options.initial_state())?;
+                    }
                     info!(
                         "create {} {} ({})",
                         item_type,
@@ -1566,6 +1607,7 @@ impl Catalog {
                         | CatalogItem::View(_)
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_)
+                        | CatalogItem::Sequence(_)
                         | CatalogItem::Secret(_)
                         | CatalogItem::Connection(_)
                         | CatalogItem::ContinualTask(_) => {
@@ -1706,6 +1748,11 @@ impl Catalog {
                         .map(|id| id)
                         .partition(|id| !state.get_entry(*id).item().is_temporary());
                 tx.remove_items(&durable_items_to_drop)?;
+                for id in &durable_items_to_drop {
+                    if let CatalogItem::Sequence(_) = state.get_entry(*id).item() {
+                        tx.remove_sequence_state(**id)?;
+                    }
+                }
                 temporary_item_updates.extend(temporary_items_to_drop.into_iter().map(|id| {
                     let entry = state.get_entry(&id);
                     (entry.clone().into(), StateDiff::Retraction)
@@ -3208,7 +3255,12 @@ mod tests {
                     resolved_ids: ResolvedIds::empty(),
                     custom_logical_compaction_window: None,
                     is_retained_metrics_object: false,
-                    data_source: TableDataSource::TableWrites { defaults: vec![] },
+                    storage_compression: None,
+                    data_source: TableDataSource::TableWrites {
+                        defaults: vec![],
+                        checks: vec![],
+                        uniques: vec![],
+                    },
                 }),
                 owner_id: MZ_SYSTEM_ROLE_ID,
             };
@@ -239,6 +239,7 @@ impl Client {
         let client_ip = session.client_ip();
         let application_name = session.application_name().into();
         let notice_tx = session.retain_notice_transmitter();
+        let notification_tx = session.retain_notification_transmitter();
 
         let (tx, rx) = oneshot::channel();
 
@@ -263,6 +264,7 @@ impl Client {
             client_ip: client_ip.copied(),
             application_name,
             notice_tx,
+            notification_tx,
         });
 
         // When startup fails, no need to call terminate (handle_startup does this). Delay creating
@@ -52,7 +52,7 @@ use crate::coord::peek::{PeekDataflowPlan, PeekResponseUnary};
 use crate::coord::timestamp_selection::TimestampDetermination;
 use crate::coord::{ExecuteContextExtra, ExecuteContextGuard};
 use crate::error::AdapterError;
-use crate::session::{EndTransactionAction, RowBatchStream, Session};
+use crate::session::{EndTransactionAction, Notification, RowBatchStream, Session};
 use crate::statement_logging::{
     FrontendStatementLoggingEvent, StatementEndedExecutionReason, StatementExecutionStrategy,
     StatementLoggingFrontend,
@@ -98,6 +98,7 @@ pub enum Command {
         uuid: Uuid,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        notification_tx: mpsc::UnboundedSender<Notification>,
     },
 
     AuthenticatePassword {
@@ -549,6 +550,8 @@ pub enum ExecuteResponse {
         params: CopyFormatParams<'static>,
         ctx_extra: ExecuteContextGuard,
     },
+    /// The requested channel was bound to a view.
+    CreatedChannel,
     /// The requested connection was created.
     CreatedConnection,
     /// The requested database was created.
@@ -583,6 +586,10 @@ pub enum ExecuteResponse {
     CreatedContinualTask,
     /// The requested type was created.
     CreatedType,
+    /// The requested function was created.
+    CreatedFunction,
+    /// The requested sequence was created.
+    CreatedSequence,
     /// The requested network policy was created.
     CreatedNetworkPolicy,
     /// The requested prepared statement was removed.
@@ -595,6 +602,8 @@ pub enum ExecuteResponse {
     DiscardedTemp,
     /// All state associated with the session has been discarded.
     DiscardedAll,
+    /// The requested channel was unbound from its view.
+    DroppedChannel,
     /// The requested object was dropped.
     DroppedObject(ObjectType),
     /// The requested objects were dropped.
@@ -617,6 +626,13 @@ pub enum ExecuteResponse {
     GrantedRole,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
+    /// The session started listening on the requested channel.
+    Listened,
+    /// The specified number of rows were inserted, updated, or deleted by a
+    /// `MERGE` into the requested table.
+    Merged(usize),
+    /// A notification was sent on the requested channel.
+    Notified,
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
@@ -665,6 +681,8 @@ pub enum ExecuteResponse {
         /// Session parameters that changed because the transaction ended.
         params: BTreeMap<&'static str, String>,
     },
+    /// The session stopped listening on the requested channels.
+    Unlistened,
     /// The specified number of rows were updated in the requested table.
     Updated(usize),
     /// A connection was validated.
@@ -732,6 +750,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Copied => Err(()),
             ExecuteResponseKind::CopyTo => Err(()),
             ExecuteResponseKind::CopyFrom => Err(()),
+            ExecuteResponseKind::CreatedChannel => Ok(ExecuteResponse::CreatedChannel),
             ExecuteResponseKind::CreatedConnection => Ok(ExecuteResponse::CreatedConnection),
             ExecuteResponseKind::CreatedDatabase => Ok(ExecuteResponse::CreatedDatabase),
             ExecuteResponseKind::CreatedSchema => Ok(ExecuteResponse::CreatedSchema),
@@ -753,11 +772,14 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::CreatedNetworkPolicy => Ok(ExecuteResponse::CreatedNetworkPolicy),
             ExecuteResponseKind::CreatedContinualTask => Ok(ExecuteResponse::CreatedContinualTask),
             ExecuteResponseKind::CreatedType => Ok(ExecuteResponse::CreatedType),
+            ExecuteResponseKind::CreatedFunction => Ok(ExecuteResponse::CreatedFunction),
+            ExecuteResponseKind::CreatedSequence => Ok(ExecuteResponse::CreatedSequence),
             ExecuteResponseKind::Deallocate => Err(()),
             ExecuteResponseKind::DeclaredCursor => Ok(ExecuteResponse::DeclaredCursor),
             ExecuteResponseKind::Deleted => Err(()),
             ExecuteResponseKind::DiscardedTemp => Ok(ExecuteResponse::DiscardedTemp),
             ExecuteResponseKind::DiscardedAll => Ok(ExecuteResponse::DiscardedAll),
+            ExecuteResponseKind::DroppedChannel => Ok(ExecuteResponse::DroppedChannel),
             ExecuteResponseKind::DroppedObject => Err(()),
             ExecuteResponseKind::DroppedOwned => Ok(ExecuteResponse::DroppedOwned),
             ExecuteResponseKind::EmptyQuery => Ok(ExecuteResponse::EmptyQuery),
@@ -765,6 +787,9 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
             ExecuteResponseKind::GrantedRole => Ok(ExecuteResponse::GrantedRole),
             ExecuteResponseKind::Inserted => Err(()),
+            ExecuteResponseKind::Listened => Ok(ExecuteResponse::Listened),
+            ExecuteResponseKind::Merged => Err(()),
+            ExecuteResponseKind::Notified => Ok(ExecuteResponse::Notified),
             ExecuteResponseKind::Prepare => Ok(ExecuteResponse::Prepare),
             ExecuteResponseKind::Raised => Ok(ExecuteResponse::Raised),
             ExecuteResponseKind::ReassignOwned => Ok(ExecuteResponse::ReassignOwned),
@@ -775,6 +800,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Subscribing => Err(()),
             ExecuteResponseKind::TransactionCommitted => Err(()),
             ExecuteResponseKind::TransactionRolledBack => Err(()),
+            ExecuteResponseKind::Unlistened => Ok(ExecuteResponse::Unlistened),
             ExecuteResponseKind::Updated => Err(()),
             ExecuteResponseKind::ValidatedConnection => Ok(ExecuteResponse::ValidatedConnection),
             ExecuteResponseKind::SendingRowsStreaming => Err(()),
@@ -799,6 +825,7 @@ impl ExecuteResponse {
             Copied(n) => Some(format!("COPY {}", n)),
             CopyTo { .. } => None,
             CopyFrom { .. } => None,
+            CreatedChannel => Some("CREATE CHANNEL".into()),
             CreatedConnection { .. } => Some("CREATE CONNECTION".into()),
             CreatedDatabase { .. } => Some("CREATE DATABASE".into()),
             CreatedSchema { .. } => Some("CREATE SCHEMA".into()),
@@ -815,12 +842,15 @@ impl ExecuteResponse {
             CreatedMaterializedView { .. } => Some("CREATE MATERIALIZED VIEW".into()),
             CreatedContinualTask { .. } => Some("CREATE CONTINUAL TASK".into()),
             CreatedType => Some("CREATE TYPE".into()),
+            CreatedFunction => Some("CREATE FUNCTION".into()),
+            CreatedSequence => Some("CREATE SEQUENCE".into()),
             CreatedNetworkPolicy => Some("CREATE NETWORKPOLICY".into()),
             Deallocate { all } => Some(format!("DEALLOCATE{}", if *all { " ALL" } else { "" })),
             DeclaredCursor => Some("DECLARE CURSOR".into()),
             Deleted(n) => Some(format!("DELETE {}", n)),
             DiscardedTemp => Some("DISCARD TEMP".into()),
             DiscardedAll => Some("DISCARD ALL".into()),
+            DroppedChannel => Some("DROP CHANNEL".into()),
             DroppedObject(o) => Some(format!("DROP {o}")),
             DroppedOwned => Some("DROP OWNED".into()),
             EmptyQuery => None,
@@ -837,6 +867,9 @@ impl ExecuteResponse {
                 // have OIDs.
                 Some(format!("INSERT 0 {}", n))
             }
+            Listened => Some("LISTEN".into()),
+            Merged(n) => Some(format!("MERGE {}", n)),
+            Notified => Some("NOTIFY".into()),
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
             ReassignOwned => Some("REASSIGN OWNED".into()),
@@ -849,6 +882,7 @@ impl ExecuteResponse {
             Subscribing { .. } => None,
             TransactionCommitted { .. } => Some("COMMIT".into()),
             TransactionRolledBack { .. } => Some("ROLLBACK".into()),
+            Unlistened => Some("UNLISTEN".into()),
             Updated(n) => Some(format!("UPDATE {}", n)),
             ValidatedConnection => Some("VALIDATE CONNECTION".into()),
             CreatedIntrospectionSubscribe => Some("CREATE INTROSPECTION SUBSCRIBE".into()),
@@ -871,6 +905,7 @@ impl ExecuteResponse {
             | AlterOwner
             | AlterItemRename
             | AlterRetainHistory
+            | AlterStorageCompression
             | AlterSourceTimestampInterval
             | AlterNoop
             | AlterSchemaRename
@@ -880,6 +915,8 @@ impl ExecuteResponse {
             | AlterSource
             | AlterSink
             | AlterTableAddColumn
+            | AlterTableAddConstraint
+            | AlterTableDropConstraint
             | AlterMaterializedViewApplyReplacement
             | AlterNetworkPolicy => &[AlteredObject],
             AlterDefaultPrivileges => &[AlteredDefaultPrivileges],
@@ -893,6 +930,7 @@ impl ExecuteResponse {
             PlanKind::CopyTo => &[ExecuteResponseKind::Copied],
             PlanKind::Comment => &[ExecuteResponseKind::Comment],
             CommitTransaction => &[TransactionCommitted, TransactionRolledBack],
+            CreateChannel => &[CreatedChannel],
             CreateConnection => &[CreatedConnection],
             CreateDatabase => &[CreatedDatabase],
             CreateSchema => &[CreatedSchema],
@@ -908,16 +946,28 @@ impl ExecuteResponse {
             CreateContinualTask => &[CreatedContinualTask],
             CreateIndex => &[CreatedIndex],
             CreateType => &[CreatedType],
+            CreateFunction => &[CreatedFunction],
+            CreateSequence => &[CreatedSequence],
             PlanKind::Deallocate => &[ExecuteResponseKind::Deallocate],
             CreateNetworkPolicy => &[CreatedNetworkPolicy],
             Declare => &[DeclaredCursor],
             DiscardTemp => &[DiscardedTemp],
             DiscardAll => &[DiscardedAll],
+            DropChannel => &[DroppedChannel],
             DropObjects => &[DroppedObject],
             DropOwned => &[DroppedOwned],
             PlanKind::EmptyQuery => &[ExecuteResponseKind::EmptyQuery],
-            ExplainPlan | ExplainPushdown | ExplainTimestamp | Select | ShowAllVariables
-            | ShowCreate | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema => &[
+            ExplainPlan
+            | ExplainPushdown
+            | ExplainRecommendations
+            | ExplainTimestamp
+            | Select
+            | ShowAllVariables
+            | ShowCreate
+            | ShowColumns
+            | ShowVariable
+            | InspectShard
+            | ExplainSinkSchema => &[
                 ExecuteResponseKind::CopyTo,
                 SendingRowsStreaming,
                 SendingRowsImmediate,
@@ -925,6 +975,7 @@ impl ExecuteResponse {
             Execute | ReadThenWrite => &[
                 Deleted,
                 Inserted,
+                Merged,
                 SendingRowsStreaming,
                 SendingRowsImmediate,
                 Updated,
@@ -933,6 +984,8 @@ impl ExecuteResponse {
             GrantPrivileges => &[GrantedPrivilege],
             GrantRole => &[GrantedRole],
             Insert => &[Inserted, SendingRowsImmediate],
+            Listen => &[Listened],
+            Notify => &[Notified],
             PlanKind::Prepare => &[ExecuteResponseKind::Prepare],
             PlanKind::Raise => &[ExecuteResponseKind::Raised],
             PlanKind::ReassignOwned => &[ExecuteResponseKind::ReassignOwned],
@@ -943,6 +996,7 @@ impl ExecuteResponse {
             }
             PlanKind::Subscribe => &[Subscribing, ExecuteResponseKind::CopyTo],
             StartTransaction => &[StartedTransaction],
+            Unlisten => &[Unlistened],
             SideEffectingFunc => &[SendingRowsStreaming, SendingRowsImmediate],
             ValidateConnection => &[ExecuteResponseKind::ValidatedConnection],
         }
@@ -45,6 +45,7 @@ pub fn ct_item_from_plan(
                 compaction_window: _,
                 refresh_schedule: _,
                 as_of,
+                storage_compression: _,
             },
     } = plan;
 
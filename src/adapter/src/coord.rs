@@ -100,6 +100,7 @@ use mz_catalog::builtin::{BUILTINS, BUILTINS_STATIC, MZ_AUDIT_EVENTS, MZ_STORAGE
 use mz_catalog::config::{AwsPrincipalContext, BuiltinItemMigrationConfig, ClusterReplicaSizeMap};
 use mz_catalog::durable::{AuditLogIterator, OpenableDurableCatalogState};
 use mz_catalog::expr_cache::{GlobalExpressions, LocalExpressions};
+use mz_catalog::memory::error::ErrorKind;
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, ClusterReplicaProcessStatus, ClusterVariantManaged, Connection,
     DataSourceDesc, StateDiff, StateUpdate, StateUpdateKind, Table, TableDataSource,
@@ -144,7 +145,7 @@ use mz_repr::{CatalogItemId, Diff, GlobalId, RelationDesc, SqlRelationType, Time
 use mz_secrets::cache::CachingSecretsReader;
 use mz_secrets::{SecretsController, SecretsReader};
 use mz_sql::ast::{Raw, Statement};
-use mz_sql::catalog::{CatalogCluster, EnvironmentId};
+use mz_sql::catalog::{CatalogCluster, CatalogError, EnvironmentId};
 use mz_sql::names::{QualifiedItemName, ResolvedIds, SchemaSpecifier};
 use mz_sql::optimizer_metrics::OptimizerMetrics;
 use mz_sql::plan::{
@@ -184,7 +185,7 @@ use crate::client::{Client, Handle};
 use crate::command::{Command, ExecuteResponse};
 use crate::config::{SynchronizedParameters, SystemParameterFrontend, SystemParameterSyncConfig};
 use crate::coord::appends::{
-    BuiltinTableAppendNotify, DeferredOp, GroupCommitPermit, PendingWriteTxn,
+    BuiltinTableAppendNotify, DeferredOp, GroupCommitPermit, PendingWriteTxn, ValidatedWrites,
 };
 use crate::coord::caught_up::CaughtUpCheckContext;
 use crate::coord::cluster_scheduling::SchedulingDecision;
@@ -201,7 +202,7 @@ use crate::explain::optimizer_trace::{DispatchGuard, OptimizerTrace};
 use crate::metrics::Metrics;
 use crate::optimize::dataflows::{ComputeInstanceSnapshot, DataflowBuilder};
 use crate::optimize::{self, Optimize, OptimizerConfig};
-use crate::session::{EndTransactionAction, Session};
+use crate::session::{EndTransactionAction, Notification, Session};
 use crate::statement_logging::{
     StatementEndedExecutionReason, StatementLifecycleEvent, StatementLoggingId,
 };
@@ -217,8 +218,11 @@ pub(crate) mod id_bundle;
 pub(crate) mod in_memory_oracle;
 pub(crate) mod peek;
 pub(crate) mod read_policy;
+pub(crate) mod read_write_txns;
 pub(crate) mod sequencer;
 pub(crate) mod statement_logging;
+pub(crate) mod table_checks;
+pub(crate) mod table_keys;
 pub(crate) mod timeline;
 pub(crate) mod timestamp_selection;
 
@@ -315,6 +319,8 @@ pub enum Message {
     PurifiedStatementReady(PurifiedStatementReady),
     CreateConnectionValidationReady(CreateConnectionValidationReady),
     AlterConnectionValidationReady(AlterConnectionValidationReady),
+    ExplainRecommendationsReady(ExplainRecommendationsReady),
+    AddConstraintValidationReady(AddConstraintValidationReady),
     TryDeferred {
         /// The connection that created this op.
         conn_id: ConnectionId,
@@ -331,12 +337,22 @@ pub enum Message {
     },
     /// Initiates a group commit.
     GroupCommitInitiate(Span, Option<GroupCommitPermit>),
+    /// Commits the user writes of a group commit that were validated off the
+    /// main loop.
+    GroupCommitValidated(ValidatedWrites),
     DeferredStatementReady,
     AdvanceTimelines,
     ClusterEvent(ClusterEvent),
     CancelPendingPeeks {
         conn_id: ConnectionId,
     },
+    /// Allocates `count` values from the sequence `id` on behalf of an
+    /// `INSERT` or `COPY` into a table with sequence defaults.
+    AllocateSequenceValues {
+        id: CatalogItemId,
+        count: usize,
+        tx: oneshot::Sender<Result<Vec<i64>, AdapterError>>,
+    },
     LinearizeReads,
     StagedBatches {
         conn_id: ConnectionId,
@@ -347,6 +363,9 @@ pub enum Message {
     StorageUsageFetch,
     StorageUsageUpdate(ShardsUsageReferenced),
     StorageUsagePrune(Vec<BuiltinTableUpdate>),
+    /// Delivers the notifications of a committed transaction to the sessions
+    /// listening on their channels.
+    DeliverNotifications(Vec<Notification>),
     /// Performs any cleanup and logging actions necessary for
     /// finalizing a statement execution.
     RetireExecute {
@@ -474,15 +493,18 @@ impl Message {
             Message::CreateConnectionValidationReady(_) => "create_connection_validation_ready",
             Message::TryDeferred { .. } => "try_deferred",
             Message::GroupCommitInitiate(..) => "group_commit_initiate",
+            Message::GroupCommitValidated(..) => "group_commit_validated",
             Message::AdvanceTimelines => "advance_timelines",
             Message::ClusterEvent(_) => "cluster_event",
             Message::CancelPendingPeeks { .. } => "cancel_pending_peeks",
+            Message::AllocateSequenceValues { .. } => "allocate_sequence_values",
             Message::LinearizeReads => "linearize_reads",
             Message::StagedBatches { .. } => "staged_batches",
             Message::StorageUsageSchedule => "storage_usage_schedule",
             Message::StorageUsageFetch => "storage_usage_fetch",
             Message::StorageUsageUpdate(_) => "storage_usage_update",
             Message::StorageUsagePrune(_) => "storage_usage_prune",
+            Message::DeliverNotifications(_) => "deliver_notifications",
             Message::RetireExecute { .. } => "retire_execute",
             Message::ExecuteSingleStatementTransaction { .. } => {
                 "execute_single_statement_transaction"
@@ -502,6 +524,8 @@ impl Message {
             Message::ClusterStageReady { .. } => "cluster_stage_ready",
             Message::DrainStatementLog => "drain_statement_log",
             Message::AlterConnectionValidationReady(..) => "alter_connection_validation_ready",
+            Message::ExplainRecommendationsReady(..) => "explain_recommendations_ready",
+            Message::AddConstraintValidationReady(..) => "add_constraint_validation_ready",
             Message::PrivateLinkVpcEndpointEvents(_) => "private_link_vpc_endpoint_events",
             Message::CheckSchedulingPolicies => "check_scheduling_policies",
             Message::SchedulingDecisions { .. } => "scheduling_decision",
@@ -553,6 +577,48 @@ pub struct ValidationReady<T> {
 pub type CreateConnectionValidationReady = ValidationReady<CreateConnectionPlan>;
 pub type AlterConnectionValidationReady = ValidationReady<Connection>;
 
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct ExplainRecommendationsReady {
+    #[derivative(Debug = "ignore")]
+    pub ctx: ExecuteContext,
+    pub result: Result<Vec<IndexRecommendation>, AdapterError>,
+    pub plan_validity: PlanValidity,
+    pub otel_ctx: OpenTelemetryContext,
+}
+
+/// The result of checking the contents of a table against the constraint that
+/// `ALTER TABLE ... ADD CONSTRAINT` adds to it.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct AddConstraintValidationReady {
+    #[derivative(Debug = "ignore")]
+    pub ctx: ExecuteContext,
+    pub result: Result<(), AdapterError>,
+    pub plan: plan::AlterTableAddConstraintPlan,
+    pub plan_validity: PlanValidity,
+    pub otel_ctx: OpenTelemetryContext,
+}
+
+/// An index that `EXPLAIN RECOMMENDATIONS` recommends creating.
+#[derive(Debug)]
+pub struct IndexRecommendation {
+    /// The `CREATE INDEX` statement that creates the index.
+    pub create_sql: String,
+    /// The collection that the index is on.
+    pub on_id: GlobalId,
+    /// The number of columns of the collection that the index is on.
+    pub arity: usize,
+    /// The number of columns of the index key.
+    pub key_arity: usize,
+    /// The number of logged statement executions that the index would speed up.
+    pub statements: u64,
+    /// One of the statements that the index would speed up.
+    pub example: String,
+    /// The estimated size of the index in bytes, if known.
+    pub size: Option<u64>,
+}
+
 #[derive(Debug)]
 pub enum PeekStage {
     /// Common stages across SELECT, EXPLAIN and COPY TO queries.
@@ -956,6 +1022,9 @@ pub struct SubscribeOptimizeMir {
     /// An optional context set iff the state machine is initiated from
     /// sequencing an EXPLAIN for this statement.
     explain_ctx: ExplainContext,
+    /// The channel to bind to the subscribed view, set iff the state machine
+    /// is initiated from sequencing a `CREATE CHANNEL`.
+    channel: Option<ViewChannel>,
 }
 
 #[derive(Debug)]
@@ -970,6 +1039,7 @@ pub struct SubscribeTimestampOptimizeLir {
     /// An optional context set iff the state machine is initiated from
     /// sequencing an EXPLAIN for this statement.
     explain_ctx: ExplainContext,
+    channel: Option<ViewChannel>,
 }
 
 #[derive(Debug)]
@@ -980,6 +1050,16 @@ pub struct SubscribeFinish {
     plan: plan::SubscribePlan,
     global_lir_plan: optimize::subscribe::GlobalLirPlan,
     dependency_ids: BTreeSet<GlobalId>,
+    channel: Option<ViewChannel>,
+}
+
+/// A channel that is being bound to a view by `CREATE CHANNEL`.
+#[derive(Debug)]
+pub struct ViewChannel {
+    /// The name of the channel.
+    name: String,
+    /// The description of the view, used to render notification payloads.
+    desc: RelationDesc,
 }
 
 #[derive(Debug)]
@@ -1219,6 +1299,22 @@ pub struct ConnMeta {
     #[serde(skip)]
     notice_tx: mpsc::UnboundedSender<AdapterNotice>,
 
+    /// Channel on which to send asynchronous notifications to a session.
+    #[serde(skip)]
+    notification_tx: mpsc::UnboundedSender<Notification>,
+
+    /// The notification channels the session is listening on.
+    listen_channels: BTreeSet<String>,
+
+    /// The channels the session bound to views, and the subscribes that
+    /// follow the changes to the views.
+    view_channels: BTreeMap<String, GlobalId>,
+
+    /// The most recent value returned by `nextval` (or `setval`) for each
+    /// sequence in this session, as observed by `currval`.
+    #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
+    sequence_values: BTreeMap<CatalogItemId, i64>,
+
     /// The role that initiated the database context. Fixed for the duration of the connection.
     /// WARNING: This role reference is not updated when the role is dropped.
     /// Consumers should not assume that this role exist.
@@ -1617,7 +1713,7 @@ impl ExecuteContext {
         let ExecuteContextInner {
             tx,
             internal_cmd_tx,
-            session,
+            mut session,
             extra,
         } = *self.inner;
         let reason = if extra.is_trivial() {
@@ -1625,7 +1721,15 @@ impl ExecuteContext {
         } else {
             Some((&result).into())
         };
+        // Only a successful commit delivers the transaction's notifications.
+        let notifications = session.take_committing_notifications();
+        let committed = matches!(result, Ok(ExecuteResponse::TransactionCommitted { .. }));
         tx.send(result, session);
+        if committed && !notifications.is_empty() {
+            if let Err(e) = internal_cmd_tx.send(Message::DeliverNotifications(notifications)) {
+                warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+            }
+        }
         if let Some(reason) = reason {
             // Retire the guard to get the inner ExecuteContextExtra without triggering auto-retire
             let extra = extra.defuse();
@@ -1990,6 +2094,10 @@ pub struct Coordinator {
 
     /// Pre-allocated pool of user IDs to amortize persist writes across DDL operations.
     user_id_pool: IdPool,
+
+    /// Values that have been durably allocated from each sequence but not yet
+    /// handed out, to amortize catalog writes across `nextval` calls.
+    sequence_caches: BTreeMap<CatalogItemId, VecDeque<i64>>,
 }
 
 impl Coordinator {
@@ -2356,6 +2464,7 @@ impl Coordinator {
                 CatalogItem::Log(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::Sequence(_)
                 | CatalogItem::Secret(_) => {}
             }
         }
@@ -2919,6 +3028,7 @@ impl Coordinator {
 
         let mut compute_collections = vec![];
         let mut collections = vec![];
+        let mut storage_compressions = vec![];
         for entry in catalog.entries() {
             match entry.item() {
                 CatalogItem::Source(source) => {
@@ -2933,8 +3043,12 @@ impl Coordinator {
                     ));
                 }
                 CatalogItem::Table(table) => {
+                    if entry.id().is_user() {
+                        storage_compressions
+                            .push((table.global_id_writes(), table.storage_compression));
+                    }
                     match &table.data_source {
-                        TableDataSource::TableWrites { defaults: _ } => {
+                        TableDataSource::TableWrites { .. } => {
                             let versions: BTreeMap<_, _> = table
                                 .collection_descs()
                                 .map(|(gid, version, desc)| (version, (gid, desc)))
@@ -2974,6 +3088,9 @@ impl Coordinator {
                     };
                 }
                 CatalogItem::MaterializedView(mv) => {
+                    if entry.id().is_user() {
+                        storage_compressions.push((mv.global_id_writes(), mv.storage_compression));
+                    }
                     let collection_descs = mv.collection_descs().map(|(gid, _version, desc)| {
                         let collection_desc =
                             CollectionDescription::for_other(desc, mv.initial_as_of.clone());
@@ -3029,6 +3146,7 @@ impl Coordinator {
                 | CatalogItem::Index(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::Sequence(_)
                 | CatalogItem::Secret(_)
                 | CatalogItem::Connection(_) => (),
             }
@@ -3150,6 +3268,13 @@ impl Coordinator {
 
         if !self.controller.read_only() {
             self.apply_local_write(register_ts).await;
+
+            // Re-apply the compression policies recorded in the catalog, in
+            // case applying an `ALTER` to persist failed before a restart.
+            // This is a no-op for shards whose policy is already up to date.
+            for (global_id, compression) in storage_compressions {
+                self.set_storage_compression_in_background(global_id, compression);
+            }
         }
     }
 
@@ -3270,6 +3395,7 @@ impl Coordinator {
                                         entry.name().clone(),
                                         idx.on,
                                         idx.keys.to_vec(),
+                                        idx.spill_to_disk,
                                     );
                                     let global_mir_plan = optimizer.optimize(index_plan)?;
                                     let optimized_plan = global_mir_plan.df_desc().clone();
@@ -3477,6 +3603,7 @@ impl Coordinator {
                 | CatalogItem::Sink(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::Sequence(_)
                 | CatalogItem::Secret(_)
                 | CatalogItem::Connection(_) => (),
             }
@@ -3510,6 +3637,7 @@ impl Coordinator {
                 | CatalogItem::Sink(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
+                | CatalogItem::Sequence(_)
                 | CatalogItem::Secret(_)
                 | CatalogItem::Connection(_) => continue,
             };
@@ -3935,6 +4063,55 @@ impl Coordinator {
             .collect())
     }
 
+    /// Allocates `count` values from the sequence `id`, refilling the
+    /// sequence's cache from the catalog if needed.
+    async fn allocate_sequence_values(
+        &mut self,
+        id: CatalogItemId,
+        count: usize,
+    ) -> Result<Vec<i64>, AdapterError> {
+        // The sequence might have been dropped since the statement that uses
+        // it was planned.
+        let Some(options) = self
+            .catalog()
+            .try_get_entry(&id)
+            .and_then(|entry| entry.sequence_options())
+            .copied()
+        else {
+            return Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind: ErrorKind::Sql(CatalogError::UnknownItem(id.to_string())),
+            }));
+        };
+        let cached = self.sequence_caches.get(&id).map_or(0, |cache| cache.len());
+        if cached < count {
+            let to_allocate = (count - cached).max(usize::cast_from(options.cache));
+            let ts = self.get_catalog_write_ts().await;
+            let values = self
+                .catalog()
+                .allocate_sequence_values(id, options, to_allocate, ts)
+                .await?;
+            let cache = self.sequence_caches.entry(id).or_default();
+            cache.extend(values);
+            if cache.len() < count {
+                let name = self
+                    .catalog()
+                    .resolve_full_name(self.catalog().get_entry(&id).name(), None);
+                let ascending = options.increment > 0;
+                return Err(AdapterError::SequenceExhausted {
+                    sequence_name: name.to_string(),
+                    ascending,
+                    limit: if ascending {
+                        options.max_value
+                    } else {
+                        options.min_value
+                    },
+                });
+            }
+        }
+        let cache = self.sequence_caches.get_mut(&id).expect("cache refilled");
+        Ok(cache.drain(..count).collect())
+    }
+
     /// Obtain a reference to the coordinator's connection context.
     fn connection_context(&self) -> &ConnectionContext {
         self.controller.connection_context()
@@ -4732,6 +4909,7 @@ pub fn serve(
                     buffered_builtin_table_updates: Some(Vec::new()),
                     license_key,
                     user_id_pool: IdPool::empty(),
+                    sequence_caches: BTreeMap::new(),
                     persist_client,
                 };
                 let bootstrap = handle.block_on(async {
@@ -35,8 +35,11 @@ use tokio::sync::{Notify, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore, ones
 use tracing::{Instrument, Span, debug_span, info, warn};
 
 use crate::catalog::{BuiltinTableUpdate, Catalog};
+use crate::coord::read_write_txns::{ReadSetValidation, TxnReadSet};
+use crate::coord::table_checks::CheckValidation;
+use crate::coord::table_keys::KeyValidation;
 use crate::coord::{Coordinator, Message, PendingTxn, PlanValidity};
-use crate::session::{GroupCommitWriteLocks, Session, WriteLocks};
+use crate::session::{EndTransactionAction, GroupCommitWriteLocks, Session, WriteLocks};
 use crate::util::{CompletedClientTransmitter, ResultExt};
 use crate::{AdapterError, ExecuteContext};
 
@@ -81,7 +84,7 @@ impl DeferredOp {
                 itertools::Either::Left(iter)
             }
             DeferredOp::Write(write) => {
-                let iter = write.writes.keys().copied();
+                let iter = user_write_locks(&write.writes, &write.read_set);
                 itertools::Either::Right(iter)
             }
         }
@@ -119,9 +122,64 @@ pub struct DeferredPlan {
 pub struct DeferredWrite {
     pub span: Span,
     pub writes: BTreeMap<CatalogItemId, SmallVec<[TableData; 1]>>,
+    pub read_set: Option<TxnReadSet>,
     pub pending_txn: PendingTxn,
 }
 
+/// The user writes of a group commit that were validated off the main loop,
+/// see [`Coordinator::group_commit`].
+#[derive(Debug)]
+pub struct ValidatedWrites {
+    pub span: Span,
+    /// The accepted writes, in their original order.
+    pub writes: Vec<PendingWriteTxn>,
+    /// The write locks of all tables the writes read from or write to.
+    pub write_locks: GroupCommitWriteLocks,
+}
+
+/// What needs validating about the user writes of a group commit that reads
+/// tables, see [`Coordinator::group_commit`].
+#[derive(Debug)]
+struct WriteValidation {
+    read_sets: Option<ReadSetValidation>,
+    checks: Option<CheckValidation>,
+    keys: Option<KeyValidation>,
+}
+
+impl WriteValidation {
+    fn is_needed(&self) -> bool {
+        self.read_sets.is_some() || self.checks.is_some() || self.keys.is_some()
+    }
+}
+
+/// Rolls back the user transactions in `writes` at the positions in
+/// `violations`, with their errors. Returns the remaining writes.
+fn reject_writes(
+    writes: Vec<PendingWriteTxn>,
+    violations: Vec<(usize, AdapterError)>,
+) -> Vec<PendingWriteTxn> {
+    let mut violations: BTreeMap<_, _> = violations.into_iter().collect();
+    let mut remaining = Vec::with_capacity(writes.len());
+    for (i, write) in writes.into_iter().enumerate() {
+        match (violations.remove(&i), write) {
+            (
+                Some(err),
+                PendingWriteTxn::User {
+                    pending_txn: PendingTxn { ctx, .. },
+                    ..
+                },
+            ) => {
+                let (ctx, result) =
+                    CompletedClientTransmitter::new(ctx, Err(err), EndTransactionAction::Rollback)
+                        .finalize();
+                ctx.retire(result);
+            }
+            (_, write) => remaining.push(write),
+        }
+    }
+    remaining
+}
+
 /// Describes what action triggered an update to a builtin table.
 #[derive(Debug)]
 pub(crate) enum BuiltinTableUpdateSource {
@@ -139,7 +197,11 @@ pub(crate) enum PendingWriteTxn {
         span: Span,
         /// List of all write operations within the transaction.
         writes: BTreeMap<CatalogItemId, SmallVec<[TableData; 1]>>,
-        /// If they exist, should contain locks for each [`CatalogItemId`] in `writes`.
+        /// For read-write transactions, the tables read before writing. The
+        /// writes are only committed if none of them were written since.
+        read_set: Option<TxnReadSet>,
+        /// If they exist, should contain locks for each [`CatalogItemId`] in `writes` and
+        /// `read_set`.
         write_locks: Option<WriteLocks>,
         /// Inner transaction.
         pending_txn: PendingTxn,
@@ -151,6 +213,16 @@ pub(crate) enum PendingWriteTxn {
     },
 }
 
+/// Returns the collections a user write needs write locks for: the ones it
+/// writes to and, for a read-write transaction, the ones it read.
+fn user_write_locks<'a>(
+    writes: &'a BTreeMap<CatalogItemId, SmallVec<[TableData; 1]>>,
+    read_set: &'a Option<TxnReadSet>,
+) -> impl Iterator<Item = CatalogItemId> + 'a {
+    let reads = read_set.iter().flat_map(|read_set| read_set.ids.iter());
+    writes.keys().chain(reads).copied()
+}
+
 impl PendingWriteTxn {
     fn is_internal_system(&self) -> bool {
         match self {
@@ -253,11 +325,13 @@ impl Coordinator {
             DeferredOp::Write(DeferredWrite {
                 span,
                 writes,
+                read_set,
                 pending_txn,
             }) => {
                 self.submit_write(PendingWriteTxn::User {
                     span,
                     writes,
+                    read_set,
                     write_locks,
                     pending_txn,
                 });
@@ -323,6 +397,10 @@ impl Coordinator {
     /// STORAGE as a single batch. All applicable writes will happen at the same timestamp and all
     /// involved tables will be advanced to some timestamp larger than the timestamp of the write.
     ///
+    /// If validating the user writes requires reading tables, they are instead validated in a
+    /// task, and the accepted ones are committed once it sends them back through
+    /// [`Message::GroupCommitValidated`]. Their write locks are held until then.
+    ///
     /// Returns the timestamp of the write.
     #[instrument(name = "coord::group_commit")]
     pub(crate) async fn group_commit(&mut self, permit: Option<GroupCommitPermit>) -> Timestamp {
@@ -344,8 +422,9 @@ impl Coordinator {
                     span,
                     write_locks: Some(write_locks),
                     writes,
+                    read_set,
                     pending_txn,
-                } => match write_locks.validate(writes.keys().copied()) {
+                } => match write_locks.validate(user_write_locks(&writes, &read_set)) {
                     Ok(validated_locks) => {
                         // Merge all of our write locks together since we can allow concurrent
                         // writes at the same timestamp.
@@ -354,6 +433,7 @@ impl Coordinator {
                         let validated_write = PendingWriteTxn::User {
                             span,
                             writes,
+                            read_set,
                             write_locks: None,
                             pending_txn,
                         };
@@ -375,16 +455,32 @@ impl Coordinator {
                 PendingWriteTxn::User {
                     span,
                     writes,
+                    read_set,
                     write_locks: None,
                     pending_txn,
                 } => {
-                    let missing = group_write_locks.missing_locks(writes.keys().copied());
-
-                    if missing.is_empty() {
+                    let required: BTreeSet<_> = user_write_locks(&writes, &read_set).collect();
+                    let missing = group_write_locks.missing_locks(required.iter().copied());
+                    // The transactions before a read-write transaction in this group commit
+                    // are committed at the same timestamp but after its reads, so it must not
+                    // share any tables with them. Wait for the next group commit instead.
+                    let shared = required.difference(&missing).next().copied();
+
+                    if let (Some(shared), Some(_)) = (shared, &read_set) {
+                        let acquire_future = self.grant_object_write_lock(shared).map(Option::Some);
+                        let write = DeferredWrite {
+                            span,
+                            writes,
+                            read_set,
+                            pending_txn,
+                        };
+                        deferred_writes.push((acquire_future, write));
+                    } else if missing.is_empty() {
                         // We have all the locks! Queue the pending write.
                         let validated_write = PendingWriteTxn::User {
                             span,
                             writes,
+                            read_set,
                             write_locks: None,
                             pending_txn,
                         };
@@ -406,6 +502,7 @@ impl Coordinator {
                                 let validated_write = PendingWriteTxn::User {
                                     span,
                                     writes,
+                                    read_set,
                                     write_locks: None,
                                     pending_txn,
                                 };
@@ -418,6 +515,7 @@ impl Coordinator {
                                 let write = DeferredWrite {
                                     span,
                                     writes,
+                                    read_set,
                                     pending_txn,
                                 };
                                 deferred_writes.push((acquire_future, write));
@@ -433,6 +531,118 @@ impl Coordinator {
             self.defer_op(acquire_future, DeferredOp::Write(write));
         }
 
+        // Now that we hold all the write locks, we can reject read-write
+        // transactions whose reads are out of date, and writes that would
+        // violate the CHECK constraints or the keys of the tables they write
+        // to. That means reading the history of tables, staged batches and the
+        // tables themselves, which must not hold up the main loop. So the user
+        // writes are validated in a task, which holds on to their write locks
+        // and sends the accepted writes back to be committed in a later group
+        // commit. System writes are committed right away.
+        let validation = WriteValidation {
+            read_sets: self.read_set_validation(&validated_writes),
+            checks: self.check_validation(&validated_writes),
+            keys: self.key_validation(&validated_writes),
+        };
+        if validation.is_needed() {
+            let (user_writes, system_writes) = validated_writes
+                .into_iter()
+                .partition(|write| matches!(write, PendingWriteTxn::User { .. }));
+            self.spawn_write_validation(validation, user_writes, group_write_locks);
+            return self
+                .commit_writes(system_writes, GroupCommitWriteLocks::default(), permit)
+                .await;
+        }
+
+        self.commit_writes(validated_writes, group_write_locks, permit)
+            .await
+    }
+
+    /// Validates the user writes of a group commit in a task, holding on to
+    /// their `write_locks`, and sends the accepted writes back to be committed,
+    /// see [`Coordinator::group_commit`].
+    fn spawn_write_validation(
+        &self,
+        validation: WriteValidation,
+        writes: Vec<PendingWriteTxn>,
+        write_locks: GroupCommitWriteLocks,
+    ) {
+        let mut persist_client = self.persist_client.clone();
+        let storage_collections = Arc::clone(&self.controller.storage_collections);
+        let oracle = self.get_local_timestamp_oracle();
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        let span = Span::current();
+        task::spawn(
+            || "group_commit_validate",
+            async move {
+                // Every earlier write to the tables has been applied, because
+                // we hold their write locks, so the read timestamp covers all
+                // of them.
+                let read_ts = oracle.read_ts().await;
+                let mut writes = writes;
+                if let Some(read_sets) = validation.read_sets {
+                    let violations = read_sets
+                        .validate(storage_collections.as_ref(), read_ts, &writes)
+                        .await;
+                    writes = reject_writes(writes, violations);
+                }
+                if let Some(checks) = validation.checks {
+                    let violations = checks.validate(&mut persist_client, &writes).await;
+                    writes = reject_writes(writes, violations);
+                }
+                if let Some(keys) = validation.keys {
+                    let violations = keys
+                        .validate(
+                            &mut persist_client,
+                            storage_collections.as_ref(),
+                            read_ts,
+                            &writes,
+                        )
+                        .await;
+                    writes = reject_writes(writes, violations);
+                }
+                let validated = ValidatedWrites {
+                    span: Span::current(),
+                    writes,
+                    write_locks,
+                };
+                // It is not an error for this task to be running after `internal_cmd_rx` is dropped.
+                if let Err(e) = internal_cmd_tx.send(Message::GroupCommitValidated(validated)) {
+                    warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                }
+            }
+            .instrument(span),
+        );
+    }
+
+    /// Commits the user writes of a group commit that were validated in a
+    /// task, see [`Coordinator::group_commit`].
+    pub(crate) async fn message_group_commit_validated(
+        &mut self,
+        ValidatedWrites {
+            span,
+            writes,
+            write_locks,
+        }: ValidatedWrites,
+    ) {
+        if writes.is_empty() {
+            return;
+        }
+        self.commit_writes(writes, write_locks, None)
+            .instrument(span)
+            .await;
+    }
+
+    /// Commits `validated_writes` at the same timestamp, while holding
+    /// `group_write_locks`, which must cover all the tables they write to.
+    ///
+    /// Returns the timestamp of the write.
+    async fn commit_writes(
+        &mut self,
+        validated_writes: Vec<PendingWriteTxn>,
+        group_write_locks: GroupCommitWriteLocks,
+        permit: Option<GroupCommitPermit>,
+    ) -> Timestamp {
         // The value returned here still might be ahead of `now()` if `now()` has gone backwards at
         // any point during this method or if this was triggered from DDL. We will still commit the
         // write without waiting for `now()` to advance. This is ok because the next batch of writes
@@ -466,6 +676,7 @@ impl Coordinator {
                 PendingWriteTxn::User {
                     span: _,
                     writes,
+                    read_set: _,
                     write_locks,
                     pending_txn:
                         PendingTxn {
@@ -947,6 +1158,7 @@ pub(crate) fn waiting_on_startup_appends(
         Plan::ReadThenWrite(plan) => plan.selection.depends_on(),
         Plan::ShowColumns(plan) => plan.select_plan.source.depends_on(),
         Plan::Subscribe(plan) => plan.from.depends_on(),
+        Plan::CreateChannel(plan) => plan.subscribe.from.depends_on(),
         Plan::ExplainPlan(ExplainPlanPlan {
             explainee: Explainee::Statement(ExplaineeStatement::Select { plan, .. }),
             ..
@@ -969,6 +1181,8 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CreateMaterializedView(_)
         | Plan::CreateIndex(_)
         | Plan::CreateType(_)
+        | Plan::CreateFunction(_)
+        | Plan::CreateSequence(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
@@ -989,6 +1203,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CopyTo(_)
         | Plan::ExplainPlan(_)
         | Plan::ExplainPushdown(_)
+        | Plan::ExplainRecommendations(_)
         | Plan::ExplainSinkSchema(_)
         | Plan::Insert(_)
         | Plan::AlterNetworkPolicy(_)
@@ -1002,6 +1217,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterSetCluster(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterRetainHistory(_)
+        | Plan::AlterStorageCompression(_)
         | Plan::AlterSourceTimestampInterval(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
@@ -1013,6 +1229,8 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTableAddConstraint(_)
+        | Plan::AlterTableDropConstraint(_)
         | Plan::AlterMaterializedViewApplyReplacement(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
@@ -1021,6 +1239,10 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::Execute(_)
         | Plan::Deallocate(_)
         | Plan::Raise(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
+        | Plan::Notify(_)
+        | Plan::DropChannel(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
         | Plan::GrantPrivileges(_)
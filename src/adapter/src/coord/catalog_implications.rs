@@ -35,7 +35,7 @@ use itertools::Itertools;
 use mz_adapter_types::compaction::CompactionWindow;
 use mz_catalog::memory::objects::{
     CatalogItem, Cluster, ClusterReplica, Connection, ContinualTask, DataSourceDesc, Index,
-    MaterializedView, Secret, Sink, Source, StateDiff, Table, TableDataSource, View,
+    MaterializedView, Secret, Sequence, Sink, Source, StateDiff, Table, TableDataSource, View,
 };
 use mz_cloud_resources::VpcEndpointConfig;
 use mz_compute_client::logging::LogVariant;
@@ -211,6 +211,7 @@ impl Coordinator {
         let mut table_collections_to_create = BTreeMap::new();
         let mut source_collections_to_create = BTreeMap::new();
         let mut storage_policies_to_initialize = BTreeMap::new();
+        let mut storage_compressions_to_set = BTreeMap::new();
         let mut execution_timestamps_to_set = BTreeSet::new();
         let mut vpc_endpoints_to_create: Vec<(CatalogItemId, VpcEndpointConfig)> = vec![];
 
@@ -240,6 +241,10 @@ impl Coordinator {
 
             match implication {
                 CatalogImplication::Table(CatalogImplicationKind::Added(table)) => {
+                    if let Some(compression) = table.storage_compression {
+                        storage_compressions_to_set
+                            .insert(table.global_id_writes(), Some(compression));
+                    }
                     self.handle_create_table(
                         &ctx,
                         &mut table_collections_to_create,
@@ -398,10 +403,11 @@ impl Coordinator {
                     prev: prev_mv,
                     new: new_mv,
                 }) => {
-                    // We get here for three reasons:
+                    // We get here for four reasons:
                     //  1. Name changes, like those caused by ALTER SCHEMA.
                     //  2. Replacement application.
                     //  3. Compaction window changes (ALTER ... SET (RETAIN HISTORY ...)).
+                    //  4. Compression policy changes (ALTER ... SET (STORAGE COMPRESSION ...)).
                     //
                     // 1. Name changes: We don't have to do anything here.
                     //
@@ -416,6 +422,15 @@ impl Coordinator {
                     // is also a replacement application, then the replacement's storage collections
                     // already have the correct read policies from when they were created, so we
                     // don't need to update them here.
+                    //
+                    // 4. Compression policy changes: Setting the policy is idempotent, so we don't
+                    // need to tell these apart from the other cases.
+                    if prev_mv.storage_compression != new_mv.storage_compression {
+                        self.set_storage_compression_in_background(
+                            new_mv.global_id_writes(),
+                            new_mv.storage_compression,
+                        );
+                    }
                     if prev_mv.collections != new_mv.collections {
                         // Sanity check: The replacement's last (and only) version must be the same
                         // as the new target's last version.
@@ -504,6 +519,20 @@ impl Coordinator {
                 )) => {
                     secrets_to_drop.push(catalog_id);
                 }
+                CatalogImplication::Sequence(CatalogImplicationKind::Added(_sequence))
+                | CatalogImplication::Sequence(CatalogImplicationKind::Altered {
+                    prev: _prev_sequence,
+                    new: _new_sequence,
+                }) => {
+                    // No action needed: the durable state of a sequence is
+                    // written in the same catalog transaction as the sequence.
+                }
+                CatalogImplication::Sequence(CatalogImplicationKind::Dropped(
+                    _sequence,
+                    _full_name,
+                )) => {
+                    self.sequence_caches.remove(&catalog_id);
+                }
                 CatalogImplication::Connection(CatalogImplicationKind::Added(connection)) => {
                     match &connection.details {
                         // SSH connections: key pair is stored in secrets_controller
@@ -565,6 +594,7 @@ impl Coordinator {
                 | CatalogImplication::View(CatalogImplicationKind::None)
                 | CatalogImplication::ContinualTask(CatalogImplicationKind::None)
                 | CatalogImplication::Secret(CatalogImplicationKind::None)
+                | CatalogImplication::Sequence(CatalogImplicationKind::None)
                 | CatalogImplication::Connection(CatalogImplicationKind::None) => {
                     unreachable!("will never leave None in place");
                 }
@@ -703,6 +733,9 @@ impl Coordinator {
             self.create_table_collections(table_collections_to_create, execution_timestamps_to_set)
                 .await?;
         }
+        for (global_id, compression) in storage_compressions_to_set {
+            self.set_storage_compression_in_background(global_id, compression);
+        }
         // It is _very_ important that we only initialize read policies after we
         // have created all the sources/collections. Some of the sources created
         // in this collection might have dependencies on other sources, so the
@@ -1151,7 +1184,7 @@ impl Coordinator {
         // by environmentd (e.g. with INSERT INTO statements) or by the storage layer
         // (e.g. a source-fed table).
         match &table.data_source {
-            TableDataSource::TableWrites { defaults: _ } => {
+            TableDataSource::TableWrites { .. } => {
                 let versions: BTreeMap<_, _> = table
                     .collection_descs()
                     .map(|(gid, version, desc)| (version, (gid, desc)))
@@ -1292,6 +1325,10 @@ impl Coordinator {
         let existing_gid = prev_table.global_id_writes();
         let new_gid = new_table.global_id_writes();
 
+        if prev_table.storage_compression != new_table.storage_compression {
+            self.set_storage_compression_in_background(new_gid, new_table.storage_compression);
+        }
+
         if existing_gid == new_gid {
             // It's not an ALTER TABLE ADD COLUMN, because we still have the
             // same GlobalId. It might be a compaction window change.
@@ -1570,6 +1607,7 @@ impl Coordinator {
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::Sequence(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::ContinualTask(_) => {
                         // Other item types don't have connection dependencies
@@ -1625,6 +1663,7 @@ enum CatalogImplication {
     View(CatalogImplicationKind<View>),
     ContinualTask(CatalogImplicationKind<ContinualTask>),
     Secret(CatalogImplicationKind<Secret>),
+    Sequence(CatalogImplicationKind<Sequence>),
     Connection(CatalogImplicationKind<Connection>),
     Cluster(CatalogImplicationKind<Cluster>),
     ClusterReplica(CatalogImplicationKind<ClusterReplica>),
@@ -1774,6 +1813,9 @@ impl CatalogImplication {
                 CatalogItem::Secret(secret) => {
                     self.absorb_secret(secret, None, catalog_update.diff);
                 }
+                CatalogItem::Sequence(sequence) => {
+                    self.absorb_sequence(sequence, None, catalog_update.diff);
+                }
                 CatalogItem::Connection(connection) => {
                     self.absorb_connection(connection, None, catalog_update.diff);
                 }
@@ -1815,6 +1857,9 @@ impl CatalogImplication {
                 CatalogItem::Secret(secret) => {
                     self.absorb_secret(secret, None, catalog_update.diff);
                 }
+                CatalogItem::Sequence(sequence) => {
+                    self.absorb_sequence(sequence, None, catalog_update.diff);
+                }
                 CatalogItem::Connection(connection) => {
                     self.absorb_connection(connection, None, catalog_update.diff);
                 }
@@ -1862,6 +1907,7 @@ impl CatalogImplication {
 
     impl_absorb_method!(absorb_continual_task, ContinualTask, ContinualTask);
     impl_absorb_method!(absorb_secret, Secret, Secret);
+    impl_absorb_method!(absorb_sequence, Sequence, Sequence);
     impl_absorb_method!(absorb_connection, Connection, Connection);
 
     impl_absorb_method!(absorb_cluster, Cluster, Cluster);
@@ -1888,7 +1934,11 @@ mod tests {
             resolved_ids: ResolvedIds::empty(),
             custom_logical_compaction_window: None,
             is_retained_metrics_object: false,
-            data_source: TableDataSource::TableWrites { defaults: vec![] },
+            data_source: TableDataSource::TableWrites {
+                defaults: vec![],
+                checks: vec![],
+                uniques: vec![],
+            },
         }
     }
 
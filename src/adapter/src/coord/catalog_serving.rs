@@ -23,8 +23,8 @@ use mz_repr::GlobalId;
 use mz_repr::namespaces::is_system_schema;
 use mz_sql::catalog::SessionCatalog;
 use mz_sql::plan::{
-    ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, Plan, SubscribeFrom,
-    SubscribePlan,
+    CreateChannelPlan, ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, Plan,
+    SubscribeFrom, SubscribePlan,
 };
 use smallvec::SmallVec;
 
@@ -62,6 +62,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
             plan.select_plan.source.could_run_expensive_function(),
         ),
         Plan::Subscribe(plan) => inspect_subscribe(plan),
+        Plan::CreateChannel(plan) => inspect_subscribe(&plan.subscribe),
         Plan::ExplainPlan(ExplainPlanPlan {
             explainee: Explainee::Statement(ExplaineeStatement::Select { plan, .. }),
             ..
@@ -94,6 +95,8 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::CreateMaterializedView(_)
         | Plan::CreateIndex(_)
         | Plan::CreateType(_)
+        | Plan::CreateFunction(_)
+        | Plan::CreateSequence(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
@@ -125,6 +128,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         })
         | Plan::ExplainPlan(ExplainPlanPlan { explainee: _, .. })
         | Plan::ExplainPushdown(_)
+        | Plan::ExplainRecommendations(_)
         | Plan::ExplainSinkSchema(_)
         | Plan::Insert(_)
         | Plan::AlterNetworkPolicy(_)
@@ -138,6 +142,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterSetCluster(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterRetainHistory(_)
+        | Plan::AlterStorageCompression(_)
         | Plan::AlterSourceTimestampInterval(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
@@ -149,6 +154,8 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTableAddConstraint(_)
+        | Plan::AlterTableDropConstraint(_)
         | Plan::AlterMaterializedViewApplyReplacement(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
@@ -158,6 +165,10 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::Execute(_)
         | Plan::Deallocate(_)
         | Plan::Raise(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
+        | Plan::Notify(_)
+        | Plan::DropChannel(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
         | Plan::GrantPrivileges(_)
@@ -236,7 +247,10 @@ pub fn check_cluster_restrictions(
     // 'mz_catalog_server' cluster to be "read-only", which restricts these actions.
     let depends_on: Box<dyn Iterator<Item = GlobalId>> = match plan {
         Plan::ReadThenWrite(plan) => Box::new(plan.selection.depends_on().into_iter()),
-        Plan::Subscribe(plan) => match plan.from {
+        Plan::Subscribe(plan)
+        | Plan::CreateChannel(CreateChannelPlan {
+            subscribe: plan, ..
+        }) => match plan.from {
             SubscribeFrom::Id(id) => Box::new(std::iter::once(id)),
             SubscribeFrom::Query { ref expr, .. } => Box::new(expr.depends_on().into_iter()),
         },
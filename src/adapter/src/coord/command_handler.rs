@@ -78,7 +78,7 @@ use crate::coord::{
 };
 use crate::error::{AdapterError, AuthenticationError};
 use crate::notice::AdapterNotice;
-use crate::session::{Session, TransactionOps, TransactionStatus};
+use crate::session::{Notification, Session, TransactionOps, TransactionStatus};
 use crate::statement_logging::WatchSetCreation;
 use crate::util::{ClientTransmitter, ResultExt};
 use crate::webhook::{
@@ -128,6 +128,7 @@ impl Coordinator {
                     client_ip,
                     application_name,
                     notice_tx,
+                    notification_tx,
                 } => {
                     // Note: We purposefully do not use a ClientTransmitter here because startup
                     // handles errors and cleanup of sessions itself.
@@ -140,6 +141,7 @@ impl Coordinator {
                         client_ip,
                         application_name,
                         notice_tx,
+                        notification_tx,
                     )
                     .await;
                 }
@@ -474,7 +476,7 @@ impl Coordinator {
                     task::spawn(|| "copy_to_preflight", async move {
                         let result = mz_storage_types::sinks::s3_oneshot_sink::preflight(
                             connection_context,
-                            &s3_sink_connection.aws_connection,
+                            &s3_sink_connection.connection,
                             &s3_sink_connection.upload_info,
                             s3_sink_connection.connection_id,
                             sink_id,
@@ -768,6 +770,7 @@ impl Coordinator {
         client_ip: Option<IpAddr>,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        notification_tx: mpsc::UnboundedSender<Notification>,
     ) {
         // Early return if successful, otherwise cleanup any possible state.
         match self.handle_startup_inner(&user, &conn_id, &client_ip).await {
@@ -780,6 +783,10 @@ impl Coordinator {
                 let conn = ConnMeta {
                     secret_key,
                     notice_tx,
+                    notification_tx,
+                    listen_channels: BTreeSet::new(),
+                    view_channels: BTreeMap::new(),
+                    sequence_values: BTreeMap::new(),
                     drop_sinks: BTreeSet::new(),
                     pending_cluster_alters: BTreeSet::new(),
                     connected_at: self.now(),
@@ -1214,6 +1221,7 @@ impl Coordinator {
                     | Statement::Execute(_)
                     | Statement::ExplainPlan(_)
                     | Statement::ExplainPushdown(_)
+                    | Statement::ExplainRecommendations(_)
                     | Statement::ExplainAnalyzeObject(_)
                     | Statement::ExplainAnalyzeCluster(_)
                     | Statement::ExplainTimestamp(_)
@@ -1228,12 +1236,18 @@ impl Coordinator {
                     | Statement::ResetVariable(_)
                     | Statement::StartTransaction(_)
                     | Statement::Subscribe(_)
-                    | Statement::Raise(_) => {
+                    | Statement::Raise(_)
+                    | Statement::Listen(_)
+                    | Statement::Unlisten(_)
+                    | Statement::Notify(_) => {
                         // Always safe.
                     }
 
                     Statement::Insert(InsertStatement {
-                        source, returning, ..
+                        source,
+                        on_conflict: None,
+                        returning,
+                        ..
                     }) if returning.is_empty() && ConstantVisitor::insert_source(source) => {
                         // Inserting from constant values statements that do not need to execute on
                         // any cluster (no RETURNING) is always safe.
@@ -1270,6 +1284,7 @@ impl Coordinator {
                     | Statement::AlterSetCluster(_)
                     | Statement::AlterOwner(_)
                     | Statement::AlterRetainHistory(_)
+                    | Statement::AlterStorageCompression(_)
                     | Statement::AlterRole(_)
                     | Statement::AlterSecret(_)
                     | Statement::AlterSink(_)
@@ -1278,17 +1293,22 @@ impl Coordinator {
                     | Statement::AlterSystemResetAll(_)
                     | Statement::AlterSystemSet(_)
                     | Statement::AlterTableAddColumn(_)
+                    | Statement::AlterTableAddConstraint(_)
+                    | Statement::AlterTableDropConstraint(_)
                     | Statement::AlterNetworkPolicy(_)
+                    | Statement::CreateChannel(_)
                     | Statement::CreateCluster(_)
                     | Statement::CreateClusterReplica(_)
                     | Statement::CreateConnection(_)
                     | Statement::CreateDatabase(_)
+                    | Statement::CreateFunction(_)
                     | Statement::CreateIndex(_)
                     | Statement::CreateMaterializedView(_)
                     | Statement::CreateContinualTask(_)
                     | Statement::CreateRole(_)
                     | Statement::CreateSchema(_)
                     | Statement::CreateSecret(_)
+                    | Statement::CreateSequence(_)
                     | Statement::CreateSink(_)
                     | Statement::CreateSubsource(_)
                     | Statement::CreateTable(_)
@@ -1297,6 +1317,7 @@ impl Coordinator {
                     | Statement::CreateWebhookSource(_)
                     | Statement::CreateNetworkPolicy(_)
                     | Statement::Delete(_)
+                    | Statement::DropChannel(_)
                     | Statement::DropObjects(_)
                     | Statement::DropOwned(_)
                     | Statement::GrantPrivileges(_)
@@ -1306,6 +1327,7 @@ impl Coordinator {
                     | Statement::RevokePrivileges(_)
                     | Statement::RevokeRole(_)
                     | Statement::Update(_)
+                    | Statement::Merge(_)
                     | Statement::ValidateConnection(_)
                     | Statement::Comment(_) => {
                         let txn_status = ctx.session_mut().transaction_mut();
@@ -1882,6 +1904,7 @@ impl Coordinator {
         self.clear_connection(&conn_id).await;
 
         self.drop_temp_items(&conn_id).await;
+        self.drop_view_channels(&conn_id).await;
         // Only call catalog_mut() if a temporary schema actually exists for this connection.
         // This avoids an expensive Arc::make_mut clone for the common case where the connection
         // never created any temporary objects.
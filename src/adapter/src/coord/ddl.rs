@@ -29,6 +29,7 @@ use mz_ore::instrument;
 use mz_ore::now::to_datetime;
 use mz_ore::retry::Retry;
 use mz_ore::task;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_repr::adt::numeric::Numeric;
 use mz_repr::{CatalogItemId, GlobalId, Timestamp};
 use mz_sql::catalog::{CatalogClusterReplica, CatalogSchema};
@@ -863,6 +864,40 @@ impl Coordinator {
         );
     }
 
+    /// Applies the compression policy of a table or materialized view to its
+    /// data shard.
+    ///
+    /// Updating persist state can be slow, so it happens in a separate task
+    /// rather than on the coordinator loop. The policy is already recorded in
+    /// the catalog, so a failure is only logged; bootstrap re-applies the
+    /// catalog's policy on the next restart.
+    pub(crate) fn set_storage_compression_in_background(
+        &self,
+        id: GlobalId,
+        compression: Option<CompressionFormat>,
+    ) {
+        let future = match self
+            .controller
+            .storage
+            .set_collection_compression(id, compression)
+        {
+            Ok(future) => future,
+            Err(e) => {
+                warn!(%id, "unable to set storage compression: {e}");
+                return;
+            }
+        };
+        task::spawn(
+            || "set_storage_compression",
+            async move {
+                if let Err(e) = future.await {
+                    warn!(%id, "unable to set storage compression: {e}");
+                }
+            }
+            .instrument(info_span!("coord::set_storage_compression")),
+        );
+    }
+
     /// Removes all temporary items created by the specified connection, though
     /// not the temporary schema itself.
     pub(crate) async fn drop_temp_items(&mut self, conn_id: &ConnectionId) {
@@ -1132,7 +1167,8 @@ impl Coordinator {
                             ConnectionDetails::Csr(_)
                             | ConnectionDetails::Ssh { .. }
                             | ConnectionDetails::Aws(_)
-                            | ConnectionDetails::IcebergCatalog(_) => {}
+                            | ConnectionDetails::IcebergCatalog(_)
+                            | ConnectionDetails::Gcs(_) => {}
                         },
                         CatalogItem::Table(_) => {
                             new_tables += 1;
@@ -1154,7 +1190,8 @@ impl Coordinator {
                         | CatalogItem::View(_)
                         | CatalogItem::Index(_)
                         | CatalogItem::Type(_)
-                        | CatalogItem::Func(_) => {}
+                        | CatalogItem::Func(_)
+                        | CatalogItem::Sequence(_) => {}
                     }
                 }
                 Op::DropObjects(drop_object_infos) => {
@@ -1236,7 +1273,8 @@ impl Coordinator {
                                     | CatalogItem::View(_)
                                     | CatalogItem::Index(_)
                                     | CatalogItem::Type(_)
-                                    | CatalogItem::Func(_) => {}
+                                    | CatalogItem::Func(_)
+                                    | CatalogItem::Sequence(_) => {}
                                 }
                             }
                         }
@@ -1261,6 +1299,7 @@ impl Coordinator {
                     | CatalogItem::Table(_)
                     | CatalogItem::Sink(_)
                     | CatalogItem::MaterializedView(_)
+                    | CatalogItem::Sequence(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::Log(_)
                     | CatalogItem::View(_)
@@ -1272,6 +1311,7 @@ impl Coordinator {
                 Op::AlterRole { .. }
                 | Op::AlterRetainHistory { .. }
                 | Op::AlterSourceTimestampInterval { .. }
+                | Op::AlterStorageCompression { .. }
                 | Op::AlterNetworkPolicy { .. }
                 | Op::AlterAddColumn { .. }
                 | Op::AlterMaterializedViewApplyReplacement { .. }
@@ -1315,7 +1355,8 @@ impl Coordinator {
                 ConnectionDetails::Csr(_)
                 | ConnectionDetails::Ssh { .. }
                 | ConnectionDetails::Aws(_)
-                | ConnectionDetails::IcebergCatalog(_) => {}
+                | ConnectionDetails::IcebergCatalog(_)
+                | ConnectionDetails::Gcs(_) => {}
             }
         }
         self.validate_resource_limit(
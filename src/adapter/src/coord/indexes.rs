@@ -75,6 +75,7 @@ impl DataflowBuilder<'_> {
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::Sequence(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::Connection(_) => {
                         // Non-indexable thing; no work to do.
@@ -105,4 +106,12 @@ impl IndexOracle for DataflowBuilder<'_> {
                 .map(|(idx_id, idx)| (idx_id, idx.keys.as_ref())),
         )
     }
+
+    fn constraints_on(&self, id: GlobalId) -> Vec<MirScalarExpr> {
+        self.catalog
+            .get_table_checks(&id)
+            .iter()
+            .map(|check| check.expr.clone())
+            .collect()
+    }
 }
@@ -89,6 +89,14 @@ impl Coordinator {
                     .boxed_local()
                     .await
             }
+            Message::ExplainRecommendationsReady(ready) => {
+                self.message_explain_recommendations_ready(ready);
+            }
+            Message::AddConstraintValidationReady(ready) => {
+                self.message_add_constraint_validation_ready(ready)
+                    .boxed_local()
+                    .await
+            }
             Message::TryDeferred {
                 conn_id,
                 acquired_lock,
@@ -101,6 +109,11 @@ impl Coordinator {
                     .boxed_local()
                     .await
             }
+            Message::GroupCommitValidated(validated) => {
+                self.message_group_commit_validated(validated)
+                    .boxed_local()
+                    .await
+            }
             Message::AdvanceTimelines => {
                 self.advance_timelines().boxed_local().await;
             }
@@ -108,6 +121,11 @@ impl Coordinator {
             Message::CancelPendingPeeks { conn_id } => {
                 self.cancel_pending_peeks(&conn_id);
             }
+            Message::AllocateSequenceValues { id, count, tx } => {
+                let result = self.allocate_sequence_values(id, count).await;
+                // It is not an error if the requester has gone away.
+                let _ = tx.send(result);
+            }
             Message::LinearizeReads => {
                 self.message_linearize_reads().boxed_local().await;
             }
@@ -130,6 +148,9 @@ impl Coordinator {
             Message::StorageUsagePrune(expired) => {
                 self.storage_usage_prune(expired).boxed_local().await;
             }
+            Message::DeliverNotifications(notifications) => {
+                self.deliver_notifications(notifications);
+            }
             Message::RetireExecute {
                 otel_ctx,
                 data,
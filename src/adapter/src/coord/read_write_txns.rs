@@ -0,0 +1,242 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Validation of read-write transactions.
+//!
+//! An explicit transaction can both read from tables and write to them, in any
+//! order, but its reads and writes happen at different timestamps. Such a
+//! transaction is optimistic: it records the tables it read and when, and its
+//! writes are only committed if none of those tables were written since.
+//! Otherwise it is rolled back with a serialization failure, which clients can
+//! retry. Reads after writes see the transaction's pending writes, see
+//! [`read_own_writes`].
+//!
+//! The check happens in group commit, while holding the write locks of the
+//! tables read, so no write to them can sneak in between the check and ours.
+//! Finding out whether a table was written since it was read can take a
+//! while, so the check runs off the coordinator's main loop. Tables a
+//! read-write transaction reads or writes are never shared with other
+//! transactions in the same group commit, see [`Coordinator::group_commit`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use mz_expr::Id;
+use mz_expr::visit::Visit;
+use mz_repr::{CatalogItemId, Diff, GlobalId, Row, Timestamp};
+use mz_sql::plan::HirRelationExpr;
+use mz_storage_client::client::TableData;
+use mz_storage_client::storage_collections::StorageCollections;
+use tracing::warn;
+
+use crate::AdapterError;
+use crate::catalog::Catalog;
+use crate::coord::Coordinator;
+use crate::coord::appends::PendingWriteTxn;
+use crate::session::{Session, TransactionStatus, WriteOp};
+
+/// The tables a read-write transaction read, and the timestamp it read them
+/// at.
+#[derive(Debug, Clone)]
+pub(crate) struct TxnReadSet {
+    pub read_ts: Timestamp,
+    pub ids: BTreeSet<CatalogItemId>,
+}
+
+/// Returns the user tables that a read of `source_ids` depends on, if the read
+/// is part of an explicit transaction that may go on to write.
+pub(crate) fn txn_read_set(
+    catalog: &Catalog,
+    session: &Session,
+    source_ids: &BTreeSet<GlobalId>,
+) -> Option<BTreeSet<CatalogItemId>> {
+    if !catalog.system_config().enable_read_write_transactions()
+        || !matches!(session.transaction(), TransactionStatus::InTransaction(_))
+    {
+        return None;
+    }
+    let tables = source_ids
+        .iter()
+        .flat_map(|gid| {
+            catalog
+                .state()
+                .transitive_uses(catalog.resolve_item_id(gid))
+        })
+        .filter(|id| id.is_user() && catalog.get_entry(id).is_table())
+        .collect();
+    Some(tables)
+}
+
+/// Rewrites `expr`, a read in a transaction that has the pending `writes`, so
+/// that it sees them, by adding them to the tables it reads.
+///
+/// Reads of written tables through other objects, e.g. views, and of tables
+/// written by `COPY FROM`, are rejected, as their pending writes cannot be
+/// added to the read.
+pub(crate) fn read_own_writes(
+    catalog: &Catalog,
+    writes: &[WriteOp],
+    expr: &mut HirRelationExpr,
+) -> Result<(), AdapterError> {
+    if writes.is_empty() {
+        return Ok(());
+    }
+    // The pending updates of each written table, or `None` if some of them
+    // are staged in batches.
+    let mut updates: BTreeMap<CatalogItemId, Option<Vec<(Row, Diff)>>> = BTreeMap::new();
+    for WriteOp { id, rows } in writes {
+        let table = updates.entry(*id).or_insert_with(|| Some(Vec::new()));
+        match (table, rows) {
+            (Some(table), TableData::Rows(rows)) => table.extend(rows.iter().cloned()),
+            (table, _) => *table = None,
+        }
+    }
+
+    expr.try_visit_mut_post(&mut |expr| {
+        let HirRelationExpr::Get {
+            id: Id::Global(gid),
+            typ,
+        } = expr
+        else {
+            return Ok(());
+        };
+        let id = catalog.resolve_item_id(gid);
+        match updates.get(&id) {
+            Some(Some(table_updates)) => {
+                // Earlier versions of the table have a prefix of its columns.
+                let arity = typ.arity();
+                let mut inserts = Vec::new();
+                let mut deletes = Vec::new();
+                for (row, diff) in table_updates {
+                    let row = Row::pack(row.iter().take(arity));
+                    let copies = usize::try_from(diff.into_inner().unsigned_abs())
+                        .expect("diff fits in usize");
+                    let rows = if diff.is_positive() {
+                        &mut inserts
+                    } else {
+                        &mut deletes
+                    };
+                    rows.extend(std::iter::repeat_n(row, copies));
+                }
+                let get = HirRelationExpr::Get {
+                    id: Id::Global(*gid),
+                    typ: typ.clone(),
+                };
+                let inserts = HirRelationExpr::Constant {
+                    rows: inserts,
+                    typ: typ.clone(),
+                };
+                let deletes = HirRelationExpr::Constant {
+                    rows: deletes,
+                    typ: typ.clone(),
+                };
+                *expr = get.union(inserts).union(deletes.negate());
+                Ok(())
+            }
+            Some(None) => Err(AdapterError::Unsupported(
+                "reads of tables written by COPY FROM in the same transaction",
+            )),
+            None if catalog
+                .state()
+                .transitive_uses(id)
+                .any(|id| updates.contains_key(&id)) =>
+            {
+                Err(AdapterError::Unsupported(
+                    "reads through views of tables written in the same transaction",
+                ))
+            }
+            None => Ok(()),
+        }
+    })
+}
+
+/// The reads of the read-write transactions in a group commit, to validate
+/// off the coordinator's main loop.
+#[derive(Debug)]
+pub(crate) struct ReadSetValidation {
+    /// The tables read, and the timestamp they were read at.
+    reads: BTreeMap<(CatalogItemId, Timestamp), GlobalId>,
+    /// The names of the tables read, for reporting conflicts.
+    names: BTreeMap<CatalogItemId, String>,
+}
+
+impl Coordinator {
+    /// Returns what needs validating about the reads of the read-write
+    /// transactions in `writes`, if any.
+    pub(crate) fn read_set_validation(
+        &self,
+        writes: &[PendingWriteTxn],
+    ) -> Option<ReadSetValidation> {
+        let read_sets = writes.iter().filter_map(|write| match write {
+            PendingWriteTxn::User {
+                read_set: Some(read_set),
+                ..
+            } => Some(read_set),
+            _ => None,
+        });
+        let mut reads = BTreeMap::new();
+        let mut names = BTreeMap::new();
+        for TxnReadSet { read_ts, ids } in read_sets {
+            for id in ids {
+                // If a table was dropped since it was read, we order the read
+                // before the drop.
+                if let Some(entry) = self.catalog().try_get_entry(id) {
+                    reads.insert((*id, *read_ts), entry.latest_global_id());
+                    let name = self.catalog().resolve_full_name(entry.name(), None);
+                    names.insert(*id, name.to_string());
+                }
+            }
+        }
+        (!reads.is_empty()).then_some(ReadSetValidation { reads, names })
+    }
+}
+
+impl ReadSetValidation {
+    /// Returns the position and error of each read-write transaction in
+    /// `writes` that read a table that has been written since, as of `as_of`.
+    ///
+    /// The caller must hold the write locks of all tables read, so that every
+    /// earlier write to them is covered by `as_of`.
+    pub(crate) async fn validate(
+        self,
+        storage_collections: &(dyn StorageCollections + Send + Sync),
+        as_of: Timestamp,
+        writes: &[PendingWriteTxn],
+    ) -> Vec<(usize, AdapterError)> {
+        let mut written = BTreeMap::new();
+        for ((id, read_ts), gid) in self.reads {
+            let check = storage_collections.table_written_since(gid, read_ts, as_of);
+            let table_written = check.await.unwrap_or_else(|err| {
+                // If we cannot tell, we cannot accept any write that depends
+                // on the table.
+                warn!("unable to validate read of {id} at {read_ts}: {err}");
+                true
+            });
+            written.insert((id, read_ts), table_written);
+        }
+
+        let mut violations = Vec::new();
+        for (i, write) in writes.iter().enumerate() {
+            let PendingWriteTxn::User {
+                read_set: Some(TxnReadSet { read_ts, ids }),
+                ..
+            } = write
+            else {
+                continue;
+            };
+            let conflict = ids
+                .iter()
+                .find(|id| written.get(&(**id, *read_ts)).copied().unwrap_or(false));
+            if let Some(id) = conflict {
+                let table_name = self.names[id].clone();
+                violations.push((i, AdapterError::ReadWriteTransactionConflict { table_name }));
+            }
+        }
+        violations
+    }
+}
@@ -21,6 +21,7 @@ use futures::future::LocalBoxFuture;
 use futures::stream::FuturesOrdered;
 use http::Uri;
 use inner::return_if_err;
+use itertools::Itertools;
 use maplit::btreemap;
 use mz_catalog::memory::objects::Cluster;
 use mz_controller_types::ReplicaId;
@@ -45,7 +46,8 @@ use mz_sql::session::vars::SessionVars;
 use mz_sql_parser::ast::{Raw, Statement};
 use mz_storage_client::client::TableData;
 use mz_storage_client::storage_collections::StorageCollections;
-use mz_storage_types::connections::inline::IntoInlineConnection;
+use mz_storage_types::connections::Connection;
+use mz_storage_types::connections::inline::{IntoInlineConnection, ReferencedConnection};
 use mz_storage_types::controller::StorageError;
 use mz_storage_types::stats::RelationPartStats;
 use mz_transform::dataflow::DataflowMetainfo;
@@ -295,6 +297,18 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::CreateFunction(plan) => {
+                    let result = self
+                        .sequence_create_function(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::CreateSequence(plan) => {
+                    let result = self
+                        .sequence_create_sequence(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::CreateNetworkPolicy(plan) => {
                     let res = self
                         .sequence_create_network_policy(ctx.session(), plan)
@@ -448,6 +462,9 @@ impl Coordinator {
                     self.sequence_explain_pushdown(ctx, plan, target_cluster)
                         .await;
                 }
+                Plan::ExplainRecommendations(plan) => {
+                    self.sequence_explain_recommendations(ctx, plan).await;
+                }
                 Plan::ExplainSinkSchema(plan) => {
                     let result = self.sequence_explain_schema(plan);
                     ctx.retire(result);
@@ -493,6 +510,12 @@ impl Coordinator {
                     let result = self.sequence_alter_retain_history(&mut ctx, plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterStorageCompression(plan) => {
+                    let result = self
+                        .sequence_alter_storage_compression(&mut ctx, plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterSourceTimestampInterval(plan) => {
                     let result = self
                         .sequence_alter_source_timestamp_interval(&mut ctx, plan)
@@ -543,6 +566,15 @@ impl Coordinator {
                     let result = self.sequence_alter_table(&mut ctx, plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterTableAddConstraint(plan) => {
+                    self.sequence_alter_table_add_constraint(ctx, plan).await;
+                }
+                Plan::AlterTableDropConstraint(plan) => {
+                    let result = self
+                        .sequence_alter_table_drop_constraint(&mut ctx, plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterMaterializedViewApplyReplacement(plan) => {
                     self.sequence_alter_materialized_view_apply_replacement_prepare(ctx, plan)
                         .await;
@@ -561,6 +593,11 @@ impl Coordinator {
                     let ret = if let TransactionStatus::Started(_) = ctx.session().transaction() {
                         self.clear_transaction(ctx.session_mut()).await;
                         self.drop_temp_items(ctx.session().conn_id()).await;
+                        self.drop_view_channels(ctx.session().conn_id()).await;
+                        if let Some(conn) = self.active_conns.get_mut(ctx.session().conn_id()) {
+                            conn.listen_channels.clear();
+                            conn.sequence_values.clear();
+                        }
                         ctx.session_mut().reset();
                         Ok(ExecuteResponse::DiscardedAll)
                     } else {
@@ -653,6 +690,26 @@ impl Coordinator {
                         .add_notice(AdapterNotice::UserRequested { severity });
                     ctx.retire(Ok(ExecuteResponse::Raised));
                 }
+                Plan::Listen(plan) => {
+                    let result = self.sequence_listen(ctx.session(), plan);
+                    ctx.retire(result);
+                }
+                Plan::Unlisten(plan) => {
+                    let result = self.sequence_unlisten(ctx.session(), plan);
+                    ctx.retire(result);
+                }
+                Plan::Notify(plan) => {
+                    let result = self.sequence_notify(ctx.session_mut(), plan);
+                    ctx.retire(result);
+                }
+                Plan::CreateChannel(plan) => {
+                    self.sequence_create_channel(ctx, plan, target_cluster)
+                        .await;
+                }
+                Plan::DropChannel(plan) => {
+                    let result = self.sequence_drop_channel(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
                 Plan::GrantPrivileges(plan) => {
                     let result = self
                         .sequence_grant_privileges(ctx.session_mut(), plan)
@@ -720,6 +777,11 @@ impl Coordinator {
         let (tx, internal_cmd_tx, mut session, extra) = ctx.into_parts();
         assert!(matches!(session.transaction(), TransactionStatus::Default));
         session.start_transaction_single_stmt(self.now_datetime());
+        // Notifications sent earlier in the transaction are delivered when the
+        // statement commits.
+        for notification in session.take_committing_notifications() {
+            session.add_notification(notification);
+        }
         let conn_id = session.conn_id().unhandled();
 
         // Execute the saved statement in a temp transmitter so we can run COMMIT.
@@ -848,6 +910,7 @@ impl Coordinator {
                     kind: MutationKind::Insert,
                     returning: Vec::new(),
                     max_result_size: catalog.system_config().max_result_size(),
+                    affected_rows: None,
                 };
                 Self::send_diffs(session, diffs_plan)
             }
@@ -893,7 +956,12 @@ impl Coordinator {
                 }
             }
 
-            usize::try_from(affected_rows.into_inner()).expect("positive Diff must fit")
+            match plan.affected_rows {
+                Some(affected_rows) => affected_rows,
+                None => {
+                    usize::try_from(affected_rows.into_inner()).expect("positive Diff must fit")
+                }
+            }
         };
         event!(
             Level::TRACE,
@@ -932,6 +1000,7 @@ impl Coordinator {
             MutationKind::Delete => ExecuteResponse::Deleted(affected_rows),
             MutationKind::Insert => ExecuteResponse::Inserted(affected_rows),
             MutationKind::Update => ExecuteResponse::Updated(affected_rows / 2),
+            MutationKind::Merge => ExecuteResponse::Merged(affected_rows),
         })
     }
 }
@@ -1035,6 +1104,7 @@ pub(crate) fn emit_optimizer_notices(
 /// and the new frontend peek sequencing to avoid code duplication.
 pub fn eval_copy_to_uri(
     to: HirScalarExpr,
+    connection: &Connection<ReferencedConnection>,
     session: &Session,
     catalog_state: &CatalogState,
 ) -> Result<Uri, AdapterError> {
@@ -1055,6 +1125,13 @@ pub fn eval_copy_to_uri(
             if url.scheme_str() != Some("s3") && url.scheme_str() != Some("gs") {
                 coord_bail!("only 's3://...' and 'gs://...' urls are supported as COPY TO target");
             }
+            // A GCS connection speaks the native GCS API, which has no notion
+            // of S3 urls.
+            if matches!(connection, Connection::Gcs(_)) && url.scheme_str() != Some("gs") {
+                coord_bail!(
+                    "only 'gs://...' urls are supported as COPY TO target with a GCS CONNECTION"
+                );
+            }
             url
         }
         Err(e) => coord_bail!("could not parse COPY TO target url: {}", e),
@@ -1266,9 +1343,74 @@ pub(crate) async fn statistics_oracle(
     }
 }
 
+/// Statistics about the contents of a storage collection, as used by the optimizer.
+#[derive(Debug)]
+struct CollectionStatistics {
+    /// An estimate of the number of rows.
+    row_count: usize,
+    /// Estimates of the number of distinct non-null values of each column, if known.
+    distinct_counts: Vec<Option<usize>>,
+}
+
+impl CollectionStatistics {
+    /// Collects statistics about the contents of collection `id` at `as_of` from the stats that
+    /// persist keeps for the collection and its parts.
+    async fn collect(
+        id: GlobalId,
+        as_of: Antichain<Timestamp>,
+        storage_collections: &dyn StorageCollections,
+    ) -> Result<Self, StorageError> {
+        let row_count = storage_collections
+            .snapshot_stats(id, as_of.clone())
+            .await?
+            .num_updates;
+        let desc = storage_collections.collection_metadata(id)?.relation_desc;
+        let parts_stats = storage_collections
+            .snapshot_parts_stats(id, as_of)
+            .await
+            .await?;
+
+        // Per column, the largest distinct count of any part and the sum of the distinct counts
+        // of all parts, which bound the distinct count of the collection from below and above.
+        let name = id.to_string();
+        let mut max_counts = vec![Some(0); desc.arity()];
+        let mut sum_counts = vec![Some(0); desc.arity()];
+        for part in &parts_stats.parts {
+            let stats = part.stats.as_ref().map(|stats| stats.decode());
+            let stats = stats.as_ref().map(|stats| {
+                RelationPartStats::new(
+                    name.as_str(),
+                    &parts_stats.metrics.pushdown.part_stats,
+                    &desc,
+                    stats,
+                )
+            });
+            for (pos, (idx, _name, _typ)) in desc.iter_all().enumerate() {
+                let count = stats
+                    .as_ref()
+                    .and_then(|stats| stats.col_distinct_count(idx));
+                max_counts[pos] = max_counts[pos].zip(count).map(|(a, b)| a.max(b));
+                sum_counts[pos] = sum_counts[pos].zip(count).map(|(a, b)| a.saturating_add(b));
+            }
+        }
+        // Parts overlap in the values they contain, so we assume that the sum overestimates the
+        // distinct count, but never report fewer values than a single part has.
+        let distinct_counts = max_counts
+            .into_iter()
+            .zip_eq(sum_counts)
+            .map(|(max, sum)| Some(max?.max(sum?.min(row_count))))
+            .collect();
+
+        Ok(Self {
+            row_count,
+            distinct_counts,
+        })
+    }
+}
+
 #[derive(Debug)]
 struct CachedStatisticsOracle {
-    cache: BTreeMap<GlobalId, usize>,
+    cache: BTreeMap<GlobalId, CollectionStatistics>,
 }
 
 impl CachedStatisticsOracle {
@@ -1280,11 +1422,12 @@ impl CachedStatisticsOracle {
         let mut cache = BTreeMap::new();
 
         for id in ids {
-            let stats = storage_collections.snapshot_stats(*id, as_of.clone()).await;
+            let stats =
+                CollectionStatistics::collect(*id, as_of.clone(), storage_collections).await;
 
             match stats {
                 Ok(stats) => {
-                    cache.insert(*id, stats.num_updates);
+                    cache.insert(*id, stats);
                 }
                 Err(StorageError::IdentifierMissing(id)) => {
                     ::tracing::debug!("no statistics for {id}")
@@ -1299,10 +1442,17 @@ impl CachedStatisticsOracle {
 
 impl StatisticsOracle for CachedStatisticsOracle {
     fn cardinality_estimate(&self, id: GlobalId) -> Option<usize> {
-        self.cache.get(&id).map(|estimate| *estimate)
+        self.cache.get(&id).map(|stats| stats.row_count)
     }
 
     fn as_map(&self) -> BTreeMap<GlobalId, usize> {
-        self.cache.clone()
+        self.cache
+            .iter()
+            .map(|(id, stats)| (*id, stats.row_count))
+            .collect()
+    }
+
+    fn distinct_count_estimate(&self, id: GlobalId, column: usize) -> Option<usize> {
+        self.cache.get(&id)?.distinct_counts.get(column).copied()?
     }
 }
@@ -23,10 +23,12 @@ use mz_adapter_types::connection::ConnectionId;
 use mz_adapter_types::dyncfgs::{ENABLE_MULTI_REPLICA_SOURCES, ENABLE_PASSWORD_AUTH};
 use mz_catalog::memory::error::ErrorKind;
 use mz_catalog::memory::objects::{
-    CatalogItem, Connection, DataSourceDesc, Sink, Source, Table, TableDataSource, Type,
+    CatalogItem, Connection, DataSourceDesc, Func, FuncDefinition, Sequence, Sink, Source, Table,
+    TableDataSource, Type,
 };
 use mz_expr::{
-    CollectionPlan, MapFilterProject, OptimizedMirRelationExpr, ResultSpec, RowSetFinishing,
+    CollectionPlan, EvalError, MapFilterProject, OptimizedMirRelationExpr, ResultSpec,
+    RowSetFinishing,
 };
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::{CollectionExt, HashSet};
@@ -40,8 +42,8 @@ use mz_repr::explain::ExprHumanizer;
 use mz_repr::explain::json::json_string;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
-    CatalogItemId, Datum, Diff, GlobalId, RelationVersion, RelationVersionSelector, Row, RowArena,
-    RowIterator, Timestamp,
+    CatalogItemId, Datum, Diff, GlobalId, RelationDesc, RelationVersion, RelationVersionSelector,
+    Row, RowArena, RowIterator, SqlScalarType, Timestamp,
 };
 use mz_sql::ast::{
     AlterSourceAddSubsourceOption, CreateSinkOption, CreateSinkOptionName, CreateSourceOptionName,
@@ -92,27 +94,31 @@ use mz_storage_types::controller::StorageError;
 use mz_transform::dataflow::DataflowMetainfo;
 use smallvec::SmallVec;
 use timely::progress::Antichain;
-use tokio::sync::{oneshot, watch};
+use tokio::sync::{mpsc, oneshot, watch};
 use tracing::{Instrument, Span, info, warn};
 
 use crate::catalog::{self, Catalog, ConnCatalog, DropObjectInfo, UpdatePrivilegeVariant};
 use crate::command::{ExecuteResponse, Response};
 use crate::coord::appends::{BuiltinTableAppendNotify, DeferredOp, DeferredPlan, PendingWriteTxn};
+use crate::coord::read_write_txns::TxnReadSet;
 use crate::coord::sequencer::emit_optimizer_notices;
+use crate::coord::sequencer::inner::merge::MergeState;
+use crate::coord::table_checks::violated_check;
 use crate::coord::{
-    AlterConnectionValidationReady, AlterMaterializedViewReadyContext, AlterSinkReadyContext,
-    Coordinator, CreateConnectionValidationReady, DeferredPlanStatement, ExecuteContext,
-    ExplainContext, Message, NetworkPolicyError, PendingRead, PendingReadTxn, PendingTxn,
-    PendingTxnResponse, PlanValidity, StageResult, Staged, StagedContext, TargetCluster,
-    WatchSetResponse, validate_ip_with_policy_rules,
+    AddConstraintValidationReady, AlterConnectionValidationReady,
+    AlterMaterializedViewReadyContext, AlterSinkReadyContext, Coordinator,
+    CreateConnectionValidationReady, DeferredPlanStatement, ExecuteContext, ExplainContext,
+    Message, NetworkPolicyError, PendingRead, PendingReadTxn, PendingTxn, PendingTxnResponse,
+    PlanValidity, StageResult, Staged, StagedContext, TargetCluster, WatchSetResponse,
+    validate_ip_with_policy_rules,
 };
 use crate::error::AdapterError;
 use crate::notice::{AdapterNotice, DroppedInUseIndex};
 use crate::optimize::dataflows::{EvalTime, ExprPrep, ExprPrepOneShot};
 use crate::optimize::{self, Optimize};
 use crate::session::{
-    EndTransactionAction, RequireLinearization, Session, TransactionOps, TransactionStatus,
-    WriteLocks, WriteOp,
+    EndTransactionAction, Notification, RequireLinearization, Session, TransactionOps,
+    TransactionStatus, WriteLocks, WriteOp,
 };
 use crate::util::{ClientTransmitter, ResultExt, viewable_variables};
 use crate::{PeekResponseUnary, ReadHolds};
@@ -120,13 +126,16 @@ use crate::{PeekResponseUnary, ReadHolds};
 /// A future that resolves to a real-time recency timestamp.
 type RtrTimestampFuture = BoxFuture<'static, Result<Timestamp, StorageError>>;
 
+mod channel;
 mod cluster;
 mod copy_from;
 mod create_continual_task;
 mod create_index;
 mod create_materialized_view;
 mod create_view;
+mod explain_recommendations;
 mod explain_timestamp;
+mod merge;
 mod peek;
 mod secret;
 mod subscribe;
@@ -967,6 +976,7 @@ impl Coordinator {
             name,
             table,
             if_not_exists,
+            sequences,
         } = plan;
 
         let conn_id = if table.temporary {
@@ -978,9 +988,15 @@ impl Coordinator {
         let collections = [(RelationVersion::root(), global_id)].into_iter().collect();
 
         let data_source = match table.data_source {
-            plan::TableDataSource::TableWrites { defaults } => {
-                TableDataSource::TableWrites { defaults }
-            }
+            plan::TableDataSource::TableWrites {
+                defaults,
+                checks,
+                uniques,
+            } => TableDataSource::TableWrites {
+                defaults,
+                checks,
+                uniques,
+            },
             plan::TableDataSource::DataSource {
                 desc: data_source_plan,
                 timeline,
@@ -1037,14 +1053,35 @@ impl Coordinator {
             resolved_ids,
             custom_logical_compaction_window: table.compaction_window,
             is_retained_metrics_object: false,
+            storage_compression: table.storage_compression,
             data_source,
         };
-        let ops = vec![catalog::Op::CreateItem {
+        let mut ops = vec![catalog::Op::CreateItem {
             id: table_id,
             name: name.clone(),
             item: CatalogItem::Table(table.clone()),
             owner_id: *ctx.session().current_role_id(),
         }];
+        // The sequences of the table's identity columns are owned by, and thus
+        // depend on, the table.
+        let sequence_ids = self
+            .allocate_user_ids(u64::cast_from(sequences.len()))
+            .await?;
+        for ((sequence_name, sequence), (sequence_id, sequence_global_id)) in
+            sequences.into_iter().zip_eq(sequence_ids)
+        {
+            ops.push(catalog::Op::CreateItem {
+                id: sequence_id,
+                name: sequence_name,
+                item: CatalogItem::Sequence(Sequence {
+                    create_sql: sequence.create_sql,
+                    global_id: sequence_global_id,
+                    options: sequence.options,
+                    resolved_ids: [(table_id, global_id)].into_iter().collect(),
+                }),
+                owner_id: *ctx.session().current_role_id(),
+            });
+        }
 
         let catalog_result = self
             .catalog_transact_with_ddl_transaction(ctx, ops, |_, _| Box::pin(async {}))
@@ -1217,6 +1254,86 @@ impl Coordinator {
         }
     }
 
+    #[instrument]
+    pub(super) async fn sequence_create_function(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateFunctionPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::CreateFunctionPlan {
+            name,
+            function,
+            if_not_exists,
+        } = plan;
+        let (item_id, global_id) = self.allocate_user_id().await?;
+        let func = Func {
+            create_sql: Some(function.create_sql.clone()),
+            inner: FuncDefinition::Sql(function),
+            global_id,
+            resolved_ids,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: name.clone(),
+            item: CatalogItem::Func(func),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedFunction),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind: ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: name.item,
+                    ty: "function",
+                });
+                Ok(ExecuteResponse::CreatedFunction)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_sequence(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateSequencePlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::CreateSequencePlan {
+            name,
+            sequence,
+            if_not_exists,
+        } = plan;
+        let (item_id, global_id) = self.allocate_user_id().await?;
+        let sequence = Sequence {
+            create_sql: sequence.create_sql,
+            global_id,
+            options: sequence.options,
+            resolved_ids,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: name.clone(),
+            item: CatalogItem::Sequence(sequence),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedSequence),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind: ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: name.item,
+                    ty: "sequence",
+                });
+                Ok(ExecuteResponse::CreatedSequence)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     #[instrument]
     pub(super) async fn sequence_comment_on(
         &mut self,
@@ -2010,6 +2127,73 @@ impl Coordinator {
         })
     }
 
+    pub(super) fn sequence_listen(
+        &mut self,
+        session: &Session,
+        plan: plan::ListenPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // Sessions that are not served over pgwire have no way to deliver
+        // notifications, so they would only pile up.
+        if !session.receives_notifications() {
+            return Err(AdapterError::Unsupported(
+                "LISTEN over HTTP and WebSocket connections",
+            ));
+        }
+        let conn = self
+            .active_conns
+            .get_mut(session.conn_id())
+            .expect("connection must exist");
+        conn.listen_channels.insert(plan.channel);
+        Ok(ExecuteResponse::Listened)
+    }
+
+    pub(super) fn sequence_unlisten(
+        &mut self,
+        session: &Session,
+        plan: plan::UnlistenPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let conn = self
+            .active_conns
+            .get_mut(session.conn_id())
+            .expect("connection must exist");
+        match plan.channel {
+            Some(channel) => {
+                conn.listen_channels.remove(&channel);
+            }
+            None => conn.listen_channels.clear(),
+        }
+        Ok(ExecuteResponse::Unlistened)
+    }
+
+    /// Buffers a notification in the session's transaction. It is delivered
+    /// by [`Coordinator::deliver_notifications`] once the transaction commits.
+    pub(super) fn sequence_notify(
+        &self,
+        session: &mut Session,
+        plan: plan::NotifyPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let notification = Notification {
+            conn_id: session.conn_id().clone(),
+            channel: plan.channel,
+            payload: plan.payload,
+        };
+        session.add_notification(notification);
+        Ok(ExecuteResponse::Notified)
+    }
+
+    /// Delivers the notifications of a committed transaction to every session
+    /// listening on their channels, including the session that sent them.
+    pub(crate) fn deliver_notifications(&self, notifications: Vec<Notification>) {
+        for notification in notifications {
+            for conn in self.active_conns.values() {
+                if conn.listen_channels.contains(&notification.channel) {
+                    // We don't care if the session has gone away.
+                    let _ = conn.notification_tx.send(notification.clone());
+                }
+            }
+        }
+    }
+
     fn validate_set_isolation_level(&self, session: &Session) -> Result<(), AdapterError> {
         if session.transaction().contains_ops() {
             Err(AdapterError::InvalidSetIsolationLevel)
@@ -2077,6 +2261,7 @@ impl Coordinator {
                 self.submit_write(PendingWriteTxn::User {
                     span: Span::current(),
                     writes: collected_writes,
+                    read_set: None,
                     write_locks: validated_locks,
                     pending_txn: PendingTxn {
                         ctx,
@@ -2086,6 +2271,48 @@ impl Coordinator {
                 });
                 return;
             }
+            Ok((Some(TransactionOps::ReadWrites { writes, .. }), _)) if writes.is_empty() => {
+                (response, action)
+            }
+            Ok((
+                Some(TransactionOps::ReadWrites {
+                    determination,
+                    read_set,
+                    writes,
+                    ..
+                }),
+                _,
+            )) => {
+                let read_ts = determination
+                    .timestamp_context
+                    .timestamp()
+                    .copied()
+                    .expect("read-write transactions read at a timestamp");
+                let mut collected_writes: BTreeMap<CatalogItemId, SmallVec<_>> = BTreeMap::new();
+                for WriteOp { id, rows } in writes {
+                    let total_rows = collected_writes.entry(id).or_default();
+                    total_rows.push(rows);
+                }
+
+                self.submit_write(PendingWriteTxn::User {
+                    span: Span::current(),
+                    writes: collected_writes,
+                    read_set: Some(TxnReadSet {
+                        read_ts,
+                        ids: read_set,
+                    }),
+                    // Read-then-writes can't run in transaction blocks, so we
+                    // never hold write locks here. Group commit acquires the
+                    // locks for the tables read and written.
+                    write_locks: None,
+                    pending_txn: PendingTxn {
+                        ctx,
+                        response,
+                        action,
+                    },
+                });
+                return;
+            }
             Ok((
                 Some(TransactionOps::Peeks {
                     determination,
@@ -2162,12 +2389,21 @@ impl Coordinator {
         ctx: &mut ExecuteContext,
         action: EndTransactionAction,
     ) -> Result<(Option<TransactionOps>, Option<WriteLocks>), AdapterError> {
-        let txn = self.clear_transaction(ctx.session_mut()).await;
+        let mut txn = self.clear_transaction(ctx.session_mut()).await;
 
         if let EndTransactionAction::Commit = action {
+            // Hand the notifications to the session, which delivers them once
+            // the commit succeeds. They are dropped if the transaction rolls
+            // back.
+            if let Some(txn) = txn.inner_mut() {
+                let notifications = txn.take_notifications();
+                ctx.session_mut()
+                    .set_committing_notifications(notifications);
+            }
+
             if let (Some(mut ops), write_lock_guards) = txn.into_ops_and_lock_guard() {
                 match &mut ops {
-                    TransactionOps::Writes(writes) => {
+                    TransactionOps::Writes(writes) | TransactionOps::ReadWrites { writes, .. } => {
                         for WriteOp { id, .. } in &mut writes.iter() {
                             // Re-verify this id exists.
                             let _ = self.catalog().try_get_entry(id).ok_or_else(|| {
@@ -2242,9 +2478,100 @@ impl Coordinator {
                 };
                 ctx.retire(Ok(Self::send_immediate_rows(Row::pack_slice(&[res]))));
             }
+            SideEffectingFunc::SequenceNextval { .. }
+            | SideEffectingFunc::SequenceCurrval { .. }
+            | SideEffectingFunc::SequenceSetval { .. } => {
+                let conn_id = ctx.session().conn_id().clone();
+                let result = self.sequence_sequence_func(&conn_id, plan).await;
+                ctx.retire(result);
+            }
         }
     }
 
+    /// Executes one of the sequence manipulation functions on behalf of the
+    /// connection `conn_id`.
+    async fn sequence_sequence_func(
+        &mut self,
+        conn_id: &ConnectionId,
+        plan: SideEffectingFunc,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let (SideEffectingFunc::SequenceNextval { id }
+        | SideEffectingFunc::SequenceCurrval { id }
+        | SideEffectingFunc::SequenceSetval { id, .. }) = plan
+        else {
+            unreachable!("not a sequence function")
+        };
+        // The sequence might have been dropped since the function call was
+        // planned.
+        if self.catalog().try_get_entry(&id).is_none() {
+            return Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind: ErrorKind::Sql(CatalogError::UnknownItem(id.to_string())),
+            }));
+        }
+        let value = match plan {
+            SideEffectingFunc::SequenceNextval { id } => {
+                let values = self.allocate_sequence_values(id, 1).await?;
+                let value = values[0];
+                if let Some(conn) = self.active_conns.get_mut(conn_id) {
+                    conn.sequence_values.insert(id, value);
+                }
+                value
+            }
+            SideEffectingFunc::SequenceCurrval { id } => {
+                let value = self
+                    .active_conns
+                    .get(conn_id)
+                    .and_then(|conn| conn.sequence_values.get(&id))
+                    .copied();
+                match value {
+                    Some(value) => value,
+                    None => {
+                        let entry = self.catalog().get_entry(&id);
+                        let name = self.catalog().resolve_full_name(entry.name(), None);
+                        return Err(AdapterError::SequenceCurrvalUndefined(name.to_string()));
+                    }
+                }
+            }
+            SideEffectingFunc::SequenceSetval {
+                id,
+                value,
+                is_called,
+            } => {
+                let entry = self.catalog().get_entry(&id);
+                let options = *entry.sequence_options().expect("setval on a non-sequence");
+                if value < options.min_value || value > options.max_value {
+                    let name = self.catalog().resolve_full_name(entry.name(), None);
+                    return Err(AdapterError::SequenceValueOutOfBounds {
+                        sequence_name: name.to_string(),
+                        value,
+                        min_value: options.min_value,
+                        max_value: options.max_value,
+                    });
+                }
+                let next = if is_called {
+                    options.next_value(value)
+                } else {
+                    Some(value)
+                };
+                let state = options.encode_state(next);
+                let ts = self.get_catalog_write_ts().await;
+                self.catalog().set_sequence_state(id, state, ts).await?;
+                // Any cached values were allocated from the old state.
+                self.sequence_caches.remove(&id);
+                if is_called && let Some(conn) = self.active_conns.get_mut(conn_id) {
+                    conn.sequence_values.insert(id, value);
+                }
+                value
+            }
+            SideEffectingFunc::PgCancelBackend { .. } => {
+                unreachable!("not a sequence function")
+            }
+        };
+        Ok(Self::send_immediate_rows(Row::pack_slice(&[Datum::Int64(
+            value,
+        )])))
+    }
+
     /// Execute a side-effecting function from the frontend peek path.
     /// This is separate from `sequence_side_effecting_func` because
     /// - It doesn't have an ExecuteContext.
@@ -2304,6 +2631,13 @@ impl Coordinator {
                     Ok(Self::send_immediate_rows(Row::pack_slice(&[Datum::False])))
                 }
             }
+            SideEffectingFunc::SequenceNextval { .. }
+            | SideEffectingFunc::SequenceCurrval { .. }
+            | SideEffectingFunc::SequenceSetval { .. } => {
+                // `rbac::check_plan` already verified privileges on the
+                // sequence, which doesn't depend on `active_conns`.
+                self.sequence_sequence_func(&conn_id, plan).await
+            }
         }
     }
 
@@ -2558,7 +2892,11 @@ impl Coordinator {
         };
 
         match optimized_mir.into_inner() {
-            selection if selection.as_const().is_some() && plan.returning.is_empty() => {
+            selection
+                if selection.as_const().is_some()
+                    && plan.returning.is_empty()
+                    && plan.sequence_defaults.is_empty() =>
+            {
                 let catalog = self.owned_catalog();
                 mz_ore::task::spawn(|| "coord::sequence_inner", async move {
                     let result =
@@ -2566,7 +2904,8 @@ impl Coordinator {
                     ctx.retire(result);
                 });
             }
-            // All non-constant values must be planned as read-then-writes.
+            // All non-constant values, as well as values with sequence
+            // defaults, must be planned as read-then-writes.
             _ => {
                 let desc_arity = match self.catalog().try_get_entry(&plan.id) {
                     Some(table) => {
@@ -2600,6 +2939,8 @@ impl Coordinator {
                     assignments: BTreeMap::new(),
                     kind: MutationKind::Insert,
                     returning: plan.returning,
+                    merge: None,
+                    sequence_defaults: plan.sequence_defaults,
                 };
 
                 self.sequence_read_then_write(ctx, read_then_write_plan)
@@ -2673,6 +3014,8 @@ impl Coordinator {
             mut assignments,
             finishing,
             mut returning,
+            mut merge,
+            sequence_defaults,
         } = plan;
 
         // Read then writes can be queued, so re-verify the id exists.
@@ -2697,7 +3040,11 @@ impl Coordinator {
         // Disallow mz_now in any position because read time and write time differ.
         let contains_temporal = return_if_err!(selection.contains_temporal(), ctx)
             || assignments.values().any(|e| e.contains_temporal())
-            || returning.iter().any(|e| e.contains_temporal());
+            || returning.iter().any(|e| e.contains_temporal())
+            || merge
+                .iter()
+                .flat_map(|m| m.exprs())
+                .any(|e| e.contains_temporal());
         if contains_temporal {
             ctx.retire(Err(AdapterError::Unsupported(
                 "calls to mz_now in write statements",
@@ -2742,7 +3089,7 @@ impl Coordinator {
                             let valid_id = id.is_user() || matches!(typ, Func);
                             valid_id
                         }
-                        Source | Secret | Connection => false,
+                        Source | Secret | Connection | Sequence => false,
                         // Cannot select from sinks or indexes.
                         Sink | Index => unreachable!(),
                         Table => {
@@ -2880,17 +3227,37 @@ impl Coordinator {
                 session: ctx.session(),
                 catalog_state: catalog.state(),
             };
-            for expr in assignments.values_mut().chain(returning.iter_mut()) {
+            let merge_exprs = merge.iter_mut().flat_map(|m| m.exprs_mut());
+            for expr in assignments
+                .values_mut()
+                .chain(returning.iter_mut())
+                .chain(merge_exprs)
+            {
                 return_if_err!(style.prep_scalar_expr(expr), ctx);
             }
 
-            let make_diffs = move |mut rows: Box<dyn RowIterator>|
+            // Both are also needed after `make_diffs`, which takes ownership of
+            // what it uses.
+            let desc = &desc;
+            let sequence_defaults = &sequence_defaults;
+
+            // Both MERGE and INSERT ... ON CONFLICT are planned with a merge,
+            // which counts the rows it affects itself: an updated row shows up
+            // as two diffs, and a no-op update as none.
+            let has_merge = merge.is_some();
+            let mut merge_state = MergeState::default();
+            let merge_state_ref = &mut merge_state;
+            let mut make_diffs = move |mut rows: Box<dyn RowIterator>|
                   -> Result<(Vec<(Row, Diff)>, u64), AdapterError> {
                     let arena = RowArena::new();
                     let mut diffs = Vec::new();
                     let mut datum_vec = mz_repr::DatumVec::new();
 
                     while let Some(row) = rows.next() {
+                        if let Some(merge) = &merge {
+                            merge_state_ref.apply(merge, row, &mut datum_vec, &mut diffs)?;
+                            continue;
+                        }
                         if !assignments.is_empty() {
                             assert!(
                                 matches!(kind, MutationKind::Update),
@@ -2919,6 +3286,7 @@ impl Coordinator {
                                 diffs.push((row.to_owned(), Diff::MINUS_ONE))
                             }
                             MutationKind::Insert => diffs.push((row.to_owned(), Diff::ONE)),
+                            MutationKind::Merge => unreachable!("handled above"),
                         }
                     }
 
@@ -2929,6 +3297,10 @@ impl Coordinator {
                         byte_size = byte_size.saturating_add(u64::cast_from(row.byte_len()));
                         if diff.is_positive() {
                             for (idx, datum) in row.iter().enumerate() {
+                                // Sequence defaults are filled in below.
+                                if sequence_defaults.iter().any(|(col, _)| *col == idx) {
+                                    continue;
+                                }
                                 desc.constraints_met(idx, &datum)?;
                             }
                         }
@@ -2992,6 +3364,14 @@ impl Coordinator {
                 ))),
             };
 
+            let diffs = match diffs {
+                Ok(diffs) if !sequence_defaults.is_empty() => {
+                    Self::fill_sequence_defaults(diffs, sequence_defaults, desc, &internal_cmd_tx)
+                        .await
+                }
+                diffs => diffs,
+            };
+
             let mut returning_rows = Vec::new();
             let mut diff_err: Option<AdapterError> = None;
             if let (false, Ok(diffs)) = (returning.is_empty(), &diffs) {
@@ -3093,6 +3473,7 @@ impl Coordinator {
                             kind,
                             returning: returning_rows,
                             max_result_size,
+                            affected_rows: has_merge.then_some(merge_state.affected_rows),
                         },
                     );
                     ctx.retire(result);
@@ -3104,6 +3485,59 @@ impl Coordinator {
         });
     }
 
+    /// Fills in the columns of the rows inserted by `diffs` that default to
+    /// the next value of a sequence, as described by `sequence_defaults`.
+    ///
+    /// Rows that are inserted more than once are split up, as every inserted
+    /// row draws its own values.
+    async fn fill_sequence_defaults(
+        diffs: Vec<(Row, Diff)>,
+        sequence_defaults: &[(usize, CatalogItemId)],
+        desc: &RelationDesc,
+        internal_cmd_tx: &mpsc::UnboundedSender<Message>,
+    ) -> Result<Vec<(Row, Diff)>, AdapterError> {
+        let count = diffs
+            .iter()
+            .map(|(_, diff)| usize::try_from(diff.into_inner()).expect("inserts are positive"))
+            .sum();
+        let mut values = Vec::with_capacity(sequence_defaults.len());
+        for (_, id) in sequence_defaults {
+            let (tx, rx) = oneshot::channel();
+            internal_cmd_tx
+                .send(Message::AllocateSequenceValues { id: *id, count, tx })
+                .map_err(|_| AdapterError::Internal("coordinator shut down".into()))?;
+            let allocated = rx
+                .await
+                .map_err(|_| AdapterError::Internal("coordinator shut down".into()))??;
+            values.push(allocated.into_iter());
+        }
+
+        let mut filled = Vec::with_capacity(count);
+        let mut datum_vec = mz_repr::DatumVec::new();
+        for (row, diff) in diffs {
+            for _ in 0..diff.into_inner() {
+                let mut datums = datum_vec.borrow_with(&row);
+                for ((idx, _), values) in sequence_defaults.iter().zip_eq(values.iter_mut()) {
+                    let value = values.next().expect("allocated one value per row");
+                    datums[*idx] = match desc.get_type(*idx).scalar_type {
+                        SqlScalarType::Int16 => i16::try_from(value)
+                            .map(Datum::Int16)
+                            .map_err(|_| EvalError::Int16OutOfRange(value.to_string().into()))?,
+                        SqlScalarType::Int32 => i32::try_from(value)
+                            .map(Datum::Int32)
+                            .map_err(|_| EvalError::Int32OutOfRange(value.to_string().into()))?,
+                        SqlScalarType::Int64 => Datum::Int64(value),
+                        ref typ => {
+                            unreachable!("planning rejects sequence defaults of type {typ:?}")
+                        }
+                    };
+                }
+                filled.push((Row::pack_slice(&datums), Diff::ONE));
+            }
+        }
+        Ok(filled)
+    }
+
     #[instrument]
     pub(super) async fn sequence_alter_item_rename(
         &mut self,
@@ -3147,6 +3581,22 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(plan.object_type))
     }
 
+    #[instrument]
+    pub(super) async fn sequence_alter_storage_compression(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::AlterStorageCompressionPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let ops = vec![catalog::Op::AlterStorageCompression {
+            id: plan.id,
+            value: plan.value,
+            compression: plan.compression,
+        }];
+        self.catalog_transact_with_context(None, Some(ctx), ops)
+            .await?;
+        Ok(ExecuteResponse::AlteredObject(plan.object_type))
+    }
+
     #[instrument]
     pub(super) async fn sequence_alter_source_timestamp_interval(
         &mut self,
@@ -4731,6 +5181,208 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
     }
 
+    /// Adds a `CHECK` constraint to a table, after verifying that its current
+    /// contents satisfy it.
+    ///
+    /// The table is read off the main loop, and the constraint is added once
+    /// [`Message::AddConstraintValidationReady`] comes back. The session holds
+    /// the table's write lock throughout, so that no write can sneak in
+    /// between the verification and the constraint taking effect.
+    #[instrument]
+    pub(super) async fn sequence_alter_table_add_constraint(
+        &mut self,
+        mut ctx: ExecuteContext,
+        plan: plan::AlterTableAddConstraintPlan,
+    ) {
+        let id = plan.relation_id;
+
+        // If the transaction doesn't already have the write lock, acquire it.
+        if ctx.session().transaction().write_locks().is_none() {
+            let mut write_locks = WriteLocks::builder([id]);
+            if let Some(lock) = self.try_grant_object_write_lock(id) {
+                write_locks.insert_lock(id, lock);
+            }
+            let write_locks = match write_locks.all_or_nothing(ctx.session().conn_id()) {
+                Ok(locks) => locks,
+                Err(missing) => {
+                    let role_metadata = ctx.session().role_metadata().clone();
+                    let acquire_future = self.grant_object_write_lock(missing).map(Option::Some);
+                    let plan = DeferredPlan {
+                        ctx,
+                        plan: Plan::AlterTableAddConstraint(plan),
+                        validity: PlanValidity::new(
+                            self.catalog.transient_revision(),
+                            BTreeSet::from([id]),
+                            None,
+                            None,
+                            role_metadata,
+                        ),
+                        requires_locks: BTreeSet::from([id]),
+                    };
+                    return self.defer_op(acquire_future, DeferredOp::Plan(plan));
+                }
+            };
+            ctx.session_mut()
+                .try_grant_write_locks(write_locks)
+                .expect("session has already been granted write locks");
+        }
+
+        let relation_id = plan.relation_id;
+        let entry = self.catalog().get_entry(&relation_id);
+        if !matches!(entry.item(), CatalogItem::Table(_)) {
+            let name = entry.name().item.clone();
+            return ctx.retire(Err(AdapterError::Unstructured(anyhow!(
+                "{name} is not a table"
+            ))));
+        }
+        let table_name = self
+            .catalog()
+            .resolve_full_name(entry.name(), Some(ctx.session().conn_id()))
+            .to_string();
+
+        // We hold the write lock, so every write to the table has been
+        // applied as of the read timestamp.
+        let read_ts = self.get_local_read_ts().await;
+        let snapshot = self
+            .controller
+            .storage_collections
+            .snapshot_cursor(entry.latest_global_id(), read_ts);
+        let plan_validity = PlanValidity::new(
+            self.catalog().transient_revision(),
+            BTreeSet::from([relation_id]),
+            None,
+            None,
+            ctx.session().role_metadata().clone(),
+        );
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        let otel_ctx = OpenTelemetryContext::obtain();
+        task::spawn(|| "alter_table_add_constraint", async move {
+            let checks = [plan.check.clone()];
+            let result = async {
+                let mut cursor = snapshot.await?;
+                while let Some(updates) = cursor.next().await {
+                    for (source_data, _ts, _diff) in updates {
+                        let row = source_data.0.map_err(|e| {
+                            AdapterError::Internal(format!("invalid table row: {e}"))
+                        })?;
+                        if violated_check(&checks, &row)?.is_some() {
+                            return Err(AdapterError::CheckViolation {
+                                table_name,
+                                constraint_name: checks[0].name.clone(),
+                                existing_row: true,
+                            });
+                        }
+                    }
+                }
+                Ok(())
+            }
+            .await;
+            // It is not an error for validation to complete after `internal_cmd_rx` is dropped.
+            let result = internal_cmd_tx.send(Message::AddConstraintValidationReady(
+                AddConstraintValidationReady {
+                    ctx,
+                    result,
+                    plan,
+                    plan_validity,
+                    otel_ctx,
+                },
+            ));
+            if let Err(e) = result {
+                tracing::warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+            }
+        });
+    }
+
+    /// Adds the constraint of `ALTER TABLE ... ADD CONSTRAINT` to the table,
+    /// once its contents are known to satisfy it.
+    #[instrument]
+    pub(crate) async fn message_add_constraint_validation_ready(
+        &mut self,
+        AddConstraintValidationReady {
+            mut ctx,
+            result,
+            plan,
+            mut plan_validity,
+            otel_ctx,
+        }: AddConstraintValidationReady,
+    ) {
+        otel_ctx.attach_as_parent();
+
+        if let Err(e) = plan_validity.check(self.catalog()) {
+            return ctx.retire(Err(e));
+        }
+        if let Err(e) = result {
+            return ctx.retire(Err(e));
+        }
+        let result = self.alter_table_add_constraint_finish(&mut ctx, plan).await;
+        ctx.retire(result);
+    }
+
+    async fn alter_table_add_constraint_finish(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::AlterTableAddConstraintPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTableAddConstraintPlan {
+            relation_id,
+            create_sql,
+            check,
+        } = plan;
+
+        let entry = self.catalog().get_entry(&relation_id);
+        let CatalogItem::Table(table) = entry.item() else {
+            coord_bail!("{} is not a table", entry.name().item);
+        };
+        let name = entry.name().clone();
+        let mut table = table.clone();
+        table.create_sql = create_sql;
+        if let TableDataSource::TableWrites { checks, .. } = &mut table.data_source {
+            checks.push(check);
+        }
+        let ops = vec![catalog::Op::UpdateItem {
+            id: relation_id,
+            name,
+            to_item: CatalogItem::Table(table),
+        }];
+        self.catalog_transact_with_context(None, Some(ctx), ops)
+            .await?;
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_alter_table_drop_constraint(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::AlterTableDropConstraintPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTableDropConstraintPlan {
+            relation_id,
+            create_sql,
+            constraint_name,
+        } = plan;
+
+        let entry = self.catalog().get_entry(&relation_id);
+        let CatalogItem::Table(table) = entry.item() else {
+            coord_bail!("{} is not a table", entry.name().item);
+        };
+        let name = entry.name().clone();
+        let mut table = table.clone();
+        table.create_sql = create_sql;
+        if let TableDataSource::TableWrites { checks, .. } = &mut table.data_source {
+            checks.retain(|check| check.name != constraint_name);
+        }
+        let ops = vec![catalog::Op::UpdateItem {
+            id: relation_id,
+            name,
+            to_item: CatalogItem::Table(table),
+        }];
+        self.catalog_transact_with_context(None, Some(ctx), ops)
+            .await?;
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
     /// Prepares to apply a replacement materialized view.
     #[instrument]
     pub(super) async fn sequence_alter_materialized_view_apply_replacement_prepare(
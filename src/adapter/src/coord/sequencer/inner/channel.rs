@@ -0,0 +1,229 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Channels bound to views.
+//!
+//! `CREATE CHANNEL c ON VIEW v` installs a `SUBSCRIBE` to `v` that is owned by
+//! the creating connection and, unlike an ordinary `SUBSCRIBE`, outlives the
+//! transaction that created it. A task turns each update the subscribe
+//! produces into a notification on `c`, whose payload is the JSON object
+//! `{"mz_diff": ..., "row": {...}}`. Notifications are delivered in timestamp
+//! order, so the timestamps themselves are left out. Updates whose
+//! payload would exceed [`MAX_NOTIFY_PAYLOAD_SIZE`] carry only the columns of
+//! the view's first key under `"key"`, so listeners can look the row up.
+
+use maplit::btreemap;
+use mz_adapter_types::connection::ConnectionId;
+use mz_interchange::encode::column_names_and_types;
+use mz_interchange::json::encode_datums_as_json;
+use mz_repr::{ColumnName, GlobalId, RelationDesc, RowRef, SqlColumnType};
+use mz_sql::plan::{self, MAX_NOTIFY_PAYLOAD_SIZE};
+use mz_sql::session::metadata::SessionMetadata;
+use tokio::sync::mpsc;
+use tracing::{Span, warn};
+
+use crate::active_compute_sink::ActiveComputeSinkRetireReason;
+use crate::command::ExecuteResponse;
+use crate::coord::peek::PeekResponseUnary;
+use crate::coord::sequencer::inner::return_if_err;
+use crate::coord::{Coordinator, ExplainContext, Message, TargetCluster, ViewChannel};
+use crate::error::AdapterError;
+use crate::session::{Notification, Session};
+use crate::{AdapterNotice, ExecuteContext};
+
+impl Coordinator {
+    #[mz_ore::instrument(level = "debug")]
+    pub(crate) async fn sequence_create_channel(
+        &mut self,
+        mut ctx: ExecuteContext,
+        plan: plan::CreateChannelPlan,
+        target_cluster: TargetCluster,
+    ) {
+        let plan::CreateChannelPlan {
+            channel,
+            subscribe,
+            desc,
+        } = plan;
+        if self.view_channel_owner(&channel).is_some() {
+            ctx.retire(Err(AdapterError::DuplicateChannel(channel)));
+            return;
+        }
+        let channel = ViewChannel {
+            name: channel,
+            desc,
+        };
+        let stage = return_if_err!(
+            self.subscribe_validate(
+                ctx.session_mut(),
+                subscribe,
+                target_cluster,
+                ExplainContext::None,
+                Some(channel),
+            ),
+            ctx
+        );
+        self.sequence_staged(ctx, Span::current(), stage).await;
+    }
+
+    /// Returns the connection that bound `channel` to a view, if any.
+    fn view_channel_owner(&self, channel: &str) -> Option<&ConnectionId> {
+        self.active_conns.iter().find_map(|(conn_id, conn)| {
+            let sink_id = conn.view_channels.get(channel)?;
+            self.active_compute_sinks
+                .contains_key(sink_id)
+                .then_some(conn_id)
+        })
+    }
+
+    /// Binds `channel` to the running subscribe `sink_id`, whose updates are
+    /// received on `rx`.
+    ///
+    /// The subscribe is detached from the session's transaction, and is only
+    /// retired by `DROP CHANNEL`, `DISCARD ALL`, or the end of the session.
+    pub(super) async fn bind_view_channel(
+        &mut self,
+        session: &Session,
+        channel: ViewChannel,
+        sink_id: GlobalId,
+        mut rx: mpsc::UnboundedReceiver<PeekResponseUnary>,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // Another session may have bound the same channel while the subscribe
+        // was being planned.
+        if self.view_channel_owner(&channel.name).is_some() {
+            self.retire_compute_sinks(btreemap! {
+                sink_id => ActiveComputeSinkRetireReason::Canceled,
+            })
+            .await;
+            return Err(AdapterError::DuplicateChannel(channel.name));
+        }
+
+        let conn_id = session.conn_id().clone();
+        let conn = self
+            .active_conns
+            .get_mut(&conn_id)
+            .expect("connection must exist");
+        conn.drop_sinks.remove(&sink_id);
+        conn.view_channels.insert(channel.name.clone(), sink_id);
+
+        let ViewChannel { name, desc } = channel;
+        let encoder = ChangeEncoder::new(desc);
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        let task_name = format!("view_channel:{name}");
+        mz_ore::task::spawn(|| task_name, async move {
+            while let Some(response) = rx.recv().await {
+                let mut rows = match response {
+                    PeekResponseUnary::Rows(rows) => rows,
+                    PeekResponseUnary::Error(_) | PeekResponseUnary::Canceled => break,
+                };
+                let mut notifications = Vec::new();
+                while let Some(row) = rows.next() {
+                    notifications.push(Notification {
+                        conn_id: conn_id.clone(),
+                        channel: name.clone(),
+                        payload: encoder.encode(row),
+                    });
+                }
+                if let Err(e) = internal_cmd_tx.send(Message::DeliverNotifications(notifications)) {
+                    warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                    break;
+                }
+            }
+        });
+
+        Ok(ExecuteResponse::CreatedChannel)
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    pub(super) async fn sequence_drop_channel(
+        &mut self,
+        session: &Session,
+        plan: plan::DropChannelPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let conn = self
+            .active_conns
+            .get_mut(session.conn_id())
+            .expect("connection must exist");
+        let sink_id = conn
+            .view_channels
+            .remove(&plan.channel)
+            .filter(|sink_id| self.active_compute_sinks.contains_key(sink_id));
+        match sink_id {
+            Some(sink_id) => {
+                self.retire_compute_sinks(btreemap! {
+                    sink_id => ActiveComputeSinkRetireReason::Canceled,
+                })
+                .await;
+            }
+            None if plan.if_exists => {
+                session.add_notice(AdapterNotice::UnknownChannel { name: plan.channel });
+            }
+            None => return Err(AdapterError::UnknownChannel(plan.channel)),
+        }
+        Ok(ExecuteResponse::DroppedChannel)
+    }
+
+    /// Unbinds every channel the connection bound to a view.
+    pub(crate) async fn drop_view_channels(&mut self, conn_id: &ConnectionId) {
+        let conn = self
+            .active_conns
+            .get_mut(conn_id)
+            .expect("connection must exist");
+        let sink_ids = std::mem::take(&mut conn.view_channels);
+        let reasons = sink_ids
+            .into_values()
+            .filter(|sink_id| self.active_compute_sinks.contains_key(sink_id))
+            .map(|sink_id| (sink_id, ActiveComputeSinkRetireReason::Canceled))
+            .collect();
+        self.retire_compute_sinks(reasons).await;
+    }
+}
+
+/// Renders the updates of a view's subscribe as notification payloads.
+struct ChangeEncoder {
+    /// The names and types of the view's columns.
+    columns: Vec<(ColumnName, SqlColumnType)>,
+    /// The positions, names, and types of the columns of the view's first key.
+    key: Vec<(usize, (ColumnName, SqlColumnType))>,
+}
+
+impl ChangeEncoder {
+    fn new(desc: RelationDesc) -> Self {
+        let key = desc.typ().keys.first().cloned().unwrap_or_default();
+        let columns = column_names_and_types(desc);
+        let key = key.into_iter().map(|i| (i, columns[i].clone())).collect();
+        ChangeEncoder { columns, key }
+    }
+
+    /// Encodes a subscribe update, which has the form
+    /// `[mz_timestamp, mz_diff, columns...]`.
+    fn encode(&self, row: &RowRef) -> String {
+        let mut datums = row.iter();
+        let _timestamp = datums.next().expect("subscribe rows have a timestamp");
+        let diff = datums.next().expect("subscribe rows have a diff");
+        let values: Vec<_> = datums.collect();
+        let payload = serde_json::json!({
+            "mz_diff": diff.unwrap_int64(),
+            "row": encode_datums_as_json(values.iter().copied(), &self.columns),
+        })
+        .to_string();
+        if payload.len() < MAX_NOTIFY_PAYLOAD_SIZE {
+            return payload;
+        }
+        let (datums, names_types): (Vec<_>, Vec<_>) = self
+            .key
+            .iter()
+            .map(|(i, name_type)| (values[*i], name_type.clone()))
+            .unzip();
+        serde_json::json!({
+            "mz_diff": diff.unwrap_int64(),
+            "key": encode_datums_as_json(datums, &names_types),
+        })
+        .to_string()
+    }
+}
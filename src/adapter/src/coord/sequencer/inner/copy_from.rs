@@ -16,7 +16,7 @@ use mz_persist_client::Diagnostics;
 use mz_persist_client::batch::ProtoBatch;
 use mz_persist_types::codec_impls::UnitSchema;
 use mz_pgcopy::CopyFormatParams;
-use mz_repr::{CatalogItemId, ColumnIndex, Datum, RelationDesc, Row, RowArena};
+use mz_repr::{CatalogItemId, ColumnIndex, Datum, Diff, RelationDesc, Row, RowArena};
 use mz_sql::catalog::SessionCatalog;
 use mz_sql::plan::{self, CopyFromFilter, CopyFromSource, HirScalarExpr};
 use mz_sql::session::metadata::SessionMetadata;
@@ -32,7 +32,7 @@ use uuid::Uuid;
 
 use crate::command::CopyFromStdinWriter;
 use crate::coord::sequencer::inner::return_if_err;
-use crate::coord::{ActiveCopyFrom, Coordinator, TargetCluster};
+use crate::coord::{ActiveCopyFrom, Coordinator, Message, TargetCluster};
 use crate::optimize;
 use crate::optimize::dataflows::{EvalTime, ExprPrep, ExprPrepOneShot};
 use crate::session::{Session, TransactionOps, WriteOp};
@@ -292,8 +292,10 @@ impl Coordinator {
 
         // If we need column rewriting, pre-compute the transform by running
         // plan_copy_from with a single dummy row through the optimizer.
-        let column_transform = if all_columns_in_order {
-            None
+        // Columns that default to the next value of a sequence get a `NULL`
+        // placeholder, which the workers fill in per row.
+        let (column_transform, sequence_defaults) = if all_columns_in_order {
+            (None, vec![])
         } else {
             let dummy_datums: Vec<Datum> = columns.iter().map(|_| Datum::Null).collect();
             let dummy_row = Row::pack(&dummy_datums);
@@ -309,7 +311,7 @@ impl Coordinator {
                 prep,
             );
 
-            let hir = mz_sql::plan::plan_copy_from(
+            let (hir, sequence_defaults) = mz_sql::plan::plan_copy_from(
                 &pcx,
                 &conn_catalog,
                 target_id,
@@ -347,10 +349,11 @@ impl Coordinator {
 
             let defaults_row = Row::pack(&default_datums);
 
-            Some(ColumnTransform {
+            let transform = ColumnTransform {
                 sources,
                 defaults_row,
-            })
+            };
+            (Some(transform), sequence_defaults)
         };
 
         // Compute column types for decoding (same logic as pgwire used to do).
@@ -374,6 +377,7 @@ impl Coordinator {
 
         // Shared state across workers.
         let column_transform = Arc::new(column_transform);
+        let sequence_defaults: Arc<[(usize, CatalogItemId)]> = sequence_defaults.into();
         let target_desc = Arc::new(target_desc);
         let collection_desc = Arc::new(collection_desc);
         let persist_client = self.persist_client.clone();
@@ -406,6 +410,8 @@ impl Coordinator {
             let persist_client = persist_client.clone();
             let column_types = Arc::clone(&column_types);
             let column_transform = Arc::clone(&column_transform);
+            let sequence_defaults = Arc::clone(&sequence_defaults);
+            let internal_cmd_tx = self.internal_cmd_tx.clone();
             let target_desc = Arc::clone(&target_desc);
             let collection_desc = Arc::clone(&collection_desc);
             let params = worker_params.clone();
@@ -424,6 +430,8 @@ impl Coordinator {
                         collection_desc,
                         target_desc,
                         column_transform,
+                        sequence_defaults,
+                        internal_cmd_tx,
                         column_types,
                         params,
                         skip_header_on_first_chunk,
@@ -474,6 +482,8 @@ impl Coordinator {
         collection_desc: Arc<RelationDesc>,
         target_desc: Arc<RelationDesc>,
         column_transform: Arc<Option<ColumnTransform>>,
+        sequence_defaults: Arc<[(usize, CatalogItemId)]>,
+        internal_cmd_tx: mpsc::UnboundedSender<Message>,
         column_types: Arc<[mz_pgrepr::Type]>,
         params: CopyFormatParams<'static>,
         skip_header_on_first_chunk: bool,
@@ -520,14 +530,25 @@ impl Coordinator {
             let rows = mz_pgcopy::decode_copy_format(&raw_bytes, &column_types, chunk_params)
                 .map_err(|e| AdapterError::CopyFormatError(e.to_string()))?;
 
-            for row in rows {
-                // Apply column transform if needed (add defaults, reorder).
-                let full_row = if let Some(ref transform) = *column_transform {
-                    transform.apply(&row)
-                } else {
-                    row
-                };
+            // Apply column transform if needed (add defaults, reorder).
+            let mut full_rows: Vec<_> = rows
+                .into_iter()
+                .map(|row| match *column_transform {
+                    Some(ref transform) => (transform.apply(&row), Diff::ONE),
+                    None => (row, Diff::ONE),
+                })
+                .collect();
+            if !sequence_defaults.is_empty() {
+                full_rows = Self::fill_sequence_defaults(
+                    full_rows,
+                    &sequence_defaults,
+                    &target_desc,
+                    &internal_cmd_tx,
+                )
+                .await?;
+            }
 
+            for (full_row, _) in full_rows {
                 // Check constraints.
                 for (i, datum) in full_row.iter().enumerate() {
                     target_desc.constraints_met(i, &datum).map_err(|e| {
@@ -71,8 +71,11 @@ impl Coordinator {
                 resolved_ids: resolved_ids.clone(),
                 custom_logical_compaction_window: None,
                 is_retained_metrics_object: false,
+                storage_compression: None,
                 data_source: TableDataSource::TableWrites {
                     defaults: Vec::new(),
+                    checks: Vec::new(),
+                    uniques: Vec::new(),
                 },
             }),
             referenced_by: Vec::new(),
@@ -287,6 +290,10 @@ impl OptimizerCatalog for ContinualTaskCatalogBootstrap {
     ) -> Box<dyn Iterator<Item = (GlobalId, &mz_catalog::memory::objects::Index)> + '_> {
         self.delegate.get_indexes_on(id, cluster)
     }
+
+    fn get_table_checks(&self, id: &GlobalId) -> &[mz_sql::plan::TableCheck] {
+        self.delegate.get_table_checks(id)
+    }
 }
 
 fn update_create_sql(
@@ -346,6 +346,7 @@ impl Coordinator {
                         plan.name.clone(),
                         plan.index.on,
                         plan.index.keys.clone(),
+                        plan.index.spill_to_disk,
                     );
 
                     // MIR ⇒ MIR optimization (global)
@@ -431,6 +432,7 @@ impl Coordinator {
                             keys,
                             cluster_id,
                             compaction_window,
+                            spill_to_disk,
                         },
                     if_not_exists,
                 },
@@ -455,6 +457,7 @@ impl Coordinator {
                 cluster_id,
                 is_retained_metrics_object: false,
                 custom_logical_compaction_window: compaction_window,
+                spill_to_disk,
                 optimized_plan: None,
                 physical_plan: None,
                 dataflow_metainfo: None,
@@ -570,6 +570,7 @@ impl Coordinator {
                             non_null_assertions,
                             compaction_window,
                             refresh_schedule,
+                            storage_compression,
                             ..
                         },
                     drop_ids,
@@ -678,6 +679,7 @@ impl Coordinator {
                     non_null_assertions,
                     custom_logical_compaction_window: compaction_window,
                     refresh_schedule: refresh_schedule.clone(),
+                    storage_compression,
                     initial_as_of: Some(initial_as_of.clone()),
                     optimized_plan: None,
                     physical_plan: None,
@@ -747,6 +749,10 @@ impl Coordinator {
                         .await
                         .unwrap_or_terminate("cannot fail to append");
 
+                    if let Some(compression) = storage_compression {
+                        coord.set_storage_compression_in_background(global_id, Some(compression));
+                    }
+
                     coord
                         .initialize_storage_read_policies(
                             btreeset![item_id],
@@ -0,0 +1,459 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Sequencing of `EXPLAIN RECOMMENDATIONS FOR CLUSTER`.
+//!
+//! We replay the `SELECT`s that the statement log recorded as slow path peeks on
+//! the cluster through the optimizer, once for every index that `LiteralConstraints`
+//! could use to speed them up, pretending that the index exists. Indexes that turn
+//! a peek into a fast path lookup are recommended. Their size is estimated from the
+//! size that persist reports for the collection they are on.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use itertools::Itertools;
+use mz_catalog::builtin::{
+    MZ_PREPARED_STATEMENT_HISTORY, MZ_SQL_TEXT, MZ_STATEMENT_EXECUTION_HISTORY,
+};
+use mz_controller_types::ClusterId;
+use mz_expr::explain::{HumanizedNotice, HumanizerMode};
+use mz_expr::{MirScalarExpr, RowSetFinishing};
+use mz_ore::cast::CastFrom;
+use mz_ore::collections::CollectionExt;
+use mz_ore::instrument;
+use mz_ore::str::separated;
+use mz_ore::task;
+use mz_ore::tracing::OpenTelemetryContext;
+use mz_repr::explain::ExprHumanizer;
+use mz_repr::global_id::TransientIdGen;
+use mz_repr::{Datum, GlobalId, RelationDesc, Row};
+use mz_sql::optimizer_metrics::OptimizerMetrics;
+use mz_sql::plan::{self, Params, Plan};
+use mz_sql::session::metadata::SessionMetadata;
+use mz_sql_parser::ast::Statement;
+use mz_storage_client::healthcheck::{
+    MZ_PREPARED_STATEMENT_HISTORY_DESC, MZ_SQL_TEXT_DESC, MZ_STATEMENT_EXECUTION_HISTORY_DESC,
+};
+use mz_storage_client::storage_collections::StorageCollections;
+use mz_transform::EmptyStatisticsOracle;
+use mz_transform::literal_constraints::LiteralConstraints;
+
+use crate::catalog::Catalog;
+use crate::coord::peek::{FastPathPlan, PeekPlan};
+use crate::coord::{
+    Coordinator, ExplainRecommendationsReady, IndexRecommendation, Message, PlanValidity,
+};
+use crate::error::AdapterError;
+use crate::optimize::dataflows::ComputeInstanceSnapshot;
+use crate::optimize::{self, Optimize};
+use crate::statement_logging::StatementExecutionStrategy;
+use crate::{ExecuteContext, TimestampContext};
+
+impl Coordinator {
+    #[instrument]
+    pub(crate) async fn sequence_explain_recommendations(
+        &mut self,
+        ctx: ExecuteContext,
+        plan: plan::ExplainRecommendationsPlan,
+    ) {
+        let plan::ExplainRecommendationsPlan { cluster_id } = plan;
+
+        let compute_instance = self
+            .instance_snapshot(cluster_id)
+            .expect("compute instance does not exist");
+        let optimizer_config = optimize::OptimizerConfig::from(self.catalog().system_config())
+            .override_from(&self.catalog.get_cluster(cluster_id).config.features());
+        let replay = Replay {
+            catalog: self.owned_catalog(),
+            compute_instance,
+            optimizer_config,
+            optimizer_metrics: self.optimizer_metrics(),
+            transient_id_gen: Arc::clone(&self.transient_id_gen),
+        };
+
+        let [executions_id, prepared_statements_id, sql_texts_id] = [
+            &MZ_STATEMENT_EXECUTION_HISTORY,
+            &MZ_PREPARED_STATEMENT_HISTORY,
+            &MZ_SQL_TEXT,
+        ]
+        .map(|builtin| {
+            let item_id = self.catalog().resolve_builtin_storage_collection(builtin);
+            self.catalog().get_entry(&item_id).latest_global_id()
+        });
+        let storage_collections = Arc::clone(&self.controller.storage_collections);
+
+        let plan_validity = PlanValidity::new(
+            self.catalog().transient_revision(),
+            BTreeSet::new(),
+            Some(cluster_id),
+            None,
+            ctx.session().role_metadata().clone(),
+        );
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        let otel_ctx = OpenTelemetryContext::obtain();
+
+        task::spawn(
+            || format!("explain_recommendations:{cluster_id}"),
+            async move {
+                let result = async {
+                    let executions = storage_collections.snapshot_latest(executions_id).await?;
+                    let prepared_statements = storage_collections
+                        .snapshot_latest(prepared_statements_id)
+                        .await?;
+                    let sql_texts = storage_collections.snapshot_latest(sql_texts_id).await?;
+                    let statements = slow_path_selects(
+                        cluster_id,
+                        &executions,
+                        &prepared_statements,
+                        &sql_texts,
+                    );
+
+                    let session = ctx.session().meta();
+                    let mut recommendations = task::spawn_blocking(
+                        || "explain_recommendations_replay",
+                        move || replay.recommendations(statements, &session),
+                    )
+                    .await;
+                    for recommendation in &mut recommendations {
+                        recommendation.size =
+                            estimated_size(recommendation, storage_collections.as_ref()).await;
+                    }
+                    Ok::<_, AdapterError>(recommendations)
+                }
+                .await;
+
+                // It is not an error for the replay to complete after `internal_cmd_rx` is dropped.
+                let result = internal_cmd_tx.send(Message::ExplainRecommendationsReady(
+                    ExplainRecommendationsReady {
+                        ctx,
+                        result,
+                        plan_validity,
+                        otel_ctx,
+                    },
+                ));
+                if let Err(e) = result {
+                    tracing::warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                }
+            },
+        );
+    }
+
+    /// Reports the recommendations of `EXPLAIN RECOMMENDATIONS`, most useful first.
+    #[instrument]
+    pub(crate) fn message_explain_recommendations_ready(
+        &mut self,
+        ExplainRecommendationsReady {
+            ctx,
+            result,
+            mut plan_validity,
+            otel_ctx,
+        }: ExplainRecommendationsReady,
+    ) {
+        otel_ctx.attach_as_parent();
+
+        if let Err(e) = plan_validity.check(self.catalog()) {
+            return ctx.retire(Err(e));
+        }
+        let mut recommendations = match result {
+            Ok(recommendations) => recommendations,
+            Err(e) => return ctx.retire(Err(e)),
+        };
+        recommendations.sort_by(|a, b| {
+            b.statements
+                .cmp(&a.statements)
+                .then_with(|| a.create_sql.cmp(&b.create_sql))
+        });
+
+        let rows = recommendations
+            .iter()
+            .map(|r| {
+                let statements = i64::try_from(r.statements).unwrap_or(i64::MAX);
+                let size = match r.size {
+                    Some(size) => Datum::Int64(i64::try_from(size).unwrap_or(i64::MAX)),
+                    None => Datum::Null,
+                };
+                Row::pack_slice(&[
+                    Datum::String(&r.create_sql),
+                    Datum::Int64(statements),
+                    Datum::String(&r.example),
+                    size,
+                ])
+            })
+            .collect::<Vec<_>>();
+        ctx.retire(Ok(Self::send_immediate_rows(rows)));
+    }
+}
+
+/// Estimates the size of a recommended index from the size of the collection it is on.
+///
+/// An index arranges every record of the collection by its key, so we scale the size of the
+/// collection's data in persist by the width of the key relative to that of the records. We
+/// know nothing about the size of collections that aren't in persist, e.g., views.
+async fn estimated_size(
+    recommendation: &IndexRecommendation,
+    storage_collections: &(dyn StorageCollections + Send + Sync),
+) -> Option<u64> {
+    let id = recommendation.on_id;
+    let as_of = storage_collections
+        .collection_frontiers(id)
+        .ok()?
+        .read_capabilities;
+    if as_of.is_empty() {
+        return None;
+    }
+    let stats = storage_collections
+        .snapshot_parts_stats(id, as_of)
+        .await
+        .await
+        .ok()?;
+    let size = stats
+        .parts
+        .iter()
+        .map(|part| u64::cast_from(part.encoded_size_bytes))
+        .sum::<u64>();
+    let arity = u64::cast_from(recommendation.arity.max(1));
+    let key_arity = u64::cast_from(recommendation.key_arity);
+    Some(size.saturating_mul(arity + key_arity) / arity)
+}
+
+/// A `SELECT` that the statement log recorded as a slow path peek.
+struct LoggedSelect {
+    sql: String,
+    database: String,
+    search_path: Vec<String>,
+}
+
+/// Returns the logged `SELECT`s that ran as slow path peeks on the given cluster, with the number
+/// of times they ran.
+fn slow_path_selects(
+    cluster_id: ClusterId,
+    executions: &[Row],
+    prepared_statements: &[Row],
+    sql_texts: &[Row],
+) -> Vec<(LoggedSelect, u64)> {
+    let column = |desc: &RelationDesc, name: &str| {
+        desc.get_by_name(&name.into())
+            .map(|(i, _typ)| i)
+            .expect("known column")
+    };
+
+    let sql_hash = column(&MZ_SQL_TEXT_DESC, "sql_hash");
+    let sql = column(&MZ_SQL_TEXT_DESC, "sql");
+    let sql_texts: BTreeMap<_, _> = sql_texts
+        .iter()
+        .map(|row| {
+            let datums = row.unpack();
+            (
+                datums[sql_hash].unwrap_bytes().to_vec(),
+                datums[sql].unwrap_str().to_string(),
+            )
+        })
+        .collect();
+
+    let id = column(&MZ_PREPARED_STATEMENT_HISTORY_DESC, "id");
+    let sql_hash = column(&MZ_PREPARED_STATEMENT_HISTORY_DESC, "sql_hash");
+    let prepared_statements: BTreeMap<_, _> = prepared_statements
+        .iter()
+        .filter_map(|row| {
+            let datums = row.unpack();
+            let sql = sql_texts.get(datums[sql_hash].unwrap_bytes())?;
+            Some((datums[id].unwrap_uuid(), sql))
+        })
+        .collect();
+
+    let desc = &*MZ_STATEMENT_EXECUTION_HISTORY_DESC;
+    let prepared_statement_id = column(desc, "prepared_statement_id");
+    let execution_cluster_id = column(desc, "cluster_id");
+    let database_name = column(desc, "database_name");
+    let search_path = column(desc, "search_path");
+    let execution_strategy = column(desc, "execution_strategy");
+
+    let cluster_id = cluster_id.to_string();
+    let mut selects: BTreeMap<_, u64> = BTreeMap::new();
+    for row in executions {
+        let datums = row.unpack();
+        if datums[execution_cluster_id] != Datum::String(&cluster_id)
+            || datums[execution_strategy]
+                != Datum::String(StatementExecutionStrategy::Standard.name())
+        {
+            continue;
+        }
+        let Some(sql) = prepared_statements.get(&datums[prepared_statement_id].unwrap_uuid())
+        else {
+            continue;
+        };
+        let search_path = datums[search_path]
+            .unwrap_list()
+            .iter()
+            .map(|schema| schema.unwrap_str().to_string())
+            .collect_vec();
+        let key = (
+            (*sql).clone(),
+            datums[database_name].unwrap_str().to_string(),
+            search_path,
+        );
+        *selects.entry(key).or_default() += 1;
+    }
+
+    selects
+        .into_iter()
+        .map(|((sql, database, search_path), count)| {
+            let select = LoggedSelect {
+                sql,
+                database,
+                search_path,
+            };
+            (select, count)
+        })
+        .collect()
+}
+
+/// The state required to replay logged statements through the optimizer.
+struct Replay {
+    catalog: Arc<Catalog>,
+    compute_instance: ComputeInstanceSnapshot,
+    optimizer_config: optimize::OptimizerConfig,
+    optimizer_metrics: OptimizerMetrics,
+    transient_id_gen: Arc<TransientIdGen>,
+}
+
+impl Replay {
+    /// Returns the indexes that would turn the given statements into fast path peeks.
+    fn recommendations(
+        &self,
+        statements: Vec<(LoggedSelect, u64)>,
+        session: &dyn SessionMetadata,
+    ) -> Vec<IndexRecommendation> {
+        let mut recommendations: BTreeMap<(GlobalId, Vec<MirScalarExpr>), (u64, String)> =
+            BTreeMap::new();
+        for (select, count) in statements {
+            // Statements that we can't replay, e.g., because they have parameters or because the
+            // objects that they depend on are gone, simply don't contribute recommendations.
+            let Ok(keys) = self.fast_path_keys(&select, session) else {
+                continue;
+            };
+            for key in keys {
+                let (statements, _example) = recommendations
+                    .entry(key)
+                    .or_insert_with(|| (0, select.sql.clone()));
+                *statements += count;
+            }
+        }
+
+        let humanizer = self.catalog.for_system_session();
+        let mode = HumanizedNotice::new(false);
+        recommendations
+            .into_iter()
+            .filter_map(|((on_id, key), (statements, example))| {
+                let on_name = humanizer.humanize_id(on_id)?;
+                let arity = self
+                    .catalog
+                    .get_entry_by_global_id(&on_id)
+                    .relation_desc()?
+                    .arity();
+                let col_names = humanizer.column_names_for_id(on_id);
+                let key_arity = key.len();
+                let key = separated(", ", mode.seq(&key, col_names.as_ref()));
+                Some(IndexRecommendation {
+                    create_sql: format!("CREATE INDEX ON {on_name} ({key})"),
+                    on_id,
+                    arity,
+                    key_arity,
+                    statements,
+                    example,
+                    size: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the index keys that would turn the given statement into a fast path peek.
+    fn fast_path_keys(
+        &self,
+        select: &LoggedSelect,
+        session: &dyn SessionMetadata,
+    ) -> Result<Vec<(GlobalId, Vec<MirScalarExpr>)>, AdapterError> {
+        let stmt = mz_sql::parse::parse(&select.sql)?.into_element().ast;
+        if !matches!(stmt, Statement::Select(_)) {
+            return Ok(Vec::new());
+        }
+        let catalog = self
+            .catalog
+            .state()
+            .for_system_session_with_search_path(&select.database, &select.search_path);
+        let (stmt, resolved_ids) = mz_sql::names::resolve(&catalog, stmt)?;
+        let Plan::Select(plan) =
+            mz_sql::plan::plan(None, &catalog, stmt, &Params::empty(), &resolved_ids)?
+        else {
+            return Ok(Vec::new());
+        };
+
+        // HIR ⇒ MIR lowering and MIR optimization (local), which doesn't depend on indexes.
+        let local_mir_plan = self
+            .optimizer(&plan.finishing)
+            .catch_unwind_optimize(plan.source)?;
+
+        // Plans that are fast path by now don't need a new index.
+        let global_lir_plan = self.optimizer(&plan.finishing).catch_unwind_optimize(
+            local_mir_plan.clone().resolve(
+                TimestampContext::NoTimestamp,
+                session,
+                Box::new(EmptyStatisticsOracle),
+            ),
+        )?;
+        if matches!(global_lir_plan.peek_plan(), PeekPlan::FastPath(_)) {
+            return Ok(Vec::new());
+        }
+
+        let mut keys = Vec::new();
+        for (on_id, key) in LiteralConstraints::recommend_index_keys(local_mir_plan.expr())? {
+            if !on_id.is_user() {
+                continue;
+            }
+            let (_, index_id) = self.transient_id_gen.allocate_id();
+            let mut optimizer = self
+                .optimizer(&plan.finishing)
+                .with_hypothetical_indexes(vec![(index_id, on_id, key.clone())]);
+            // Optimization fails for plans that would need to render a dataflow that uses the
+            // hypothetical index.
+            let Ok(global_lir_plan) =
+                optimizer.catch_unwind_optimize(local_mir_plan.clone().resolve(
+                    TimestampContext::NoTimestamp,
+                    session,
+                    Box::new(EmptyStatisticsOracle),
+                ))
+            else {
+                continue;
+            };
+            if let PeekPlan::FastPath(FastPathPlan::PeekExisting(_, idx_id, Some(_), _)) =
+                global_lir_plan.peek_plan()
+            {
+                if *idx_id == index_id {
+                    keys.push((on_id, key));
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    fn optimizer(&self, finishing: &RowSetFinishing) -> optimize::peek::Optimizer {
+        let (_, select_id) = self.transient_id_gen.allocate_id();
+        let (_, index_id) = self.transient_id_gen.allocate_id();
+        optimize::peek::Optimizer::new(
+            Arc::clone(&self.catalog),
+            self.compute_instance.clone(),
+            finishing.clone(),
+            select_id,
+            index_id,
+            self.optimizer_config.clone(),
+            self.optimizer_metrics.clone(),
+        )
+    }
+}
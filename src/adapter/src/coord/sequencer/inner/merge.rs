@@ -0,0 +1,152 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Evaluation of the `WHEN` clauses of a `MERGE` statement, and of the
+//! `ON CONFLICT` clause of an `INSERT` statement.
+
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+use mz_repr::{Datum, DatumVec, Diff, Row, RowArena, RowRef};
+use mz_sql::plan::{MergeActionPlan, MergePlan};
+
+use crate::error::AdapterError;
+
+/// Turns the rows read by a `MERGE` or `INSERT ... ON CONFLICT` into updates
+/// to its target table.
+///
+/// State is kept across calls to [`MergeState::apply`], so that a target row
+/// matched by several source rows is detected even if those source rows are
+/// delivered in different batches.
+#[derive(Debug, Default)]
+pub(super) struct MergeState {
+    /// Target rows that have already been updated or deleted.
+    touched: BTreeSet<Row>,
+    /// The conflict keys of the rows inserted so far, per
+    /// [`MergePlan::conflict_keys`].
+    inserted_keys: BTreeSet<(usize, Row)>,
+    /// The number of target rows inserted, updated, or deleted so far.
+    pub(super) affected_rows: usize,
+}
+
+impl MergeState {
+    /// Applies the first `WHEN` clause of `plan` that matches `row`, pushing
+    /// the resulting updates to `diffs`.
+    ///
+    /// See [`MergePlan`] for the layout of `row`.
+    pub(super) fn apply(
+        &mut self,
+        plan: &MergePlan,
+        row: &RowRef,
+        datum_vec: &mut DatumVec,
+        diffs: &mut Vec<(Row, Diff)>,
+    ) -> Result<(), AdapterError> {
+        let arena = RowArena::new();
+        let datums = datum_vec.borrow_with(row);
+        let (source, target) = datums.split_at(plan.source_arity);
+        let (target, count) = target.split_at(target.len() - 1);
+        let matched = !count[0].is_null();
+
+        if !matched && self.conflicts_with_inserted(plan, source) {
+            // The row conflicts with a row inserted by this statement, which
+            // can be ignored but not updated.
+            let updates = plan.clauses.iter().any(|c| {
+                c.matched
+                    && matches!(
+                        c.action,
+                        MergeActionPlan::Update { .. } | MergeActionPlan::Delete
+                    )
+            });
+            if updates {
+                return Err(Self::affected_twice(plan));
+            }
+            return Ok(());
+        }
+
+        for clause in plan.clauses.iter().filter(|c| c.matched == matched) {
+            if let Some(condition) = &clause.condition {
+                let datum = condition
+                    .eval(&datums, &arena)
+                    .map_err(|e| AdapterError::Unstructured(anyhow!(e)))?;
+                if datum != Datum::True {
+                    continue;
+                }
+            }
+
+            match &clause.action {
+                MergeActionPlan::Update { assignments } => {
+                    let target_row = self.touch(plan, target)?;
+                    let copies = count[0].unwrap_int64();
+                    let mut updated = target.to_vec();
+                    for (idx, expr) in assignments {
+                        updated[*idx] = expr
+                            .eval(&datums, &arena)
+                            .map_err(|e| AdapterError::Unstructured(anyhow!(e)))?;
+                    }
+                    diffs.push((target_row, Diff::from(-copies)));
+                    diffs.push((Row::pack_slice(&updated), Diff::from(copies)));
+                    self.affected_rows += usize::try_from(copies).expect("count is positive");
+                }
+                MergeActionPlan::Delete => {
+                    let target_row = self.touch(plan, target)?;
+                    let copies = count[0].unwrap_int64();
+                    diffs.push((target_row, Diff::from(-copies)));
+                    self.affected_rows += usize::try_from(copies).expect("count is positive");
+                }
+                MergeActionPlan::Insert { values } => {
+                    let values = values
+                        .iter()
+                        .map(|expr| expr.eval(source, &arena))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| AdapterError::Unstructured(anyhow!(e)))?;
+                    diffs.push((Row::pack_slice(&values), Diff::ONE));
+                    self.affected_rows += 1;
+                    for (i, unique) in plan.conflict_keys.iter().enumerate() {
+                        if let Some(key) = unique.key(source) {
+                            self.inserted_keys.insert((i, key));
+                        }
+                    }
+                }
+                MergeActionPlan::DoNothing => {}
+            }
+            break;
+        }
+        Ok(())
+    }
+
+    /// Reports whether the source row conflicts on any of the plan's conflict
+    /// keys with a row inserted earlier. Inserted rows are exactly their
+    /// source rows when there are conflict keys.
+    fn conflicts_with_inserted(&self, plan: &MergePlan, source: &[Datum]) -> bool {
+        plan.conflict_keys.iter().enumerate().any(|(i, unique)| {
+            unique
+                .key(source)
+                .is_some_and(|key| self.inserted_keys.contains(&(i, key)))
+        })
+    }
+
+    /// Records that `target` is being updated or deleted, erroring if it has
+    /// already been.
+    fn touch(&mut self, plan: &MergePlan, target: &[Datum]) -> Result<Row, AdapterError> {
+        let row = Row::pack_slice(target);
+        if !self.touched.insert(row.clone()) {
+            return Err(Self::affected_twice(plan));
+        }
+        Ok(row)
+    }
+
+    fn affected_twice(plan: &MergePlan) -> AdapterError {
+        let command = if plan.conflict_keys.is_empty() {
+            "MERGE"
+        } else {
+            "ON CONFLICT DO UPDATE"
+        };
+        AdapterError::RowAffectedTwice { command }
+    }
+}
@@ -34,6 +34,7 @@ use crate::active_compute_sink::{ActiveComputeSink, ActiveCopyTo};
 use crate::command::ExecuteResponse;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::peek::{self, PeekDataflowPlan, PeekPlan, PlannedPeek};
+use crate::coord::read_write_txns::{read_own_writes, txn_read_set};
 use crate::coord::sequencer::inner::return_if_err;
 use crate::coord::sequencer::{check_log_reads, emit_optimizer_notices, eval_copy_to_uri};
 use crate::coord::timeline::{TimelineContext, timedomain_for};
@@ -163,7 +164,7 @@ impl Coordinator {
         target_cluster: TargetCluster,
     ) {
         let uri = return_if_err!(
-            eval_copy_to_uri(to, ctx.session(), self.catalog().state()),
+            eval_copy_to_uri(to, &connection, ctx.session(), self.catalog().state()),
             ctx
         );
 
@@ -244,7 +245,7 @@ impl Coordinator {
     pub fn peek_validate(
         &self,
         session: &Session,
-        plan: mz_sql::plan::SelectPlan,
+        mut plan: mz_sql::plan::SelectPlan,
         target_cluster: TargetCluster,
         copy_to_ctx: Option<CopyToContext>,
         explain_ctx: ExplainContext,
@@ -327,6 +328,13 @@ impl Coordinator {
             })
             .transpose()?;
 
+        // Reads after writes in a transaction see its pending writes.
+        read_own_writes(
+            self.catalog(),
+            session.transaction().pending_writes(),
+            &mut plan.source,
+        )?;
+
         let source_ids = plan.source.depends_on();
         let mut timeline_context = self
             .catalog()
@@ -937,7 +945,7 @@ impl Coordinator {
                     ComputeSinkConnection::CopyToS3Oneshot(conn) => {
                         mz_storage_types::sinks::s3_oneshot_sink::preflight(
                             connection_context,
-                            &conn.aws_connection,
+                            &conn.connection,
                             &conn.upload_info,
                             conn.connection_id,
                             *sink_id,
@@ -1175,10 +1183,12 @@ impl Coordinator {
         // depend on whether or not reads have occurred in the txn.
         let mut transaction_determination = determination.clone();
         if when.is_transactional() {
+            let read_set = txn_read_set(self.catalog(), session, source_ids);
             session.add_transaction_ops(TransactionOps::Peeks {
                 determination: transaction_determination,
                 cluster_id,
                 requires_linearization,
+                read_set,
             })?;
         } else if matches!(session.transaction(), &TransactionStatus::InTransaction(_)) {
             // If the query uses AS OF, then ignore the timestamp.
@@ -1187,6 +1197,7 @@ impl Coordinator {
                 determination: transaction_determination,
                 cluster_id,
                 requires_linearization,
+                read_set: None,
             })?;
         };
 
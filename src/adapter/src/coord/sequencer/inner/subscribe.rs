@@ -33,7 +33,7 @@ use crate::coord::sequencer::{check_log_reads, emit_optimizer_notices};
 use crate::coord::{
     Coordinator, ExplainContext, ExplainPlanContext, Message, PlanValidity, StageResult, Staged,
     SubscribeExplain, SubscribeFinish, SubscribeOptimizeMir, SubscribeStage,
-    SubscribeTimestampOptimizeLir, TargetCluster,
+    SubscribeTimestampOptimizeLir, TargetCluster, ViewChannel,
 };
 use crate::error::AdapterError;
 use crate::explain::optimizer_trace::OptimizerTrace;
@@ -96,7 +96,8 @@ impl Coordinator {
                 ctx.session_mut(),
                 plan,
                 target_cluster,
-                ExplainContext::None
+                ExplainContext::None,
+                None,
             ),
             ctx
         );
@@ -145,19 +146,20 @@ impl Coordinator {
             optimizer_trace,
         });
         let stage = return_if_err!(
-            self.subscribe_validate(ctx.session_mut(), plan, target_cluster, explain_ctx),
+            self.subscribe_validate(ctx.session_mut(), plan, target_cluster, explain_ctx, None),
             ctx
         );
         self.sequence_staged(ctx, Span::current(), stage).await;
     }
 
     #[instrument]
-    fn subscribe_validate(
+    pub(super) fn subscribe_validate(
         &self,
         session: &mut Session,
         plan: plan::SubscribePlan,
         target_cluster: TargetCluster,
         explain_ctx: ExplainContext,
+        channel: Option<ViewChannel>,
     ) -> Result<SubscribeStage, AdapterError> {
         let plan::SubscribePlan { from, when, .. } = &plan;
 
@@ -237,6 +239,7 @@ impl Coordinator {
             cluster_id,
             replica_id,
             explain_ctx,
+            channel,
         }))
     }
 
@@ -251,6 +254,7 @@ impl Coordinator {
             cluster_id,
             replica_id,
             explain_ctx,
+            channel,
         }: SubscribeOptimizeMir,
     ) -> Result<StageResult<Box<SubscribeStage>>, AdapterError> {
         let plan::SubscribePlan {
@@ -311,6 +315,7 @@ impl Coordinator {
                             dependency_ids,
                             replica_id,
                             explain_ctx,
+                            channel,
                         });
                     Ok(Box::new(stage))
                 })
@@ -331,6 +336,7 @@ impl Coordinator {
             dependency_ids,
             replica_id,
             explain_ctx,
+            channel,
         }: SubscribeTimestampOptimizeLir,
     ) -> Result<StageResult<Box<SubscribeStage>>, AdapterError> {
         let plan::SubscribePlan { when, .. } = &plan;
@@ -402,6 +408,7 @@ impl Coordinator {
                                     global_lir_plan,
                                     dependency_ids,
                                     replica_id,
+                                    channel,
                                 })
                             }
                         }
@@ -442,10 +449,12 @@ impl Coordinator {
             global_lir_plan,
             dependency_ids,
             replica_id,
+            channel,
         }: SubscribeFinish,
     ) -> Result<StageResult<Box<SubscribeStage>>, AdapterError> {
         let (df_desc, df_meta) = global_lir_plan.unapply();
         emit_optimizer_notices(&*self.catalog, ctx.session(), &df_meta.optimizer_notices);
+        let sink_id = df_desc.sink_id();
         let conn_id = ctx.session.conn_id().clone();
         let session_uuid = ctx.session().uuid();
         let txn_read_holds = self
@@ -465,6 +474,19 @@ impl Coordinator {
                 plan,
             )
             .await?;
+        let resp = match channel {
+            None => resp,
+            Some(channel) => {
+                let ExecuteResponse::Subscribing { rx, ctx_extra, .. } = resp else {
+                    unreachable!("channel subscribes are not copied out");
+                };
+                // The statement is done once the channel is bound, so it is
+                // retired with the context rather than with the subscribe.
+                *ctx.extra_mut() = ctx_extra;
+                self.bind_view_channel(ctx.session(), channel, sink_id, rx)
+                    .await?
+            }
+        };
         Ok(StageResult::Response(resp))
     }
 
@@ -0,0 +1,171 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Enforcement of `CHECK` constraints on tables.
+//!
+//! Like keys, `CHECK` constraints are trusted by the optimizer, so they are
+//! enforced in group commit, where every write to a table passes through,
+//! whether it consists of rows or of batches staged by `COPY FROM`. Reading
+//! back staged batches can take a while, so the checks run off the
+//! coordinator's main loop, see [`Coordinator::group_commit`].
+
+use std::collections::BTreeMap;
+
+use mz_catalog::memory::objects::CatalogItem;
+use mz_persist_client::{PersistClient, ShardId};
+use mz_repr::{CatalogItemId, RelationDesc, Row, RowArena};
+use mz_sql::plan::TableCheck;
+use mz_storage_client::client::TableData;
+use tracing::warn;
+
+use crate::AdapterError;
+use crate::coord::Coordinator;
+use crate::coord::appends::PendingWriteTxn;
+use crate::coord::table_keys::read_batches;
+
+/// A table with `CHECK` constraints that is written to in a group commit.
+#[derive(Debug)]
+struct CheckedTable {
+    name: String,
+    desc: RelationDesc,
+    shard_id: ShardId,
+    checks: Vec<TableCheck>,
+}
+
+/// Returns the first of `checks` that `row` violates, if any.
+pub(crate) fn violated_check<'a>(
+    checks: &'a [TableCheck],
+    row: &Row,
+) -> Result<Option<&'a TableCheck>, AdapterError> {
+    let arena = RowArena::new();
+    let datums = row.unpack();
+    for check in checks {
+        if !check.holds(&datums, &arena)? {
+            return Ok(Some(check));
+        }
+    }
+    Ok(None)
+}
+
+/// The tables with `CHECK` constraints written to in a group commit, to
+/// validate off the coordinator's main loop.
+#[derive(Debug)]
+pub(crate) struct CheckValidation {
+    tables: BTreeMap<CatalogItemId, CheckedTable>,
+}
+
+impl Coordinator {
+    /// Returns what needs validating about the `CHECK` constraints of the
+    /// tables written to by `writes`, if anything.
+    pub(crate) fn check_validation(&self, writes: &[PendingWriteTxn]) -> Option<CheckValidation> {
+        let mut tables = BTreeMap::new();
+        for write in writes {
+            let PendingWriteTxn::User { writes, .. } = write else {
+                continue;
+            };
+            for (id, data) in writes {
+                if tables.contains_key(id) || data.iter().all(|d| d.is_empty()) {
+                    continue;
+                }
+                let Some(entry) = self.catalog().try_get_entry(id) else {
+                    continue;
+                };
+                let CatalogItem::Table(table) = entry.item() else {
+                    continue;
+                };
+                if table.checks().is_empty() {
+                    continue;
+                }
+                let gid = entry.latest_global_id();
+                let Ok(metadata) = self.controller.storage_collections.collection_metadata(gid)
+                else {
+                    continue;
+                };
+                let table = CheckedTable {
+                    name: self
+                        .catalog()
+                        .resolve_full_name(entry.name(), None)
+                        .to_string(),
+                    desc: table.desc.latest(),
+                    shard_id: metadata.data_shard,
+                    checks: table.checks().to_vec(),
+                };
+                tables.insert(*id, table);
+            }
+        }
+        (!tables.is_empty()).then_some(CheckValidation { tables })
+    }
+}
+
+impl CheckValidation {
+    /// Returns the position and error of each user transaction in `writes`
+    /// that inserts rows violating the `CHECK` constraints of the tables it
+    /// writes to.
+    pub(crate) async fn validate(
+        self,
+        persist_client: &mut PersistClient,
+        writes: &[PendingWriteTxn],
+    ) -> Vec<(usize, AdapterError)> {
+        let mut violations = Vec::new();
+        for (i, write) in writes.iter().enumerate() {
+            let PendingWriteTxn::User { writes, .. } = write else {
+                continue;
+            };
+            let data = writes
+                .iter()
+                .filter(|(id, _)| self.tables.contains_key(id))
+                .map(|(id, data)| (*id, data.to_vec()))
+                .collect();
+            if let Err(err) = check_write(persist_client, &self.tables, data).await {
+                violations.push((i, err));
+            }
+        }
+        violations
+    }
+}
+
+async fn check_write(
+    persist_client: &mut PersistClient,
+    tables: &BTreeMap<CatalogItemId, CheckedTable>,
+    data: Vec<(CatalogItemId, Vec<TableData>)>,
+) -> Result<(), AdapterError> {
+    for (id, data) in data {
+        let table = &tables[&id];
+        for data in data {
+            let updates = match data {
+                TableData::Rows(rows) => rows
+                    .into_iter()
+                    .map(|(row, diff)| (row, diff.into_inner()))
+                    .collect(),
+                TableData::Batches(batches) => {
+                    // If we cannot read the batches, we cannot accept them.
+                    read_batches(
+                        persist_client,
+                        table.shard_id,
+                        &table.desc,
+                        batches.into_vec(),
+                    )
+                    .await
+                    .inspect_err(|err| warn!("unable to enforce table checks: {err}"))?
+                }
+            };
+            // Retractions cannot introduce a violation.
+            for (row, _diff) in updates.iter().filter(|(_row, diff)| *diff > 0) {
+                if let Some(check) = violated_check(&table.checks, row)? {
+                    return Err(AdapterError::CheckViolation {
+                        table_name: table.name.clone(),
+                        constraint_name: check.name.clone(),
+                        existing_row: false,
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
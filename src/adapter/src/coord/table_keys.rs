@@ -0,0 +1,397 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Enforcement of `PRIMARY KEY` and `UNIQUE` constraints on tables.
+//!
+//! The optimizer trusts the keys declared on a table, so a write that would
+//! leave more than one row with the same key is rejected in group commit,
+//! where the current contents of the table can be read knowing that no other
+//! write to it can sneak in before ours. Reading the tables can take a while,
+//! so this happens off the coordinator's main loop, see
+//! [`Coordinator::group_commit`].
+//!
+//! Only the key values that are written are looked up in the tables, using
+//! the statistics of their parts to skip the parts that cannot contain them.
+//! As in Postgres, rows with a `NULL` in a constraint's columns never conflict
+//! on it, unless the constraint is `NULLS NOT DISTINCT`. Constraints on
+//! columns that may be `NULL` are not keys of the table, but are enforced all
+//! the same.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use mz_catalog::memory::objects::CatalogItem;
+use mz_expr::{MapFilterProject, MirScalarExpr, ResultSpec, func};
+use mz_ore::str::separated;
+use mz_persist_client::batch::ProtoBatch;
+use mz_persist_client::{PersistClient, Schemas, ShardId};
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{CatalogItemId, GlobalId, RelationDesc, ReprScalarType, Row, Timestamp};
+use mz_sql::plan::TableUnique;
+use mz_storage_client::client::TableData;
+use mz_storage_client::storage_collections::{PartFilter, StorageCollections};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::sources::SourceData;
+use mz_storage_types::stats::RelationPartStats;
+use timely::progress::Antichain;
+use tracing::warn;
+
+use crate::AdapterError;
+use crate::coord::Coordinator;
+use crate::coord::appends::PendingWriteTxn;
+
+/// A table with unique constraints that is written to in a group commit.
+#[derive(Debug)]
+struct KeyedTable {
+    name: String,
+    desc: RelationDesc,
+    global_id: GlobalId,
+    shard_id: ShardId,
+    uniques: Vec<TableUnique>,
+}
+
+impl KeyedTable {
+    fn keys(&self) -> &[TableUnique] {
+        &self.uniques
+    }
+
+    /// Packs the values of `key` in `row`, unless the row cannot conflict on
+    /// it.
+    fn key_row(row: &Row, key: &TableUnique) -> Option<Row> {
+        key.key(&row.unpack())
+    }
+
+    /// Returns a filter for the parts of the table that might contain rows
+    /// with one of `key_rows`, given as pairs of the position of a key and
+    /// its values. Returns `None` if there are too many values to bother.
+    fn part_filter<'a>(
+        &self,
+        key_rows: impl Iterator<Item = (usize, &'a Row)>,
+    ) -> Option<PartFilter> {
+        let mut lookups = Vec::new();
+        for (key, key_row) in key_rows {
+            if lookups.len() >= MAX_FILTERED_KEY_ROWS {
+                return None;
+            }
+            let columns = &self.keys()[key].columns;
+            let lookup = columns
+                .iter()
+                .zip(key_row.iter())
+                .map(|(column, datum)| {
+                    if datum.is_null() {
+                        return MirScalarExpr::column(*column).call_is_null();
+                    }
+                    let typ =
+                        ReprScalarType::from(&self.desc.typ().column_types[*column].scalar_type);
+                    MirScalarExpr::column(*column)
+                        .call_binary(MirScalarExpr::literal_ok(datum, typ), func::Eq)
+                })
+                .reduce(MirScalarExpr::and)?;
+            lookups.push(lookup);
+        }
+        let predicate = lookups.into_iter().reduce(MirScalarExpr::or)?;
+        let mfp = MapFilterProject::new(self.desc.arity()).filter([predicate]);
+        Some(Arc::new(move |stats: &RelationPartStats| {
+            stats.may_match_mfp(ResultSpec::value_all(), &mfp)
+        }))
+    }
+
+    fn violation(&self, key: &TableUnique, key_row: &Row) -> AdapterError {
+        let columns = key.columns.iter().map(|i| self.desc.get_name(*i).as_str());
+        AdapterError::UniqueViolation {
+            table_name: self.name.clone(),
+            columns: separated(", ", columns).to_string(),
+            values: separated(", ", key_row.iter()).to_string(),
+        }
+    }
+}
+
+/// The number of rows per key value, per key, per table.
+type KeyCounts = BTreeMap<(CatalogItemId, usize, Row), StorageDiff>;
+
+/// The largest number of key values that we look up using the statistics of
+/// the parts of a table, rather than by reading all of them.
+const MAX_FILTERED_KEY_ROWS: usize = 1024;
+
+/// The tables with keys written to in a group commit, to validate off the
+/// coordinator's main loop.
+#[derive(Debug)]
+pub(crate) struct KeyValidation {
+    tables: BTreeMap<CatalogItemId, KeyedTable>,
+}
+
+impl Coordinator {
+    /// Returns what needs validating about the keys of the tables written to
+    /// by `writes`, if anything.
+    pub(crate) fn key_validation(&self, writes: &[PendingWriteTxn]) -> Option<KeyValidation> {
+        if !self
+            .catalog()
+            .system_config()
+            .enable_table_key_enforcement()
+        {
+            return None;
+        }
+
+        let mut tables = BTreeMap::new();
+        for write in writes {
+            let PendingWriteTxn::User { writes, .. } = write else {
+                continue;
+            };
+            for (id, data) in writes {
+                if tables.contains_key(id) || data.iter().all(|d| d.is_empty()) {
+                    continue;
+                }
+                let Some(entry) = self.catalog().try_get_entry(id) else {
+                    continue;
+                };
+                let CatalogItem::Table(table) = entry.item() else {
+                    continue;
+                };
+                if table.uniques().is_empty() {
+                    continue;
+                }
+                let gid = entry.latest_global_id();
+                let Ok(metadata) = self.controller.storage_collections.collection_metadata(gid)
+                else {
+                    continue;
+                };
+                let table = KeyedTable {
+                    name: self
+                        .catalog()
+                        .resolve_full_name(entry.name(), None)
+                        .to_string(),
+                    desc: table.desc.latest(),
+                    global_id: gid,
+                    shard_id: metadata.data_shard,
+                    uniques: table.uniques().to_vec(),
+                };
+                tables.insert(*id, table);
+            }
+        }
+        (!tables.is_empty()).then_some(KeyValidation { tables })
+    }
+}
+
+impl KeyValidation {
+    /// Returns the position and error of each user transaction in `writes`
+    /// that would violate the keys of the tables it writes to, given their
+    /// contents as of `read_ts` and the transactions before it in `writes`.
+    ///
+    /// The caller must hold the write locks of all tables written to, so that
+    /// their contents as of `read_ts` are their contents as of our write.
+    pub(crate) async fn validate(
+        self,
+        persist_client: &mut PersistClient,
+        storage_collections: &(dyn StorageCollections + Send + Sync),
+        read_ts: Timestamp,
+        writes: &[PendingWriteTxn],
+    ) -> Vec<(usize, AdapterError)> {
+        let mut checked = Vec::new();
+        for (i, write) in writes.iter().enumerate() {
+            if let PendingWriteTxn::User { writes, .. } = write {
+                let data: Vec<_> = writes
+                    .iter()
+                    .filter(|(id, _)| self.tables.contains_key(id))
+                    .map(|(id, data)| (*id, data.to_vec()))
+                    .collect();
+                if !data.is_empty() {
+                    checked.push((i, data));
+                }
+            }
+        }
+        let checked_positions: Vec<_> = checked.iter().map(|(i, _)| *i).collect();
+
+        let check = check_table_keys(
+            persist_client,
+            storage_collections,
+            read_ts,
+            &self.tables,
+            checked,
+        );
+        match check.await {
+            Ok(violations) => violations,
+            Err(err) => {
+                // If we cannot read the tables, we cannot accept any write
+                // that might violate their keys.
+                warn!("unable to enforce table keys: {err}");
+                checked_positions
+                    .into_iter()
+                    .map(|i| (i, AdapterError::Internal(err.to_string())))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Checks the writes of each transaction in `writes`, in order, against the
+/// contents of the tables as of `read_ts` and the writes of the accepted
+/// transactions before it. Returns the position and error of each rejected
+/// transaction.
+async fn check_table_keys(
+    persist_client: &mut PersistClient,
+    storage_collections: &(dyn StorageCollections + Send + Sync),
+    read_ts: Timestamp,
+    tables: &BTreeMap<CatalogItemId, KeyedTable>,
+    writes: Vec<(usize, Vec<(CatalogItemId, Vec<TableData>)>)>,
+) -> Result<Vec<(usize, AdapterError)>, AdapterError> {
+    let mut deltas = Vec::with_capacity(writes.len());
+    for (i, data) in writes {
+        let mut delta = KeyCounts::new();
+        for (id, data) in data {
+            let table = &tables[&id];
+            for data in data {
+                let updates = match data {
+                    TableData::Rows(rows) => rows
+                        .into_iter()
+                        .map(|(row, diff)| (row, diff.into_inner()))
+                        .collect(),
+                    TableData::Batches(batches) => {
+                        read_batches(
+                            persist_client,
+                            table.shard_id,
+                            &table.desc,
+                            batches.into_vec(),
+                        )
+                        .await?
+                    }
+                };
+                for (row, diff) in updates {
+                    add_key_counts(&mut delta, id, table, &row, diff);
+                }
+            }
+        }
+        deltas.push((i, delta));
+    }
+
+    // Count the rows of the tables with the key values that are written.
+    let written: BTreeSet<_> = deltas.iter().flat_map(|(_i, delta)| delta.keys()).collect();
+    let mut counts = KeyCounts::new();
+    for (id, table) in tables {
+        let key_rows: Vec<_> = written
+            .iter()
+            .filter(|(key_id, _, _)| key_id == id)
+            .map(|(_id, key, key_row)| (*key, key_row))
+            .collect();
+        if key_rows.is_empty() {
+            continue;
+        }
+        let mut cursor = match table.part_filter(key_rows.into_iter()) {
+            Some(filter) => {
+                storage_collections
+                    .snapshot_cursor_filtered(table.global_id, read_ts, filter)
+                    .await?
+            }
+            None => {
+                storage_collections
+                    .snapshot_cursor(table.global_id, read_ts)
+                    .await?
+            }
+        };
+        while let Some(updates) = cursor.next().await {
+            for (source_data, _ts, diff) in updates {
+                let row = source_data
+                    .0
+                    .map_err(|e| AdapterError::Internal(format!("invalid table row: {e}")))?;
+                for (key, unique) in table.keys().iter().enumerate() {
+                    if let Some(key_row) = KeyedTable::key_row(&row, unique) {
+                        let k = (*id, key, key_row);
+                        if written.contains(&k) {
+                            *counts.entry(k).or_default() += diff;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut violations = Vec::new();
+    for (i, delta) in deltas {
+        let violation = delta.iter().find_map(|((id, key, key_row), diff)| {
+            let count = counts
+                .get(&(*id, *key, key_row.clone()))
+                .copied()
+                .unwrap_or(0);
+            (count + diff > 1).then(|| {
+                let table = &tables[id];
+                table.violation(&table.keys()[*key], key_row)
+            })
+        });
+        match violation {
+            Some(err) => violations.push((i, err)),
+            None => {
+                for (k, diff) in delta {
+                    *counts.entry(k).or_default() += diff;
+                }
+            }
+        }
+    }
+    Ok(violations)
+}
+
+fn add_key_counts(
+    counts: &mut KeyCounts,
+    id: CatalogItemId,
+    table: &KeyedTable,
+    row: &Row,
+    diff: StorageDiff,
+) {
+    for (i, key) in table.keys().iter().enumerate() {
+        if let Some(key_row) = KeyedTable::key_row(row, key) {
+            *counts.entry((id, i, key_row)).or_default() += diff;
+        }
+    }
+}
+
+/// Reads the updates in batches staged for the table with data shard
+/// `shard_id` and description `desc`, e.g. by `COPY FROM`.
+pub(super) async fn read_batches(
+    persist_client: &mut PersistClient,
+    shard_id: ShardId,
+    desc: &RelationDesc,
+    batches: Vec<ProtoBatch>,
+) -> Result<Vec<(Row, StorageDiff)>, AdapterError> {
+    let batches = batches
+        .into_iter()
+        .map(|batch| persist_client.batch_from_transmittable_batch(&shard_id, batch))
+        .collect();
+    let read_schemas: Schemas<SourceData, ()> = Schemas {
+        id: None,
+        key: Arc::new(desc.clone()),
+        val: Arc::new(UnitSchema),
+    };
+    let mut cursor = persist_client
+        .read_batches_consolidated::<SourceData, (), Timestamp, StorageDiff>(
+            shard_id,
+            Antichain::from_elem(Timestamp::MIN),
+            read_schemas,
+            batches,
+            |_stats| true,
+            usize::MAX,
+        )
+        .await
+        .map_err(|since| {
+            AdapterError::Internal(format!("staged batches not readable: {since:?}"))
+        })?;
+
+    let mut updates = Vec::new();
+    while let Some(part) = cursor.next().await {
+        for ((source_data, _val), _ts, diff) in part {
+            let row = source_data
+                .0
+                .map_err(|e| AdapterError::Internal(format!("invalid staged row: {e}")))?;
+            updates.push((row, diff));
+        }
+    }
+    // The batches still belong to the write, which appends them later, so we
+    // must not delete them.
+    for batch in cursor.into_lease() {
+        let _ = batch.into_transmittable_batch();
+    }
+    Ok(updates)
+}
@@ -61,6 +61,8 @@ pub enum AdapterError {
     /// TODO(ggevay): we should refactor 2. usages to use `ConcurrentDependencyDrop` instead
     /// (e.g., in MV sequencing)
     ChangedPlan(String),
+    /// The channel is already bound to a view.
+    DuplicateChannel(String),
     /// The cursor already exists.
     DuplicateCursor(String),
     /// An error while evaluating an expression.
@@ -108,6 +110,20 @@ pub enum AdapterError {
     },
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
+    /// A `MERGE` or `INSERT ... ON CONFLICT DO UPDATE` would update or delete
+    /// the same row more than once.
+    RowAffectedTwice {
+        /// The command, e.g. `MERGE`.
+        command: &'static str,
+    },
+    /// A row violates a table's CHECK constraint.
+    CheckViolation {
+        table_name: String,
+        constraint_name: String,
+        /// Whether the row was already present in the table, i.e. the
+        /// violation was found while adding the constraint.
+        existing_row: bool,
+    },
     /// An error occurred while decoding COPY data.
     CopyFormatError(String),
     /// Transaction cluster was dropped in the middle of a transaction.
@@ -159,6 +175,23 @@ pub enum AdapterError {
     ResultSize(String),
     /// The specified feature is not permitted in safe mode.
     SafeModeViolation(String),
+    /// `currval` was called on a sequence that `nextval` has not yet been
+    /// called on in this session.
+    SequenceCurrvalUndefined(String),
+    /// A sequence that doesn't cycle ran out of values.
+    SequenceExhausted {
+        sequence_name: String,
+        /// Whether the sequence reached its maximum, as opposed to its minimum.
+        ascending: bool,
+        limit: i64,
+    },
+    /// `setval` was called with a value outside of the range of a sequence.
+    SequenceValueOutOfBounds {
+        sequence_name: String,
+        value: i64,
+        min_value: i64,
+        max_value: i64,
+    },
     /// The current transaction had the wrong set of write locks.
     WrongSetOfLocks,
     /// Waiting on a query timed out.
@@ -180,6 +213,14 @@ pub enum AdapterError {
     },
     /// A user tried to perform an action that they were unauthorized to do.
     Unauthorized(rbac::UnauthorizedError),
+    /// A write would leave a table with more than one row with the same key.
+    UniqueViolation {
+        table_name: String,
+        columns: String,
+        values: String,
+    },
+    /// The named channel is not bound to a view by the session.
+    UnknownChannel(String),
     /// The named cursor does not exist.
     UnknownCursor(String),
     /// The named role does not exist.
@@ -217,6 +258,11 @@ pub enum AdapterError {
     DDLOnlyTransaction,
     /// Another session modified the Catalog while this transaction was open.
     DDLTransactionRace,
+    /// Another session wrote to a table that this read-write transaction read
+    /// since it read it.
+    ReadWriteTransactionConflict {
+        table_name: String,
+    },
     /// An error occurred in the storage layer
     Storage(mz_storage_types::controller::StorageError),
     /// An error occurred in the compute layer
@@ -306,6 +352,9 @@ impl AdapterError {
             }
             AdapterError::Catalog(c) => c.detail(),
             AdapterError::Eval(e) => e.detail(),
+            AdapterError::UniqueViolation {
+                columns, values, ..
+            } => Some(format!("Key ({columns})=({values}) already exists.")),
             AdapterError::RelationOutsideTimeDomain { relations, names } => Some(format!(
                 "The following relations in the query are outside the transaction's time domain:\n{}\n{}",
                 relations
@@ -532,6 +581,9 @@ impl AdapterError {
                     .into(),
             ),
             AdapterError::PlanError(e) => e.hint(),
+            AdapterError::ReadWriteTransactionConflict { .. } => {
+                Some("The transaction might succeed if retried.".into())
+            }
             AdapterError::UnallowedOnCluster { cluster, .. } => {
                 (cluster != MZ_CATALOG_SERVER_CLUSTER.name).then(||
                     "Use `SET CLUSTER = <cluster-name>` to change your cluster and re-run the query."
@@ -556,6 +608,10 @@ impl AdapterError {
             AdapterError::CollectionUnreadable { .. } => Some(
                 "This could be because the collection has recently been dropped.".into()
             ),
+            AdapterError::ReadWriteTransactionConflict { table_name } => Some(format!(
+                "Table {} was written after this transaction read it.",
+                table_name.quoted()
+            )),
             _ => None,
         }
     }
@@ -584,6 +640,7 @@ impl AdapterError {
                 _ => SqlState::INTERNAL_ERROR,
             },
             AdapterError::ChangedPlan(_) => SqlState::FEATURE_NOT_SUPPORTED,
+            AdapterError::DuplicateChannel(_) => SqlState::DUPLICATE_OBJECT,
             AdapterError::DuplicateCursor(_) => SqlState::DUPLICATE_CURSOR,
             AdapterError::Eval(EvalError::CharacterNotValidForEncoding(_)) => {
                 SqlState::PROGRAM_LIMIT_EXCEEDED
@@ -592,6 +649,7 @@ impl AdapterError {
                 SqlState::PROGRAM_LIMIT_EXCEEDED
             }
             AdapterError::Eval(EvalError::LengthTooLarge) => SqlState::PROGRAM_LIMIT_EXCEEDED,
+            AdapterError::Eval(EvalError::WasmError(_)) => SqlState::EXTERNAL_ROUTINE_EXCEPTION,
             AdapterError::Eval(EvalError::NullCharacterNotPermitted) => {
                 SqlState::PROGRAM_LIMIT_EXCEEDED
             }
@@ -610,6 +668,8 @@ impl AdapterError {
                 SqlState::INVALID_TRANSACTION_STATE
             }
             AdapterError::ConstraintViolation(NotNullViolation(_)) => SqlState::NOT_NULL_VIOLATION,
+            AdapterError::CheckViolation { .. } => SqlState::CHECK_VIOLATION,
+            AdapterError::RowAffectedTwice { .. } => SqlState::CARDINALITY_VIOLATION,
             AdapterError::CopyFormatError(_) => SqlState::BAD_COPY_FILE_FORMAT,
             AdapterError::ConcurrentClusterDrop => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ConcurrentDependencyDrop { .. } => SqlState::UNDEFINED_OBJECT,
@@ -644,6 +704,9 @@ impl AdapterError {
             AdapterError::ResourceExhaustion { .. } => SqlState::INSUFFICIENT_RESOURCES,
             AdapterError::ResultSize(_) => SqlState::OUT_OF_MEMORY,
             AdapterError::SafeModeViolation(_) => SqlState::INTERNAL_ERROR,
+            AdapterError::SequenceCurrvalUndefined(_) => SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE,
+            AdapterError::SequenceExhausted { .. } => SqlState::SEQUENCE_GENERATOR_LIMIT_EXCEEDED,
+            AdapterError::SequenceValueOutOfBounds { .. } => SqlState::NUMERIC_VALUE_OUT_OF_RANGE,
             AdapterError::SubscribeOnlyTransaction => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::Optimizer(e) => match e {
                 OptimizerError::PlanError(PlanError::InvalidSchemaName) => {
@@ -680,6 +743,8 @@ impl AdapterError {
                 SqlState::S_R_E_PROHIBITED_SQL_STATEMENT_ATTEMPTED
             }
             AdapterError::Unauthorized(_) => SqlState::INSUFFICIENT_PRIVILEGE,
+            AdapterError::UniqueViolation { .. } => SqlState::UNIQUE_VIOLATION,
+            AdapterError::UnknownChannel(_) => SqlState::UNDEFINED_OBJECT,
             AdapterError::UnknownCursor(_) => SqlState::INVALID_CURSOR_NAME,
             AdapterError::UnknownPreparedStatement(_) => SqlState::UNDEFINED_PSTATEMENT,
             AdapterError::UnknownLoginRole(_) => SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
@@ -690,6 +755,9 @@ impl AdapterError {
             AdapterError::Unstructured(_) => SqlState::INTERNAL_ERROR,
             AdapterError::UntargetedLogRead { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::DDLTransactionRace => SqlState::T_R_SERIALIZATION_FAILURE,
+            AdapterError::ReadWriteTransactionConflict { .. } => {
+                SqlState::T_R_SERIALIZATION_FAILURE
+            }
             // It's not immediately clear which error code to use here because a
             // "write-only transaction", "single table write transaction", or "ddl only
             // transaction" are not things in Postgres. This error code is the generic "bad txn
@@ -876,6 +944,9 @@ impl fmt::Display for AdapterError {
             }
             AdapterError::ChangedPlan(e) => write!(f, "{}", e),
             AdapterError::Catalog(e) => e.fmt(f),
+            AdapterError::DuplicateChannel(name) => {
+                write!(f, "channel {} is already bound to a view", name.quoted())
+            }
             AdapterError::DuplicateCursor(name) => {
                 write!(f, "cursor {} already exists", name.quoted())
             }
@@ -921,6 +992,29 @@ impl fmt::Display for AdapterError {
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
+            AdapterError::RowAffectedTwice { command } => {
+                write!(f, "{command} command cannot affect row a second time")
+            }
+            AdapterError::CheckViolation {
+                table_name,
+                constraint_name,
+                existing_row: false,
+            } => write!(
+                f,
+                "new row for relation {} violates check constraint {}",
+                table_name.quoted(),
+                constraint_name.quoted()
+            ),
+            AdapterError::CheckViolation {
+                table_name,
+                constraint_name,
+                existing_row: true,
+            } => write!(
+                f,
+                "check constraint {} of relation {} is violated by some row",
+                constraint_name.quoted(),
+                table_name.quoted()
+            ),
             AdapterError::CopyFormatError(e) => write!(f, "{e}"),
             AdapterError::ConcurrentClusterDrop => {
                 write!(f, "the transaction's active cluster has been dropped")
@@ -998,6 +1092,31 @@ impl fmt::Display for AdapterError {
             AdapterError::SafeModeViolation(feature) => {
                 write!(f, "cannot create {} in safe mode", feature)
             }
+            AdapterError::SequenceCurrvalUndefined(sequence_name) => write!(
+                f,
+                "currval of sequence {} is not yet defined in this session",
+                sequence_name.quoted()
+            ),
+            AdapterError::SequenceExhausted {
+                sequence_name,
+                ascending,
+                limit,
+            } => write!(
+                f,
+                "nextval: reached {} value of sequence {} ({limit})",
+                if *ascending { "maximum" } else { "minimum" },
+                sequence_name.quoted()
+            ),
+            AdapterError::SequenceValueOutOfBounds {
+                sequence_name,
+                value,
+                min_value,
+                max_value,
+            } => write!(
+                f,
+                "setval: value {value} is out of bounds for sequence {} ({min_value}..{max_value})",
+                sequence_name.quoted()
+            ),
             AdapterError::SubscribeOnlyTransaction => {
                 f.write_str("SUBSCRIBE in transactions must be the only read statement")
             }
@@ -1016,6 +1135,14 @@ impl fmt::Display for AdapterError {
             AdapterError::Unauthorized(unauthorized) => {
                 write!(f, "{unauthorized}")
             }
+            AdapterError::UniqueViolation { table_name, .. } => write!(
+                f,
+                "duplicate key value violates unique constraint on table {}",
+                table_name.quoted()
+            ),
+            AdapterError::UnknownChannel(name) => {
+                write!(f, "channel {} is not bound to a view", name.quoted())
+            }
             AdapterError::UnknownCursor(name) => {
                 write!(f, "cursor {} does not exist", name.quoted())
             }
@@ -1049,6 +1176,9 @@ impl fmt::Display for AdapterError {
             AdapterError::DDLTransactionRace => f.write_str(
                 "another session modified the catalog while this DDL transaction was open",
             ),
+            AdapterError::ReadWriteTransactionConflict { .. } => {
+                f.write_str("could not serialize access due to concurrent update")
+            }
             AdapterError::Storage(e) => e.fmt(f),
             AdapterError::Compute(e) => e.fmt(f),
             AdapterError::Orchestrator(e) => e.fmt(f),
@@ -45,6 +45,7 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 use crate::catalog::Catalog;
 use crate::command::Command;
 use crate::coord::peek::{FastPathPlan, PeekPlan};
+use crate::coord::read_write_txns::txn_read_set;
 use crate::coord::sequencer::{eval_copy_to_uri, statistics_oracle};
 use crate::coord::timeline::timedomain_for;
 use crate::coord::timestamp_selection::TimestampDetermination;
@@ -193,6 +194,13 @@ impl PeekClient {
             }
         }
 
+        // Reads after writes in a transaction must see its pending writes,
+        // which only the old peek sequencing supports.
+        if !session.transaction().pending_writes().is_empty() {
+            debug!("Bailing out from try_frontend_peek, because the transaction has writes");
+            return Ok(None);
+        }
+
         // Set up statement logging, and log the beginning of execution.
         // (But only if we're not executing in the context of another statement.)
         let statement_logging_id = if outer_ctx_extra.is_none() {
@@ -374,7 +382,7 @@ impl PeekClient {
                 format,
                 max_file_size,
             }) => {
-                let uri = eval_copy_to_uri(to.clone(), session, catalog.state())?;
+                let uri = eval_copy_to_uri(to.clone(), connection, session, catalog.state())?;
 
                 // (output_batch_count will be set later)
                 let copy_to_ctx = CopyToContext {
@@ -795,10 +803,12 @@ impl PeekClient {
             }
             QueryPlan::Select(..) | QueryPlan::CopyTo(..) => {
                 if when.is_transactional() {
+                    let read_set = txn_read_set(&catalog, session, &source_ids);
                     session.add_transaction_ops(TransactionOps::Peeks {
                         determination: transaction_determination,
                         cluster_id: target_cluster_id,
                         requires_linearization,
+                        read_set,
                     })?;
                 } else if matches!(session.transaction(), &TransactionStatus::InTransaction(_)) {
                     // If the query uses AS OF, then ignore the timestamp.
@@ -807,6 +817,7 @@ impl PeekClient {
                         determination: transaction_determination,
                         cluster_id: target_cluster_id,
                         requires_linearization,
+                        read_set: None,
                     })?;
                 }
             }
@@ -84,6 +84,9 @@ pub enum AdapterNotice {
     EqualSubscribeBounds {
         bound: mz_repr::Timestamp,
     },
+    UnknownChannel {
+        name: String,
+    },
     QueryTrace {
         trace_id: opentelemetry::trace::TraceId,
     },
@@ -176,6 +179,7 @@ impl AdapterNotice {
             AdapterNotice::DroppedActiveCluster { .. } => Severity::Notice,
             AdapterNotice::QueryTimestamp { .. } => Severity::Notice,
             AdapterNotice::EqualSubscribeBounds { .. } => Severity::Notice,
+            AdapterNotice::UnknownChannel { .. } => Severity::Notice,
             AdapterNotice::QueryTrace { .. } => Severity::Notice,
             AdapterNotice::UnimplementedIsolationLevel { .. } => Severity::Notice,
             AdapterNotice::StrongSessionSerializable => Severity::Notice,
@@ -190,6 +194,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::ColumnAlreadyExists { .. } => Severity::Notice,
+                PlanNotice::ConstraintDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => Severity::Notice,
             },
@@ -282,6 +287,7 @@ impl AdapterNotice {
             AdapterNotice::DroppedActiveCluster { .. } => SqlState::from_code("MZ003"),
             AdapterNotice::QueryTimestamp { .. } => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::EqualSubscribeBounds { .. } => SqlState::SUCCESSFUL_COMPLETION,
+            AdapterNotice::UnknownChannel { .. } => SqlState::UNDEFINED_OBJECT,
             AdapterNotice::QueryTrace { .. } => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::UnimplementedIsolationLevel { .. } => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::StrongSessionSerializable => SqlState::SUCCESSFUL_COMPLETION,
@@ -296,6 +302,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::ColumnAlreadyExists { .. } => SqlState::DUPLICATE_COLUMN,
+                PlanNotice::ConstraintDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => {
                     SqlState::WARNING_DEPRECATED_FEATURE
@@ -390,6 +397,13 @@ impl fmt::Display for AdapterNotice {
                     "subscribe as of {bound} (inclusive) up to the same bound {bound} (exclusive) is guaranteed to be empty"
                 )
             }
+            AdapterNotice::UnknownChannel { name } => {
+                write!(
+                    f,
+                    "channel {} is not bound to a view, skipping",
+                    name.quoted()
+                )
+            }
             AdapterNotice::QueryTrace { trace_id } => {
                 write!(f, "trace id: {}", trace_id)
             }
@@ -75,7 +75,7 @@ use mz_repr::adt::timestamp::TimestampError;
 use mz_repr::optimize::{OptimizerFeatureOverrides, OptimizerFeatures, OverrideFrom};
 use mz_repr::{CatalogItemId, GlobalId};
 use mz_sql::names::{FullItemName, QualifiedItemName};
-use mz_sql::plan::PlanError;
+use mz_sql::plan::{PlanError, TableCheck};
 use mz_sql::session::vars::SystemVars;
 use mz_transform::{MaybeShouldPanic, TransformCtx, TransformError};
 
@@ -224,6 +224,7 @@ impl From<&OptimizerConfig> for mz_sql::plan::HirToMirConfig {
         Self {
             enable_new_outer_join_lowering: config.features.enable_new_outer_join_lowering,
             enable_variadic_left_join_lowering: config.features.enable_variadic_left_join_lowering,
+            enable_native_outer_join: config.features.enable_native_outer_join,
             enable_guard_subquery_tablefunc: config.features.enable_guard_subquery_tablefunc,
             enable_cast_elimination: config.features.enable_cast_elimination,
             enable_simplify_quantified_comparisons: config
@@ -252,6 +253,10 @@ pub trait OptimizerCatalog: Debug + Send + Sync {
         id: GlobalId,
         cluster: ClusterId,
     ) -> Box<dyn Iterator<Item = (GlobalId, &Index)> + '_>;
+
+    /// Returns the `CHECK` constraints of the table whose latest version is
+    /// identified by `id`, or nothing if `id` does not identify such a table.
+    fn get_table_checks(&self, id: &GlobalId) -> &[TableCheck];
 }
 
 // OptimizerError
@@ -26,6 +26,7 @@ use mz_sql::plan::HirRelationExpr;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_storage_types::connections::Connection;
 use mz_storage_types::sinks::S3UploadInfo;
+use mz_storage_types::sinks::s3_oneshot_sink::CopyToConnection;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::normalize_lets::normalize_lets;
 use mz_transform::typecheck::{SharedTypecheckingContext, empty_typechecking_context};
@@ -244,33 +245,32 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
         )?;
         df_builder.maybe_reoptimize_imported_views(&mut df_desc, &self.config)?;
 
-        // Creating an S3 sink as currently only s3 sinks are supported. It
-        // might be possible in the future for COPY TO to write to different
-        // sinks, which should be set here depending upon the url scheme.
-        let connection = match &self.copy_to_context.connection {
-            Connection::Aws(aws_connection) => {
-                ComputeSinkConnection::CopyToS3Oneshot(CopyToS3OneshotSinkConnection {
-                    upload_info: S3UploadInfo {
-                        uri: self.copy_to_context.uri.to_string(),
-                        max_file_size: self.copy_to_context.max_file_size,
-                        desc: self.copy_to_context.desc.clone(),
-                        format: self.copy_to_context.format.clone(),
-                    },
-                    aws_connection: aws_connection.clone(),
-                    connection_id: self.copy_to_context.connection_id,
-                    output_batch_count: self
-                        .copy_to_context
-                        .output_batch_count
-                        .expect("output_batch_count should be set in sequencer"),
-                })
-            }
+        // Both S3 and GCS are written by the same oneshot sink, which picks
+        // the object store client based on the connection.
+        let copy_to_connection = match &self.copy_to_context.connection {
+            Connection::Aws(aws_connection) => CopyToConnection::Aws(aws_connection.clone()),
+            Connection::Gcs(gcs_connection) => CopyToConnection::Gcs(gcs_connection.clone()),
             _ => {
-                // Currently only s3 sinks are supported. It was already validated in planning that this
-                // is an aws connection.
-                let msg = "only aws connection is supported in COPY TO";
+                // It was already validated in planning that this is an AWS or
+                // GCS connection.
+                let msg = "only aws and gcs connections are supported in COPY TO";
                 return Err(OptimizerError::Internal(msg.to_string()));
             }
         };
+        let connection = ComputeSinkConnection::CopyToS3Oneshot(CopyToS3OneshotSinkConnection {
+            upload_info: S3UploadInfo {
+                uri: self.copy_to_context.uri.to_string(),
+                max_file_size: self.copy_to_context.max_file_size,
+                desc: self.copy_to_context.desc.clone(),
+                format: self.copy_to_context.format.clone(),
+            },
+            connection: copy_to_connection,
+            connection_id: self.copy_to_context.connection_id,
+            output_batch_count: self
+                .copy_to_context
+                .output_batch_count
+                .expect("output_batch_count should be set in sequencer"),
+        });
         let sink_description = ComputeSinkDesc {
             from_desc: self.copy_to_context.desc.clone(),
             from: self.select_id,
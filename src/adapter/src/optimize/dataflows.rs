@@ -329,6 +329,7 @@ impl<'a> DataflowBuilder<'a> {
                     let index_desc = IndexDesc {
                         on_id: *id,
                         key: idx.keys.to_vec(),
+                        spill_to_disk: idx.spill_to_disk,
                     };
                     let entry = self.catalog.get_entry(id);
                     let desc = entry
@@ -374,6 +375,7 @@ impl<'a> DataflowBuilder<'a> {
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
+                    | CatalogItem::Sequence(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::Connection(_) => {
                         // Non-importable thing; can't get here.
@@ -550,6 +552,7 @@ impl<'a> DataflowBuilder<'a> {
                     self.monotonic_object_inner(*on, memo, features)
                 }
                 CatalogItem::Secret(_)
+                | CatalogItem::Sequence(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Connection(_)
                 | CatalogItem::Log(_)
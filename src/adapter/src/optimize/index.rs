@@ -90,12 +90,23 @@ pub struct Index {
     name: QualifiedItemName,
     on: GlobalId,
     keys: Vec<mz_expr::MirScalarExpr>,
+    spill_to_disk: bool,
 }
 
 impl Index {
     /// Construct a new [`Index`]. Arguments are recorded as-is.
-    pub fn new(name: QualifiedItemName, on: GlobalId, keys: Vec<mz_expr::MirScalarExpr>) -> Self {
-        Self { name, on, keys }
+    pub fn new(
+        name: QualifiedItemName,
+        on: GlobalId,
+        keys: Vec<mz_expr::MirScalarExpr>,
+        spill_to_disk: bool,
+    ) -> Self {
+        Self {
+            name,
+            on,
+            keys,
+            spill_to_disk,
+        }
     }
 }
 
@@ -160,6 +171,7 @@ impl Optimize<Index> for Optimizer {
         let index_desc = IndexDesc {
             on_id: index.on,
             key: index.keys.clone(),
+            spill_to_disk: index.spill_to_disk,
         };
         df_desc.export_index(
             self.exported_index_id,
@@ -178,7 +190,7 @@ impl Optimize<Index> for Optimizer {
         let mut df_meta = DataflowMetainfo::default();
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
-            &mz_transform::EmptyStatisticsOracle, // TODO: wire proper stats
+            &mz_transform::EmptyStatisticsOracle, // stats only inform one-shot peeks
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
@@ -293,7 +293,7 @@ impl Optimize<LocalMirPlan> for Optimizer {
         // Construct TransformCtx for global optimization.
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
-            &mz_transform::EmptyStatisticsOracle, // TODO: wire proper stats
+            &mz_transform::EmptyStatisticsOracle, // stats only inform one-shot peeks
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
@@ -26,7 +26,7 @@ use mz_sql::session::metadata::SessionMetadata;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::normalize_lets::normalize_lets;
 use mz_transform::typecheck::{SharedTypecheckingContext, empty_typechecking_context};
-use mz_transform::{StatisticsOracle, TransformCtx};
+use mz_transform::{HypotheticalIndexOracle, StatisticsOracle, TransformCtx};
 use timely::progress::Antichain;
 use tracing::debug_span;
 
@@ -59,6 +59,12 @@ pub struct Optimizer {
     config: OptimizerConfig,
     /// Optimizer metrics.
     metrics: OptimizerMetrics,
+    /// Indexes that don't exist, but that the optimizer should assume to exist,
+    /// as triples of index id, indexed collection id, and key.
+    ///
+    /// Plans that use these indexes must not be rendered, so optimization
+    /// fails if it does not result in a fast path plan.
+    hypothetical_indexes: Vec<(GlobalId, GlobalId, Vec<MirScalarExpr>)>,
     /// The time spent performing optimization so far.
     duration: Duration,
 }
@@ -82,10 +88,21 @@ impl Optimizer {
             index_id,
             config,
             metrics,
+            hypothetical_indexes: Vec::new(),
             duration: Default::default(),
         }
     }
 
+    /// Makes the optimizer assume that the given indexes exist, in addition to
+    /// the indexes on the cluster. See [`Optimizer::hypothetical_indexes`].
+    pub fn with_hypothetical_indexes(
+        mut self,
+        hypothetical_indexes: Vec<(GlobalId, GlobalId, Vec<MirScalarExpr>)>,
+    ) -> Self {
+        self.hypothetical_indexes = hypothetical_indexes;
+        self
+    }
+
     pub fn cluster_id(&self) -> ComputeInstanceId {
         self.compute_instance.instance_id()
     }
@@ -201,6 +218,12 @@ impl Optimize<HirRelationExpr> for Optimizer {
     }
 }
 
+impl<T> LocalMirPlan<T> {
+    pub fn expr(&self) -> &MirRelationExpr {
+        &self.expr
+    }
+}
+
 impl LocalMirPlan<Unresolved> {
     /// Produces the [`LocalMirPlan`] with [`Resolved`] contextual information
     /// required for the next stage.
@@ -270,6 +293,33 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
         )?;
         df_builder.maybe_reoptimize_imported_views(&mut df_desc, &self.config)?;
 
+        // Make hypothetical indexes on imported collections available to the dataflow. Unused
+        // index imports are pruned during global optimization.
+        for (index_id, on_id, key) in &self.hypothetical_indexes {
+            if !df_desc.source_imports.contains_key(on_id)
+                && !df_desc
+                    .index_imports
+                    .values()
+                    .any(|i| i.desc.on_id == *on_id)
+            {
+                continue;
+            }
+            let entry = self.catalog.get_entry_by_global_id(on_id);
+            let desc = entry
+                .relation_desc()
+                .expect("indexes can only be built on items with descs");
+            df_desc.import_index(
+                *index_id,
+                IndexDesc {
+                    on_id: *on_id,
+                    key: key.clone(),
+                    spill_to_disk: false,
+                },
+                ReprRelationType::from(desc.typ()),
+                false,
+            );
+        }
+
         // TODO: Instead of conditioning here we should really
         // reconsider how to render multi-plan peek dataflows. The main
         // difficulty here is rendering the optional finishing bit.
@@ -279,6 +329,7 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
                 IndexDesc {
                     on_id: self.select_id,
                     key,
+                    spill_to_disk: false,
                 },
                 ReprRelationType::from(&typ),
             );
@@ -323,8 +374,12 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
         }
 
         // Construct TransformCtx for global optimization.
+        let index_oracle = HypotheticalIndexOracle {
+            existing: &df_builder,
+            hypothetical: &self.hypothetical_indexes,
+        };
         let mut transform_ctx = TransformCtx::global(
-            &df_builder,
+            &index_oracle,
             &*stats,
             &self.config.features,
             &self.typecheck_ctx,
@@ -406,6 +461,13 @@ impl<'s> Optimize<LocalMirPlan<Resolved<'s>>> for Optimizer {
                     "The fast_path_optimizer shouldn't make a fast path plan slow path."
                 );
 
+                // The dataflow might depend on indexes that don't exist.
+                if !self.hypothetical_indexes.is_empty() {
+                    return Err(OptimizerError::Internal(
+                        "cannot render a dataflow with hypothetical indexes".to_string(),
+                    ));
+                }
+
                 // Ensure all expressions are normalized before finalizing.
                 for build in df_desc.objects_to_build.iter_mut() {
                     normalize_lets(&mut build.plan.0, &self.config.features)?
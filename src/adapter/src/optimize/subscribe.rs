@@ -172,6 +172,10 @@ impl Optimize<SubscribePlan> for Optimizer {
 
     fn optimize(&mut self, plan: SubscribePlan) -> Result<Self::To, OptimizerError> {
         let output = plan.output;
+        let emit_every_ms = plan
+            .emit_every
+            .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX));
+        let max_rows_per_second = plan.max_rows_per_second;
         let plan = plan.from;
         let time = Instant::now();
 
@@ -199,6 +203,8 @@ impl Optimize<SubscribePlan> for Optimizer {
                     from_desc,
                     connection: ComputeSinkConnection::Subscribe(SubscribeSinkConnection {
                         output: output.row_order().to_vec(),
+                        emit_every_ms,
+                        max_rows_per_second,
                     }),
                     with_snapshot: self.with_snapshot,
                     up_to: self.up_to.map(Antichain::from_elem).unwrap_or_default(),
@@ -243,6 +249,8 @@ impl Optimize<SubscribePlan> for Optimizer {
                     from_desc: desc.clone(),
                     connection: ComputeSinkConnection::Subscribe(SubscribeSinkConnection {
                         output: output.row_order().to_vec(),
+                        emit_every_ms,
+                        max_rows_per_second,
                     }),
                     with_snapshot: self.with_snapshot,
                     up_to: self.up_to.map(Antichain::from_elem).unwrap_or_default(),
@@ -265,7 +273,7 @@ impl Optimize<SubscribePlan> for Optimizer {
         // Construct TransformCtx for global optimization.
         let mut transform_ctx = TransformCtx::global(
             &df_builder,
-            &mz_transform::EmptyStatisticsOracle, // TODO: wire proper stats
+            &mz_transform::EmptyStatisticsOracle, // stats only inform one-shot peeks
             &self.config.features,
             &self.typecheck_ctx,
             &mut df_meta,
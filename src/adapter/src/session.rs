@@ -100,6 +100,13 @@ pub struct Session {
     vars: SessionVars,
     notices_tx: mpsc::UnboundedSender<AdapterNotice>,
     notices_rx: mpsc::UnboundedReceiver<AdapterNotice>,
+    notifications_tx: mpsc::UnboundedSender<Notification>,
+    /// The receiving end of `notifications_tx`, until it is taken by the
+    /// protocol layer that delivers notifications to the client.
+    notifications_rx: Option<mpsc::UnboundedReceiver<Notification>>,
+    /// The notifications of the transaction that is being committed, to be
+    /// delivered once the commit succeeds.
+    committing_notifications: Vec<Notification>,
     next_transaction_id: TransactionId,
     secret_key: u32,
     external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
@@ -317,6 +324,7 @@ impl Session {
         metrics: SessionMetrics,
     ) -> Session {
         let (notices_tx, notices_rx) = mpsc::unbounded_channel();
+        let (notifications_tx, notifications_rx) = mpsc::unbounded_channel();
         let default_cluster = INTERNAL_USER_NAME_TO_DEFAULT_CLUSTER.get(&user);
         let user = User {
             name: user,
@@ -344,6 +352,9 @@ impl Session {
             vars,
             notices_tx,
             notices_rx,
+            notifications_tx,
+            notifications_rx: Some(notifications_rx),
+            committing_notifications: Vec::new(),
             next_transaction_id: 0,
             secret_key: rand::random(),
             external_metadata_rx,
@@ -384,6 +395,7 @@ impl Session {
                 }
                 TransactionOps::None
                 | TransactionOps::Writes(_)
+                | TransactionOps::ReadWrites { .. }
                 | TransactionOps::SingleStatement { .. }
                 | TransactionOps::DDL { .. } => false,
             };
@@ -403,6 +415,7 @@ impl Session {
                     write_lock_guards: None,
                     access,
                     id,
+                    notifications: Vec::new(),
                 });
             }
             TransactionStatus::Started(mut txn)
@@ -436,6 +449,7 @@ impl Session {
                 write_lock_guards: None,
                 access: None,
                 id,
+                notifications: Vec::new(),
             };
             match stmts {
                 1 => self.transaction = TransactionStatus::Started(txn),
@@ -549,6 +563,42 @@ impl Session {
         notices
     }
 
+    /// Returns a channel on which to send notifications to the session.
+    pub fn retain_notification_transmitter(&self) -> UnboundedSender<Notification> {
+        self.notifications_tx.clone()
+    }
+
+    /// Takes the receiving end of the session's notification channel.
+    ///
+    /// Returns `None` if the receiver has already been taken.
+    pub fn take_notification_receiver(&mut self) -> Option<UnboundedReceiver<Notification>> {
+        self.notifications_rx.take()
+    }
+
+    /// Reports whether notifications sent to the session are delivered to the
+    /// client, i.e. whether the protocol layer took the notification receiver.
+    pub fn receives_notifications(&self) -> bool {
+        self.notifications_rx.is_none()
+    }
+
+    /// Buffers a notification in the current transaction. It is delivered
+    /// when the transaction commits and dropped if it rolls back.
+    pub fn add_notification(&mut self, notification: Notification) {
+        if let Some(txn) = self.transaction.inner_mut() {
+            txn.add_notification(notification);
+        }
+    }
+
+    /// Stashes the notifications of the transaction that is being committed.
+    pub fn set_committing_notifications(&mut self, notifications: Vec<Notification>) {
+        self.committing_notifications = notifications;
+    }
+
+    /// Takes the notifications of the transaction that is being committed.
+    pub fn take_committing_notifications(&mut self) -> Vec<Notification> {
+        mem::take(&mut self.committing_notifications)
+    }
+
     /// Returns Some if the notice should be reported, otherwise None.
     fn notice_filter(&self, notice: AdapterNotice) -> Option<AdapterNotice> {
         // Filter out low threshold severity.
@@ -598,15 +648,18 @@ impl Session {
     /// Returns the transaction's read timestamp determination, if set.
     ///
     /// Returns `None` if there is no active transaction, or if the active
-    /// transaction is not a read transaction.
+    /// transaction has not read anything.
     pub fn get_transaction_timestamp_determination(&self) -> Option<TimestampDetermination> {
         match self.transaction.inner() {
             Some(Transaction {
                 pcx: _,
-                ops: TransactionOps::Peeks { determination, .. },
+                ops:
+                    TransactionOps::Peeks { determination, .. }
+                    | TransactionOps::ReadWrites { determination, .. },
                 write_lock_guards: _,
                 access: _,
                 id: _,
+                notifications: _,
             }) => Some(determination.clone()),
             _ => None,
         }
@@ -624,10 +677,11 @@ impl Session {
                         ..
                     },
                     ..
-                },
+                } | TransactionOps::ReadWrites { .. },
                 write_lock_guards: _,
                 access: _,
                 id: _,
+                notifications: _,
             })
         )
     }
@@ -1249,6 +1303,17 @@ impl TransactionStatus {
         }
     }
 
+    /// The writes of the transaction that are waiting to be committed.
+    pub fn pending_writes(&self) -> &[WriteOp] {
+        match self.inner() {
+            Some(Transaction {
+                ops: TransactionOps::Writes(writes) | TransactionOps::ReadWrites { writes, .. },
+                ..
+            }) => writes,
+            _ => &[],
+        }
+    }
+
     /// Reports whether any operations have been executed as part of this transaction
     pub fn contains_ops(&self) -> bool {
         match self.inner() {
@@ -1267,14 +1332,20 @@ impl TransactionStatus {
             | TransactionStatus::InTransactionImplicit(Transaction { ops, access, .. }) => {
                 match ops {
                     TransactionOps::None => access != &Some(TransactionAccessMode::ReadOnly),
-                    TransactionOps::Peeks { determination, .. } => {
+                    TransactionOps::Peeks {
+                        determination,
+                        read_set,
+                        ..
+                    } => {
                         // If-and-only-if peeks thus far do not have a timestamp
-                        // (i.e. they are constant), we can switch to a write
-                        // transaction.
+                        // (i.e. they are constant), or we know which tables
+                        // they read, we can switch to a write transaction.
                         !determination.timestamp_context.contains_timestamp()
+                            || (read_set.is_some()
+                                && access != &Some(TransactionAccessMode::ReadOnly))
                     }
                     TransactionOps::Subscribe => false,
-                    TransactionOps::Writes(_) => true,
+                    TransactionOps::Writes(_) | TransactionOps::ReadWrites { .. } => true,
                     TransactionOps::SingleStatement { .. } => false,
                     TransactionOps::DDL { .. } => false,
                 }
@@ -1315,13 +1386,30 @@ impl TransactionStatus {
                         determination,
                         cluster_id,
                         requires_linearization,
+                        read_set,
                     } => match add_ops {
                         TransactionOps::Peeks {
                             determination: add_timestamp_determination,
                             cluster_id: add_cluster_id,
                             requires_linearization: add_requires_linearization,
+                            read_set: add_read_set,
                         } => {
                             assert_eq!(*cluster_id, add_cluster_id);
+                            // Peeks without a timestamp don't read any tables.
+                            if add_timestamp_determination
+                                .timestamp_context
+                                .contains_timestamp()
+                            {
+                                if !determination.timestamp_context.contains_timestamp() {
+                                    *read_set = add_read_set;
+                                } else if let (Some(txn_read_set), Some(add_read_set)) =
+                                    (read_set.as_mut(), add_read_set)
+                                {
+                                    txn_read_set.extend(add_read_set);
+                                } else {
+                                    *read_set = None;
+                                }
+                            }
                             match (
                                 &determination.timestamp_context,
                                 &add_timestamp_determination.timestamp_context,
@@ -1363,6 +1451,22 @@ impl TransactionStatus {
                         {
                             *ops = writes;
                         }
+                        // If we know which tables the peeks read, we can switch
+                        // to a read-write transaction.
+                        TransactionOps::Writes(writes)
+                            if read_set.is_some()
+                                && !matches!(access, Some(TransactionAccessMode::ReadOnly)) =>
+                        {
+                            let determination = determination.clone();
+                            let cluster_id = *cluster_id;
+                            let read_set = read_set.take().expect("checked above");
+                            *ops = TransactionOps::ReadWrites {
+                                determination,
+                                cluster_id,
+                                read_set,
+                                writes,
+                            };
+                        }
                         _ => return Err(AdapterError::ReadOnlyTransaction),
                     },
                     TransactionOps::Subscribe => {
@@ -1379,6 +1483,54 @@ impl TransactionStatus {
                         // constant), we can permit them.
                         TransactionOps::Peeks { determination, .. }
                             if !determination.timestamp_context.contains_timestamp() => {}
+                        // If we know which tables the peeks read, we can switch
+                        // to a read-write transaction.
+                        TransactionOps::Peeks {
+                            determination,
+                            cluster_id,
+                            read_set: Some(read_set),
+                            ..
+                        } => {
+                            let writes = std::mem::take(txn_writes);
+                            *ops = TransactionOps::ReadWrites {
+                                determination,
+                                cluster_id,
+                                read_set,
+                                writes,
+                            };
+                        }
+                        _ => {
+                            return Err(AdapterError::WriteOnlyTransaction);
+                        }
+                    },
+                    TransactionOps::ReadWrites {
+                        determination,
+                        cluster_id,
+                        read_set,
+                        writes: txn_writes,
+                    } => match add_ops {
+                        TransactionOps::Writes(mut add_writes) => {
+                            txn_writes.append(&mut add_writes);
+                        }
+                        TransactionOps::Peeks {
+                            determination: add_determination,
+                            ..
+                        } if !add_determination.timestamp_context.contains_timestamp() => {}
+                        // Further peeks happen at the timestamp of the first,
+                        // on the same cluster.
+                        TransactionOps::Peeks {
+                            determination: add_determination,
+                            cluster_id: add_cluster_id,
+                            read_set: Some(add_read_set),
+                            ..
+                        } => {
+                            assert_eq!(*cluster_id, add_cluster_id);
+                            assert_eq!(
+                                determination.timestamp_context.timestamp(),
+                                add_determination.timestamp_context.timestamp()
+                            );
+                            read_set.extend(add_read_set);
+                        }
                         _ => {
                             return Err(AdapterError::WriteOnlyTransaction);
                         }
@@ -1448,9 +1600,28 @@ pub struct Transaction {
     write_lock_guards: Option<WriteLocks>,
     /// Access mode (read only, read write).
     access: Option<TransactionAccessMode>,
+    /// Notifications sent by the transaction, delivered once it commits.
+    notifications: Vec<Notification>,
 }
 
 impl Transaction {
+    /// Buffers a notification until the transaction commits. Like PostgreSQL,
+    /// duplicate notifications on the same channel are only delivered once.
+    fn add_notification(&mut self, notification: Notification) {
+        if !self
+            .notifications
+            .iter()
+            .any(|n| n.channel == notification.channel && n.payload == notification.payload)
+        {
+            self.notifications.push(notification);
+        }
+    }
+
+    /// Takes the notifications sent by the transaction.
+    pub fn take_notifications(&mut self) -> Vec<Notification> {
+        mem::take(&mut self.notifications)
+    }
+
     /// Tries to grant the write lock to this transaction for the remainder of its lifetime. Errors
     /// if this [`Transaction`] has already been granted write locks.
     fn try_grant_write_locks(&mut self, guards: WriteLocks) -> Result<(), &WriteLocks> {
@@ -1473,11 +1644,20 @@ impl Transaction {
                         ..
                     },
                 ..
+            }
+            | TransactionOps::ReadWrites {
+                determination:
+                    TimestampDetermination {
+                        timestamp_context: TimestampContext::TimelineTimestamp { timeline, .. },
+                        ..
+                    },
+                ..
             } => Some(timeline.clone()),
             TransactionOps::Peeks { .. }
             | TransactionOps::None
             | TransactionOps::Subscribe
             | TransactionOps::Writes(_)
+            | TransactionOps::ReadWrites { .. }
             | TransactionOps::SingleStatement { .. }
             | TransactionOps::DDL { .. } => None,
         }
@@ -1486,7 +1666,8 @@ impl Transaction {
     /// The cluster of the transaction, if one exists.
     pub fn cluster(&self) -> Option<ClusterId> {
         match &self.ops {
-            TransactionOps::Peeks { cluster_id, .. } => Some(cluster_id.clone()),
+            TransactionOps::Peeks { cluster_id, .. }
+            | TransactionOps::ReadWrites { cluster_id, .. } => Some(cluster_id.clone()),
             TransactionOps::None
             | TransactionOps::Subscribe
             | TransactionOps::Writes(_)
@@ -1501,6 +1682,17 @@ impl Transaction {
     }
 }
 
+/// An asynchronous notification sent to a channel via `NOTIFY`.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    /// The ID of the connection that sent the notification.
+    pub conn_id: ConnectionId,
+    /// The channel on which the notification was sent.
+    pub channel: String,
+    /// The payload of the notification.
+    pub payload: String,
+}
+
 /// A transaction's status code.
 #[derive(Debug, Clone, Copy)]
 pub enum TransactionCode {
@@ -1563,12 +1755,31 @@ pub enum TransactionOps {
         cluster_id: ClusterId,
         /// Whether this peek needs to be linearized.
         requires_linearization: RequireLinearization,
+        /// The user tables read by the peeks, if the transaction may go on to
+        /// write. See [`TransactionOps::ReadWrites`].
+        read_set: Option<BTreeSet<CatalogItemId>>,
     },
     /// This transaction has done a `SUBSCRIBE` and must do nothing else.
     Subscribe,
     /// This transaction has had a write (`INSERT`, `UPDATE`, `DELETE`) and must
     /// only do other writes, or reads whose timestamp is None (i.e. constants).
+    /// Reads with a timestamp are only allowed if we know which tables they
+    /// read, and turn it into a [`TransactionOps::ReadWrites`].
     Writes(Vec<WriteOp>),
+    /// This transaction has had both peeks with a timestamp and writes, in any
+    /// order. It can do other writes, and other peeks at the same timestamp,
+    /// which see its writes. The writes are only committed if none of the
+    /// tables in `read_set` have been written since the timestamp of the peeks.
+    ReadWrites {
+        /// The timestamp and timestamp related metadata for the peeks.
+        determination: TimestampDetermination,
+        /// The cluster used to execute peeks.
+        cluster_id: ClusterId,
+        /// The user tables read by the peeks.
+        read_set: BTreeSet<CatalogItemId>,
+        /// The writes.
+        writes: Vec<WriteOp>,
+    },
     /// This transaction has a prospective statement that will execute during commit.
     SingleStatement {
         /// The prospective statement.
@@ -1613,6 +1824,7 @@ impl TransactionOps {
             TransactionOps::None
             | TransactionOps::Subscribe
             | TransactionOps::Writes(_)
+            | TransactionOps::ReadWrites { .. }
             | TransactionOps::SingleStatement { .. }
             | TransactionOps::DDL { .. } => None,
         }
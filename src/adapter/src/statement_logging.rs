@@ -218,6 +218,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::ClosedCursor
             | ExecuteResponse::Comment
             | ExecuteResponse::Copied(_)
+            | ExecuteResponse::CreatedChannel
             | ExecuteResponse::CreatedConnection
             | ExecuteResponse::CreatedDatabase
             | ExecuteResponse::CreatedSchema
@@ -235,18 +236,24 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedMaterializedView
             | ExecuteResponse::CreatedContinualTask
             | ExecuteResponse::CreatedType
+            | ExecuteResponse::CreatedFunction
+            | ExecuteResponse::CreatedSequence
             | ExecuteResponse::CreatedNetworkPolicy
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::DeclaredCursor
             | ExecuteResponse::Deleted(_)
             | ExecuteResponse::DiscardedTemp
             | ExecuteResponse::DiscardedAll
+            | ExecuteResponse::DroppedChannel
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
             | ExecuteResponse::EmptyQuery
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(_)
+            | ExecuteResponse::Listened
+            | ExecuteResponse::Merged(_)
+            | ExecuteResponse::Notified
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
@@ -256,6 +263,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::StartedTransaction
             | ExecuteResponse::TransactionCommitted { .. }
             | ExecuteResponse::TransactionRolledBack { .. }
+            | ExecuteResponse::Unlistened
             | ExecuteResponse::Updated(_)
             | ExecuteResponse::ValidatedConnection { .. } => {
                 StatementEndedExecutionReason::Success {
@@ -329,6 +337,7 @@ impl PreparedStatementLoggingInfo {
                 | StatementKind::AlterSecret
                 | StatementKind::Insert
                 | StatementKind::Update
+                | StatementKind::Merge
                 | StatementKind::Execute,
             ) => stmt.map(|s| s.to_ast_string_redacted()).unwrap_or_default(),
             _ => raw_sql,
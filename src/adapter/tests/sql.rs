@@ -105,8 +105,11 @@ async fn datadriven() {
                                             resolved_ids: ResolvedIds::empty(),
                                             custom_logical_compaction_window: None,
                                             is_retained_metrics_object: false,
+                                            storage_compression: None,
                                             data_source: TableDataSource::TableWrites {
                                                 defaults: vec![],
+                                                checks: vec![],
+                                                uniques: vec![],
                                             },
                                         }),
                                         owner_id: MZ_SYSTEM_ROLE_ID,
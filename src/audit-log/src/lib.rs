@@ -149,6 +149,7 @@ pub enum ObjectType {
     Role,
     Secret,
     Schema,
+    Sequence,
     Sink,
     Source,
     System,
@@ -172,6 +173,7 @@ impl ObjectType {
             ObjectType::Role => "Role",
             ObjectType::Schema => "Schema",
             ObjectType::Secret => "Secret",
+            ObjectType::Sequence => "Sequence",
             ObjectType::Sink => "Sink",
             ObjectType::Source => "Source",
             ObjectType::System => "System",
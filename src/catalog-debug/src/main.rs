@@ -710,6 +710,7 @@ async fn upgrade_check(
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_) => None,
         });
 
@@ -108,6 +108,9 @@ impl RustType<crate::objects::audit_log_event_v1::ObjectType> for mz_audit_log::
             mz_audit_log::ObjectType::Schema => {
                 crate::objects::audit_log_event_v1::ObjectType::Schema
             }
+            mz_audit_log::ObjectType::Sequence => {
+                crate::objects::audit_log_event_v1::ObjectType::Sequence
+            }
             mz_audit_log::ObjectType::Sink => crate::objects::audit_log_event_v1::ObjectType::Sink,
             mz_audit_log::ObjectType::Source => {
                 crate::objects::audit_log_event_v1::ObjectType::Source
@@ -163,6 +166,9 @@ impl RustType<crate::objects::audit_log_event_v1::ObjectType> for mz_audit_log::
             crate::objects::audit_log_event_v1::ObjectType::Schema => {
                 Ok(mz_audit_log::ObjectType::Schema)
             }
+            crate::objects::audit_log_event_v1::ObjectType::Sequence => {
+                Ok(mz_audit_log::ObjectType::Sequence)
+            }
             crate::objects::audit_log_event_v1::ObjectType::Sink => {
                 Ok(mz_audit_log::ObjectType::Sink)
             }
@@ -566,6 +566,7 @@ pub enum CommentObject {
     Type(CatalogItemId),
     Secret(CatalogItemId),
     ContinualTask(CatalogItemId),
+    Sequence(CatalogItemId),
     Role(RoleId),
     Database(DatabaseId),
     Schema(ResolvedSchema),
@@ -2438,6 +2439,7 @@ pub mod audit_log_event_v1 {
         System = 16,
         ContinualTask = 17,
         NetworkPolicy = 18,
+        Sequence = 19,
     }
 
     #[derive(
@@ -2930,6 +2932,7 @@ pub enum CatalogItemType {
     Secret = 9,
     Connection = 10,
     ContinualTask = 11,
+    Sequence = 12,
 }
 
 #[derive(
@@ -2965,4 +2968,5 @@ pub enum ObjectType {
     Func = 15,
     ContinualTask = 16,
     NetworkPolicy = 17,
+    Sequence = 18,
 }
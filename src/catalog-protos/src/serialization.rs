@@ -224,6 +224,7 @@ impl RustType<crate::objects::CatalogItemType> for CatalogItemType {
             CatalogItemType::Secret => crate::objects::CatalogItemType::Secret,
             CatalogItemType::Connection => crate::objects::CatalogItemType::Connection,
             CatalogItemType::ContinualTask => crate::objects::CatalogItemType::ContinualTask,
+            CatalogItemType::Sequence => crate::objects::CatalogItemType::Sequence,
         }
     }
 
@@ -240,6 +241,7 @@ impl RustType<crate::objects::CatalogItemType> for CatalogItemType {
             crate::objects::CatalogItemType::Secret => CatalogItemType::Secret,
             crate::objects::CatalogItemType::Connection => CatalogItemType::Connection,
             crate::objects::CatalogItemType::ContinualTask => CatalogItemType::ContinualTask,
+            crate::objects::CatalogItemType::Sequence => CatalogItemType::Sequence,
             crate::objects::CatalogItemType::Unknown => {
                 return Err(TryFromProtoError::unknown_enum_variant("CatalogItemType"));
             }
@@ -268,6 +270,7 @@ impl RustType<crate::objects::ObjectType> for ObjectType {
             ObjectType::Func => crate::objects::ObjectType::Func,
             ObjectType::ContinualTask => crate::objects::ObjectType::ContinualTask,
             ObjectType::NetworkPolicy => crate::objects::ObjectType::NetworkPolicy,
+            ObjectType::Sequence => crate::objects::ObjectType::Sequence,
         }
     }
 
@@ -290,6 +293,7 @@ impl RustType<crate::objects::ObjectType> for ObjectType {
             crate::objects::ObjectType::Func => Ok(ObjectType::Func),
             crate::objects::ObjectType::ContinualTask => Ok(ObjectType::ContinualTask),
             crate::objects::ObjectType::NetworkPolicy => Ok(ObjectType::NetworkPolicy),
+            crate::objects::ObjectType::Sequence => Ok(ObjectType::Sequence),
             crate::objects::ObjectType::Unknown => Err(TryFromProtoError::unknown_enum_variant(
                 "ObjectType::Unknown",
             )),
@@ -441,6 +445,9 @@ impl RustType<crate::objects::CommentObject> for CommentObjectId {
             CommentObjectId::Secret(global_id) => {
                 crate::objects::CommentObject::Secret(global_id.into_proto())
             }
+            CommentObjectId::Sequence(item_id) => {
+                crate::objects::CommentObject::Sequence(item_id.into_proto())
+            }
             CommentObjectId::Role(role_id) => {
                 crate::objects::CommentObject::Role(role_id.into_proto())
             }
@@ -504,6 +511,9 @@ impl RustType<crate::objects::CommentObject> for CommentObjectId {
             crate::objects::CommentObject::Secret(item_id) => {
                 CommentObjectId::Secret(item_id.into_rust()?)
             }
+            crate::objects::CommentObject::Sequence(item_id) => {
+                CommentObjectId::Sequence(item_id.into_rust()?)
+            }
             crate::objects::CommentObject::ContinualTask(item_id) => {
                 CommentObjectId::ContinualTask(item_id.into_rust()?)
             }
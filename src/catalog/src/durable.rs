@@ -24,6 +24,7 @@ use mz_persist_client::PersistClient;
 use mz_persist_types::ShardId;
 use mz_repr::{CatalogItemId, Diff, GlobalId, RelationDesc, SqlScalarType};
 use mz_sql::catalog::CatalogError as SqlCatalogError;
+use mz_sql::plan::SequenceOptions;
 use uuid::Uuid;
 
 use crate::config::ClusterReplicaSizeMap;
@@ -76,6 +77,12 @@ pub const BUILTIN_MIGRATION_SHARD_KEY: &str = "builtin_migration_shard";
 pub const EXPRESSION_CACHE_SHARD_KEY: &str = "expression_cache_shard";
 pub const MOCK_AUTHENTICATION_NONCE_KEY: &str = "mock_authentication_nonce";
 
+/// Returns the ID allocator key that holds the durable state of the sequence
+/// `id`.
+pub fn sequence_alloc_key(id: CatalogItemId) -> String {
+    format!("sequence_{id}")
+}
+
 #[derive(Clone, Debug)]
 pub struct BootstrapArgs {
     pub cluster_replica_size_map: ClusterReplicaSizeMap,
@@ -352,6 +359,39 @@ pub trait DurableCatalogState: ReadOnlyDurableCatalogState {
         Ok(ids)
     }
 
+    /// Takes up to `count` values from the sequence `id`, whose parameters are
+    /// `options`. Fewer than `count` values are returned only if the sequence
+    /// is exhausted.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn allocate_sequence_values(
+        &mut self,
+        id: CatalogItemId,
+        options: SequenceOptions,
+        count: usize,
+        commit_ts: Timestamp,
+    ) -> Result<Vec<i64>, CatalogError> {
+        let mut txn = self.transaction().await?;
+        let values = txn.take_sequence_values(id, &options, count)?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(values)
+    }
+
+    /// Overwrites the durable state of the sequence `id` with `state`.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn set_sequence_state(
+        &mut self,
+        id: CatalogItemId,
+        state: u64,
+        commit_ts: Timestamp,
+    ) -> Result<(), CatalogError> {
+        let mut txn = self.transaction().await?;
+        txn.set_sequence_state(id, state)?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(())
+    }
+
     /// Allocates and returns `amount` many user [`CatalogItemId`] and [`GlobalId`].
     ///
     /// See [`Self::commit_transaction`] for details on `commit_ts`.
@@ -1356,6 +1356,7 @@ pub fn item_type(create_sql: &str) -> CatalogItemType {
         Some("TYPE") => CatalogItemType::Type,
         Some("FUNCTION") => CatalogItemType::Func,
         Some("SECRET") => CatalogItemType::Secret,
+        Some("SEQUENCE") => CatalogItemType::Sequence,
         Some("CONNECTION") => CatalogItemType::Connection,
         _ => panic!("unexpected create sql: {}", create_sql),
     }
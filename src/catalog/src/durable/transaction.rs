@@ -35,7 +35,7 @@ use mz_sql::catalog::{
     RoleAttributesRaw, RoleMembership, RoleVars,
 };
 use mz_sql::names::{CommentObjectId, DatabaseId, ResolvedDatabaseSpecifier, SchemaId};
-use mz_sql::plan::NetworkPolicyRule;
+use mz_sql::plan::{NetworkPolicyRule, SequenceOptions};
 use mz_sql_parser::ast::QualifiedReplica;
 use mz_storage_client::controller::StorageTxn;
 use mz_storage_types::controller::StorageError;
@@ -67,7 +67,7 @@ use crate::durable::{
     SCHEMA_ID_ALLOC_KEY, STORAGE_USAGE_ID_ALLOC_KEY, SYSTEM_CLUSTER_ID_ALLOC_KEY,
     SYSTEM_ITEM_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY, Snapshot, SystemConfiguration,
     USER_ITEM_ALLOC_KEY, USER_NETWORK_POLICY_ID_ALLOC_KEY, USER_REPLICA_ID_ALLOC_KEY,
-    USER_ROLE_ID_ALLOC_KEY,
+    USER_ROLE_ID_ALLOC_KEY, sequence_alloc_key,
 };
 use crate::memory::objects::{StateDiff, StateUpdate, StateUpdateKind};
 
@@ -1072,6 +1072,63 @@ impl<'a> Transaction<'a> {
         }
     }
 
+    /// Initializes the durable state of the sequence `id` to `state`.
+    pub fn insert_sequence_state(
+        &mut self,
+        id: CatalogItemId,
+        state: u64,
+    ) -> Result<(), CatalogError> {
+        self.insert_id_allocator(sequence_alloc_key(id), state)
+    }
+
+    /// Overwrites the durable state of the sequence `id` with `state`.
+    pub fn set_sequence_state(
+        &mut self,
+        id: CatalogItemId,
+        state: u64,
+    ) -> Result<(), CatalogError> {
+        let key = sequence_alloc_key(id);
+        let prev = self.id_allocator.set(
+            IdAllocKey { name: key.clone() },
+            Some(IdAllocValue { next_id: state }),
+            self.op_id,
+        )?;
+        assert!(prev.is_some(), "{key} id allocator missing");
+        Ok(())
+    }
+
+    /// Removes the durable state of the sequence `id`.
+    pub fn remove_sequence_state(&mut self, id: CatalogItemId) -> Result<(), CatalogError> {
+        self.id_allocator.set(
+            IdAllocKey {
+                name: sequence_alloc_key(id),
+            },
+            None,
+            self.op_id,
+        )?;
+        Ok(())
+    }
+
+    /// Takes up to `count` values from the sequence `id`, whose parameters are
+    /// `options`, and advances its durable state past them.
+    pub fn take_sequence_values(
+        &mut self,
+        id: CatalogItemId,
+        options: &SequenceOptions,
+        count: usize,
+    ) -> Result<Vec<i64>, CatalogError> {
+        let key = sequence_alloc_key(id);
+        let state = self
+            .id_allocator
+            .items()
+            .get(&IdAllocKey { name: key.clone() })
+            .unwrap_or_else(|| panic!("{key} id allocator missing"))
+            .next_id;
+        let (values, state) = options.take(state, count);
+        self.set_sequence_state(id, state)?;
+        Ok(values)
+    }
+
     /// Removes the database `id` from the transaction.
     ///
     /// Returns an error if `id` is not found.
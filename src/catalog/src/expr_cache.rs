@@ -807,6 +807,7 @@ mod tests {
                 desc: IndexDesc {
                     on_id: GlobalId::User(1),
                     key: Default::default(),
+                    spill_to_disk: false,
                 },
                 typ: ReprRelationType::empty(),
                 monotonic: false,
@@ -27,6 +27,7 @@ use mz_controller::clusters::{ClusterRole, ClusterStatus, ReplicaConfig, Replica
 use mz_controller_types::{ClusterId, ReplicaId};
 use mz_expr::{MirScalarExpr, OptimizedMirRelationExpr};
 use mz_ore::collections::CollectionExt;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::optimize::OptimizerFeatureOverrides;
@@ -54,8 +55,8 @@ use mz_sql::names::{
 use mz_sql::plan::{
     ClusterSchedule, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
     CreateClusterManagedPlan, CreateClusterPlan, CreateClusterVariant, CreateSourcePlan,
-    HirRelationExpr, NetworkPolicyRule, PlanError, WebhookBodyFormat, WebhookHeaders,
-    WebhookValidation,
+    HirRelationExpr, NetworkPolicyRule, PlanError, SequenceOptions, SqlFunction, TableCheck,
+    TableUnique, WebhookBodyFormat, WebhookHeaders, WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -727,6 +728,14 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.func()
     }
 
+    fn sql_function(&self) -> Option<&SqlFunction> {
+        self.entry.sql_function()
+    }
+
+    fn sequence_options(&self) -> Option<&SequenceOptions> {
+        self.entry.sequence_options()
+    }
+
     fn source_desc(&self) -> Result<Option<&SourceDesc<ReferencedConnection>>, SqlCatalogError> {
         self.entry.source_desc()
     }
@@ -754,6 +763,10 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.writable_table_details()
     }
 
+    fn table_uniques(&self) -> &[TableUnique] {
+        self.entry.table_uniques()
+    }
+
     fn replacement_target(&self) -> Option<CatalogItemId> {
         self.entry.replacement_target()
     }
@@ -844,6 +857,7 @@ pub enum CatalogItem {
     Secret(Secret),
     Connection(Connection),
     ContinualTask(ContinualTask),
+    Sequence(Sequence),
 }
 
 impl From<CatalogEntry> for durable::Item {
@@ -884,6 +898,10 @@ pub struct Table {
     ///
     /// ['metrics_retention']: mz_sql::session::vars::METRICS_RETENTION
     pub is_retained_metrics_object: bool,
+    /// Compression policy for the table's data shard, e.g. set via `ALTER ... SET (STORAGE
+    /// COMPRESSION ...)`. Derived from the `create_sql`, so skipped when serializing.
+    #[serde(skip)]
+    pub storage_compression: Option<CompressionFormat>,
     /// Where data for this table comes from, e.g. `INSERT` statements or an upstream source.
     pub data_source: TableDataSource,
 }
@@ -898,6 +916,24 @@ impl Table {
         }
     }
 
+    /// Returns the `CHECK` constraints that rows written to this [`Table`] must
+    /// satisfy.
+    pub fn checks(&self) -> &[TableCheck] {
+        match &self.data_source {
+            TableDataSource::TableWrites { checks, .. } => checks,
+            TableDataSource::DataSource { .. } => &[],
+        }
+    }
+
+    /// Returns the `PRIMARY KEY` and `UNIQUE` constraints that rows written to
+    /// this [`Table`] must satisfy.
+    pub fn uniques(&self) -> &[TableUnique] {
+        match &self.data_source {
+            TableDataSource::TableWrites { uniques, .. } => uniques,
+            TableDataSource::DataSource { .. } => &[],
+        }
+    }
+
     /// Returns all of the [`GlobalId`]s that this [`Table`] can be referenced by.
     pub fn global_ids(&self) -> impl Iterator<Item = GlobalId> + '_ {
         self.collections.values().copied()
@@ -942,6 +978,10 @@ pub enum TableDataSource {
     TableWrites {
         #[serde(skip)]
         defaults: Vec<Expr<Aug>>,
+        /// The `CHECK` constraints that rows written to the table must satisfy.
+        checks: Vec<TableCheck>,
+        /// The `PRIMARY KEY` and `UNIQUE` constraints of the table.
+        uniques: Vec<TableUnique>,
     },
 
     /// The table receives its data from the identified `DataSourceDesc`.
@@ -1425,6 +1465,10 @@ pub struct MaterializedView {
     pub custom_logical_compaction_window: Option<CompactionWindow>,
     /// Schedule to refresh this materialized view, e.g. set via `REFRESH EVERY` option.
     pub refresh_schedule: Option<RefreshSchedule>,
+    /// Compression policy for the materialized view's data shard, e.g. set via `ALTER ... SET
+    /// (STORAGE COMPRESSION ...)`. Derived from the `create_sql`, so skipped when serializing.
+    #[serde(skip)]
+    pub storage_compression: Option<CompressionFormat>,
     /// The initial `as_of` of the storage collection associated with the materialized view.
     ///
     /// Note: This doesn't change upon restarts.
@@ -1574,6 +1618,9 @@ pub struct Index {
     ///
     /// ['metrics_retention']: mz_sql::session::vars::METRICS_RETENTION
     pub is_retained_metrics_object: bool,
+    /// Whether large batches of the index's arrangement may be spilled to disk, e.g. set via
+    /// `WITH (STORAGE = 'disk')`.
+    pub spill_to_disk: bool,
     // The catalog `dump` method uses serde to serialize catalog state, e.g., Testdrive catalog
     // consistency checks do two dumps and compare them. One of these states comes from the durable
     // catalog, but the following fields are not restored when the consistency check loads the
@@ -1610,11 +1657,24 @@ pub struct Type {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Func {
-    /// Static definition of the function.
+    /// Parse-able SQL that defines this function, or `None` for built-in functions.
+    pub create_sql: Option<String>,
+    /// Definition of the function.
     #[serde(skip)]
-    pub inner: &'static mz_sql::func::Func,
+    pub inner: FuncDefinition,
     /// [`GlobalId`] used to reference this function from outside the catalog.
     pub global_id: GlobalId,
+    /// Other catalog objects referenced by this function.
+    pub resolved_ids: ResolvedIds,
+}
+
+/// The definition of a [`Func`].
+#[derive(Debug, Clone)]
+pub enum FuncDefinition {
+    /// Static definition of a built-in function.
+    Builtin(&'static mz_sql::func::Func),
+    /// A function defined in SQL with `CREATE FUNCTION`.
+    Sql(SqlFunction),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1625,6 +1685,18 @@ pub struct Secret {
     pub global_id: GlobalId,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Sequence {
+    /// Parse-able SQL that defines this sequence.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this sequence from outside the catalog.
+    pub global_id: GlobalId,
+    /// The sequence's parameters.
+    pub options: SequenceOptions,
+    /// Other catalog objects referenced by this sequence.
+    pub resolved_ids: ResolvedIds,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Connection {
     /// Parse-able SQL that defines this connection.
@@ -1770,6 +1842,7 @@ impl CatalogItem {
             CatalogItem::Type(_) => CatalogItemType::Type,
             CatalogItem::Func(_) => CatalogItemType::Func,
             CatalogItem::Secret(_) => CatalogItemType::Secret,
+            CatalogItem::Sequence(_) => CatalogItemType::Sequence,
             CatalogItem::Connection(_) => CatalogItemType::Connection,
             CatalogItem::ContinualTask(_) => CatalogItemType::ContinualTask,
         }
@@ -1790,6 +1863,7 @@ impl CatalogItem {
             CatalogItem::Func(func) => func.global_id,
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
+            CatalogItem::Sequence(seq) => seq.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
             CatalogItem::Table(table) => {
                 return itertools::Either::Left(table.collections.values().copied());
@@ -1813,6 +1887,7 @@ impl CatalogItem {
             CatalogItem::Func(func) => func.global_id,
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
+            CatalogItem::Sequence(seq) => seq.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
             CatalogItem::Table(table) => table.global_id_writes(),
         }
@@ -1893,6 +1968,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_) => false,
         }
     }
@@ -1919,6 +1995,7 @@ impl CatalogItem {
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_)
             | CatalogItem::Type(_) => None,
         }
@@ -1929,7 +2006,10 @@ impl CatalogItem {
         entry: &CatalogEntry,
     ) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
         match &self {
-            CatalogItem::Func(func) => Ok(func.inner),
+            CatalogItem::Func(Func {
+                inner: FuncDefinition::Builtin(func),
+                ..
+            }) => Ok(func),
             _ => Err(SqlCatalogError::UnexpectedType {
                 name: entry.name().item.to_string(),
                 actual_type: entry.item_type(),
@@ -1976,7 +2056,7 @@ impl CatalogItem {
     pub fn references(&self) -> &ResolvedIds {
         static EMPTY: LazyLock<ResolvedIds> = LazyLock::new(ResolvedIds::empty);
         match self {
-            CatalogItem::Func(_) => &*EMPTY,
+            CatalogItem::Func(func) => &func.resolved_ids,
             CatalogItem::Index(idx) => &idx.resolved_ids,
             CatalogItem::Sink(sink) => &sink.resolved_ids,
             CatalogItem::Source(source) => &source.resolved_ids,
@@ -1986,6 +2066,7 @@ impl CatalogItem {
             CatalogItem::View(view) => &view.resolved_ids,
             CatalogItem::MaterializedView(mview) => &mview.resolved_ids,
             CatalogItem::Secret(_) => &*EMPTY,
+            CatalogItem::Sequence(seq) => &seq.resolved_ids,
             CatalogItem::Connection(connection) => &connection.resolved_ids,
             CatalogItem::ContinualTask(ct) => &ct.resolved_ids,
         }
@@ -2014,6 +2095,7 @@ impl CatalogItem {
             }
             CatalogItem::ContinualTask(ct) => uses.extend(ct.dependencies.0.iter().copied()),
             CatalogItem::Secret(_) => {}
+            CatalogItem::Sequence(_) => {}
             CatalogItem::Connection(_) => {}
         }
         uses
@@ -2031,6 +2113,7 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Connection(_)
@@ -2050,6 +2133,7 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Connection(_)
@@ -2122,6 +2206,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
+            CatalogItem::Sequence(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Sequence(i))
+            }
             CatalogItem::Connection(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql)?;
@@ -2132,7 +2221,16 @@ impl CatalogItem {
                 i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
                 Ok(CatalogItem::Type(i))
             }
-            CatalogItem::Func(i) => Ok(CatalogItem::Func(i.clone())),
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                if let (FuncDefinition::Sql(function), Some(create_sql)) =
+                    (&mut i.inner, &i.create_sql)
+                {
+                    function.create_sql = create_sql.clone();
+                }
+                Ok(CatalogItem::Func(i))
+            }
             CatalogItem::ContinualTask(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql)?;
@@ -2200,6 +2298,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
+            CatalogItem::Sequence(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Sequence(i))
+            }
             CatalogItem::Func(_) | CatalogItem::Type(_) => {
                 unreachable!("{}s cannot be renamed", self.typ())
             }
@@ -2267,6 +2370,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql);
                 CatalogItem::Secret(i)
             }
+            CatalogItem::Sequence(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Sequence(i)
+            }
             CatalogItem::Func(_) | CatalogItem::Type(_) => {
                 unreachable!("references of {}s cannot be replaced", self.typ())
             }
@@ -2400,6 +2508,61 @@ impl CatalogItem {
         }
     }
 
+    /// Updates the storage compression policy for a table or materialized view. Returns an error
+    /// if this item does not support a storage compression policy.
+    pub fn update_storage_compression(
+        &mut self,
+        value: Option<Value>,
+        compression: Option<CompressionFormat>,
+    ) -> Result<(), ()> {
+        let update = |ast: &mut Statement<Raw>| {
+            macro_rules! update_storage_compression {
+                ( $stmt:ident, $opt:ident, $name:ident ) => {{
+                    let pos = $stmt
+                        .with_options
+                        .iter()
+                        .rposition(|o| o.name == mz_sql_parser::ast::$name::StorageCompression);
+                    if let Some(value) = value {
+                        let next = mz_sql_parser::ast::$opt {
+                            name: mz_sql_parser::ast::$name::StorageCompression,
+                            value: Some(WithOptionValue::Value(value)),
+                        };
+                        if let Some(idx) = pos {
+                            $stmt.with_options[idx] = next;
+                        } else {
+                            $stmt.with_options.push(next);
+                        }
+                    } else if let Some(idx) = pos {
+                        $stmt.with_options.swap_remove(idx);
+                    }
+                }};
+            }
+            match ast {
+                Statement::CreateTable(stmt) => {
+                    update_storage_compression!(stmt, TableOption, TableOptionName)
+                }
+                Statement::CreateMaterializedView(stmt) => {
+                    update_storage_compression!(
+                        stmt,
+                        MaterializedViewOption,
+                        MaterializedViewOptionName
+                    )
+                }
+                _ => return Err(()),
+            }
+            Ok(())
+        };
+
+        self.update_sql(update)?;
+
+        match self {
+            CatalogItem::Table(table) => table.storage_compression = compression,
+            CatalogItem::MaterializedView(mv) => mv.storage_compression = compression,
+            _ => return Err(()),
+        }
+        Ok(())
+    }
+
     pub fn add_column(
         &mut self,
         name: ColumnName,
@@ -2449,15 +2612,17 @@ impl CatalogItem {
         let create_sql = match self {
             CatalogItem::Table(Table { create_sql, .. })
             | CatalogItem::Type(Type { create_sql, .. })
+            | CatalogItem::Func(Func { create_sql, .. })
             | CatalogItem::Source(Source { create_sql, .. }) => create_sql.as_mut(),
             CatalogItem::Sink(Sink { create_sql, .. })
             | CatalogItem::View(View { create_sql, .. })
             | CatalogItem::MaterializedView(MaterializedView { create_sql, .. })
             | CatalogItem::Index(Index { create_sql, .. })
             | CatalogItem::Secret(Secret { create_sql, .. })
+            | CatalogItem::Sequence(Sequence { create_sql, .. })
             | CatalogItem::Connection(Connection { create_sql, .. })
             | CatalogItem::ContinualTask(ContinualTask { create_sql, .. }) => Some(create_sql),
-            CatalogItem::Func(_) | CatalogItem::Log(_) => None,
+            CatalogItem::Log(_) => None,
         };
         let Some(create_sql) = create_sql else {
             return Err(());
@@ -2491,6 +2656,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2520,6 +2686,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_) => None,
         }
     }
@@ -2538,6 +2705,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2560,6 +2728,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => return None,
         };
@@ -2586,6 +2755,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_) => return None,
         };
         Some(custom_logical_compaction_window.unwrap_or(CompactionWindow::Default))
@@ -2606,6 +2776,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => false,
         }
@@ -2658,12 +2829,19 @@ impl CatalogItem {
             CatalogItem::Secret(secret) => {
                 (secret.create_sql.clone(), secret.global_id, BTreeMap::new())
             }
+            CatalogItem::Sequence(seq) => (seq.create_sql.clone(), seq.global_id, BTreeMap::new()),
             CatalogItem::Connection(connection) => (
                 connection.create_sql.clone(),
                 connection.global_id,
                 BTreeMap::new(),
             ),
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => {
+                let create_sql = func
+                    .create_sql
+                    .clone()
+                    .expect("builtin functions cannot be serialized");
+                (create_sql, func.global_id, BTreeMap::new())
+            }
             CatalogItem::ContinualTask(ct) => {
                 (ct.create_sql.clone(), ct.global_id, BTreeMap::new())
             }
@@ -2708,10 +2886,16 @@ impl CatalogItem {
                 (create_sql, typ.global_id, BTreeMap::new())
             }
             CatalogItem::Secret(secret) => (secret.create_sql, secret.global_id, BTreeMap::new()),
+            CatalogItem::Sequence(seq) => (seq.create_sql, seq.global_id, BTreeMap::new()),
             CatalogItem::Connection(connection) => {
                 (connection.create_sql, connection.global_id, BTreeMap::new())
             }
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => {
+                let create_sql = func
+                    .create_sql
+                    .expect("builtin functions cannot be serialized");
+                (create_sql, func.global_id, BTreeMap::new())
+            }
             CatalogItem::ContinualTask(ct) => (ct.create_sql, ct.global_id, BTreeMap::new()),
         }
     }
@@ -2730,6 +2914,7 @@ impl CatalogItem {
             CatalogItem::Type(ty) => return Some(ty.global_id),
             CatalogItem::Func(func) => return Some(func.global_id),
             CatalogItem::Secret(secret) => return Some(secret.global_id),
+            CatalogItem::Sequence(seq) => return Some(seq.global_id),
             CatalogItem::Connection(conn) => return Some(conn.global_id),
             CatalogItem::ContinualTask(ct) => return Some(ct.global_id),
         };
@@ -2762,6 +2947,31 @@ impl CatalogEntry {
         self.item.func(self)
     }
 
+    /// Returns the [`SqlFunction`] associated with this `CatalogEntry`, if it is a function
+    /// defined with `CREATE FUNCTION`.
+    pub fn sql_function(&self) -> Option<&SqlFunction> {
+        match self.item() {
+            CatalogItem::Func(Func {
+                inner: FuncDefinition::Sql(function),
+                ..
+            }) => Some(function),
+            _ => None,
+        }
+    }
+
+    /// Returns the inner [`Sequence`] if this entry is a sequence, else `None`.
+    pub fn sequence(&self) -> Option<&Sequence> {
+        match self.item() {
+            CatalogItem::Sequence(sequence) => Some(sequence),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`SequenceOptions`] of this entry, if it is a sequence.
+    pub fn sequence_options(&self) -> Option<&SequenceOptions> {
+        self.sequence().map(|sequence| &sequence.options)
+    }
+
     /// Returns the inner [`Index`] if this entry is an index, else `None`.
     pub fn index(&self) -> Option<&Index> {
         match self.item() {
@@ -2936,6 +3146,7 @@ impl CatalogEntry {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -3071,6 +3282,7 @@ impl CatalogEntry {
             Connection => CommentObjectId::Connection(self.id),
             Type => CommentObjectId::Type(self.id),
             Secret => CommentObjectId::Secret(self.id),
+            Sequence => CommentObjectId::Sequence(self.id),
             ContinualTask => CommentObjectId::ContinualTask(self.id),
         }
     }
@@ -3654,6 +3866,14 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         self.func()
     }
 
+    fn sql_function(&self) -> Option<&SqlFunction> {
+        self.sql_function()
+    }
+
+    fn sequence_options(&self) -> Option<&SequenceOptions> {
+        self.sequence_options()
+    }
+
     fn source_desc(&self) -> Result<Option<&SourceDesc<ReferencedConnection>>, SqlCatalogError> {
         self.source_desc()
     }
@@ -3681,8 +3901,11 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
                 create_sql.as_deref().unwrap_or("<builtin>")
             }
             CatalogItem::Secret(Secret { create_sql, .. }) => create_sql,
+            CatalogItem::Sequence(Sequence { create_sql, .. }) => create_sql,
             CatalogItem::Connection(Connection { create_sql, .. }) => create_sql,
-            CatalogItem::Func(_) => "<builtin>",
+            CatalogItem::Func(Func { create_sql, .. }) => {
+                create_sql.as_deref().unwrap_or("<builtin>")
+            }
             CatalogItem::Log(_) => "<builtin>",
             CatalogItem::ContinualTask(ContinualTask { create_sql, .. }) => create_sql,
         }
@@ -3702,7 +3925,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
 
     fn writable_table_details(&self) -> Option<&[Expr<Aug>]> {
         if let CatalogItem::Table(Table {
-            data_source: TableDataSource::TableWrites { defaults },
+            data_source: TableDataSource::TableWrites { defaults, .. },
             ..
         }) = self.item()
         {
@@ -3712,6 +3935,13 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn table_uniques(&self) -> &[TableUnique] {
+        match self.item() {
+            CatalogItem::Table(table) => table.uniques(),
+            _ => &[],
+        }
+    }
+
     fn replacement_target(&self) -> Option<CatalogItemId> {
         if let CatalogItem::MaterializedView(mv) = self.item() {
             mv.replacement_target
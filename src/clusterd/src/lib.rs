@@ -29,8 +29,10 @@ use mz_ore::error::ErrorExt;
 use mz_ore::metrics::{MetricsRegistry, register_runtime_metrics};
 use mz_ore::netio::{Listener, SocketAddr};
 use mz_ore::now::SYSTEM_TIME;
+use mz_persist::encryption::SecretsBlobKeys;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::cfg::PersistConfig;
+use mz_persist_client::compaction_service::GrpcCompactionClient;
 use mz_persist_client::rpc::{GrpcPubSubClient, PersistPubSubClient, PersistPubSubClientConfig};
 use mz_service::emit_boot_diagnostics;
 use mz_service::grpc::GrpcServerMetrics;
@@ -107,6 +109,24 @@ struct Args {
         default_value = "http://localhost:6879"
     )]
     persist_pubsub_url: String,
+    /// The URL for the Persist compaction service, if compaction should be
+    /// offloaded to one. Only used while `persist_compaction_service_enabled`
+    /// is set; compaction runs inline otherwise, or if the service is
+    /// unreachable.
+    #[clap(
+        long,
+        env = "PERSIST_COMPACTION_SERVICE_URL",
+        value_name = "http://HOST:PORT",
+        requires = "persist_compaction_service_auth_token"
+    )]
+    persist_compaction_service_url: Option<String>,
+    /// The token to authenticate to the Persist compaction service with.
+    #[clap(long, env = "PERSIST_COMPACTION_SERVICE_AUTH_TOKEN")]
+    persist_compaction_service_auth_token: Option<String>,
+    /// Whether persist blobs may be encrypted, with key-encryption keys read
+    /// from secrets.
+    #[clap(long, env = "PERSIST_BLOB_ENCRYPTION")]
+    persist_blob_encryption: bool,
 
     // === Cloud options. ===
     /// An external ID to be supplied to all AWS AssumeRole operations.
@@ -343,6 +363,20 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), mz_dyncfgs::all_dyncfgs());
     persist_cfg.is_cc_active = args.is_cc;
     persist_cfg.announce_memory_limit = args.announce_memory_limit;
+    if args.persist_blob_encryption {
+        persist_cfg.blob_keys = Some(Arc::new(SecretsBlobKeys::new(Arc::clone(&secrets_reader))));
+    }
+    persist_cfg.blob_cache_dir = args
+        .scratch_directory
+        .as_ref()
+        .map(|dir| dir.join("persist").join("blob_cache"));
+    if let (Some(url), Some(auth_token)) = (
+        &args.persist_compaction_service_url,
+        &args.persist_compaction_service_auth_token,
+    ) {
+        let client = GrpcCompactionClient::new(url, auth_token, &persist_cfg)?;
+        persist_cfg.compaction_executor = Some(Arc::new(client));
+    }
     // Start with compaction disabled, will get enabled once a cluster receives AllowWrites.
     persist_cfg.disable_compaction();
 
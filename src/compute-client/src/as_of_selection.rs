@@ -859,7 +859,7 @@ mod tests {
     use mz_repr::{ReprRelationType, Timestamp};
     use mz_storage_client::client::TimestamplessUpdateBuilder;
     use mz_storage_client::controller::{CollectionDescription, StorageMetadata, StorageTxn};
-    use mz_storage_client::storage_collections::{CollectionFrontiers, SnapshotCursor};
+    use mz_storage_client::storage_collections::{CollectionFrontiers, PartFilter, SnapshotCursor};
     use mz_storage_types::StorageDiff;
     use mz_storage_types::controller::{CollectionMetadata, StorageError};
     use mz_storage_types::errors::CollectionMissing;
@@ -960,6 +960,15 @@ mod tests {
             unimplemented!()
         }
 
+        fn table_written_since(
+            &self,
+            _id: GlobalId,
+            _since: Timestamp,
+            _as_of: Timestamp,
+        ) -> BoxFuture<'static, Result<bool, StorageError>> {
+            unimplemented!()
+        }
+
         fn snapshot_cursor(
             &self,
             _id: GlobalId,
@@ -968,6 +977,15 @@ mod tests {
             unimplemented!()
         }
 
+        fn snapshot_cursor_filtered(
+            &self,
+            _id: GlobalId,
+            _as_of: Timestamp,
+            _should_fetch: PartFilter,
+        ) -> BoxFuture<'static, Result<SnapshotCursor, StorageError>> {
+            unimplemented!()
+        }
+
         fn snapshot_and_stream(
             &self,
             _id: GlobalId,
@@ -1093,6 +1111,7 @@ mod tests {
                     desc: IndexDesc {
                         on_id: GlobalId::Transient(0),
                         key: Default::default(),
+                        spill_to_disk: false,
                     },
                     typ: ReprRelationType::empty(),
                     monotonic: Default::default(),
@@ -1108,6 +1127,7 @@ mod tests {
                 let desc = IndexDesc {
                     on_id: GlobalId::Transient(0),
                     key: Default::default(),
+                    spill_to_disk: false,
                 };
                 let typ = ReprRelationType::empty();
                 (id, (desc, typ))
@@ -593,6 +593,8 @@ pub struct IndexDesc {
     pub on_id: GlobalId,
     /// Expressions to be arranged, in order of decreasing primacy.
     pub key: Vec<MirScalarExpr>,
+    /// Whether large batches of the exported arrangement may be spilled to disk.
+    pub spill_to_disk: bool,
 }
 
 /// Information about an imported index, and how it will be used by the dataflow.
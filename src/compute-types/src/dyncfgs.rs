@@ -149,6 +149,14 @@ pub const ENABLE_COLUMNAR_LGALLOC: Config<bool> = Config::new(
     "Enable allocating aligned regions in columnar from lgalloc.",
 );
 
+/// The minimum size of a merged batch in an arrangement of an index with `STORAGE = 'disk'` for
+/// it to be spilled to the scratch directory.
+pub const ARRANGEMENT_SPILL_THRESHOLD_BYTES: Config<usize> = Config::new(
+    "compute_arrangement_spill_threshold_bytes",
+    64 << 20,
+    "The minimum size of a merged batch of a disk-backed index to be spilled to the scratch directory.",
+);
+
 /// The interval at which the compute server performs maintenance tasks.
 pub const COMPUTE_SERVER_MAINTENANCE_INTERVAL: Config<Duration> = Config::new(
     "compute_server_maintenance_interval",
@@ -398,6 +406,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&ENABLE_LGALLOC_EAGER_RECLAMATION)
         .add(&ENABLE_COLUMNATION_LGALLOC)
         .add(&ENABLE_COLUMNAR_LGALLOC)
+        .add(&ARRANGEMENT_SPILL_THRESHOLD_BYTES)
         .add(&COMPUTE_SERVER_MAINTENANCE_INTERVAL)
         .add(&DATAFLOW_MAX_INFLIGHT_BYTES)
         .add(&DATAFLOW_MAX_INFLIGHT_BYTES_CC)
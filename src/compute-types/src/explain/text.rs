@@ -35,7 +35,7 @@ use mz_repr::explain::{
 
 use crate::plan::join::delta_join::{DeltaPathPlan, DeltaStagePlan};
 use crate::plan::join::linear_join::LinearStagePlan;
-use crate::plan::join::{DeltaJoinPlan, JoinClosure, LinearJoinPlan};
+use crate::plan::join::{DeltaJoinPlan, JoinClosure, LinearJoinPlan, OuterJoinPlan};
 use crate::plan::reduce::{
     AccumulablePlan, BasicPlan, BucketedPlan, HierarchicalPlan, MonotonicPlan, SingleBasicPlan,
 };
@@ -275,6 +275,11 @@ impl Plan {
                         writeln!(f, "{annotations}")?;
                         ctx.indented(|ctx| plan.fmt_text(f, ctx))?;
                     }
+                    JoinPlan::Outer(plan) => {
+                        let kind = plan.kind.name();
+                        writeln!(f, "{}→{kind} Outer Join %0 » %1{annotations}", ctx.indent)?;
+                        ctx.indented(|ctx| plan.fmt_text(f, ctx))?;
+                    }
                 }
 
                 ctx.indented(|ctx| {
@@ -724,6 +729,10 @@ impl Plan {
                         writeln!(f, "{}Join::Delta{}", ctx.indent, annotations)?;
                         ctx.indented(|ctx| plan.fmt_text(f, ctx))?;
                     }
+                    JoinPlan::Outer(plan) => {
+                        writeln!(f, "{}Join::Outer{}", ctx.indent, annotations)?;
+                        ctx.indented(|ctx| plan.fmt_text(f, ctx))?;
+                    }
                 }
                 ctx.indented(|ctx| {
                     for input in inputs {
@@ -1069,6 +1078,50 @@ impl LinearJoinPlan {
     }
 }
 
+impl DisplayText<PlanRenderingContext<'_, Plan>> for OuterJoinPlan {
+    fn fmt_text(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctx: &mut PlanRenderingContext<'_, Plan>,
+    ) -> fmt::Result {
+        if ctx.config.verbose_syntax {
+            self.fmt_verbose_text(f, ctx)
+        } else {
+            self.fmt_default_text(f, ctx)
+        }
+    }
+}
+impl OuterJoinPlan {
+    #[allow(clippy::needless_pass_by_ref_mut)]
+    fn fmt_default_text(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctx: &mut PlanRenderingContext<'_, Plan>,
+    ) -> fmt::Result {
+        let left_key = CompactScalarSeq(&self.left_key);
+        let right_key = CompactScalarSeq(&self.right_key);
+        writeln!(f, "{}Left key {left_key} in %0", ctx.indent)?;
+        writeln!(f, "{}Right key {right_key} in %1", ctx.indent)
+    }
+
+    fn fmt_verbose_text(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        ctx: &mut PlanRenderingContext<'_, Plan>,
+    ) -> fmt::Result {
+        let mode = HumanizedExplain::new(ctx.config.redacted);
+        let left_key = CompactScalars(mode.seq(&self.left_key, None));
+        let right_key = CompactScalars(mode.seq(&self.right_key, None));
+        writeln!(f, "{}kind={}", ctx.indent, self.kind.name())?;
+        writeln!(f, "{}left={{ relation=0, key=[{}] }}", ctx.indent, left_key)?;
+        writeln!(
+            f,
+            "{}right={{ relation=1, key=[{}] }}",
+            ctx.indent, right_key
+        )
+    }
+}
+
 impl DisplayText<PlanRenderingContext<'_, Plan>> for LinearStagePlan {
     fn fmt_text(
         &self,
@@ -151,14 +151,18 @@ impl Interpreter for SingleTimeMonotonic<'_> {
         &self,
         _ctx: &Context<Self::Domain>,
         inputs: Vec<Self::Domain>,
-        _plan: &JoinPlan,
+        plan: &JoinPlan,
     ) -> Self::Domain {
         // When we see a join, we must consider that the inputs could have
         // been `Plan::Get`s on arrangements. These are not in general safe
         // wrt. producing physically monotonic data. So here, we conservatively
         // judge that output of a join to be physically monotonic iff all
         // inputs are physically monotonic.
-        PhysicallyMonotonic(inputs.iter().all(|monotonic| monotonic.0))
+        //
+        // Outer joins retract the padded versions of matched rows, and are
+        // never physically monotonic.
+        let outer = matches!(plan, JoinPlan::Outer(_));
+        PhysicallyMonotonic(!outer && inputs.iter().all(|monotonic| monotonic.0))
     }
 
     fn reduce(
@@ -35,9 +35,11 @@ use serde::{Deserialize, Serialize};
 
 pub mod delta_join;
 pub mod linear_join;
+pub mod outer_join;
 
 pub use delta_join::DeltaJoinPlan;
 pub use linear_join::LinearJoinPlan;
+pub use outer_join::OuterJoinPlan;
 
 /// A complete enumeration of possible join plans to render.
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
@@ -46,6 +48,8 @@ pub enum JoinPlan {
     Linear(LinearJoinPlan),
     /// A join implemented by a delta join.
     Delta(DeltaJoinPlan),
+    /// A binary outer join, implemented over arrangements of both inputs.
+    Outer(OuterJoinPlan),
 }
 
 /// A manual closure implementation of filtering and logic application.
@@ -0,0 +1,88 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Planning of outer joins.
+//!
+//! An outer join is a binary equijoin that additionally produces the rows of
+//! its preserved inputs that have no match, padded with nulls. Both inputs are
+//! read from arrangements by their join keys: the matched rows are the result
+//! of joining the two arrangements, and the unmatched rows of a preserved input
+//! are its rows minus those whose key is present in the other arrangement.
+
+use mz_expr::{MirScalarExpr, OuterJoinKind, permutation_for_arrangement};
+use serde::{Deserialize, Serialize};
+
+use crate::plan::AvailableCollections;
+
+/// A plan for the execution of an outer join.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+pub struct OuterJoinPlan {
+    /// Which inputs have their unmatched rows preserved.
+    pub kind: OuterJoinKind,
+    /// The key by which the left input is arranged.
+    pub left_key: Vec<MirScalarExpr>,
+    /// Reconstructs a left row from the concatenation of its key and value.
+    pub left_permutation: Vec<usize>,
+    /// The key by which the right input is arranged.
+    pub right_key: Vec<MirScalarExpr>,
+    /// Reconstructs a right row from the concatenation of its key and value.
+    pub right_permutation: Vec<usize>,
+}
+
+impl OuterJoinPlan {
+    /// Create a new outer join plan from the keys and arities of its inputs.
+    ///
+    /// Also returns the collections that must be built for each input, as they are
+    /// not present in `available`.
+    pub fn create_from(
+        kind: OuterJoinKind,
+        left_key: &[MirScalarExpr],
+        right_key: &[MirScalarExpr],
+        arities: [usize; 2],
+        available: &[AvailableCollections],
+    ) -> (Self, Vec<AvailableCollections>) {
+        let mut requested: Vec<AvailableCollections> = vec![Default::default(); 2];
+        let mut permutations = Vec::with_capacity(2);
+        for (index, key) in [left_key, right_key].into_iter().enumerate() {
+            let permutation = available[index]
+                .arranged
+                .iter()
+                .find_map(|(k, permutation, _)| (k == key).then(|| permutation.clone()))
+                .unwrap_or_else(|| {
+                    let (permutation, thinning) = permutation_for_arrangement(key, arities[index]);
+                    requested[index]
+                        .arranged
+                        .push((key.to_vec(), permutation.clone(), thinning));
+                    permutation
+                });
+            permutations.push(permutation);
+        }
+        let right_permutation = permutations.pop().unwrap();
+        let left_permutation = permutations.pop().unwrap();
+
+        let plan = OuterJoinPlan {
+            kind,
+            left_key: left_key.to_vec(),
+            left_permutation,
+            right_key: right_key.to_vec(),
+            right_permutation,
+        };
+        (plan, requested)
+    }
+
+    /// The number of columns in the left input.
+    pub fn left_arity(&self) -> usize {
+        self.left_permutation.len()
+    }
+
+    /// The number of columns in the right input.
+    pub fn right_arity(&self) -> usize {
+        self.right_permutation.len()
+    }
+}
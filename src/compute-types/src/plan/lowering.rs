@@ -23,7 +23,7 @@ use mz_repr::optimize::OptimizerFeatures;
 use mz_repr::{GlobalId, Timestamp};
 
 use crate::dataflows::{BuildDesc, DataflowDescription, IndexImport};
-use crate::plan::join::{DeltaJoinPlan, JoinPlan, LinearJoinPlan};
+use crate::plan::join::{DeltaJoinPlan, JoinPlan, LinearJoinPlan, OuterJoinPlan};
 use crate::plan::reduce::{KeyValPlan, ReducePlan};
 use crate::plan::threshold::ThresholdPlan;
 use crate::plan::top_k::TopKPlan;
@@ -677,6 +677,55 @@ This is not expected to cause incorrect results, but could indicate a performanc
                     AvailableCollections::new_raw(),
                 )
             }
+            MirRelationExpr::OuterJoin {
+                left,
+                right,
+                kind,
+                left_key,
+                right_key,
+            } => {
+                let (left_plan, left_keys) = self.lower_mir_expr(left)?;
+                let (right_plan, right_keys) = self.lower_mir_expr(right)?;
+                let arities = [left.arity(), right.arity()];
+                let input_keys = [left_keys, right_keys];
+                let (ojp, missing) =
+                    OuterJoinPlan::create_from(*kind, left_key, right_key, arities, &input_keys);
+
+                // Both inputs are read from arrangements by their keys. `JoinImplementation`
+                // installs MIR `ArrangeBy`s for them, but we plan any that are missing.
+                let mut plans = vec![left_plan, right_plan];
+                for (((input_plan, input_keys), missing), arity) in plans
+                    .iter_mut()
+                    .zip_eq(input_keys.iter())
+                    .zip_eq(missing)
+                    .zip_eq(arities)
+                {
+                    if missing != Default::default() {
+                        soft_panic_or_log!("Arrangements depended on by an outer join are absent: {:?}
+Dataflow info: {}
+This is not expected to cause incorrect results, but could indicate a performance issue in Materialize.", missing, self.debug_info);
+                        let lir_id = self.allocate_lir_id();
+                        let raw_plan = std::mem::replace(
+                            input_plan,
+                            PlanNode::Constant {
+                                rows: Ok(Vec::new()),
+                            }
+                            .as_plan(lir_id),
+                        );
+                        *input_plan = self.arrange_by(raw_plan, missing, input_keys, arity);
+                    }
+                }
+                // Return the plan, and no arrangements.
+                let lir_id = self.allocate_lir_id();
+                (
+                    PlanNode::Join {
+                        inputs: plans,
+                        plan: JoinPlan::Outer(ojp),
+                    }
+                    .as_plan(lir_id),
+                    AvailableCollections::new_raw(),
+                )
+            }
             MirRelationExpr::Reduce {
                 input,
                 group_key,
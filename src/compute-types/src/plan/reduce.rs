@@ -664,6 +664,7 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::LagLead { .. }
         | AggregateFunc::FirstValue { .. }
         | AggregateFunc::LastValue { .. }
+        | AggregateFunc::SessionWindow { .. }
         | AggregateFunc::WindowAggregate { .. }
         | AggregateFunc::FusedValueWindowFunc { .. }
         | AggregateFunc::FusedWindowAggregate { .. } => ReductionType::Basic,
@@ -22,7 +22,7 @@ use mz_repr::explain::{CompactScalars, ExprHumanizer};
 use mz_repr::{Diff, GlobalId, Row, Timestamp};
 use serde::{Deserialize, Serialize};
 
-use crate::plan::join::{DeltaJoinPlan, JoinPlan, LinearJoinPlan};
+use crate::plan::join::{DeltaJoinPlan, JoinPlan, LinearJoinPlan, OuterJoinPlan};
 use crate::plan::reduce::{BucketedPlan, HierarchicalPlan, KeyValPlan, MonotonicPlan, ReducePlan};
 use crate::plan::threshold::ThresholdPlan;
 use crate::plan::top_k::{MonotonicTopKPlan, TopKPlan};
@@ -900,6 +900,9 @@ impl<'a> std::fmt::Display for RenderPlanExprHumanizer<'a> {
 
                     Ok(())
                 }
+                JoinPlan::Outer(OuterJoinPlan { kind, .. }) => {
+                    write!(f, "{} Outer Join %0 » %1", kind.name())
+                }
             },
             Reduce {
                 input_key: _input_key,
@@ -12,8 +12,8 @@
 use mz_expr::ColumnOrder;
 use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::{CatalogItemId, GlobalId, RelationDesc, Timestamp};
-use mz_storage_types::connections::aws::AwsConnection;
 use mz_storage_types::sinks::S3UploadInfo;
+use mz_storage_types::sinks::s3_oneshot_sink::CopyToConnection;
 use serde::{Deserialize, Serialize};
 use timely::progress::Antichain;
 
@@ -76,6 +76,11 @@ impl<S> ComputeSinkConnection<S> {
 pub struct SubscribeSinkConnection {
     /// An ordering for the data in the subscribe.
     pub output: Vec<ColumnOrder>,
+    /// If set, the length in milliseconds of the windows into which updates are
+    /// consolidated before they are emitted.
+    pub emit_every_ms: Option<u64>,
+    /// If set, the maximum number of updates to emit per second.
+    pub max_rows_per_second: Option<u64>,
 }
 
 /// Connection attributes required to do a oneshot copy to s3.
@@ -83,8 +88,8 @@ pub struct SubscribeSinkConnection {
 pub struct CopyToS3OneshotSinkConnection {
     /// Information specific to the upload.
     pub upload_info: S3UploadInfo,
-    /// The AWS connection information to do the writes.
-    pub aws_connection: AwsConnection,
+    /// The connection information to do the writes.
+    pub connection: CopyToConnection,
     /// The ID of the Connection object, used to generate the External ID when
     /// using AssumeRole with AWS connection.
     pub connection_id: CatalogItemId,
@@ -293,6 +293,11 @@ impl ComputeState {
         let enable_columnar_lgalloc = ENABLE_COLUMNAR_LGALLOC.get(config);
         mz_timely_util::containers::set_enable_columnar_lgalloc(enable_columnar_lgalloc);
 
+        crate::row_spine::spill::set_spill_config(
+            self.context.scratch_directory.clone(),
+            ARRANGEMENT_SPILL_THRESHOLD_BYTES.get(config),
+        );
+
         // Remember the maintenance interval locally to avoid reading it from the config set on
         // every server iteration.
         self.server_maintenance_interval = COMPUTE_SERVER_MAINTENANCE_INTERVAL.get(config);
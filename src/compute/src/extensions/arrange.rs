@@ -196,6 +196,50 @@ where
     }
 }
 
+/// An arrangement whose large batches can spill to disk.
+pub trait ArrangementSpill {
+    /// Install an operator that allows batches merged in the target's trace to spill to disk.
+    ///
+    /// Batches opt in as they pass the operator, and batches merged from opted-in batches inherit
+    /// the choice. Whether a merged batch actually spills depends on its size and the replica's
+    /// spill configuration.
+    fn enable_spill(self) -> Self;
+}
+
+impl<'scope, T, R> ArrangementSpill for Arranged<'scope, RowRowAgent<T, R>>
+where
+    T: MzTimestamp,
+    R: Semigroup + Ord + MzArrangeData + 'static,
+{
+    fn enable_spill(self) -> Self {
+        let trace = Rc::downgrade(&self.trace.trace_box_unstable());
+        let stream = self
+            .stream
+            .unary(Pipeline, "ArrangementSpill", |_cap, _info| {
+                move |input, output| {
+                    input.for_each(|time, data| {
+                        for batch in data.iter() {
+                            batch.storage.keys.enable_spill();
+                            batch.storage.vals.vals.enable_spill();
+                        }
+                        output.session(&time).give_container(data);
+                    });
+                    // Batches the arrangement merged before we could observe their inputs.
+                    if let Some(trace) = trace.upgrade() {
+                        trace.borrow().trace.map_batches(|batch| {
+                            batch.storage.keys.enable_spill();
+                            batch.storage.vals.vals.enable_spill();
+                        });
+                    }
+                }
+            });
+        Arranged {
+            trace: self.trace,
+            stream,
+        }
+    }
+}
+
 /// A type that can log its heap size.
 pub trait ArrangementSize {
     /// Install a logger to track the heap size of the target.
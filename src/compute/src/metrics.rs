@@ -71,6 +71,7 @@ pub struct ComputeMetrics {
 
     // memory usage
     shared_row_heap_capacity_bytes: raw::UIntGaugeVec,
+    arrangement_spilled_bytes: raw::UIntGaugeVec,
 
     // replica expiration
     replica_expiration_timestamp_seconds: raw::UIntGaugeVec,
@@ -155,6 +156,11 @@ impl ComputeMetrics {
                 help: "The heap capacity of the shared row.",
                 var_labels: ["worker_id"],
             )),
+            arrangement_spilled_bytes: registry.register(metric!(
+                name: "mz_arrangement_spilled_bytes",
+                help: "The number of bytes of arrangements spilled to the scratch directory.",
+                var_labels: ["worker_id"],
+            )),
             persist_peek_seconds: registry.register(metric!(
                 name: "mz_persist_peek_seconds",
                 help: "Time spent in (experimental) Persist fast-path peeks.",
@@ -276,6 +282,8 @@ impl ComputeMetrics {
         let shared_row_heap_capacity_bytes = self
             .shared_row_heap_capacity_bytes
             .with_label_values(&[&worker]);
+        let arrangement_spilled_bytes =
+            self.arrangement_spilled_bytes.with_label_values(&[&worker]);
 
         WorkerMetrics {
             worker_label: worker,
@@ -297,6 +305,7 @@ impl ComputeMetrics {
             replica_expiration_timestamp_seconds,
             replica_expiration_remaining_seconds,
             shared_row_heap_capacity_bytes,
+            arrangement_spilled_bytes,
         }
     }
 }
@@ -345,6 +354,8 @@ pub struct WorkerMetrics {
     pub(crate) replica_expiration_remaining_seconds: raw::Gauge,
     /// Heap capacity of the shared row.
     shared_row_heap_capacity_bytes: UIntGauge,
+    /// Bytes of arrangements spilled to the scratch directory.
+    arrangement_spilled_bytes: UIntGauge,
 }
 
 impl WorkerMetrics {
@@ -426,6 +437,12 @@ impl WorkerMetrics {
             .set(u64::cast_from(binding.byte_capacity()));
     }
 
+    /// Record the number of bytes that this worker's arrangements spilled to disk.
+    pub fn record_arrangement_spill_metrics(&self) {
+        self.arrangement_spilled_bytes
+            .set(u64::cast_from(crate::row_spine::spill::spilled_bytes()));
+    }
+
     /// Increase the count of maintained collections.
     fn inc_collection_count(&self, collection_type: &str, hydrated: bool) {
         let hydrated = if hydrated { "1" } else { "0" };
@@ -153,7 +153,7 @@ use timely::worker::Worker as TimelyWorker;
 
 use crate::arrangement::manager::TraceBundle;
 use crate::compute_state::ComputeState;
-use crate::extensions::arrange::{KeyCollection, MzArrange};
+use crate::extensions::arrange::{ArrangementSpill, KeyCollection, MzArrange};
 use crate::extensions::reduce::MzReduce;
 use crate::extensions::temporal_bucket::TemporalBucketing;
 use crate::logging::compute::{
@@ -731,6 +731,10 @@ impl<'g> Context<'g, mz_repr::Timestamp> {
 
         match bundle.arrangement(&idx.key) {
             Some(ArrangementFlavor::Local(mut oks, mut errs)) => {
+                if idx.spill_to_disk {
+                    oks = oks.enable_spill();
+                }
+
                 // Ensure that the frontier does not advance past the expiration time, if set.
                 // Otherwise, we might write down incorrect data.
                 if let Some(&expiration) = self.dataflow_expiration.as_option() {
@@ -819,6 +823,9 @@ where
                     .mz_arrange::<RowRowBatcher<_, _>, RowRowBuilder<_, _>, _>(
                         "Arrange export iterative",
                     );
+                if idx.spill_to_disk {
+                    oks = oks.enable_spill();
+                }
 
                 let mut errs = errs
                     .as_collection(|k, v| (k.clone(), v.clone()))
@@ -1298,6 +1305,9 @@ impl<'scope, T: RenderTimestamp> Context<'scope, T> {
                     mz_compute_types::plan::join::JoinPlan::Delta(delta_plan) => {
                         self.render_delta_join(inputs, delta_plan)
                     }
+                    mz_compute_types::plan::join::JoinPlan::Outer(outer_plan) => {
+                        self.render_outer_join(inputs, outer_plan)
+                    }
                 }
             }
             Reduce {
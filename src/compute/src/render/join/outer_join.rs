@@ -0,0 +1,293 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Rendering of outer join plans.
+//!
+//! Consult [OuterJoinPlan] documentation for details.
+//!
+//! Both inputs are read from arrangements by their join keys. The matched rows are produced by
+//! joining the two arrangements. The unmatched rows of a preserved input are all of its rows,
+//! minus those that join with the distinct keys of the other input. Beyond the input
+//! arrangements, the only state maintained is the arrangement of distinct keys of each input
+//! that can be the cause of padding.
+
+use differential_dataflow::Data;
+use differential_dataflow::lattice::Lattice;
+use differential_dataflow::operators::arrange::{Arranged, TraceAgent};
+use differential_dataflow::trace::implementations::BatchContainer;
+use differential_dataflow::trace::implementations::merge_batcher::container::InternalMerge;
+use differential_dataflow::trace::{Builder, Trace, TraceReader};
+use differential_dataflow::{AsCollection, VecCollection};
+use mz_compute_types::plan::join::OuterJoinPlan;
+use mz_repr::fixed_length::ToDatumIter;
+use mz_repr::{Datum, DatumVec, Diff, Row, SharedRow};
+use timely::Container;
+use timely::container::PushInto;
+use timely::dataflow::Scope;
+
+use crate::extensions::arrange::ArrangementSize;
+use crate::extensions::reduce::{ClearContainer, MzReduce};
+use crate::render::RenderTimestamp;
+use crate::render::context::{ArrangementFlavor, CollectionBundle, Context};
+use crate::row_spine::{RowRowBuilder, RowRowSpine};
+use crate::typedefs::{MzData, MzTimestamp};
+
+/// Reconstructs a row of a preserved input from its arranged key and value, and pads it with
+/// nulls in place of the columns of the other input.
+#[derive(Clone)]
+struct Padding {
+    /// Reconstructs the row from the concatenation of key and value.
+    permutation: Vec<usize>,
+    /// The number of nulls to place before the row.
+    before: usize,
+    /// The number of nulls to place after the row.
+    after: usize,
+}
+
+impl Padding {
+    fn apply(&self, datums: &[Datum]) -> Row {
+        SharedRow::pack(
+            std::iter::repeat_n(Datum::Null, self.before)
+                .chain(self.permutation.iter().map(|c| datums[*c]))
+                .chain(std::iter::repeat_n(Datum::Null, self.after)),
+        )
+    }
+}
+
+/// Arranges the distinct keys of `arrangement` that do not contain nulls, each with a count of one.
+fn distinct_keys<'scope, Ts, T1, Bu2, T2>(
+    arrangement: Arranged<'scope, T1>,
+    name: &str,
+) -> Arranged<'scope, TraceAgent<T2>>
+where
+    Ts: MzTimestamp,
+    T1: TraceReader<
+            KeyContainer: BatchContainer<Owned: MzData + Data>,
+            ValOwn: MzData + Data,
+            Time = Ts,
+            Diff = Diff,
+        > + Clone
+        + 'static,
+    for<'a> T1::Key<'a>: ToDatumIter,
+    Bu2: Builder<
+            Time = Ts,
+            Input: Container
+                       + InternalMerge
+                       + ClearContainer
+                       + PushInto<(
+                (<T1::KeyContainer as BatchContainer>::Owned, T1::ValOwn),
+                Ts,
+                Diff,
+            )>,
+            Output = T2::Batch,
+        >,
+    T2: for<'a> Trace<
+            Key<'a> = T1::Key<'a>,
+            Val<'a> = T1::Val<'a>,
+            KeyContainer: BatchContainer<Owned = <T1::KeyContainer as BatchContainer>::Owned>,
+            ValOwn = T1::ValOwn,
+            Time = Ts,
+            Diff = Diff,
+        > + 'static,
+    T1::ValOwn: Default,
+    Arranged<'scope, TraceAgent<T2>>: ArrangementSize,
+{
+    arrangement.mz_reduce_abelian::<_, Bu2, T2>(name, move |key, _s, t| {
+        // Keys containing nulls never match, and so are never the cause of a match.
+        if !key.to_datum_iter().any(|d| d.is_null()) {
+            t.push((Default::default(), Diff::ONE));
+        }
+    })
+}
+
+impl<'scope, T> Context<'scope, T>
+where
+    T: Lattice + RenderTimestamp,
+{
+    pub(crate) fn render_outer_join(
+        &self,
+        inputs: Vec<CollectionBundle<'scope, T>>,
+        plan: OuterJoinPlan,
+    ) -> CollectionBundle<'scope, T> {
+        self.scope.clone().region_named("Join(Outer)", |inner| {
+            self.render_outer_join_inner(inputs, plan, inner)
+        })
+    }
+
+    fn render_outer_join_inner(
+        &self,
+        inputs: Vec<CollectionBundle<'scope, T>>,
+        plan: OuterJoinPlan,
+        inner: Scope<'_, T>,
+    ) -> CollectionBundle<'scope, T> {
+        let left = inputs[0]
+            .arrangement(&plan.left_key)
+            .expect("Arrangement absent despite explicit construction")
+            .enter_region(inner);
+        let right = inputs[1]
+            .arrangement(&plan.right_key)
+            .expect("Arrangement absent despite explicit construction")
+            .enter_region(inner);
+
+        // Collect the errors of both inputs.
+        let mut errors = Vec::new();
+        for arrangement in [&left, &right] {
+            let errs = match arrangement {
+                ArrangementFlavor::Local(_, errs) => errs.clone().as_collection(|k, _v| k.clone()),
+                ArrangementFlavor::Trace(_, _, errs) => {
+                    errs.clone().as_collection(|k, _v| k.clone())
+                }
+            };
+            errors.push(errs);
+        }
+
+        // Demultiplex the four different cross products of arrangement types we might have.
+        let matched = match (&left, &right) {
+            (ArrangementFlavor::Local(l, _), ArrangementFlavor::Local(r, _)) => {
+                self.outer_join_matched(l.clone(), r.clone(), &plan)
+            }
+            (ArrangementFlavor::Local(l, _), ArrangementFlavor::Trace(_, r, _)) => {
+                self.outer_join_matched(l.clone(), r.clone(), &plan)
+            }
+            (ArrangementFlavor::Trace(_, l, _), ArrangementFlavor::Local(r, _)) => {
+                self.outer_join_matched(l.clone(), r.clone(), &plan)
+            }
+            (ArrangementFlavor::Trace(_, l, _), ArrangementFlavor::Trace(_, r, _)) => {
+                self.outer_join_matched(l.clone(), r.clone(), &plan)
+            }
+        };
+        let mut oks = vec![matched];
+
+        if plan.kind.retains_left() {
+            let padding = Padding {
+                permutation: plan.left_permutation.clone(),
+                before: 0,
+                after: plan.right_arity(),
+            };
+            oks.push(self.outer_join_unmatched(&left, &right, padding));
+        }
+        if plan.kind.retains_right() {
+            let padding = Padding {
+                permutation: plan.right_permutation.clone(),
+                before: plan.left_arity(),
+                after: 0,
+            };
+            oks.push(self.outer_join_unmatched(&right, &left, padding));
+        }
+
+        let bundle = CollectionBundle::from_collections(
+            differential_dataflow::collection::concatenate(inner, oks),
+            differential_dataflow::collection::concatenate(inner, errors),
+        );
+        bundle.leave_region(self.scope)
+    }
+
+    /// Joins the two arrangements, producing the concatenation of matching left and right rows.
+    fn outer_join_matched<'s, Tr1, Tr2>(
+        &self,
+        left: Arranged<'s, Tr1>,
+        right: Arranged<'s, Tr2>,
+        plan: &OuterJoinPlan,
+    ) -> VecCollection<'s, T, Row, Diff>
+    where
+        Tr1: TraceReader<Time = T, Diff = Diff> + Clone + 'static,
+        Tr2: for<'a> TraceReader<Key<'a> = Tr1::Key<'a>, Time = T, Diff = Diff> + Clone + 'static,
+        for<'a> Tr1::Key<'a>: ToDatumIter,
+        for<'a> Tr1::Val<'a>: ToDatumIter,
+        for<'a> Tr2::Val<'a>: ToDatumIter,
+    {
+        let left_permutation = plan.left_permutation.clone();
+        let right_permutation = plan.right_permutation.clone();
+        // Reuseable allocations for unpacking.
+        let mut left_datums = DatumVec::new();
+        let mut right_datums = DatumVec::new();
+
+        self.linear_join_spec
+            .render(left, right, move |key, left_val, right_val| {
+                // Keys containing nulls never match.
+                if key.to_datum_iter().any(|d| d.is_null()) {
+                    return None;
+                }
+                let mut left_local = left_datums.borrow();
+                left_local.extend(key.to_datum_iter());
+                left_local.extend(left_val.to_datum_iter());
+                let mut right_local = right_datums.borrow();
+                right_local.extend(key.to_datum_iter());
+                right_local.extend(right_val.to_datum_iter());
+
+                Some(SharedRow::pack(
+                    left_permutation
+                        .iter()
+                        .map(|c| left_local[*c])
+                        .chain(right_permutation.iter().map(|c| right_local[*c])),
+                ))
+            })
+    }
+
+    /// Produces the padded rows of `preserved` whose key is not present in `other`.
+    fn outer_join_unmatched<'s>(
+        &self,
+        preserved: &ArrangementFlavor<'s, T>,
+        other: &ArrangementFlavor<'s, T>,
+        padding: Padding,
+    ) -> VecCollection<'s, T, Row, Diff> {
+        let keys = match other {
+            ArrangementFlavor::Local(oks, _) => {
+                distinct_keys::<_, _, RowRowBuilder<_, _>, RowRowSpine<_, _>>(
+                    oks.clone(),
+                    "OuterJoinKeys local",
+                )
+            }
+            ArrangementFlavor::Trace(_, oks, _) => {
+                distinct_keys::<_, _, RowRowBuilder<_, _>, RowRowSpine<_, _>>(
+                    oks.clone(),
+                    "OuterJoinKeys trace",
+                )
+            }
+        };
+        let matched = match preserved {
+            ArrangementFlavor::Local(oks, _) => {
+                self.outer_join_semijoin(oks.clone(), keys, padding.clone())
+            }
+            ArrangementFlavor::Trace(_, oks, _) => {
+                self.outer_join_semijoin(oks.clone(), keys, padding.clone())
+            }
+        };
+        // The errors of `preserved` have already been collected by the caller.
+        let (all, _errs) = preserved.flat_map(None, usize::MAX, move |datums, t, r| {
+            Some((padding.apply(datums), t, r))
+        });
+        all.as_collection().concat(matched.negate())
+    }
+
+    /// Produces the padded rows of `preserved` whose key is present in `keys`.
+    fn outer_join_semijoin<'s, Tr1, Tr2>(
+        &self,
+        preserved: Arranged<'s, Tr1>,
+        keys: Arranged<'s, Tr2>,
+        padding: Padding,
+    ) -> VecCollection<'s, T, Row, Diff>
+    where
+        Tr1: TraceReader<Time = T, Diff = Diff> + Clone + 'static,
+        Tr2: for<'a> TraceReader<Key<'a> = Tr1::Key<'a>, Time = T, Diff = Diff> + Clone + 'static,
+        for<'a> Tr1::Key<'a>: ToDatumIter,
+        for<'a> Tr1::Val<'a>: ToDatumIter,
+    {
+        // Reuseable allocation for unpacking.
+        let mut datums = DatumVec::new();
+
+        self.linear_join_spec
+            .render(preserved, keys, move |key, val, _| {
+                let mut datums_local = datums.borrow();
+                datums_local.extend(key.to_datum_iter());
+                datums_local.extend(val.to_datum_iter());
+                Some(padding.apply(&datums_local))
+            })
+    }
+}
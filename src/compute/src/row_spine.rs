@@ -16,6 +16,8 @@ pub use self::spines::{
 };
 use differential_dataflow::trace::implementations::OffsetList;
 
+pub(crate) mod spill;
+
 /// Spines specialized to contain `Row` types in keys and values.
 mod spines {
     use std::rc::Rc;
@@ -123,6 +125,18 @@ mod container {
         pub fn heap_size(&self, callback: impl FnMut(usize, usize)) {
             self.bytes.heap_size(callback)
         }
+
+        /// Visit contained spilled regions to determine their size and capacity.
+        #[inline]
+        pub fn spilled_size(&self, callback: impl FnMut(usize, usize)) {
+            self.bytes.spilled_size(callback)
+        }
+
+        /// Allow containers merged from this container to spill to disk.
+        #[inline]
+        pub fn enable_spill(&self) {
+            self.bytes.enable_spill()
+        }
     }
 
     impl BatchContainer for DatumContainer {
@@ -292,6 +306,7 @@ mod container {
     #[cfg(test)]
     mod tests {
         use crate::row_spine::DatumContainer;
+        use crate::row_spine::spill::{set_spill_config, spilled_bytes};
         use differential_dataflow::trace::implementations::BatchContainer;
         use mz_repr::adt::date::Date;
         use mz_repr::adt::interval::Interval;
@@ -360,25 +375,86 @@ mod container {
                 Datum::String("العَرَبِيَّة"),
             ]);
         }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `mmap`
+        fn test_spilled_merge() {
+            let directory = tempfile::tempdir().expect("scratch directory");
+            set_spill_config(Some(directory.path().to_path_buf()), 0);
+
+            let rows = (0..100)
+                .map(|i| Row::pack_slice(&[Datum::String(&format!("{i:064}"))]))
+                .collect::<Vec<_>>();
+            let byte_len = rows.iter().map(|row| row.data().len()).sum::<usize>();
+
+            // Containers are built in memory, and only merged containers spill.
+            let mut container = DatumContainer::with_capacity(byte_len);
+            for row in &rows {
+                container.push_own(row);
+            }
+            container.enable_spill();
+            let empty = DatumContainer::with_capacity(0);
+            let mut merged = DatumContainer::merge_capacity(&container, &empty);
+            for index in 0..container.len() {
+                merged.push_ref(container.index(index));
+            }
+            assert_eq!(spilled_bytes(), byte_len);
+
+            for (index, row) in rows.iter().enumerate() {
+                assert_eq!(merged.index(index).to_row(), *row);
+            }
+
+            // Spilled bytes are reported separately from the heap.
+            let (mut heap_size, mut spilled_size) = (0, 0);
+            container.heap_size(|size, _capacity| heap_size += size);
+            assert!(heap_size >= byte_len);
+            heap_size = 0;
+            merged.heap_size(|size, _capacity| heap_size += size);
+            merged.spilled_size(|size, _capacity| spilled_size += size);
+            assert!(heap_size < byte_len);
+            assert_eq!(spilled_size, byte_len);
+
+            drop(merged);
+            assert_eq!(spilled_bytes(), 0);
+            set_spill_config(None, usize::MAX);
+        }
     }
 }
 
 mod bytes_container {
 
+    use std::cell::Cell;
+
     use differential_dataflow::trace::implementations::BatchContainer;
     use timely::container::PushInto;
 
     use mz_ore::region::Region;
 
+    use crate::row_spine::spill::SpillRegion;
+
     /// A slice container with four bytes overhead per slice.
     pub struct BytesContainer {
         /// Total length of `batches`, maintained because recomputation is expensive.
         length: usize,
         batches: Vec<BytesBatch>,
+        /// Whether large allocations of containers merged from this container may spill to disk.
+        ///
+        /// This is a `Cell` because arrangements opt in after their batches have been formed and
+        /// shared.
+        spill: Cell<bool>,
     }
 
     impl BytesContainer {
+        /// Allow containers merged from this container to spill to disk.
+        #[inline]
+        pub fn enable_spill(&self) {
+            self.spill.set(true);
+        }
+
         /// Visit contained allocations to determine their size and capacity.
+        ///
+        /// Spilled regions live on disk and are not part of the heap, see
+        /// [`BytesContainer::spilled_size`].
         #[inline]
         pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
             // Calculate heap size for local, stash, and stash entries
@@ -388,7 +464,19 @@ mod bytes_container {
             );
             for batch in self.batches.iter() {
                 batch.offsets.heap_size(&mut callback);
-                callback(batch.storage.len(), batch.storage.capacity());
+                if let BytesStorage::Region(region) = &batch.storage {
+                    callback(region.len(), region.capacity());
+                }
+            }
+        }
+
+        /// Visit contained spilled regions to determine their size and capacity.
+        #[inline]
+        pub fn spilled_size(&self, mut callback: impl FnMut(usize, usize)) {
+            for batch in self.batches.iter() {
+                if let BytesStorage::Spilled(region) = &batch.storage {
+                    callback(region.len(), region.capacity());
+                }
             }
         }
     }
@@ -420,14 +508,15 @@ mod bytes_container {
 
         fn clear(&mut self) {
             self.batches.clear();
-            self.batches.push(BytesBatch::with_capacities(0, 0));
+            self.batches.push(BytesBatch::with_capacities(0, 0, false));
             self.length = 0;
         }
 
         fn with_capacity(size: usize) -> Self {
             Self {
                 length: 0,
-                batches: vec![BytesBatch::with_capacities(size, size)],
+                batches: vec![BytesBatch::with_capacities(size, size, false)],
+                spill: Cell::new(false),
             }
         }
 
@@ -442,9 +531,11 @@ mod bytes_container {
                 item_cap += batch.offsets.len() - 1;
                 byte_cap += batch.storage.len();
             }
+            let spill = cont1.spill.get() || cont2.spill.get();
             Self {
                 length: 0,
-                batches: vec![BytesBatch::with_capacities(item_cap, byte_cap)],
+                batches: vec![BytesBatch::with_capacities(item_cap, byte_cap, spill)],
+                spill: Cell::new(spill),
             }
         }
 
@@ -480,7 +571,8 @@ mod bytes_container {
                     // double the lengths from `batch`.
                     let item_cap = 2 * batch.offsets.len();
                     let byte_cap = std::cmp::max(2 * batch.storage.capacity(), item.len());
-                    let mut new_batch = BytesBatch::with_capacities(item_cap, byte_cap);
+                    let mut new_batch =
+                        BytesBatch::with_capacities(item_cap, byte_cap, self.spill.get());
                     assert!(new_batch.try_push(item));
                     self.batches.push(new_batch);
                 }
@@ -493,10 +585,54 @@ mod bytes_container {
     /// The backing storage for this batch will not be resized.
     pub struct BytesBatch {
         offsets: crate::row_spine::OffsetOptimized,
-        storage: Region<u8>,
+        storage: BytesStorage,
         len: usize,
     }
 
+    /// The backing storage of a [`BytesBatch`], either in memory or spilled to disk.
+    enum BytesStorage {
+        Region(Region<u8>),
+        Spilled(SpillRegion),
+    }
+
+    impl BytesStorage {
+        #[inline]
+        fn len(&self) -> usize {
+            match self {
+                BytesStorage::Region(region) => region.len(),
+                BytesStorage::Spilled(region) => region.len(),
+            }
+        }
+
+        #[inline]
+        fn capacity(&self) -> usize {
+            match self {
+                BytesStorage::Region(region) => region.capacity(),
+                BytesStorage::Spilled(region) => region.capacity(),
+            }
+        }
+
+        #[inline]
+        fn extend_from_slice(&mut self, slice: &[u8]) {
+            match self {
+                BytesStorage::Region(region) => region.extend_from_slice(slice),
+                BytesStorage::Spilled(region) => region.extend_from_slice(slice),
+            }
+        }
+    }
+
+    impl std::ops::Deref for BytesStorage {
+        type Target = [u8];
+
+        #[inline]
+        fn deref(&self) -> &[u8] {
+            match self {
+                BytesStorage::Region(region) => &region[..],
+                BytesStorage::Spilled(region) => &region[..],
+            }
+        }
+    }
+
     impl BytesBatch {
         /// Either accepts the slice and returns true,
         /// or does not and returns false.
@@ -522,13 +658,21 @@ mod bytes_container {
             self.len
         }
 
-        fn with_capacities(item_cap: usize, byte_cap: usize) -> Self {
+        /// Allocate a batch, spilling its storage to disk if `spill` is set and the spill
+        /// configuration permits.
+        fn with_capacities(item_cap: usize, byte_cap: usize, spill: bool) -> Self {
             // TODO: be wary of `byte_cap` greater than 2^32.
             let mut offsets = crate::row_spine::OffsetOptimized::with_capacity(item_cap + 1);
             offsets.push_into(0);
+            let byte_cap = byte_cap.next_power_of_two();
+            let storage = spill
+                .then(|| SpillRegion::new(byte_cap))
+                .flatten()
+                .map(BytesStorage::Spilled)
+                .unwrap_or_else(|| BytesStorage::Region(Region::new_auto(byte_cap)));
             Self {
                 offsets,
-                storage: Region::new_auto(byte_cap.next_power_of_two()),
+                storage,
                 len: 0,
             }
         }
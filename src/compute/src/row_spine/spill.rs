@@ -0,0 +1,147 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! File-backed storage for large batches of arrangements that may spill to disk.
+//!
+//! Arrangements of indexes created with `STORAGE = 'disk'` allocate the bytes of large merged
+//! batches in files in the replica's scratch directory instead of in memory. The files are
+//! mapped into memory, so reads work as for in-memory batches, but the kernel writes the pages
+//! back to disk and can evict them under memory pressure. Recently built and small batches, which
+//! see most of the merge activity, remain in memory.
+
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+
+use memmap2::MmapMut;
+use mz_ore::cast::CastFrom;
+use tracing::warn;
+
+/// The granularity at which we ask the kernel to write back spilled bytes.
+const FLUSH_CHUNK_BYTES: usize = 2 << 20;
+
+/// Configuration of spilling, per worker thread.
+struct SpillConfig {
+    /// The directory to create spill files in, if any.
+    directory: Option<PathBuf>,
+    /// The minimum capacity in bytes of an allocation to be spilled.
+    threshold_bytes: usize,
+}
+
+thread_local! {
+    static SPILL_CONFIG: RefCell<SpillConfig> = const {
+        RefCell::new(SpillConfig {
+            directory: None,
+            threshold_bytes: usize::MAX,
+        })
+    };
+    /// The number of bytes stored in live spilled regions of this worker thread.
+    static SPILLED_BYTES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Configure where and from which size on batches spill. Applies to future allocations.
+pub fn set_spill_config(directory: Option<PathBuf>, threshold_bytes: usize) {
+    SPILL_CONFIG.with_borrow_mut(|config| {
+        config.directory = directory;
+        config.threshold_bytes = threshold_bytes;
+    });
+}
+
+/// The number of bytes that arrangements of this worker thread currently store in spilled regions.
+///
+/// These bytes are not part of the heap size that arrangements report.
+pub fn spilled_bytes() -> usize {
+    SPILLED_BYTES.get()
+}
+
+/// A fixed-capacity byte region backed by an anonymous file in the scratch directory.
+pub struct SpillRegion {
+    map: MmapMut,
+    len: usize,
+    /// Offset up to which we have requested write-back.
+    flushed: usize,
+}
+
+impl SpillRegion {
+    /// Allocate a spilled region with capacity for `capacity` bytes.
+    ///
+    /// Returns `None` if spilling is not configured, if `capacity` is below the spill threshold,
+    /// or if creating the backing file fails. Callers are expected to fall back to in-memory
+    /// storage in that case.
+    pub fn new(capacity: usize) -> Option<Self> {
+        let directory = SPILL_CONFIG.with_borrow(|config| {
+            (capacity >= config.threshold_bytes)
+                .then(|| config.directory.clone())
+                .flatten()
+        })?;
+
+        let map = tempfile::tempfile_in(&directory)
+            .and_then(|file| {
+                file.set_len(u64::cast_from(capacity))?;
+                // SAFETY: The file is unlinked and only referenced by this mapping, so no other
+                // process or thread can modify or truncate it underneath us.
+                unsafe { MmapMut::map_mut(&file) }
+            })
+            .inspect_err(|err| warn!(?directory, capacity, "failed to spill region: {err}"))
+            .ok()?;
+
+        Some(Self {
+            map,
+            len: 0,
+            flushed: 0,
+        })
+    }
+
+    /// The number of bytes that can be stored in the region.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.map.len()
+    }
+
+    /// The number of bytes stored in the region.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Append `slice` to the region.
+    ///
+    /// Panics if the region does not have enough capacity left.
+    #[inline]
+    pub fn extend_from_slice(&mut self, slice: &[u8]) {
+        let end = self.len + slice.len();
+        self.map[self.len..end].copy_from_slice(slice);
+        self.len = end;
+        SPILLED_BYTES.set(SPILLED_BYTES.get() + slice.len());
+
+        // Batches are written once and front to back, so completed chunks will not change
+        // anymore. Start their write-back early to keep the amount of dirty pages small.
+        if self.len - self.flushed >= FLUSH_CHUNK_BYTES {
+            let chunk = (self.len - self.flushed) / FLUSH_CHUNK_BYTES * FLUSH_CHUNK_BYTES;
+            if let Err(err) = self.map.flush_async_range(self.flushed, chunk) {
+                warn!("failed to flush spilled region: {err}");
+            }
+            self.flushed += chunk;
+        }
+    }
+}
+
+impl Drop for SpillRegion {
+    fn drop(&mut self) {
+        SPILLED_BYTES.set(SPILLED_BYTES.get().saturating_sub(self.len));
+    }
+}
+
+impl std::ops::Deref for SpillRegion {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.map[..self.len]
+    }
+}
@@ -367,6 +367,7 @@ impl<'w> Worker<'w> {
                 }
 
                 self.metrics.record_shared_row_metrics();
+                self.metrics.record_arrangement_spill_metrics();
             } else {
                 // We didn't perform maintenance, sleep until the next maintenance interval.
                 let next_maintenance = last_maintenance + maintenance_interval;
@@ -118,7 +118,7 @@ impl<'scope> SinkRender<'scope> for CopyToS3OneshotSinkConnection {
             sink.up_to.clone(),
             self.upload_info.clone(),
             compute_state.context.connection_context.clone(),
-            self.aws_connection.clone(),
+            self.connection.clone(),
             sink_id,
             self.connection_id,
             params,
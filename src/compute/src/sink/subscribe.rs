@@ -11,12 +11,14 @@ use std::any::Any;
 use std::cell::RefCell;
 use std::ops::DerefMut;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 use differential_dataflow::consolidation::consolidate_updates;
 use differential_dataflow::{AsCollection, VecCollection};
 use mz_compute_client::protocol::response::{SubscribeBatch, SubscribeResponse};
 use mz_compute_types::sinks::{ComputeSinkDesc, SubscribeSinkConnection};
 use mz_expr::{ColumnOrder, compare_columns};
+use mz_ore::cast::{CastFrom, CastLossy};
 use mz_ore::iter;
 use mz_repr::{Diff, GlobalId, Row, Timestamp, UpdateCollection};
 use mz_storage_types::controller::CollectionMetadata;
@@ -27,6 +29,7 @@ use timely::dataflow::channels::pact::Pipeline;
 use timely::dataflow::operators::generic::builder_rc::OperatorBuilder;
 use timely::progress::Antichain;
 use timely::progress::timestamp::Timestamp as TimelyTimestamp;
+use timely::scheduling::Activator;
 
 use crate::render::StartSignal;
 use crate::render::sinks::SinkRender;
@@ -67,6 +70,8 @@ impl<'scope> SinkRender<'scope> for SubscribeSinkConnection {
             sink.with_snapshot,
             as_of,
             sink.up_to.clone(),
+            self.emit_every_ms,
+            self.max_rows_per_second,
             subscribe_protocol_handle,
         );
 
@@ -88,17 +93,25 @@ fn subscribe<'scope>(
     with_snapshot: bool,
     as_of: Antichain<Timestamp>,
     up_to: Antichain<Timestamp>,
+    emit_every_ms: Option<u64>,
+    max_rows_per_second: Option<u64>,
     subscribe_protocol_handle: Rc<RefCell<Option<SubscribeProtocol>>>,
 ) {
     let name = format!("subscribe-{}", sink_id);
-    let mut op = OperatorBuilder::new(name, sinked_collection.scope());
+    let scope = sinked_collection.scope();
+    let mut op = OperatorBuilder::new(name, scope.clone());
     let mut ok_input = op.new_input(sinked_collection.inner, Pipeline);
     let mut err_input = op.new_input(err_collection.inner, Pipeline);
+    let operator_info = op.operator_info();
 
     op.build(|_cap| {
         let mut rows_to_emit = Vec::new();
         let mut errors_to_emit = Vec::new();
         let mut finished = false;
+        let mut rate_limiter = max_rows_per_second
+            .map(|max_rows_per_second| RateLimiter::new(max_rows_per_second, Instant::now()));
+        // Used to reschedule the operator once held-back updates can be emitted.
+        let activator = scope.activator_for(operator_info.address);
 
         move |frontiers| {
             if finished {
@@ -123,46 +136,171 @@ fn subscribe<'scope>(
                 beyond_as_of && before_up_to
             };
 
+            // With `EMIT EVERY`, updates are moved to the end of their window, so that all updates
+            // within a window are consolidated and emitted together.
+            let emit_time = |time: Timestamp| match emit_every_ms {
+                Some(window_ms) => window_end(time, window_ms, &up_to),
+                None => time,
+            };
+
             ok_input.for_each(|_, data| {
                 for (row, time, diff) in data.drain(..) {
                     if should_emit(&time) {
-                        rows_to_emit.push((time, row, diff));
+                        rows_to_emit.push((emit_time(time), row, diff));
                     }
                 }
             });
             err_input.for_each(|_, data| {
                 for (error, time, diff) in data.drain(..) {
                     if should_emit(&time) {
-                        errors_to_emit.push((time, error, diff));
+                        errors_to_emit.push((emit_time(time), error, diff));
                     }
                 }
             });
 
+            // Only report progress at window boundaries, so that clients observe a single batch
+            // per window.
+            let emit_frontier = match emit_every_ms {
+                Some(window_ms) => window_start(&frontier, window_ms),
+                None => frontier.clone(),
+            };
+
             if let Some(subscribe_protocol) = subscribe_protocol_handle.borrow_mut().deref_mut() {
-                subscribe_protocol.send_batch(
-                    frontier.clone(),
+                let wait = subscribe_protocol.send_batch(
+                    emit_frontier,
                     &mut rows_to_emit,
                     &mut errors_to_emit,
+                    rate_limiter.as_mut(),
                 );
+                if let Some(wait) = wait {
+                    activator.activate_after(wait);
+                }
             }
 
             if PartialOrder::less_equal(&up_to, &frontier) {
-                finished = true;
-                // We are done; indicate this by sending a batch at the
-                // empty frontier.
-                if let Some(subscribe_protocol) = subscribe_protocol_handle.borrow_mut().deref_mut()
-                {
-                    subscribe_protocol.send_batch(
-                        Antichain::default(),
-                        &mut Vec::new(),
-                        &mut Vec::new(),
-                    );
+                // We are done; indicate this by sending a batch at the empty frontier. Updates
+                // that are still buffered, because of window rounding or rate limiting, are sent
+                // along with it. If the rate limiter holds some of them back, we try again once
+                // it has scheduled us.
+                match subscribe_protocol_handle.borrow_mut().deref_mut() {
+                    Some(subscribe_protocol) => {
+                        let wait = subscribe_protocol.send_batch(
+                            Antichain::default(),
+                            &mut rows_to_emit,
+                            &mut errors_to_emit,
+                            rate_limiter.as_mut(),
+                        );
+                        if let Some(wait) = wait {
+                            activator.activate_after(wait);
+                        }
+                        finished = rows_to_emit.is_empty();
+                    }
+                    None => finished = true,
                 }
             }
         }
     });
 }
 
+/// Returns the end of the `EMIT EVERY` window containing `time`.
+///
+/// Windows are aligned to multiples of `window_ms`. The result is clamped to remain before
+/// `up_to`, which `time` is known to be before.
+fn window_end(time: Timestamp, window_ms: u64, up_to: &Antichain<Timestamp>) -> Timestamp {
+    let ms = u64::from(time);
+    let end = match ms % window_ms {
+        0 => ms,
+        rem => ms.saturating_add(window_ms - rem),
+    };
+    let end = Timestamp::from(end);
+    match up_to.as_option().and_then(|up_to| up_to.step_back()) {
+        Some(last) => std::cmp::min(end, last),
+        None => end,
+    }
+}
+
+/// Returns `frontier` rounded down to the closest `EMIT EVERY` window boundary.
+///
+/// All updates that are not yet complete at `frontier` have been moved to window ends at or
+/// beyond the returned frontier, so it is safe to report it as progress.
+fn window_start(frontier: &Antichain<Timestamp>, window_ms: u64) -> Antichain<Timestamp> {
+    frontier
+        .iter()
+        .map(|time| Timestamp::from(u64::from(time) / window_ms * window_ms))
+        .collect()
+}
+
+/// The number of seconds worth of updates a rate-limited subscribe may hold back before it fails.
+const MAX_BUFFERED_SECONDS: u64 = 60;
+
+/// A token bucket limiting the rate at which a subscribe emits updates.
+///
+/// The bucket holds at most one second worth of updates. Because the subscribe protocol cannot
+/// split a timestamp across batches, a timestamp is emitted once the bucket holds enough budget
+/// for all of its updates. Timestamps with more updates than that are emitted once the bucket is
+/// full, overdrawing it.
+struct RateLimiter {
+    max_rows_per_second: u64,
+    /// The number of updates that may currently be emitted.
+    budget: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    fn new(max_rows_per_second: u64, now: Instant) -> Self {
+        Self {
+            max_rows_per_second,
+            budget: f64::cast_lossy(max_rows_per_second),
+            last_refill: now,
+        }
+    }
+
+    /// Returns the frontier up to which updates in `rows` may be emitted at `now`, and consumes
+    /// budget for them.
+    ///
+    /// `rows` must be consolidated and sorted in reverse time order. The returned frontier is
+    /// never beyond `upper`. If some updates before `upper` are held back, also returns how long
+    /// to wait until they can be emitted.
+    fn limit(
+        &mut self,
+        upper: Antichain<Timestamp>,
+        rows: &[(Timestamp, Row, Diff)],
+        now: Instant,
+    ) -> (Antichain<Timestamp>, Option<Duration>) {
+        let rate = f64::cast_lossy(self.max_rows_per_second);
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.budget = (self.budget + elapsed * rate).min(rate);
+        self.last_refill = now;
+
+        let mut rest = rows;
+        while let Some((time, _, _)) = rest.last() {
+            if upper.less_equal(time) {
+                break;
+            }
+            let count = rest.iter().rev().take_while(|(t, _, _)| t == time).count();
+            let cost = f64::cast_lossy(count);
+            let required = cost.min(rate);
+            if self.budget < required {
+                let wait = Duration::from_secs_f64((required - self.budget) / rate);
+                return (Antichain::from_elem(*time), Some(wait));
+            }
+            self.budget -= cost;
+            rest = &rest[..rest.len() - count];
+        }
+        (upper, None)
+    }
+
+    /// Reports whether `buffered` held-back updates exceed what the subscribe may buffer.
+    fn exceeds_buffer(&self, buffered: usize) -> bool {
+        let max_buffered = self
+            .max_rows_per_second
+            .saturating_mul(MAX_BUFFERED_SECONDS);
+        u64::cast_from(buffered) > max_buffered
+    }
+}
+
 /// A type that guides the transmission of rows back to the coordinator.
 ///
 /// A protocol instance may `send` rows indefinitely in response to `send_batch` calls.
@@ -196,17 +334,23 @@ impl SubscribeProtocol {
     /// only actually send the first error received in a `SubscribeResponse`. Subsequent errors are
     /// dropped. To simplify life for the caller, this method still maintains the illusion that
     /// `errors` are handled the same way as `rows`.
+    ///
+    /// If a `rate_limiter` is provided, the batch may be sent with an `upper` less than the
+    /// requested one, holding back updates that would exceed the configured rate. In that case,
+    /// returns how long to wait until the held-back updates can be sent. A subscribe that holds
+    /// back more than [`MAX_BUFFERED_SECONDS`] worth of updates fails with an error.
     fn send_batch(
         &mut self,
         upper: Antichain<Timestamp>,
         rows: &mut Vec<(Timestamp, Row, Diff)>,
         errors: &mut Vec<(Timestamp, DataflowError, Diff)>,
-    ) {
+        rate_limiter: Option<&mut RateLimiter>,
+    ) -> Option<Duration> {
         // Only send a batch if both conditions hold:
         //  a) `upper` has reached or passed the sink's `as_of` frontier.
         //  b) `upper` is different from when we last sent a batch.
         if !PartialOrder::less_equal(&self.sink_as_of, &upper) || upper == self.prev_upper {
-            return;
+            return None;
         }
 
         // The compute protocol requires us to only send out consolidated batches.
@@ -230,23 +374,45 @@ impl SubscribeProtocol {
                     .reverse()
             });
         }
+        // Consolidate before rate limiting, so that updates that cancel out are not charged.
+        let consolidated = iter::consolidate_update_iter(rows.drain(..)).collect();
+        *rows = consolidated;
         consolidate_updates(errors);
 
+        let (upper, wait) = match rate_limiter {
+            Some(rate_limiter) if self.poison.is_none() => {
+                let (limited, wait) = rate_limiter.limit(upper.clone(), rows, Instant::now());
+                let held_back = rows.partition_point(|(t, _, _)| limited.less_equal(t))
+                    - rows.partition_point(|(t, _, _)| upper.less_equal(t));
+                if rate_limiter.exceeds_buffer(held_back) {
+                    // Fail the subscribe rather than buffering without bound. Poisoning it sends
+                    // the error with all updates up to `upper`, which drains the buffer.
+                    self.poison = Some(format!(
+                        "subscribe held back more than {MAX_BUFFERED_SECONDS} seconds of updates \
+                         because of MAX ROWS PER SECOND"
+                    ));
+                    (upper, None)
+                } else {
+                    (limited, wait)
+                }
+            }
+            _ => (upper, None),
+        };
+        if upper == self.prev_upper {
+            return wait;
+        }
+
         let ship_rows = {
             // Chop of the tail of the reverse-sorted buffer (ie. the prefix we care about) and ship
             // it, preserving the rest of the values for future iterations.
             let split_at = rows.partition_point(|(t, _, _)| upper.less_equal(t));
-            let ship_updates = rows[split_at..]
-                .iter()
-                .rev()
-                .map(|(t, r, d)| (r.as_row_ref(), t, *d));
             let len = rows[split_at..].len();
             // We can't estimate the total size of the consolidated bytes exactly without extra work,
             // so for now we initialize to the length times a small constant factor.
             let byte_len = len * 32;
             let mut builder = UpdateCollection::builder(byte_len, len);
-            for update in iter::consolidate_update_iter(ship_updates) {
-                builder.push(update);
+            for (t, r, d) in rows[split_at..].iter().rev() {
+                builder.push((r.as_row_ref(), t, *d));
             }
             rows.truncate(split_at);
             builder.build()
@@ -292,6 +458,7 @@ impl SubscribeProtocol {
             // to avoid sending `SubscribeResponse::DroppedAt`.
             self.subscribe_response_buffer = None;
         }
+        wait
     }
 }
 
@@ -305,3 +472,103 @@ impl Drop for SubscribeProtocol {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use mz_repr::{Datum, Diff, Row, Timestamp};
+    use timely::progress::Antichain;
+
+    use super::{RateLimiter, window_end, window_start};
+
+    fn ts(time: u64) -> Timestamp {
+        Timestamp::from(time)
+    }
+
+    fn frontier(time: u64) -> Antichain<Timestamp> {
+        Antichain::from_elem(ts(time))
+    }
+
+    /// Returns one update per entry in `times`, sorted in reverse time order.
+    fn updates(times: &[u64]) -> Vec<(Timestamp, Row, Diff)> {
+        let mut updates: Vec<_> = times
+            .iter()
+            .enumerate()
+            .map(|(i, time)| {
+                let row = Row::pack_slice(&[Datum::Int64(i64::try_from(i).unwrap())]);
+                (Timestamp::from(*time), row, Diff::ONE)
+            })
+            .collect();
+        updates.sort_by(|a, b| b.cmp(a));
+        updates
+    }
+
+    #[mz_ore::test]
+    fn test_window_end() {
+        let unbounded = Antichain::new();
+        assert_eq!(window_end(ts(0), 1000, &unbounded), ts(0));
+        assert_eq!(window_end(ts(1), 1000, &unbounded), ts(1000));
+        assert_eq!(window_end(ts(1000), 1000, &unbounded), ts(1000));
+        assert_eq!(window_end(ts(1001), 1000, &unbounded), ts(2000));
+        // Windows ending at or beyond `UP TO` are clamped to the last time before it.
+        assert_eq!(window_end(ts(1001), 1000, &frontier(1500)), ts(1499));
+        assert_eq!(window_end(ts(u64::MAX), 1000, &unbounded), ts(u64::MAX));
+    }
+
+    #[mz_ore::test]
+    fn test_window_start() {
+        assert_eq!(window_start(&frontier(0), 1000), frontier(0));
+        assert_eq!(window_start(&frontier(999), 1000), frontier(0));
+        assert_eq!(window_start(&frontier(1000), 1000), frontier(1000));
+        assert_eq!(window_start(&frontier(2500), 1000), frontier(2000));
+        assert_eq!(window_start(&Antichain::new(), 1000), Antichain::new());
+    }
+
+    #[mz_ore::test]
+    fn test_rate_limiter() {
+        let start = Instant::now();
+        let mut limiter = RateLimiter::new(2, start);
+        let rows = updates(&[100, 250, 1500, 2100]);
+
+        // The full bucket admits two updates; the third is held back.
+        let (upper, wait) = limiter.limit(Antichain::new(), &rows, start);
+        assert_eq!(upper, frontier(1500));
+        assert_eq!(wait, Some(Duration::from_millis(500)));
+
+        // Nothing is admitted before the bucket has refilled.
+        let (upper, _) = limiter.limit(Antichain::new(), &rows[..2], start);
+        assert_eq!(upper, frontier(1500));
+
+        // Half a second later, one more update is admitted.
+        let now = start + Duration::from_millis(500);
+        let (upper, wait) = limiter.limit(Antichain::new(), &rows[..2], now);
+        assert_eq!(upper, frontier(2100));
+        assert_eq!(wait, Some(Duration::from_millis(500)));
+
+        // Updates beyond the requested upper are not charged.
+        let now = now + Duration::from_secs(10);
+        let (upper, wait) = limiter.limit(frontier(2000), &rows[..1], now);
+        assert_eq!(upper, frontier(2000));
+        assert_eq!(wait, None);
+        let (upper, wait) = limiter.limit(Antichain::new(), &rows[..1], now);
+        assert_eq!(upper, Antichain::new());
+        assert_eq!(wait, None);
+    }
+
+    #[mz_ore::test]
+    fn test_rate_limiter_large_timestamp() {
+        let start = Instant::now();
+        let mut limiter = RateLimiter::new(2, start);
+        let rows = updates(&[100, 100, 100, 200]);
+
+        // A timestamp with more updates than the bucket holds is admitted once it is full, and
+        // overdraws it.
+        let (upper, wait) = limiter.limit(Antichain::new(), &rows, start);
+        assert_eq!(upper, frontier(200));
+        assert_eq!(wait, Some(Duration::from_secs(1)));
+
+        assert!(!limiter.exceeds_buffer(120));
+        assert!(limiter.exceeds_buffer(121));
+    }
+}
@@ -640,6 +640,7 @@ impl Controller {
         let aws_external_id_prefix = self.connection_context().aws_external_id_prefix.clone();
         let aws_connection_role_arn = self.connection_context().aws_connection_role_arn.clone();
         let persist_pubsub_url = self.persist_pubsub_url.clone();
+        let persist_blob_encryption = self.persist_blob_encryption;
         let secrets_args = self.secrets_args.to_flags();
 
         // TODO(teskje): use the same values as for compute?
@@ -741,6 +742,9 @@ impl Controller {
                     if location.allocation.is_cc {
                         args.push("--is-cc".into());
                     }
+                    if persist_blob_encryption {
+                        args.push("--persist-blob-encryption".into());
+                    }
 
                     // If swap is enabled, make the replica limit its own heap usage based on the
                     // configured memory and disk limits.
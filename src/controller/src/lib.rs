@@ -176,6 +176,8 @@ pub struct Controller {
 
     /// The URL for Persist PubSub.
     persist_pubsub_url: String,
+    /// Whether persist blobs may be encrypted, which replicas must be told.
+    persist_blob_encryption: bool,
 
     /// Arguments for secrets readers.
     secrets_args: SecretsReaderCliArgs,
@@ -260,6 +262,7 @@ impl Controller {
             metrics_rx: _,
             now: _,
             persist_pubsub_url: _,
+            persist_blob_encryption: _,
             secrets_args: _,
             unfulfilled_watch_sets_by_object: _,
             unfulfilled_watch_sets,
@@ -650,6 +653,7 @@ impl Controller {
         let wallclock_lag_fn = WallclockLagFn::new(now_fn);
 
         let controller_metrics = ControllerMetrics::new(&config.metrics_registry);
+        let persist_blob_encryption = config.persist_clients.cfg().blob_keys.is_some();
 
         let txns_metrics = Arc::new(TxnMetrics::new(&config.metrics_registry));
         let collections_ctl = storage_collections::StorageCollectionsImpl::new(
@@ -711,6 +715,7 @@ impl Controller {
             metrics_rx,
             now: config.now,
             persist_pubsub_url: config.persist_pubsub_url,
+            persist_blob_encryption,
             secrets_args: config.secrets_args,
             unfulfilled_watch_sets_by_object: BTreeMap::new(),
             unfulfilled_watch_sets: BTreeMap::new(),
@@ -58,6 +58,7 @@ use mz_ore::metrics::{MetricsRegistry, register_runtime_metrics};
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::task::RuntimeExt;
 use mz_ore::url::SensitiveUrl;
+use mz_persist::encryption::SecretsBlobKeys;
 use mz_persist_client::PersistLocation;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::cfg::PersistConfig;
@@ -381,6 +382,14 @@ pub struct Args {
     /// of threads returned by [`num_cpus::get`].
     #[clap(long, env = "PERSIST_ISOLATED_RUNTIME_THREADS")]
     persist_isolated_runtime_threads: Option<isize>,
+    /// Whether persist blobs may be encrypted, with key-encryption keys read
+    /// from secrets.
+    ///
+    /// The key to encrypt new blobs with is chosen by the
+    /// `persist_blob_encryption_key_id` configuration parameter. This flag is
+    /// passed through to `clusterd`.
+    #[clap(long, env = "PERSIST_BLOB_ENCRYPTION")]
+    persist_blob_encryption: bool,
     /// The interval in seconds at which to collect storage usage information.
     #[clap(
         long,
@@ -942,6 +951,10 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, now.clone(), mz_dyncfgs::all_dyncfgs());
     // Start with compaction disabled, later enable it if we're not in read-only mode.
     persist_config.disable_compaction();
+    if args.persist_blob_encryption {
+        persist_config.blob_keys =
+            Some(Arc::new(SecretsBlobKeys::new(Arc::clone(&secrets_reader))));
+    }
 
     let persist_pubsub_server = PersistGrpcPubSubServer::new(&persist_config, &metrics_registry);
     let persist_pubsub_client = persist_pubsub_server.new_same_process_connection();
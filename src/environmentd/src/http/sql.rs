@@ -1475,7 +1475,8 @@ async fn execute_stmt<S: ResultSender>(
     let tag = res.tag();
 
     Ok(match res {
-        ExecuteResponse::CreatedConnection { .. }
+        ExecuteResponse::CreatedChannel
+        | ExecuteResponse::CreatedConnection { .. }
         | ExecuteResponse::CreatedDatabase { .. }
         | ExecuteResponse::CreatedSchema { .. }
         | ExecuteResponse::CreatedRole
@@ -1492,11 +1493,14 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedMaterializedView { .. }
         | ExecuteResponse::CreatedContinualTask { .. }
         | ExecuteResponse::CreatedType
+        | ExecuteResponse::CreatedFunction
+        | ExecuteResponse::CreatedSequence
         | ExecuteResponse::CreatedNetworkPolicy
         | ExecuteResponse::Comment
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
         | ExecuteResponse::DiscardedAll
+        | ExecuteResponse::DroppedChannel
         | ExecuteResponse::DroppedObject(_)
         | ExecuteResponse::DroppedOwned
         | ExecuteResponse::EmptyQuery
@@ -1504,6 +1508,10 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::GrantedRole
         | ExecuteResponse::Inserted(_)
         | ExecuteResponse::Copied(_)
+        | ExecuteResponse::Listened
+        | ExecuteResponse::Merged(_)
+        | ExecuteResponse::Notified
+        | ExecuteResponse::Unlistened
         | ExecuteResponse::Raised
         | ExecuteResponse::ReassignOwned
         | ExecuteResponse::RevokedPrivilege
@@ -649,8 +649,14 @@ fn pg_test_inner(path: &Path, mz_flags: bool) {
             server.enable_feature_flags(&[
                 "enable_copy_to_expr",
                 "enable_create_table_from_source",
+                "enable_listen_notify",
                 "enable_load_generator_datums",
+                "enable_merge_statement",
                 "enable_raise_statement",
+                "enable_sequences",
+                "enable_table_check_constraints",
+                "enable_table_key_enforcement",
+                "unsafe_enable_table_keys",
                 "unsafe_enable_unorchestrated_cluster_replicas",
                 "unsafe_enable_unsafe_functions",
             ]);
@@ -775,6 +781,24 @@ fn test_pgtest_mz_copy_from_csv() {
     pg_test_inner(Path::new("../../test/pgtest-mz/copy-from-csv.pt"), true);
 }
 
+#[mz_ore::test]
+fn test_pgtest_mz_copy_from_checks() {
+    pg_test_inner(Path::new("../../test/pgtest-mz/copy-from-checks.pt"), true);
+}
+
+#[mz_ore::test]
+fn test_pgtest_mz_copy_from_keys() {
+    pg_test_inner(Path::new("../../test/pgtest-mz/copy-from-keys.pt"), true);
+}
+
+#[mz_ore::test]
+fn test_pgtest_mz_copy_from_identity() {
+    pg_test_inner(
+        Path::new("../../test/pgtest-mz/copy-from-identity.pt"),
+        true,
+    );
+}
+
 #[mz_ore::test]
 fn test_pgtest_mz_copy_to() {
     pg_test_inner(Path::new("../../test/pgtest-mz/copy-to.pt"), true);
@@ -795,6 +819,16 @@ fn test_pgtest_mz_desc() {
     pg_test_inner(Path::new("../../test/pgtest-mz/desc.pt"), true);
 }
 
+#[mz_ore::test]
+fn test_pgtest_mz_listen_notify() {
+    pg_test_inner(Path::new("../../test/pgtest-mz/listen-notify.pt"), true);
+}
+
+#[mz_ore::test]
+fn test_pgtest_mz_merge() {
+    pg_test_inner(Path::new("../../test/pgtest-mz/merge.pt"), true);
+}
+
 #[mz_ore::test]
 fn test_pgtest_mz_notice() {
     pg_test_inner(Path::new("../../test/pgtest-mz/notice.pt"), true);
@@ -32,6 +32,7 @@ use mz_environmentd::test_util::{
     self, KAFKA_ADDRS, MzTimestamp, PostgresErrorExt, TestServerWithRuntime, get_explain_timestamp,
     get_explain_timestamp_determination, try_get_explain_timestamp,
 };
+use mz_gcp_util::gcs::{GcsClient, GcsCredentials};
 use mz_ore::collections::CollectionExt;
 use mz_ore::error::ErrorExt;
 use mz_ore::now::{EpochMillis, NOW_ZERO, NowFn};
@@ -3898,3 +3899,84 @@ async fn test_serialized_ddl_cancel() {
     // run, because spawn_blocking (used by optimization) are waited upon during Drop. Thus, don't
     // pass very high durations to mz_sleep so that we aren't waiting for long.
 }
+
+/// Tests `COPY ... TO` a GCS bucket end to end, against the `fake-gcs-server`
+/// that `ci/test/cargo-test/mzcompose.py` points `MZ_GCS_UPLOADER_TEST_ENDPOINT`
+/// at.
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // too slow
+async fn test_copy_to_gcs() {
+    const BUCKET: &str = "mz-test-copy-to-gcs";
+
+    let endpoint = match std::env::var("MZ_GCS_UPLOADER_TEST_ENDPOINT") {
+        Ok(endpoint) => endpoint,
+        Err(_) => {
+            if mz_ore::env::is_var_truthy("CI") {
+                panic!("CI is supposed to run this test but something has gone wrong!");
+            }
+            return;
+        }
+    };
+    let gcs = GcsClient::new(
+        Some(endpoint.clone()),
+        GcsCredentials::Anonymous,
+        reqwest::ClientBuilder::new(),
+    )
+    .await
+    .unwrap();
+    // The bucket will already exist on repeat runs.
+    let _ = gcs.create_bucket("test", BUCKET).await;
+
+    let server = test_util::TestHarness::default().start().await;
+    let client = server.connect().await.unwrap();
+
+    // Connections without a credential are only allowed for testing.
+    server
+        .enable_feature_flags(&["enable_gcs_connection"])
+        .await;
+    let err = client
+        .batch_execute(&format!(
+            "CREATE CONNECTION gcs TO GCS (ENDPOINT = '{endpoint}')"
+        ))
+        .await
+        .unwrap_err();
+    assert_contains!(
+        err.to_string_with_causes(),
+        "GCS connections without a CREDENTIAL is not available"
+    );
+    server
+        .enable_feature_flags(&["unsafe_enable_unauthenticated_gcs_connections"])
+        .await;
+    client
+        .batch_execute(&format!(
+            "CREATE CONNECTION gcs TO GCS (ENDPOINT = '{endpoint}')"
+        ))
+        .await
+        .unwrap();
+
+    let prefix = format!("copy_to/{}", uuid::Uuid::new_v4());
+    let copied = client
+        .execute(
+            &format!(
+                "COPY (SELECT generate_series(1, 3) AS a) TO 'gs://{BUCKET}/{prefix}' \
+                 WITH (GCS CONNECTION = gcs, FORMAT = 'csv')"
+            ),
+            &[],
+        )
+        .await
+        .unwrap();
+    assert_eq!(copied, 3);
+
+    let mut lines = Vec::new();
+    for object in gcs.list_all_objects(BUCKET, &prefix).await.unwrap() {
+        let response = gcs
+            .get_object(BUCKET, &object.name)
+            .await
+            .unwrap()
+            .expect("listed object exists");
+        let body = response.text().await.unwrap();
+        lines.extend(body.lines().map(|line| line.to_string()));
+    }
+    lines.sort();
+    assert_eq!(lines, ["1", "2", "3"]);
+}
@@ -265,6 +265,10 @@ pub fn enforce_linear_chains(expr: &mut MirRelationExpr) -> Result<(), ExplainEr
                     wrap_in_let(input);
                 }
             }
+            OuterJoin { left, right, .. } => {
+                wrap_in_let(left);
+                wrap_in_let(right);
+            }
             Union { base, inputs } => {
                 wrap_in_let(base);
                 for input in inputs {
@@ -767,6 +767,42 @@ impl MirRelationExpr {
                 )?;
                 self.fmt_analyses(f, ctx)?;
             }
+            OuterJoin {
+                left,
+                right,
+                kind,
+                left_key,
+                right_key,
+            } => {
+                write!(f, "{}{}OuterJoin", ctx.indent, kind.name())?;
+                if !left_key.is_empty() {
+                    // Express the keys in terms of the output columns.
+                    let left_arity = left.arity();
+                    let right_key = right_key
+                        .iter()
+                        .map(|k| {
+                            let mut k = k.clone();
+                            k.visit_columns(|c| *c += left_arity);
+                            k
+                        })
+                        .collect::<Vec<_>>();
+                    let cols = self.column_names(ctx);
+                    let left_key = mode.seq(left_key, cols);
+                    let right_key = mode.seq(&right_key, cols);
+                    let on = separated(
+                        " AND ",
+                        left_key
+                            .zip_eq(right_key)
+                            .map(|(l, r)| separated(" = ", [l, r])),
+                    );
+                    write!(f, " on=({})", on)?;
+                }
+                self.fmt_analyses(f, ctx)?;
+                ctx.indented(|ctx| {
+                    left.fmt_text(f, ctx)?;
+                    right.fmt_text(f, ctx)
+                })?;
+            }
             Reduce {
                 group_key,
                 aggregates,
@@ -36,14 +36,14 @@ pub use linear::{MapFilterProject, memoize_expr};
 pub use relation::func::order_aggregate_datums as order_aggregate_datums_exported_for_benchmarking;
 pub use relation::func::{
     AggregateFunc, AnalyzedRegex, AnalyzedRegexOpts, CaptureGroupDesc, LagLeadType,
-    NaiveOneByOneAggr, OneByOneAggr, TableFunc,
+    NaiveOneByOneAggr, OneByOneAggr, SessionWindowBound, TableFunc,
 };
 pub use relation::join_input_mapper::JoinInputMapper;
 pub use relation::{
     AccessStrategy, AggregateExpr, CollectionPlan, ColumnOrder, JoinImplementation,
-    JoinInputCharacteristics, LetRecLimit, MirRelationExpr, RECURSION_LIMIT, RowComparator,
-    RowSetFinishing, RowSetFinishingIncremental, WindowFrame, WindowFrameBound, WindowFrameUnits,
-    canonicalize, compare_columns, non_nullable_columns,
+    JoinInputCharacteristics, LetRecLimit, MirRelationExpr, OuterJoinKind, RECURSION_LIMIT,
+    RowComparator, RowSetFinishing, RowSetFinishingIncremental, WindowFrame, WindowFrameBound,
+    WindowFrameUnits, canonicalize, compare_columns, non_nullable_columns,
 };
 pub use scalar::func::{self, BinaryFunc, UnaryFunc, UnmaterializableFunc, VariadicFunc};
 pub use scalar::{
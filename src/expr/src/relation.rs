@@ -43,7 +43,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::Id::Local;
 use crate::explain::{HumanizedExpr, HumanizerMode};
-use crate::relation::func::{AggregateFunc, LagLeadType, TableFunc};
+use crate::relation::func::{AggregateFunc, LagLeadType, SessionWindowBound, TableFunc};
 use crate::row::{RowCollection, RowCollectionIter};
 use crate::scalar::func::variadic::{
     JsonbBuildArray, JsonbBuildObject, ListCreate, ListIndex, MapBuild, RecordCreate,
@@ -233,6 +233,28 @@ pub enum MirRelationExpr {
         #[serde(default)]
         implementation: JoinImplementation,
     },
+    /// Join two collections on equal keys, retaining the records without a match.
+    ///
+    /// The result contains the equijoin of `left` and `right` on `left_key` and `right_key`,
+    /// followed by the records of `left` (for left and full joins) and of `right` (for right and
+    /// full joins) that have no match in the other input, padded with nulls. As with SQL `=`,
+    /// keys that contain a null never match.
+    ///
+    /// The runtime memory footprint of this operator is proportional to the sizes of both
+    /// inputs, plus the number of distinct keys of each input whose matches need to be tracked.
+    /// This may be reduced due to arrangements available at rendering time.
+    OuterJoin {
+        /// The left input.
+        left: Box<MirRelationExpr>,
+        /// The right input.
+        right: Box<MirRelationExpr>,
+        /// Which inputs retain their records without a match.
+        kind: OuterJoinKind,
+        /// Expressions over the columns of `left`, to be matched with `right_key`.
+        left_key: Vec<MirScalarExpr>,
+        /// Expressions over the columns of `right`, to be matched with `left_key`.
+        right_key: Vec<MirScalarExpr>,
+    },
     /// Group a dataflow by some columns and aggregate over each group
     ///
     /// The runtime memory footprint of this operator is at most proportional to the
@@ -505,6 +527,24 @@ impl MirRelationExpr {
                 }
                 types
             }
+            OuterJoin { kind, .. } => {
+                // The columns of an input become nullable if records of the other input are
+                // padded with nulls.
+                let mut types = input_types.next().unwrap().clone();
+                if kind.retains_right() {
+                    for typ in types.iter_mut() {
+                        typ.nullable = true;
+                    }
+                }
+                let mut right = input_types.next().unwrap().clone();
+                if kind.retains_left() {
+                    for typ in right.iter_mut() {
+                        typ.nullable = true;
+                    }
+                }
+                types.extend(right);
+                types
+            }
             Reduce {
                 group_key,
                 aggregates,
@@ -827,6 +867,34 @@ impl MirRelationExpr {
 
                 input_mapper.global_keys(input_keys, equivalences)
             }
+            OuterJoin {
+                kind,
+                left_key,
+                right_key,
+                ..
+            } => {
+                // If the key of one input is unique in the other input, then each of its records
+                // contributes exactly one output record, and its unique keys are retained. This
+                // does not hold for full joins, which also pad records of the other input with
+                // nulls.
+                let left_arity = input_arities.next().unwrap();
+                let left_keys = input_keys.next().unwrap();
+                let right_keys = input_keys.next().unwrap();
+                let covers = |keys: &Vec<Vec<usize>>, key: &Vec<MirScalarExpr>| {
+                    keys.iter().any(|cols| {
+                        cols.iter()
+                            .all(|c| key.contains(&MirScalarExpr::column(*c)))
+                    })
+                };
+                match kind {
+                    OuterJoinKind::Left if covers(right_keys, right_key) => left_keys.clone(),
+                    OuterJoinKind::Right if covers(left_keys, left_key) => right_keys
+                        .iter()
+                        .map(|cols| cols.iter().map(|c| c + left_arity).collect())
+                        .collect(),
+                    _ => vec![],
+                }
+            }
             Reduce { group_key, .. } => {
                 // The group key should form a key, but we might already have
                 // keys that are subsets of the group key, and should retain
@@ -1040,7 +1108,7 @@ impl MirRelationExpr {
             Project { outputs, .. } => outputs.len(),
             Map { scalars, .. } => input_arities.next().unwrap() + scalars.len(),
             FlatMap { func, .. } => input_arities.next().unwrap() + func.output_arity(),
-            Join { .. } => input_arities.sum(),
+            Join { .. } | OuterJoin { .. } => input_arities.sum(),
             Reduce {
                 input: _,
                 group_key,
@@ -1320,6 +1388,25 @@ impl MirRelationExpr {
         }
     }
 
+    /// Constructs an outer join of `self` and `right` on equal keys.
+    ///
+    /// For further details consult the documentation for [`MirRelationExpr::OuterJoin`].
+    pub fn outer_join(
+        self,
+        right: MirRelationExpr,
+        kind: OuterJoinKind,
+        left_key: Vec<MirScalarExpr>,
+        right_key: Vec<MirScalarExpr>,
+    ) -> Self {
+        MirRelationExpr::OuterJoin {
+            left: Box::new(self),
+            right: Box::new(right),
+            kind,
+            left_key,
+            right_key,
+        }
+    }
+
     /// Perform a key-wise reduction / aggregation.
     ///
     /// The `group_key` argument indicates columns in the input collection that should
@@ -1748,6 +1835,15 @@ impl MirRelationExpr {
                     JoinImplementation::Unimplemented => {} // No scalar exprs
                 }
             }
+            OuterJoin {
+                left_key,
+                right_key,
+                ..
+            } => {
+                for k in left_key.iter_mut().chain(right_key.iter_mut()) {
+                    f(k)?;
+                }
+            }
             ArrangeBy { keys, .. } => {
                 for key in keys {
                     for s in key {
@@ -1877,6 +1973,15 @@ impl MirRelationExpr {
                     JoinImplementation::Unimplemented => {} // No scalar exprs
                 }
             }
+            OuterJoin {
+                left_key,
+                right_key,
+                ..
+            } => {
+                for k in left_key.iter().chain(right_key.iter()) {
+                    f(k)?;
+                }
+            }
             ArrangeBy { keys, .. } => {
                 for key in keys {
                     for s in key {
@@ -2273,6 +2378,10 @@ impl MirRelationExpr {
             Join { inputs, .. } => {
                 rest = Some(inputs);
             }
+            OuterJoin { left, right, .. } => {
+                first = Some(&**left);
+                second = Some(&**right);
+            }
             Union { base, inputs } => {
                 first = Some(&**base);
                 rest = Some(inputs);
@@ -2318,6 +2427,10 @@ impl MirRelationExpr {
             Join { inputs, .. } => {
                 rest = Some(inputs);
             }
+            OuterJoin { left, right, .. } => {
+                first = Some(&mut **left);
+                second = Some(&mut **right);
+            }
             Union { base, inputs } => {
                 first = Some(&mut **base);
                 rest = Some(inputs);
@@ -2550,6 +2663,7 @@ impl AggregateExpr {
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::SessionWindow { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => self.expr.is_literal_err(),
@@ -2801,6 +2915,47 @@ impl AggregateExpr {
                 )
             }
 
+            // The input type for SessionWindow is ((OriginalRow, (Time, Reach)), OrderByExprs...)
+            AggregateFunc::SessionWindow { bound, .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type_with_orig_row = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+                let session_window_return_type =
+                    return_type_with_orig_row.unwrap_record_element_type()[0].clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the encoded args
+                let encoded_args =
+                    tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                let (result_expr, column_name) =
+                    Self::on_unique_session_window(bound, encoded_args, session_window_return_type);
+
+                MirScalarExpr::call_variadic(
+                    ListCreate {
+                        elem_type: SqlScalarType::from_repr(&return_type_with_orig_row),
+                    },
+                    vec![MirScalarExpr::call_variadic(
+                        RecordCreate {
+                            field_names: vec![column_name, ColumnName::from("?record?")],
+                        },
+                        vec![result_expr, original_row],
+                    )],
+                )
+            }
+
             // The input type for window aggs is ((OriginalRow, InputValue), OrderByExprs...)
             // See an example MIR in `window_func_applied_to`.
             AggregateFunc::WindowAggregate {
@@ -2990,6 +3145,14 @@ impl AggregateExpr {
                                 return_type_for_func,
                             )
                         }
+                        AggregateFunc::SessionWindow { bound, order_by } => {
+                            assert_eq!(order_by, outer_order_by);
+                            Self::on_unique_session_window(
+                                bound,
+                                args_for_func,
+                                return_type_for_func,
+                            )
+                        }
                         _ => panic!("unknown function in FusedValueWindowFunc"),
                     };
                     func_result_exprs.push(result);
@@ -3158,6 +3321,34 @@ impl AggregateExpr {
         (result_expr, ColumnName::from("?first_value?"))
     }
 
+    /// `on_unique` for `session_window_start` and `session_window_end`
+    fn on_unique_session_window(
+        bound: &SessionWindowBound,
+        encoded_args: MirScalarExpr,
+        return_type: ReprScalarType,
+    ) -> (MirScalarExpr, ColumnName) {
+        let time = encoded_args
+            .clone()
+            .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+        let reach = encoded_args.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+        // A single row is a session of its own, from its time to its reach, unless its reach is
+        // null.
+        let result_expr = match bound {
+            SessionWindowBound::Start => reach
+                .call_unary(UnaryFunc::IsNull(crate::func::IsNull))
+                .if_then_else(MirScalarExpr::literal_null(return_type), time),
+            SessionWindowBound::End => reach,
+        };
+
+        let column_name = ColumnName::from(match bound {
+            SessionWindowBound::Start => "?session_window_start?",
+            SessionWindowBound::End => "?session_window_end?",
+        });
+
+        (result_expr, column_name)
+    }
+
     /// `on_unique` for window aggregations
     fn on_unique_window_agg(
         window_frame: &WindowFrame,
@@ -3278,6 +3469,50 @@ impl JoinImplementation {
     }
 }
 
+/// The records without a match that a [`MirRelationExpr::OuterJoin`] retains.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect
+)]
+pub enum OuterJoinKind {
+    /// Retain the records of the left input without a match.
+    Left,
+    /// Retain the records of the right input without a match.
+    Right,
+    /// Retain the records of both inputs without a match.
+    Full,
+}
+
+impl OuterJoinKind {
+    /// Returns `true` iff records of the left input without a match are retained.
+    pub fn retains_left(&self) -> bool {
+        matches!(self, Self::Left | Self::Full)
+    }
+
+    /// Returns `true` iff records of the right input without a match are retained.
+    pub fn retains_right(&self) -> bool {
+        matches!(self, Self::Right | Self::Full)
+    }
+
+    /// Returns the name of the join kind, for EXPLAIN output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Full => "Full",
+        }
+    }
+}
+
 /// Characteristics of a join order candidate collection.
 ///
 /// A candidate is described by a collection and a key, and may have various liabilities.
@@ -4360,6 +4595,29 @@ mod structured_diff {
                             self.todo.extend(inputs1.iter().zip_eq(inputs2.iter()));
                         }
                     }
+                    (
+                        MirRelationExpr::OuterJoin {
+                            left: left1,
+                            right: right1,
+                            kind: kind1,
+                            left_key: lk1,
+                            right_key: rk1,
+                        },
+                        MirRelationExpr::OuterJoin {
+                            left: left2,
+                            right: right2,
+                            kind: kind2,
+                            left_key: lk2,
+                            right_key: rk2,
+                        },
+                    ) => {
+                        if kind1 != kind2 || lk1 != lk2 || rk1 != rk2 {
+                            return Some((expr1, expr2));
+                        } else {
+                            self.todo.push((right1, right2));
+                            self.todo.push((left1, left2));
+                        }
+                    }
                     (
                         MirRelationExpr::Reduce {
                             aggregates: aggregates1,
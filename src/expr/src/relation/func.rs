@@ -1116,6 +1116,129 @@ fn last_value_inner<'a>(
     results
 }
 
+/// Executes `SessionWindow` on a reduction group.
+/// The expected input is in the format of `[((OriginalRow, (Time, Reach)), OrderByExprs...)]`,
+/// where `Reach` is `Time` plus the gap of the sessions, and `OrderByExprs` order the rows by
+/// `Time`.
+///
+/// The output is in the format of `[result_value, original_row]`, where `result_value` is the
+/// start or the end of the session of the row, depending on `bound`.
+fn session_window<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+    bound: &SessionWindowBound,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let iter = session_window_no_list(datums, &temp_storage, order_by, bound);
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(iter);
+    })
+}
+
+/// Like `session_window`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn session_window_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+    bound: &SessionWindowBound,
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the (OriginalRow, EncodedArgs) record
+    let datums = order_aggregate_datums(datums, order_by);
+
+    // Decode the input (OriginalRow, (Time, Reach)) into separate datums
+    let (orig_rows, args): (Vec<_>, Vec<_>) = datums
+        .into_iter()
+        .map(|d| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let args = unwrap_session_window_encoded_args(iter.next().unwrap());
+            (original_row, args)
+        })
+        .unzip();
+
+    let results = session_window_inner(args, bound);
+
+    callers_temp_storage.reserve(results.len());
+    results
+        .into_iter()
+        .zip_eq(orig_rows)
+        .map(|(result_value, original_row)| {
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(result_value);
+                    packer.push(original_row);
+                });
+            })
+        })
+}
+
+/// The arguments of `SessionWindow` are in a record. This function unwraps this record.
+fn unwrap_session_window_encoded_args(encoded_args: Datum) -> (Datum, Datum) {
+    let mut encoded_args_iter = encoded_args.unwrap_list().iter();
+    let (time, reach) = (
+        encoded_args_iter.next().unwrap(),
+        encoded_args_iter.next().unwrap(),
+    );
+    (time, reach)
+}
+
+/// Each element of `args` has the time and the reach of a single input row, in time order.
+/// Returns the start or the end of the session of each input row.
+///
+/// A row joins the session of the rows before it if its time is at most the largest reach in that
+/// session, and starts a new session otherwise. A session starts at the time of its first row and
+/// ends at its largest reach. Rows with a null reach don't belong to any session, and both of
+/// their bounds are null.
+///
+/// The reduction that evaluates this recomputes the sessions of a partition whenever the partition
+/// changes, and emits only the rows whose sessions changed. A late row that bridges the gap
+/// between two sessions thus merges them, and retracting the row splits them again.
+fn session_window_inner<'a>(
+    args: Vec<(Datum<'a>, Datum<'a>)>,
+    bound: &SessionWindowBound,
+) -> Vec<Datum<'a>> {
+    // The (start, end) of each session, and the index of the session of each row.
+    let mut sessions: Vec<(Datum, Datum)> = Vec::new();
+    let mut row_sessions = Vec::with_capacity(args.len());
+    for (time, reach) in args {
+        if reach.is_null() {
+            row_sessions.push(None);
+            continue;
+        }
+        match sessions.last_mut() {
+            Some((_start, end)) if time <= *end => {
+                if reach > *end {
+                    *end = reach;
+                }
+            }
+            _ => sessions.push((time, reach)),
+        }
+        row_sessions.push(Some(sessions.len() - 1));
+    }
+
+    row_sessions
+        .into_iter()
+        .map(|session| match session {
+            None => Datum::Null,
+            Some(session) => {
+                let (start, end) = sessions[session];
+                match bound {
+                    SessionWindowBound::Start => start,
+                    SessionWindowBound::End => end,
+                }
+            }
+        })
+        .collect()
+}
+
 /// Executes `FusedValueWindowFunc` on a reduction group.
 /// The expected input is in the format of `[((OriginalRow, (Args1, Args2, ...)), OrderByExprs...)]`
 /// where `Args1`, `Args2`, are the arguments of each of the fused functions. For functions that
@@ -1205,6 +1328,17 @@ where
                 // wrapped into a record.)
                 last_value_inner(encoded_argss, &order_by_rows, window_frame)
             }
+            AggregateFunc::SessionWindow {
+                order_by: inner_order_by,
+                bound,
+            } => {
+                assert_eq!(order_by, inner_order_by);
+                let unwrapped_argss = encoded_argss
+                    .into_iter()
+                    .map(|encoded_args| unwrap_session_window_encoded_args(encoded_args))
+                    .collect();
+                session_window_inner(unwrapped_argss, bound)
+            }
             _ => panic!("unknown window function in FusedValueWindowFunc"),
         };
         for (results, result) in results_per_row.iter_mut().zip_eq(results) {
@@ -1806,6 +1940,25 @@ pub enum LagLeadType {
     Lead,
 }
 
+/// Identify which bound of its session window `SessionWindow` returns for each row, since the
+/// start and the end share an implementation.
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect
+)]
+pub enum SessionWindowBound {
+    Start,
+    End,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1925,6 +2078,11 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    /// Assigns each row to a session window. The rows need to be ordered by their time.
+    SessionWindow {
+        order_by: Vec<ColumnOrder>,
+        bound: SessionWindowBound,
+    },
     /// Several value window functions fused into one function, to amortize overheads.
     FusedValueWindowFunc {
         funcs: Vec<AggregateFunc>,
@@ -2036,6 +2194,9 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::SessionWindow { order_by, bound } => {
+                session_window(datums, temp_storage, order_by, bound)
+            }
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate,
                 order_by,
@@ -2139,6 +2300,9 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value_no_list(datums, temp_storage, order_by, window_frame).collect_vec(),
+            AggregateFunc::SessionWindow { order_by, bound } => {
+                session_window_no_list(datums, temp_storage, order_by, bound).collect_vec()
+            }
             AggregateFunc::FusedValueWindowFunc { funcs, order_by } => {
                 fused_value_window_func_no_list(datums, temp_storage, funcs, order_by).collect_vec()
             }
@@ -2198,6 +2362,7 @@ impl AggregateFunc {
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::SessionWindow { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => Datum::empty_list(),
@@ -2260,6 +2425,7 @@ impl AggregateFunc {
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::SessionWindow { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => true,
@@ -2426,6 +2592,28 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::SessionWindow { bound, .. } => {
+                // The input type for SessionWindow is ((OriginalRow, (Time, Reach)), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let encoded_args = fields[0].unwrap_record_element_type()[1];
+                let output_type_inner =
+                    Self::session_window_output_type_inner_from_encoded_args(encoded_args, bound);
+                let column_name = Self::session_window_result_column_name(bound);
+
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Record {
+                        fields: [
+                            (column_name, output_type_inner),
+                            (ColumnName::from("?orig_row?"), original_row_type),
+                        ].into(),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate, ..
             } => {
@@ -2529,6 +2717,17 @@ impl AggregateFunc {
                                                 arg_type.clone().nullable(true),
                                             )
                                         }
+                                        AggregateFunc::SessionWindow { bound, .. } => {
+                                            let name = Self::session_window_result_column_name(
+                                                bound,
+                                            );
+                                            let ty = Self
+                                                ::session_window_output_type_inner_from_encoded_args(
+                                                    arg_type,
+                                                    bound,
+                                                );
+                                            (name, ty)
+                                        }
                                         _ => panic!("FusedValueWindowFunc has an unknown function"),
                                     }
                                 }).collect(),
@@ -2660,6 +2859,28 @@ impl AggregateFunc {
         })
     }
 
+    fn session_window_output_type_inner_from_encoded_args(
+        encoded_args_type: &SqlScalarType,
+        bound: &SessionWindowBound,
+    ) -> SqlColumnType {
+        // The arguments are the time and the reach of the row. Sessions start at a time and end
+        // at a reach, and both bounds are null for rows that don't belong to a session.
+        let index = match bound {
+            SessionWindowBound::Start => 0,
+            SessionWindowBound::End => 1,
+        };
+        encoded_args_type.unwrap_record_element_type()[index]
+            .clone()
+            .nullable(true)
+    }
+
+    fn session_window_result_column_name(bound: &SessionWindowBound) -> ColumnName {
+        ColumnName::from(match bound {
+            SessionWindowBound::Start => "?session_window_start?",
+            SessionWindowBound::End => "?session_window_end?",
+        })
+    }
+
     /// Returns true if the non-null constraint on the aggregation can be
     /// converted into a non-null constraint on its parameter expression, ie.
     /// whether the result of the aggregation is null if all the input values
@@ -2725,6 +2946,7 @@ impl AggregateFunc {
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::SessionWindow { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedWindowAggregate { .. }
@@ -3063,6 +3285,14 @@ impl AggregateFunc {
             } => "lead",
             Self::FirstValue { .. } => "first_value",
             Self::LastValue { .. } => "last_value",
+            Self::SessionWindow {
+                bound: SessionWindowBound::Start,
+                ..
+            } => "session_window_start",
+            Self::SessionWindow {
+                bound: SessionWindowBound::End,
+                ..
+            } => "session_window_end",
             Self::WindowAggregate { .. } => "window_agg",
             Self::FusedValueWindowFunc { .. } => "fused_value_window_func",
             Self::FusedWindowAggregate { .. } => "fused_window_agg",
@@ -3087,7 +3317,8 @@ where
             | StringAgg { order_by }
             | RowNumber { order_by }
             | Rank { order_by }
-            | DenseRank { order_by } => {
+            | DenseRank { order_by }
+            | SessionWindow { order_by, .. } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "{}[order_by=[{}]]", name, separated(", ", order_by))
             }
@@ -2722,6 +2722,7 @@ pub enum EvalError {
     MzAclArrayNullElement,
     PrettyError(Box<str>),
     RedactError(Box<str>),
+    WasmError(Box<str>),
 }
 
 impl fmt::Display for EvalError {
@@ -2832,6 +2833,7 @@ impl fmt::Display for EvalError {
             EvalError::Parse(e) => e.fmt(f),
             EvalError::PrettyError(e) => e.fmt(f),
             EvalError::RedactError(e) => e.fmt(f),
+            EvalError::WasmError(e) => write!(f, "WebAssembly function failed: {}", e),
             EvalError::ParseHex(e) => e.fmt(f),
             EvalError::Internal(s) => write!(f, "internal error: {}", s),
             EvalError::InfinityOutOfDomain(s) => {
@@ -3136,6 +3138,7 @@ impl RustType<ProtoEvalError> for EvalError {
             EvalError::Parse(error) => Parse(error.into_proto()),
             EvalError::PrettyError(error) => PrettyError(error.into_proto()),
             EvalError::RedactError(error) => RedactError(error.into_proto()),
+            EvalError::WasmError(error) => WasmError(error.into_proto()),
             EvalError::ParseHex(error) => ParseHex(error.into_proto()),
             EvalError::Internal(v) => Internal(v.into_proto()),
             EvalError::InfinityOutOfDomain(v) => InfinityOutOfDomain(v.into_proto()),
@@ -3323,6 +3326,7 @@ impl RustType<ProtoEvalError> for EvalError {
                 InvalidIanaTimezoneId(s) => Ok(EvalError::InvalidIanaTimezoneId(s.into())),
                 PrettyError(s) => Ok(EvalError::PrettyError(s.into())),
                 RedactError(s) => Ok(EvalError::RedactError(s.into())),
+                WasmError(s) => Ok(EvalError::WasmError(s.into())),
             },
             None => Err(TryFromProtoError::missing_field("ProtoEvalError::kind")),
         }
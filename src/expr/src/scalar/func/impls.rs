@@ -40,6 +40,7 @@ mod uint32;
 mod uint64;
 mod uuid;
 mod varchar;
+mod wasm;
 
 pub use crate::scalar::func::impls::array::*;
 pub use crate::scalar::func::impls::boolean::*;
@@ -74,3 +75,4 @@ pub use crate::scalar::func::impls::uint32::*;
 pub use crate::scalar::func::impls::uint64::*;
 pub use crate::scalar::func::impls::uuid::*;
 pub use crate::scalar::func::impls::varchar::*;
+pub use crate::scalar::func::impls::wasm::*;
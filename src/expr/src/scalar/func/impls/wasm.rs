@@ -0,0 +1,476 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Evaluation of user-defined scalar functions implemented in WebAssembly.
+//!
+//! A [`WasmCall`] is represented as a `CallVariadic { func: WasmCall { .. }, exprs }`
+//! where `exprs` are the arguments of the function, already cast to the
+//! function's declared parameter types.
+//!
+//! Replicas must agree on the output of every function, so modules are
+//! sandboxed: they may not import anything (and thus have no access to clocks,
+//! randomness or IO), every call runs in a fresh instance so no state survives
+//! between rows, and execution is bounded by deterministic fuel and memory
+//! limits.
+//!
+//! Arguments and results are marshalled from and to [`Datum`]s as follows:
+//! * `bool`, `smallint` and `integer` are passed as `i32`,
+//! * `bigint` as `i64`, `real` as `f32`, and `double precision` as `f64`,
+//! * `text` and `bytea` arguments are copied into a buffer obtained by calling
+//!   the module's exported `alloc(len: i32) -> i32` function, and passed as a
+//!   pointer and a length (`i32`, `i32`) into the exported `memory`,
+//! * `text` and `bytea` results are returned as an `i64` whose upper 32 bits
+//!   are the pointer and whose lower 32 bits are the length of the result in
+//!   the exported `memory`.
+//!
+//! Functions are strict: a `NULL` argument yields a `NULL` result without
+//! calling into the module.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
+use mz_repr::{Datum, RowArena, SqlColumnType, SqlScalarType};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use wasmi::core::{F32, F64, ValType};
+use wasmi::{
+    Config, Engine, ExternType, Instance, Linker, Module, Store, StoreLimits, StoreLimitsBuilder,
+    Val,
+};
+
+use crate::scalar::func::variadic::LazyVariadicFunc;
+use crate::{EvalError, MirScalarExpr};
+
+/// The name of the memory that `text` and `bytea` values are exchanged through.
+const MEMORY_EXPORT: &str = "memory";
+/// The name of the function used to allocate buffers for `text` and `bytea`
+/// arguments.
+const ALLOC_EXPORT: &str = "alloc";
+
+/// The engine that compiles and executes all WebAssembly functions.
+static ENGINE: LazyLock<Engine> = LazyLock::new(|| {
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    Engine::new(&config)
+});
+
+/// A validated WebAssembly module.
+///
+/// Equality, ordering and hashing only consider the module's bytes. The
+/// compiled module is cached so that it is compiled at most once per process.
+#[derive(Clone)]
+pub struct WasmModule {
+    bytes: Arc<[u8]>,
+    compiled: Arc<OnceLock<Result<Module, String>>>,
+}
+
+impl WasmModule {
+    /// Compiles and validates the module in `bytes`.
+    ///
+    /// Returns an error if the module is not valid WebAssembly or if it
+    /// declares any imports.
+    pub fn new(bytes: Vec<u8>) -> Result<Self, String> {
+        let module = Self::from_bytes(bytes.into());
+        module.module()?;
+        Ok(module)
+    }
+
+    fn from_bytes(bytes: Arc<[u8]>) -> Self {
+        WasmModule {
+            bytes,
+            compiled: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// The raw bytes of the module.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Verifies that the module exports a function named `export` that can be
+    /// called with arguments of `param_types` and returns a `return_type`.
+    pub fn check_signature(
+        &self,
+        export: &str,
+        param_types: &[SqlScalarType],
+        return_type: &SqlScalarType,
+    ) -> Result<(), String> {
+        let module = self.module()?;
+        let mut expected_params = vec![];
+        for typ in param_types {
+            expected_params.extend(param_abi(typ).ok_or_else(|| unsupported_type(typ))?);
+        }
+        let expected_result =
+            result_abi(return_type).ok_or_else(|| unsupported_type(return_type))?;
+        match module.get_export(export) {
+            Some(ExternType::Func(ty))
+                if ty.params() == expected_params && ty.results() == [expected_result] => {}
+            Some(ExternType::Func(ty)) => {
+                return Err(format!(
+                    "exported function {export} has signature {:?} -> {:?}, \
+                     but the declared types require {:?} -> {:?}",
+                    ty.params(),
+                    ty.results(),
+                    expected_params,
+                    [expected_result],
+                ));
+            }
+            _ => return Err(format!("module does not export a function named {export}")),
+        }
+
+        let uses_memory = param_types.iter().chain([return_type]).any(is_buffer_type);
+        if uses_memory
+            && !matches!(
+                module.get_export(MEMORY_EXPORT),
+                Some(ExternType::Memory(_))
+            )
+        {
+            return Err(format!(
+                "module must export a memory named {MEMORY_EXPORT} to exchange text and bytea values"
+            ));
+        }
+        if param_types.iter().any(is_buffer_type) {
+            match module.get_export(ALLOC_EXPORT) {
+                Some(ExternType::Func(ty))
+                    if ty.params() == [ValType::I32] && ty.results() == [ValType::I32] => {}
+                _ => {
+                    return Err(format!(
+                        "module must export a function {ALLOC_EXPORT}(i32) -> i32 to receive text and bytea arguments"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the compiled module, compiling it if necessary.
+    fn module(&self) -> Result<&Module, String> {
+        self.compiled
+            .get_or_init(|| {
+                let module = Module::new(&ENGINE, &self.bytes).map_err(|e| e.to_string())?;
+                if let Some(import) = module.imports().next() {
+                    return Err(format!(
+                        "module must not import anything, but imports {}.{}",
+                        import.module(),
+                        import.name()
+                    ));
+                }
+                Ok(module)
+            })
+            .as_ref()
+            .map_err(|e| e.clone())
+    }
+}
+
+impl fmt::Debug for WasmModule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WasmModule")
+            .field("len", &self.bytes.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for WasmModule {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for WasmModule {}
+
+impl PartialOrd for WasmModule {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WasmModule {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+impl Hash for WasmModule {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state)
+    }
+}
+
+impl Serialize for WasmModule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (*self.bytes).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for WasmModule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        Ok(WasmModule::from_bytes(bytes.into()))
+    }
+}
+
+/// Returns whether values of `typ` can be passed to and returned from
+/// WebAssembly functions.
+pub fn wasm_supports_type(typ: &SqlScalarType) -> bool {
+    param_abi(typ).is_some()
+}
+
+/// The WebAssembly types that an argument of type `typ` is passed as.
+fn param_abi(typ: &SqlScalarType) -> Option<&'static [ValType]> {
+    match typ {
+        SqlScalarType::Bool | SqlScalarType::Int16 | SqlScalarType::Int32 => Some(&[ValType::I32]),
+        SqlScalarType::Int64 => Some(&[ValType::I64]),
+        SqlScalarType::Float32 => Some(&[ValType::F32]),
+        SqlScalarType::Float64 => Some(&[ValType::F64]),
+        SqlScalarType::String | SqlScalarType::Bytes => Some(&[ValType::I32, ValType::I32]),
+        _ => None,
+    }
+}
+
+/// The WebAssembly type that a result of type `typ` is returned as.
+fn result_abi(typ: &SqlScalarType) -> Option<ValType> {
+    match typ {
+        SqlScalarType::String | SqlScalarType::Bytes => Some(ValType::I64),
+        typ => match param_abi(typ)? {
+            [abi] => Some(*abi),
+            _ => None,
+        },
+    }
+}
+
+fn is_buffer_type(typ: &SqlScalarType) -> bool {
+    matches!(typ, SqlScalarType::String | SqlScalarType::Bytes)
+}
+
+fn unsupported_type(typ: &SqlScalarType) -> String {
+    format!("type {typ:?} is not supported by WebAssembly functions")
+}
+
+fn wasm_error(e: impl fmt::Display) -> EvalError {
+    EvalError::WasmError(e.to_string().into())
+}
+
+/// Calls an exported function of a WebAssembly module.
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect
+)]
+pub struct WasmCall {
+    /// The name of the SQL function, for display purposes.
+    pub name: String,
+    /// The module that implements the function.
+    #[mzreflect(ignore)]
+    pub module: WasmModule,
+    /// The name of the exported function to call.
+    pub export: String,
+    /// The types of the function's arguments.
+    pub param_types: Vec<SqlScalarType>,
+    /// The type of the function's result.
+    pub return_type: SqlScalarType,
+    /// The amount of fuel that a single call may consume.
+    pub max_fuel: u64,
+    /// The maximum size of the linear memory of a single call, in bytes.
+    pub max_memory_bytes: u64,
+}
+
+impl WasmCall {
+    fn call<'a>(
+        &self,
+        args: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError> {
+        let module = self.module.module().map_err(wasm_error)?;
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(usize::cast_from(self.max_memory_bytes))
+            .instances(1)
+            .memories(1)
+            .tables(1)
+            .trap_on_grow_failure(true)
+            .build();
+        let mut store = Store::new(&ENGINE, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.max_fuel).map_err(wasm_error)?;
+        // A fresh instance per call ensures that no state leaks between rows,
+        // which would make results depend on the order in which they are
+        // processed.
+        let instance = Linker::<StoreLimits>::new(&ENGINE)
+            .instantiate(&mut store, module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(wasm_error)?;
+
+        let mut inputs = Vec::with_capacity(args.len());
+        for (datum, typ) in args.iter().zip(&self.param_types) {
+            match typ {
+                SqlScalarType::Bool => inputs.push(Val::I32(datum.unwrap_bool().into())),
+                SqlScalarType::Int16 => inputs.push(Val::I32(datum.unwrap_int16().into())),
+                SqlScalarType::Int32 => inputs.push(Val::I32(datum.unwrap_int32())),
+                SqlScalarType::Int64 => inputs.push(Val::I64(datum.unwrap_int64())),
+                SqlScalarType::Float32 => {
+                    inputs.push(Val::F32(F32::from_float(datum.unwrap_float32())))
+                }
+                SqlScalarType::Float64 => {
+                    inputs.push(Val::F64(F64::from_float(datum.unwrap_float64())))
+                }
+                SqlScalarType::String => {
+                    let (ptr, len) =
+                        write_buffer(&mut store, &instance, datum.unwrap_str().as_bytes())?;
+                    inputs.extend([Val::I32(ptr), Val::I32(len)]);
+                }
+                SqlScalarType::Bytes => {
+                    let (ptr, len) = write_buffer(&mut store, &instance, datum.unwrap_bytes())?;
+                    inputs.extend([Val::I32(ptr), Val::I32(len)]);
+                }
+                typ => return Err(EvalError::Internal(unsupported_type(typ).into())),
+            }
+        }
+
+        let result_abi = result_abi(&self.return_type)
+            .ok_or_else(|| EvalError::Internal(unsupported_type(&self.return_type).into()))?;
+        let mut outputs = [Val::default(result_abi)];
+        let func = instance
+            .get_func(&store, &self.export)
+            .ok_or_else(|| wasm_error(format!("missing export {}", self.export)))?;
+        func.call(&mut store, &inputs, &mut outputs)
+            .map_err(wasm_error)?;
+
+        let datum = match (&self.return_type, &outputs[0]) {
+            (SqlScalarType::Bool, Val::I32(v)) => Datum::from(*v != 0),
+            (SqlScalarType::Int16, Val::I32(v)) => Datum::Int16(
+                i16::try_from(*v).map_err(|_| EvalError::Int16OutOfRange(v.to_string().into()))?,
+            ),
+            (SqlScalarType::Int32, Val::I32(v)) => Datum::Int32(*v),
+            (SqlScalarType::Int64, Val::I64(v)) => Datum::Int64(*v),
+            // NaN bit patterns may differ between platforms, so canonicalize
+            // them to keep replicas in agreement.
+            (SqlScalarType::Float32, Val::F32(v)) => match v.to_float() {
+                v if v.is_nan() => Datum::from(f32::NAN),
+                v => Datum::from(v),
+            },
+            (SqlScalarType::Float64, Val::F64(v)) => match v.to_float() {
+                v if v.is_nan() => Datum::from(f64::NAN),
+                v => Datum::from(v),
+            },
+            (SqlScalarType::String, Val::I64(v)) => {
+                let bytes = read_buffer(&store, &instance, *v)?;
+                let s = String::from_utf8(bytes)
+                    .map_err(|_| wasm_error("function returned invalid UTF-8"))?;
+                Datum::String(temp_storage.push_string(s))
+            }
+            (SqlScalarType::Bytes, Val::I64(v)) => {
+                let bytes = read_buffer(&store, &instance, *v)?;
+                Datum::Bytes(temp_storage.push_bytes(bytes))
+            }
+            (typ, val) => {
+                return Err(EvalError::Internal(
+                    format!("unexpected result {val:?} for type {typ:?}").into(),
+                ));
+            }
+        };
+        Ok(datum)
+    }
+}
+
+/// Copies `bytes` into a buffer allocated by the module's `alloc` export and
+/// returns the buffer's pointer and length.
+fn write_buffer(
+    store: &mut Store<StoreLimits>,
+    instance: &Instance,
+    bytes: &[u8],
+) -> Result<(i32, i32), EvalError> {
+    let len = i32::try_from(bytes.len()).map_err(|_| wasm_error("argument too large"))?;
+    let alloc = instance
+        .get_func(&*store, ALLOC_EXPORT)
+        .ok_or_else(|| wasm_error(format!("missing export {ALLOC_EXPORT}")))?;
+    let mut ptr = [Val::I32(0)];
+    alloc
+        .call(&mut *store, &[Val::I32(len)], &mut ptr)
+        .map_err(wasm_error)?;
+    let Val::I32(ptr) = ptr[0] else {
+        return Err(wasm_error(format!(
+            "{ALLOC_EXPORT} returned a non-i32 value"
+        )));
+    };
+    let memory = instance
+        .get_memory(&*store, MEMORY_EXPORT)
+        .ok_or_else(|| wasm_error(format!("missing export {MEMORY_EXPORT}")))?;
+    memory
+        .write(
+            &mut *store,
+            usize::cast_from(u32::reinterpret_cast(ptr)),
+            bytes,
+        )
+        .map_err(wasm_error)?;
+    Ok((ptr, len))
+}
+
+/// Reads the buffer described by a packed pointer and length out of the
+/// module's memory.
+fn read_buffer(
+    store: &Store<StoreLimits>,
+    instance: &Instance,
+    packed: i64,
+) -> Result<Vec<u8>, EvalError> {
+    let packed = u64::reinterpret_cast(packed);
+    let ptr = usize::cast_from(packed >> 32);
+    let len = usize::cast_from(packed & u64::from(u32::MAX));
+    let memory = instance
+        .get_memory(store, MEMORY_EXPORT)
+        .ok_or_else(|| wasm_error(format!("missing export {MEMORY_EXPORT}")))?;
+    let mut buffer = vec![0; len];
+    memory.read(store, ptr, &mut buffer).map_err(wasm_error)?;
+    Ok(buffer)
+}
+
+impl LazyVariadicFunc for WasmCall {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        exprs: &'a [MirScalarExpr],
+    ) -> Result<Datum<'a>, EvalError> {
+        let args = exprs
+            .iter()
+            .map(|e| e.eval(datums, temp_storage))
+            .collect::<Result<Vec<_>, _>>()?;
+        if args.iter().any(|d| d.is_null()) {
+            return Ok(Datum::Null);
+        }
+        self.call(&args, temp_storage)
+    }
+
+    fn output_type(&self, input_types: &[SqlColumnType]) -> SqlColumnType {
+        let nullable = input_types.iter().any(|t| t.nullable);
+        self.return_type.clone().nullable(nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for WasmCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
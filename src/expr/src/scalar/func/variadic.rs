@@ -40,7 +40,7 @@ use mz_repr::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::func::CaseLiteral;
+use crate::func::{CaseLiteral, WasmCall};
 use crate::func::{
     MAX_STRING_FUNC_RESULT_BYTES, array_create_scalar, build_regex, date_bin, parse_timezone,
     regexp_match_static, regexp_replace_parse_flags, regexp_split_to_array_re, stringify_datum,
@@ -1748,6 +1748,7 @@ derive_variadic! {
     RegexpSplitToArray(RegexpSplitToArray),
     RegexpReplace(RegexpReplace),
     CaseLiteral(CaseLiteral),
+    WasmCall(WasmCall),
 }
 
 impl VariadicFunc {
@@ -0,0 +1,230 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! OAuth 2.0 access tokens for Google Cloud APIs.
+//!
+//! Supports the credential types we actually encounter: service account keys
+//! (signed JWT assertions), `gcloud` user credentials (refresh tokens), and the
+//! GCE/GKE metadata server. Tokens are cached until shortly before they
+//! expire.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+
+/// The token endpoint used when a credential doesn't name one.
+const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// The token endpoint of the GCE/GKE metadata server.
+const METADATA_TOKEN_URI: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// How long before its expiry we stop using a cached token.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// How long the JWT assertions we sign are valid for. This is also the
+/// maximum Google accepts.
+const ASSERTION_LIFETIME: Duration = Duration::from_secs(3600);
+
+/// An error obtaining an access token.
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("invalid credential: {0}")]
+    InvalidCredential(String),
+    #[error("reading credential file {path}: {source}")]
+    CredentialFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("signing token request: {0}")]
+    Signing(#[from] jsonwebtoken::errors::Error),
+    #[error("token request failed with status {status}: {body}")]
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    #[error("token request failed: {0}")]
+    Http(#[from] reqwest::Error),
+}
+
+/// The JSON key of a service account, as downloaded from the console.
+#[derive(Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+    private_key_id: Option<String>,
+    token_uri: Option<String>,
+}
+
+/// User credentials written by `gcloud auth application-default login`.
+#[derive(Deserialize)]
+struct AuthorizedUser {
+    client_id: String,
+    client_secret: String,
+    refresh_token: String,
+}
+
+/// A credential file, discriminated by its `type` field.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum CredentialFile {
+    ServiceAccount(ServiceAccountKey),
+    AuthorizedUser(AuthorizedUser),
+}
+
+enum Source {
+    ServiceAccount {
+        key: ServiceAccountKey,
+        encoding_key: EncodingKey,
+    },
+    AuthorizedUser(AuthorizedUser),
+    MetadataServer,
+}
+
+/// The claims of the JWT assertion exchanged for a service account's token.
+///
+/// See <https://developers.google.com/identity/protocols/oauth2/service-account#authorizingrequests>.
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+/// Hands out access tokens for a fixed credential, caching them until shortly
+/// before they expire.
+pub(crate) struct TokenProvider {
+    http: reqwest::Client,
+    source: Source,
+    cached: Mutex<Option<(String, Instant)>>,
+}
+
+impl TokenProvider {
+    /// Returns a provider for the JSON key of a service account.
+    pub(crate) fn from_service_account_key(key: &str) -> Result<Self, AuthError> {
+        let key: ServiceAccountKey =
+            serde_json::from_str(key).map_err(|e| AuthError::InvalidCredential(e.to_string()))?;
+        Self::new(Self::service_account(key)?)
+    }
+
+    /// Returns a provider for the application default credentials: the file
+    /// named by `GOOGLE_APPLICATION_CREDENTIALS`, then the `gcloud` user
+    /// credentials, then the metadata server.
+    pub(crate) fn application_default() -> Result<Self, AuthError> {
+        let path = match std::env::var_os("GOOGLE_APPLICATION_CREDENTIALS") {
+            Some(path) => Some(PathBuf::from(path)),
+            None => std::env::var_os("HOME")
+                .map(|home| {
+                    PathBuf::from(home).join(".config/gcloud/application_default_credentials.json")
+                })
+                .filter(|path| path.exists()),
+        };
+        let source = match path {
+            Some(path) => {
+                let contents = std::fs::read_to_string(&path)
+                    .map_err(|source| AuthError::CredentialFile { path, source })?;
+                let file: CredentialFile = serde_json::from_str(&contents)
+                    .map_err(|e| AuthError::InvalidCredential(e.to_string()))?;
+                match file {
+                    CredentialFile::ServiceAccount(key) => Self::service_account(key)?,
+                    CredentialFile::AuthorizedUser(user) => Source::AuthorizedUser(user),
+                }
+            }
+            None => Source::MetadataServer,
+        };
+        Self::new(source)
+    }
+
+    fn service_account(key: ServiceAccountKey) -> Result<Source, AuthError> {
+        let encoding_key = EncodingKey::from_rsa_pem(key.private_key.as_bytes())?;
+        Ok(Source::ServiceAccount { key, encoding_key })
+    }
+
+    fn new(source: Source) -> Result<Self, AuthError> {
+        Ok(TokenProvider {
+            http: reqwest::Client::builder().build()?,
+            source,
+            cached: Mutex::new(None),
+        })
+    }
+
+    /// Returns an access token for `scope`.
+    ///
+    /// A provider is only ever used with a single scope, so the cache doesn't
+    /// key on it.
+    pub(crate) async fn token(&self, scope: &str) -> Result<String, AuthError> {
+        if let Some((token, expires_at)) = &*self.cached.lock().expect("lock poisoned")
+            && Instant::now() < *expires_at
+        {
+            return Ok(token.clone());
+        }
+
+        let requested_at = Instant::now();
+        let request = match &self.source {
+            Source::ServiceAccount { key, encoding_key } => {
+                let token_uri = key.token_uri.as_deref().unwrap_or(DEFAULT_TOKEN_URI);
+                let iat = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                let claims = Claims {
+                    iss: &key.client_email,
+                    scope,
+                    aud: token_uri,
+                    iat,
+                    exp: iat + ASSERTION_LIFETIME.as_secs(),
+                };
+                let header = Header {
+                    kid: key.private_key_id.clone(),
+                    ..Header::new(Algorithm::RS256)
+                };
+                let assertion = jsonwebtoken::encode(&header, &claims, encoding_key)?;
+                self.http.post(token_uri).form(&[
+                    ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                    ("assertion", &assertion),
+                ])
+            }
+            Source::AuthorizedUser(user) => self.http.post(DEFAULT_TOKEN_URI).form(&[
+                ("grant_type", "refresh_token"),
+                ("client_id", &user.client_id),
+                ("client_secret", &user.client_secret),
+                ("refresh_token", &user.refresh_token),
+            ]),
+            Source::MetadataServer => self
+                .http
+                .get(METADATA_TOKEN_URI)
+                .query(&[("scopes", scope)])
+                .header("Metadata-Flavor", "Google"),
+        };
+
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AuthError::Status { status, body });
+        }
+        let response: TokenResponse = response.json().await?;
+
+        let lifetime = Duration::from_secs(response.expires_in).saturating_sub(EXPIRY_MARGIN);
+        *self.cached.lock().expect("lock poisoned") =
+            Some((response.access_token.clone(), requested_at + lifetime));
+        Ok(response.access_token)
+    }
+}
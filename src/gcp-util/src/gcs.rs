@@ -0,0 +1,579 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A minimal client for the Google Cloud Storage JSON API.
+//!
+//! This covers the handful of object operations we need (get, list, simple
+//! and resumable uploads, delete, and copy), each of which can carry
+//! `ifGenerationMatch` preconditions. It talks to
+//! `https://storage.googleapis.com` by default, and to any compatible
+//! endpoint (e.g. `fake-gcs-server`) when one is configured.
+
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use mz_ore::cast::CastFrom;
+use reqwest::header::{CONTENT_LENGTH, CONTENT_RANGE, LOCATION, RANGE};
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Deserializer};
+
+use crate::auth::{AuthError, TokenProvider};
+
+/// The endpoint of the production GCS JSON API.
+pub const GCS_DEFAULT_ENDPOINT: &str = "https://storage.googleapis.com";
+
+/// The OAuth scope required for reading and writing objects.
+const READ_WRITE_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+
+/// How a [`GcsClient`] authenticates its requests.
+#[derive(Clone)]
+pub enum GcsCredentials {
+    /// Application default credentials: `GOOGLE_APPLICATION_CREDENTIALS`, the
+    /// gcloud CLI configuration, or the GCE/GKE metadata server.
+    Default,
+    /// The JSON key of a service account.
+    ServiceAccountKey(String),
+    /// No authentication, for use against an emulator.
+    Anonymous,
+}
+
+impl Debug for GcsCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GcsCredentials::Default => f.write_str("Default"),
+            GcsCredentials::ServiceAccountKey(_) => f.write_str("ServiceAccountKey(<redacted>)"),
+            GcsCredentials::Anonymous => f.write_str("Anonymous"),
+        }
+    }
+}
+
+/// An error returned by a [`GcsClient`].
+#[derive(Debug, thiserror::Error)]
+pub enum GcsError {
+    /// An `ifGenerationMatch` (or similar) precondition did not hold.
+    #[error("gcs precondition failed")]
+    PreconditionFailed,
+    /// The server responded with an unexpected status.
+    #[error("gcs request failed with status {status}: {body}")]
+    Status { status: StatusCode, body: String },
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl GcsError {
+    /// Returns the HTTP status of the failed request, if there was one.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            GcsError::PreconditionFailed => Some(StatusCode::PRECONDITION_FAILED),
+            GcsError::Status { status, .. } => Some(*status),
+            GcsError::Http(e) => e.status(),
+            GcsError::Auth(_) | GcsError::Other(_) => None,
+        }
+    }
+}
+
+/// Preconditions attached to a mutating request.
+///
+/// See <https://cloud.google.com/storage/docs/request-preconditions>.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Preconditions {
+    /// The request only succeeds if the live generation of the object matches.
+    /// A value of `0` matches only if there is no live object.
+    pub if_generation_match: Option<i64>,
+}
+
+impl Preconditions {
+    /// No preconditions.
+    pub const NONE: Preconditions = Preconditions {
+        if_generation_match: None,
+    };
+
+    /// Only succeed if there is no live object with the same name.
+    pub fn does_not_exist() -> Self {
+        Preconditions {
+            if_generation_match: Some(0),
+        }
+    }
+
+    /// Only succeed if the live object has the given generation.
+    pub fn generation(generation: i64) -> Self {
+        Preconditions {
+            if_generation_match: Some(generation),
+        }
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(generation) = self.if_generation_match {
+            query.push(("ifGenerationMatch", generation.to_string()));
+        }
+        query
+    }
+}
+
+/// The metadata of a stored object.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct ObjectMetadata {
+    pub name: String,
+    #[serde(deserialize_with = "from_str")]
+    pub size: u64,
+    #[serde(deserialize_with = "from_str")]
+    pub generation: i64,
+    /// Set for noncurrent versions of an object in a bucket with object
+    /// versioning enabled.
+    #[serde(rename = "timeDeleted", default)]
+    pub time_deleted: Option<String>,
+}
+
+/// One page of the results of [`GcsClient::list_objects`].
+#[derive(Debug, Default, Deserialize)]
+pub struct ObjectsPage {
+    #[serde(default)]
+    pub items: Vec<ObjectMetadata>,
+    #[serde(rename = "nextPageToken")]
+    pub next_page_token: Option<String>,
+}
+
+/// The JSON API encodes 64-bit integers as strings.
+fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+}
+
+/// A client for the Google Cloud Storage JSON API.
+#[derive(Clone)]
+pub struct GcsClient {
+    http: reqwest::Client,
+    endpoint: String,
+    token_provider: Option<Arc<TokenProvider>>,
+}
+
+impl Debug for GcsClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcsClient")
+            .field("endpoint", &self.endpoint)
+            .field("authenticated", &self.token_provider.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl GcsClient {
+    /// Returns a new client for `endpoint` (or [`GCS_DEFAULT_ENDPOINT`]).
+    ///
+    /// `http` carries the caller's timeouts. Redirects are disabled on it,
+    /// since resumable uploads signal progress with a `308` status.
+    pub async fn new(
+        endpoint: Option<String>,
+        credentials: GcsCredentials,
+        http: reqwest::ClientBuilder,
+    ) -> Result<Self, GcsError> {
+        let token_provider = match credentials {
+            GcsCredentials::Default => Some(Arc::new(TokenProvider::application_default()?)),
+            GcsCredentials::ServiceAccountKey(key) => {
+                Some(Arc::new(TokenProvider::from_service_account_key(&key)?))
+            }
+            GcsCredentials::Anonymous => None,
+        };
+        let http = http.redirect(reqwest::redirect::Policy::none()).build()?;
+        let endpoint = endpoint
+            .unwrap_or_else(|| GCS_DEFAULT_ENDPOINT.to_string())
+            .trim_end_matches('/')
+            .to_string();
+        Ok(GcsClient {
+            http,
+            endpoint,
+            token_provider,
+        })
+    }
+
+    /// The endpoint this client sends requests to.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Response, GcsError> {
+        let request = match &self.token_provider {
+            Some(provider) => {
+                let token = provider.token(READ_WRITE_SCOPE).await?;
+                request.bearer_auth(token)
+            }
+            None => request,
+        };
+        Ok(request.send().await?)
+    }
+
+    fn bucket_url(&self, bucket: &str) -> String {
+        format!(
+            "{}/storage/v1/b/{}",
+            self.endpoint,
+            urlencoding::encode(bucket)
+        )
+    }
+
+    fn object_url(&self, bucket: &str, name: &str) -> String {
+        format!(
+            "{}/o/{}",
+            self.bucket_url(bucket),
+            urlencoding::encode(name)
+        )
+    }
+
+    fn upload_url(&self, bucket: &str) -> String {
+        format!(
+            "{}/upload/storage/v1/b/{}/o",
+            self.endpoint,
+            urlencoding::encode(bucket)
+        )
+    }
+
+    /// Creates `bucket` in `project`. Intended for emulators, where buckets
+    /// aren't provisioned out of band.
+    pub async fn create_bucket(&self, project: &str, bucket: &str) -> Result<(), GcsError> {
+        let request = self
+            .http
+            .post(format!("{}/storage/v1/b", self.endpoint))
+            .query(&[("project", project)])
+            .json(&serde_json::json!({ "name": bucket }));
+        check(self.send(request).await?).await?;
+        Ok(())
+    }
+
+    /// Starts downloading an object, returning `None` if it doesn't exist.
+    ///
+    /// The caller reads the body from the returned response.
+    pub async fn get_object(&self, bucket: &str, name: &str) -> Result<Option<Response>, GcsError> {
+        let request = self
+            .http
+            .get(self.object_url(bucket, name))
+            .query(&[("alt", "media")]);
+        let response = self.send(request).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Ok(Some(check(response).await?))
+    }
+
+    /// Fetches the metadata of the live version of an object, returning
+    /// `None` if it doesn't exist.
+    pub async fn get_object_metadata(
+        &self,
+        bucket: &str,
+        name: &str,
+    ) -> Result<Option<ObjectMetadata>, GcsError> {
+        let request = self.http.get(self.object_url(bucket, name));
+        let response = self.send(request).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Ok(Some(check(response).await?.json().await?))
+    }
+
+    /// Lists one page of the objects in `bucket` whose names start with
+    /// `prefix`. With `versions`, noncurrent versions are included too.
+    pub async fn list_objects(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        versions: bool,
+        page_token: Option<&str>,
+    ) -> Result<ObjectsPage, GcsError> {
+        let mut query = vec![("prefix", prefix.to_string())];
+        if versions {
+            query.push(("versions", "true".to_string()));
+        }
+        if let Some(page_token) = page_token {
+            query.push(("pageToken", page_token.to_string()));
+        }
+        let request = self
+            .http
+            .get(format!("{}/o", self.bucket_url(bucket)))
+            .query(&query);
+        Ok(check(self.send(request).await?).await?.json().await?)
+    }
+
+    /// Lists the live objects in `bucket` whose names start with `prefix`,
+    /// following pagination until exhausted.
+    pub async fn list_all_objects(
+        &self,
+        bucket: &str,
+        prefix: &str,
+    ) -> Result<Vec<ObjectMetadata>, GcsError> {
+        let mut objects = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page = self
+                .list_objects(bucket, prefix, false, page_token.as_deref())
+                .await?;
+            objects.extend(page.items);
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => return Ok(objects),
+            }
+        }
+    }
+
+    /// Uploads `data` as an object in a single request.
+    pub async fn insert_object(
+        &self,
+        bucket: &str,
+        name: &str,
+        data: Bytes,
+        preconditions: Preconditions,
+    ) -> Result<ObjectMetadata, GcsError> {
+        let mut query = vec![
+            ("uploadType", "media".to_string()),
+            ("name", name.to_string()),
+        ];
+        query.extend(preconditions.query());
+        let request = self
+            .http
+            .post(self.upload_url(bucket))
+            .query(&query)
+            .header(CONTENT_LENGTH, data.len())
+            .body(data);
+        Ok(check(self.send(request).await?).await?.json().await?)
+    }
+
+    /// Starts a resumable upload of an object, whose data is then sent with
+    /// [`ResumableUpload::upload_chunk`].
+    ///
+    /// Preconditions are evaluated when the upload is finalized.
+    pub async fn start_resumable_upload(
+        &self,
+        bucket: &str,
+        name: &str,
+        preconditions: Preconditions,
+    ) -> Result<ResumableUpload, GcsError> {
+        let mut query = vec![
+            ("uploadType", "resumable".to_string()),
+            ("name", name.to_string()),
+        ];
+        query.extend(preconditions.query());
+        let request = self
+            .http
+            .post(self.upload_url(bucket))
+            .query(&query)
+            .header(CONTENT_LENGTH, 0);
+        let response = check(self.send(request).await?).await?;
+        let session_uri = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .ok_or_else(|| anyhow::anyhow!("resumable upload response missing Location header"))?
+            .to_string();
+        Ok(ResumableUpload {
+            client: self.clone(),
+            session_uri,
+            offset: 0,
+        })
+    }
+
+    /// Deletes the live version of an object. Returns `false` if it didn't
+    /// exist.
+    pub async fn delete_object(
+        &self,
+        bucket: &str,
+        name: &str,
+        preconditions: Preconditions,
+    ) -> Result<bool, GcsError> {
+        let request = self
+            .http
+            .delete(self.object_url(bucket, name))
+            .query(&preconditions.query());
+        let response = self.send(request).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        check(response).await?;
+        Ok(true)
+    }
+
+    /// Copies a (possibly noncurrent) generation of `source` to
+    /// `destination` within `bucket`.
+    pub async fn copy_object(
+        &self,
+        bucket: &str,
+        source: &str,
+        source_generation: Option<i64>,
+        destination: &str,
+        preconditions: Preconditions,
+    ) -> Result<ObjectMetadata, GcsError> {
+        let mut query = preconditions.query();
+        if let Some(generation) = source_generation {
+            query.push(("sourceGeneration", generation.to_string()));
+        }
+        let url = format!(
+            "{}/copyTo/b/{}/o/{}",
+            self.object_url(bucket, source),
+            urlencoding::encode(bucket),
+            urlencoding::encode(destination)
+        );
+        let request = self.http.post(url).query(&query).header(CONTENT_LENGTH, 0);
+        Ok(check(self.send(request).await?).await?.json().await?)
+    }
+}
+
+/// An in-progress resumable upload.
+///
+/// See <https://cloud.google.com/storage/docs/performing-resumable-uploads>.
+#[derive(Debug)]
+pub struct ResumableUpload {
+    client: GcsClient,
+    session_uri: String,
+    /// The number of bytes the server has persisted.
+    offset: u64,
+}
+
+impl ResumableUpload {
+    /// Every chunk but the last must be a multiple of this size.
+    pub const CHUNK_ALIGNMENT: u64 = 256 * 1024;
+
+    /// The number of bytes the server has persisted so far.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Sends the next chunk of the object.
+    ///
+    /// Unless `last` is set, `data` must be a multiple of
+    /// [`Self::CHUNK_ALIGNMENT`] in size. Returns the metadata of the created
+    /// object once the last chunk is accepted.
+    pub async fn upload_chunk(
+        &mut self,
+        mut data: Bytes,
+        last: bool,
+    ) -> Result<Option<ObjectMetadata>, GcsError> {
+        if !last && u64::cast_from(data.len()) % Self::CHUNK_ALIGNMENT != 0 {
+            return Err(GcsError::Other(anyhow::anyhow!(
+                "resumable upload chunk of {} bytes is not a multiple of {}",
+                data.len(),
+                Self::CHUNK_ALIGNMENT
+            )));
+        }
+        loop {
+            let len = u64::cast_from(data.len());
+            let end = self.offset + len;
+            let total = if last {
+                end.to_string()
+            } else {
+                "*".to_string()
+            };
+            let range = if len == 0 {
+                format!("bytes */{total}")
+            } else {
+                format!("bytes {}-{}/{total}", self.offset, end - 1)
+            };
+            let request = self
+                .client
+                .http
+                .put(&self.session_uri)
+                .header(CONTENT_RANGE, range)
+                .header(CONTENT_LENGTH, data.len())
+                .body(data.clone());
+            let response = self.client.send(request).await?;
+
+            // The server responds with 308 until the final chunk is received,
+            // reporting how much it persisted in the Range header.
+            if response.status() == StatusCode::PERMANENT_REDIRECT {
+                let persisted = match response.headers().get(RANGE) {
+                    Some(range) => parse_persisted_range(range.to_str().ok())?,
+                    None => 0,
+                };
+                if persisted < self.offset || persisted > end {
+                    return Err(GcsError::Other(anyhow::anyhow!(
+                        "resumable upload persisted {persisted} bytes, expected between {} and {end}",
+                        self.offset
+                    )));
+                }
+                let consumed = usize::cast_from(persisted - self.offset);
+                self.offset = persisted;
+                if persisted == end && !last {
+                    return Ok(None);
+                }
+                // Resend whatever the server didn't persist.
+                data = data.slice(consumed..);
+                continue;
+            }
+
+            let metadata: ObjectMetadata = check(response).await?.json().await?;
+            self.offset = end;
+            return Ok(Some(metadata));
+        }
+    }
+}
+
+/// Parses the `Range: bytes=0-N` header of a `308` response into the number
+/// of persisted bytes.
+fn parse_persisted_range(range: Option<&str>) -> Result<u64, GcsError> {
+    let last_byte = range
+        .and_then(|range| range.strip_prefix("bytes=0-"))
+        .and_then(|last| last.parse::<u64>().ok())
+        .ok_or_else(|| anyhow::anyhow!("invalid resumable upload Range header: {range:?}"))?;
+    Ok(last_byte + 1)
+}
+
+/// Maps unsuccessful responses to a [`GcsError`].
+async fn check(response: Response) -> Result<Response, GcsError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    if status == StatusCode::PRECONDITION_FAILED {
+        return Err(GcsError::PreconditionFailed);
+    }
+    let body = response.text().await.unwrap_or_default();
+    Err(GcsError::Status { status, body })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn persisted_range() {
+        assert_eq!(parse_persisted_range(Some("bytes=0-0")).unwrap(), 1);
+        assert_eq!(
+            parse_persisted_range(Some("bytes=0-262143")).unwrap(),
+            ResumableUpload::CHUNK_ALIGNMENT
+        );
+        assert!(parse_persisted_range(Some("bytes=5-10")).is_err());
+        assert!(parse_persisted_range(None).is_err());
+    }
+
+    #[mz_ore::test]
+    fn object_metadata_from_json() {
+        let page: ObjectsPage = serde_json::from_str(
+            r#"{"kind":"storage#objects","items":[
+                {"name":"a/b","size":"12","generation":"1700000000000001"},
+                {"name":"a/c","size":"0","generation":"2","timeDeleted":"2025-01-01T00:00:00Z"}
+            ],"nextPageToken":"next"}"#,
+        )
+        .unwrap();
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].size, 12);
+        assert_eq!(page.items[0].generation, 1_700_000_000_000_001);
+        assert_eq!(page.items[0].time_deleted, None);
+        assert!(page.items[1].time_deleted.is_some());
+        assert_eq!(page.next_page_token.as_deref(), Some("next"));
+
+        let empty: ObjectsPage = serde_json::from_str(r#"{"kind":"storage#objects"}"#).unwrap();
+        assert!(empty.items.is_empty());
+    }
+}
@@ -0,0 +1,307 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use anyhow::anyhow;
+use bytes::BytesMut;
+use bytesize::ByteSize;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::task::{JoinHandle, spawn};
+
+use crate::gcs::{GcsClient, GcsError, Preconditions, ResumableUpload};
+
+/// An uploader which streams a single object to GCS with a resumable upload,
+/// the GCS counterpart of `mz_aws_util::s3_uploader::S3MultiPartUploader`.
+///
+/// Data is buffered until it reaches `part_size_limit`, at which point a chunk
+/// is sent. Chunks of a resumable upload must be sent in order, so each chunk
+/// upload task waits for the previous one before sending its own data. The
+/// object only becomes visible once `finish` sends the last chunk.
+#[derive(Debug)]
+pub struct GcsResumableUploader {
+    // Config settings for this particular upload.
+    config: GcsResumableUploaderConfig,
+    // The gcs bucket.
+    bucket: String,
+    // The name of the object being uploaded.
+    name: String,
+    // The upload session, when no chunk upload is in flight.
+    session: Option<ResumableUpload>,
+    // The most recent chunk upload, which hands the session back when done.
+    in_flight: Option<JoinHandle<Result<ResumableUpload, GcsUploadError>>>,
+    // The number of chunks sent till now.
+    chunk_count: u32,
+    // Number of bytes sent till now.
+    total_bytes_uploaded: u64,
+    // A buffer to accumulate data till it reaches `part_size_limit` in size.
+    buffer: BytesMut,
+}
+
+/// The maximum size of an object in GCS.
+///
+/// From <https://cloud.google.com/storage/quotas#objects>
+pub const GCS_MAX_OBJECT_SIZE: ByteSize = ByteSize::tib(5);
+
+/// Information about a completed upload after `finish` is called.
+#[derive(Debug)]
+pub struct CompletedUpload {
+    /// The total number of chunks sent.
+    pub chunk_count: u32,
+    /// The total number of bytes uploaded.
+    pub total_bytes_uploaded: u64,
+    pub bucket: String,
+    pub name: String,
+}
+
+/// Configuration object to configure the behaviour of the `GcsResumableUploader`.
+#[derive(Debug)]
+pub struct GcsResumableUploaderConfig {
+    /// Size of data buffered in memory before being sent as a chunk. Rounded
+    /// down to a multiple of [`ResumableUpload::CHUNK_ALIGNMENT`].
+    pub part_size_limit: u64,
+    /// The max file size of the object uploaded by a `GcsResumableUploader`.
+    pub file_size_limit: u64,
+}
+
+impl GcsResumableUploaderConfig {
+    fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.part_size_limit < ResumableUpload::CHUNK_ALIGNMENT {
+            return Err(anyhow!(
+                "invalid part size: {}, should be at least {} bytes",
+                self.part_size_limit,
+                ResumableUpload::CHUNK_ALIGNMENT
+            ));
+        }
+        if self.file_size_limit > GCS_MAX_OBJECT_SIZE.as_u64() {
+            return Err(anyhow!(
+                "invalid file size: {}, cannot exceed {} bytes",
+                self.file_size_limit,
+                GCS_MAX_OBJECT_SIZE.as_u64()
+            ));
+        }
+        Ok(())
+    }
+
+    fn chunk_size(&self) -> u64 {
+        self.part_size_limit - self.part_size_limit % ResumableUpload::CHUNK_ALIGNMENT
+    }
+}
+
+impl GcsResumableUploader {
+    /// Creates an instance of `GcsResumableUploader` for the object `name` in
+    /// `bucket`, starting the resumable upload session.
+    pub async fn try_new(
+        client: &GcsClient,
+        bucket: String,
+        name: String,
+        config: GcsResumableUploaderConfig,
+    ) -> Result<GcsResumableUploader, GcsUploadError> {
+        config.validate()?;
+        let session = client
+            .start_resumable_upload(&bucket, &name, Preconditions::NONE)
+            .await?;
+        Ok(GcsResumableUploader {
+            config,
+            bucket,
+            name,
+            session: Some(session),
+            in_flight: None,
+            chunk_count: 0,
+            total_bytes_uploaded: 0,
+            buffer: Default::default(),
+        })
+    }
+
+    /// Adds the `data` to the internal buffer and sends chunks while the
+    /// buffer is larger than the configured part size.
+    /// Returns an `UploadExceedsMaxFileLimit` error if the upload would exceed
+    /// the configured `file_size_limit`, unless no data has been added yet.
+    pub fn buffer_chunk(&mut self, data: &[u8]) -> Result<(), GcsUploadError> {
+        let data_len = u64::cast_from(data.len());
+        let can_force_first_upload =
+            self.added_bytes() == 0 && data_len <= GCS_MAX_OBJECT_SIZE.as_u64();
+
+        if data_len <= self.remaining_bytes_limit() || can_force_first_upload {
+            self.buffer.extend_from_slice(data);
+            let chunk_size = self.config.chunk_size();
+            while u64::cast_from(self.buffer.len()) > chunk_size {
+                let chunk = self.buffer.split_to(usize::cast_from(chunk_size));
+                self.send_chunk(chunk);
+            }
+            Ok(())
+        } else {
+            Err(GcsUploadError::UploadExceedsMaxFileLimit(
+                self.config.file_size_limit,
+            ))
+        }
+    }
+
+    /// Sends the remaining buffered data as the last chunk, completing the
+    /// upload.
+    pub async fn finish(mut self) -> Result<CompletedUpload, GcsUploadError> {
+        let remaining = self.buffer.split().freeze();
+        let num_of_bytes = u64::cast_from(remaining.len());
+        let mut session = self.take_session().await?;
+        session.upload_chunk(remaining, true).await?;
+        Ok(CompletedUpload {
+            chunk_count: self.chunk_count + 1,
+            total_bytes_uploaded: self.total_bytes_uploaded + num_of_bytes,
+            bucket: self.bucket,
+            name: self.name,
+        })
+    }
+
+    /// Returns the number of bytes added till now.
+    pub fn added_bytes(&self) -> u64 {
+        self.total_bytes_uploaded + u64::cast_from(self.buffer.len())
+    }
+
+    /// Returns the amount of bytes which can still be added without exceeding
+    /// `file_size_limit`.
+    fn remaining_bytes_limit(&self) -> u64 {
+        self.config
+            .file_size_limit
+            .saturating_sub(self.added_bytes())
+    }
+
+    /// Waits for any in-flight chunk and returns the upload session.
+    async fn take_session(&mut self) -> Result<ResumableUpload, GcsUploadError> {
+        match self.in_flight.take() {
+            Some(handle) => handle.await,
+            None => Ok(self.session.take().expect("session present")),
+        }
+    }
+
+    fn send_chunk(&mut self, chunk: BytesMut) {
+        let num_of_bytes = u64::cast_from(chunk.len());
+        let previous = self.in_flight.take();
+        let session = self.session.take();
+        let handle = spawn(|| "gcs::upload_chunk", async move {
+            let mut session = match previous {
+                Some(previous) => previous.await?,
+                None => session.expect("session present"),
+            };
+            session.upload_chunk(chunk.freeze(), false).await?;
+            Ok(session)
+        });
+        self.in_flight = Some(handle);
+        self.chunk_count += 1;
+        self.total_bytes_uploaded += num_of_bytes;
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GcsUploadError {
+    #[error("upload will exceed configured file_size_limit: {} bytes", .0)]
+    UploadExceedsMaxFileLimit(u64),
+    #[error("{}", .0.display_with_causes())]
+    Gcs(#[from] GcsError),
+    #[error("{}", .0.display_with_causes())]
+    Other(#[from] anyhow::Error),
+}
+
+/// These tests run against `fake-gcs-server` when
+/// `MZ_GCS_UPLOADER_TEST_ENDPOINT` is set, as it is in
+/// `ci/test/cargo-test/mzcompose.py`.
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use uuid::Uuid;
+
+    use crate::gcs::GcsCredentials;
+
+    use super::*;
+
+    const TEST_BUCKET: &str = "mz-test-gcs-uploader";
+
+    async fn client_for_test() -> Option<GcsClient> {
+        let endpoint = match std::env::var("MZ_GCS_UPLOADER_TEST_ENDPOINT") {
+            Ok(endpoint) => endpoint,
+            Err(_) => {
+                if mz_ore::env::is_var_truthy("CI") {
+                    panic!("CI is supposed to run this test but something has gone wrong!");
+                }
+                return None;
+            }
+        };
+        let client = GcsClient::new(
+            Some(endpoint),
+            GcsCredentials::Anonymous,
+            reqwest::ClientBuilder::new(),
+        )
+        .await
+        .expect("valid client");
+        // The bucket will already exist on repeat runs.
+        let _ = client.create_bucket("test", TEST_BUCKET).await;
+        Some(client)
+    }
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    async fn resumable_upload_success() -> Result<(), GcsUploadError> {
+        let Some(client) = client_for_test().await else {
+            return Ok(());
+        };
+        let name = format!("cargo_test/{}/file", Uuid::new_v4());
+        let chunk = usize::cast_from(ResumableUpload::CHUNK_ALIGNMENT);
+        let config = GcsResumableUploaderConfig {
+            part_size_limit: ResumableUpload::CHUNK_ALIGNMENT,
+            file_size_limit: ByteSize::mib(10).as_u64(),
+        };
+        let mut uploader =
+            GcsResumableUploader::try_new(&client, TEST_BUCKET.into(), name.clone(), config)
+                .await?;
+
+        // Two and a half chunks: two full chunks are sent while buffering,
+        // the remainder on finish.
+        let mut expected = Vec::new();
+        for i in 0..5 {
+            let part = vec![u8::try_from(i).unwrap(); chunk / 2];
+            uploader.buffer_chunk(&part)?;
+            expected.extend(part);
+        }
+        let CompletedUpload {
+            chunk_count,
+            total_bytes_uploaded,
+            ..
+        } = uploader.finish().await?;
+        assert_eq!(chunk_count, 3);
+        assert_eq!(total_bytes_uploaded, u64::cast_from(expected.len()));
+
+        let response = client
+            .get_object(TEST_BUCKET, &name)
+            .await?
+            .expect("object exists");
+        let body = response.bytes().await.map_err(GcsError::from)?;
+        assert_eq!(body, Bytes::from(expected));
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    async fn resumable_upload_exceeds_max_file_limit() -> Result<(), GcsUploadError> {
+        let Some(client) = client_for_test().await else {
+            return Ok(());
+        };
+        let name = format!("cargo_test/{}/file", Uuid::new_v4());
+        let config = GcsResumableUploaderConfig {
+            part_size_limit: ResumableUpload::CHUNK_ALIGNMENT,
+            file_size_limit: 10,
+        };
+        let mut uploader =
+            GcsResumableUploader::try_new(&client, TEST_BUCKET.into(), name, config).await?;
+        uploader.buffer_chunk(b"0123456789")?;
+        let res = uploader.buffer_chunk(b"a");
+        assert!(matches!(
+            res,
+            Err(GcsUploadError::UploadExceedsMaxFileLimit(10))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Google Cloud Platform utilities.
+
+pub mod auth;
+pub mod gcs;
+pub mod gcs_uploader;
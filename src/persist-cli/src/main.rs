@@ -43,6 +43,9 @@ enum Command {
     Inspect(mz_persist_client::cli::inspect::InspectArgs),
     Admin(mz_persist_client::cli::admin::AdminArgs),
     Bench(mz_persist_client::cli::bench::BenchArgs),
+    Compaction(mz_persist_client::cli::compaction::CompactionArgs),
+    Export(mz_persist_client::cli::export::ExportArgs),
+    Import(mz_persist_client::cli::export::ImportArgs),
     Service(crate::service::Args),
 }
 
@@ -80,6 +83,21 @@ fn main() {
         }
         Command::Admin(command) => runtime.block_on(mz_persist_client::cli::admin::run(command)),
         Command::Bench(command) => runtime.block_on(mz_persist_client::cli::bench::run(command)),
+        Command::Compaction(args) => {
+            runtime.block_on(mz_persist_client::cli::compaction::run(args, |server| {
+                server.register::<
+                    mz_storage_types::sources::SourceData,
+                    (),
+                    mz_repr::Timestamp,
+                    mz_storage_types::StorageDiff,
+                >();
+            }))
+        }
+        Command::Export(args) => runtime.block_on(mz_persist_client::cli::export::run_export(args)),
+        Command::Import(args) => runtime.block_on(mz_persist_client::cli::export::run_import::<
+            mz_storage_types::sources::SourceData,
+            (),
+        >(args)),
         Command::Service(args) => runtime.block_on(crate::service::run(args)),
     };
 
@@ -54,6 +54,7 @@ fn main() {
         .bytes([
             ".mz_persist_client.batch.ProtoBatch",
             ".mz_persist_client.internal.diff.ProtoStateFieldDiffs",
+            ".mz_persist_client.internal.service.ProtoCompactRequest",
             ".mz_persist_client.internal.service.ProtoPushDiff",
             ".mz_persist_client.internal.state.ProtoEncodedSchemas",
             ".mz_persist_client.internal.state.ProtoHollowBatchPart",
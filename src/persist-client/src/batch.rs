@@ -60,9 +60,12 @@ use crate::internal::metrics::{BatchWriteMetrics, Metrics, RetryMetrics, ShardMe
 use crate::internal::paths::{PartId, PartialBatchKey, WriterKey};
 use crate::internal::state::{
     BatchPart, ENABLE_INCREMENTAL_COMPACTION, HollowBatch, HollowBatchPart, HollowRun,
-    HollowRunRef, ProtoInlineBatchPart, RunId, RunMeta, RunOrder, RunPart,
+    HollowRunRef, PART_COMPRESSION, ProtoInlineBatchPart, RunId, RunMeta, RunOrder, RunPart,
+};
+use crate::stats::{
+    STATS_BLOOM_FILTER_ENABLED, STATS_BLOOM_FILTER_MAX_BYTES, STATS_BUDGET_BYTES,
+    STATS_COLLECTION_ENABLED, untrimmable_columns,
 };
-use crate::stats::{STATS_BUDGET_BYTES, STATS_COLLECTION_ENABLED, untrimmable_columns};
 use crate::{PersistConfig, ShardId};
 
 include!(concat!(env!("OUT_DIR"), "/mz_persist_client.batch.rs"));
@@ -359,8 +362,14 @@ pub struct BatchBuilderConfig {
     pub(crate) inline_writes_single_max_bytes: usize,
     pub(crate) stats_collection_enabled: bool,
     pub(crate) stats_budget: usize,
+    /// The maximum size of each bloom filter to compute for a part, or None if
+    /// we shouldn't compute them at all.
+    pub(crate) stats_bloom_filter_max_bytes: Option<usize>,
     pub(crate) stats_untrimmable_columns: Arc<UntrimmableColumns>,
     pub(crate) encoding_config: EncodingConfig,
+    /// The shard's compression policy, if it has one. Parts only record the
+    /// compression they were written with when it comes from a policy.
+    pub(crate) compression_policy: Option<CompressionFormat>,
     pub(crate) preferred_order: RunOrder,
     pub(crate) structured_key_lower_len: usize,
     pub(crate) run_length_limit: usize,
@@ -453,11 +462,15 @@ impl BatchBuilderConfig {
             inline_writes_single_max_bytes: INLINE_WRITES_SINGLE_MAX_BYTES.get(value),
             stats_collection_enabled: STATS_COLLECTION_ENABLED.get(value),
             stats_budget: STATS_BUDGET_BYTES.get(value),
+            stats_bloom_filter_max_bytes: STATS_BLOOM_FILTER_ENABLED
+                .get(value)
+                .then(|| STATS_BLOOM_FILTER_MAX_BYTES.get(value)),
             stats_untrimmable_columns: Arc::new(untrimmable_columns(value)),
             encoding_config: EncodingConfig {
                 use_dictionary: ENCODING_ENABLE_DICTIONARY.get(value),
                 compression: CompressionFormat::from_str(&ENCODING_COMPRESSION_FORMAT.get(value)),
             },
+            compression_policy: None,
             preferred_order,
             structured_key_lower_len: STRUCTURED_KEY_LOWER_LEN.get(value),
             run_length_limit: MAX_RUN_LEN.get(value).clamp(2, usize::MAX),
@@ -468,6 +481,15 @@ impl BatchBuilderConfig {
             enable_incremental_compaction: ENABLE_INCREMENTAL_COMPACTION.get(value),
         }
     }
+
+    /// Applies a shard's compression policy, which takes precedence over the
+    /// process-wide default. See [crate::PersistClient::set_compression].
+    pub(crate) fn apply_compression_policy(&mut self, compression: Option<CompressionFormat>) {
+        if let Some(compression) = compression {
+            self.encoding_config.compression = compression;
+        }
+        self.compression_policy = compression;
+    }
 }
 
 /// A list of (lowercase) column names that persist will always retain
@@ -1170,6 +1192,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
         let goodbytes = updates.updates.goodbytes();
         let metrics_ = Arc::clone(&metrics);
         let schema_id = write_schemas.id;
+        let compression_policy = cfg.compression_policy;
 
         let (stats, key_lower, structured_key_lower, (buf, encode_time)) = isolated_runtime
             .spawn_named(|| "batch::encode_part", async move {
@@ -1181,11 +1204,14 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
                             write_schemas.val.as_ref(),
                         );
 
-                        let key_stats = write_schemas
+                        let key_decoder = write_schemas
                             .key
                             .decoder_any(ext.key.as_ref())
-                            .expect("decoding just-encoded data")
-                            .stats();
+                            .expect("decoding just-encoded data");
+                        let mut key_stats = key_decoder.stats();
+                        if let Some(max_bytes) = cfg.stats_bloom_filter_max_bytes {
+                            key_decoder.add_filters(&mut key_stats, max_bytes);
+                        }
 
                         let part_stats = PartStats { key: key_stats };
 
@@ -1301,7 +1327,12 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
             stats
         });
 
-        let meta = MetadataMap::default();
+        // Parts written with the process-wide default don't record it, which
+        // keeps state for shards without a policy the same size as before.
+        let mut meta = MetadataMap::default();
+        if let Some(compression) = compression_policy {
+            meta.set(PART_COMPRESSION, compression);
+        }
         BatchPart::Hollow(HollowBatchPart {
             key: partial_key,
             meta,
@@ -1514,11 +1545,11 @@ mod tests {
     use mz_dyncfg::ConfigUpdates;
 
     use super::*;
-    use crate::PersistLocation;
     use crate::cache::PersistClientCache;
     use crate::cfg::BATCH_BUILDER_MAX_OUTSTANDING_PARTS;
     use crate::internal::paths::{BlobKey, PartialBlobKey};
     use crate::tests::{all_ok, new_test_client};
+    use crate::{Diagnostics, PersistLocation};
 
     #[mz_ore::test(tokio::test)]
     #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
@@ -1650,6 +1681,46 @@ mod tests {
         }
     }
 
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn batch_builder_compression_policy() {
+        let cache = PersistClientCache::new_no_metrics();
+        cache.cfg.set_config(&INLINE_WRITES_SINGLE_MAX_BYTES, 0);
+        cache.cfg.set_config(&INLINE_WRITES_TOTAL_MAX_BYTES, 0);
+        let client = cache
+            .open(PersistLocation::new_in_mem())
+            .await
+            .expect("client construction failed");
+        let shard_id = ShardId::new();
+        let (mut write, _) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+
+        let data = [(("1".to_owned(), "one".to_owned()), 1, 1)];
+        let batch = write.expect_batch(&data, 0, 2).await;
+        for part in &batch.batch.parts {
+            let part = part.expect_hollow_part();
+            assert_eq!(part.compression(), None);
+        }
+
+        // Once a policy is set, newly written parts pick it up.
+        let policy = CompressionFormat::parse("zstd-3").expect("valid format");
+        client
+            .set_compression::<String, String, u64, i64>(
+                shard_id,
+                Some(policy),
+                Diagnostics::for_tests(),
+            )
+            .await
+            .expect("valid usage");
+        let batch = write.expect_batch(&data, 2, 4).await;
+        assert!(batch.batch.part_count() > 0);
+        for part in &batch.batch.parts {
+            let part = part.expect_hollow_part();
+            assert_eq!(part.compression(), Some(policy));
+        }
+    }
+
     #[mz_ore::test(tokio::test)]
     #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
     async fn batch_delete() {
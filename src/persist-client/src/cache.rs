@@ -25,6 +25,7 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::task::{AbortOnDropHandle, JoinHandle};
 use mz_ore::url::SensitiveUrl;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encryption::EncryptedBlob;
 use mz_persist::location::{
     BLOB_GET_LIVENESS_KEY, Blob, CONSENSUS_HEAD_LIVENESS_KEY, Consensus, ExternalError, Tasked,
     VersionedData,
@@ -36,7 +37,7 @@ use tracing::debug;
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::error::{CodecConcreteType, CodecMismatch};
-use crate::internal::cache::BlobMemCache;
+use crate::internal::cache::{BlobDiskCache, BlobMemCache};
 use crate::internal::machine::retry_external;
 use crate::internal::metrics::{LockMetrics, Metrics, MetricsBlob, MetricsConsensus, ShardMetrics};
 use crate::internal::state::TypedState;
@@ -258,8 +259,28 @@ impl PersistClientCache {
                     Self::PROMETHEUS_SCRAPE_INTERVAL,
                 )
                 .await;
-                // This is intentionally "outside" (wrapping) MetricsBlob so
-                // that we don't include cached responses in blob metrics.
+                // The caches are intentionally "outside" (wrapping) MetricsBlob
+                // so that we don't include cached responses in blob metrics.
+                let blob: Arc<dyn Blob> = match &self.cfg.blob_cache_dir {
+                    Some(dir) => {
+                        BlobDiskCache::new(&self.cfg, Arc::clone(&self.metrics), blob, dir.clone())
+                            .await
+                    }
+                    None => blob,
+                };
+                // Encryption is "outside" the disk cache, so that only
+                // ciphertext is written to local disk, but "inside" the memory
+                // cache, so that cached parts are not decrypted again. Being
+                // "outside" MetricsBlob, we still measure the bytes that
+                // actually go over the wire.
+                let blob: Arc<dyn Blob> = match &self.cfg.blob_keys {
+                    Some(keys) => Arc::new(Tasked(Arc::new(EncryptedBlob::new(
+                        blob,
+                        Arc::clone(keys),
+                        Arc::clone(&self.cfg.configs),
+                    )))),
+                    None => blob,
+                };
                 let blob = BlobMemCache::new(&self.cfg, Arc::clone(&self.metrics), blob);
                 Arc::clone(&x.insert((RttLatencyTask(task.abort_on_drop()), blob)).1)
             }
@@ -887,6 +908,59 @@ mod tests {
         assert_eq!(cache.consensus_by_uri.lock().await.len(), 4);
     }
 
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn blob_disk_cache_encrypted() {
+        use bytes::Bytes;
+        use mz_persist::encryption::{BLOB_ENCRYPTION_KEY_ID, StaticBlobKeys};
+
+        use crate::internal::cache::{BLOB_CACHE_DISK_LIMIT_BYTES, BLOB_CACHE_MEM_LIMIT_BYTES};
+        use crate::internal::paths::{PartId, PartialBatchKey, WriterKey};
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut cfg = PersistConfig::new_for_tests();
+        // Skip the memory cache, so that reads go to the disk cache.
+        cfg.set_config(&BLOB_CACHE_MEM_LIMIT_BYTES, 0);
+        cfg.set_config(&BLOB_CACHE_DISK_LIMIT_BYTES, 1024 * 1024);
+        cfg.set_config(&BLOB_ENCRYPTION_KEY_ID, "k1");
+        let mut keys = StaticBlobKeys::default();
+        keys.insert("k1", vec![1; 32]);
+        cfg.blob_keys = Some(Arc::new(keys));
+        cfg.blob_cache_dir = Some(dir.path().to_owned());
+        let writer = WriterKey::for_version(&cfg.build_version);
+        let cache = PersistClientCache::new(cfg, &MetricsRegistry::new(), |_, _| {
+            PubSubClientConnection::noop()
+        });
+        let blob = cache
+            .open_blob(SensitiveUrl::from_str("mem://").expect("invalid URL"))
+            .await
+            .expect("failed to open blob");
+
+        let key = PartialBatchKey::new(&writer, &PartId::new())
+            .complete(&ShardId::new())
+            .to_string();
+        let plaintext = b"very secret plaintext";
+        blob.set(&key, Bytes::from_static(plaintext))
+            .await
+            .expect("set");
+        for _ in 0..2 {
+            let value = blob.get(&key).await.expect("get").expect("present");
+            assert_eq!(value.into_contiguous(), plaintext);
+        }
+
+        // The part was cached on disk, but only as ciphertext.
+        let files: Vec<_> = std::fs::read_dir(dir.path())
+            .expect("dir")
+            .map(|entry| std::fs::read(entry.expect("entry").path()).expect("read"))
+            .collect();
+        assert_eq!(files.len(), 1);
+        assert!(
+            !files[0]
+                .windows(plaintext.len())
+                .any(|window| window == plaintext)
+        );
+    }
+
     #[mz_ore::test(tokio::test)]
     #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
     async fn state_cache() {
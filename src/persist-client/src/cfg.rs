@@ -11,6 +11,7 @@
 
 //! The tunable knobs for persist.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -20,6 +21,7 @@ use mz_dyncfg::{Config, ConfigDefault, ConfigSet, ConfigUpdates};
 use mz_ore::instrument;
 use mz_ore::now::NowFn;
 use mz_persist::cfg::BlobKnobs;
+use mz_persist::encryption::BlobKeys;
 use mz_persist::retry::Retry;
 use mz_postgres_client::PostgresClientKnobs;
 use proptest_derive::Arbitrary;
@@ -28,6 +30,7 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::watch;
 
 use crate::async_runtime;
+use crate::compaction_service::CompactionExecutor;
 use crate::internal::machine::{
     NEXT_LISTEN_BATCH_RETRYER_CLAMP, NEXT_LISTEN_BATCH_RETRYER_INITIAL_BACKOFF,
     NEXT_LISTEN_BATCH_RETRYER_MULTIPLIER,
@@ -137,6 +140,20 @@ pub struct PersistConfig {
     /// Number of worker threads to create for the [`crate::IsolatedRuntime`], defaults to the
     /// number of threads.
     pub isolated_runtime_worker_threads: usize,
+    /// The source of the keys to encrypt and decrypt blobs with, if any.
+    ///
+    /// See [mz_persist::encryption].
+    pub blob_keys: Option<Arc<dyn BlobKeys>>,
+    /// A directory dedicated to caching blobs on local disk, if any.
+    ///
+    /// See [crate::internal::cache::BlobDiskCache].
+    pub blob_cache_dir: Option<PathBuf>,
+    /// Where to send compaction requests instead of running them inline, if
+    /// anywhere.
+    ///
+    /// Only used while [crate::compaction_service::COMPACTION_SERVICE_ENABLED]
+    /// is set. See [crate::compaction_service].
+    pub compaction_executor: Option<Arc<dyn CompactionExecutor>>,
 }
 
 // Impl Deref to ConfigSet for convenience of accessing the dynamic configs.
@@ -178,6 +195,9 @@ impl PersistConfig {
             writer_lease_duration: 60 * Duration::from_secs(60),
             critical_downgrade_interval: Duration::from_secs(30),
             isolated_runtime_worker_threads: num_cpus::get(),
+            blob_keys: None,
+            blob_cache_dir: None,
+            compaction_executor: None,
             // TODO: This doesn't work with the process orchestrator. Instead,
             // separate --log-prefix into --service-name and --enable-log-prefix
             // options, where the first is always provided and the second is
@@ -299,6 +319,8 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&BLOB_OPERATION_ATTEMPT_TIMEOUT)
         .add(&BLOB_CONNECT_TIMEOUT)
         .add(&BLOB_READ_TIMEOUT)
+        .add(&crate::compaction_service::COMPACTION_SERVICE_ENABLED)
+        .add(&crate::compaction_service::COMPACTION_SERVICE_CONNECT_TIMEOUT)
         .add(&crate::cfg::CONSENSUS_CONNECTION_POOL_MAX_SIZE)
         .add(&crate::cfg::CONSENSUS_CONNECTION_POOL_MAX_WAIT)
         .add(&crate::cfg::CONSENSUS_CONNECTION_POOL_TTL_STAGGER)
@@ -332,6 +354,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::internal::cache::BLOB_CACHE_MEM_LIMIT_BYTES)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_WITH_THREADS)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_FACTOR_BYTES)
+        .add(&crate::internal::cache::BLOB_CACHE_DISK_LIMIT_BYTES)
         .add(&crate::internal::compact::COMPACTION_MINIMUM_TIMEOUT)
         .add(&crate::internal::compact::COMPACTION_CHECK_PROCESS_FLAG)
         .add(&crate::internal::machine::CLAIM_UNCLAIMED_COMPACTIONS)
@@ -364,6 +387,8 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::rpc::PUBSUB_RECONNECT_BACKOFF)
         .add(&crate::stats::STATS_AUDIT_PERCENT)
         .add(&crate::stats::STATS_AUDIT_PANIC)
+        .add(&crate::stats::STATS_BLOOM_FILTER_ENABLED)
+        .add(&crate::stats::STATS_BLOOM_FILTER_MAX_BYTES)
         .add(&crate::stats::STATS_BUDGET_BYTES)
         .add(&crate::stats::STATS_COLLECTION_ENABLED)
         .add(&crate::stats::STATS_FILTER_ENABLED)
@@ -34,7 +34,7 @@ use tracing::{info, warn};
 use crate::async_runtime::IsolatedRuntime;
 use crate::cache::StateCache;
 use crate::cfg::{COMPACTION_MEMORY_BOUND_BYTES, all_dyncfgs};
-use crate::cli::args::{StateArgs, StoreArgs, make_blob, make_consensus};
+use crate::cli::args::{BlobKeyArgs, NO_COMMIT, StateArgs, StoreArgs, make_blob, make_consensus};
 use crate::critical::Opaque;
 use crate::internal::compact::{CompactConfig, CompactReq, Compactor};
 use crate::internal::encoding::Schemas;
@@ -77,6 +77,11 @@ pub(crate) enum Command {
     /// Attempt to ensure that all the files referenced by consensus are available
     /// in Blob.
     RestoreBlob(RestoreBlobArgs),
+    /// Back up every shard in an environment, at a single point in time, to a
+    /// separate blob location.
+    Backup(BackupArgs),
+    /// Restore a backup taken by `backup` into a fresh consensus and blob.
+    Restore(RestoreArgs),
 }
 
 /// Manually completes all fueled compactions in a shard.
@@ -123,6 +128,66 @@ pub(crate) struct RestoreBlobArgs {
     concurrency: usize,
 }
 
+/// Back up every shard in an environment to a separate blob location.
+///
+/// Stop environmentd and all clusters first: the backup needs every shard to
+/// stop changing. The source is only read from. The backup is written only with `--commit`.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct BackupArgs {
+    #[clap(flatten)]
+    state: StoreArgs,
+
+    #[clap(flatten)]
+    blob_keys: BlobKeyArgs,
+
+    /// Blob to write the backup to, e.g. `file:///path/to/backup`.
+    ///
+    /// This should be empty, or contain an older backup to overwrite.
+    #[clap(long, env = "BACKUP_BLOB_URI")]
+    backup_blob_uri: SensitiveUrl,
+
+    /// The id of the key, given with `--blob-encryption-key`, to encrypt the
+    /// written blobs with. Required if any keys are given.
+    #[clap(long, value_name = "ID")]
+    blob_encryption_key_id: Option<String>,
+
+    /// The number of concurrent copy operations to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+
+    /// The number of passes over the environment to make before giving up on
+    /// finding a consistent point in time. The first pass copies every shard
+    /// and the second confirms that none changed, so on a quiesced
+    /// environment the backup takes two passes.
+    #[clap(long, default_value_t = 10)]
+    max_attempts: usize,
+}
+
+/// Restore a backup into a fresh consensus and blob.
+///
+/// None of the backed up shards may already exist in the target consensus.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct RestoreArgs {
+    #[clap(flatten)]
+    state: StoreArgs,
+
+    #[clap(flatten)]
+    blob_keys: BlobKeyArgs,
+
+    /// Blob that the backup was written to.
+    #[clap(long, env = "BACKUP_BLOB_URI")]
+    backup_blob_uri: SensitiveUrl,
+
+    /// The id of the key, given with `--blob-encryption-key`, to encrypt the
+    /// written blobs with. Required if any keys are given.
+    #[clap(long, value_name = "ID")]
+    blob_encryption_key_id: Option<String>,
+
+    /// The number of concurrent copy operations to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+}
+
 /// Runs the given read-write admin command.
 pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
     match command.command {
@@ -188,6 +253,7 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
                         shard_id,
                         consensus_uri,
                         blob_uri,
+                        blob_keys,
                     },
                 force_downgrade_since,
                 force_downgrade_upper,
@@ -202,7 +268,8 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
 
             let configs = all_dyncfgs(ConfigSet::default());
             // TODO: Fetch the latest values of these configs from Launch Darkly.
-            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            let mut cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            blob_keys.configure(&mut cfg)?;
             let metrics_registry = MetricsRegistry::new();
             let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
             let consensus =
@@ -352,6 +419,93 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
                 bail!("referenced blobs were not restored: {not_restored:#?}")
             }
         }
+        Command::Backup(args) => {
+            let BackupArgs {
+                state:
+                    StoreArgs {
+                        consensus_uri,
+                        blob_uri,
+                    },
+                blob_keys,
+                backup_blob_uri,
+                blob_encryption_key_id,
+                concurrency,
+                max_attempts,
+            } = args;
+            let commit = command.commit;
+            let configs = all_dyncfgs(ConfigSet::default());
+            // TODO: Fetch the latest values of these configs from Launch Darkly.
+            let mut cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            blob_keys.configure(&mut cfg)?;
+            // The backup is encrypted like the environment it's taken from.
+            // Only `target` is written to, so this doesn't affect the others.
+            blob_keys.configure_writes(&cfg, blob_encryption_key_id.as_deref())?;
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            // The environment being backed up is never modified.
+            let consensus =
+                make_consensus(&cfg, &consensus_uri, NO_COMMIT, Arc::clone(&metrics)).await?;
+            let blob = make_blob(&cfg, &blob_uri, NO_COMMIT, Arc::clone(&metrics)).await?;
+            let target = make_blob(&cfg, &backup_blob_uri, commit, Arc::clone(&metrics)).await?;
+            let versions = StateVersions::new(cfg, consensus, blob, Arc::clone(&metrics));
+
+            let start = Instant::now();
+            let manifest = crate::internal::backup::backup(
+                &versions,
+                target.as_ref(),
+                concurrency,
+                max_attempts,
+            )
+            .await?;
+            info!(
+                "Backed up {} shards; {:?} elapsed.",
+                manifest.shards.len(),
+                start.elapsed()
+            );
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
+        Command::Restore(args) => {
+            let RestoreArgs {
+                state:
+                    StoreArgs {
+                        consensus_uri,
+                        blob_uri,
+                    },
+                blob_keys,
+                backup_blob_uri,
+                blob_encryption_key_id,
+                concurrency,
+            } = args;
+            let commit = command.commit;
+            let configs = all_dyncfgs(ConfigSet::default());
+            // TODO: Fetch the latest values of these configs from Launch Darkly.
+            let mut cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            blob_keys.configure(&mut cfg)?;
+            blob_keys.configure_writes(&cfg, blob_encryption_key_id.as_deref())?;
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            let source = make_blob(&cfg, &backup_blob_uri, NO_COMMIT, Arc::clone(&metrics)).await?;
+            let consensus =
+                make_consensus(&cfg, &consensus_uri, commit, Arc::clone(&metrics)).await?;
+            let blob = make_blob(&cfg, &blob_uri, commit, Arc::clone(&metrics)).await?;
+
+            let start = Instant::now();
+            let manifest = crate::internal::backup::restore(
+                &cfg,
+                source.as_ref(),
+                consensus.as_ref(),
+                blob.as_ref(),
+                concurrency,
+            )
+            .await?;
+            info!(
+                "Restored {} shards from a backup taken by version {}; {:?} elapsed.",
+                manifest.shards.len(),
+                manifest.build_version,
+                start.elapsed()
+            );
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
     }
     Ok(())
 }
@@ -467,8 +621,12 @@ where
                 val: Arc::clone(&val_schema),
             };
 
+            let mut compact_cfg = CompactConfig::new(&cfg, shard_id);
+            compact_cfg
+                .batch
+                .apply_compression_policy(machine.compression());
             let res = Compactor::<K, V, T, D>::compact(
-                CompactConfig::new(&cfg, shard_id),
+                compact_cfg,
                 Arc::clone(&blob),
                 Arc::clone(&metrics),
                 Arc::clone(&machine.applier.shard_metrics),
@@ -14,14 +14,17 @@ use crate::cfg::PersistConfig;
 use crate::internal::metrics::{MetricsBlob, MetricsConsensus};
 use crate::internal::state_versions::StateVersions;
 use crate::metrics::Metrics;
+use anyhow::{Context, anyhow, bail};
 use async_trait::async_trait;
 use bytes::Bytes;
 use mz_build_info::BuildInfo;
+use mz_dyncfg::ConfigUpdates;
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::url::SensitiveUrl;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encryption::{BLOB_ENCRYPTION_KEY_ID, EncryptedBlob, StaticBlobKeys};
 use mz_persist::location::{
     Blob, BlobMetadata, CaSResult, Consensus, ExternalError, ResultStream, SeqNo, Tasked,
     VersionedData,
@@ -92,6 +95,70 @@ pub struct StateArgs {
     /// URI scoped to the environment's bucket prefix.
     #[clap(long, env = "BLOB_URI")]
     pub(crate) blob_uri: SensitiveUrl,
+
+    #[clap(flatten)]
+    pub(crate) blob_keys: BlobKeyArgs,
+}
+
+/// Arguments for reading blobs encrypted by [mz_persist::encryption].
+#[derive(Debug, Clone, clap::Args)]
+pub struct BlobKeyArgs {
+    /// A key-encryption key to decrypt blobs with, as `<ID>=<PATH>`, where the file at `PATH`
+    /// contains the 32 bytes of the key with id `ID`.
+    ///
+    /// May be given multiple times, e.g. for the keys before and after a rotation.
+    #[clap(long = "blob-encryption-key", value_name = "ID=PATH")]
+    pub(crate) keys: Vec<String>,
+}
+
+impl BlobKeyArgs {
+    /// Configures `cfg` to decrypt blobs with the given keys, if any.
+    pub(crate) fn configure(&self, cfg: &mut PersistConfig) -> Result<(), anyhow::Error> {
+        if self.keys.is_empty() {
+            return Ok(());
+        }
+        let mut keys = StaticBlobKeys::default();
+        for arg in &self.keys {
+            let (id, path) = arg
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid blob encryption key {arg}: expected ID=PATH"))?;
+            let key = std::fs::read(path)
+                .with_context(|| format!("reading blob encryption key {id} from {path}"))?;
+            keys.insert(id, key);
+        }
+        cfg.blob_keys = Some(Arc::new(keys));
+        Ok(())
+    }
+
+    /// Configures `cfg` to encrypt the blobs it writes with the key `key_id`,
+    /// which must be one of the given keys.
+    ///
+    /// Blobs are written unencrypted without a `key_id`, which is only
+    /// allowed if no keys were given, i.e. if the blobs being read aren't
+    /// encrypted either.
+    pub(crate) fn configure_writes(
+        &self,
+        cfg: &PersistConfig,
+        key_id: Option<&str>,
+    ) -> Result<(), anyhow::Error> {
+        let Some(key_id) = key_id else {
+            if !self.keys.is_empty() {
+                bail!("blobs read with an encryption key must also be written with one");
+            }
+            return Ok(());
+        };
+        let known = self
+            .keys
+            .iter()
+            .any(|arg| arg.split_once('=').is_some_and(|(id, _)| id == key_id));
+        if !known {
+            bail!("unknown blob encryption key {key_id}: pass it with --blob-encryption-key");
+        }
+        let mut updates = ConfigUpdates::default();
+        updates.add(&BLOB_ENCRYPTION_KEY_ID, key_id.to_owned());
+        cfg.apply_from(&updates);
+        Ok(())
+    }
 }
 
 // BuildInfo with a larger version than any version we expect to see in prod,
@@ -111,7 +178,8 @@ impl StateArgs {
     }
 
     pub(crate) async fn open(&self) -> Result<StateVersions, anyhow::Error> {
-        let cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+        let mut cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+        self.blob_keys.configure(&mut cfg)?;
         let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
         let consensus =
             make_consensus(&cfg, &self.consensus_uri, NO_COMMIT, Arc::clone(&metrics)).await?;
@@ -157,6 +225,14 @@ pub(super) async fn make_blob(
     )
     .await?;
     let blob = blob.clone().open().await?;
+    let blob: Arc<dyn Blob> = match &cfg.blob_keys {
+        Some(keys) => Arc::new(EncryptedBlob::new(
+            blob,
+            Arc::clone(keys),
+            Arc::clone(&cfg.configs),
+        )),
+        None => blob,
+    };
     let blob = if commit {
         blob
     } else {
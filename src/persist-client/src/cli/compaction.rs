@@ -0,0 +1,72 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! CLI tool for running a Persist compaction service
+//!
+//! See [crate::compaction_service] for how writers hand compaction requests to
+//! it.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use mz_dyncfg::ConfigSet;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::now::SYSTEM_TIME;
+use mz_ore::url::SensitiveUrl;
+
+use crate::async_runtime::IsolatedRuntime;
+use crate::cfg::all_dyncfgs;
+use crate::cli::args::{BlobKeyArgs, make_blob};
+use crate::compaction_service::PersistCompactionServer;
+use crate::{BUILD_INFO, Metrics, PersistConfig};
+
+/// Serve compaction requests for shards in a blob store.
+#[derive(Debug, clap::Parser)]
+pub struct CompactionArgs {
+    /// The address to listen for compaction requests on.
+    #[clap(long, value_name = "HOST:PORT", default_value = "127.0.0.1:6890")]
+    listen_addr: SocketAddr,
+
+    /// Blob to read inputs from and write outputs to. Must be the blob of the
+    /// processes sending requests.
+    #[clap(long, env = "BLOB_URI")]
+    blob_uri: SensitiveUrl,
+
+    #[clap(flatten)]
+    blob_keys: BlobKeyArgs,
+
+    /// The token that requests must carry to be served.
+    #[clap(long, env = "AUTH_TOKEN")]
+    auth_token: String,
+}
+
+/// Runs the compaction service until it fails.
+///
+/// `register` is called once with the server, to
+/// [register](PersistCompactionServer::register) the codecs of the shards it
+/// should compact.
+pub async fn run(
+    args: CompactionArgs,
+    register: impl FnOnce(&mut PersistCompactionServer),
+) -> Result<(), anyhow::Error> {
+    let configs = all_dyncfgs(ConfigSet::default());
+    let mut cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+    args.blob_keys.configure(&mut cfg)?;
+    let metrics_registry = MetricsRegistry::new();
+    let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+    let blob = make_blob(&cfg, &args.blob_uri, true, Arc::clone(&metrics)).await?;
+    let isolated_runtime = Arc::new(IsolatedRuntime::new(
+        &metrics_registry,
+        Some(cfg.isolated_runtime_worker_threads),
+    ));
+
+    let mut server = PersistCompactionServer::new(&cfg, metrics, blob, isolated_runtime);
+    register(&mut server);
+    server.serve(args.listen_addr, args.auth_token).await
+}
@@ -0,0 +1,687 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! CLI tools for moving persist shards in and out of Parquet datasets
+//!
+//! An export is a directory of Parquet files, each with a `key`, `val`, `ts`
+//! and `diff` column, where `key` and `val` use the shard's registered Arrow
+//! schema. These can be read directly by tools like DuckDB. A `manifest.json`
+//! alongside them records what `import` needs to turn them back into a shard.
+
+use std::fmt::Debug;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail};
+use arrow::array::{Array, AsArray, Int64Array, UInt64Array};
+use arrow::datatypes::{DataType, Field, Fields, Int64Type, UInt64Type};
+use bytes::Bytes;
+use futures_util::TryStreamExt;
+use mz_dyncfg::ConfigSet;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::now::SYSTEM_TIME;
+use mz_persist_types::parquet::{EncodingConfig, decode_arrays, encode_arrays};
+use mz_persist_types::part::Part;
+use mz_persist_types::schema::backward_compatible;
+use mz_persist_types::{Codec, Codec64};
+use serde::{Deserialize, Serialize};
+use timely::progress::{Antichain, Timestamp};
+use tracing::info;
+
+use crate::async_runtime::IsolatedRuntime;
+use crate::cache::StateCache;
+use crate::cfg::all_dyncfgs;
+use crate::cli::args::{BlobKeyArgs, StateArgs, StoreArgs, make_blob, make_consensus};
+use crate::fetch::{EncodedPart, FetchConfig};
+use crate::internal::state::EncodedSchemas;
+use crate::internal::state_versions::StateVersions;
+use crate::rpc::{NoopPubSubSender, PubSubSender};
+use crate::{BUILD_INFO, Diagnostics, Metrics, PersistClient, PersistConfig, ShardId};
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Describes the contents of an export directory.
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportManifest {
+    /// The shard that was exported.
+    shard_id: String,
+    /// The timestamp that all exported updates were advanced to.
+    as_of: u64,
+    /// The codec names of the exported shard.
+    key_codec: String,
+    val_codec: String,
+    /// The hex-encoded key and value schemas, as registered with the exported
+    /// shard.
+    key_schema: String,
+    val_schema: String,
+    /// The Parquet files, relative to the export directory.
+    files: Vec<String>,
+}
+
+/// Write the consolidated contents of a shard at a timestamp as Parquet.
+///
+/// All updates are held in memory while consolidating, so this is intended
+/// for debugging-sized shards.
+#[derive(Debug, clap::Parser)]
+pub struct ExportArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The timestamp to export the contents of the shard as of. Must be
+    /// readable: not less than the shard's since and less than its upper.
+    #[clap(long)]
+    as_of: u64,
+
+    /// The directory to write the export to. Created if it doesn't exist.
+    #[clap(long)]
+    output: PathBuf,
+
+    /// The maximum number of updates to write to each Parquet file.
+    #[clap(long, default_value_t = 1_000_000)]
+    rows_per_file: usize,
+}
+
+/// Create a new shard from the output of `export`.
+///
+/// All updates are written at the export's `as_of`, so the new shard's upper
+/// is the `as_of` plus one.
+#[derive(Debug, clap::Parser)]
+pub struct ImportArgs {
+    #[clap(flatten)]
+    store: StoreArgs,
+
+    #[clap(flatten)]
+    blob_keys: BlobKeyArgs,
+
+    /// A directory previously written by `export`.
+    #[clap(long)]
+    input: PathBuf,
+
+    /// The shard to create. Must not contain any data. Defaults to a new,
+    /// randomly generated shard id.
+    #[clap(long)]
+    shard_id: Option<String>,
+
+    /// Whether to commit any modifications (defaults to dry run).
+    #[clap(long)]
+    commit: bool,
+}
+
+/// Runs `export`.
+pub async fn run_export(args: ExportArgs) -> Result<(), anyhow::Error> {
+    let shard_id = args.state.shard_id();
+    let state_versions = args.state.open().await?;
+    let manifest = export(
+        &state_versions,
+        shard_id,
+        args.as_of,
+        &args.output,
+        args.rows_per_file,
+    )
+    .await?;
+    info!(
+        "exported {} files to {}",
+        manifest.files.len(),
+        args.output.display()
+    );
+    Ok(())
+}
+
+async fn export(
+    state_versions: &StateVersions,
+    shard_id: ShardId,
+    as_of: u64,
+    output: &Path,
+    rows_per_file: usize,
+) -> Result<ExportManifest, anyhow::Error> {
+    let cfg = &state_versions.cfg;
+    let versions = state_versions
+        .fetch_recent_live_diffs::<u64>(&shard_id)
+        .await;
+    let state = state_versions
+        .fetch_current_state::<u64>(&shard_id, versions.0)
+        .await;
+    let key_codec = state.key_codec.clone();
+    let val_codec = state.val_codec.clone();
+    if state.diff_codec != i64::codec_name() {
+        bail!("unsupported diff codec: {}", state.diff_codec);
+    }
+    let state = state.check_ts_codec(&shard_id)?;
+
+    if !timely::PartialOrder::less_equal(state.since(), &Antichain::from_elem(as_of)) {
+        bail!(
+            "as_of {} is not beyond the since of the shard: {:?}",
+            as_of,
+            state.since().elements()
+        );
+    }
+    if state.upper().less_equal(&as_of) {
+        bail!(
+            "as_of {} is not less than the upper of the shard: {:?}",
+            as_of,
+            state.upper().elements()
+        );
+    }
+    let Some((schema_id, schemas)) = state.collections.schemas.last_key_value() else {
+        bail!("shard {shard_id} has no registered schema");
+    };
+    let key_data_type = EncodedSchemas::decode_data_type(&schemas.key_data_type);
+    let val_data_type = EncodedSchemas::decode_data_type(&schemas.val_data_type);
+
+    let shard_metrics = state_versions.metrics.shards.shard(&shard_id, "unknown");
+    let mut parts = Vec::new();
+    for batch in state.collections.trace.batches() {
+        let mut part_stream =
+            pin!(batch.part_stream(shard_id, &*state_versions.blob, &*state_versions.metrics));
+        while let Some(part) = part_stream.try_next().await? {
+            let name = part.printable_name().to_owned();
+            info!("fetching {name}");
+            let encoded_part = EncodedPart::fetch(
+                &FetchConfig::from_persist_config(cfg),
+                &shard_id,
+                &*state_versions.blob,
+                &state_versions.metrics,
+                &shard_metrics,
+                &state_versions.metrics.read.snapshot,
+                &batch.desc,
+                &part,
+            )
+            .await
+            .map_err(|blob_key| anyhow!("missing blob: {blob_key}"))?;
+            let mut part = encoded_part
+                .updates()
+                .as_part()
+                .ok_or_else(|| anyhow!("expected structured data"))?;
+            // Parts written before the shard's schema was last evolved are
+            // migrated to the latest schema, as a reader would do.
+            if part.key.data_type() != &key_data_type {
+                let migration = backward_compatible(part.key.data_type(), &key_data_type)
+                    .ok_or_else(|| {
+                        anyhow!("keys of part {name} can't be migrated to schema {schema_id}")
+                    })?;
+                part.key = migration.migrate(part.key);
+            }
+            if part.val.data_type() != &val_data_type {
+                let migration = backward_compatible(part.val.data_type(), &val_data_type)
+                    .ok_or_else(|| {
+                        anyhow!("values of part {name} can't be migrated to schema {schema_id}")
+                    })?;
+                part.val = migration.migrate(part.val);
+            }
+            parts.push(part);
+        }
+    }
+
+    // Every update at or before the as_of advances to it, so consolidating
+    // only needs to compare keys and values.
+    let ords: Vec<_> = parts.iter().map(|part| part.as_ord()).collect();
+    let mut updates = Vec::new();
+    for (part_idx, part) in ords.iter().enumerate() {
+        for (row_idx, (k, v, t, d)) in part.iter().enumerate() {
+            if <u64 as Codec64>::decode(t) > as_of {
+                continue;
+            }
+            let d = <i64 as Codec64>::decode(d);
+            updates.push(((k, v), (part_idx, row_idx), d));
+        }
+    }
+    updates.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+    let mut indices = Vec::new();
+    let mut diffs = Vec::new();
+    for group in updates.chunk_by(|(a, _, _), (b, _, _)| a == b) {
+        let diff: i64 = group.iter().map(|(_, _, d)| d).sum();
+        if diff != 0 {
+            indices.push(group[0].1);
+            diffs.push(diff);
+        }
+    }
+    drop(updates);
+    drop(ords);
+
+    std::fs::create_dir_all(&output)?;
+    let fields = Fields::from(vec![
+        Field::new("key", key_data_type, true),
+        Field::new("val", val_data_type, true),
+        Field::new("ts", DataType::UInt64, false),
+        Field::new("diff", DataType::Int64, false),
+    ]);
+    let mut files = Vec::new();
+    for (indices, diffs) in indices
+        .chunks(rows_per_file.max(1))
+        .zip(diffs.chunks(rows_per_file.max(1)))
+    {
+        let part = Part::interleave(&parts, indices)?;
+        let ts = UInt64Array::from(vec![as_of; indices.len()]);
+        let diff = Int64Array::from(diffs.to_vec());
+        let name = format!("part-{:05}.parquet", files.len());
+        let mut file = File::create(output.join(&name))?;
+        encode_arrays(
+            &mut file,
+            fields.clone(),
+            vec![part.key, part.val, Arc::new(ts), Arc::new(diff)],
+            &EncodingConfig::default(),
+        )?;
+        files.push(name);
+    }
+
+    let manifest = ExportManifest {
+        shard_id: shard_id.to_string(),
+        as_of: as_of,
+        key_codec,
+        val_codec,
+        key_schema: hex::encode(&schemas.key),
+        val_schema: hex::encode(&schemas.val),
+        files,
+    };
+    let file = File::create(output.join(MANIFEST_FILE))?;
+    serde_json::to_writer_pretty(file, &manifest)?;
+    info!("exported {} updates", diffs.len());
+    Ok(manifest)
+}
+
+/// Runs `import`, creating a shard with the given key and value types.
+///
+/// The export must have been taken from a shard with the same codecs. Without
+/// `--commit`, this checks that the export can be imported into the shard
+/// without writing anything.
+pub async fn run_import<K, V>(args: ImportArgs) -> Result<(), anyhow::Error>
+where
+    K: Debug + Codec + Default + Clone,
+    V: Debug + Codec + Default + Clone,
+{
+    let shard_id = match &args.shard_id {
+        Some(shard_id) => shard_id.parse().map_err(|err: String| anyhow!(err))?,
+        None => ShardId::new(),
+    };
+
+    let configs = all_dyncfgs(ConfigSet::default());
+    let mut cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+    args.blob_keys.configure(&mut cfg)?;
+    let metrics_registry = MetricsRegistry::new();
+    let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+    let consensus = make_consensus(
+        &cfg,
+        &args.store.consensus_uri,
+        args.commit,
+        Arc::clone(&metrics),
+    )
+    .await?;
+    let blob = make_blob(
+        &cfg,
+        &args.store.blob_uri,
+        args.commit,
+        Arc::clone(&metrics),
+    )
+    .await?;
+
+    if !args.commit {
+        let state_versions = StateVersions::new(cfg, consensus, blob, metrics);
+        let num_updates = validate_import::<K, V>(&state_versions, shard_id, &args.input).await?;
+        info!(
+            "dry run: {num_updates} updates can be imported into shard {shard_id}, \
+            rerun with --commit to import them"
+        );
+        return Ok(());
+    }
+
+    let isolated_runtime = Arc::new(IsolatedRuntime::new(&metrics_registry, None));
+    let pubsub_sender: Arc<dyn PubSubSender> = Arc::new(NoopPubSubSender);
+    let shared_states = Arc::new(StateCache::new(
+        &cfg,
+        Arc::clone(&metrics),
+        Arc::clone(&pubsub_sender),
+    ));
+    let client = PersistClient::new(
+        cfg,
+        blob,
+        consensus,
+        metrics,
+        isolated_runtime,
+        shared_states,
+        pubsub_sender,
+    )?;
+    import::<K, V>(&client, shard_id, &args.input).await?;
+    Ok(())
+}
+
+/// Reads the manifest of the export in `input` and the key and value schemas
+/// it records.
+fn read_manifest<K: Codec, V: Codec>(
+    input: &Path,
+) -> Result<(ExportManifest, Arc<K::Schema>, Arc<V::Schema>), anyhow::Error> {
+    let file = File::open(input.join(MANIFEST_FILE))?;
+    let manifest: ExportManifest = serde_json::from_reader(file)?;
+    if manifest.key_codec != K::codec_name() || manifest.val_codec != V::codec_name() {
+        bail!(
+            "export has codecs ({}, {}) but import expects ({}, {})",
+            manifest.key_codec,
+            manifest.val_codec,
+            K::codec_name(),
+            V::codec_name()
+        );
+    }
+    let key_schema = Arc::new(K::decode_schema(&Bytes::from(hex::decode(
+        &manifest.key_schema,
+    )?)));
+    let val_schema = Arc::new(V::decode_schema(&Bytes::from(hex::decode(
+        &manifest.val_schema,
+    )?)));
+    Ok((manifest, key_schema, val_schema))
+}
+
+/// Decodes the updates in the export file `name`.
+fn read_file<K, V>(
+    input: &Path,
+    name: &str,
+    manifest: &ExportManifest,
+    key_schema: &K::Schema,
+    val_schema: &V::Schema,
+) -> Result<Vec<((K, V), u64, i64)>, anyhow::Error>
+where
+    K: Debug + Codec + Default + Clone,
+    V: Debug + Codec + Default + Clone,
+{
+    let mut updates = Vec::new();
+    let reader = decode_arrays(File::open(input.join(name))?)?;
+    for batch in reader {
+        let batch = batch?;
+        let column = |name: &str| {
+            batch
+                .column_by_name(name)
+                .cloned()
+                .ok_or_else(|| anyhow!("missing column {name}"))
+        };
+        let ts = column("ts")?;
+        let ts = ts
+            .as_primitive_opt::<UInt64Type>()
+            .ok_or_else(|| anyhow!("expected ts to be u64"))?;
+        let diff = column("diff")?;
+        let diff = diff
+            .as_primitive_opt::<Int64Type>()
+            .ok_or_else(|| anyhow!("expected diff to be i64"))?
+            .clone();
+        let part = Part {
+            key: column("key")?,
+            val: column("val")?,
+            time: Int64Array::from_iter_values(
+                ts.values().iter().map(|ts| i64::from_le_bytes(ts.encode())),
+            ),
+            diff,
+        };
+        for update in part.decode_iter::<K, V, u64, i64>(key_schema, val_schema)? {
+            if update.1 != manifest.as_of {
+                bail!(
+                    "update at {} is not at the as_of {}",
+                    update.1,
+                    manifest.as_of
+                );
+            }
+            updates.push(update);
+        }
+    }
+    Ok(updates)
+}
+
+/// Checks that the export in `input` can be imported into `shard_id`,
+/// without writing anything, and returns the number of updates it contains.
+async fn validate_import<K, V>(
+    state_versions: &StateVersions,
+    shard_id: ShardId,
+    input: &Path,
+) -> Result<usize, anyhow::Error>
+where
+    K: Debug + Codec + Default + Clone,
+    V: Debug + Codec + Default + Clone,
+{
+    let (manifest, key_schema, val_schema) = read_manifest::<K, V>(input)?;
+
+    let diffs = state_versions
+        .fetch_recent_live_diffs::<u64>(&shard_id)
+        .await;
+    if !diffs.0.is_empty() {
+        let state = state_versions
+            .fetch_current_state::<u64>(&shard_id, diffs.0)
+            .await;
+        if state.key_codec != manifest.key_codec || state.val_codec != manifest.val_codec {
+            bail!(
+                "shard {shard_id} has codecs ({}, {}) but the export has ({}, {})",
+                state.key_codec,
+                state.val_codec,
+                manifest.key_codec,
+                manifest.val_codec
+            );
+        }
+        let state = state.check_ts_codec(&shard_id)?;
+        if state.upper() != &Antichain::from_elem(Timestamp::minimum()) {
+            bail!("shard {shard_id} already contains data");
+        }
+    }
+
+    let mut num_updates = 0;
+    for name in &manifest.files {
+        info!("validating {name}");
+        let updates = read_file::<K, V>(input, name, &manifest, &key_schema, &val_schema)?;
+        num_updates += updates.len();
+    }
+    Ok(num_updates)
+}
+
+/// Imports the export in `input` into `shard_id`, which must not contain any
+/// data, and returns the number of imported updates.
+async fn import<K, V>(
+    client: &PersistClient,
+    shard_id: ShardId,
+    input: &Path,
+) -> Result<usize, anyhow::Error>
+where
+    K: Debug + Codec + Default + Clone,
+    V: Debug + Codec + Default + Clone,
+{
+    let (manifest, key_schema, val_schema) = read_manifest::<K, V>(input)?;
+    let mut write = client
+        .open_writer::<K, V, u64, i64>(
+            shard_id,
+            Arc::clone(&key_schema),
+            Arc::clone(&val_schema),
+            Diagnostics::from_purpose("persistcli import"),
+        )
+        .await?;
+    let lower = Antichain::from_elem(Timestamp::minimum());
+    if write.shared_upper() != lower {
+        bail!("shard {shard_id} already contains data");
+    }
+
+    let mut builder = write.builder(lower.clone());
+    let mut num_updates = 0;
+    for name in &manifest.files {
+        info!("importing {name}");
+        let updates = read_file::<K, V>(input, name, &manifest, &key_schema, &val_schema)?;
+        for ((k, v), t, d) in updates {
+            builder.add(&k, &v, &t, &d).await?;
+            num_updates += 1;
+        }
+    }
+
+    let upper = Antichain::from_elem(manifest.as_of + 1);
+    let mut batch = builder.finish(upper.clone()).await?;
+    write
+        .compare_and_append_batch(&mut [&mut batch], lower, upper, true)
+        .await?
+        .map_err(|mismatch| anyhow!("concurrent write to shard {shard_id}: {mismatch:?}"))?;
+    info!(
+        "imported {num_updates} updates from shard {} into shard {shard_id}",
+        manifest.shard_id
+    );
+    Ok(num_updates)
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_dyncfg::ConfigUpdates;
+    use mz_persist_types::codec_impls::UnitSchema;
+    use mz_persist_types::schema::SchemaId;
+
+    use crate::schema::CaESchema;
+    use crate::schema::tests::{Strings, StringsSchema, strings};
+    use crate::tests::{all_ok, new_test_client};
+
+    use super::*;
+
+    fn state_versions(client: &PersistClient) -> StateVersions {
+        StateVersions::new(
+            client.cfg.clone(),
+            Arc::clone(&client.consensus),
+            Arc::clone(&client.blob),
+            Arc::clone(&client.metrics),
+        )
+    }
+
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn export_import_round_trip(dyncfgs: ConfigUpdates) {
+        let data = vec![
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("1".to_owned(), "one".to_owned()), 3, -1),
+            (("3".to_owned(), "three".to_owned()), 3, 2),
+            (("4".to_owned(), "four".to_owned()), 5, 1),
+        ];
+        let client = new_test_client(&dyncfgs).await;
+        let shard_id = ShardId::new();
+        let (mut write, _read) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_compare_and_append(&data[..2], 0, 3).await;
+        write.expect_compare_and_append(&data[2..], 3, 6).await;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let versions = state_versions(&client);
+        let manifest = export(&versions, shard_id, 4, dir.path(), 1)
+            .await
+            .expect("export succeeds");
+        // The retracted update is consolidated away.
+        assert_eq!(manifest.files.len(), 2);
+
+        // A dry run validates the export without writing anything.
+        let imported = ShardId::new();
+        let num_updates = validate_import::<String, String>(&versions, imported, dir.path())
+            .await
+            .expect("export is valid");
+        assert_eq!(num_updates, 2);
+        let res = validate_import::<String, String>(&versions, shard_id, dir.path()).await;
+        assert!(res.is_err());
+        let res = validate_import::<String, ()>(&versions, imported, dir.path()).await;
+        assert!(res.is_err());
+        assert!(
+            versions
+                .fetch_recent_live_diffs::<u64>(&imported)
+                .await
+                .0
+                .is_empty()
+        );
+
+        let num_updates = import::<String, String>(&client, imported, dir.path())
+            .await
+            .expect("import succeeds");
+        assert_eq!(num_updates, 2);
+        let (_write, mut read) = client
+            .expect_open::<String, String, u64, i64>(imported)
+            .await;
+        assert_eq!(
+            read.expect_snapshot_and_fetch(4).await,
+            all_ok(&data[..4], 4)
+        );
+
+        // Importing into a shard that has data is refused, with or without a
+        // dry run.
+        let res = validate_import::<String, String>(&versions, imported, dir.path()).await;
+        assert!(res.is_err());
+        let res = import::<String, String>(&client, imported, dir.path()).await;
+        assert!(res.is_err());
+    }
+
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn export_migrates_schema(dyncfgs: ConfigUpdates) {
+        let client = new_test_client(&dyncfgs).await;
+        let d = Diagnostics::for_tests();
+        let shard_id = ShardId::new();
+        let schema0 = StringsSchema(vec![false]);
+        let schema1 = StringsSchema(vec![false, true]);
+
+        // Write some data at the original schema, then some at an evolved one.
+        let (mut write0, _read) = client
+            .open::<Strings, (), u64, i64>(
+                shard_id,
+                Arc::new(schema0),
+                Arc::new(UnitSchema),
+                d.clone(),
+                true,
+            )
+            .await
+            .expect("invalid persist usage");
+        write0
+            .expect_compare_and_append(&[((Strings(vec!["old".into()]), ()), 0, 1)], 0, 1)
+            .await;
+        let res = client
+            .compare_and_evolve_schema::<Strings, (), u64, i64>(
+                shard_id,
+                SchemaId(0),
+                &schema1,
+                &UnitSchema,
+                d.clone(),
+            )
+            .await
+            .expect("invalid persist usage");
+        assert_eq!(res, CaESchema::Ok(SchemaId(1)));
+        let (mut write1, _read) = client
+            .open::<Strings, (), u64, i64>(
+                shard_id,
+                Arc::new(schema1.clone()),
+                Arc::new(UnitSchema),
+                d.clone(),
+                true,
+            )
+            .await
+            .expect("invalid persist usage");
+        write1
+            .expect_compare_and_append(
+                &[((Strings(vec!["new".into(), "x".into()]), ()), 1, 1)],
+                1,
+                2,
+            )
+            .await;
+
+        // The export uses the latest schema, filling in nulls for the old
+        // data.
+        let dir = tempfile::tempdir().expect("tempdir");
+        export(&state_versions(&client), shard_id, 1, dir.path(), 100)
+            .await
+            .expect("export succeeds");
+        let imported = ShardId::new();
+        import::<Strings, ()>(&client, imported, dir.path())
+            .await
+            .expect("import succeeds");
+        let (_write, mut read) = client
+            .open::<Strings, (), u64, i64>(
+                imported,
+                Arc::new(schema1),
+                Arc::new(UnitSchema),
+                d,
+                true,
+            )
+            .await
+            .expect("invalid persist usage");
+        let mut snapshot = read.expect_snapshot_and_fetch(1).await;
+        snapshot.sort();
+        assert_eq!(strings(&snapshot), vec![vec!["new", "x"], vec!["old", ""]]);
+    }
+}
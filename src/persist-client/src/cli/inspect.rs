@@ -33,7 +33,9 @@ use serde_json::json;
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::cache::StateCache;
-use crate::cli::args::{NO_COMMIT, READ_ALL_BUILD_INFO, StateArgs, make_blob, make_consensus};
+use crate::cli::args::{
+    BlobKeyArgs, NO_COMMIT, READ_ALL_BUILD_INFO, StateArgs, make_blob, make_consensus,
+};
 use crate::error::CodecConcreteType;
 use crate::fetch::{EncodedPart, FetchConfig};
 use crate::internal::encoding::{Rollup, UntypedState};
@@ -146,12 +148,19 @@ pub async fn run(command: InspectArgs) -> Result<(), anyhow::Error> {
             }
         }
         Command::BlobCount(args) => {
-            let blob_counts = blob_counts(&args.blob_uri).await?;
+            let blob_counts = blob_counts(&args.blob_uri, &args.blob_keys).await?;
             println!("{}", json!(blob_counts));
         }
         Command::BlobBatchPart(args) => {
             let shard_id = ShardId::from_str(&args.shard_id).expect("invalid shard id");
-            let updates = blob_batch_part(&args.blob_uri, shard_id, args.key, args.limit).await?;
+            let updates = blob_batch_part(
+                &args.blob_uri,
+                &args.blob_keys,
+                shard_id,
+                args.key,
+                args.limit,
+            )
+            .await?;
             println!("{}", json!(updates));
         }
         Command::ConsolidatedSize(args) => {
@@ -165,7 +174,7 @@ pub async fn run(command: InspectArgs) -> Result<(), anyhow::Error> {
             let () = blob_usage(&args).await?;
         }
         Command::ShardStats(args) => {
-            shard_stats(&args.blob_uri).await?;
+            shard_stats(&args.blob_uri, &args.blob_keys).await?;
         }
     }
 
@@ -297,6 +306,9 @@ pub struct BlobBatchPartArgs {
     #[clap(long)]
     blob_uri: SensitiveUrl,
 
+    #[clap(flatten)]
+    blob_keys: BlobKeyArgs,
+
     /// Number of updates to output. Default is unbounded.
     #[clap(long, default_value = "18446744073709551615")]
     limit: usize,
@@ -319,11 +331,13 @@ struct BatchPartUpdate {
 /// Fetches the updates in a blob batch part
 pub async fn blob_batch_part(
     blob_uri: &SensitiveUrl,
+    blob_keys: &BlobKeyArgs,
     shard_id: ShardId,
     partial_key: String,
     limit: usize,
 ) -> Result<impl serde::Serialize, anyhow::Error> {
-    let cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+    let mut cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+    blob_keys.configure(&mut cfg)?;
     let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
     let blob = make_blob(&cfg, blob_uri, NO_COMMIT, Arc::clone(&metrics)).await?;
 
@@ -445,6 +459,9 @@ pub struct BlobArgs {
     /// URI scoped to the environment's bucket prefix.
     #[clap(long)]
     blob_uri: SensitiveUrl,
+
+    #[clap(flatten)]
+    blob_keys: BlobKeyArgs,
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -456,8 +473,12 @@ struct BlobCounts {
 }
 
 /// Fetches the blob count for given path
-pub async fn blob_counts(blob_uri: &SensitiveUrl) -> Result<impl serde::Serialize, anyhow::Error> {
-    let cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+pub async fn blob_counts(
+    blob_uri: &SensitiveUrl,
+    blob_keys: &BlobKeyArgs,
+) -> Result<impl serde::Serialize, anyhow::Error> {
+    let mut cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+    blob_keys.configure(&mut cfg)?;
     let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
     let blob = make_blob(&cfg, blob_uri, NO_COMMIT, metrics).await?;
 
@@ -486,8 +507,9 @@ pub async fn blob_counts(blob_uri: &SensitiveUrl) -> Result<impl serde::Serializ
 }
 
 /// Rummages through S3 to find the latest rollup for each shard, then calculates summary stats.
-pub async fn shard_stats(blob_uri: &SensitiveUrl) -> anyhow::Result<()> {
-    let cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+pub async fn shard_stats(blob_uri: &SensitiveUrl, blob_keys: &BlobKeyArgs) -> anyhow::Result<()> {
+    let mut cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+    blob_keys.configure(&mut cfg)?;
     let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
     let blob = make_blob(&cfg, blob_uri, NO_COMMIT, metrics).await?;
 
@@ -645,7 +667,8 @@ pub async fn blob_usage(args: &StateArgs) -> Result<(), anyhow::Error> {
     } else {
         Some(args.shard_id())
     };
-    let cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+    let mut cfg = PersistConfig::new_default_configs(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+    args.blob_keys.configure(&mut cfg)?;
     let metrics_registry = MetricsRegistry::new();
     let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
     let consensus =
@@ -668,8 +691,12 @@ pub async fn blob_usage(args: &StateArgs) -> Result<(), anyhow::Error> {
     )?);
 
     if let Some(shard_id) = shard_id {
-        let usage = usage.shard_usage_audit(shard_id).await;
-        println!("{}\n{}", shard_id, usage);
+        let audit = usage.shard_usage_audit(shard_id).await;
+        println!("{}\n{}", shard_id, audit);
+        let referenced = usage.shard_usage_referenced(shard_id).await;
+        for (compression, bytes) in referenced.current_state_batches_bytes_by_compression() {
+            println!("          {}: {}", compression, HumanBytes(*bytes));
+        }
     } else {
         let usage = usage.shards_usage_audit().await;
         let mut by_shard = usage.by_shard.iter().collect::<Vec<_>>();
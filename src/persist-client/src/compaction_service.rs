@@ -0,0 +1,746 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! gRPC-based implementations of a Persist compaction service client and
+//! server.
+//!
+//! By default, compaction runs inline in whichever process enqueued the
+//! [CompactReq], competing with it for CPU and memory. When a
+//! [CompactionExecutor] is configured in [PersistConfig::compaction_executor]
+//! and [COMPACTION_SERVICE_ENABLED] is set, the expensive part of compaction
+//! (fetching, consolidating, and writing out parts) is instead handed to the
+//! executor, usually a pool of [PersistCompactionServer]s reached through a
+//! [GrpcCompactionClient]. The requesting process still applies the results to
+//! state itself with [Machine::merge_res](crate::internal::machine::Machine),
+//! so the server only needs access to [Blob], not to [Consensus].
+//!
+//! Requests carry the dynamic configs of the requesting process, which the
+//! server compacts with, so the output is the same as if compaction had run
+//! inline. Requests over gRPC must carry the token the server was started
+//! with.
+//!
+//! If the executor can't be reached or refuses the request (e.g. because it
+//! runs a different version or doesn't know the shard's codecs), the request
+//! falls back to inline compaction. So does a request that fails partway
+//! through.
+//!
+//! [Consensus]: mz_persist::location::Consensus
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use differential_dataflow::difference::Monoid;
+use differential_dataflow::lattice::Lattice;
+use futures::Stream;
+use futures::stream::BoxStream;
+use futures_util::StreamExt;
+use mz_dyncfg::{Config, ConfigSet, ConfigUpdates};
+use mz_ore::error::ErrorExt;
+use mz_persist::location::Blob;
+use mz_persist_types::parquet::CompressionFormat;
+use mz_persist_types::schema::SchemaId;
+use mz_persist_types::{Codec, Codec64};
+use mz_proto::{IntoRustIfSome, ProtoMapEntry, ProtoType, RustType, TryFromProtoError};
+use sha2::{Digest, Sha256};
+use timely::progress::Timestamp;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::metadata::MetadataValue;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Request, Response, Status};
+use tracing::{debug, info, warn};
+
+use crate::ShardId;
+use crate::async_runtime::IsolatedRuntime;
+use crate::cfg::{PersistConfig, all_dyncfgs};
+use crate::internal::compact::{CompactConfig, CompactReq, Compactor};
+use crate::internal::encoding::Schemas;
+use crate::internal::service::proto_persist_compaction_client::ProtoPersistCompactionClient;
+use crate::internal::service::proto_persist_compaction_server::{
+    ProtoPersistCompaction, ProtoPersistCompactionServer,
+};
+use crate::internal::service::{
+    ProtoCompactRequest, ProtoCompactResponse, ProtoCompactionInput, ProtoCompactionPartialBatch,
+    proto_compaction_input,
+};
+use crate::internal::state::HollowBatch;
+use crate::internal::trace::{CompactionInput, FueledMergeRes, IdHollowBatch, SpineId};
+use crate::metrics::Metrics;
+use crate::rpc::MAX_GRPC_MESSAGE_SIZE;
+
+/// Whether to hand compaction requests to the configured
+/// [PersistConfig::compaction_executor] instead of running them inline.
+pub const COMPACTION_SERVICE_ENABLED: Config<bool> = Config::new(
+    "persist_compaction_service_enabled",
+    false,
+    "Whether to run compaction on the configured compaction service instead of inline.",
+);
+
+/// Timeout per connection attempt to the compaction service.
+pub(crate) const COMPACTION_SERVICE_CONNECT_TIMEOUT: Config<Duration> = Config::new(
+    "persist_compaction_service_connect_timeout",
+    Duration::from_secs(5),
+    "Timeout per connection attempt to the Persist compaction service.",
+);
+
+/// The metadata key of the token that authenticates requests to a
+/// [PersistCompactionServer].
+const AUTH_METADATA_KEY: &str = "authorization";
+
+/// Runs [CompactReq]s on behalf of the process that enqueued them.
+///
+/// The request and response are in their protobuf form so that
+/// implementations don't need to know the shard's key, value, timestamp, and
+/// diff types.
+#[async_trait]
+pub trait CompactionExecutor: Debug + Send + Sync {
+    /// Starts compacting `req`, returning a stream of the merge results in the
+    /// order they should be applied.
+    ///
+    /// An error means the request was not started and the caller should
+    /// compact inline instead. Errors in the returned stream mean compaction
+    /// failed partway through.
+    async fn compact(
+        &self,
+        req: ProtoCompactRequest,
+    ) -> Result<BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>, anyhow::Error>;
+}
+
+/// Hands `req` to the configured [CompactionExecutor], if any.
+///
+/// Returns `None` if compaction should happen inline instead, either because
+/// the service is disabled or because the executor didn't accept the request.
+pub(crate) async fn compact_remote<K, V, T, D>(
+    cfg: &PersistConfig,
+    metrics: &Metrics,
+    req: &CompactReq<T>,
+    schemas: &Schemas<K, V>,
+    compression: Option<CompressionFormat>,
+    incremental: bool,
+) -> Option<BoxStream<'static, Result<FueledMergeRes<T>, anyhow::Error>>>
+where
+    K: Codec,
+    V: Codec,
+    T: Timestamp + Codec64,
+    D: Codec64,
+{
+    if !COMPACTION_SERVICE_ENABLED.get(cfg) {
+        return None;
+    }
+    let executor = cfg.compaction_executor.as_ref()?;
+
+    let mut configs = ConfigUpdates::default();
+    for entry in cfg.configs.entries() {
+        configs.add_dynamic(entry.name(), entry.val());
+    }
+    let proto = ProtoCompactRequest {
+        shard_id: req.shard_id.into_proto(),
+        version: cfg.build_version.to_string(),
+        key_codec: K::codec_name(),
+        val_codec: V::codec_name(),
+        ts_codec: T::codec_name(),
+        diff_codec: D::codec_name(),
+        desc: Some(req.desc.into_proto()),
+        inputs: req
+            .inputs
+            .iter()
+            .map(|input| ProtoMapEntry::from_rust((&input.id, &input.batch)))
+            .collect(),
+        schema_id: schemas.id.into_proto(),
+        key_schema: K::encode_schema(&schemas.key),
+        val_schema: V::encode_schema(&schemas.val),
+        compression: compression.into_proto(),
+        incremental,
+        configs: serde_json::to_string(&configs).expect("configs are serializable"),
+    };
+    match executor.compact(proto).await {
+        Ok(stream) => {
+            metrics.compaction.remote_started.inc();
+            let stream = stream.map(|res| {
+                let res = res?;
+                let res: FueledMergeRes<T> = res.into_rust()?;
+                Ok::<_, anyhow::Error>(res)
+            });
+            Some(stream.boxed())
+        }
+        Err(err) => {
+            metrics.compaction.remote_fallback.inc();
+            warn!(
+                shard_id = %req.shard_id,
+                "compaction service unavailable, compacting inline: {}",
+                err.display_with_causes()
+            );
+            None
+        }
+    }
+}
+
+/// A [CompactionExecutor] that sends requests to a [PersistCompactionServer]
+/// over gRPC.
+#[derive(Debug)]
+pub struct GrpcCompactionClient {
+    client: ProtoPersistCompactionClient<Channel>,
+    auth: MetadataValue<tonic::metadata::Ascii>,
+}
+
+impl GrpcCompactionClient {
+    /// Returns a client for the compaction service at `url`, which was
+    /// started with `auth_token`.
+    ///
+    /// The connection is established lazily, so this succeeds even if the
+    /// service is not (yet) reachable.
+    pub fn new(url: &str, auth_token: &str, cfg: &PersistConfig) -> Result<Self, anyhow::Error> {
+        let endpoint =
+            Endpoint::from_str(url)?.connect_timeout(COMPACTION_SERVICE_CONNECT_TIMEOUT.get(cfg));
+        let client = ProtoPersistCompactionClient::new(endpoint.connect_lazy())
+            .max_decoding_message_size(MAX_GRPC_MESSAGE_SIZE);
+        let auth = format!("Bearer {auth_token}").parse()?;
+        Ok(GrpcCompactionClient { client, auth })
+    }
+}
+
+#[async_trait]
+impl CompactionExecutor for GrpcCompactionClient {
+    async fn compact(
+        &self,
+        req: ProtoCompactRequest,
+    ) -> Result<BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>, anyhow::Error>
+    {
+        let mut client = self.client.clone();
+        let mut req = Request::new(req);
+        req.metadata_mut()
+            .insert(AUTH_METADATA_KEY, self.auth.clone());
+        let response = client.compact(req).await?;
+        let stream = response
+            .into_inner()
+            .map(|res| res.map_err(anyhow::Error::from));
+        Ok(stream.boxed())
+    }
+}
+
+/// The resources a [PersistCompactionServer] compacts with.
+#[derive(Debug, Clone)]
+struct CompactionContext {
+    cfg: PersistConfig,
+    metrics: Arc<Metrics>,
+    blob: Arc<dyn Blob>,
+    isolated_runtime: Arc<IsolatedRuntime>,
+}
+
+/// The names of the codecs of a shard.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct CompactionCodecs {
+    key: String,
+    val: String,
+    ts: String,
+    diff: String,
+}
+
+type CompactFn = fn(
+    CompactionContext,
+    ProtoCompactRequest,
+) -> BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>;
+
+/// A gRPC server that runs [CompactReq]s for other processes.
+///
+/// The server compacts shards of any combination of codecs registered with
+/// [Self::register] and refuses the rest.
+#[derive(Debug, Clone)]
+pub struct PersistCompactionServer {
+    ctx: CompactionContext,
+    codecs: BTreeMap<CompactionCodecs, CompactFn>,
+    /// The digest of the token that gRPC requests must carry, set by
+    /// [Self::serve].
+    auth_digest: Option<[u8; 32]>,
+}
+
+impl PersistCompactionServer {
+    /// Creates a new [PersistCompactionServer] that reads and writes parts in
+    /// `blob`.
+    pub fn new(
+        cfg: &PersistConfig,
+        metrics: Arc<Metrics>,
+        blob: Arc<dyn Blob>,
+        isolated_runtime: Arc<IsolatedRuntime>,
+    ) -> Self {
+        PersistCompactionServer {
+            ctx: CompactionContext {
+                cfg: cfg.clone(),
+                metrics,
+                blob,
+                isolated_runtime,
+            },
+            codecs: BTreeMap::new(),
+            auth_digest: None,
+        }
+    }
+
+    /// Allows this server to compact shards with the given codecs.
+    pub fn register<K, V, T, D>(&mut self)
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64 + Sync,
+        D: Monoid + Ord + Codec64 + Send + Sync,
+    {
+        let codecs = CompactionCodecs {
+            key: K::codec_name(),
+            val: V::codec_name(),
+            ts: T::codec_name(),
+            diff: D::codec_name(),
+        };
+        self.codecs.insert(codecs, compact_typed::<K, V, T, D>);
+    }
+
+    /// Starts the gRPC server, serving only requests that carry `auth_token`.
+    /// Consumes `self` and runs until the task is cancelled.
+    pub async fn serve(
+        mut self,
+        listen_addr: SocketAddr,
+        auth_token: String,
+    ) -> Result<(), anyhow::Error> {
+        self.auth_digest = Some(Sha256::digest(auth_token.as_bytes()).into());
+        info!("persist compaction service listening on {}", listen_addr);
+        tonic::transport::Server::builder()
+            .add_service(
+                ProtoPersistCompactionServer::new(self)
+                    .max_decoding_message_size(MAX_GRPC_MESSAGE_SIZE),
+            )
+            .serve(listen_addr)
+            .await?;
+        Ok(())
+    }
+
+    /// Checks that `request` carries the token the server was started with.
+    fn authenticate<R>(&self, request: &Request<R>) -> Result<(), Status> {
+        let Some(expected) = &self.auth_digest else {
+            return Ok(());
+        };
+        let token = request
+            .metadata()
+            .get(AUTH_METADATA_KEY)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        // Comparing digests, rather than the tokens themselves, doesn't leak
+        // how much of the token matched through timing.
+        match token {
+            Some(token) if Sha256::digest(token.as_bytes()).as_slice() == expected => Ok(()),
+            _ => Err(Status::unauthenticated(
+                "missing or invalid compaction service token",
+            )),
+        }
+    }
+
+    /// Validates `req` and starts compacting it on the isolated runtime.
+    fn start(
+        &self,
+        req: ProtoCompactRequest,
+    ) -> Result<BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>, anyhow::Error>
+    {
+        // Blob keys and the encoding of parts may change between versions, so
+        // only compact on behalf of processes of the same version.
+        let version = self.ctx.cfg.build_version.to_string();
+        if req.version != version {
+            return Err(anyhow!(
+                "compaction service is running version {} but request is from version {}",
+                version,
+                req.version
+            ));
+        }
+        let codecs = CompactionCodecs {
+            key: req.key_codec.clone(),
+            val: req.val_codec.clone(),
+            ts: req.ts_codec.clone(),
+            diff: req.diff_codec.clone(),
+        };
+        let Some(compact_fn) = self.codecs.get(&codecs) else {
+            return Err(anyhow!("unsupported codecs: {:?}", codecs));
+        };
+        debug!(shard_id = %req.shard_id, "starting compaction");
+
+        // Compaction is cpu intensive, so run it on the isolated runtime and
+        // forward the results.
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        let stream = compact_fn(self.ctx.clone(), req);
+        let _handle = self.ctx.isolated_runtime.spawn_named(
+            || "persist::compaction_service::compact",
+            async move {
+                futures::pin_mut!(stream);
+                while let Some(res) = stream.next().await {
+                    if tx.send(res).await.is_err() {
+                        // The requester went away, no need to continue.
+                        break;
+                    }
+                }
+            },
+        );
+        Ok(ReceiverStream::new(rx).boxed())
+    }
+}
+
+#[async_trait]
+impl ProtoPersistCompaction for PersistCompactionServer {
+    type CompactStream = Pin<Box<dyn Stream<Item = Result<ProtoCompactResponse, Status>> + Send>>;
+
+    #[mz_ore::instrument(name = "persist::compaction_service::server", level = "debug")]
+    async fn compact(
+        &self,
+        request: Request<ProtoCompactRequest>,
+    ) -> Result<Response<Self::CompactStream>, Status> {
+        self.authenticate(&request)?;
+        let stream = self
+            .start(request.into_inner())
+            .map_err(|err| Status::failed_precondition(err.display_with_causes().to_string()))?;
+        let stream = stream
+            .map(|res| res.map_err(|err| Status::internal(err.display_with_causes().to_string())));
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// Serving requests in the same process skips the network and lets tests
+/// exercise the server without one.
+#[async_trait]
+impl CompactionExecutor for PersistCompactionServer {
+    async fn compact(
+        &self,
+        req: ProtoCompactRequest,
+    ) -> Result<BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>, anyhow::Error>
+    {
+        self.start(req)
+    }
+}
+
+fn compact_typed<K, V, T, D>(
+    ctx: CompactionContext,
+    req: ProtoCompactRequest,
+) -> BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>
+where
+    K: Debug + Codec,
+    V: Debug + Codec,
+    T: Timestamp + Lattice + Codec64 + Sync,
+    D: Monoid + Ord + Codec64 + Send + Sync,
+{
+    let stream = async_stream::try_stream! {
+        let shard_id: ShardId = req.shard_id.into_rust()?;
+        let desc = req.desc.into_rust_if_some("ProtoCompactRequest::desc")?;
+        let inputs = req
+            .inputs
+            .into_iter()
+            .map(|input| {
+                let (id, batch) =
+                    ProtoMapEntry::<SpineId, Arc<HollowBatch<T>>>::into_rust(input)?;
+                Ok::<_, TryFromProtoError>(IdHollowBatch { id, batch })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let schema_id: Option<SchemaId> = req.schema_id.into_rust()?;
+        let schemas = Schemas::<K, V> {
+            id: schema_id,
+            key: Arc::new(K::decode_schema(&req.key_schema)),
+            val: Arc::new(V::decode_schema(&req.val_schema)),
+        };
+        let compression: Option<CompressionFormat> = req.compression.into_rust()?;
+        // Compact with the requester's configs. The version check means the
+        // two processes know the same configs.
+        let configs: ConfigUpdates = serde_json::from_str(&req.configs)?;
+        let mut cfg = ctx.cfg.clone();
+        cfg.configs = Arc::new(all_dyncfgs(ConfigSet::default()));
+        configs.apply(&cfg.configs);
+        let compact_req = CompactReq {
+            shard_id,
+            desc,
+            inputs,
+        };
+
+        let mut compact_cfg = CompactConfig::new(&cfg, shard_id);
+        compact_cfg.batch.apply_compression_policy(compression);
+        let stream = Compactor::<K, V, T, D>::compact_stream(
+            compact_cfg,
+            Arc::clone(&ctx.blob),
+            Arc::clone(&ctx.metrics),
+            ctx.metrics.shards.shard(&shard_id, "compaction_service"),
+            Arc::clone(&ctx.isolated_runtime),
+            compact_req,
+            schemas,
+            req.incremental,
+        );
+        for await res in stream {
+            let res = res?;
+            yield res.into_proto();
+        }
+    };
+    stream.boxed()
+}
+
+impl<T: Timestamp + Codec64> RustType<ProtoCompactResponse> for FueledMergeRes<T> {
+    fn into_proto(&self) -> ProtoCompactResponse {
+        ProtoCompactResponse {
+            output: Some(self.output.into_proto()),
+            input: Some(self.input.into_proto()),
+            new_active_compaction: self.new_active_compaction.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoCompactResponse) -> Result<Self, TryFromProtoError> {
+        Ok(FueledMergeRes {
+            output: proto
+                .output
+                .into_rust_if_some("ProtoCompactResponse::output")?,
+            input: proto
+                .input
+                .into_rust_if_some("ProtoCompactResponse::input")?,
+            new_active_compaction: proto.new_active_compaction.into_rust()?,
+        })
+    }
+}
+
+impl RustType<ProtoCompactionInput> for CompactionInput {
+    fn into_proto(&self) -> ProtoCompactionInput {
+        use proto_compaction_input::Kind;
+        let kind = match self {
+            CompactionInput::Legacy => Kind::Legacy(()),
+            CompactionInput::IdRange(id) => Kind::IdRange(id.into_proto()),
+            CompactionInput::PartialBatch(id, run_ids) => {
+                Kind::PartialBatch(ProtoCompactionPartialBatch {
+                    id: Some(id.into_proto()),
+                    run_ids: run_ids.iter().map(|x| x.into_proto()).collect(),
+                })
+            }
+        };
+        ProtoCompactionInput { kind: Some(kind) }
+    }
+
+    fn from_proto(proto: ProtoCompactionInput) -> Result<Self, TryFromProtoError> {
+        use proto_compaction_input::Kind;
+        match proto.kind {
+            Some(Kind::Legacy(())) => Ok(CompactionInput::Legacy),
+            Some(Kind::IdRange(id)) => Ok(CompactionInput::IdRange(id.into_rust()?)),
+            Some(Kind::PartialBatch(batch)) => Ok(CompactionInput::PartialBatch(
+                batch
+                    .id
+                    .into_rust_if_some("ProtoCompactionPartialBatch::id")?,
+                batch
+                    .run_ids
+                    .into_iter()
+                    .map(|x| x.into_rust())
+                    .collect::<Result<_, _>>()?,
+            )),
+            None => Err(TryFromProtoError::missing_field(
+                "ProtoCompactionInput::kind",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use differential_dataflow::trace::Description;
+    use mz_dyncfg::{ConfigUpdates, ConfigVal};
+    use timely::progress::Antichain;
+
+    use crate::PersistLocation;
+    use crate::batch::BLOB_TARGET_SIZE;
+    use crate::tests::{all_ok, new_test_client_cache};
+
+    use super::*;
+
+    async fn compact_with_executor(
+        dyncfgs: ConfigUpdates,
+        executor: impl FnOnce(&crate::PersistClient) -> Arc<dyn CompactionExecutor>,
+    ) -> Arc<Metrics> {
+        let data = [
+            (("0".to_owned(), "zero".to_owned()), 0, 1),
+            (("0".to_owned(), "zero".to_owned()), 1, -1),
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+        ];
+
+        let mut cache = new_test_client_cache(&dyncfgs);
+        cache.cfg.set_config(&BLOB_TARGET_SIZE, 100);
+        cache.cfg.set_config(&COMPACTION_SERVICE_ENABLED, true);
+        // Clients opened from the same cache share the in-memory blob, so the
+        // executor can read and write the parts of the second client.
+        let client = cache
+            .open(PersistLocation::new_in_mem())
+            .await
+            .expect("client construction failed");
+        cache.cfg.compaction_executor = Some(executor(&client));
+        let (mut write, mut read) = cache
+            .open(PersistLocation::new_in_mem())
+            .await
+            .expect("client construction failed")
+            .expect_open::<String, String, u64, i64>(ShardId::new())
+            .await;
+
+        write.expect_compare_and_append(&data[..1], 0, 1).await;
+        write.expect_compare_and_append(&data[1..], 1, 2).await;
+
+        let batches = write.machine.applier.all_batches();
+        let req = CompactReq {
+            shard_id: write.machine.shard_id(),
+            desc: Description::new(
+                Antichain::from_elem(0),
+                Antichain::from_elem(2),
+                Antichain::from_elem(0),
+            ),
+            inputs: batches
+                .into_iter()
+                .enumerate()
+                .map(|(idx, batch)| IdHollowBatch {
+                    id: SpineId(idx, idx + 1),
+                    batch: Arc::new(batch),
+                })
+                .collect(),
+        };
+        Compactor::<String, String, u64, i64>::compact_and_apply(&write.machine, req)
+            .await
+            .expect("compaction success");
+
+        assert_eq!(read.expect_snapshot_and_fetch(1).await, all_ok(&data, 1));
+        Arc::clone(&write.metrics)
+    }
+
+    #[mz_persist_proc::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn compaction_service_same_process(dyncfgs: ConfigUpdates) {
+        let metrics = compact_with_executor(dyncfgs, |client| {
+            let mut server = PersistCompactionServer::new(
+                &client.cfg,
+                Arc::clone(&client.metrics),
+                Arc::clone(&client.blob),
+                Arc::clone(&client.isolated_runtime),
+            );
+            server.register::<String, String, u64, i64>();
+            Arc::new(server)
+        })
+        .await;
+        assert_eq!(metrics.compaction.remote_started.get(), 1);
+        assert_eq!(metrics.compaction.remote_fallback.get(), 0);
+    }
+
+    #[mz_persist_proc::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn compaction_service_unsupported_codecs(dyncfgs: ConfigUpdates) {
+        let metrics = compact_with_executor(dyncfgs, |client| {
+            // Nothing registered, so every request falls back to inline.
+            Arc::new(PersistCompactionServer::new(
+                &client.cfg,
+                Arc::clone(&client.metrics),
+                Arc::clone(&client.blob),
+                Arc::clone(&client.isolated_runtime),
+            ))
+        })
+        .await;
+        assert_eq!(metrics.compaction.remote_started.get(), 0);
+        assert_eq!(metrics.compaction.remote_fallback.get(), 1);
+    }
+
+    #[mz_persist_proc::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn compaction_service_unreachable(dyncfgs: ConfigUpdates) {
+        let metrics = compact_with_executor(dyncfgs, |client| {
+            // Nothing listens on the discard port.
+            let client = GrpcCompactionClient::new("http://127.0.0.1:9", "token", &client.cfg)
+                .expect("valid url");
+            Arc::new(client)
+        })
+        .await;
+        assert_eq!(metrics.compaction.remote_started.get(), 0);
+        assert_eq!(metrics.compaction.remote_fallback.get(), 1);
+    }
+
+    /// An executor that accepts every request, but fails before producing any
+    /// results.
+    #[derive(Debug)]
+    struct FailingExecutor;
+
+    #[async_trait]
+    impl CompactionExecutor for FailingExecutor {
+        async fn compact(
+            &self,
+            _req: ProtoCompactRequest,
+        ) -> Result<BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>, anyhow::Error>
+        {
+            let stream = futures::stream::once(async { Err(anyhow!("connection reset")) });
+            Ok(stream.boxed())
+        }
+    }
+
+    #[mz_persist_proc::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn compaction_service_fails_partway(dyncfgs: ConfigUpdates) {
+        let metrics = compact_with_executor(dyncfgs, |_| Arc::new(FailingExecutor)).await;
+        assert_eq!(metrics.compaction.remote_started.get(), 1);
+        assert_eq!(metrics.compaction.remote_failed.get(), 1);
+        assert_eq!(metrics.compaction.failed.get(), 0);
+    }
+
+    /// An executor that records the requests it is sent and refuses them.
+    #[derive(Debug, Default)]
+    struct RecordingExecutor(Mutex<Vec<ProtoCompactRequest>>);
+
+    #[async_trait]
+    impl CompactionExecutor for RecordingExecutor {
+        async fn compact(
+            &self,
+            req: ProtoCompactRequest,
+        ) -> Result<BoxStream<'static, Result<ProtoCompactResponse, anyhow::Error>>, anyhow::Error>
+        {
+            self.0.lock().expect("lock poisoned").push(req);
+            Err(anyhow!("not serving"))
+        }
+    }
+
+    #[mz_persist_proc::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn compaction_service_sends_configs(dyncfgs: ConfigUpdates) {
+        let executor = Arc::new(RecordingExecutor::default());
+        let recorded = Arc::clone(&executor);
+        compact_with_executor(dyncfgs, move |_| executor).await;
+        let reqs = recorded.0.lock().expect("lock poisoned");
+        assert_eq!(reqs.len(), 1);
+        let configs: ConfigUpdates = serde_json::from_str(&reqs[0].configs).expect("valid configs");
+        assert_eq!(
+            configs.updates.get(BLOB_TARGET_SIZE.name()),
+            Some(&ConfigVal::Usize(100))
+        );
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn compaction_service_auth() {
+        let client = crate::tests::new_test_client(&ConfigUpdates::default()).await;
+        let mut server = PersistCompactionServer::new(
+            &client.cfg,
+            Arc::clone(&client.metrics),
+            Arc::clone(&client.blob),
+            Arc::clone(&client.isolated_runtime),
+        );
+        server.auth_digest = Some(Sha256::digest(b"secret").into());
+
+        let request = |token: Option<&str>| {
+            let mut request = Request::new(());
+            if let Some(token) = token {
+                let value = token.parse().expect("valid metadata");
+                request.metadata_mut().insert(AUTH_METADATA_KEY, value);
+            }
+            request
+        };
+        assert!(server.authenticate(&request(Some("Bearer secret"))).is_ok());
+        assert!(server.authenticate(&request(Some("Bearer wrong"))).is_err());
+        assert!(server.authenticate(&request(Some("secret"))).is_err());
+        assert!(server.authenticate(&request(None)).is_err());
+    }
+}
@@ -40,6 +40,7 @@ use differential_dataflow::lattice::Lattice;
 use mz_ore::cast::CastFrom;
 use mz_ore::soft_assert_or_log;
 use mz_persist::location::{CaSResult, Indeterminate, SeqNo, VersionedData};
+use mz_persist_types::parquet::CompressionFormat;
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
 use timely::progress::{Antichain, Timestamp};
@@ -233,6 +234,16 @@ where
             })
     }
 
+    /// The compression policy of the shard, if it has one.
+    ///
+    /// See [crate::PersistClient::set_compression].
+    pub fn compression(&self) -> Option<CompressionFormat> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_cacheable, |state| {
+                state.collections.compression
+            })
+    }
+
     /// See [crate::PersistClient::get_schema].
     pub fn get_schema(&self, schema_id: SchemaId) -> Option<(K::Schema, V::Schema)> {
         self.state
@@ -0,0 +1,687 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Point-in-time backups of every shard in a persist environment.
+//!
+//! A backup is written to its own [Blob] location and consists of:
+//! - `state/<shard_id>`: the state of each shard at the backed up seqno,
+//!   encoded as a rollup.
+//! - `blob/<key>`: a copy of each batch part (and hollow run) referenced by
+//!   those states, under its original key.
+//! - `MANIFEST`: a JSON [BackupManifest] listing the above. This is written
+//!   last, so a location without one holds at most a partial backup.
+//!
+//! Shards are all backed up at a single point in time, including the catalog
+//! and txn-wal shards, which is what lets the restored environment make sense
+//! of the relationships between them. Shards that have been finalized, or
+//! whose state no longer exists, are left out of the backup.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+use bytes::Bytes;
+use futures_util::{StreamExt, TryStreamExt, stream};
+use mz_persist::location::{Blob, CaSResult, Consensus, SeqNo, VersionedData};
+use mz_proto::RustType;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::ShardId;
+use crate::cfg::PersistConfig;
+use crate::internal::encoding::{Rollup, UntypedState};
+use crate::internal::paths::{PartialRollupKey, RollupId};
+use crate::internal::state::{BatchPart, HollowRollup, RunPart, State, StateCollections};
+use crate::internal::state_diff::StateDiff;
+use crate::internal::state_versions::StateVersions;
+use crate::internal::trace::Trace;
+
+const MANIFEST_KEY: &str = "MANIFEST";
+
+fn state_key(shard_id: &ShardId) -> String {
+    format!("state/{shard_id}")
+}
+
+fn blob_key(key: &str) -> String {
+    format!("blob/{key}")
+}
+
+/// The contents of a backup.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// The version of persist that took the backup.
+    pub build_version: String,
+    /// The backed up shards, keyed by shard id.
+    pub shards: BTreeMap<String, ShardBackup>,
+}
+
+/// The backup of an individual shard.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShardBackup {
+    /// The seqno of the backed up state.
+    pub seqno: u64,
+    /// The keys of the blobs referenced by the backed up state.
+    pub blobs: Vec<String>,
+}
+
+/// Backs up every shard in `versions` to `target`.
+///
+/// The environment must be quiesced, i.e. environmentd and every clusterd
+/// stopped, before taking a backup: a running environment advances its table
+/// shards every second, so the shards never all hold still long enough.
+///
+/// The first pass copies every shard, and each later pass checks the latest
+/// seqno of every shard and re-copies any shard that changed since it was
+/// last copied. Once a full pass finds nothing changed, there was a moment,
+/// between the last copy and the start of that pass, at which every shard was
+/// at its backed up seqno. On a quiesced environment that is the second pass.
+/// Gives up after `max_attempts` passes, which catches writers that were left
+/// running.
+///
+/// Blobs are written to `target` as is, so it should be configured to
+/// encrypt them if the environment's blobs are encrypted.
+pub async fn backup(
+    versions: &StateVersions,
+    target: &dyn Blob,
+    concurrency: usize,
+    max_attempts: usize,
+) -> anyhow::Result<BackupManifest> {
+    let mut shards = BTreeMap::new();
+    // Shards that were finalized or dropped, and the seqno at which they were
+    // last seen as such.
+    let mut skipped = BTreeMap::new();
+    for attempt in 1..=max_attempts {
+        let keys: Vec<String> = versions.consensus.list_keys().try_collect().await?;
+        let heads: Vec<(String, Option<SeqNo>)> = stream::iter(keys)
+            .map(|key| async move {
+                let head = versions.consensus.head(&key).await?;
+                Ok::<_, anyhow::Error>((key, head.map(|x| x.seqno)))
+            })
+            .buffer_unordered(concurrency)
+            .try_collect()
+            .await?;
+        let heads: BTreeMap<_, _> = heads
+            .into_iter()
+            .filter_map(|(key, head)| Some((key, head?)))
+            .collect();
+        // Shards whose state was deleted since they were copied are gone.
+        shards.retain(|key, _| heads.contains_key(key));
+        skipped.retain(|key, _| heads.contains_key(key));
+        let changed: Vec<_> = heads
+            .into_iter()
+            .filter_map(|(key, head)| {
+                let backed_up = shards
+                    .get(&key)
+                    .map(|x: &ShardBackup| SeqNo(x.seqno))
+                    .or_else(|| skipped.get(&key).copied());
+                (backed_up != Some(head)).then_some(key)
+            })
+            .collect();
+        if changed.is_empty() {
+            let manifest = BackupManifest {
+                build_version: versions.cfg.build_version.to_string(),
+                shards,
+            };
+            let buf = serde_json::to_vec_pretty(&manifest)?;
+            target.set(MANIFEST_KEY, Bytes::from(buf)).await?;
+            return Ok(manifest);
+        }
+
+        info!(
+            "backup attempt {attempt}: copying {} changed shards",
+            changed.len()
+        );
+        let copied: Vec<_> = stream::iter(changed)
+            .map(|key| async move {
+                let shard_id = ShardId::from_str(&key).map_err(|err| anyhow!(err))?;
+                let shard = backup_shard(versions, target, shard_id).await?;
+                Ok::<_, anyhow::Error>((key, shard))
+            })
+            .buffer_unordered(concurrency)
+            .try_collect()
+            .await?;
+        for (key, shard) in copied {
+            match shard {
+                CopiedShard::Copied(shard) => {
+                    skipped.remove(&key);
+                    shards.insert(key, shard);
+                }
+                CopiedShard::Skipped(seqno) => {
+                    shards.remove(&key);
+                    skipped.insert(key, seqno);
+                }
+                // Leave it to the next pass to copy the newer state.
+                CopiedShard::Changed => {
+                    shards.remove(&key);
+                }
+            }
+        }
+    }
+    bail!("shards were still changing after {max_attempts} backup attempts")
+}
+
+/// The outcome of [backup_shard].
+enum CopiedShard {
+    /// The shard was copied.
+    Copied(ShardBackup),
+    /// The shard was finalized, or its state was deleted, as of the given
+    /// seqno, so there is nothing to back up.
+    Skipped(SeqNo),
+    /// A referenced blob no longer exists, which means the state was replaced
+    /// and its blobs were garbage collected while we were copying.
+    Changed,
+}
+
+/// Copies the current state of a shard and every blob it references to
+/// `target`.
+async fn backup_shard(
+    versions: &StateVersions,
+    target: &dyn Blob,
+    shard_id: ShardId,
+) -> anyhow::Result<CopiedShard> {
+    let diffs = versions.fetch_recent_live_diffs::<u64>(&shard_id).await;
+    if diffs.0.is_empty() {
+        // The shard's state was deleted after we listed it.
+        return Ok(CopiedShard::Skipped(SeqNo::minimum()));
+    }
+    let state = versions
+        .fetch_current_state::<u64>(&shard_id, diffs.0)
+        .await;
+    let codecs = (
+        state.key_codec.clone(),
+        state.val_codec.clone(),
+        state.ts_codec.clone(),
+        state.diff_codec.clone(),
+    );
+    let state = state.check_ts_codec(&shard_id)?;
+    let seqno = state.seqno;
+    if state.collections.is_tombstone() {
+        return Ok(CopiedShard::Skipped(seqno));
+    }
+
+    let mut blobs = vec![];
+    let mut parts: Vec<_> = state
+        .collections
+        .trace
+        .batches()
+        .flat_map(|batch| batch.parts.iter().cloned())
+        .collect();
+    while let Some(part) = parts.pop() {
+        let key = match part {
+            RunPart::Single(BatchPart::Inline { .. }) => continue,
+            RunPart::Single(BatchPart::Hollow(part)) => part.key.complete(&shard_id),
+            RunPart::Many(runs) => {
+                let Some(run) = runs
+                    .get(shard_id, versions.blob.as_ref(), &versions.metrics)
+                    .await
+                else {
+                    return Ok(CopiedShard::Changed);
+                };
+                parts.extend(run.parts);
+                runs.key.complete(&shard_id)
+            }
+        };
+        let Some(value) = versions.blob.get(&key).await? else {
+            return Ok(CopiedShard::Changed);
+        };
+        target
+            .set(&blob_key(&key), Bytes::from(value.into_contiguous()))
+            .await?;
+        blobs.push(key.to_string());
+    }
+
+    let (key_codec, val_codec, ts_codec, diff_codec) = codecs;
+    let rollup =
+        Rollup::from_state_without_diffs(state, key_codec, val_codec, ts_codec, diff_codec);
+    let buf = rollup.into_proto().encode_to_vec();
+    target.set(&state_key(&shard_id), Bytes::from(buf)).await?;
+
+    Ok(CopiedShard::Copied(ShardBackup {
+        seqno: seqno.0,
+        blobs,
+    }))
+}
+
+/// Restores the backup in `source` into a fresh `consensus` and `blob`.
+///
+/// Each shard is restored as a new shard history starting at the initial
+/// seqno, whose single rollup is the backed up state. Leased readers and
+/// writers are dropped, as the processes that held them can't be talking to
+/// the restored environment. Critical readers, whose since holds and opaque
+/// values outlive any process, are kept.
+///
+/// Fails without writing anything if any of the backed up shards already
+/// exists in `consensus`.
+pub async fn restore(
+    cfg: &PersistConfig,
+    source: &dyn Blob,
+    consensus: &dyn Consensus,
+    blob: &dyn Blob,
+    concurrency: usize,
+) -> anyhow::Result<BackupManifest> {
+    let manifest = source
+        .get(MANIFEST_KEY)
+        .await?
+        .ok_or_else(|| anyhow!("backup has no manifest, it may be incomplete"))?;
+    let manifest: BackupManifest = serde_json::from_slice(&manifest.into_contiguous())?;
+
+    for key in manifest.shards.keys() {
+        if consensus.head(key).await?.is_some() {
+            bail!("shard {key} already exists in the restore target");
+        }
+    }
+
+    stream::iter(&manifest.shards)
+        .map(|(key, shard)| async move {
+            let shard_id = ShardId::from_str(key).map_err(|err| anyhow!(err))?;
+            restore_shard(cfg, source, consensus, blob, shard_id, shard).await
+        })
+        .buffer_unordered(concurrency)
+        .try_collect::<Vec<()>>()
+        .await?;
+    Ok(manifest)
+}
+
+async fn restore_shard(
+    cfg: &PersistConfig,
+    source: &dyn Blob,
+    consensus: &dyn Consensus,
+    blob: &dyn Blob,
+    shard_id: ShardId,
+    shard: &ShardBackup,
+) -> anyhow::Result<()> {
+    // Copy the data before the state that references it.
+    for key in &shard.blobs {
+        let value = source
+            .get(&blob_key(key))
+            .await?
+            .ok_or_else(|| anyhow!("backup is missing blob {key}"))?;
+        blob.set(key, Bytes::from(value.into_contiguous())).await?;
+    }
+
+    let buf = source
+        .get(&state_key(&shard_id))
+        .await?
+        .ok_or_else(|| anyhow!("backup is missing the state of shard {shard_id}"))?;
+    let state = UntypedState::<u64>::decode(&cfg.build_version, buf);
+    let codecs = (
+        state.key_codec.clone(),
+        state.val_codec.clone(),
+        state.ts_codec.clone(),
+        state.diff_codec.clone(),
+    );
+    let mut state = state.check_ts_codec(&shard_id)?;
+
+    let walltime_ms = (cfg.now)();
+    let empty_state = State {
+        shard_id,
+        seqno: SeqNo::minimum(),
+        walltime_ms,
+        hostname: cfg.hostname.clone(),
+        collections: StateCollections {
+            version: state.collections.version.clone(),
+            last_gc_req: SeqNo::minimum(),
+            rollups: BTreeMap::new(),
+            active_rollup: None,
+            active_gc: None,
+            compression: None,
+            leased_readers: BTreeMap::new(),
+            critical_readers: BTreeMap::new(),
+            writers: BTreeMap::new(),
+            schemas: BTreeMap::new(),
+            trace: Trace::default(),
+        },
+    };
+
+    // Rewrite the state as if it were the initial state of the shard,
+    // including a self-referential rollup, as in `write_initial_rollup`.
+    let rollup_key = PartialRollupKey::new(SeqNo::minimum(), &RollupId::new());
+    state.seqno = SeqNo::minimum();
+    state.walltime_ms = walltime_ms;
+    state.hostname = cfg.hostname.clone();
+    let collections = &mut state.collections;
+    collections.last_gc_req = SeqNo::minimum();
+    collections.active_rollup = None;
+    collections.active_gc = None;
+    collections.leased_readers.clear();
+    collections.writers.clear();
+    collections.rollups = BTreeMap::from([(
+        SeqNo::minimum(),
+        HollowRollup {
+            key: rollup_key.clone(),
+            encoded_size_bytes: None,
+        },
+    )]);
+
+    let diff = StateDiff::from_diff(&empty_state, &state);
+    let mut diff_buf = Vec::new();
+    diff.encode(&mut diff_buf);
+
+    let (key_codec, val_codec, ts_codec, diff_codec) = codecs;
+    let rollup =
+        Rollup::from_state_without_diffs(state, key_codec, val_codec, ts_codec, diff_codec);
+    let rollup = Rollup::from(rollup.state, vec![]);
+    let rollup_buf = rollup.into_proto().encode_to_vec();
+    blob.set(&rollup_key.complete(&shard_id), Bytes::from(rollup_buf))
+        .await?;
+
+    let new = VersionedData {
+        seqno: SeqNo::minimum(),
+        data: Bytes::from(diff_buf),
+    };
+    match consensus
+        .compare_and_set(&shard_id.to_string(), new)
+        .await?
+    {
+        CaSResult::Committed => {
+            info!("restored shard {shard_id} from seqno {}", shard.seqno);
+            Ok(())
+        }
+        CaSResult::ExpectationMismatch => {
+            bail!("shard {shard_id} was concurrently created in the restore target")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_persist::encryption::{BLOB_ENCRYPTION_KEY_ID, BlobKeys, EncryptedBlob, StaticBlobKeys};
+    use mz_persist::file::FileBlob;
+    use mz_persist::mem::{MemBlob, MemBlobConfig, MemConsensus};
+    use mz_persist::postgres::{PostgresConsensus, PostgresConsensusConfig};
+    use timely::progress::Antichain;
+
+    use crate::async_runtime::IsolatedRuntime;
+    use crate::cache::StateCache;
+    use crate::critical::{CriticalReaderId, Opaque};
+    use crate::rpc::NoopPubSubSender;
+    use crate::tests::all_ok;
+    use crate::{Diagnostics, Metrics, PersistClient};
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn backup_and_restore() {
+        backup_and_restore_impl(
+            Arc::new(MemBlob::open(MemBlobConfig::default())),
+            Arc::new(MemConsensus::default()),
+            Arc::new(MemBlob::open(MemBlobConfig::default())),
+            Arc::new(MemBlob::open(MemBlobConfig::default())),
+            Arc::new(MemConsensus::default()),
+        )
+        .await;
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: integer-to-pointer casts and `ptr::from_exposed_addr` are not supported with `-Zmiri-strict-provenance`
+    async fn backup_and_restore_file_blob() {
+        let temp_dir = tempfile::tempdir().expect("tempdir");
+        let file_blob = |path: &str| FileBlob::open(temp_dir.path().join(path).into());
+        backup_and_restore_impl(
+            Arc::new(file_blob("source").await.expect("blob opens")),
+            Arc::new(MemConsensus::default()),
+            Arc::new(file_blob("backup").await.expect("blob opens")),
+            Arc::new(file_blob("restored").await.expect("blob opens")),
+            Arc::new(MemConsensus::default()),
+        )
+        .await;
+    }
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+    async fn backup_and_restore_postgres_consensus() {
+        let config = match PostgresConsensusConfig::new_for_test().expect("valid config") {
+            Some(config) => config,
+            None => {
+                info!(
+                    "{} env not set: skipping test that uses external service",
+                    PostgresConsensusConfig::EXTERNAL_TESTS_POSTGRES_URL
+                );
+                return;
+            }
+        };
+        // Backups list every shard in the source consensus, which other tests
+        // share, so only restore into Postgres. Restored shards are new
+        // [ShardId]s, so they don't collide with anything already there.
+        let consensus = PostgresConsensus::open(config)
+            .await
+            .expect("consensus opens");
+        backup_and_restore_impl(
+            Arc::new(MemBlob::open(MemBlobConfig::default())),
+            Arc::new(MemConsensus::default()),
+            Arc::new(MemBlob::open(MemBlobConfig::default())),
+            Arc::new(MemBlob::open(MemBlobConfig::default())),
+            Arc::new(consensus),
+        )
+        .await;
+    }
+
+    async fn backup_and_restore_impl(
+        blob: Arc<dyn Blob>,
+        consensus: Arc<dyn Consensus>,
+        backup_blob: Arc<dyn Blob>,
+        restored_blob: Arc<dyn Blob>,
+        restored_consensus: Arc<dyn Consensus>,
+    ) {
+        let data = vec![
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("3".to_owned(), "three".to_owned()), 3, 1),
+        ];
+        let later = vec![(("4".to_owned(), "four".to_owned()), 4, 1)];
+
+        let cfg = PersistConfig::new_for_tests();
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let client = |blob: Arc<dyn Blob>, consensus: Arc<dyn Consensus>| {
+            PersistClient::new(
+                cfg.clone(),
+                blob,
+                consensus,
+                Arc::clone(&metrics),
+                Arc::new(IsolatedRuntime::new_for_tests()),
+                Arc::new(StateCache::new_no_metrics()),
+                Arc::new(NoopPubSubSender),
+            )
+            .expect("client construction failed")
+        };
+
+        let shard_id = ShardId::new();
+        let (mut write, _read) = client(Arc::clone(&blob), Arc::clone(&consensus))
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_compare_and_append(&data[..2], 0, 3).await;
+        write.expect_compare_and_append(&data[2..], 3, 4).await;
+
+        let versions = StateVersions::new(cfg.clone(), consensus, blob, Arc::clone(&metrics));
+        let manifest = backup(&versions, backup_blob.as_ref(), 4, 3)
+            .await
+            .expect("backup succeeds");
+        assert_eq!(manifest.shards.len(), 1);
+
+        // Writes after the backup aren't part of it.
+        write.expect_compare_and_append(&later, 4, 5).await;
+
+        restore(
+            &cfg,
+            backup_blob.as_ref(),
+            restored_consensus.as_ref(),
+            restored_blob.as_ref(),
+            4,
+        )
+        .await
+        .expect("restore succeeds");
+
+        // Restoring over existing shards is refused.
+        let res = restore(
+            &cfg,
+            backup_blob.as_ref(),
+            restored_consensus.as_ref(),
+            restored_blob.as_ref(),
+            4,
+        )
+        .await;
+        assert!(res.is_err());
+
+        let (mut write, mut read) = client(restored_blob, restored_consensus)
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        assert_eq!(write.upper(), &Antichain::from_elem(4));
+        assert_eq!(read.expect_snapshot_and_fetch(3).await, all_ok(&data, 3));
+
+        // The restored shard is fully writable.
+        write.expect_compare_and_append(&later, 4, 5).await;
+        assert_eq!(
+            read.expect_snapshot_and_fetch(4).await,
+            all_ok(data.iter().chain(later.iter()), 4)
+        );
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn backup_skips_finalized_shards() {
+        let data = vec![(("1".to_owned(), "one".to_owned()), 1, 1)];
+
+        let cfg = PersistConfig::new_for_tests();
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let blob: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let consensus: Arc<dyn Consensus> = Arc::new(MemConsensus::default());
+        let client = PersistClient::new(
+            cfg.clone(),
+            Arc::clone(&blob),
+            Arc::clone(&consensus),
+            Arc::clone(&metrics),
+            Arc::new(IsolatedRuntime::new_for_tests()),
+            Arc::new(StateCache::new_no_metrics()),
+            Arc::new(NoopPubSubSender),
+        )
+        .expect("client construction failed");
+
+        let live = ShardId::new();
+        let (mut write, _read) = client.expect_open::<String, String, u64, i64>(live).await;
+        write.expect_compare_and_append(&data, 0, 2).await;
+
+        let finalized = ShardId::new();
+        let (mut write, mut read) = client
+            .expect_open::<String, String, u64, i64>(finalized)
+            .await;
+        write.expect_compare_and_append(&data, 0, 2).await;
+        let () = read.downgrade_since(&Antichain::new()).await;
+        let () = write.advance_upper(&Antichain::new()).await;
+        let mut since = client
+            .open_critical_since::<String, String, u64, i64>(
+                finalized,
+                CriticalReaderId::new(),
+                Opaque::encode(&0u64),
+                Diagnostics::for_tests(),
+            )
+            .await
+            .expect("invalid persist usage");
+        let epoch = since.opaque().clone();
+        since
+            .compare_and_downgrade_since(&epoch, (&epoch, &Antichain::new()))
+            .await
+            .expect("downgrade succeeds");
+        client
+            .finalize_shard::<String, String, u64, i64>(finalized, Diagnostics::for_tests())
+            .await
+            .expect("finalize succeeds");
+
+        let versions = StateVersions::new(cfg, consensus, blob, metrics);
+        let backup_blob = MemBlob::open(MemBlobConfig::default());
+        let manifest = backup(&versions, &backup_blob, 4, 3)
+            .await
+            .expect("backup succeeds");
+        let shards: Vec<_> = manifest.shards.keys().cloned().collect();
+        assert_eq!(shards, vec![live.to_string()]);
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn backup_encrypted() {
+        let data = vec![(("1".to_owned(), "very secret".to_owned()), 1, 1)];
+
+        let mut cfg = PersistConfig::new_for_tests();
+        cfg.set_config(&BLOB_ENCRYPTION_KEY_ID, "k1");
+        let mut keys = StaticBlobKeys::default();
+        keys.insert("k1", vec![1; 32]);
+        let keys: Arc<dyn BlobKeys> = Arc::new(keys);
+        cfg.blob_keys = Some(Arc::clone(&keys));
+        let encrypted = |blob: Arc<dyn Blob>| -> Arc<dyn Blob> {
+            Arc::new(EncryptedBlob::new(
+                blob,
+                Arc::clone(&keys),
+                Arc::clone(&cfg.configs),
+            ))
+        };
+
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let blob = encrypted(Arc::new(MemBlob::open(MemBlobConfig::default())));
+        let consensus: Arc<dyn Consensus> = Arc::new(MemConsensus::default());
+        let client = PersistClient::new(
+            cfg.clone(),
+            Arc::clone(&blob),
+            Arc::clone(&consensus),
+            Arc::clone(&metrics),
+            Arc::new(IsolatedRuntime::new_for_tests()),
+            Arc::new(StateCache::new_no_metrics()),
+            Arc::new(NoopPubSubSender),
+        )
+        .expect("client construction failed");
+        let shard_id = ShardId::new();
+        let (mut write, _read) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_compare_and_append(&data, 0, 2).await;
+
+        let versions = StateVersions::new(cfg.clone(), consensus, blob, metrics);
+        let raw_backup: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let backup_blob = encrypted(Arc::clone(&raw_backup));
+        let manifest = backup(&versions, backup_blob.as_ref(), 4, 3)
+            .await
+            .expect("backup succeeds");
+
+        // Everything in the backup, including the manifest, is encrypted.
+        let mut keys = vec![];
+        raw_backup
+            .list_keys_and_metadata("", &mut |x| keys.push(x.key.to_owned()))
+            .await
+            .expect("list succeeds");
+        assert_eq!(
+            keys.len(),
+            2 + manifest.shards[&shard_id.to_string()].blobs.len()
+        );
+        for key in keys {
+            let value = raw_backup
+                .get(&key)
+                .await
+                .expect("get succeeds")
+                .expect("blob exists")
+                .into_contiguous();
+            assert!(!value.windows(11).any(|x| x == b"very secret"));
+            assert!(serde_json::from_slice::<BackupManifest>(&value).is_err());
+        }
+
+        let restored_blob = encrypted(Arc::new(MemBlob::open(MemBlobConfig::default())));
+        let restored_consensus: Arc<dyn Consensus> = Arc::new(MemConsensus::default());
+        restore(
+            &cfg,
+            backup_blob.as_ref(),
+            restored_consensus.as_ref(),
+            restored_blob.as_ref(),
+            4,
+        )
+        .await
+        .expect("restore succeeds");
+    }
+}
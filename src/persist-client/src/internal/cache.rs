@@ -7,9 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! In-process caches of [Blob].
+//! In-process and on-disk caches of [Blob].
 
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -17,9 +20,14 @@ use mz_dyncfg::{Config, ConfigSet};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use mz_persist::location::{Blob, BlobMetadata, ExternalError};
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncWriteExt;
+use tracing::{info, warn};
+use uuid::Uuid;
 
 use crate::cfg::PersistConfig;
 use crate::internal::metrics::Metrics;
+use crate::internal::paths::{BlobKey, PartialBlobKey};
 
 // In-memory cache for [Blob].
 #[derive(Debug)]
@@ -170,6 +178,319 @@ impl Blob for BlobMemCache {
     }
 }
 
+pub(crate) const BLOB_CACHE_DISK_LIMIT_BYTES: Config<usize> = Config::new(
+    "persist_blob_cache_disk_limit_bytes",
+    0,
+    "Capacity of the on-disk blob cache in bytes, or 0 to disable it (Materialize).",
+);
+
+/// On-disk cache for the batch parts in [Blob].
+///
+/// This sits beneath [BlobMemCache], so that parts evicted from memory, or
+/// fetched by an earlier process using the same directory, are served from
+/// local disk instead of being downloaded again. Each part is stored in a file
+/// named by the hash of its key, and prefixed by the hash of its contents,
+/// which is verified on every read. Rollups are not cached, as they are rarely
+/// read more than once. Blob encryption, if enabled, wraps this cache, so that
+/// parts are only ever written to local disk encrypted.
+///
+/// The cache is usually opened before the process has synced its dyncfgs, at
+/// which point the limit still has its default of 0. To not throw away the
+/// files of a previous process, nothing is evicted, and nothing new is cached,
+/// until a non-zero limit has been seen.
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    /// [`ConfigSet`] of dynamic configs.
+    cfg: Arc<ConfigSet>,
+    dir: PathBuf,
+    metrics: Arc<Metrics>,
+    /// The cached files, by name, weighted by their size.
+    cache: Mutex<lru::Lru<String, ()>>,
+    /// Whether a non-zero limit has been seen. Only changed under the `cache`
+    /// lock.
+    limit_known: AtomicBool,
+    /// Files evicted from `cache` that are yet to be removed from disk, which
+    /// is done outside the `cache` lock.
+    evicted: Arc<Mutex<Vec<String>>>,
+    blob: Arc<dyn Blob>,
+}
+
+impl BlobDiskCache {
+    /// The length of the content hash at the start of every file.
+    const HASH_LEN: usize = 32;
+
+    /// Returns a [Blob] that caches parts of `blob` in `dir`, picking up the
+    /// files already there.
+    ///
+    /// Returns `blob` itself if `dir` cannot be used.
+    pub async fn new(
+        cfg: &PersistConfig,
+        metrics: Arc<Metrics>,
+        blob: Arc<dyn Blob>,
+        dir: PathBuf,
+    ) -> Arc<dyn Blob> {
+        let scan_dir = dir.clone();
+        let existing = mz_ore::task::spawn_blocking(
+            || "persist::blob_disk_cache::scan",
+            move || Self::scan(&scan_dir),
+        )
+        .await;
+        let existing = match existing {
+            Ok(existing) => existing,
+            Err(err) => {
+                warn!("disabling disk blob cache in {}: {err}", dir.display());
+                return blob;
+            }
+        };
+
+        let eviction_metrics = Arc::clone(&metrics);
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let eviction_queue = Arc::clone(&evicted);
+        // The real limit is applied by `resize_and_update_size_metrics`, once
+        // it is known.
+        let mut cache = lru::Lru::new(usize::MAX, move |name: String, (), _| {
+            eviction_metrics.blob_cache_disk.evictions.inc();
+            eviction_queue.lock().expect("lock poisoned").push(name);
+        });
+        for (name, size) in existing {
+            cache.insert(name, (), size);
+        }
+        info!(
+            "opened disk blob cache in {} with {} files, {} bytes",
+            dir.display(),
+            cache.entry_count(),
+            cache.entry_weight()
+        );
+
+        let blob = BlobDiskCache {
+            cfg: Arc::clone(&cfg.configs),
+            dir,
+            metrics,
+            cache: Mutex::new(cache),
+            limit_known: AtomicBool::new(false),
+            evicted,
+            blob,
+        };
+        blob.resize_and_update_size_metrics(&mut blob.cache.lock().expect("lock poisoned"));
+        blob.remove_evicted().await;
+        Arc::new(blob)
+    }
+
+    /// Returns the names and sizes of the files in `dir`, least recently
+    /// modified first, creating `dir` if necessary and removing any files left
+    /// behind by interrupted writes.
+    fn scan(dir: &Path) -> Result<Vec<(String, usize)>, std::io::Error> {
+        std::fs::create_dir_all(dir)?;
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.ends_with(".tmp") {
+                std::fs::remove_file(entry.path())?;
+                continue;
+            }
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((modified, name, usize::cast_from(metadata.len())));
+        }
+        files.sort();
+        Ok(files
+            .into_iter()
+            .map(|(_modified, name, size)| (name, size))
+            .collect())
+    }
+
+    /// Applies the current limit, if one is known, returning the capacity
+    /// available for new files.
+    fn resize_and_update_size_metrics(&self, cache: &mut lru::Lru<String, ()>) -> usize {
+        let limit = BLOB_CACHE_DISK_LIMIT_BYTES.get(&self.cfg);
+        if limit > 0 {
+            self.limit_known.store(true, Ordering::Relaxed);
+        }
+        let capacity = if self.limit_known.load(Ordering::Relaxed) {
+            cache.update_capacity(limit);
+            limit
+        } else {
+            0
+        };
+        self.metrics
+            .blob_cache_disk
+            .size_blobs
+            .set(u64::cast_from(cache.entry_count()));
+        self.metrics
+            .blob_cache_disk
+            .size_bytes
+            .set(u64::cast_from(cache.entry_weight()));
+        capacity
+    }
+
+    /// Removes the files evicted from the cache from disk.
+    async fn remove_evicted(&self) {
+        let evicted = std::mem::take(&mut *self.evicted.lock().expect("lock poisoned"));
+        for name in evicted {
+            if let Err(err) = tokio::fs::remove_file(self.dir.join(name)).await {
+                warn!("failed to remove evicted blob cache file: {err}");
+            }
+        }
+    }
+
+    /// Returns the name of the file caching the blob `key`, if it is cached at
+    /// all.
+    fn file_name(key: &str) -> Option<String> {
+        match BlobKey::parse_ids(key) {
+            Ok((_, PartialBlobKey::Batch(..))) => Some(hex::encode(Sha256::digest(key))),
+            Ok((_, PartialBlobKey::Rollup(..))) | Err(_) => None,
+        }
+    }
+
+    /// Reads the cached file `name`, returning an inner error if its contents
+    /// fail verification.
+    async fn read(&self, name: &str) -> Result<Result<Bytes, &'static str>, std::io::Error> {
+        let contents = Bytes::from(tokio::fs::read(self.dir.join(name)).await?);
+        if contents.len() < Self::HASH_LEN {
+            return Ok(Err("truncated file"));
+        }
+        let (hash, value) = (
+            contents.slice(..Self::HASH_LEN),
+            contents.slice(Self::HASH_LEN..),
+        );
+        if hash.as_ref() != Sha256::digest(&value).as_slice() {
+            return Ok(Err("content hash mismatch"));
+        }
+        Ok(Ok(value))
+    }
+
+    /// Writes `value` to the file `name`, atomically.
+    async fn write(&self, name: &str, value: &SegmentedBytes) -> Result<(), std::io::Error> {
+        let mut hasher = Sha256::new();
+        for segment in value.clone().into_segments() {
+            hasher.update(&segment);
+        }
+        let hash = hasher.finalize();
+
+        let tmp = self.dir.join(format!("{name}.{}.tmp", Uuid::new_v4()));
+        let mut file = tokio::fs::File::create(&tmp).await?;
+        let written = async {
+            file.write_all(&hash).await?;
+            for segment in value.clone().into_segments() {
+                file.write_all(&segment).await?;
+            }
+            file.sync_all().await?;
+            tokio::fs::rename(&tmp, self.dir.join(name)).await
+        }
+        .await;
+        if written.is_err() {
+            let _ = tokio::fs::remove_file(&tmp).await;
+        }
+        written
+    }
+
+    /// Removes the file `name` from the cache and from disk.
+    async fn remove(&self, name: &str) {
+        {
+            let mut cache = self.cache.lock().expect("lock poisoned");
+            cache.remove(name);
+            self.resize_and_update_size_metrics(&mut cache);
+        }
+        self.remove_evicted().await;
+        match tokio::fs::remove_file(self.dir.join(name)).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => warn!("failed to remove blob cache file: {err}"),
+        }
+    }
+}
+
+#[async_trait]
+impl Blob for BlobDiskCache {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let Some(name) = Self::file_name(key) else {
+            return self.blob.get(key).await;
+        };
+
+        // Blobs are write-once modify-never, so a cached file can only be
+        // wrong if the disk corrupted it, which the content hash catches.
+        let cached = self
+            .cache
+            .lock()
+            .expect("lock poisoned")
+            .get(&name)
+            .is_some();
+        if cached {
+            match self.read(&name).await {
+                Ok(Ok(value)) => {
+                    self.metrics.blob_cache_disk.hits_blobs.inc();
+                    self.metrics
+                        .blob_cache_disk
+                        .hits_bytes
+                        .inc_by(u64::cast_from(value.len()));
+                    return Ok(Some(SegmentedBytes::from(value)));
+                }
+                Ok(Err(err)) => {
+                    warn!("discarding corrupted cached blob {key}: {err}");
+                    self.metrics.blob_cache_disk.corruptions.inc();
+                    self.remove(&name).await;
+                }
+                Err(err) => {
+                    warn!("failed to read cached blob {key}: {err}");
+                    self.remove(&name).await;
+                }
+            }
+        }
+
+        let res = self.blob.get(key).await?;
+        if let Some(value) = res.as_ref() {
+            let weight = Self::HASH_LEN + value.len();
+            let capacity = {
+                let mut cache = self.cache.lock().expect("lock poisoned");
+                self.resize_and_update_size_metrics(&mut cache)
+            };
+            // See the comment in `BlobMemCache::get` about blobs larger than
+            // the entire cache.
+            if weight <= capacity {
+                match self.write(&name, value).await {
+                    Ok(()) => {
+                        let mut cache = self.cache.lock().expect("lock poisoned");
+                        cache.insert(name, (), weight);
+                        self.resize_and_update_size_metrics(&mut cache);
+                    }
+                    Err(err) => warn!("failed to cache blob {key} on disk: {err}"),
+                }
+            }
+            self.remove_evicted().await;
+        }
+        Ok(res)
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        // Parts written by this process are cached in memory, if at all.
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let res = self.blob.delete(key).await;
+        if let Some(name) = Self::file_name(key) {
+            self.remove(&name).await;
+        }
+        res
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
 mod lru {
     use std::borrow::Borrow;
     use std::collections::BTreeMap;
@@ -555,4 +876,92 @@ mod tests {
         assert_eq!(cache.entry_weight(), 2);
         assert_eq!(cache.keys(), &["j", "i"]);
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `open` on OS `linux`
+    async fn blob_disk_cache() {
+        use std::sync::Arc;
+
+        use bytes::Bytes;
+        use mz_ore::metrics::MetricsRegistry;
+        use mz_persist::location::Blob;
+        use mz_persist::mem::{MemBlob, MemBlobConfig};
+
+        use crate::ShardId;
+        use crate::cfg::PersistConfig;
+        use crate::internal::metrics::Metrics;
+        use crate::internal::paths::{PartId, PartialBatchKey, WriterKey};
+
+        use super::{BLOB_CACHE_DISK_LIMIT_BYTES, BlobDiskCache};
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cfg = PersistConfig::new_for_tests();
+        cfg.set_config(&BLOB_CACHE_DISK_LIMIT_BYTES, 1024);
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let mem: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let open = || {
+            BlobDiskCache::new(
+                &cfg,
+                Arc::clone(&metrics),
+                Arc::clone(&mem),
+                dir.path().to_owned(),
+            )
+        };
+        let part_key = |shard_id| {
+            let writer = WriterKey::for_version(&cfg.build_version);
+            PartialBatchKey::new(&writer, &PartId::new())
+                .complete(&shard_id)
+                .to_string()
+        };
+        let get = |blob: &Arc<dyn Blob>, key: &str| {
+            let blob = Arc::clone(blob);
+            let key = key.to_owned();
+            async move {
+                blob.get(&key)
+                    .await
+                    .expect("get")
+                    .map(|x| x.into_contiguous())
+            }
+        };
+
+        let shard_id = ShardId::new();
+        let (k0, k1) = (part_key(shard_id), part_key(shard_id));
+        mem.set(&k0, Bytes::from(vec![0; 100])).await.expect("set");
+        mem.set(&k1, Bytes::from(vec![1; 1000])).await.expect("set");
+
+        // A miss populates the cache, so the next get is a hit.
+        let cache = open().await;
+        assert_eq!(get(&cache, &k0).await, Some(vec![0; 100]));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 0);
+        assert_eq!(get(&cache, &k0).await, Some(vec![0; 100]));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 1);
+
+        // The cache survives being reopened, even before the limit is synced.
+        drop(cache);
+        cfg.set_config(&BLOB_CACHE_DISK_LIMIT_BYTES, 0);
+        let cache = open().await;
+        assert_eq!(std::fs::read_dir(dir.path()).expect("dir").count(), 1);
+        assert_eq!(get(&cache, &k0).await, Some(vec![0; 100]));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 2);
+        cfg.set_config(&BLOB_CACHE_DISK_LIMIT_BYTES, 1024);
+        assert_eq!(get(&cache, &k0).await, Some(vec![0; 100]));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 3);
+
+        // Caching another part evicts the first, as both exceed the budget.
+        assert_eq!(get(&cache, &k1).await, Some(vec![1; 1000]));
+        assert_eq!(metrics.blob_cache_disk.evictions.get(), 1);
+        assert_eq!(std::fs::read_dir(dir.path()).expect("dir").count(), 1);
+
+        // Corrupted files are detected, and the blob is read from upstream.
+        for entry in std::fs::read_dir(dir.path()).expect("dir") {
+            let path = entry.expect("entry").path();
+            let mut contents = std::fs::read(&path).expect("read");
+            *contents.last_mut().expect("non-empty") ^= 1;
+            std::fs::write(&path, contents).expect("write");
+        }
+        assert_eq!(get(&cache, &k1).await, Some(vec![1; 1000]));
+        assert_eq!(metrics.blob_cache_disk.corruptions.get(), 1);
+        assert_eq!(get(&cache, &k1).await, Some(vec![1; 1000]));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 4);
+    }
 }
@@ -42,6 +42,7 @@ use crate::cfg::{
     COMPACTION_HEURISTIC_MIN_UPDATES, COMPACTION_MEMORY_BOUND_BYTES,
     GC_BLOB_DELETE_CONCURRENCY_LIMIT, MiB,
 };
+use crate::compaction_service::compact_remote;
 use crate::fetch::{FetchBatchFilter, FetchConfig};
 use crate::internal::encoding::Schemas;
 use crate::internal::gc::GarbageCollector;
@@ -58,9 +59,10 @@ use crate::{Metrics, PersistConfig, ShardId};
 
 /// A request for compaction.
 ///
-/// This is similar to FueledMergeReq, but intentionally a different type. If we
-/// move compaction to an rpc server, this one will become a protobuf; the type
-/// parameters will become names of codecs to look up in some registry.
+/// This is similar to FueledMergeReq, but intentionally a different type. When
+/// compaction is sent to the compaction service, this is encoded as a
+/// `ProtoCompactRequest`, with the type parameters replaced by the names of
+/// their codecs. See [crate::compaction_service].
 #[derive(Debug, Clone)]
 pub struct CompactReq<T> {
     /// The shard the input and output batches belong to.
@@ -109,7 +111,8 @@ impl CompactConfig {
 
 /// A service for performing physical and logical compaction.
 ///
-/// This will possibly be called over RPC in the future. Physical compaction is
+/// The work may be delegated to a [crate::compaction_service::CompactionExecutor]
+/// when one is configured. Physical compaction is
 /// merging adjacent batches. Logical compaction is advancing timestamps to a
 /// new since and consolidating the resulting updates.
 #[derive(Debug)]
@@ -404,44 +407,84 @@ where
                         .iter()
                         .all(|x| x.batch.runs().all(|(meta, _)| meta.len.is_some()));
 
-                    let compact_cfg =
+                    let mut compact_cfg =
                         CompactConfig::new(&machine_clone.applier.cfg, machine_clone.shard_id());
+                    // Compaction rewrites its inputs in the shard's current
+                    // compression policy, which is how parts written under an
+                    // older policy are (lazily) migrated to the new one.
+                    let compression = machine_clone.compression();
+                    compact_cfg.batch.apply_compression_policy(compression);
                     let incremental_enabled = compact_cfg.batch.enable_incremental_compaction
                         && all_runs_have_uuids
                         && all_runs_have_len;
-                    let stream = Self::compact_stream(
-                        compact_cfg,
-                        Arc::clone(&machine_clone.applier.state_versions.blob),
-                        Arc::clone(&metrics_clone),
-                        Arc::clone(&machine_clone.applier.shard_metrics),
-                        Arc::clone(&machine_clone.isolated_runtime),
-                        req.clone(),
-                        compaction_schema,
+                    let inline = || {
+                        Self::compact_stream(
+                            compact_cfg.clone(),
+                            Arc::clone(&machine_clone.applier.state_versions.blob),
+                            Arc::clone(&metrics_clone),
+                            Arc::clone(&machine_clone.applier.shard_metrics),
+                            Arc::clone(&machine_clone.isolated_runtime),
+                            req.clone(),
+                            compaction_schema.clone(),
+                            incremental_enabled,
+                        )
+                    };
+                    // Prefer the compaction service, if there is one, so
+                    // that this process doesn't spend its own CPU and memory
+                    // on compaction. The results are applied here either way.
+                    let remote = compact_remote::<K, V, T, D>(
+                        &machine_clone.applier.cfg,
+                        &metrics_clone,
+                        &req,
+                        &compaction_schema,
+                        compression,
                         incremental_enabled,
-                    );
-
-                    let maintenance = if incremental_enabled {
-                        let mut maintenance = RoutineMaintenance::default();
-                        pin_mut!(stream);
-                        while let Some(res) = stream.next().await {
-                            let res = res?;
-                            let new_maintenance =
-                                Self::apply(res, &metrics_clone, &machine_clone).await?;
-                            maintenance.merge(new_maintenance);
+                    )
+                    .await;
+                    let maintenance = match remote {
+                        Some(stream) => {
+                            let res = Self::apply_stream(
+                                stream,
+                                &req,
+                                incremental_enabled,
+                                &metrics_clone,
+                                &machine_clone,
+                            )
+                            .await;
+                            match res {
+                                Ok(maintenance) => maintenance,
+                                // Redo the whole request inline. Any results
+                                // that were already applied replaced some of
+                                // the inputs, so the inline results for those
+                                // won't match and are discarded.
+                                Err(err) => {
+                                    metrics_clone.compaction.remote_failed.inc();
+                                    warn!(
+                                        shard_id = %req.shard_id,
+                                        "compaction service failed, compacting inline: {}",
+                                        err.display_with_causes()
+                                    );
+                                    Self::apply_stream(
+                                        inline(),
+                                        &req,
+                                        incremental_enabled,
+                                        &metrics_clone,
+                                        &machine_clone,
+                                    )
+                                    .await?
+                                }
+                            }
+                        }
+                        None => {
+                            Self::apply_stream(
+                                inline(),
+                                &req,
+                                incremental_enabled,
+                                &metrics_clone,
+                                &machine_clone,
+                            )
+                            .await?
                         }
-                        maintenance
-                    } else {
-                        let res = Self::compact_all(stream, req.clone()).await?;
-                        Self::apply(
-                            FueledMergeRes {
-                                output: res.output,
-                                input: CompactionInput::Legacy,
-                                new_active_compaction: None,
-                            },
-                            &metrics_clone,
-                            &machine_clone,
-                        )
-                        .await?
                     };
 
                     Ok::<_, anyhow::Error>(maintenance)
@@ -478,6 +521,39 @@ where
         }
     }
 
+    /// Applies the results of compacting `req` from `stream` as they arrive,
+    /// or all at once if the compaction isn't incremental.
+    async fn apply_stream(
+        stream: impl Stream<Item = Result<FueledMergeRes<T>, anyhow::Error>>,
+        req: &CompactReq<T>,
+        incremental_enabled: bool,
+        metrics: &Metrics,
+        machine: &Machine<K, V, T, D>,
+    ) -> Result<RoutineMaintenance, anyhow::Error> {
+        if incremental_enabled {
+            let mut maintenance = RoutineMaintenance::default();
+            pin_mut!(stream);
+            while let Some(res) = stream.next().await {
+                let res = res?;
+                let new_maintenance = Self::apply(res, metrics, machine).await?;
+                maintenance.merge(new_maintenance);
+            }
+            Ok(maintenance)
+        } else {
+            let res = Self::compact_all(stream, req.clone()).await?;
+            Self::apply(
+                FueledMergeRes {
+                    output: res.output,
+                    input: CompactionInput::Legacy,
+                    new_active_compaction: None,
+                },
+                metrics,
+                machine,
+            )
+            .await
+        }
+    }
+
     pub async fn compact_all(
         stream: impl Stream<Item = Result<FueledMergeRes<T>, anyhow::Error>>,
         req: CompactReq<T>,
@@ -439,6 +439,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
             rollups,
             active_rollup,
             active_gc,
+            compression,
             hostname,
             last_gc_req,
             leased_readers,
@@ -462,6 +463,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
         field_diffs_into_proto(ProtoStateField::Rollups, rollups, &mut writer);
         field_diffs_into_proto(ProtoStateField::ActiveRollup, active_rollup, &mut writer);
         field_diffs_into_proto(ProtoStateField::ActiveGc, active_gc, &mut writer);
+        field_diffs_into_proto(ProtoStateField::Compression, compression, &mut writer);
         field_diffs_into_proto(ProtoStateField::LeasedReaders, leased_readers, &mut writer);
         field_diffs_into_proto(
             ProtoStateField::CriticalReaders,
@@ -536,6 +538,12 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
                             |v| v.into_rust(),
                         )?
                     }
+                    ProtoStateField::Compression => field_diff_into_rust::<(), String, _, _, _, _>(
+                        diff,
+                        &mut state_diff.compression,
+                        |()| Ok(()),
+                        |v| v.into_rust(),
+                    )?,
                     ProtoStateField::ActiveRollup => {
                         field_diff_into_rust::<(), ProtoActiveRollup, _, _, _, _>(
                             diff,
@@ -974,6 +982,7 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
             last_gc_req: self.state.state.collections.last_gc_req.into_proto(),
             active_rollup: self.state.state.collections.active_rollup.into_proto(),
             active_gc: self.state.state.collections.active_gc.into_proto(),
+            compression: self.state.state.collections.compression.into_proto(),
             rollups: self
                 .state
                 .state
@@ -1069,11 +1078,13 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
             .map(|rollup| rollup.into_rust())
             .transpose()?;
         let active_gc = x.active_gc.map(|gc| gc.into_rust()).transpose()?;
+        let compression = x.compression.map(|c| c.into_rust()).transpose()?;
         let collections = StateCollections {
             version: applier_version.clone(),
             rollups,
             active_rollup,
             active_gc,
+            compression,
             last_gc_req: x.last_gc_req.into_rust()?,
             leased_readers,
             critical_readers,
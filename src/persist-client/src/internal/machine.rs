@@ -26,6 +26,7 @@ use mz_ore::fmt::FormatBuffer;
 use mz_ore::{assert_none, soft_assert_no_log};
 use mz_persist::location::{ExternalError, Indeterminate, SeqNo};
 use mz_persist::retry::Retry;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
 use semver::Version;
@@ -708,6 +709,25 @@ where
         self.applier.is_finalized()
     }
 
+    /// See [crate::PersistClient::set_compression].
+    pub fn compression(&self) -> Option<CompressionFormat> {
+        self.applier.compression()
+    }
+
+    /// See [crate::PersistClient::set_compression].
+    pub async fn set_compression(
+        &self,
+        compression: Option<CompressionFormat>,
+    ) -> RoutineMaintenance {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, (), maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.set_compression, |_seqno, _cfg, state| {
+                state.set_compression(compression)
+            })
+            .await;
+        maintenance
+    }
+
     /// See [crate::PersistClient::get_schema].
     pub fn get_schema(&self, schema_id: SchemaId) -> Option<(K::Schema, V::Schema)> {
         self.applier.get_schema(schema_id)
@@ -92,8 +92,10 @@ pub struct Metrics {
     pub pushdown: PushdownMetrics,
     /// Metrics for consolidation.
     pub consolidation: ConsolidationMetrics,
-    /// Metrics for blob caching.
-    pub blob_cache_mem: BlobMemCache,
+    /// Metrics for in-memory blob caching.
+    pub blob_cache_mem: BlobCacheMetrics,
+    /// Metrics for on-disk blob caching.
+    pub blob_cache_disk: BlobCacheMetrics,
     /// Metrics for tokio tasks.
     pub tasks: TasksMetrics,
     /// Metrics for columnar data encoding and decoding.
@@ -165,7 +167,8 @@ impl Metrics {
             pubsub_client: PubSubClientMetrics::new(registry),
             pushdown: PushdownMetrics::new(registry),
             consolidation: ConsolidationMetrics::new(registry),
-            blob_cache_mem: BlobMemCache::new(registry),
+            blob_cache_mem: BlobCacheMetrics::new(registry, "mem"),
+            blob_cache_disk: BlobCacheMetrics::new(registry, "disk"),
             tasks: TasksMetrics::new(registry),
             columnar,
             schema: SchemaMetrics::new(registry),
@@ -426,6 +429,7 @@ impl MetricsVecs {
             merge_res: self.cmd_metrics("merge_res"),
             become_tombstone: self.cmd_metrics("become_tombstone"),
             compare_and_evolve_schema: self.cmd_metrics("compare_and_evolve_schema"),
+            set_compression: self.cmd_metrics("set_compression"),
             spine_exert: self.cmd_metrics("spine_exert"),
             fetch_upper_count: registry.register(metric!(
                 name: "mz_persist_cmd_fetch_upper_count",
@@ -634,6 +638,7 @@ pub struct CmdsMetrics {
     pub(crate) merge_res: CmdMetrics,
     pub(crate) become_tombstone: CmdMetrics,
     pub(crate) compare_and_evolve_schema: CmdMetrics,
+    pub(crate) set_compression: CmdMetrics,
     pub(crate) spine_exert: CmdMetrics,
     pub(crate) fetch_upper_count: IntCounter,
 }
@@ -808,6 +813,9 @@ pub struct CompactionMetrics {
     pub(crate) parts_waited: IntCounter,
     pub(crate) fast_path_eligible: IntCounter,
     pub(crate) admin_count: IntCounter,
+    pub(crate) remote_started: IntCounter,
+    pub(crate) remote_fallback: IntCounter,
+    pub(crate) remote_failed: IntCounter,
 
     pub(crate) applied_exact_match: IntCounter,
     pub(crate) applied_subset_match: IntCounter,
@@ -914,6 +922,18 @@ impl CompactionMetrics {
                 name: "mz_persist_compaction_admin_count",
                 help: "count of compaction requests that were performed by admin tooling",
             )),
+            remote_started: registry.register(metric!(
+                name: "mz_persist_compaction_remote_started",
+                help: "count of compaction requests handed to the compaction service",
+            )),
+            remote_fallback: registry.register(metric!(
+                name: "mz_persist_compaction_remote_fallback",
+                help: "count of compaction requests run inline because the compaction service was unavailable",
+            )),
+            remote_failed: registry.register(metric!(
+                name: "mz_persist_compaction_remote_failed",
+                help: "count of compaction requests rerun inline because the compaction service failed partway through",
+            )),
             applied_exact_match: registry.register(metric!(
                 name: "mz_persist_compaction_applied_exact_match",
                 help: "count of merge results that exactly replaced a SpineBatch",
@@ -1279,6 +1299,7 @@ pub struct ShardsMetrics {
     compaction_applied: mz_ore::metrics::IntCounterVec,
     cmd_succeeded: mz_ore::metrics::IntCounterVec,
     usage_current_state_batches_bytes: mz_ore::metrics::UIntGaugeVec,
+    usage_current_state_batches_compression_bytes: mz_ore::metrics::UIntGaugeVec,
     usage_current_state_rollups_bytes: mz_ore::metrics::UIntGaugeVec,
     usage_referenced_not_current_state_bytes: mz_ore::metrics::UIntGaugeVec,
     usage_not_leaked_not_referenced_bytes: mz_ore::metrics::UIntGaugeVec,
@@ -1424,6 +1445,11 @@ impl ShardsMetrics {
                 help: "data in batches/parts referenced by current version of state",
                 var_labels: ["shard", "name"],
             )),
+            usage_current_state_batches_compression_bytes: registry.register(metric!(
+                name: "mz_persist_shard_usage_current_state_batches_compression_bytes",
+                help: "data in batches/parts referenced by current version of state by compression",
+                var_labels: ["shard", "name", "compression"],
+            )),
             usage_current_state_rollups_bytes: registry.register(metric!(
                 name: "mz_persist_shard_usage_current_state_rollups_bytes",
                 help: "data in rollups referenced by current version of state",
@@ -1604,6 +1630,9 @@ pub struct ShardMetrics {
     pub gc_seqno_held_parts: DeleteOnDropGauge<AtomicU64, Vec<String>>,
     pub gc_live_diffs: DeleteOnDropGauge<AtomicU64, Vec<String>>,
     pub usage_current_state_batches_bytes: DeleteOnDropGauge<AtomicU64, Vec<String>>,
+    usage_current_state_batches_compression_bytes: mz_ore::metrics::UIntGaugeVec,
+    usage_current_state_batches_compression_map:
+        Mutex<BTreeMap<String, DeleteOnDropGauge<AtomicU64, Vec<String>>>>,
     pub usage_current_state_rollups_bytes: DeleteOnDropGauge<AtomicU64, Vec<String>>,
     pub usage_referenced_not_current_state_bytes: DeleteOnDropGauge<AtomicU64, Vec<String>>,
     pub usage_not_leaked_not_referenced_bytes: DeleteOnDropGauge<AtomicU64, Vec<String>>,
@@ -1695,6 +1724,10 @@ impl ShardMetrics {
             usage_current_state_batches_bytes: shards_metrics
                 .usage_current_state_batches_bytes
                 .get_delete_on_drop_metric(vec![shard.clone(), name.to_string()]),
+            usage_current_state_batches_compression_bytes: shards_metrics
+                .usage_current_state_batches_compression_bytes
+                .clone(),
+            usage_current_state_batches_compression_map: Mutex::new(BTreeMap::new()),
             usage_current_state_rollups_bytes: shards_metrics
                 .usage_current_state_rollups_bytes
                 .get_delete_on_drop_metric(vec![shard.clone(), name.to_string()]),
@@ -1829,6 +1862,34 @@ impl ShardMetrics {
             value.batch_part_version_bytes.add(u64::cast_from(bytes));
         }
     }
+
+    pub(crate) fn set_usage_current_state_batches_by_compression(
+        &self,
+        by_compression: &BTreeMap<String, u64>,
+    ) {
+        let mut map = self
+            .usage_current_state_batches_compression_map
+            .lock()
+            .expect("mutex should not be poisoned");
+        // Reset compressions that no longer appear, rather than removing them,
+        // same as for the batch part versions.
+        for (compression, gauge) in map.iter() {
+            if !by_compression.contains_key(compression) {
+                gauge.set(0);
+            }
+        }
+        for (compression, bytes) in by_compression {
+            let gauge = map.entry(compression.clone()).or_insert_with(|| {
+                self.usage_current_state_batches_compression_bytes
+                    .get_delete_on_drop_metric(vec![
+                        self.shard_id.to_string(),
+                        self.name.clone(),
+                        compression.clone(),
+                    ])
+            });
+            gauge.set(*bytes);
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -2468,41 +2529,47 @@ impl ConsolidationMetrics {
 }
 
 #[derive(Debug)]
-pub struct BlobMemCache {
+pub struct BlobCacheMetrics {
     pub(crate) size_blobs: UIntGauge,
     pub(crate) size_bytes: UIntGauge,
     pub(crate) hits_blobs: IntCounter,
     pub(crate) hits_bytes: IntCounter,
     pub(crate) evictions: IntCounter,
+    pub(crate) corruptions: IntCounter,
 }
 
-impl BlobMemCache {
-    fn new(registry: &MetricsRegistry) -> Self {
-        BlobMemCache {
+impl BlobCacheMetrics {
+    fn new(registry: &MetricsRegistry, cache: &str) -> Self {
+        BlobCacheMetrics {
             size_blobs: registry.register(metric!(
                 name: "mz_persist_blob_cache_size_blobs",
                 help: "count of blobs in the cache",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             size_bytes: registry.register(metric!(
                 name: "mz_persist_blob_cache_size_bytes",
                 help: "total size of blobs in the cache",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             hits_blobs: registry.register(metric!(
                 name: "mz_persist_blob_cache_hits_blobs",
                 help: "count of blobs served via cache instead of s3",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             hits_bytes: registry.register(metric!(
                 name: "mz_persist_blob_cache_hits_bytes",
                 help: "total size of blobs served via cache instead of s3",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             evictions: registry.register(metric!(
                 name: "mz_persist_blob_cache_evictions",
                 help: "count of capacity-based cache evictions",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
+            )),
+            corruptions: registry.register(metric!(
+                name: "mz_persist_blob_cache_corruptions",
+                help: "count of cached blobs discarded because they failed an integrity check",
+                const_labels: {"cache" => cache},
             )),
         }
     }
@@ -40,6 +40,7 @@ use mz_persist::indexed::encoding::{BatchColumnarFormat, BlobTraceUpdates};
 use mz_persist::location::{Blob, SeqNo};
 use mz_persist_types::arrow::{ArrayBound, ProtoArrayData};
 use mz_persist_types::columnar::{ColumnEncoder, Schema};
+use mz_persist_types::parquet::CompressionFormat;
 use mz_persist_types::schema::{SchemaId, backward_compatible};
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::ProtoType;
@@ -57,7 +58,7 @@ use uuid::Uuid;
 use crate::critical::{CriticalReaderId, Opaque};
 use crate::error::InvalidUsage;
 use crate::internal::encoding::{
-    LazyInlineBatchPart, LazyPartStats, LazyProto, MetadataMap, parse_id,
+    LazyInlineBatchPart, LazyPartStats, LazyProto, MetadataKey, MetadataMap, parse_id,
 };
 use crate::internal::gc::GcReq;
 use crate::internal::machine::retry_external;
@@ -869,6 +870,21 @@ pub struct HollowBatchPart<T> {
     pub deprecated_schema_id: Option<SchemaId>,
 }
 
+/// The compression a part was written with, stored in [HollowBatchPart::meta].
+pub(crate) const PART_COMPRESSION: MetadataKey<CompressionFormat, String> =
+    MetadataKey::new("compression");
+
+impl<T> HollowBatchPart<T> {
+    /// The compression this part was written with, if it came from the
+    /// shard's compression policy.
+    ///
+    /// This is `None` if the part was written with the process-wide default,
+    /// or before we started recording it.
+    pub(crate) fn compression(&self) -> Option<CompressionFormat> {
+        self.meta.get(PART_COMPRESSION)
+    }
+}
+
 /// A [Batch] but with the updates themselves stored externally.
 ///
 /// [Batch]: differential_dataflow::trace::BatchReader
@@ -1328,6 +1344,12 @@ pub struct StateCollections<T> {
     pub(crate) active_rollup: Option<ActiveRollup>,
     /// The gc request that is currently being computed.
     pub(crate) active_gc: Option<ActiveGc>,
+    /// The compression that batches written to this shard should use, or
+    /// `None` to use the process-wide default.
+    ///
+    /// Parts written before this was last changed keep their compression
+    /// until compaction rewrites them.
+    pub(crate) compression: Option<CompressionFormat>,
 
     pub(crate) leased_readers: BTreeMap<LeasedReaderId, LeasedReaderState<T>>,
     pub(crate) critical_readers: BTreeMap<CriticalReaderId, CriticalReaderState<T>>,
@@ -1605,6 +1627,17 @@ where
         }
     }
 
+    pub fn set_compression(
+        &mut self,
+        compression: Option<CompressionFormat>,
+    ) -> ControlFlow<NoOpStateTransition<()>, ()> {
+        if self.compression == compression || self.is_tombstone() {
+            return Break(NoOpStateTransition(()));
+        }
+        self.compression = compression;
+        Continue(())
+    }
+
     pub fn compare_and_evolve_schema<K: Codec, V: Codec>(
         &mut self,
         expected: SchemaId,
@@ -2324,6 +2357,7 @@ where
                 rollups: BTreeMap::new(),
                 active_rollup: None,
                 active_gc: None,
+                compression: None,
                 leased_readers: BTreeMap::new(),
                 critical_readers: BTreeMap::new(),
                 writers: BTreeMap::new(),
@@ -2735,6 +2769,7 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
                     rollups,
                     active_rollup,
                     active_gc,
+                    compression,
                     leased_readers,
                     critical_readers,
                     writers,
@@ -2742,7 +2777,7 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
                     trace,
                 },
         } = self;
-        let mut s = s.serialize_struct("State", 13)?;
+        let mut s = s.serialize_struct("State", 14)?;
         let () = s.serialize_field("applier_version", &applier_version.to_string())?;
         let () = s.serialize_field("shard_id", shard_id)?;
         let () = s.serialize_field("seqno", seqno)?;
@@ -2752,6 +2787,7 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
         let () = s.serialize_field("rollups", rollups)?;
         let () = s.serialize_field("active_rollup", active_rollup)?;
         let () = s.serialize_field("active_gc", active_gc)?;
+        let () = s.serialize_field("compression", &compression.map(|c| c.to_string()))?;
         let () = s.serialize_field("leased_readers", leased_readers)?;
         let () = s.serialize_field("critical_readers", critical_readers)?;
         let () = s.serialize_field("writers", writers)?;
@@ -3123,6 +3159,7 @@ pub(crate) mod tests {
                     rollups,
                     active_rollup,
                     active_gc,
+                    compression: None,
                     leased_readers,
                     critical_readers,
                     writers,
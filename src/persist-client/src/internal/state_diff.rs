@@ -19,6 +19,7 @@ use mz_ore::assert_none;
 use mz_ore::cast::CastFrom;
 use mz_persist::location::{SeqNo, VersionedData};
 use mz_persist_types::Codec64;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_persist_types::schema::SchemaId;
 use mz_proto::TryFromProtoError;
 use timely::PartialOrder;
@@ -79,6 +80,7 @@ pub struct StateDiff<T> {
     pub(crate) rollups: Vec<StateFieldDiff<SeqNo, HollowRollup>>,
     pub(crate) active_rollup: Vec<StateFieldDiff<(), ActiveRollup>>,
     pub(crate) active_gc: Vec<StateFieldDiff<(), ActiveGc>>,
+    pub(crate) compression: Vec<StateFieldDiff<(), CompressionFormat>>,
     pub(crate) hostname: Vec<StateFieldDiff<(), String>>,
     pub(crate) last_gc_req: Vec<StateFieldDiff<(), SeqNo>>,
     pub(crate) leased_readers: Vec<StateFieldDiff<LeasedReaderId, LeasedReaderState<T>>>,
@@ -109,6 +111,7 @@ impl<T: Timestamp + Codec64> StateDiff<T> {
             rollups: Vec::default(),
             active_rollup: Vec::default(),
             active_gc: Vec::default(),
+            compression: Vec::default(),
             hostname: Vec::default(),
             last_gc_req: Vec::default(),
             leased_readers: Vec::default(),
@@ -157,6 +160,7 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
                     rollups: from_rollups,
                     active_rollup: from_active_rollup,
                     active_gc: from_active_gc,
+                    compression: from_compression,
                     leased_readers: from_leased_readers,
                     critical_readers: from_critical_readers,
                     writers: from_writers,
@@ -176,6 +180,7 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
                     rollups: to_rollups,
                     active_rollup: to_active_rollup,
                     active_gc: to_active_gc,
+                    compression: to_compression,
                     leased_readers: to_leased_readers,
                     critical_readers: to_critical_readers,
                     writers: to_writers,
@@ -205,6 +210,11 @@ impl<T: Timestamp + Lattice + Codec64> StateDiff<T> {
             to_active_gc.iter().map(|g| (&(), g)),
             &mut diffs.active_gc,
         );
+        diff_field_sorted_iter(
+            from_compression.iter().map(|c| (&(), c)),
+            to_compression.iter().map(|c| (&(), c)),
+            &mut diffs.compression,
+        );
         diff_field_sorted_iter(from_rollups.iter(), to_rollups, &mut diffs.rollups);
         diff_field_sorted_iter(
             from_leased_readers.iter(),
@@ -422,6 +432,7 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
             rollups: diff_rollups,
             active_rollup: diff_active_rollup,
             active_gc: diff_active_gc,
+            compression: diff_compression,
             hostname: diff_hostname,
             last_gc_req: diff_last_gc_req,
             leased_readers: diff_leased_readers,
@@ -462,6 +473,7 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
             rollups,
             active_rollup,
             active_gc,
+            compression,
             leased_readers,
             critical_readers,
             writers,
@@ -474,6 +486,7 @@ impl<T: Timestamp + Lattice + Codec64> State<T> {
         apply_diffs_single("last_gc_req", diff_last_gc_req, last_gc_req)?;
         apply_diffs_single_option("active_rollup", diff_active_rollup, active_rollup)?;
         apply_diffs_single_option("active_gc", diff_active_gc, active_gc)?;
+        apply_diffs_single_option("compression", diff_compression, compression)?;
         apply_diffs_map("leased_readers", diff_leased_readers, leased_readers)?;
         apply_diffs_map("critical_readers", diff_critical_readers, critical_readers)?;
         apply_diffs_map("writers", diff_writers, writers)?;
@@ -26,6 +26,7 @@ use mz_build_info::{BuildInfo, build_info};
 use mz_dyncfg::ConfigSet;
 use mz_ore::instrument;
 use mz_persist::location::{Blob, Consensus, ExternalError};
+use mz_persist_types::parquet::CompressionFormat;
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::{IntoRustIfSome, ProtoType};
@@ -57,11 +58,14 @@ pub mod async_runtime;
 pub mod batch;
 pub mod cache;
 pub mod cfg;
+pub mod compaction_service;
 pub mod cli {
     //! Persist command-line utilities
     pub mod admin;
     pub mod args;
     pub mod bench;
+    pub mod compaction;
+    pub mod export;
     pub mod inspect;
 }
 pub mod critical;
@@ -101,6 +105,7 @@ pub mod write;
 /// An implementation of the public crate interface.
 mod internal {
     pub mod apply;
+    pub mod backup;
     pub mod cache;
     pub mod compact;
     pub mod encoding;
@@ -766,6 +771,34 @@ impl PersistClient {
         Ok(res)
     }
 
+    /// Sets the compression policy of the given shard.
+    ///
+    /// Batches written to the shard after a writer has seen the new policy,
+    /// including the outputs of compaction, are compressed with `compression`,
+    /// or with the process-wide default if it is `None`. Existing parts are not
+    /// rewritten eagerly; they pick up the policy when compaction next
+    /// rewrites them.
+    pub async fn set_compression<K, V, T, D>(
+        &self,
+        shard_id: ShardId,
+        compression: Option<CompressionFormat>,
+        diagnostics: Diagnostics,
+    ) -> Result<(), InvalidUsage<T>>
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64 + Sync,
+        D: Monoid + Codec64 + Send + Sync,
+    {
+        let machine = self
+            .make_machine::<K, V, T, D>(shard_id, diagnostics)
+            .await?;
+        let gc = GarbageCollector::new(machine.clone(), Arc::clone(&self.isolated_runtime));
+        let maintenance = machine.set_compression(compression).await;
+        maintenance.start_performing(&machine, &gc);
+        Ok(())
+    }
+
     /// Check if the given shard is in a finalized state; ie. it can no longer be
     /// read, any data that was written to it is no longer accessible, and we've
     /// discarded references to that data from state.
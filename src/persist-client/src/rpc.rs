@@ -139,7 +139,7 @@ pub(crate) const PUBSUB_RECONNECT_BACKOFF: Config<Duration> = Config::new(
 ///
 /// While `max_encoding_message_size` defaults to `usize::MAX`, `max_decoding_message_size` only
 /// defaults to 4MB, so we bump it to avoid protocol errors.
-const MAX_GRPC_MESSAGE_SIZE: usize = usize::MAX;
+pub(crate) const MAX_GRPC_MESSAGE_SIZE: usize = usize::MAX;
 
 /// Top-level Trait to create a PubSubClient.
 ///
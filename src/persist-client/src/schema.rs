@@ -421,7 +421,7 @@ impl<K: Codec, V: Codec> PartMigration<K, V> {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use arrow::array::{
         Array, ArrayBuilder, StringArray, StringBuilder, StructArray, as_string_array,
     };
@@ -450,8 +450,8 @@ mod tests {
         assert!(SchemaId::try_from("nope".to_owned()).is_err());
     }
 
-    #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-    struct Strings(Vec<String>);
+    #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+    pub(crate) struct Strings(pub(crate) Vec<String>);
 
     impl Codec for Strings {
         type Schema = StringsSchema;
@@ -501,7 +501,7 @@ mod tests {
     }
 
     #[derive(Debug, Clone, Default, PartialEq)]
-    struct StringsSchema(Vec<bool>);
+    pub(crate) struct StringsSchema(pub(crate) Vec<bool>);
 
     impl Schema<Strings> for StringsSchema {
         type ArrowColumn = StructArray;
@@ -553,6 +553,7 @@ mod tests {
             StructStats {
                 len: self.0[0].len(),
                 cols: Default::default(),
+                filters: Default::default(),
             }
         }
     }
@@ -679,7 +680,7 @@ mod tests {
         assert_eq!(write1.write_schemas.id.unwrap(), SchemaId(1));
     }
 
-    fn strings(xs: &[((Strings, ()), u64, i64)]) -> Vec<Vec<&str>> {
+    pub(crate) fn strings(xs: &[((Strings, ()), u64, i64)]) -> Vec<Vec<&str>> {
         xs.iter()
             .map(|((k, _), _, _)| k.0.iter().map(|x| x.as_str()).collect())
             .collect()
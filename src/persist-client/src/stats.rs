@@ -70,6 +70,27 @@ pub(crate) const STATS_BUDGET_BYTES: Config<usize> = Config::new(
     "The budget (in bytes) of how many stats to maintain per batch part.",
 );
 
+/// Computes and stores membership filters for the key columns of each batch
+/// part, allowing point lookups to skip parts that can't contain the key.
+pub(crate) const STATS_BLOOM_FILTER_ENABLED: Config<bool> = Config::new(
+    "persist_stats_bloom_filter_enabled",
+    false,
+    "\
+    Whether to calculate and record bloom filters for the key columns of the \
+    data stored in persist, see persist_stats_bloom_filter_max_bytes \
+    (Materialize).",
+);
+
+/// The maximum size (in bytes) of each bloom filter written down per batch
+/// part. Columns with too many distinct values to fit are skipped. Filters
+/// count against `STATS_BUDGET_BYTES` and are the first stats to be trimmed,
+/// so this should be well below it.
+pub(crate) const STATS_BLOOM_FILTER_MAX_BYTES: Config<usize> = Config::new(
+    "persist_stats_bloom_filter_max_bytes",
+    512,
+    "The maximum size (in bytes) of each bloom filter to maintain per batch part.",
+);
+
 pub(crate) const STATS_UNTRIMMABLE_COLUMNS_EQUALS: Config<fn() -> String> = Config::new(
     "persist_stats_untrimmable_columns_equals",
     || {
@@ -21,7 +21,7 @@ use tracing::{error, info};
 
 use crate::cfg::{PersistConfig, USAGE_STATE_FETCH_CONCURRENCY_LIMIT};
 use crate::internal::paths::{BlobKey, BlobKeyPrefix, PartialBlobKey, WriterKey};
-use crate::internal::state::HollowBlobRef;
+use crate::internal::state::{BatchPart, HollowBlobRef, RunPart, State};
 use crate::internal::state_versions::StateVersions;
 use crate::{Metrics, PersistClient, ShardId, retry_external};
 
@@ -31,6 +31,7 @@ use crate::{Metrics, PersistClient, ShardId, retry_external};
 pub struct ShardUsageReferenced {
     pub(crate) batches_bytes: u64,
     pub(crate) rollup_bytes: u64,
+    pub(crate) current_state_batches_bytes_by_compression: BTreeMap<String, u64>,
 }
 
 impl ShardUsageReferenced {
@@ -39,9 +40,20 @@ impl ShardUsageReferenced {
         let Self {
             batches_bytes,
             rollup_bytes,
+            current_state_batches_bytes_by_compression: _,
         } = self;
         *batches_bytes + *rollup_bytes
     }
+
+    /// Byte size of the batch parts in the current state of the shard, by the
+    /// compression they were written with.
+    ///
+    /// Parts written with the process-wide default compression, which don't
+    /// record it, are reported as `default`, parts inlined in state as
+    /// `inline`, and parts of runs stored outside of state as `unknown`.
+    pub fn current_state_batches_bytes_by_compression(&self) -> &BTreeMap<String, u64> {
+        &self.current_state_batches_bytes_by_compression
+    }
 }
 
 /// The referenced blob usage for a set of shards.
@@ -189,6 +201,7 @@ impl StorageUsageClient {
                 return ShardUsageReferenced {
                     batches_bytes: 0,
                     rollup_bytes: 0,
+                    current_state_batches_bytes_by_compression: BTreeMap::new(),
                 };
             }
         };
@@ -221,9 +234,15 @@ impl StorageUsageClient {
             })
         }) {}
 
+        let current_state_batches_bytes_by_compression =
+            batches_bytes_by_compression(states_iter.state());
+        shard_metrics.set_usage_current_state_batches_by_compression(
+            &current_state_batches_bytes_by_compression,
+        );
         let referenced = ShardUsageReferenced {
             batches_bytes: u64::cast_from(batches_bytes),
             rollup_bytes: u64::cast_from(rollup_bytes),
+            current_state_batches_bytes_by_compression,
         };
 
         let current_state_sizes = states_iter.state().size_metrics();
@@ -662,6 +681,37 @@ impl From<ShardUsageCumulativeMaybeRacy<'_>> for ShardUsageAudit {
     }
 }
 
+/// Sums the batch parts in `state` by the compression they were written with.
+/// See [ShardUsageReferenced::current_state_batches_bytes_by_compression].
+fn batches_bytes_by_compression(state: &State<u64>) -> BTreeMap<String, u64> {
+    let mut ret = BTreeMap::new();
+    state.blobs().for_each(|x| {
+        let HollowBlobRef::Batch(x) = x else {
+            return;
+        };
+        for part in x.parts.iter() {
+            let compression = match part {
+                RunPart::Single(BatchPart::Hollow(x)) => x.compression(),
+                RunPart::Single(BatchPart::Inline { .. }) => {
+                    *ret.entry("inline".to_owned()).or_default() +=
+                        u64::cast_from(part.encoded_size_bytes());
+                    continue;
+                }
+                // We'd have to fetch the run to know how its parts are
+                // compressed.
+                RunPart::Many(_) => {
+                    *ret.entry("unknown".to_owned()).or_default() +=
+                        u64::cast_from(part.encoded_size_bytes());
+                    continue;
+                }
+            };
+            let compression = compression.map_or_else(|| "default".to_owned(), |c| c.to_string());
+            *ret.entry(compression).or_default() += u64::cast_from(part.encoded_size_bytes());
+        }
+    });
+    ret
+}
+
 impl std::fmt::Display for ShardUsageAudit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -705,7 +705,8 @@ where
                     .finish(desc.upper().clone())
                     .await
                     .expect("invalid usage");
-                let cfg = BatchBuilderConfig::new(&self.cfg, self.shard_id());
+                let mut cfg = BatchBuilderConfig::new(&self.cfg, self.shard_id());
+                cfg.apply_compression_policy(self.machine.compression());
                 finished
                     .flush_to_blob(
                         &cfg,
@@ -802,7 +803,8 @@ where
                         continue;
                     }
 
-                    let cfg = BatchBuilderConfig::new(&self.cfg, self.shard_id());
+                    let mut cfg = BatchBuilderConfig::new(&self.cfg, self.shard_id());
+                    cfg.apply_compression_policy(self.machine.compression());
                     // We could have a large number of inline parts (imagine the
                     // sharded persist_sink), do this flushing concurrently.
                     let flush_batches = batches
@@ -873,9 +875,13 @@ where
     /// enough that we can reasonably chunk them up: O(KB) is definitely fine,
     /// O(MB) come talk to us.
     pub fn builder(&self, lower: Antichain<T>) -> BatchBuilder<K, V, T, D> {
+        let mut compact_cfg = CompactConfig::new(&self.cfg, self.shard_id());
+        compact_cfg
+            .batch
+            .apply_compression_policy(self.machine.compression());
         Self::builder_inner(
             &self.cfg,
-            CompactConfig::new(&self.cfg, self.shard_id()),
+            compact_cfg,
             Arc::clone(&self.metrics),
             Arc::clone(&self.machine.applier.shard_metrics),
             &self.metrics.user,
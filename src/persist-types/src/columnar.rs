@@ -82,6 +82,13 @@ pub trait ColumnDecoder<T> {
     /// named the empty string. Fix this restriction if we end up with non-test
     /// code that isn't naturally a struct.
     fn stats(&self) -> StructStats;
+
+    /// Adds membership filters to `stats`, as returned by [Self::stats], for
+    /// the columns that the client expects to be used in point lookups.
+    ///
+    /// Each filter must fit in `_max_bytes`; columns with too many distinct
+    /// values to do so are skipped. The default implementation adds none.
+    fn add_filters(&self, _stats: &mut StructStats, _max_bytes: usize) {}
 }
 
 /// An encoder for values of a fixed schema
@@ -9,12 +9,13 @@
 
 //! Parquet serialization and deserialization for persist data.
 
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use std::io::Write;
 use std::sync::Arc;
 
 use arrow::array::{Array, RecordBatch};
 use arrow::datatypes::{Fields, Schema as ArrowSchema};
+use mz_proto::{RustType, TryFromProtoError};
 use parquet::arrow::ArrowWriter;
 use parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
 use parquet::basic::Encoding;
@@ -91,6 +92,64 @@ impl CompressionFormat {
             },
         }
     }
+
+    /// Parse a [`CompressionFormat`] from a string, returning an error if the
+    /// string is not valid.
+    ///
+    /// Unlike [`CompressionFormat::from_str`], this doesn't fall back to
+    /// defaults, except that a format given without a level uses the default
+    /// level for the format. Accepts the output of the [`fmt::Display`] impl.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        fn parse_level<const MIN: i32, const MAX: i32, const D: i32>(
+            name: &'static str,
+            val: Option<&str>,
+        ) -> Result<CompressionLevel<MIN, MAX, D>, String> {
+            match val {
+                None => Ok(CompressionLevel::default()),
+                Some(val) => CompressionLevel::from_str(val).map_err(|_| {
+                    format!("invalid {name} compression level {val}, must be {MIN} to {MAX}")
+                }),
+            }
+        }
+
+        let s = s.to_lowercase();
+        let (name, level) = match s.split_once('-') {
+            Some((name, level)) => (name, Some(level)),
+            None => (s.as_str(), None),
+        };
+        match (name, level) {
+            ("none", None) => Ok(CompressionFormat::None),
+            ("snappy", None) => Ok(CompressionFormat::Snappy),
+            ("lz4", None) => Ok(CompressionFormat::Lz4),
+            ("brotli", level) => Ok(CompressionFormat::Brotli(parse_level("brotli", level)?)),
+            ("zstd", level) => Ok(CompressionFormat::Zstd(parse_level("zstd", level)?)),
+            ("gzip", level) => Ok(CompressionFormat::Gzip(parse_level("gzip", level)?)),
+            _ => Err(format!("unrecognized compression format {s}")),
+        }
+    }
+}
+
+impl fmt::Display for CompressionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompressionFormat::None => f.write_str("none"),
+            CompressionFormat::Snappy => f.write_str("snappy"),
+            CompressionFormat::Lz4 => f.write_str("lz4"),
+            CompressionFormat::Brotli(level) => write!(f, "brotli-{}", level.0),
+            CompressionFormat::Zstd(level) => write!(f, "zstd-{}", level.0),
+            CompressionFormat::Gzip(level) => write!(f, "gzip-{}", level.0),
+        }
+    }
+}
+
+impl RustType<String> for CompressionFormat {
+    fn into_proto(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_proto(proto: String) -> Result<Self, TryFromProtoError> {
+        CompressionFormat::parse(&proto).map_err(TryFromProtoError::InvalidFieldError)
+    }
 }
 
 impl From<CompressionFormat> for parquet::basic::Compression {
@@ -244,4 +303,35 @@ mod tests {
             assert_eq!(CompressionFormat::from_str(s), *val);
         }
     }
+
+    #[mz_ore::test]
+    fn compression_format_strict_parsing() {
+        let cases = &[
+            ("none", Ok(CompressionFormat::None)),
+            ("Snappy", Ok(CompressionFormat::Snappy)),
+            ("lz4", Ok(CompressionFormat::Lz4)),
+            ("zstd", Ok(CompressionFormat::Zstd(Default::default()))),
+            ("zstd-22", Ok(CompressionFormat::Zstd(CompressionLevel(22)))),
+            ("GZIP-2", Ok(CompressionFormat::Gzip(CompressionLevel(2)))),
+            ("brotli", Ok(CompressionFormat::Brotli(Default::default()))),
+            ("", Err(())),
+            ("foo", Err(())),
+            ("lz4-1", Err(())),
+            ("zstd-23", Err(())),
+            ("gzip-", Err(())),
+        ];
+        for (s, expected) in cases {
+            let actual = CompressionFormat::parse(s).map_err(|_| ());
+            assert_eq!(actual, *expected, "{s}");
+        }
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn compression_format_display_roundtrip() {
+        proptest!(|(format in any::<CompressionFormat>())| {
+            assert_eq!(CompressionFormat::parse(&format.to_string()), Ok(format));
+            assert_eq!(CompressionFormat::from_proto(format.into_proto()).ok(), Some(format));
+        });
+    }
 }
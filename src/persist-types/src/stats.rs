@@ -11,6 +11,7 @@
 
 //! Aggregate statistics about data stored in persist.
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
 use anyhow::Context;
@@ -32,11 +33,13 @@ use crate::part::Part;
 use crate::stats::bytes::any_bytes_stats;
 use crate::stats::primitive::any_primitive_stats;
 
+pub mod bloom;
 pub mod bytes;
 pub mod json;
 pub mod primitive;
 pub mod structured;
 
+pub use bloom::BloomFilter;
 pub use bytes::{AtomicBytesStats, BytesStats, FixedSizeBytesStats, FixedSizeBytesStatsKind};
 pub use json::{JsonMapElementStats, JsonStats};
 pub use primitive::{
@@ -66,6 +69,7 @@ impl ColumnarStats {
         StructStats {
             len,
             cols: [("".to_owned(), col)].into_iter().collect(),
+            filters: BTreeMap::new(),
         }
     }
 
@@ -571,10 +575,72 @@ impl ColumnarStatsBuilder<&[u8]> for PrimitiveStats<Vec<u8>> {
 /// are force-kept are collectively larger than the budget.
 ///
 /// The number of bytes trimmed is returned.
+///
+/// Membership filters count against the budget too, but they are only an
+/// optimization for point lookups, so they are trimmed first, largest first.
 pub fn trim_to_budget(
     stats: &mut ProtoStructStats,
     budget: usize,
     force_keep_col: impl Fn(&str) -> bool,
+) -> usize {
+    let original_cost = stats.encoded_len();
+    if original_cost <= budget {
+        return 0;
+    }
+    trim_filters_to_budget(stats, budget);
+    let filtered_cost = stats.encoded_len();
+    original_cost.saturating_sub(filtered_cost) + trim_cols_to_budget(stats, budget, force_keep_col)
+}
+
+/// Removes membership filters from `stats` and its nested structs, largest
+/// first, until it fits within `budget` or none are left.
+fn trim_filters_to_budget(stats: &mut ProtoStructStats, budget: usize) {
+    fn collect(
+        stats: &ProtoStructStats,
+        path: &mut Vec<String>,
+        filters: &mut Vec<(usize, Vec<String>, String)>,
+    ) {
+        for (name, filter) in stats.filters.iter() {
+            filters.push((filter.encoded_len(), path.clone(), name.clone()));
+        }
+        for (name, col) in stats.cols.iter() {
+            if let Some(proto_dyn_stats::Kind::Struct(col)) = &col.kind {
+                path.push(name.clone());
+                collect(col, path, filters);
+                path.pop();
+            }
+        }
+    }
+
+    fn struct_at<'a>(
+        mut stats: &'a mut ProtoStructStats,
+        path: &[String],
+    ) -> &'a mut ProtoStructStats {
+        for name in path {
+            match stats.cols.get_mut(name).and_then(|x| x.kind.as_mut()) {
+                Some(proto_dyn_stats::Kind::Struct(col)) => stats = col,
+                _ => unreachable!("collected path to a struct"),
+            }
+        }
+        stats
+    }
+
+    let mut filters = Vec::new();
+    collect(stats, &mut Vec::new(), &mut filters);
+    // Sorted ascending, so `pop` returns the largest.
+    filters.sort();
+    while stats.encoded_len() > budget {
+        let Some((_, path, name)) = filters.pop() else {
+            break;
+        };
+        struct_at(stats, &path).filters.remove(&name);
+    }
+}
+
+fn trim_cols_to_budget(
+    stats: &mut ProtoStructStats,
+    budget: usize,
+    force_keep_col: impl Fn(&str) -> bool,
 ) -> usize {
     // No trimming necessary should be the overwhelming common case in practice.
     let original_cost = stats.encoded_len();
@@ -793,7 +859,11 @@ pub(crate) fn any_columnar_stats() -> impl Strategy<Value = ColumnarStats> {
             proptest::collection::btree_map(any::<String>(), inner, 0..3),
         )
             .prop_map(|(len, cols)| {
-                let values = ColumnStatKinds::Struct(StructStats { len, cols });
+                let values = ColumnStatKinds::Struct(StructStats {
+                    len,
+                    cols,
+                    filters: BTreeMap::new(),
+                });
                 ColumnarStats {
                     nulls: None,
                     values,
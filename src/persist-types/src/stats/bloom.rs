@@ -0,0 +1,196 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Membership sketches for point lookups.
+
+use std::fmt::{Debug, Formatter};
+
+use mz_ore::cast::{CastFrom, CastLossy};
+use mz_proto::{RustType, TryFromProtoError};
+
+use crate::stats::ProtoBloomFilter;
+
+/// The target false positive probability used to size a [BloomFilter].
+pub const BLOOM_FILTER_FPP: f64 = 0.01;
+
+/// A bloom filter over a set of byte strings.
+///
+/// A negative answer from [BloomFilter::may_contain] is definitive: the value
+/// was never inserted. A positive answer may be a false positive.
+///
+/// The hash function and bit layout are part of the durable format, so they
+/// must never change for a given encoding. Values are hashed once with
+/// seahash and the probe positions derived by double hashing (Kirsch and
+/// Mitzenmacher), splitting the 64-bit hash into two 32-bit halves.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    num_hashes: u32,
+    bits: Vec<u64>,
+}
+
+impl Debug for BloomFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BloomFilter")
+            .field("num_hashes", &self.num_hashes)
+            .field("num_bits", &self.num_bits())
+            .finish()
+    }
+}
+
+impl BloomFilter {
+    /// Returns an empty filter sized for `num_items` distinct values at
+    /// [BLOOM_FILTER_FPP], or None if that would need more than `max_bytes`.
+    pub fn with_capacity(num_items: usize, max_bytes: usize) -> Option<Self> {
+        let num_items = f64::cast_lossy(num_items.max(1));
+        // m = -n * ln(p) / ln(2)^2 and k = m / n * ln(2)
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-num_items * BLOOM_FILTER_FPP.ln() / (ln2 * ln2)).ceil();
+        let num_words = usize::cast_lossy((num_bits / 64.0).ceil()).max(1);
+        if num_words * 8 > max_bytes {
+            return None;
+        }
+        let num_bits = f64::cast_lossy(num_words * 64);
+        let num_hashes = u32::cast_lossy((num_bits / num_items * ln2).round()).clamp(1, 16);
+        Some(BloomFilter {
+            num_hashes,
+            bits: vec![0; num_words],
+        })
+    }
+
+    /// Returns the hash of `value` used by [BloomFilter::insert_hash] and
+    /// [BloomFilter::may_contain_hash].
+    pub fn hash(value: &[u8]) -> u64 {
+        seahash::hash(value)
+    }
+
+    /// Adds `value` to the filter.
+    pub fn insert(&mut self, value: &[u8]) {
+        self.insert_hash(Self::hash(value))
+    }
+
+    /// Adds a value, as hashed by [BloomFilter::hash], to the filter.
+    pub fn insert_hash(&mut self, hash: u64) {
+        for bit in self.probes(hash) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns false if `value` was definitely never inserted into the filter.
+    pub fn may_contain(&self, value: &[u8]) -> bool {
+        self.may_contain_hash(Self::hash(value))
+    }
+
+    /// Returns false if a value with this hash, as computed by
+    /// [BloomFilter::hash], was definitely never inserted into the filter.
+    pub fn may_contain_hash(&self, hash: u64) -> bool {
+        self.probes(hash)
+            .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// The size of the filter in bits.
+    pub fn num_bits(&self) -> usize {
+        self.bits.len() * 64
+    }
+
+    /// Estimates the number of distinct values inserted into the filter from
+    /// the fraction of set bits (Swamidass and Baldi), or returns None if the
+    /// filter is saturated.
+    pub fn estimated_len(&self) -> Option<usize> {
+        let num_set = self
+            .bits
+            .iter()
+            .map(|word| u64::from(word.count_ones()))
+            .sum::<u64>();
+        let num_set = f64::cast_lossy(num_set);
+        let num_bits = f64::cast_lossy(self.num_bits());
+        if num_set >= num_bits {
+            return None;
+        }
+        // n = -m / k * ln(1 - X / m)
+        let estimate = -num_bits / f64::from(self.num_hashes) * (1.0 - num_set / num_bits).ln();
+        Some(usize::cast_lossy(estimate.round()))
+    }
+
+    fn probes(&self, hash: u64) -> impl Iterator<Item = usize> {
+        let num_bits = u64::cast_from(self.num_bits());
+        let h1 = hash & u64::from(u32::MAX);
+        let h2 = hash >> 32;
+        (0..u64::from(self.num_hashes)).map(move |i| {
+            let probe = h1.wrapping_add(i.wrapping_mul(h2)) % num_bits;
+            usize::cast_from(probe)
+        })
+    }
+}
+
+impl RustType<ProtoBloomFilter> for BloomFilter {
+    fn into_proto(&self) -> ProtoBloomFilter {
+        ProtoBloomFilter {
+            num_hashes: self.num_hashes,
+            bits: self.bits.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoBloomFilter) -> Result<Self, TryFromProtoError> {
+        if proto.num_hashes == 0 || proto.bits.is_empty() {
+            return Err(TryFromProtoError::InvalidFieldError(format!(
+                "invalid bloom filter: num_hashes={} num_words={}",
+                proto.num_hashes,
+                proto.bits.len()
+            )));
+        }
+        Ok(BloomFilter {
+            num_hashes: proto.num_hashes,
+            bits: proto.bits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_proto::protobuf_roundtrip;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn bloom_filter() {
+        let values: Vec<String> = (0..1000).map(|x| format!("value-{x}")).collect();
+        let mut filter = BloomFilter::with_capacity(values.len(), usize::MAX).expect("fits");
+        for value in values.iter() {
+            filter.insert(value.as_bytes());
+        }
+
+        // No false negatives.
+        for value in values.iter() {
+            assert!(filter.may_contain(value.as_bytes()));
+        }
+
+        // Roughly the target false positive rate.
+        let false_positives = (0..10_000)
+            .filter(|x| filter.may_contain(format!("other-{x}").as_bytes()))
+            .count();
+        assert!(false_positives < 300, "{false_positives}");
+
+        // Distinct values are counted within a few percent, and duplicates
+        // don't count.
+        for value in values.iter().take(100) {
+            filter.insert(value.as_bytes());
+        }
+        let estimate = filter.estimated_len().expect("not saturated");
+        assert!((950..=1050).contains(&estimate), "{estimate}");
+        let empty = BloomFilter::with_capacity(values.len(), usize::MAX).expect("fits");
+        assert_eq!(empty.estimated_len(), Some(0));
+
+        // Too large for the limit.
+        assert_eq!(BloomFilter::with_capacity(values.len(), 64), None);
+
+        let roundtrip =
+            protobuf_roundtrip::<BloomFilter, ProtoBloomFilter>(&filter).expect("valid filter");
+        assert_eq!(roundtrip, filter);
+    }
+}
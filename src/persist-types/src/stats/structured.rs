@@ -15,6 +15,7 @@ use proptest::strategy::Strategy;
 use proptest_derive::Arbitrary;
 use serde::ser::{SerializeMap, SerializeStruct};
 
+use crate::stats::bloom::BloomFilter;
 use crate::stats::{
     ColumnStatKinds, ColumnStats, ColumnarStats, DynStats, OptionStats, ProtoStructStats,
     TrimStats, any_columnar_stats, proto_dyn_stats,
@@ -32,6 +33,13 @@ pub struct StructStats {
     /// reserves the right to prune statistics about some or all of the columns.
     #[proptest(strategy = "any_struct_stats_cols()")]
     pub cols: BTreeMap<String, ColumnarStats>,
+    /// Membership filters for some of the columns in the struct, keyed by
+    /// column name.
+    ///
+    /// These are optional and are only computed for columns that the writer
+    /// expects to be used in point lookups.
+    #[proptest(value = "BTreeMap::new()")]
+    pub filters: BTreeMap<String, BloomFilter>,
 }
 
 impl std::fmt::Debug for StructStats {
@@ -42,10 +50,16 @@ impl std::fmt::Debug for StructStats {
 
 impl serde::Serialize for StructStats {
     fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        let StructStats { len, cols } = self;
-        let mut s = s.serialize_struct("StructStats", 2)?;
+        let StructStats { len, cols, filters } = self;
+        let mut s = s.serialize_struct("StructStats", 3)?;
         let () = s.serialize_field("len", len)?;
         let () = s.serialize_field("cols", &DynStatsCols(cols))?;
+        if filters.is_empty() {
+            let () = s.skip_field("filters")?;
+        } else {
+            let filters: Vec<_> = filters.keys().collect();
+            let () = s.serialize_field("filters", &filters)?;
+        }
         s.end()
     }
 }
@@ -116,6 +130,11 @@ impl RustType<ProtoStructStats> for StructStats {
                 .iter()
                 .map(|(k, v)| (k.into_proto(), RustType::into_proto(v)))
                 .collect(),
+            filters: self
+                .filters
+                .iter()
+                .map(|(k, v)| (k.into_proto(), v.into_proto()))
+                .collect(),
         }
     }
 
@@ -124,9 +143,14 @@ impl RustType<ProtoStructStats> for StructStats {
         for (k, v) in proto.cols {
             cols.insert(k.into_rust()?, v.into_rust()?);
         }
+        let mut filters = BTreeMap::new();
+        for (k, v) in proto.filters {
+            filters.insert(k.into_rust()?, v.into_rust()?);
+        }
         Ok(StructStats {
             len: proto.len.into_rust()?,
             cols,
+            filters,
         })
     }
 }
@@ -191,7 +215,11 @@ mod tests {
                     ((*key).to_owned(), stats)
                 })
                 .collect();
-            let mut stats: ProtoStructStats = RustType::into_proto(&StructStats { len: 0, cols });
+            let mut stats: ProtoStructStats = RustType::into_proto(&StructStats {
+                len: 0,
+                cols,
+                filters: BTreeMap::new(),
+            });
             let mut budget = stats.encoded_len().next_power_of_two();
             while budget > 0 {
                 let cost_before = stats.encoded_len();
@@ -236,6 +264,7 @@ mod tests {
                     column_stats("aaaaaaaaaaaaaaaaaa", "aaaaaaaaaaaaaaaaab"),
                 ),
             ]),
+            filters: BTreeMap::new(),
         };
 
         // The threshold here is arbitrary... we just care that there's some budget where
@@ -246,6 +275,41 @@ mod tests {
         assert!(!proto_stats.cols.contains_key("bar"));
     }
 
+    // Filters count against the budget, and are trimmed before any columns.
+    #[mz_ore::test]
+    fn trim_drops_filters_first() {
+        let mut filter = BloomFilter::with_capacity(100, usize::MAX).expect("fits");
+        filter.insert(b"a");
+        let stats = StructStats {
+            len: 2,
+            cols: BTreeMap::from([(
+                "foo".to_owned(),
+                ColumnarStats {
+                    nulls: None,
+                    values: ColumnStatKinds::Bytes(BytesStats::Primitive(PrimitiveStats {
+                        lower: vec![],
+                        upper: vec![0u8; 10],
+                    })),
+                },
+            )]),
+            filters: BTreeMap::from([("foo".to_owned(), filter.clone())]),
+        };
+
+        // Everything fits in a large enough budget.
+        let mut proto_stats = RustType::into_proto(&stats);
+        assert_eq!(trim_to_budget(&mut proto_stats, 1024, |_| false), 0);
+        let trimmed: StructStats = proto_stats.into_rust().expect("valid stats");
+        assert_eq!(trimmed.filters.get("foo"), Some(&filter));
+
+        // The filter goes first, which is enough to keep the column.
+        let mut proto_stats = RustType::into_proto(&stats);
+        let trimmed = trim_to_budget(&mut proto_stats, 60, |_| false);
+        assert!(trimmed > 0);
+        assert!(proto_stats.cols.contains_key("foo"));
+        assert!(proto_stats.filters.is_empty());
+        assert!(proto_stats.encoded_len() <= 60);
+    }
+
     // Regression test for a bug found by a customer: trim_to_budget method only
     // operates on the top level struct columns. This (sorta) worked before
     // materialize#19309, but now there are always two columns at the top level, "ok" and
@@ -284,10 +348,15 @@ mod tests {
                     "ok".to_owned(),
                     ColumnarStats {
                         nulls: None,
-                        values: ColumnStatKinds::Struct(StructStats { len: 2, cols }),
+                        values: ColumnStatKinds::Struct(StructStats {
+                            len: 2,
+                            cols,
+                            filters: BTreeMap::new(),
+                        }),
                     },
                 ),
             ]),
+            filters: BTreeMap::new(),
         };
         let mut proto_stats = RustType::into_proto(&source_data_stats);
         let trimmed = trim_to_budget(&mut proto_stats, BIG, |x| {
@@ -430,7 +430,7 @@ impl Blob for AzureBlob {
 
 /// If possible we'll pre-allocate a chunk of memory in lgalloc and write into
 /// that as we read bytes off the network.
-enum PreSizedBuffer {
+pub(crate) enum PreSizedBuffer {
     Sized(MetricsRegion<u8>),
     Unknown(SegmentedBytes),
 }
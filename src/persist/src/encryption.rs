@@ -0,0 +1,456 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client-side envelope encryption of [Blob] contents.
+//!
+//! Every blob written through an [EncryptedBlob] is encrypted with AES-256-GCM
+//! under a fresh, random data key. The data key is itself encrypted ("wrapped")
+//! with a key-encryption key (KEK), whose id is recorded in the blob's header
+//! next to the wrapped data key. KEKs are never stored in blob, they are looked
+//! up by id through [BlobKeys], e.g. in the secrets of the environment.
+//!
+//! The KEK used for new blobs is chosen by [BLOB_ENCRYPTION_KEY_ID], which can
+//! be changed while running. Reads use whatever KEK a blob's header names, so
+//! rotating to a new KEK only requires keeping the old one available until no
+//! blob refers to it anymore. Blobs are never rewritten in place: old blobs
+//! are lazily re-wrapped as compaction replaces them with new ones, which are
+//! written under the current KEK.
+//!
+//! Blobs without the encryption header are passed through unchanged on read,
+//! so encryption can be turned on for an existing environment.
+//!
+//! The layout of an encrypted blob is:
+//!
+//! ```text
+//! MAGIC | kek id len (u8) | kek id | wrapped data key | nonce | ciphertext | tag
+//! ```
+//!
+//! The wrapped data key is itself `nonce | ciphertext | tag`, authenticated
+//! with the KEK id. The ciphertext of the blob is authenticated with the blob's
+//! key, so that a blob cannot be passed off as another one.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, bail};
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes, BytesMut};
+use mz_dyncfg::{Config, ConfigSet};
+use mz_ore::bytes::SegmentedBytes;
+use openssl::symm::{Cipher, decrypt_aead, encrypt_aead};
+
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+
+/// The id of the key-encryption key to encrypt newly written blobs with.
+///
+/// Empty to write blobs unencrypted. Blobs that are already encrypted can
+/// always be read, as long as their key-encryption key is available.
+pub const BLOB_ENCRYPTION_KEY_ID: Config<&'static str> = Config::new(
+    "persist_blob_encryption_key_id",
+    "",
+    "The id of the key-encryption key to encrypt newly written blobs with, or \
+    empty to write them unencrypted.",
+);
+
+/// Marks the start of an encrypted blob.
+///
+/// Neither Parquet files nor encoded protobuf messages, which is what persist
+/// writes to blob, can start with a zero byte.
+const MAGIC: &[u8; 8] = b"\0mzenc1\0";
+/// The size of AES-256 keys.
+const KEY_LEN: usize = 32;
+/// The size of AES-GCM nonces.
+const NONCE_LEN: usize = 12;
+/// The size of AES-GCM authentication tags.
+const TAG_LEN: usize = 16;
+/// The size of a wrapped data key.
+const WRAPPED_KEY_LEN: usize = NONCE_LEN + KEY_LEN + TAG_LEN;
+
+/// A source of key-encryption keys.
+#[async_trait]
+pub trait BlobKeys: Debug + Send + Sync {
+    /// Returns the contents of the key-encryption key with the given id.
+    ///
+    /// Keys must be exactly 32 bytes long.
+    async fn get(&self, id: &str) -> Result<Vec<u8>, anyhow::Error>;
+}
+
+/// A [BlobKeys] backed by a static set of keys, e.g. for tests or tools.
+#[derive(Debug, Default)]
+pub struct StaticBlobKeys {
+    keys: BTreeMap<String, Vec<u8>>,
+}
+
+impl StaticBlobKeys {
+    /// Adds the key-encryption key `key` under `id`.
+    pub fn insert(&mut self, id: impl Into<String>, key: Vec<u8>) {
+        self.keys.insert(id.into(), key);
+    }
+}
+
+#[async_trait]
+impl BlobKeys for StaticBlobKeys {
+    async fn get(&self, id: &str) -> Result<Vec<u8>, anyhow::Error> {
+        self.keys
+            .get(id)
+            .cloned()
+            .ok_or_else(|| anyhow!("unknown blob encryption key: {id}"))
+    }
+}
+
+/// A [BlobKeys] that reads key-encryption keys from secrets, where the id of a
+/// key is the id of its secret.
+#[derive(Debug)]
+pub struct SecretsBlobKeys {
+    reader: Arc<dyn mz_secrets::SecretsReader>,
+}
+
+impl SecretsBlobKeys {
+    /// Returns a new [SecretsBlobKeys] reading from `reader`.
+    pub fn new(reader: Arc<dyn mz_secrets::SecretsReader>) -> Self {
+        SecretsBlobKeys { reader }
+    }
+}
+
+#[async_trait]
+impl BlobKeys for SecretsBlobKeys {
+    async fn get(&self, id: &str) -> Result<Vec<u8>, anyhow::Error> {
+        let id = id
+            .parse()
+            .map_err(|e| anyhow!("invalid blob encryption key id {id}: {e}"))?;
+        self.reader.read(id).await
+    }
+}
+
+/// A [Blob] that encrypts the values written to the wrapped [Blob] and
+/// decrypts the ones read from it.
+///
+/// See the [module docs](self) for details.
+#[derive(Debug)]
+pub struct EncryptedBlob {
+    blob: Arc<dyn Blob>,
+    keys: Arc<dyn BlobKeys>,
+    cfg: Arc<ConfigSet>,
+    /// Key-encryption keys already fetched from `keys`. Ids are never reused
+    /// for different keys, so there is no need to invalidate these.
+    cached_keys: Mutex<BTreeMap<String, Arc<[u8; KEY_LEN]>>>,
+}
+
+impl EncryptedBlob {
+    /// Returns a new [EncryptedBlob] wrapping `blob`.
+    pub fn new(blob: Arc<dyn Blob>, keys: Arc<dyn BlobKeys>, cfg: Arc<ConfigSet>) -> Self {
+        EncryptedBlob {
+            blob,
+            keys,
+            cfg,
+            cached_keys: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    async fn key(&self, id: &str) -> Result<Arc<[u8; KEY_LEN]>, ExternalError> {
+        if let Some(key) = self.cached_keys.lock().expect("lock poisoned").get(id) {
+            return Ok(Arc::clone(key));
+        }
+        let key = self.keys.get(id).await?;
+        let key: [u8; KEY_LEN] = key.try_into().map_err(|key: Vec<u8>| {
+            Determinate::new(anyhow!(
+                "blob encryption key {id} has {} bytes, expected {KEY_LEN}",
+                key.len()
+            ))
+        })?;
+        let key = Arc::new(key);
+        self.cached_keys
+            .lock()
+            .expect("lock poisoned")
+            .insert(id.to_owned(), Arc::clone(&key));
+        Ok(key)
+    }
+
+    /// Encrypts `value`, to be stored under `key`, with a new data key wrapped
+    /// by the key-encryption key `kek_id`.
+    fn encrypt(
+        key: &str,
+        kek_id: &str,
+        kek: &[u8; KEY_LEN],
+        value: &[u8],
+    ) -> Result<Bytes, anyhow::Error> {
+        let kek_id_len = u8::try_from(kek_id.len())
+            .map_err(|_| anyhow!("blob encryption key id too long: {kek_id}"))?;
+        let mut data_key = [0; KEY_LEN];
+        openssl::rand::rand_bytes(&mut data_key)?;
+
+        let mut buf = BytesMut::with_capacity(
+            MAGIC.len() + 1 + kek_id.len() + WRAPPED_KEY_LEN + NONCE_LEN + value.len() + TAG_LEN,
+        );
+        buf.put_slice(MAGIC);
+        buf.put_u8(kek_id_len);
+        buf.put_slice(kek_id.as_bytes());
+        seal(&mut buf, kek, kek_id.as_bytes(), &data_key)?;
+        seal(&mut buf, &data_key, key.as_bytes(), value)?;
+        Ok(buf.freeze())
+    }
+
+    /// Decrypts the blob `value` stored under `key`, if it is encrypted.
+    async fn decrypt(
+        &self,
+        key: &str,
+        value: SegmentedBytes,
+    ) -> Result<SegmentedBytes, ExternalError> {
+        // Most blobs are not encrypted, so check the header before copying
+        // the value into contiguous memory. The header usually lies entirely
+        // within the first segment.
+        if !has_magic(&value) {
+            return Ok(value);
+        }
+        let value = value.into_contiguous();
+
+        let header = Header::parse(&value)
+            .map_err(|e| Determinate::new(anyhow!("invalid encrypted blob {key}: {e}")))?;
+        let kek = self.key(header.kek_id).await?;
+        let plaintext = open(&kek, header.kek_id.as_bytes(), header.wrapped_key)
+            .and_then(|data_key| {
+                let data_key: [u8; KEY_LEN] = data_key
+                    .try_into()
+                    .map_err(|_| anyhow!("invalid data key length"))?;
+                open(&data_key, key.as_bytes(), header.data)
+            })
+            .map_err(|e| Determinate::new(anyhow!("unable to decrypt blob {key}: {e}")))?;
+        Ok(SegmentedBytes::from(plaintext))
+    }
+}
+
+/// Returns whether `value` starts with [MAGIC], without copying it.
+fn has_magic(value: &SegmentedBytes) -> bool {
+    if value.len() < MAGIC.len() {
+        return false;
+    }
+    let mut expected = &MAGIC[..];
+    for segment in value.clone().into_segments() {
+        let n = expected.len().min(segment.len());
+        if segment[..n] != expected[..n] {
+            return false;
+        }
+        expected = &expected[n..];
+        if expected.is_empty() {
+            break;
+        }
+    }
+    true
+}
+
+#[async_trait]
+impl Blob for EncryptedBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        match self.blob.get(key).await? {
+            Some(value) => Ok(Some(self.decrypt(key, value).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let kek_id = BLOB_ENCRYPTION_KEY_ID.get(&self.cfg);
+        if kek_id.is_empty() {
+            return self.blob.set(key, value).await;
+        }
+        let kek = self.key(&kek_id).await?;
+        let value = Self::encrypt(key, &kek_id, &kek, &value).map_err(Determinate::new)?;
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        self.blob.delete(key).await
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
+/// The parsed parts of an encrypted blob.
+#[derive(Debug)]
+struct Header<'a> {
+    kek_id: &'a str,
+    wrapped_key: &'a [u8],
+    data: &'a [u8],
+}
+
+impl<'a> Header<'a> {
+    fn parse(value: &'a [u8]) -> Result<Self, anyhow::Error> {
+        let rest = &value[MAGIC.len()..];
+        let Some((kek_id_len, rest)) = rest.split_first() else {
+            bail!("missing key id");
+        };
+        let kek_id_len = usize::from(*kek_id_len);
+        if rest.len() < kek_id_len + WRAPPED_KEY_LEN + NONCE_LEN + TAG_LEN {
+            bail!("truncated header");
+        }
+        let (kek_id, rest) = rest.split_at(kek_id_len);
+        let kek_id = std::str::from_utf8(kek_id)?;
+        let (wrapped_key, data) = rest.split_at(WRAPPED_KEY_LEN);
+        Ok(Header {
+            kek_id,
+            wrapped_key,
+            data,
+        })
+    }
+}
+
+/// Appends `nonce | ciphertext | tag` of `plaintext` to `buf`.
+fn seal(
+    buf: &mut BytesMut,
+    key: &[u8; KEY_LEN],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<(), anyhow::Error> {
+    let mut nonce = [0; NONCE_LEN];
+    openssl::rand::rand_bytes(&mut nonce)?;
+    let mut tag = [0; TAG_LEN];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(&nonce),
+        aad,
+        plaintext,
+        &mut tag,
+    )?;
+    buf.put_slice(&nonce);
+    buf.put_slice(&ciphertext);
+    buf.put_slice(&tag);
+    Ok(())
+}
+
+/// The inverse of [seal].
+fn open(key: &[u8; KEY_LEN], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+    if sealed.len() < NONCE_LEN + TAG_LEN {
+        bail!("truncated ciphertext");
+    }
+    let (nonce, rest) = sealed.split_at(NONCE_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+    let plaintext = decrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(nonce),
+        aad,
+        ciphertext,
+        tag,
+    )?;
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_dyncfg::ConfigUpdates;
+
+    use crate::location::tests::blob_impl_test;
+    use crate::mem::{MemBlob, MemBlobConfig};
+
+    use super::*;
+
+    fn keys() -> Arc<StaticBlobKeys> {
+        let mut keys = StaticBlobKeys::default();
+        keys.insert("k1", vec![1; KEY_LEN]);
+        keys.insert("k2", vec![2; KEY_LEN]);
+        Arc::new(keys)
+    }
+
+    fn cfg(kek_id: &'static str) -> Arc<ConfigSet> {
+        let cfg = crate::cfg::all_dyn_configs(ConfigSet::default());
+        let mut updates = ConfigUpdates::default();
+        updates.add(&BLOB_ENCRYPTION_KEY_ID, kek_id);
+        updates.apply(&cfg);
+        Arc::new(cfg)
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob() -> Result<(), ExternalError> {
+        let blob: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::new(false)));
+        blob_impl_test(move |_path| {
+            let blob = Arc::clone(&blob);
+            async move { Ok(EncryptedBlob::new(blob, keys(), cfg("k1"))) }
+        })
+        .await
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob_rotation() -> Result<(), ExternalError> {
+        let mem: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::new(false)));
+        let cfg = cfg("");
+        let blob = EncryptedBlob::new(Arc::clone(&mem), keys(), Arc::clone(&cfg));
+
+        // Unencrypted blobs are readable.
+        blob.set("plain", Bytes::from_static(b"plain")).await?;
+        assert_eq!(
+            mem.get("plain").await?.map(|x| x.into_contiguous()),
+            Some(b"plain".to_vec())
+        );
+
+        let mut updates = ConfigUpdates::default();
+        updates.add(&BLOB_ENCRYPTION_KEY_ID, "k1");
+        updates.apply(&cfg);
+        blob.set("a", Bytes::from_static(b"a")).await?;
+        let raw = mem.get("a").await?.expect("present").into_contiguous();
+        assert!(raw.starts_with(MAGIC));
+
+        // Rotate to a new key: blobs under the old key stay readable.
+        let mut updates = ConfigUpdates::default();
+        updates.add(&BLOB_ENCRYPTION_KEY_ID, "k2");
+        updates.apply(&cfg);
+        blob.set("b", Bytes::from_static(b"b")).await?;
+        for (key, value) in [("plain", b"plain".as_slice()), ("a", b"a"), ("b", b"b")] {
+            assert_eq!(
+                blob.get(key).await?.map(|x| x.into_contiguous()),
+                Some(value.to_vec())
+            );
+        }
+
+        // Without the key, an encrypted blob is unreadable.
+        let mut only_k1 = StaticBlobKeys::default();
+        only_k1.insert("k1", vec![1; KEY_LEN]);
+        let blob = EncryptedBlob::new(Arc::clone(&mem), Arc::new(only_k1), Arc::clone(&cfg));
+        assert!(blob.get("a").await.is_ok());
+        assert!(blob.get("b").await.is_err());
+
+        // A blob cannot be passed off as another one.
+        let raw = mem.get("b").await?.expect("present").into_contiguous();
+        mem.set("c", Bytes::from(raw)).await?;
+        let blob = EncryptedBlob::new(Arc::clone(&mem), keys(), cfg);
+        assert!(blob.get("c").await.is_err());
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    fn magic_across_segments() {
+        let split: SegmentedBytes = vec![
+            Bytes::from_static(&MAGIC[..3]),
+            Bytes::from_static(&MAGIC[3..]),
+            Bytes::from_static(b"rest"),
+        ]
+        .into();
+        assert!(has_magic(&split));
+        let plain: SegmentedBytes = vec![
+            Bytes::from_static(b"\0mz"),
+            Bytes::from_static(b"enc2\0rest"),
+        ]
+        .into();
+        assert!(!has_magic(&plain));
+        assert!(!has_magic(&SegmentedBytes::from(MAGIC[..4].to_vec())));
+    }
+}
@@ -0,0 +1,469 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A Google Cloud Storage implementation of [Blob] storage.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use mz_dyncfg::{Config, ConfigSet};
+use mz_gcp_util::gcs::{GcsClient, GcsCredentials, GcsError, Preconditions, ResumableUpload};
+use mz_ore::bytes::SegmentedBytes;
+use mz_ore::cast::CastFrom;
+use mz_ore::metrics::MetricsRegistry;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::azure::PreSizedBuffer;
+use crate::cfg::BlobKnobs;
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+use crate::metrics::S3BlobMetrics;
+
+/// Values larger than this are written with a resumable upload instead of a
+/// single request, so that a failure partway through doesn't lose the part of
+/// the object that was already sent.
+pub(crate) const GCS_RESUMABLE_UPLOAD_THRESHOLD_BYTES: Config<usize> = Config::new(
+    "persist_gcs_resumable_upload_threshold_bytes",
+    8 * 1024 * 1024,
+    "Blobs larger than this are written to GCS with a resumable upload, sent \
+    in chunks of this size (rounded down to a multiple of 256 KiB).",
+);
+
+/// Configuration for opening a [GcsBlob].
+#[derive(Clone, Debug)]
+pub struct GcsBlobConfig {
+    // As with Azure, we only need the LgBytes metrics here, and reusing
+    // [S3BlobMetrics] saves considerable plumbing.
+    metrics: S3BlobMetrics,
+    client: GcsClient,
+    bucket: String,
+    prefix: String,
+    emulator: bool,
+    cfg: Arc<ConfigSet>,
+}
+
+impl GcsBlobConfig {
+    const EXTERNAL_TESTS_GCS_BUCKET: &'static str = "MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET";
+    const EXTERNAL_TESTS_GCS_ENDPOINT: &'static str =
+        "MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_ENDPOINT";
+
+    /// Returns a new [GcsBlobConfig] for use in production.
+    ///
+    /// Stores objects in the given bucket prepended with the (possibly empty)
+    /// prefix. Requests are authenticated with application default
+    /// credentials, unless an `endpoint` is given, which is assumed to be an
+    /// emulator such as `fake-gcs-server` and is accessed anonymously.
+    pub async fn new(
+        bucket: String,
+        prefix: String,
+        endpoint: Option<String>,
+        metrics: S3BlobMetrics,
+        knobs: Box<dyn BlobKnobs>,
+        cfg: Arc<ConfigSet>,
+    ) -> Result<Self, ExternalError> {
+        let emulator = endpoint.is_some();
+        let credentials = if emulator {
+            info!("Connecting to GCS emulator");
+            GcsCredentials::Anonymous
+        } else {
+            GcsCredentials::Default
+        };
+        // We specify a client explicitly to plumb through our timeouts.
+        let http = reqwest::ClientBuilder::new()
+            .timeout(knobs.operation_attempt_timeout())
+            .read_timeout(knobs.read_timeout())
+            .connect_timeout(knobs.connect_timeout());
+        let client = GcsClient::new(endpoint, credentials, http).await?;
+
+        Ok(GcsBlobConfig {
+            metrics,
+            client,
+            bucket,
+            prefix,
+            emulator,
+            cfg,
+        })
+    }
+
+    /// Returns a new [GcsBlobConfig] for use in unit tests.
+    pub async fn new_for_test() -> Result<Option<Self>, ExternalError> {
+        struct TestBlobKnobs;
+        impl Debug for TestBlobKnobs {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("TestBlobKnobs").finish_non_exhaustive()
+            }
+        }
+        impl BlobKnobs for TestBlobKnobs {
+            fn operation_timeout(&self) -> Duration {
+                Duration::from_secs(30)
+            }
+
+            fn operation_attempt_timeout(&self) -> Duration {
+                Duration::from_secs(10)
+            }
+
+            fn connect_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn read_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn is_cc_active(&self) -> bool {
+                false
+            }
+        }
+
+        let bucket = match std::env::var(Self::EXTERNAL_TESTS_GCS_BUCKET) {
+            Ok(bucket) => bucket,
+            Err(_) => {
+                assert!(
+                    !mz_ore::env::is_var_truthy("CI"),
+                    "CI is supposed to run this test but something has gone wrong!"
+                );
+                return Ok(None);
+            }
+        };
+        let endpoint = std::env::var(Self::EXTERNAL_TESTS_GCS_ENDPOINT)
+            .unwrap_or_else(|_| "http://localhost:40112".to_string());
+
+        let prefix = Uuid::new_v4().to_string();
+        let metrics = S3BlobMetrics::new(&MetricsRegistry::new());
+        let cfg = Arc::new(ConfigSet::default().add(&GCS_RESUMABLE_UPLOAD_THRESHOLD_BYTES));
+
+        let config = GcsBlobConfig::new(
+            bucket,
+            prefix,
+            Some(endpoint),
+            metrics,
+            Box::new(TestBlobKnobs),
+            cfg,
+        )
+        .await?;
+
+        Ok(Some(config))
+    }
+}
+
+/// Implementation of [Blob] backed by Google Cloud Storage.
+#[derive(Debug)]
+pub struct GcsBlob {
+    metrics: S3BlobMetrics,
+    client: GcsClient,
+    bucket: String,
+    prefix: String,
+    cfg: Arc<ConfigSet>,
+}
+
+impl GcsBlob {
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: GcsBlobConfig) -> Result<Self, ExternalError> {
+        if config.emulator {
+            // As with the Azure emulator, it's surprisingly annoying to create
+            // the bucket out-of-band, so we do it here.
+            if let Err(error) = config
+                .client
+                .create_bucket("materialize", &config.bucket)
+                .await
+            {
+                info!(
+                    ?error,
+                    "failed to create emulator bucket; this is expected on repeat runs"
+                );
+            }
+        }
+
+        Ok(GcsBlob {
+            metrics: config.metrics,
+            client: config.client,
+            bucket: config.bucket,
+            prefix: config.prefix,
+            cfg: config.cfg,
+        })
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        format!("{}/{}", self.prefix, key)
+    }
+
+    /// Writes `value` in chunks of (roughly) `chunk_size` bytes with a
+    /// resumable upload.
+    async fn set_resumable(
+        &self,
+        path: &str,
+        mut value: Bytes,
+        chunk_size: usize,
+    ) -> Result<(), ExternalError> {
+        let alignment = usize::cast_from(ResumableUpload::CHUNK_ALIGNMENT);
+        let chunk_size = std::cmp::max(chunk_size - chunk_size % alignment, alignment);
+        let mut upload = self
+            .client
+            .start_resumable_upload(&self.bucket, path, Preconditions::NONE)
+            .await?;
+        while value.len() > chunk_size {
+            let chunk = value.split_to(chunk_size);
+            upload.upload_chunk(chunk, false).await?;
+        }
+        upload.upload_chunk(value, true).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Blob for GcsBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let path = self.get_path(key);
+        let Some(response) = self.client.get_object(&self.bucket, &path).await? else {
+            return Ok(None);
+        };
+
+        // As in the Azure implementation, a missing or zero content length is
+        // most likely incorrect, so collect the body into segments and copy it
+        // into lgalloc afterwards.
+        let content_length = response.content_length().unwrap_or(0);
+        let mut buffer = match content_length {
+            1.. => {
+                let region = self
+                    .metrics
+                    .lgbytes
+                    .persist_gcs
+                    .new_region(usize::cast_from(content_length));
+                PreSizedBuffer::Sized(region)
+            }
+            0 => PreSizedBuffer::Unknown(SegmentedBytes::new()),
+        };
+
+        let mut body = response.bytes_stream();
+        while let Some(value) = body.next().await {
+            let value = value.map_err(GcsError::from)?;
+            match &mut buffer {
+                PreSizedBuffer::Sized(region) => region.extend_from_slice(&value),
+                PreSizedBuffer::Unknown(segments) => segments.push(value),
+            }
+        }
+
+        let lgbytes: Bytes = match buffer {
+            PreSizedBuffer::Sized(region) => region.into(),
+            PreSizedBuffer::Unknown(segments) => {
+                let mut region = self.metrics.lgbytes.persist_gcs.new_region(segments.len());
+                for segment in segments.into_segments() {
+                    region.extend_from_slice(segment.as_ref());
+                }
+                region.into()
+            }
+        };
+
+        // Report if the content-length header didn't match the number of
+        // bytes we read from the network.
+        if content_length != u64::cast_from(lgbytes.len()) {
+            self.metrics.get_invalid_resp.inc();
+        }
+
+        let mut segments = SegmentedBytes::with_capacity(1);
+        segments.push(lgbytes);
+        Ok(Some(segments))
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        let blob_key_prefix = self.get_path(key_prefix);
+        let strippable_root_prefix = format!("{}/", self.prefix);
+
+        let mut page_token = None;
+        loop {
+            self.metrics.list_objects.inc();
+            let page = self
+                .client
+                .list_objects(&self.bucket, &blob_key_prefix, false, page_token.as_deref())
+                .await?;
+
+            for object in page.items {
+                if let Some(key) = object.name.strip_prefix(&strippable_root_prefix) {
+                    f(BlobMetadata {
+                        key,
+                        size_in_bytes: object.size,
+                    });
+                }
+            }
+
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => return Ok(()),
+            }
+        }
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let path = self.get_path(key);
+        let threshold = GCS_RESUMABLE_UPLOAD_THRESHOLD_BYTES.get(&self.cfg);
+        if value.len() <= threshold {
+            self.metrics.set_single.inc();
+            self.client
+                .insert_object(&self.bucket, &path, value, Preconditions::NONE)
+                .await?;
+            Ok(())
+        } else {
+            self.metrics.set_multi_create.inc();
+            self.set_resumable(&path, value, threshold).await
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let path = self.get_path(key);
+        loop {
+            let Some(metadata) = self.client.get_object_metadata(&self.bucket, &path).await? else {
+                return Ok(None);
+            };
+            // Only delete the generation we looked at, so the size we return
+            // is the size of the object we actually deleted.
+            match self
+                .client
+                .delete_object(
+                    &self.bucket,
+                    &path,
+                    Preconditions::generation(metadata.generation),
+                )
+                .await
+            {
+                Ok(true) => return Ok(Some(usize::cast_from(metadata.size))),
+                Ok(false) => return Ok(None),
+                // The object was replaced in between, try again.
+                Err(GcsError::PreconditionFailed) => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        let path = self.get_path(key);
+        if self
+            .client
+            .get_object_metadata(&self.bucket, &path)
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        // If the bucket has object versioning enabled, the deleted object is
+        // still around as a noncurrent version. Find the latest one.
+        let mut latest = None;
+        let mut page_token = None;
+        loop {
+            let page = self
+                .client
+                .list_objects(&self.bucket, &path, true, page_token.as_deref())
+                .await?;
+            for object in page.items {
+                // We need to check that any versions we're looking at have the
+                // right name, not just a name with our name as a prefix.
+                if object.name == path && latest.is_none_or(|g| object.generation > g) {
+                    latest = Some(object.generation);
+                }
+            }
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        let Some(generation) = latest else {
+            return Err(Determinate::new(anyhow!(
+                "gcs blob error: unable to restore non-existent key {key}"
+            ))
+            .into());
+        };
+
+        // Copy the noncurrent version back, unless something else has written
+        // the key in the meantime.
+        match self
+            .client
+            .copy_object(
+                &self.bucket,
+                &path,
+                Some(generation),
+                &path,
+                Preconditions::does_not_exist(),
+            )
+            .await
+        {
+            Ok(_) | Err(GcsError::PreconditionFailed) => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::info;
+
+    use crate::location::tests::blob_impl_test;
+
+    use super::*;
+
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    async fn gcs_blob() -> Result<(), ExternalError> {
+        let config = match GcsBlobConfig::new_for_test().await? {
+            Some(config) => config,
+            None => {
+                info!(
+                    "{} env not set: skipping test that uses external service",
+                    GcsBlobConfig::EXTERNAL_TESTS_GCS_BUCKET
+                );
+                return Ok(());
+            }
+        };
+
+        blob_impl_test(move |path| {
+            let config = config.clone();
+            let path = path.to_owned();
+            async move {
+                let config = GcsBlobConfig {
+                    prefix: format!("{}/{}", config.prefix, path),
+                    ..config
+                };
+                GcsBlob::open(config).await
+            }
+        })
+        .await
+    }
+
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    async fn gcs_blob_resumable_set() -> Result<(), ExternalError> {
+        let config = match GcsBlobConfig::new_for_test().await? {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        let mut updates = mz_dyncfg::ConfigUpdates::default();
+        updates.add(&GCS_RESUMABLE_UPLOAD_THRESHOLD_BYTES, 256 * 1024);
+        updates.apply(&config.cfg);
+        let blob = GcsBlob::open(config).await?;
+
+        // Large enough to be sent as several chunks, with a partial last one.
+        let value: Vec<u8> = (0..(3 * 256 * 1024 + 17))
+            .map(|i| u8::try_from(i % 251).expect("fits"))
+            .collect();
+        blob.set("resumable", Bytes::from(value.clone())).await?;
+        let fetched = blob.get("resumable").await?.expect("blob exists");
+        assert_eq!(fetched.into_contiguous(), value);
+        assert_eq!(blob.delete("resumable").await?, Some(value.len()));
+        Ok(())
+    }
+}
@@ -19,10 +19,12 @@
 
 pub mod azure;
 pub mod cfg;
+pub mod encryption;
 pub mod error;
 pub mod file;
 #[cfg(feature = "foundationdb")]
 pub mod foundationdb;
+pub mod gcs;
 pub mod generated;
 pub mod indexed;
 pub mod intercept;
@@ -32,6 +34,7 @@ pub mod metrics;
 pub mod postgres;
 pub mod retry;
 pub mod s3;
+pub mod sqlite;
 #[cfg(feature = "turmoil")]
 pub mod turmoil;
 pub mod unreliable;
@@ -19,6 +19,7 @@ use async_trait::async_trait;
 use azure_core::StatusCode;
 use bytes::Bytes;
 use futures_util::Stream;
+use mz_gcp_util::gcs::GcsError;
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::u64_to_usize;
 use mz_postgres_client::error::PostgresError;
@@ -337,6 +338,23 @@ impl From<azure_core::Error> for ExternalError {
     }
 }
 
+impl From<GcsError> for ExternalError {
+    fn from(value: GcsError) -> Self {
+        // As with Azure, only include the codes that we know are safe to
+        // treat as determinate.
+        match value.status() {
+            Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => {
+                ExternalError::Determinate(Determinate {
+                    inner: anyhow!(value),
+                })
+            }
+            _ => ExternalError::Indeterminate(Indeterminate {
+                inner: anyhow!(value),
+            }),
+        }
+    }
+}
+
 impl From<deadpool_postgres::PoolError> for ExternalError {
     fn from(x: deadpool_postgres::PoolError) -> Self {
         match x {
@@ -0,0 +1,318 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Implementation of [Consensus] backed by an embedded SQLite database.
+//!
+//! This is intended for single-node deployments, where it (together with a
+//! file-backed [crate::location::Blob]) removes the need for an external
+//! metadata database. The database is opened in WAL mode with
+//! `synchronous = FULL`, so every committed compare-and-set is fsync'd before
+//! it returns.
+//!
+//! SQLite connections are synchronous, so all operations run on the blocking
+//! thread pool. Writes take the database lock up front (`BEGIN IMMEDIATE`),
+//! which linearizes them with writes from any other process sharing the file.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_stream::try_stream;
+use async_trait::async_trait;
+use bytes::Bytes;
+use mz_ore::url::SensitiveUrl;
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
+
+use crate::error::Error;
+use crate::location::{CaSResult, Consensus, ExternalError, ResultStream, SeqNo, VersionedData};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS consensus (
+    shard TEXT NOT NULL,
+    sequence_number INTEGER NOT NULL,
+    data BLOB NOT NULL,
+    PRIMARY KEY(shard, sequence_number)
+) WITHOUT ROWID
+";
+
+/// How long to wait for another connection to release the database lock
+/// before failing an operation.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl From<rusqlite::Error> for ExternalError {
+    fn from(x: rusqlite::Error) -> Self {
+        ExternalError::from(anyhow::Error::new(x))
+    }
+}
+
+/// Configuration to open an SQLite backed implementation of [Consensus].
+#[derive(Clone, Debug)]
+pub struct SqliteConsensusConfig {
+    path: PathBuf,
+}
+
+impl SqliteConsensusConfig {
+    /// Returns a new [SqliteConsensusConfig] for the database file in the
+    /// path of a `sqlite://` url, e.g. `sqlite:///var/lib/mz/consensus.db`.
+    pub fn new(url: &SensitiveUrl) -> Result<Self, Error> {
+        let path = url.path();
+        if path.is_empty() {
+            return Err(Error::from(format!(
+                "sqlite consensus url must include a path: {}",
+                url.as_str()
+            )));
+        }
+        Ok(SqliteConsensusConfig {
+            path: PathBuf::from(path),
+        })
+    }
+}
+
+/// Implementation of [Consensus] over an SQLite database file.
+#[derive(Debug)]
+pub struct SqliteConsensus {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteConsensus {
+    /// Open an SQLite [Consensus] instance with `config`, creating the
+    /// database file if it doesn't exist.
+    pub async fn open(config: SqliteConsensusConfig) -> Result<Self, ExternalError> {
+        let path = config.path;
+        let conn = mz_ore::task::spawn_blocking(
+            || "persist::sqlite::open",
+            move || {
+                let conn = Connection::open(&path)?;
+                conn.busy_timeout(BUSY_TIMEOUT)?;
+                let journal_mode: String =
+                    conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get(0))?;
+                if !journal_mode.eq_ignore_ascii_case("wal") {
+                    return Err(ExternalError::from(anyhow!(
+                        "unable to enable WAL mode for {}: {}",
+                        path.display(),
+                        journal_mode
+                    )));
+                }
+                conn.pragma_update(None, "synchronous", "FULL")?;
+                conn.execute_batch(SCHEMA)?;
+                Ok::<_, ExternalError>(conn)
+            },
+        )
+        .await?;
+        Ok(SqliteConsensus {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Runs `f` with exclusive access to the connection on the blocking
+    /// thread pool.
+    async fn run<R, F>(&self, name: &'static str, f: F) -> Result<R, ExternalError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<R, ExternalError> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        mz_ore::task::spawn_blocking(
+            || name,
+            move || {
+                let mut conn = conn.lock().expect("lock poisoned");
+                f(&mut conn)
+            },
+        )
+        .await
+    }
+}
+
+fn seqno_to_sql(seqno: SeqNo) -> Result<i64, ExternalError> {
+    // We can only represent sequence numbers in the range [0, i64::MAX].
+    i64::try_from(seqno.0).map_err(|_| {
+        ExternalError::from(anyhow!(
+            "sequence numbers must fit within [0, i64::MAX], received: {:?}",
+            seqno
+        ))
+    })
+}
+
+fn seqno_from_sql(seqno: i64) -> Result<SeqNo, ExternalError> {
+    u64::try_from(seqno)
+        .map(SeqNo)
+        .map_err(|_| ExternalError::from(anyhow!("invalid sequence number: {seqno}")))
+}
+
+/// Returns the latest sequence number for `key`, if any.
+fn head_seqno(conn: &Connection, key: &str) -> Result<Option<SeqNo>, ExternalError> {
+    let seqno: Option<i64> = conn.query_row(
+        "SELECT MAX(sequence_number) FROM consensus WHERE shard = ?1",
+        params![key],
+        |row| row.get(0),
+    )?;
+    seqno.map(seqno_from_sql).transpose()
+}
+
+#[async_trait]
+impl Consensus for SqliteConsensus {
+    fn list_keys(&self) -> ResultStream<'_, String> {
+        Box::pin(try_stream! {
+            let keys = self
+                .run("persist::sqlite::list_keys", |conn| {
+                    let mut statement = conn.prepare_cached("SELECT DISTINCT shard FROM consensus")?;
+                    let keys = statement
+                        .query_map([], |row| row.get(0))?
+                        .collect::<Result<Vec<String>, _>>()?;
+                    Ok(keys)
+                })
+                .await?;
+            for key in keys {
+                yield key;
+            }
+        })
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run("persist::sqlite::head", move |conn| {
+            let mut statement = conn.prepare_cached(
+                "SELECT sequence_number, data FROM consensus
+                 WHERE shard = ?1 ORDER BY sequence_number DESC LIMIT 1",
+            )?;
+            let row = statement
+                .query_row(params![key], |row| {
+                    Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+                })
+                .optional()?;
+            row.map(|(seqno, data)| {
+                Ok(VersionedData {
+                    seqno: seqno_from_sql(seqno)?,
+                    data: Bytes::from(data),
+                })
+            })
+            .transpose()
+        })
+        .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        let key = key.to_owned();
+        let expected = new.seqno.previous();
+        let seqno = seqno_to_sql(new.seqno)?;
+        self.run("persist::sqlite::compare_and_set", move |conn| {
+            let txn = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+            if head_seqno(&txn, &key)? != expected {
+                return Ok(CaSResult::ExpectationMismatch);
+            }
+            txn.execute(
+                "INSERT INTO consensus (shard, sequence_number, data) VALUES (?1, ?2, ?3)",
+                params![key, seqno, new.data.as_ref()],
+            )?;
+            txn.commit()?;
+            Ok(CaSResult::Committed)
+        })
+        .await
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        let from = seqno_to_sql(from)?;
+        let Ok(limit) = i64::try_from(limit) else {
+            return Err(ExternalError::from(anyhow!(
+                "limit must be [0, i64::MAX]. was: {:?}",
+                limit
+            )));
+        };
+        self.run("persist::sqlite::scan", move |conn| {
+            let mut statement = conn.prepare_cached(
+                "SELECT sequence_number, data FROM consensus
+                 WHERE shard = ?1 AND sequence_number >= ?2
+                 ORDER BY sequence_number ASC LIMIT ?3",
+            )?;
+            let rows = statement.query_map(params![key, from, limit], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+            })?;
+            let mut results = Vec::new();
+            for row in rows {
+                let (seqno, data) = row?;
+                results.push(VersionedData {
+                    seqno: seqno_from_sql(seqno)?,
+                    data: Bytes::from(data),
+                });
+            }
+            Ok(results)
+        })
+        .await
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<Option<usize>, ExternalError> {
+        let key = key.to_owned();
+        let until = seqno_to_sql(seqno)?;
+        self.run("persist::sqlite::truncate", move |conn| {
+            let txn = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+            if head_seqno(&txn, &key)?.map_or(true, |head| head < seqno) {
+                return Err(ExternalError::from(anyhow!(
+                    "upper bound too high for truncate: {:?}",
+                    seqno
+                )));
+            }
+            let deleted = txn.execute(
+                "DELETE FROM consensus WHERE shard = ?1 AND sequence_number < ?2",
+                params![key, until],
+            )?;
+            txn.commit()?;
+            Ok(Some(deleted))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::location::tests::consensus_impl_test;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `sqlite3_open_v2`
+    async fn sqlite_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let url = format!(
+            "sqlite://{}",
+            temp_dir.path().join("consensus.db").display()
+        );
+        let config = SqliteConsensusConfig::new(&url.parse().expect("valid url"))?;
+
+        consensus_impl_test(|| SqliteConsensus::open(config.clone())).await?;
+
+        // Data survives reopening the database.
+        let key = "durable".to_owned();
+        let state = VersionedData {
+            seqno: SeqNo(0),
+            data: Bytes::from("abc"),
+        };
+        {
+            let consensus = SqliteConsensus::open(config.clone()).await?;
+            assert_eq!(
+                consensus.compare_and_set(&key, state.clone()).await,
+                Ok(CaSResult::Committed),
+            );
+        }
+        let consensus = SqliteConsensus::open(config).await?;
+        assert_eq!(consensus.head(&key).await, Ok(Some(state)));
+
+        Ok(())
+    }
+}
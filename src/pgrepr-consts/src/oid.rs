@@ -792,3 +792,10 @@ pub const VIEW_MZ_MCP_DATA_PRODUCT_DETAILS_OID: u32 = 17071;
 pub const VIEW_MZ_BUILTIN_MATERIALIZED_VIEWS_OID: u32 = 17072;
 pub const FUNC_PARSE_CATALOG_CREATE_SQL_OID: u32 = 17073;
 pub const FUNC_REDACT_SQL_OID: u32 = 17074;
+pub const FUNC_TUMBLE_OID: u32 = 17075;
+pub const FUNC_HOP_OID: u32 = 17076;
+pub const FUNC_SESSION_OID: u32 = 17077;
+pub const FUNC_MZ_SESSION_WINDOW_START_TS_OID: u32 = 17078;
+pub const FUNC_MZ_SESSION_WINDOW_START_TSTZ_OID: u32 = 17079;
+pub const FUNC_MZ_SESSION_WINDOW_END_TS_OID: u32 = 17080;
+pub const FUNC_MZ_SESSION_WINDOW_END_TSTZ_OID: u32 = 17081;
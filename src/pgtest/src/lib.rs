@@ -296,6 +296,13 @@ impl PgConn {
                             parameters: body.parameters().collect().unwrap(),
                         })?,
                     ),
+                    Message::NotificationResponse(body) => (
+                        "NotificationResponse",
+                        serde_json::to_string(&NotificationResponse {
+                            channel: body.channel()?.to_string(),
+                            payload: body.message()?.to_string(),
+                        })?,
+                    ),
                     Message::ParameterStatus(_) => continue,
                     Message::NoData => ("NoData", "".to_string()),
                     Message::EmptyQueryResponse => ("EmptyQueryResponse", "".to_string()),
@@ -458,6 +465,12 @@ pub struct CommandComplete {
     pub tag: String,
 }
 
+#[derive(Serialize)]
+pub struct NotificationResponse {
+    pub channel: String,
+    pub payload: String,
+}
+
 #[derive(Serialize)]
 pub struct ErrorResponse {
     pub fields: Vec<ErrorField>,
@@ -282,6 +282,7 @@ impl Codec {
             BackendMessage::ParameterStatus(_, _) => b'S',
             BackendMessage::PortalSuspended => b's',
             BackendMessage::BackendKeyData { .. } => b'K',
+            BackendMessage::NotificationResponse { .. } => b'A',
             BackendMessage::ParameterDescription(_) => b't',
             BackendMessage::ParseComplete => b'1',
             BackendMessage::BindComplete => b'2',
@@ -442,6 +443,15 @@ impl Codec {
                 dst.put_u32(conn_id);
                 dst.put_u32(secret_key);
             }
+            BackendMessage::NotificationResponse {
+                process_id,
+                channel,
+                payload,
+            } => {
+                dst.put_u32(process_id);
+                dst.put_string(&channel);
+                dst.put_string(&payload);
+            }
             BackendMessage::ParameterDescription(params) => {
                 if params.len() > usize::try_from(i16::MAX).expect("i16::MAX is positive") {
                     return Err(io::Error::new(
@@ -36,6 +36,11 @@ pub enum BackendMessage {
         conn_id: u32,
         secret_key: u32,
     },
+    NotificationResponse {
+        process_id: u32,
+        channel: String,
+        payload: String,
+    },
     ParameterDescription(Vec<mz_pgrepr::Type>),
     PortalSuspended,
     NoData,
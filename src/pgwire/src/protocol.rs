@@ -22,8 +22,8 @@ use futures::future::{BoxFuture, FutureExt, pending};
 use itertools::Itertools;
 use mz_adapter::client::RecordFirstRowStream;
 use mz_adapter::session::{
-    EndTransactionAction, InProgressRows, LifecycleTimestamps, PortalRefMut, PortalState, Session,
-    SessionConfig, TransactionStatus,
+    EndTransactionAction, InProgressRows, LifecycleTimestamps, Notification, PortalRefMut,
+    PortalState, Session, SessionConfig, TransactionStatus,
 };
 use mz_adapter::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use mz_adapter::{
@@ -63,6 +63,7 @@ use mz_sql::session::vars::VarInput;
 use postgres::error::SqlState;
 use tokio::io::{self, AsyncRead, AsyncWrite};
 use tokio::select;
+use tokio::sync::mpsc;
 use tokio::time::{self};
 use tokio_metrics::TaskMetrics;
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -580,9 +581,15 @@ where
     conn.send_all(buf).await?;
     conn.flush().await?;
 
+    let notifications_rx = adapter_client
+        .session()
+        .take_notification_receiver()
+        .expect("notification receiver taken only once");
+
     let machine = StateMachine {
         conn,
         adapter_client,
+        notifications_rx,
         txn_needs_commit: false,
         tokio_metrics_intervals,
     };
@@ -818,6 +825,8 @@ where
 {
     conn: &'a mut FramedConn<A>,
     adapter_client: mz_adapter::SessionClient,
+    /// Asynchronous notifications for channels the session is listening on.
+    notifications_rx: mpsc::UnboundedReceiver<Notification>,
     txn_needs_commit: bool,
     tokio_metrics_intervals: I,
 }
@@ -869,6 +878,12 @@ where
             .next()
             .expect("infinite iterator");
 
+        // Like PostgreSQL, only deliver notifications between transactions.
+        let deliver_notifications = matches!(
+            self.adapter_client.session().transaction(),
+            TransactionStatus::Default
+        );
+
         // Handle timeouts first so we don't execute any statements when there's a pending timeout.
         let message = select! {
             biased;
@@ -893,6 +908,17 @@ where
                 return error_state;
             },
             // `recv()` is cancel-safe as per it's docs.
+            Some(notification) = self.notifications_rx.recv(), if deliver_notifications => {
+                self.send(BackendMessage::NotificationResponse {
+                    process_id: notification.conn_id.unhandled(),
+                    channel: notification.channel,
+                    payload: notification.payload,
+                })
+                .await?;
+                self.conn.flush().await?;
+                return Ok(State::Ready);
+            },
+            // `recv()` is cancel-safe as per it's docs.
             message = self.conn.recv() => message?,
         };
 
@@ -2267,6 +2293,7 @@ where
             | ExecuteResponse::AlteredSystemConfiguration
             | ExecuteResponse::CreatedCluster { .. }
             | ExecuteResponse::CreatedClusterReplica { .. }
+            | ExecuteResponse::CreatedChannel
             | ExecuteResponse::CreatedConnection { .. }
             | ExecuteResponse::CreatedDatabase { .. }
             | ExecuteResponse::CreatedIndex { .. }
@@ -2280,6 +2307,8 @@ where
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
             | ExecuteResponse::CreatedType
+            | ExecuteResponse::CreatedFunction
+            | ExecuteResponse::CreatedSequence
             | ExecuteResponse::CreatedView { .. }
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::CreatedNetworkPolicy
@@ -2288,18 +2317,23 @@ where
             | ExecuteResponse::Deleted(..)
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DiscardedTemp
+            | ExecuteResponse::DroppedChannel
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
             | ExecuteResponse::Copied(..)
+            | ExecuteResponse::Listened
+            | ExecuteResponse::Merged(..)
+            | ExecuteResponse::Notified
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
             | ExecuteResponse::RevokedPrivilege
             | ExecuteResponse::RevokedRole
             | ExecuteResponse::StartedTransaction { .. }
+            | ExecuteResponse::Unlistened
             | ExecuteResponse::Updated(..)
             | ExecuteResponse::ValidatedConnection => {
                 command_complete!()
@@ -113,6 +113,10 @@ optimizer_feature_flags!({
     enable_variadic_left_join_lowering: bool,
     // Enable cardinality estimation
     enable_cardinality_estimates: bool,
+    // Enable cost-based join ordering using collection statistics.
+    enable_cost_based_join_ordering: bool,
+    // Lower outer equijoins to a native outer join operator.
+    enable_native_outer_join: bool,
     // An exclusive upper bound on the number of results we may return from a
     // Persist fast-path peek. Required by the `create_fast_path_plan` call in
     // `peek::Optimizer`.
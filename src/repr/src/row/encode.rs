@@ -11,6 +11,7 @@
 //!
 //! See row.proto for details.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::ops::AddAssign;
 use std::sync::Arc;
@@ -36,8 +37,8 @@ use mz_persist_types::Codec;
 use mz_persist_types::arrow::ArrayOrd;
 use mz_persist_types::columnar::{ColumnDecoder, ColumnEncoder, FixedSizeCodec, Schema};
 use mz_persist_types::stats::{
-    ColumnNullStats, ColumnStatKinds, ColumnarStats, ColumnarStatsBuilder, FixedSizeBytesStatsKind,
-    OptionStats, PrimitiveStats, StructStats,
+    BloomFilter, ColumnNullStats, ColumnStatKinds, ColumnarStats, ColumnarStatsBuilder,
+    FixedSizeBytesStatsKind, OptionStats, PrimitiveStats, StructStats,
 };
 use mz_proto::chrono::ProtoNaiveTime;
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
@@ -58,7 +59,10 @@ use crate::row::{
     ProtoArray, ProtoArrayDimension, ProtoDatum, ProtoDatumOther, ProtoDict, ProtoDictElement,
     ProtoNumeric, ProtoRange, ProtoRangeInner, ProtoRow,
 };
-use crate::stats::{fixed_stats_from_column, numeric_stats_from_column, stats_for_json};
+use crate::stats::{
+    filter_key, filter_key_supported, fixed_stats_from_column, numeric_stats_from_column,
+    stats_for_json,
+};
 use crate::{Datum, ProtoRelationDesc, RelationDesc, Row, RowPacker, SqlScalarType, Timestamp};
 
 // TODO(parkmycar): Benchmark the difference between `FixedSizeBinaryArray` and `BinaryArray`.
@@ -1285,6 +1289,9 @@ pub struct RowColumnarDecoder {
     /// The null buffer for this row, if present. (At time of writing, all rows are assumed to be
     /// logically nullable.)
     nullability: Option<NullBuffer>,
+    /// Indexes into `decoders` of the key columns that support membership filters, along with
+    /// their types.
+    filter_cols: Vec<(usize, SqlScalarType)>,
 }
 
 /// Merge the provided null buffer with the existing array's null buffer, if any.
@@ -1340,10 +1347,25 @@ impl RowColumnarDecoder {
             decoders.push((col_name.as_str().into(), null_count, decoder));
         }
 
+        // Point lookups are most likely on the columns of a key, so those are
+        // the ones we build filters for.
+        let typ = desc.typ();
+        let filter_cols = typ
+            .keys
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter(|idx| filter_key_supported(&typ.column_types[*idx].scalar_type))
+            .map(|idx| (idx, typ.column_types[idx].scalar_type.clone()))
+            .collect();
+
         Ok(RowColumnarDecoder {
             len: col.len(),
             decoders,
             nullability: col.logical_nulls(),
+            filter_cols,
         })
     }
 
@@ -1401,6 +1423,37 @@ impl ColumnDecoder<Row> for RowColumnarDecoder {
                     (name, stats)
                 })
                 .collect(),
+            filters: BTreeMap::new(),
+        }
+    }
+
+    fn add_filters(&self, stats: &mut StructStats, max_bytes: usize) {
+        let mut row = Row::default();
+        'cols: for (col_idx, scalar_type) in &self.filter_cols {
+            let (name, _null_count, decoder) = &self.decoders[*col_idx];
+            let mut hashes = BTreeSet::new();
+            for idx in 0..self.len {
+                if self.is_null(idx) {
+                    continue;
+                }
+                decoder.get(idx, &mut row.packer());
+                let Some(key) = filter_key(row.unpack_first(), scalar_type) else {
+                    continue;
+                };
+                hashes.insert(BloomFilter::hash(&key));
+                // Every value takes more than a byte of filter, so there's no
+                // point in continuing once we've seen this many.
+                if hashes.len() > max_bytes {
+                    continue 'cols;
+                }
+            }
+            let Some(mut filter) = BloomFilter::with_capacity(hashes.len(), max_bytes) else {
+                continue;
+            };
+            for hash in hashes {
+                filter.insert_hash(hash);
+            }
+            stats.filters.insert(name.to_string(), filter);
         }
     }
 }
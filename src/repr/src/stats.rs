@@ -96,6 +96,51 @@ pub fn fixed_stats_from_column(
     .into()
 }
 
+/// Returns whether columns of type `typ` support membership filters, see
+/// [`filter_key`].
+pub fn filter_key_supported(typ: &SqlScalarType) -> bool {
+    matches!(
+        typ,
+        SqlScalarType::Int16
+            | SqlScalarType::Int32
+            | SqlScalarType::Int64
+            | SqlScalarType::UInt16
+            | SqlScalarType::UInt32
+            | SqlScalarType::UInt64
+            | SqlScalarType::String
+            | SqlScalarType::VarChar { .. }
+            | SqlScalarType::Bytes
+            | SqlScalarType::Uuid
+    )
+}
+
+/// Returns the bytes recorded in a membership filter for `datum`, a value of a
+/// column with type `typ`.
+///
+/// Returns `None` for nulls, which never compare equal to anything, and for
+/// types that don't support filters. This is part of the durable format of
+/// persist stats: two datums that are equal must map to the same bytes, so
+/// only types where that is easy to guarantee are supported. Integers are
+/// widened so that the encoding doesn't depend on the exact width of a literal.
+pub fn filter_key(datum: Datum, typ: &SqlScalarType) -> Option<Vec<u8>> {
+    if !filter_key_supported(typ) {
+        return None;
+    }
+    let key = match datum {
+        Datum::Int16(x) => i64::from(x).to_le_bytes().to_vec(),
+        Datum::Int32(x) => i64::from(x).to_le_bytes().to_vec(),
+        Datum::Int64(x) => x.to_le_bytes().to_vec(),
+        Datum::UInt16(x) => u64::from(x).to_le_bytes().to_vec(),
+        Datum::UInt32(x) => u64::from(x).to_le_bytes().to_vec(),
+        Datum::UInt64(x) => x.to_le_bytes().to_vec(),
+        Datum::String(x) => x.as_bytes().to_vec(),
+        Datum::Bytes(x) => x.to_vec(),
+        Datum::Uuid(x) => x.as_bytes().to_vec(),
+        _ => return None,
+    };
+    Some(key)
+}
+
 /// Returns a `(lower, upper)` bound from the provided [`ColumnStatKinds`], if applicable.
 pub fn col_values<'a>(
     typ: &SqlScalarType,
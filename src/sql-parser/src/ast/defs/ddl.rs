@@ -25,7 +25,8 @@ use std::fmt;
 
 use crate::ast::display::{self, AstDisplay, AstFormatter, WithOptionName};
 use crate::ast::{
-    AstInfo, ColumnName, Expr, Ident, OrderByExpr, UnresolvedItemName, Version, WithOptionValue,
+    AstInfo, ColumnName, Expr, Ident, OrderByExpr, SequenceOption, UnresolvedItemName, Version,
+    WithOptionValue,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -36,6 +37,8 @@ pub enum MaterializedViewOptionName {
     RetainHistory,
     /// The `REFRESH [=] ...` option.
     Refresh,
+    /// The `STORAGE COMPRESSION [=] <format>` option.
+    StorageCompression,
 }
 
 impl AstDisplay for MaterializedViewOptionName {
@@ -45,6 +48,7 @@ impl AstDisplay for MaterializedViewOptionName {
             MaterializedViewOptionName::PartitionBy => f.write_str("PARTITION BY"),
             MaterializedViewOptionName::RetainHistory => f.write_str("RETAIN HISTORY"),
             MaterializedViewOptionName::Refresh => f.write_str("REFRESH"),
+            MaterializedViewOptionName::StorageCompression => f.write_str("STORAGE COMPRESSION"),
         }
     }
 }
@@ -60,7 +64,8 @@ impl WithOptionName for MaterializedViewOptionName {
             MaterializedViewOptionName::AssertNotNull
             | MaterializedViewOptionName::PartitionBy
             | MaterializedViewOptionName::RetainHistory
-            | MaterializedViewOptionName::Refresh => false,
+            | MaterializedViewOptionName::Refresh
+            | MaterializedViewOptionName::StorageCompression => false,
         }
     }
 }
@@ -952,6 +957,7 @@ pub enum CreateConnectionType {
     SqlServer,
     MySql,
     IcebergCatalog,
+    Gcs,
 }
 
 impl CreateConnectionType {
@@ -966,6 +972,7 @@ impl CreateConnectionType {
             Self::MySql => "mysql",
             Self::SqlServer => "sql-server",
             Self::IcebergCatalog => "iceberg-catalog",
+            Self::Gcs => "gcs",
         }
     }
 }
@@ -1000,6 +1007,9 @@ impl AstDisplay for CreateConnectionType {
             Self::IcebergCatalog => {
                 f.write_str("ICEBERG CATALOG");
             }
+            Self::Gcs => {
+                f.write_str("GCS");
+            }
         }
     }
 }
@@ -1679,6 +1689,17 @@ pub enum TableConstraint<T: AstInfo> {
     },
 }
 
+impl<T: AstInfo> TableConstraint<T> {
+    /// Returns the name of the constraint, if it has one.
+    pub fn name(&self) -> Option<&Ident> {
+        match self {
+            TableConstraint::Unique { name, .. }
+            | TableConstraint::ForeignKey { name, .. }
+            | TableConstraint::Check { name, .. } => name.as_ref(),
+        }
+    }
+}
+
 impl<T: AstInfo> AstDisplay for TableConstraint<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
@@ -1870,6 +1891,11 @@ pub enum ColumnOption<T: AstInfo> {
         action: ColumnVersioned,
         version: Version,
     },
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [ ( <sequence options> ) ]`
+    Identity {
+        always: bool,
+        options: Vec<SequenceOption<T>>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for ColumnOption<T> {
@@ -1910,6 +1936,18 @@ impl<T: AstInfo> AstDisplay for ColumnOption<T> {
                 f.write_str(" ");
                 f.write_node(version);
             }
+            Identity { always, options } => {
+                if *always {
+                    f.write_str("GENERATED ALWAYS AS IDENTITY");
+                } else {
+                    f.write_str("GENERATED BY DEFAULT AS IDENTITY");
+                }
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::separated(options, " "));
+                    f.write_str(")");
+                }
+            }
         }
     }
 }
@@ -23,7 +23,7 @@ use std::hash::Hash;
 use std::mem;
 
 use crate::ast::display::{self, AstDisplay, AstFormatter, WithOptionName};
-use crate::ast::{AstInfo, Expr, Function, Ident, ShowStatement, WithOptionValue};
+use crate::ast::{AstInfo, Expr, Function, Ident, ShowStatement, Value, WithOptionValue};
 
 /// The most complete variant of a `SELECT` query expression, optionally
 /// including `WITH`, `UNION` / other set operations, and `ORDER BY`.
@@ -330,11 +330,13 @@ impl<T: AstInfo> AstDisplay for Distinct<T> {
 /// A block of common table expressions (CTEs).
 ///
 /// The block can either be entirely "simple" (traditional SQL `WITH` block),
+/// "recursive" (SQL `WITH RECURSIVE`, whose bindings may refer to themselves),
 /// or "mutually recursive", which introduce their bindings before the block
 /// and may result in mutually recursive definitions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum CteBlock<T: AstInfo> {
     Simple(Vec<Cte<T>>),
+    Recursive(Vec<CteRecursive<T>>),
     MutuallyRecursive(MutRecBlock<T>),
 }
 
@@ -353,6 +355,7 @@ impl<T: AstInfo> CteBlock<T> {
     pub fn is_empty(&self) -> bool {
         match self {
             CteBlock::Simple(list) => list.is_empty(),
+            CteBlock::Recursive(list) => list.is_empty(),
             CteBlock::MutuallyRecursive(list) => list.ctes.is_empty(),
         }
     }
@@ -365,6 +368,11 @@ impl<T: AstInfo> CteBlock<T> {
                     names.push(&cte.alias.name);
                 }
             }
+            CteBlock::Recursive(list) => {
+                for cte in list.iter() {
+                    names.push(&cte.alias.name);
+                }
+            }
             CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
                 for cte in ctes.iter() {
                     names.push(&cte.name);
@@ -383,6 +391,10 @@ impl<T: AstInfo> AstDisplay for CteBlock<T> {
                     f.write_str("WITH ");
                     f.write_node(&display::comma_separated(list));
                 }
+                CteBlock::Recursive(list) => {
+                    f.write_str("WITH RECURSIVE ");
+                    f.write_node(&display::comma_separated(list));
+                }
                 CteBlock::MutuallyRecursive(MutRecBlock { options, ctes }) => {
                     f.write_str("WITH MUTUALLY RECURSIVE ");
                     if !options.is_empty() {
@@ -419,6 +431,88 @@ impl<T: AstInfo> AstDisplay for Cte<T> {
 }
 impl_display_t!(Cte);
 
+/// A CTE in a `WITH RECURSIVE` block:
+/// `alias [(col1, col2, ...)] AS ( query ) [SEARCH ...] [CYCLE ...]`.
+///
+/// Unlike [`CteMutRec`], the column types are inferred from the query's
+/// non-recursive term.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CteRecursive<T: AstInfo> {
+    pub alias: TableAlias,
+    pub id: T::CteId,
+    pub query: Query<T>,
+    pub search: Option<CteSearchClause>,
+    pub cycle: Option<CteCycleClause>,
+}
+
+impl<T: AstInfo> AstDisplay for CteRecursive<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.alias);
+        f.write_str(" AS (");
+        f.write_node(&self.query);
+        f.write_str(")");
+        if let Some(search) = &self.search {
+            f.write_str(" ");
+            f.write_node(search);
+        }
+        if let Some(cycle) = &self.cycle {
+            f.write_str(" ");
+            f.write_node(cycle);
+        }
+    }
+}
+impl_display_t!(CteRecursive);
+
+/// `SEARCH { DEPTH | BREADTH } FIRST BY col1, col2, ... SET sequence_column`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CteSearchClause {
+    pub breadth_first: bool,
+    pub columns: Vec<Ident>,
+    pub sequence_column: Ident,
+}
+
+impl AstDisplay for CteSearchClause {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if self.breadth_first {
+            f.write_str("SEARCH BREADTH FIRST BY ");
+        } else {
+            f.write_str("SEARCH DEPTH FIRST BY ");
+        }
+        f.write_node(&display::comma_separated(&self.columns));
+        f.write_str(" SET ");
+        f.write_node(&self.sequence_column);
+    }
+}
+impl_display!(CteSearchClause);
+
+/// `CYCLE col1, col2, ... SET mark_column [TO value DEFAULT value] USING path_column`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CteCycleClause {
+    pub columns: Vec<Ident>,
+    pub mark_column: Ident,
+    /// The `TO` and `DEFAULT` values of the mark column, if specified.
+    pub mark_values: Option<(Value, Value)>,
+    pub path_column: Ident,
+}
+
+impl AstDisplay for CteCycleClause {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CYCLE ");
+        f.write_node(&display::comma_separated(&self.columns));
+        f.write_str(" SET ");
+        f.write_node(&self.mark_column);
+        if let Some((value, default)) = &self.mark_values {
+            f.write_str(" TO ");
+            f.write_node(value);
+            f.write_str(" DEFAULT ");
+            f.write_node(default);
+        }
+        f.write_str(" USING ");
+        f.write_node(&self.path_column);
+    }
+}
+impl_display!(CteCycleClause);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct CteMutRec<T: AstInfo> {
     pub name: Ident,
@@ -46,6 +46,7 @@ pub enum Statement<T: AstInfo> {
     Copy(CopyStatement<T>),
     Update(UpdateStatement<T>),
     Delete(DeleteStatement<T>),
+    Merge(MergeStatement<T>),
     CreateConnection(CreateConnectionStatement<T>),
     CreateDatabase(CreateDatabaseStatement),
     CreateSchema(CreateSchemaStatement),
@@ -60,6 +61,8 @@ pub enum Statement<T: AstInfo> {
     CreateTableFromSource(CreateTableFromSourceStatement<T>),
     CreateIndex(CreateIndexStatement<T>),
     CreateType(CreateTypeStatement<T>),
+    CreateFunction(CreateFunctionStatement<T>),
+    CreateSequence(CreateSequenceStatement<T>),
     CreateRole(CreateRoleStatement),
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
@@ -70,6 +73,7 @@ pub enum Statement<T: AstInfo> {
     AlterObjectRename(AlterObjectRenameStatement),
     AlterObjectSwap(AlterObjectSwapStatement),
     AlterRetainHistory(AlterRetainHistoryStatement<T>),
+    AlterStorageCompression(AlterStorageCompressionStatement),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
     AlterSetCluster(AlterSetClusterStatement<T>),
@@ -82,6 +86,8 @@ pub enum Statement<T: AstInfo> {
     AlterNetworkPolicy(AlterNetworkPolicyStatement<T>),
     AlterRole(AlterRoleStatement<T>),
     AlterTableAddColumn(AlterTableAddColumnStatement<T>),
+    AlterTableAddConstraint(AlterTableAddConstraintStatement<T>),
+    AlterTableDropConstraint(AlterTableDropConstraintStatement),
     AlterMaterializedViewApplyReplacement(AlterMaterializedViewApplyReplacementStatement),
     Discard(DiscardStatement),
     DropObjects(DropObjectsStatement),
@@ -100,6 +106,7 @@ pub enum Statement<T: AstInfo> {
     ExplainSinkSchema(ExplainSinkSchemaStatement<T>),
     ExplainAnalyzeObject(ExplainAnalyzeObjectStatement<T>),
     ExplainAnalyzeCluster(ExplainAnalyzeClusterStatement),
+    ExplainRecommendations(ExplainRecommendationsStatement<T>),
     Declare(DeclareStatement<T>),
     Fetch(FetchStatement<T>),
     Close(CloseStatement),
@@ -107,6 +114,11 @@ pub enum Statement<T: AstInfo> {
     Execute(ExecuteStatement<T>),
     Deallocate(DeallocateStatement),
     Raise(RaiseStatement),
+    Listen(ListenStatement),
+    Unlisten(UnlistenStatement),
+    Notify(NotifyStatement),
+    CreateChannel(CreateChannelStatement<T>),
+    DropChannel(DropChannelStatement),
     GrantRole(GrantRoleStatement<T>),
     RevokeRole(RevokeRoleStatement<T>),
     GrantPrivileges(GrantPrivilegesStatement<T>),
@@ -124,6 +136,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Insert(stmt) => f.write_node(stmt),
             Statement::Copy(stmt) => f.write_node(stmt),
             Statement::Update(stmt) => f.write_node(stmt),
+            Statement::Merge(stmt) => f.write_node(stmt),
             Statement::Delete(stmt) => f.write_node(stmt),
             Statement::CreateConnection(stmt) => f.write_node(stmt),
             Statement::CreateDatabase(stmt) => f.write_node(stmt),
@@ -141,6 +154,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
+            Statement::CreateFunction(stmt) => f.write_node(stmt),
+            Statement::CreateSequence(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::CreateNetworkPolicy(stmt) => f.write_node(stmt),
@@ -149,6 +164,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterOwner(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterRetainHistory(stmt) => f.write_node(stmt),
+            Statement::AlterStorageCompression(stmt) => f.write_node(stmt),
             Statement::AlterObjectSwap(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
@@ -161,6 +177,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterConnection(stmt) => f.write_node(stmt),
             Statement::AlterRole(stmt) => f.write_node(stmt),
             Statement::AlterTableAddColumn(stmt) => f.write_node(stmt),
+            Statement::AlterTableAddConstraint(stmt) => f.write_node(stmt),
+            Statement::AlterTableDropConstraint(stmt) => f.write_node(stmt),
             Statement::AlterMaterializedViewApplyReplacement(stmt) => f.write_node(stmt),
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
@@ -177,6 +195,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::ExplainPushdown(stmt) => f.write_node(stmt),
             Statement::ExplainAnalyzeObject(stmt) => f.write_node(stmt),
             Statement::ExplainAnalyzeCluster(stmt) => f.write_node(stmt),
+            Statement::ExplainRecommendations(stmt) => f.write_node(stmt),
             Statement::ExplainTimestamp(stmt) => f.write_node(stmt),
             Statement::ExplainSinkSchema(stmt) => f.write_node(stmt),
             Statement::Declare(stmt) => f.write_node(stmt),
@@ -186,6 +205,11 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Execute(stmt) => f.write_node(stmt),
             Statement::Deallocate(stmt) => f.write_node(stmt),
             Statement::Raise(stmt) => f.write_node(stmt),
+            Statement::Listen(stmt) => f.write_node(stmt),
+            Statement::Unlisten(stmt) => f.write_node(stmt),
+            Statement::Notify(stmt) => f.write_node(stmt),
+            Statement::CreateChannel(stmt) => f.write_node(stmt),
+            Statement::DropChannel(stmt) => f.write_node(stmt),
             Statement::GrantRole(stmt) => f.write_node(stmt),
             Statement::RevokeRole(stmt) => f.write_node(stmt),
             Statement::GrantPrivileges(stmt) => f.write_node(stmt),
@@ -206,6 +230,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Insert => "insert",
         StatementKind::Copy => "copy",
         StatementKind::Update => "update",
+        StatementKind::Merge => "merge",
         StatementKind::Delete => "delete",
         StatementKind::CreateConnection => "create_connection",
         StatementKind::CreateDatabase => "create_database",
@@ -221,6 +246,8 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateTableFromSource => "create_table_from_source",
         StatementKind::CreateIndex => "create_index",
         StatementKind::CreateType => "create_type",
+        StatementKind::CreateFunction => "create_function",
+        StatementKind::CreateSequence => "create_sequence",
         StatementKind::CreateRole => "create_role",
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
@@ -229,6 +256,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterCluster => "alter_cluster",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterRetainHistory => "alter_retain_history",
+        StatementKind::AlterStorageCompression => "alter_storage_compression",
         StatementKind::AlterObjectSwap => "alter_object_swap",
         StatementKind::AlterIndex => "alter_index",
         StatementKind::AlterNetworkPolicy => "alter_network_policy",
@@ -243,6 +271,8 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterOwner => "alter_owner",
         StatementKind::AlterConnection => "alter_connection",
         StatementKind::AlterTableAddColumn => "alter_table",
+        StatementKind::AlterTableAddConstraint => "alter_table",
+        StatementKind::AlterTableDropConstraint => "alter_table",
         StatementKind::AlterMaterializedViewApplyReplacement => {
             "alter_materialized_view_apply_replacement"
         }
@@ -261,6 +291,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::ExplainPushdown => "explain_pushdown",
         StatementKind::ExplainAnalyzeObject => "explain_analyze_object",
         StatementKind::ExplainAnalyzeCluster => "explain_analyze_cluster",
+        StatementKind::ExplainRecommendations => "explain_recommendations",
         StatementKind::ExplainTimestamp => "explain_timestamp",
         StatementKind::ExplainSinkSchema => "explain_sink_schema",
         StatementKind::Declare => "declare",
@@ -270,6 +301,11 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Execute => "execute",
         StatementKind::Deallocate => "deallocate",
         StatementKind::Raise => "raise",
+        StatementKind::Listen => "listen",
+        StatementKind::Unlisten => "unlisten",
+        StatementKind::Notify => "notify",
+        StatementKind::CreateChannel => "create_channel",
+        StatementKind::DropChannel => "drop_channel",
         StatementKind::GrantRole => "grant_role",
         StatementKind::RevokeRole => "revoke_role",
         StatementKind::GrantPrivileges => "grant_privileges",
@@ -308,6 +344,8 @@ pub struct InsertStatement<T: AstInfo> {
     pub columns: Vec<Ident>,
     /// A SQL query that specifies what to insert.
     pub source: InsertSource<T>,
+    /// ON CONFLICT
+    pub on_conflict: Option<OnConflict<T>>,
     /// RETURNING
     pub returning: Vec<SelectItem<T>>,
 }
@@ -323,6 +361,10 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
         }
         f.write_str(" ");
         f.write_node(&self.source);
+        if let Some(on_conflict) = &self.on_conflict {
+            f.write_str(" ");
+            f.write_node(on_conflict);
+        }
         if !self.returning.is_empty() {
             f.write_str(" RETURNING ");
             f.write_node(&display::comma_separated(&self.returning));
@@ -331,6 +373,59 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
 }
 impl_display_t!(InsertStatement);
 
+/// `ON CONFLICT [(<columns>)] <action>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnConflict<T: AstInfo> {
+    /// The columns of the conflicting key. May only be empty for
+    /// `DO NOTHING`, in which case conflicts on any key are ignored.
+    pub columns: Vec<Ident>,
+    pub action: OnConflictAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for OnConflict<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ON CONFLICT ");
+        if !self.columns.is_empty() {
+            f.write_str("(");
+            f.write_node(&display::comma_separated(&self.columns));
+            f.write_str(") ");
+        }
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(OnConflict);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OnConflictAction<T: AstInfo> {
+    /// `DO NOTHING`
+    DoNothing,
+    /// `DO UPDATE SET ... [WHERE ...]`
+    DoUpdate {
+        assignments: Vec<Assignment<T>>,
+        selection: Option<Expr<T>>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for OnConflictAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            OnConflictAction::DoNothing => f.write_str("DO NOTHING"),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                f.write_str("DO UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+                if let Some(selection) = selection {
+                    f.write_str(" WHERE ");
+                    f.write_node(selection);
+                }
+            }
+        }
+    }
+}
+impl_display_t!(OnConflictAction);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CopyRelation<T: AstInfo> {
     Named {
@@ -384,6 +479,7 @@ pub enum CopyOptionName {
     Quote,
     Header,
     AwsConnection,
+    GcsConnection,
     MaxFileSize,
     Files,
     Pattern,
@@ -399,6 +495,7 @@ impl AstDisplay for CopyOptionName {
             CopyOptionName::Quote => "QUOTE",
             CopyOptionName::Header => "HEADER",
             CopyOptionName::AwsConnection => "AWS CONNECTION",
+            CopyOptionName::GcsConnection => "GCS CONNECTION",
             CopyOptionName::MaxFileSize => "MAX FILE SIZE",
             CopyOptionName::Files => "FILES",
             CopyOptionName::Pattern => "PATTERN",
@@ -421,6 +518,7 @@ impl WithOptionName for CopyOptionName {
             | CopyOptionName::Quote
             | CopyOptionName::Header
             | CopyOptionName::AwsConnection
+            | CopyOptionName::GcsConnection
             | CopyOptionName::MaxFileSize => false,
             CopyOptionName::Files | CopyOptionName::Pattern => true,
         }
@@ -548,6 +646,142 @@ impl<T: AstInfo> AstDisplay for DeleteStatement<T> {
 }
 impl_display_t!(DeleteStatement);
 
+/// `MERGE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeStatement<T: AstInfo> {
+    /// `INTO`
+    pub table_name: T::ItemName,
+    /// `AS`
+    pub alias: Option<TableAlias>,
+    /// `USING`
+    pub using: TableFactor<T>,
+    /// `ON`
+    pub on: Expr<T>,
+    /// `WHEN [NOT] MATCHED ...`
+    pub clauses: Vec<MergeWhenClause<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MergeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("MERGE INTO ");
+        f.write_node(&self.table_name);
+        if let Some(alias) = &self.alias {
+            f.write_str(" AS ");
+            f.write_node(alias);
+        }
+        f.write_str(" USING ");
+        f.write_node(&self.using);
+        f.write_str(" ON ");
+        f.write_node(&self.on);
+        for clause in &self.clauses {
+            f.write_str(" ");
+            f.write_node(clause);
+        }
+    }
+}
+impl_display_t!(MergeStatement);
+
+/// A `WHEN [NOT] MATCHED [AND <condition>] THEN <action>` clause of a `MERGE`
+/// statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeWhenClause<T: AstInfo> {
+    Matched {
+        condition: Option<Expr<T>>,
+        action: MergeMatchedAction<T>,
+    },
+    NotMatched {
+        condition: Option<Expr<T>>,
+        action: MergeNotMatchedAction<T>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for MergeWhenClause<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        let condition = match self {
+            MergeWhenClause::Matched { condition, .. } => {
+                f.write_str("WHEN MATCHED");
+                condition
+            }
+            MergeWhenClause::NotMatched { condition, .. } => {
+                f.write_str("WHEN NOT MATCHED");
+                condition
+            }
+        };
+        if let Some(condition) = condition {
+            f.write_str(" AND ");
+            f.write_node(condition);
+        }
+        f.write_str(" THEN ");
+        match self {
+            MergeWhenClause::Matched { action, .. } => f.write_node(action),
+            MergeWhenClause::NotMatched { action, .. } => f.write_node(action),
+        }
+    }
+}
+impl_display_t!(MergeWhenClause);
+
+/// The action of a `WHEN MATCHED` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeMatchedAction<T: AstInfo> {
+    /// `UPDATE SET ...`
+    Update { assignments: Vec<Assignment<T>> },
+    /// `DELETE`
+    Delete,
+    /// `DO NOTHING`
+    DoNothing,
+}
+
+impl<T: AstInfo> AstDisplay for MergeMatchedAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MergeMatchedAction::Update { assignments } => {
+                f.write_str("UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+            }
+            MergeMatchedAction::Delete => f.write_str("DELETE"),
+            MergeMatchedAction::DoNothing => f.write_str("DO NOTHING"),
+        }
+    }
+}
+impl_display_t!(MergeMatchedAction);
+
+/// The action of a `WHEN NOT MATCHED` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeNotMatchedAction<T: AstInfo> {
+    /// `INSERT [(<columns>)] VALUES (...)`, or `INSERT DEFAULT VALUES` if
+    /// `values` is empty.
+    Insert {
+        columns: Vec<Ident>,
+        values: Vec<Expr<T>>,
+    },
+    /// `DO NOTHING`
+    DoNothing,
+}
+
+impl<T: AstInfo> AstDisplay for MergeNotMatchedAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            MergeNotMatchedAction::Insert { columns, values } => {
+                f.write_str("INSERT");
+                if !columns.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(columns));
+                    f.write_str(")");
+                }
+                if values.is_empty() {
+                    f.write_str(" DEFAULT VALUES");
+                } else {
+                    f.write_str(" VALUES (");
+                    f.write_node(&display::comma_separated(values));
+                    f.write_str(")");
+                }
+            }
+            MergeNotMatchedAction::DoNothing => f.write_str("DO NOTHING"),
+        }
+    }
+}
+impl_display_t!(MergeNotMatchedAction);
+
 /// `CREATE DATABASE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateDatabaseStatement {
@@ -1645,6 +1879,8 @@ pub enum TableOptionName {
     PartitionBy,
     // The `RETAIN HISTORY` option
     RetainHistory,
+    /// The `STORAGE COMPRESSION [=] <format>` option.
+    StorageCompression,
     /// A special option to test that we do redact values.
     RedactedTest,
 }
@@ -1658,6 +1894,9 @@ impl AstDisplay for TableOptionName {
             TableOptionName::RetainHistory => {
                 f.write_str("RETAIN HISTORY");
             }
+            TableOptionName::StorageCompression => {
+                f.write_str("STORAGE COMPRESSION");
+            }
             TableOptionName::RedactedTest => {
                 f.write_str("REDACTED");
             }
@@ -1675,6 +1914,7 @@ impl WithOptionName for TableOptionName {
         match self {
             TableOptionName::PartitionBy => false,
             TableOptionName::RetainHistory => false,
+            TableOptionName::StorageCompression => false,
             TableOptionName::RedactedTest => true,
         }
     }
@@ -1895,6 +2135,8 @@ impl_display_t!(CreateIndexStatement);
 pub enum IndexOptionName {
     // The `RETAIN HISTORY` option
     RetainHistory,
+    // The `STORAGE` option
+    Storage,
 }
 
 impl AstDisplay for IndexOptionName {
@@ -1903,6 +2145,9 @@ impl AstDisplay for IndexOptionName {
             IndexOptionName::RetainHistory => {
                 f.write_str("RETAIN HISTORY");
             }
+            IndexOptionName::Storage => {
+                f.write_str("STORAGE");
+            }
         }
     }
 }
@@ -1915,7 +2160,7 @@ impl WithOptionName for IndexOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            IndexOptionName::RetainHistory => false,
+            IndexOptionName::RetainHistory | IndexOptionName::Storage => false,
         }
     }
 }
@@ -2218,6 +2463,267 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
 }
 impl_display_t!(CreateTypeStatement);
 
+/// `CREATE FUNCTION ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateFunctionStatement<T: AstInfo> {
+    /// Name of the created function.
+    pub name: UnresolvedItemName,
+    /// Whether to ignore the statement if the function already exists.
+    pub if_not_exists: bool,
+    /// The function's parameters.
+    pub params: Vec<FunctionParameter<T>>,
+    /// Whether the function returns a set of values, i.e. `RETURNS SETOF`.
+    pub returns_set: bool,
+    /// The type of the function's return value.
+    pub return_type: T::DataType,
+    /// The language and body of the function.
+    pub body: FunctionBody<T>,
+    /// `WITH` options.
+    pub with_options: Vec<FunctionOption<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE FUNCTION ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str("(");
+        f.write_node(&display::comma_separated(&self.params));
+        f.write_str(") RETURNS ");
+        if self.returns_set {
+            f.write_str("SETOF ");
+        }
+        f.write_node(&self.return_type);
+        f.write_str(" ");
+        f.write_node(&self.body);
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(CreateFunctionStatement);
+
+/// An option in a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FunctionOptionName {
+    /// The `FUEL` option.
+    Fuel,
+    /// The `MEMORY` option.
+    Memory,
+}
+
+impl AstDisplay for FunctionOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionOptionName::Fuel => f.write_str("FUEL"),
+            FunctionOptionName::Memory => f.write_str("MEMORY"),
+        }
+    }
+}
+
+impl WithOptionName for FunctionOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            FunctionOptionName::Fuel | FunctionOptionName::Memory => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionOption<T: AstInfo> {
+    pub name: FunctionOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(FunctionOption);
+
+/// The body of a function created with `CREATE FUNCTION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionBody<T: AstInfo> {
+    /// `LANGUAGE SQL AS '<query>'`
+    Sql(Query<T>),
+    /// `LANGUAGE WASM AS '<module>' [, '<export>']`
+    Wasm {
+        /// The WebAssembly module, in the text format of `bytea`.
+        module: String,
+        /// The name of the exported function to call.
+        export: Option<String>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for FunctionBody<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionBody::Sql(query) => {
+                f.write_str("LANGUAGE SQL AS ");
+                // The body is a string literal, so print it in the current mode and quote the
+                // result.
+                let body = query.to_ast_string(f.mode());
+                f.write_node(&display::escaped_string_literal(&body));
+            }
+            FunctionBody::Wasm { module, export } => {
+                f.write_str("LANGUAGE WASM AS ");
+                if f.redacted() {
+                    f.write_str("'<REDACTED>'");
+                } else {
+                    f.write_node(&display::escaped_string_literal(module));
+                }
+                if let Some(export) = export {
+                    f.write_str(", ");
+                    f.write_node(&display::escaped_string_literal(export));
+                }
+            }
+        }
+    }
+}
+impl_display_t!(FunctionBody);
+
+/// A parameter of a function created with `CREATE FUNCTION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionParameter<T: AstInfo> {
+    /// The name of the parameter, if any.
+    pub name: Option<Ident>,
+    /// The type of the parameter.
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionParameter<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let Some(name) = &self.name {
+            f.write_node(name);
+            f.write_str(" ");
+        }
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionParameter);
+
+/// `CREATE SEQUENCE ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateSequenceStatement<T: AstInfo> {
+    /// Name of the created sequence.
+    pub name: UnresolvedItemName,
+    /// Whether to ignore the statement if the sequence already exists.
+    pub if_not_exists: bool,
+    /// The sequence's parameters.
+    pub options: Vec<SequenceOption<T>>,
+    /// The table column that owns the sequence, if any.
+    pub owned_by: Option<ColumnName<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateSequenceStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE SEQUENCE ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        if !self.options.is_empty() {
+            f.write_str(" ");
+            f.write_node(&display::separated(&self.options, " "));
+        }
+        if let Some(owned_by) = &self.owned_by {
+            f.write_str(" OWNED BY ");
+            f.write_node(owned_by);
+        }
+    }
+}
+impl_display_t!(CreateSequenceStatement);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SequenceOptionName {
+    /// The `INCREMENT [BY] <n>` option.
+    Increment,
+    /// The `MINVALUE <n>` option.
+    MinValue,
+    /// The `NO MINVALUE` option.
+    NoMinValue,
+    /// The `MAXVALUE <n>` option.
+    MaxValue,
+    /// The `NO MAXVALUE` option.
+    NoMaxValue,
+    /// The `START [WITH] <n>` option.
+    Start,
+    /// The `CACHE <n>` option.
+    Cache,
+    /// The `CYCLE` option.
+    Cycle,
+    /// The `NO CYCLE` option.
+    NoCycle,
+}
+
+impl AstDisplay for SequenceOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            SequenceOptionName::Increment => f.write_str("INCREMENT BY"),
+            SequenceOptionName::MinValue => f.write_str("MINVALUE"),
+            SequenceOptionName::NoMinValue => f.write_str("NO MINVALUE"),
+            SequenceOptionName::MaxValue => f.write_str("MAXVALUE"),
+            SequenceOptionName::NoMaxValue => f.write_str("NO MAXVALUE"),
+            SequenceOptionName::Start => f.write_str("START WITH"),
+            SequenceOptionName::Cache => f.write_str("CACHE"),
+            SequenceOptionName::Cycle => f.write_str("CYCLE"),
+            SequenceOptionName::NoCycle => f.write_str("NO CYCLE"),
+        }
+    }
+}
+
+impl WithOptionName for SequenceOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            SequenceOptionName::Increment
+            | SequenceOptionName::MinValue
+            | SequenceOptionName::NoMinValue
+            | SequenceOptionName::MaxValue
+            | SequenceOptionName::NoMaxValue
+            | SequenceOptionName::Start
+            | SequenceOptionName::Cache
+            | SequenceOptionName::Cycle
+            | SequenceOptionName::NoCycle => false,
+        }
+    }
+}
+
+/// An option in a `CREATE SEQUENCE` statement or an identity column definition.
+///
+/// Unlike most options, sequence options use PostgreSQL's space-separated
+/// syntax, e.g. `INCREMENT BY 2 NO CYCLE`, rather than `NAME = value`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SequenceOption<T: AstInfo> {
+    pub name: SequenceOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for SequenceOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" ");
+            if f.redacted() && !self.name.redact_value() {
+                let mode = f.unredact();
+                f.write_node(v);
+                f.set_mode(mode);
+            } else {
+                f.write_node(v);
+            }
+        }
+    }
+}
+impl_display_t!(SequenceOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ClusterOptionName {
     /// The `AVAILABILITY ZONES [[=] '[' <values> ']' ]` option.
@@ -2793,6 +3299,35 @@ impl<T: AstInfo> AstDisplay for AlterRetainHistoryStatement<T> {
 }
 impl_display_t!(AlterRetainHistoryStatement);
 
+/// `ALTER <OBJECT> ... [RE]SET (STORAGE COMPRESSION [= ...])`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterStorageCompressionStatement {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub compression: Option<Value>,
+}
+
+impl AstDisplay for AlterStorageCompressionStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        if let Some(compression) = &self.compression {
+            f.write_str(" SET (STORAGE COMPRESSION = ");
+            f.write_node(compression);
+        } else {
+            f.write_str(" RESET (STORAGE COMPRESSION");
+        }
+        f.write_str(")");
+    }
+}
+impl_display!(AlterStorageCompressionStatement);
+
 /// `ALTER <OBJECT> SWAP ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterObjectSwapStatement {
@@ -3236,6 +3771,62 @@ impl<T: AstInfo> AstDisplay for AlterTableAddColumnStatement<T> {
 
 impl_display_t!(AlterTableAddColumnStatement);
 
+/// `ALTER TABLE ... ADD <constraint>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableAddConstraintStatement<T: AstInfo> {
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub constraint: TableConstraint<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterTableAddConstraintStatement<T> {
+    fn fmt<W>(&self, f: &mut AstFormatter<W>)
+    where
+        W: fmt::Write,
+    {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+
+        f.write_str(" ADD ");
+        f.write_node(&self.constraint);
+    }
+}
+
+impl_display_t!(AlterTableAddConstraintStatement);
+
+/// `ALTER TABLE ... DROP CONSTRAINT ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableDropConstraintStatement {
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub if_constraint_exists: bool,
+    pub constraint_name: Ident,
+}
+
+impl AstDisplay for AlterTableDropConstraintStatement {
+    fn fmt<W>(&self, f: &mut AstFormatter<W>)
+    where
+        W: fmt::Write,
+    {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+
+        f.write_str(" DROP CONSTRAINT ");
+        if self.if_constraint_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.constraint_name);
+    }
+}
+
+impl_display!(AlterTableDropConstraintStatement);
+
 /// `ALTER MATERIALIZED VIEW ... APPLY REPLACEMENT ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterMaterializedViewApplyReplacementStatement {
@@ -3883,6 +4474,8 @@ impl_display!(RollbackStatement);
 pub enum SubscribeOptionName {
     Snapshot,
     Progress,
+    EmitEvery,
+    MaxRowsPerSecond,
 }
 
 impl AstDisplay for SubscribeOptionName {
@@ -3890,6 +4483,8 @@ impl AstDisplay for SubscribeOptionName {
         match self {
             SubscribeOptionName::Snapshot => f.write_str("SNAPSHOT"),
             SubscribeOptionName::Progress => f.write_str("PROGRESS"),
+            SubscribeOptionName::EmitEvery => f.write_str("EMIT EVERY"),
+            SubscribeOptionName::MaxRowsPerSecond => f.write_str("MAX ROWS PER SECOND"),
         }
     }
 }
@@ -3903,7 +4498,10 @@ impl WithOptionName for SubscribeOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            SubscribeOptionName::Snapshot | SubscribeOptionName::Progress => false,
+            SubscribeOptionName::Snapshot
+            | SubscribeOptionName::Progress
+            | SubscribeOptionName::EmitEvery
+            | SubscribeOptionName::MaxRowsPerSecond => false,
         }
     }
 }
@@ -4227,6 +4825,19 @@ impl AstDisplay for ExplainAnalyzeClusterStatement {
 }
 impl_display!(ExplainAnalyzeClusterStatement);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExplainRecommendationsStatement<T: AstInfo> {
+    pub cluster: T::ClusterName,
+}
+
+impl<T: AstInfo> AstDisplay for ExplainRecommendationsStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("EXPLAIN RECOMMENDATIONS FOR CLUSTER ");
+        f.write_node(&self.cluster);
+    }
+}
+impl_display_t!(ExplainRecommendationsStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExplainTimestampStatement<T: AstInfo> {
     pub format: Option<ExplainFormat>,
@@ -4288,6 +4899,7 @@ pub enum ObjectType {
     Subsource,
     ContinualTask,
     NetworkPolicy,
+    Sequence,
 }
 
 impl ObjectType {
@@ -4304,7 +4916,8 @@ impl ObjectType {
             | ObjectType::Connection
             | ObjectType::Func
             | ObjectType::Subsource
-            | ObjectType::ContinualTask => true,
+            | ObjectType::ContinualTask
+            | ObjectType::Sequence => true,
             ObjectType::Database
             | ObjectType::Schema
             | ObjectType::Cluster
@@ -4336,6 +4949,7 @@ impl AstDisplay for ObjectType {
             ObjectType::Subsource => "SUBSOURCE",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Sequence => "SEQUENCE",
         })
     }
 }
@@ -5145,6 +5759,98 @@ impl AstDisplay for NoticeSeverity {
 }
 impl_display!(NoticeSeverity);
 
+/// `LISTEN <channel>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenStatement {
+    pub channel: Ident,
+}
+
+impl AstDisplay for ListenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("LISTEN ");
+        f.write_node(&self.channel);
+    }
+}
+impl_display!(ListenStatement);
+
+/// `UNLISTEN { <channel> | * }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnlistenStatement {
+    /// The channel to stop listening on, or `None` for all channels.
+    pub channel: Option<Ident>,
+}
+
+impl AstDisplay for UnlistenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("UNLISTEN ");
+        match &self.channel {
+            Some(channel) => f.write_node(channel),
+            None => f.write_str("*"),
+        }
+    }
+}
+impl_display!(UnlistenStatement);
+
+/// `NOTIFY <channel> [, <payload>]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotifyStatement {
+    pub channel: Ident,
+    pub payload: Option<String>,
+}
+
+impl AstDisplay for NotifyStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("NOTIFY ");
+        f.write_node(&self.channel);
+        if let Some(payload) = &self.payload {
+            f.write_str(", ");
+            if f.redacted() {
+                f.write_str("'<REDACTED>'");
+            } else {
+                f.write_str("'");
+                f.write_node(&display::escape_single_quote_string(payload));
+                f.write_str("'");
+            }
+        }
+    }
+}
+impl_display!(NotifyStatement);
+
+/// `CREATE CHANNEL <channel> ON VIEW <view>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateChannelStatement<T: AstInfo> {
+    pub channel: Ident,
+    pub view: T::ItemName,
+}
+
+impl<T: AstInfo> AstDisplay for CreateChannelStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE CHANNEL ");
+        f.write_node(&self.channel);
+        f.write_str(" ON VIEW ");
+        f.write_node(&self.view);
+    }
+}
+impl_display_t!(CreateChannelStatement);
+
+/// `DROP CHANNEL [IF EXISTS] <channel>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropChannelStatement {
+    pub if_exists: bool,
+    pub channel: Ident,
+}
+
+impl AstDisplay for DropChannelStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP CHANNEL ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.channel);
+    }
+}
+impl_display!(DropChannelStatement);
+
 /// `ALTER SYSTEM SET ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterSystemSetStatement {
@@ -5289,6 +5995,7 @@ pub enum Privilege {
     INSERT,
     UPDATE,
     DELETE,
+    EXECUTE,
     USAGE,
     CREATE,
     CREATEROLE,
@@ -5304,6 +6011,7 @@ impl AstDisplay for Privilege {
             Privilege::INSERT => "INSERT",
             Privilege::UPDATE => "UPDATE",
             Privilege::DELETE => "DELETE",
+            Privilege::EXECUTE => "EXECUTE",
             Privilege::CREATE => "CREATE",
             Privilege::USAGE => "USAGE",
             Privilege::CREATEROLE => "CREATEROLE",
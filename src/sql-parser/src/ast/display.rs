@@ -138,6 +138,11 @@ where
         }
     }
 
+    /// The mode the AST is printed in.
+    pub fn mode(&self) -> FormatMode {
+        self.mode
+    }
+
     pub fn set_mode(&mut self, mode: FormatMode) {
         self.mode = mode;
     }
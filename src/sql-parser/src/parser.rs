@@ -413,6 +413,9 @@ impl<'a> Parser<'a> {
                 Token::Keyword(UPDATE) => {
                     Ok(self.parse_update().map_parser_err(StatementKind::Update)?)
                 }
+                Token::Keyword(MERGE) => {
+                    Ok(self.parse_merge().map_parser_err(StatementKind::Merge)?)
+                }
                 Token::Keyword(ALTER) => Ok(self.parse_alter()?),
                 Token::Keyword(COPY) => Ok(self.parse_copy()?),
                 Token::Keyword(SET) => Ok(self.parse_set()?),
@@ -461,6 +464,15 @@ impl<'a> Parser<'a> {
                 Token::Keyword(RAISE) => {
                     Ok(self.parse_raise().map_parser_err(StatementKind::Raise)?)
                 }
+                Token::Keyword(LISTEN) => {
+                    Ok(self.parse_listen().map_parser_err(StatementKind::Listen)?)
+                }
+                Token::Keyword(UNLISTEN) => Ok(self
+                    .parse_unlisten()
+                    .map_parser_err(StatementKind::Unlisten)?),
+                Token::Keyword(NOTIFY) => {
+                    Ok(self.parse_notify().map_parser_err(StatementKind::Notify)?)
+                }
                 Token::Keyword(GRANT) => Ok(self.parse_grant()?),
                 Token::Keyword(REVOKE) => Ok(self.parse_revoke()?),
                 Token::Keyword(REASSIGN) => Ok(self
@@ -1938,6 +1950,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(TYPE) {
             self.parse_create_type()
                 .map_parser_err(StatementKind::CreateType)
+        } else if self.peek_keyword(FUNCTION) {
+            self.parse_create_function()
+                .map_parser_err(StatementKind::CreateFunction)
         } else if self.peek_keyword(ROLE) {
             self.parse_create_role()
                 .map_parser_err(StatementKind::CreateRole)
@@ -1975,6 +1990,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(SECRET) {
             self.parse_create_secret()
                 .map_parser_err(StatementKind::CreateSecret)
+        } else if self.peek_keyword(SEQUENCE) {
+            self.parse_create_sequence()
+                .map_parser_err(StatementKind::CreateSequence)
         } else if self.peek_keyword(CONNECTION) {
             self.parse_create_connection()
                 .map_parser_err(StatementKind::CreateConnection)
@@ -2005,6 +2023,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keywords(&[NETWORK, POLICY]) {
             self.parse_create_network_policy()
                 .map_parser_err(StatementKind::CreateNetworkPolicy)
+        } else if self.peek_keyword(CHANNEL) {
+            self.parse_create_channel()
+                .map_parser_err(StatementKind::CreateChannel)
         } else {
             let index = self.index;
 
@@ -2451,9 +2472,9 @@ impl<'a> Parser<'a> {
             TO => true,
             _ => unreachable!(),
         };
-        let connection_type = match self
-            .expect_one_of_keywords(&[AWS, KAFKA, CONFLUENT, POSTGRES, SSH, SQL, MYSQL, ICEBERG])?
-        {
+        let connection_type = match self.expect_one_of_keywords(&[
+            AWS, KAFKA, CONFLUENT, POSTGRES, SSH, SQL, MYSQL, ICEBERG, GCS,
+        ])? {
             AWS => {
                 if self.parse_keyword(PRIVATELINK) {
                     CreateConnectionType::AwsPrivatelink
@@ -2480,6 +2501,7 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(CATALOG)?;
                 CreateConnectionType::IcebergCatalog
             }
+            GCS => CreateConnectionType::Gcs,
             _ => unreachable!(),
         };
         if expect_paren {
@@ -4089,6 +4111,7 @@ impl<'a> Parser<'a> {
             table_name: name.clone(),
             columns: Vec::new(),
             source: InsertSource::Query(transform.clone()),
+            on_conflict: None,
             returning: Vec::new(),
         };
 
@@ -4151,6 +4174,7 @@ impl<'a> Parser<'a> {
                 limit: None,
                 offset: None,
             }),
+            on_conflict: None,
             returning: Vec::new(),
         };
 
@@ -4185,7 +4209,7 @@ impl<'a> Parser<'a> {
     fn parse_materialized_view_option_name(
         &mut self,
     ) -> Result<MaterializedViewOptionName, ParserError> {
-        let option = self.expect_one_of_keywords(&[ASSERT, PARTITION, RETAIN, REFRESH])?;
+        let option = self.expect_one_of_keywords(&[ASSERT, PARTITION, RETAIN, REFRESH, STORAGE])?;
         let name = match option {
             ASSERT => {
                 self.expect_keywords(&[NOT, NULL])?;
@@ -4200,6 +4224,10 @@ impl<'a> Parser<'a> {
                 MaterializedViewOptionName::RetainHistory
             }
             REFRESH => MaterializedViewOptionName::Refresh,
+            STORAGE => {
+                self.expect_keyword(COMPRESSION)?;
+                MaterializedViewOptionName::StorageCompression
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -4365,7 +4393,7 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(REDACTED) {
             return Ok(TableOptionName::RedactedTest);
         }
-        let name = match self.expect_one_of_keywords(&[PARTITION, RETAIN])? {
+        let name = match self.expect_one_of_keywords(&[PARTITION, RETAIN, STORAGE])? {
             PARTITION => {
                 self.expect_keyword(BY)?;
                 TableOptionName::PartitionBy
@@ -4374,6 +4402,10 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(HISTORY)?;
                 TableOptionName::RetainHistory
             }
+            STORAGE => {
+                self.expect_keyword(COMPRESSION)?;
+                TableOptionName::StorageCompression
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -4384,20 +4416,29 @@ impl<'a> Parser<'a> {
         let value = match name {
             TableOptionName::PartitionBy => self.parse_optional_option_value(),
             TableOptionName::RetainHistory => self.parse_option_retain_history(),
+            TableOptionName::StorageCompression => self.parse_optional_option_value(),
             TableOptionName::RedactedTest => self.parse_optional_option_value(),
         }?;
         Ok(TableOption { name, value })
     }
 
     fn parse_index_option_name(&mut self) -> Result<IndexOptionName, ParserError> {
-        self.expect_keywords(&[RETAIN, HISTORY])?;
-        Ok(IndexOptionName::RetainHistory)
+        let option = match self.expect_one_of_keywords(&[RETAIN, STORAGE])? {
+            RETAIN => {
+                self.expect_keyword(HISTORY)?;
+                IndexOptionName::RetainHistory
+            }
+            STORAGE => IndexOptionName::Storage,
+            _ => unreachable!(),
+        };
+        Ok(option)
     }
 
     fn parse_index_option(&mut self) -> Result<IndexOption<Raw>, ParserError> {
         let name = self.parse_index_option_name()?;
         let value = match name {
             IndexOptionName::RetainHistory => self.parse_option_retain_history(),
+            IndexOptionName::Storage => self.parse_optional_option_value(),
         }?;
         Ok(IndexOption { name, value })
     }
@@ -4504,6 +4545,74 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_create_sequence(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(SEQUENCE)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        let mut options = vec![];
+        while let Some(option) = self.parse_optional_sequence_option()? {
+            options.push(option);
+        }
+        let owned_by = if self.parse_keywords(&[OWNED, BY]) {
+            if self.parse_keyword(NONE) {
+                None
+            } else {
+                Some(self.parse_column_name()?)
+            }
+        } else {
+            None
+        };
+        Ok(Statement::CreateSequence(CreateSequenceStatement {
+            name,
+            if_not_exists,
+            options,
+            owned_by,
+        }))
+    }
+
+    /// Parses a single sequence option, as accepted by `CREATE SEQUENCE` and
+    /// identity columns, if one is present.
+    fn parse_optional_sequence_option(
+        &mut self,
+    ) -> Result<Option<SequenceOption<Raw>>, ParserError> {
+        let (name, value) = if self.parse_keyword(INCREMENT) {
+            let _ = self.parse_keyword(BY);
+            (
+                SequenceOptionName::Increment,
+                Some(self.parse_number_value()?),
+            )
+        } else if self.parse_keyword(MINVALUE) {
+            (
+                SequenceOptionName::MinValue,
+                Some(self.parse_number_value()?),
+            )
+        } else if self.parse_keyword(MAXVALUE) {
+            (
+                SequenceOptionName::MaxValue,
+                Some(self.parse_number_value()?),
+            )
+        } else if self.parse_keyword(START) {
+            let _ = self.parse_keyword(WITH);
+            (SequenceOptionName::Start, Some(self.parse_number_value()?))
+        } else if self.parse_keyword(CACHE) {
+            (SequenceOptionName::Cache, Some(self.parse_number_value()?))
+        } else if self.parse_keyword(CYCLE) {
+            (SequenceOptionName::Cycle, None)
+        } else if self.parse_keywords(&[NO, MINVALUE]) {
+            (SequenceOptionName::NoMinValue, None)
+        } else if self.parse_keywords(&[NO, MAXVALUE]) {
+            (SequenceOptionName::NoMaxValue, None)
+        } else if self.parse_keywords(&[NO, CYCLE]) {
+            (SequenceOptionName::NoCycle, None)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(SequenceOption {
+            name,
+            value: value.map(WithOptionValue::Value),
+        }))
+    }
+
     fn parse_create_type(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_item_name()?;
@@ -4567,6 +4676,144 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_create_function(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(FUNCTION)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        self.expect_token(&Token::LParen)?;
+        let params = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let params = self.parse_comma_separated(Parser::parse_function_parameter)?;
+            self.expect_token(&Token::RParen)?;
+            params
+        };
+        self.expect_keyword(RETURNS)?;
+        let returns_set = self.parse_keyword(SETOF);
+        let return_type = self.parse_data_type()?;
+
+        // Like PostgreSQL, accept the language and the body in either order.
+        let mut language = None;
+        let mut body = None;
+        while language.is_none() || body.is_none() {
+            match self.expect_one_of_keywords(&[LANGUAGE, AS])? {
+                LANGUAGE if language.is_none() => {
+                    let pos = self.peek_pos();
+                    let ident = self.parse_identifier()?;
+                    language = Some((pos, ident));
+                }
+                AS if body.is_none() => {
+                    let pos = self.peek_pos();
+                    let definition = self.parse_literal_string()?;
+                    let link_symbol = if self.consume_token(&Token::Comma) {
+                        Some(self.parse_literal_string()?)
+                    } else {
+                        None
+                    };
+                    body = Some((pos, definition, link_symbol));
+                }
+                kw => {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        format!("conflicting or redundant {kw} clauses")
+                    );
+                }
+            }
+        }
+        let (language_pos, language) = language.expect("loop exits once the language is parsed");
+        let (body_pos, definition, link_symbol) = body.expect("loop exits once the body is parsed");
+
+        let body = match language.as_str().to_lowercase().as_str() {
+            "sql" => {
+                if link_symbol.is_some() {
+                    return parser_err!(self, body_pos, "only one AS item needed for language SQL");
+                }
+                FunctionBody::Sql(self.parse_function_body(body_pos, &definition)?)
+            }
+            "wasm" => FunctionBody::Wasm {
+                module: definition,
+                export: link_symbol,
+            },
+            _ => {
+                return parser_err!(
+                    self,
+                    language_pos,
+                    format!("unsupported language {language}")
+                );
+            }
+        };
+
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_function_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        Ok(Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            if_not_exists,
+            params,
+            returns_set,
+            return_type,
+            body,
+            with_options,
+        }))
+    }
+
+    fn parse_function_option(&mut self) -> Result<FunctionOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[FUEL, MEMORY])? {
+            FUEL => FunctionOptionName::Fuel,
+            MEMORY => FunctionOptionName::Memory,
+            _ => unreachable!(),
+        };
+        let value = self.parse_optional_option_value()?;
+        Ok(FunctionOption { name, value })
+    }
+
+    fn parse_function_parameter(&mut self) -> Result<FunctionParameter<Raw>, ParserError> {
+        // Parameter names are optional, so `(a int)` and `(int)` are both valid. Try to parse
+        // an unnamed parameter first, which correctly handles multi-word types like `double
+        // precision`, and fall back to a named parameter otherwise.
+        let index = self.index;
+        if let Ok(data_type) = self.parse_data_type() {
+            if matches!(self.peek_token(), Some(Token::Comma | Token::RParen)) {
+                return Ok(FunctionParameter {
+                    name: None,
+                    data_type,
+                });
+            }
+        }
+        self.index = index;
+        let name = self.parse_identifier()?;
+        let data_type = self.parse_data_type()?;
+        Ok(FunctionParameter {
+            name: Some(name),
+            data_type,
+        })
+    }
+
+    /// Parses the body of a `LANGUAGE SQL` function, i.e. the string literal at `pos`
+    /// following `AS` in a `CREATE FUNCTION` statement, as a query.
+    fn parse_function_body(&self, pos: usize, body: &str) -> Result<Query<Raw>, ParserError> {
+        let err = |e: ParserError| self.error(pos, format!("invalid function body: {}", e.message));
+        let tokens = lexer::lex(body).map_err(|e| err(e.into()))?;
+        let mut parser = Parser::new(body, tokens);
+        let query = parser.parse_query().map_err(err)?;
+        // Allow a trailing semicolon, as in PostgreSQL.
+        parser.consume_token(&Token::Semicolon);
+        if let Some(token) = parser.peek_token() {
+            return Err(err(parser.error(
+                parser.peek_pos(),
+                format!("extra token {token} after query"),
+            )));
+        }
+        Ok(query)
+    }
+
     fn parse_create_cluster(&mut self) -> Result<Statement<Raw>, ParserError> {
         let name = self.parse_identifier()?;
         // For historical reasons, the parentheses around the options can be
@@ -4901,6 +5148,9 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
                 .map_parser_err(StatementKind::DropOwned)
+        } else if self.parse_keyword(CHANNEL) {
+            self.parse_drop_channel()
+                .map_parser_err(StatementKind::DropChannel)
         } else {
             self.parse_drop_objects()
                 .map_parser_err(StatementKind::DropObjects)
@@ -4975,7 +5225,9 @@ impl<'a> Parser<'a> {
             | ObjectType::Type
             | ObjectType::Secret
             | ObjectType::Connection
-            | ObjectType::ContinualTask => {
+            | ObjectType::Func
+            | ObjectType::ContinualTask
+            | ObjectType::Sequence => {
                 let names = self.parse_comma_separated(|parser| {
                     Ok(UnresolvedObjectName::Item(parser.parse_item_name()?))
                 })?;
@@ -4990,7 +5242,7 @@ impl<'a> Parser<'a> {
                     cascade,
                 }))
             }
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Subsource => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported DROP on {object_type}")
@@ -5492,6 +5744,24 @@ impl<'a> Parser<'a> {
             let version = self.parse_version()?;
 
             ColumnOption::Versioned { action, version }
+        } else if self.parse_keyword(GENERATED) {
+            let always = match self.expect_one_of_keywords(&[ALWAYS, BY])? {
+                ALWAYS => true,
+                BY => {
+                    self.expect_keyword(DEFAULT)?;
+                    false
+                }
+                _ => unreachable!(),
+            };
+            self.expect_keywords(&[AS, IDENTITY])?;
+            let mut options = vec![];
+            if self.consume_token(&Token::LParen) {
+                while let Some(option) = self.parse_optional_sequence_option()? {
+                    options.push(option);
+                }
+                self.expect_token(&Token::RParen)?;
+            }
+            ColumnOption::Identity { always, options }
         } else {
             return self.expected(self.peek_pos(), "column option", self.peek_token());
         };
@@ -5780,7 +6050,7 @@ impl<'a> Parser<'a> {
             ObjectType::NetworkPolicy => self
                 .parse_alter_network_policy()
                 .map_parser_err(StatementKind::AlterNetworkPolicy),
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Func | ObjectType::Subsource | ObjectType::Sequence => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported ALTER on {object_type}")
@@ -6524,7 +6794,7 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
         let keywords: &[_] = match object_type {
-            ObjectType::Table => &[SET, RENAME, OWNER, RESET, ADD],
+            ObjectType::Table => &[SET, RENAME, OWNER, RESET, ADD, DROP],
             ObjectType::MaterializedView => &[SET, RENAME, OWNER, RESET, APPLY],
             ObjectType::View | ObjectType::ContinualTask => &[SET, RENAME, OWNER, RESET],
             ObjectType::Source
@@ -6540,7 +6810,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Schema
             | ObjectType::Func
             | ObjectType::Subsource
-            | ObjectType::NetworkPolicy => {
+            | ObjectType::NetworkPolicy
+            | ObjectType::Sequence => {
                 unreachable!("parse_alter_views called with unsupported object type: {object_type}")
             }
         };
@@ -6567,6 +6838,29 @@ impl<'a> Parser<'a> {
                 } else {
                     self.expect_token(&Token::LParen)
                         .map_no_statement_parser_err()?;
+                    if self.peek_keyword(STORAGE)
+                        && matches!(
+                            object_type,
+                            ObjectType::Table | ObjectType::MaterializedView
+                        )
+                    {
+                        self.expect_keywords(&[STORAGE, COMPRESSION])
+                            .map_parser_err(StatementKind::AlterStorageCompression)?;
+                        let _ = self.consume_token(&Token::Eq);
+                        let compression = self
+                            .parse_value()
+                            .map_parser_err(StatementKind::AlterStorageCompression)?;
+                        self.expect_token(&Token::RParen)
+                            .map_parser_err(StatementKind::AlterStorageCompression)?;
+                        return Ok(Statement::AlterStorageCompression(
+                            AlterStorageCompressionStatement {
+                                object_type,
+                                if_exists,
+                                name,
+                                compression: Some(compression),
+                            },
+                        ));
+                    }
                     self.expect_keywords(&[RETAIN, HISTORY])
                         .map_parser_err(StatementKind::AlterRetainHistory)?;
                     let history = self
@@ -6585,6 +6879,25 @@ impl<'a> Parser<'a> {
             RESET => {
                 self.expect_token(&Token::LParen)
                     .map_no_statement_parser_err()?;
+                if self.peek_keyword(STORAGE)
+                    && matches!(
+                        object_type,
+                        ObjectType::Table | ObjectType::MaterializedView
+                    )
+                {
+                    self.expect_keywords(&[STORAGE, COMPRESSION])
+                        .map_parser_err(StatementKind::AlterStorageCompression)?;
+                    self.expect_token(&Token::RParen)
+                        .map_parser_err(StatementKind::AlterStorageCompression)?;
+                    return Ok(Statement::AlterStorageCompression(
+                        AlterStorageCompressionStatement {
+                            object_type,
+                            if_exists,
+                            name,
+                            compression: None,
+                        },
+                    ));
+                }
                 self.expect_keywords(&[RETAIN, HISTORY])
                     .map_parser_err(StatementKind::AlterRetainHistory)?;
                 self.expect_token(&Token::RParen)
@@ -6611,6 +6924,19 @@ impl<'a> Parser<'a> {
             ADD => {
                 assert_eq!(object_type, ObjectType::Table, "checked object_type above");
 
+                if let Some(constraint) = self
+                    .parse_optional_table_constraint()
+                    .map_parser_err(StatementKind::AlterTableAddConstraint)?
+                {
+                    return Ok(Statement::AlterTableAddConstraint(
+                        AlterTableAddConstraintStatement {
+                            if_exists,
+                            name,
+                            constraint,
+                        },
+                    ));
+                }
+
                 self.expect_keyword(COLUMN)
                     .map_parser_err(StatementKind::AlterTableAddColumn)?;
                 let if_col_not_exist = self
@@ -6633,6 +6959,27 @@ impl<'a> Parser<'a> {
                     },
                 ))
             }
+            DROP => {
+                assert_eq!(object_type, ObjectType::Table, "checked object_type above");
+
+                self.expect_keyword(CONSTRAINT)
+                    .map_parser_err(StatementKind::AlterTableDropConstraint)?;
+                let if_constraint_exists = self
+                    .parse_if_exists()
+                    .map_parser_err(StatementKind::AlterTableDropConstraint)?;
+                let constraint_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterTableDropConstraint)?;
+
+                Ok(Statement::AlterTableDropConstraint(
+                    AlterTableDropConstraintStatement {
+                        if_exists,
+                        name,
+                        if_constraint_exists,
+                        constraint_name,
+                    },
+                ))
+            }
             APPLY => {
                 assert_eq!(
                     object_type,
@@ -6846,7 +7193,7 @@ impl<'a> Parser<'a> {
 
     fn parse_copy_option(&mut self) -> Result<CopyOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[
-            FORMAT, DELIMITER, NULL, ESCAPE, QUOTE, HEADER, AWS, MAX, FILES, PATTERN,
+            FORMAT, DELIMITER, NULL, ESCAPE, QUOTE, HEADER, AWS, GCS, MAX, FILES, PATTERN,
         ])? {
             FORMAT => CopyOptionName::Format,
             DELIMITER => CopyOptionName::Delimiter,
@@ -6861,6 +7208,13 @@ impl<'a> Parser<'a> {
                     value: Some(self.parse_object_option_value()?),
                 });
             }
+            GCS => {
+                self.expect_keyword(CONNECTION)?;
+                return Ok(CopyOption {
+                    name: CopyOptionName::GcsConnection,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
             MAX => {
                 self.expect_keywords(&[FILE, SIZE])?;
                 CopyOptionName::MaxFileSize
@@ -7380,7 +7734,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Secret
             | ObjectType::Connection
             | ObjectType::Func
-            | ObjectType::ContinualTask => UnresolvedObjectName::Item(self.parse_item_name()?),
+            | ObjectType::ContinualTask
+            | ObjectType::Sequence => UnresolvedObjectName::Item(self.parse_item_name()?),
             ObjectType::Role => UnresolvedObjectName::Role(self.parse_identifier()?),
             ObjectType::Cluster => UnresolvedObjectName::Cluster(self.parse_identifier()?),
             ObjectType::ClusterReplica => {
@@ -7567,8 +7922,9 @@ impl<'a> Parser<'a> {
                         options,
                         ctes: parser.parse_comma_separated(Parser::parse_cte_mut_rec)?,
                     })
+                } else if parser.parse_keyword(RECURSIVE) {
+                    CteBlock::Recursive(parser.parse_comma_separated(Parser::parse_cte_recursive)?)
                 } else {
-                    // TODO: optional RECURSIVE
                     CteBlock::Simple(parser.parse_comma_separated(Parser::parse_cte)?)
                 }
             } else {
@@ -7747,6 +8103,60 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a CTE in a `WITH RECURSIVE` block, including its optional
+    /// `SEARCH` and `CYCLE` clauses.
+    fn parse_cte_recursive(&mut self) -> Result<CteRecursive<Raw>, ParserError> {
+        let Cte { alias, query, id } = self.parse_cte()?;
+        let search = if self.parse_keyword(SEARCH) {
+            let breadth_first = match self.expect_one_of_keywords(&[DEPTH, BREADTH])? {
+                DEPTH => false,
+                BREADTH => true,
+                _ => unreachable!(),
+            };
+            self.expect_keywords(&[FIRST, BY])?;
+            let columns = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_keyword(SET)?;
+            let sequence_column = self.parse_identifier()?;
+            Some(CteSearchClause {
+                breadth_first,
+                columns,
+                sequence_column,
+            })
+        } else {
+            None
+        };
+        let cycle = if self.parse_keyword(CYCLE) {
+            let columns = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_keyword(SET)?;
+            let mark_column = self.parse_identifier()?;
+            let mark_values = if self.parse_keyword(TO) {
+                let value = self.parse_value()?;
+                self.expect_keyword(DEFAULT)?;
+                let default = self.parse_value()?;
+                Some((value, default))
+            } else {
+                None
+            };
+            self.expect_keyword(USING)?;
+            let path_column = self.parse_identifier()?;
+            Some(CteCycleClause {
+                columns,
+                mark_column,
+                mark_values,
+                path_column,
+            })
+        } else {
+            None
+        };
+        Ok(CteRecursive {
+            alias,
+            id,
+            query,
+            search,
+            cycle,
+        })
+    }
+
     /// Parse a mutually recursive CTE (`alias ( col1: typ1, col2: typ2, ... ) AS (subquery)`).
     ///
     /// The main distinction from `parse_cte` is that the column names and types are mandatory.
@@ -8204,7 +8614,7 @@ impl<'a> Parser<'a> {
                         on_object,
                     }
                 }
-                ObjectType::Func => {
+                ObjectType::Func | ObjectType::Sequence => {
                     return parser_err!(
                         self,
                         self.peek_prev_pos(),
@@ -8632,15 +9042,44 @@ impl<'a> Parser<'a> {
         } else {
             InsertSource::Query(self.parse_query()?)
         };
+        let on_conflict = if self.parse_keywords(&[ON, CONFLICT]) {
+            Some(self.parse_on_conflict()?)
+        } else {
+            None
+        };
         let returning = self.parse_returning()?;
         Ok(Statement::Insert(InsertStatement {
             table_name,
             columns,
             source,
+            on_conflict,
             returning,
         }))
     }
 
+    fn parse_on_conflict(&mut self) -> Result<OnConflict<Raw>, ParserError> {
+        let columns = self.parse_parenthesized_column_list(Optional)?;
+        self.expect_keyword(DO)?;
+        let action = match self.expect_one_of_keywords(&[NOTHING, UPDATE])? {
+            NOTHING => OnConflictAction::DoNothing,
+            UPDATE => {
+                self.expect_keyword(SET)?;
+                let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                let selection = if self.parse_keyword(WHERE) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                OnConflictAction::DoUpdate {
+                    assignments,
+                    selection,
+                }
+            }
+            _ => unreachable!(),
+        };
+        Ok(OnConflict { columns, action })
+    }
+
     fn parse_returning(&mut self) -> Result<Vec<SelectItem<Raw>>, ParserError> {
         Ok(if self.parse_keyword(RETURNING) {
             self.parse_comma_separated(Parser::parse_select_item)?
@@ -8675,6 +9114,85 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse a `MERGE` statement, assuming that the `MERGE` token has already
+    /// been consumed.
+    fn parse_merge(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(INTO)?;
+        let table_name = RawItemName::Name(self.parse_item_name()?);
+        // The alias here doesn't support columns, so don't use parse_optional_table_alias.
+        let alias = self.parse_optional_alias(Keyword::is_reserved_in_table_alias)?;
+        let alias = alias.map(|name| TableAlias {
+            name,
+            columns: Vec::new(),
+            strict: false,
+        });
+        self.expect_keyword(USING)?;
+        let using = self.parse_table_factor()?;
+        self.expect_keyword(ON)?;
+        let on = self.parse_expr()?;
+        let mut clauses = vec![self.parse_merge_when_clause()?];
+        while self.peek_keyword(WHEN) {
+            clauses.push(self.parse_merge_when_clause()?);
+        }
+        Ok(Statement::Merge(MergeStatement {
+            table_name,
+            alias,
+            using,
+            on,
+            clauses,
+        }))
+    }
+
+    fn parse_merge_when_clause(&mut self) -> Result<MergeWhenClause<Raw>, ParserError> {
+        self.expect_keyword(WHEN)?;
+        let matched = !self.parse_keyword(NOT);
+        self.expect_keyword(MATCHED)?;
+        let condition = if self.parse_keyword(AND) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        self.expect_keyword(THEN)?;
+        if matched {
+            let action = match self.expect_one_of_keywords(&[UPDATE, DELETE, DO])? {
+                UPDATE => {
+                    self.expect_keyword(SET)?;
+                    let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                    MergeMatchedAction::Update { assignments }
+                }
+                DELETE => MergeMatchedAction::Delete,
+                DO => {
+                    self.expect_keyword(NOTHING)?;
+                    MergeMatchedAction::DoNothing
+                }
+                _ => unreachable!(),
+            };
+            Ok(MergeWhenClause::Matched { condition, action })
+        } else {
+            let action = match self.expect_one_of_keywords(&[INSERT, DO])? {
+                INSERT => {
+                    let columns = self.parse_parenthesized_column_list(Optional)?;
+                    let values = if self.parse_keywords(&[DEFAULT, VALUES]) {
+                        vec![]
+                    } else {
+                        self.expect_keyword(VALUES)?;
+                        self.expect_token(&Token::LParen)?;
+                        let values = self.parse_comma_separated(Parser::parse_expr)?;
+                        self.expect_token(&Token::RParen)?;
+                        values
+                    };
+                    MergeNotMatchedAction::Insert { columns, values }
+                }
+                DO => {
+                    self.expect_keyword(NOTHING)?;
+                    MergeNotMatchedAction::DoNothing
+                }
+                _ => unreachable!(),
+            };
+            Ok(MergeWhenClause::NotMatched { condition, action })
+        }
+    }
+
     /// Parse a `var = expr` assignment, used in an UPDATE statement
     fn parse_assignment(&mut self) -> Result<Assignment<Raw>, ParserError> {
         let id = self.parse_identifier()?;
@@ -8941,7 +9459,15 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_subscribe_option(&mut self) -> Result<SubscribeOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[PROGRESS, SNAPSHOT])? {
+        let name = match self.expect_one_of_keywords(&[EMIT, MAX, PROGRESS, SNAPSHOT])? {
+            EMIT => {
+                self.expect_keyword(EVERY)?;
+                SubscribeOptionName::EmitEvery
+            }
+            MAX => {
+                self.expect_keywords(&[ROWS, PER, SECOND])?;
+                SubscribeOptionName::MaxRowsPerSecond
+            }
             PROGRESS => SubscribeOptionName::Progress,
             SNAPSHOT => SubscribeOptionName::Snapshot,
             _ => unreachable!(),
@@ -8986,6 +9512,9 @@ impl<'a> Parser<'a> {
         } else if self.parse_keyword(ANALYZE) || self.parse_keyword(ANALYSE) {
             self.parse_explain_analyze()
                 .map_parser_err(StatementKind::ExplainAnalyzeObject)
+        } else if self.parse_keyword(RECOMMENDATIONS) {
+            self.parse_explain_recommendations()
+                .map_parser_err(StatementKind::ExplainRecommendations)
         } else if self.peek_keyword(KEY) || self.peek_keyword(VALUE) {
             self.parse_explain_schema()
                 .map_parser_err(StatementKind::ExplainSinkSchema)
@@ -9191,6 +9720,16 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_explain_recommendations(&mut self) -> Result<Statement<Raw>, ParserError> {
+        // EXPLAIN RECOMMENDATIONS FOR CLUSTER name
+        self.expect_keywords(&[FOR, CLUSTER])?;
+        let cluster = self.parse_raw_ident()?;
+
+        Ok(Statement::ExplainRecommendations(
+            ExplainRecommendationsStatement { cluster },
+        ))
+    }
+
     fn parse_explain_analyze(&mut self) -> Result<Statement<Raw>, ParserError> {
         // EXPLAIN ANALYZE CLUSTER (MEMORY | CPU) [WITH SKEW] [AS SQL]
         if self.parse_keyword(CLUSTER) {
@@ -9382,6 +9921,7 @@ impl<'a> Parser<'a> {
                 | Statement::Insert(_)
                 | Statement::Delete(_)
                 | Statement::Update(_)
+                | Statement::Merge(_)
                 | Statement::Fetch(_),
         ) {
             return parser_err!(self, pos, "unpreparable statement").map_no_statement_parser_err();
@@ -9471,6 +10011,60 @@ impl<'a> Parser<'a> {
         Ok(Statement::Raise(RaiseStatement { severity }))
     }
 
+    /// Parse a `LISTEN` statement, assuming that the `LISTEN` token
+    /// has already been consumed.
+    fn parse_listen(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = self.parse_identifier()?;
+        Ok(Statement::Listen(ListenStatement { channel }))
+    }
+
+    /// Parse an `UNLISTEN` statement, assuming that the `UNLISTEN` token
+    /// has already been consumed.
+    fn parse_unlisten(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = if self.consume_token(&Token::Star) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(Statement::Unlisten(UnlistenStatement { channel }))
+    }
+
+    /// Parse a `NOTIFY` statement, assuming that the `NOTIFY` token
+    /// has already been consumed.
+    fn parse_notify(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = self.parse_identifier()?;
+        let payload = if self.consume_token(&Token::Comma) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        Ok(Statement::Notify(NotifyStatement { channel, payload }))
+    }
+
+    /// Parse a `CREATE CHANNEL` statement, assuming that the `CREATE` token
+    /// has already been consumed.
+    fn parse_create_channel(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(CHANNEL)?;
+        let channel = self.parse_identifier()?;
+        self.expect_keywords(&[ON, VIEW])?;
+        let view = self.parse_raw_name()?;
+        Ok(Statement::CreateChannel(CreateChannelStatement {
+            channel,
+            view,
+        }))
+    }
+
+    /// Parse a `DROP CHANNEL` statement, assuming that the `DROP CHANNEL`
+    /// tokens have already been consumed.
+    fn parse_drop_channel(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let channel = self.parse_identifier()?;
+        Ok(Statement::DropChannel(DropChannelStatement {
+            if_exists,
+            channel,
+        }))
+    }
+
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
@@ -9663,8 +10257,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Index
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::Func
-            | ObjectType::Subsource => {
+            | ObjectType::Subsource
+            | ObjectType::Sequence => {
                 parser_err!(
                     self,
                     self.peek_prev_pos(),
@@ -9673,6 +10267,7 @@ impl<'a> Parser<'a> {
             }
             ObjectType::Table
             | ObjectType::Type
+            | ObjectType::Func
             | ObjectType::Cluster
             | ObjectType::Secret
             | ObjectType::Connection
@@ -9703,6 +10298,7 @@ impl<'a> Parser<'a> {
                 FUNCTION,
                 CONTINUAL,
                 NETWORK,
+                SEQUENCE,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -9744,6 +10340,7 @@ impl<'a> Parser<'a> {
                     }
                     ObjectType::NetworkPolicy
                 }
+                SEQUENCE => ObjectType::Sequence,
                 _ => unreachable!(),
             },
         )
@@ -10021,6 +10618,7 @@ impl<'a> Parser<'a> {
                 SELECT,
                 UPDATE,
                 DELETE,
+                EXECUTE,
                 USAGE,
                 CREATE,
                 CREATEROLE,
@@ -10032,6 +10630,7 @@ impl<'a> Parser<'a> {
                 SELECT => Privilege::SELECT,
                 UPDATE => Privilege::UPDATE,
                 DELETE => Privilege::DELETE,
+                EXECUTE => Privilege::EXECUTE,
                 USAGE => Privilege::USAGE,
                 CREATE => Privilege::CREATE,
                 CREATEROLE => Privilege::CREATEROLE,
@@ -849,6 +849,11 @@ impl Pretty {
                 CteBlock::Simple(ctes) => {
                     docs.push(title_comma_separate("WITH", |cte| self.doc_cte(cte), ctes))
                 }
+                CteBlock::Recursive(ctes) => docs.push(title_comma_separate(
+                    "WITH RECURSIVE",
+                    |cte| self.doc_cte_recursive(cte),
+                    ctes,
+                )),
                 CteBlock::MutuallyRecursive(mutrec) => {
                     let mut doc = RcDoc::text("WITH MUTUALLY RECURSIVE");
                     if !mutrec.options.is_empty() {
@@ -906,6 +911,23 @@ impl Pretty {
         ])
     }
 
+    fn doc_cte_recursive<'a, T: AstInfo>(&'a self, v: &'a CteRecursive<T>) -> RcDoc<'a> {
+        let mut docs = vec![
+            RcDoc::text(format!("{} AS", v.alias)),
+            RcDoc::line(),
+            bracket("(", self.doc_query(&v.query), ")"),
+        ];
+        if let Some(search) = &v.search {
+            docs.push(RcDoc::line());
+            docs.push(self.doc_display_pass(search));
+        }
+        if let Some(cycle) = &v.cycle {
+            docs.push(RcDoc::line());
+            docs.push(self.doc_display_pass(cycle));
+        }
+        RcDoc::concat(docs)
+    }
+
     fn doc_mutually_recursive<'a, T: AstInfo>(&'a self, v: &'a CteMutRec<T>) -> RcDoc<'a> {
         let mut docs = Vec::new();
         if !v.columns.is_empty() {
@@ -14,14 +14,17 @@ use std::collections::{BTreeMap, BTreeSet};
 use mz_ore::str::StrExt;
 use mz_repr::CatalogItemId;
 use mz_sql_parser::ast::CreateTableFromSourceStatement;
+use mz_sql_parser::parser;
 
+use crate::ast::display::AstDisplay;
 use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
     AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    CreateSecretStatement, CreateSequenceStatement, CreateSinkStatement, CreateSourceStatement,
+    CreateSubsourceStatement, CreateTableStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, Expr, Function, FunctionArgs, Ident, Query, Raw, RawItemName,
+    Statement, UnresolvedItemName, Value, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -47,6 +50,8 @@ pub fn create_stmt_rename_schema_refs(
         | stmt @ Statement::CreateTableFromSource(_)
         | stmt @ Statement::CreateIndex(_)
         | stmt @ Statement::CreateType(_)
+        | stmt @ Statement::CreateFunction(_)
+        | stmt @ Statement::CreateSequence(_)
         | stmt @ Statement::CreateSecret(_) => {
             let mut visitor = CreateSqlRewriteSchema {
                 database,
@@ -108,6 +113,20 @@ impl<'a, 'ast> VisitMut<'ast, Raw> for CreateSqlRewriteSchema<'a> {
             Expr::QualifiedWildcard(id) => {
                 self.maybe_rewrite_idents(id);
             }
+            // Sequence defaults name their sequence in a string literal.
+            Expr::Function(Function {
+                name,
+                args: FunctionArgs::Args { args, .. },
+                ..
+            }) if name.name().0.last().map(|i| i.as_str()) == Some("nextval") => {
+                self.visit_item_name_mut(name);
+                if let [Expr::Value(Value::String(sequence))] = &mut args[..]
+                    && let Ok(mut sequence_name) = parser::parse_item_name(sequence)
+                {
+                    self.maybe_rewrite_idents(&mut sequence_name.0);
+                    *sequence = sequence_name.to_ast_string_stable();
+                }
+            }
             _ => visit_mut::visit_expr_mut(self, e),
         }
     }
@@ -199,6 +218,11 @@ pub fn create_stmt_rename_refs(
         Statement::CreateSink(CreateSinkStatement { from, .. }) => {
             maybe_update_item_name(from.name_mut());
         }
+        Statement::CreateSequence(CreateSequenceStatement { owned_by, .. }) => {
+            if let Some(owned_by) = owned_by {
+                maybe_update_item_name(owned_by.relation.name_mut());
+            }
+        }
         Statement::CreateView(CreateViewStatement {
             definition: ViewDefinition { query, .. },
             ..
@@ -53,7 +53,10 @@ use crate::names::{
 };
 use crate::plan::statement::StatementDesc;
 use crate::plan::statement::ddl::PlannedRoleAttributes;
-use crate::plan::{ClusterSchedule, CreateClusterPlan, PlanError, PlanNotice, query};
+use crate::plan::{
+    ClusterSchedule, CreateClusterPlan, PlanError, PlanNotice, SequenceOptions, SqlFunction,
+    TableUnique, query,
+};
 use crate::session::vars::{OwnedVarInput, SystemVars};
 
 /// A catalog keeps track of SQL objects and session state available to the
@@ -814,6 +817,14 @@ pub trait CatalogItem {
     /// anything other than a function), it returns an error.
     fn func(&self) -> Result<&'static Func, CatalogError>;
 
+    /// Returns the definition of the function, if the catalog item is a
+    /// function defined in SQL with `CREATE FUNCTION`.
+    fn sql_function(&self) -> Option<&SqlFunction>;
+
+    /// Returns the parameters of the sequence, if the catalog item is a
+    /// sequence.
+    fn sequence_options(&self) -> Option<&SequenceOptions>;
+
     /// Returns the resolved source connection.
     ///
     /// If the catalog item is not of a type that contains a `SourceDesc`
@@ -877,6 +888,10 @@ pub trait CatalogItem {
     /// catalog item is a table that accepts writes.
     fn writable_table_details(&self) -> Option<&[Expr<Aug>]>;
 
+    /// Returns the `PRIMARY KEY` and `UNIQUE` constraints of the catalog item,
+    /// if it is a table that accepts writes.
+    fn table_uniques(&self) -> &[TableUnique];
+
     /// The item this catalog item replaces, if any.
     fn replacement_target(&self) -> Option<CatalogItemId>;
 
@@ -950,6 +965,8 @@ pub enum CatalogItemType {
     Connection,
     /// A continual task.
     ContinualTask,
+    /// A sequence.
+    Sequence,
 }
 
 impl CatalogItemType {
@@ -984,6 +1001,7 @@ impl CatalogItemType {
             CatalogItemType::Secret => false,
             CatalogItemType::Connection => false,
             CatalogItemType::ContinualTask => true,
+            CatalogItemType::Sequence => true,
         }
     }
 }
@@ -1002,6 +1020,7 @@ impl fmt::Display for CatalogItemType {
             CatalogItemType::Secret => f.write_str("secret"),
             CatalogItemType::Connection => f.write_str("connection"),
             CatalogItemType::ContinualTask => f.write_str("continual task"),
+            CatalogItemType::Sequence => f.write_str("sequence"),
         }
     }
 }
@@ -1020,6 +1039,7 @@ impl From<CatalogItemType> for ObjectType {
             CatalogItemType::Secret => ObjectType::Secret,
             CatalogItemType::Connection => ObjectType::Connection,
             CatalogItemType::ContinualTask => ObjectType::ContinualTask,
+            CatalogItemType::Sequence => ObjectType::Sequence,
         }
     }
 }
@@ -1038,6 +1058,7 @@ impl From<CatalogItemType> for mz_audit_log::ObjectType {
             CatalogItemType::Secret => mz_audit_log::ObjectType::Secret,
             CatalogItemType::Connection => mz_audit_log::ObjectType::Connection,
             CatalogItemType::ContinualTask => mz_audit_log::ObjectType::ContinualTask,
+            CatalogItemType::Sequence => mz_audit_log::ObjectType::Sequence,
         }
     }
 }
@@ -1566,6 +1587,7 @@ pub enum ObjectType {
     Func,
     ContinualTask,
     NetworkPolicy,
+    Sequence,
 }
 
 impl ObjectType {
@@ -1588,7 +1610,8 @@ impl ObjectType {
             | ObjectType::Cluster
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::NetworkPolicy => false,
+            | ObjectType::NetworkPolicy
+            | ObjectType::Sequence => false,
         }
     }
 }
@@ -1614,6 +1637,7 @@ impl From<mz_sql_parser::ast::ObjectType> for ObjectType {
             mz_sql_parser::ast::ObjectType::Func => ObjectType::Func,
             mz_sql_parser::ast::ObjectType::ContinualTask => ObjectType::ContinualTask,
             mz_sql_parser::ast::ObjectType::NetworkPolicy => ObjectType::NetworkPolicy,
+            mz_sql_parser::ast::ObjectType::Sequence => ObjectType::Sequence,
         }
     }
 }
@@ -1638,6 +1662,7 @@ impl From<CommentObjectId> for ObjectType {
             CommentObjectId::ClusterReplica(_) => ObjectType::ClusterReplica,
             CommentObjectId::ContinualTask(_) => ObjectType::ContinualTask,
             CommentObjectId::NetworkPolicy(_) => ObjectType::NetworkPolicy,
+            CommentObjectId::Sequence(_) => ObjectType::Sequence,
         }
     }
 }
@@ -1662,6 +1687,7 @@ impl Display for ObjectType {
             ObjectType::Func => "FUNCTION",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Sequence => "SEQUENCE",
         })
     }
 }
@@ -21,12 +21,20 @@ use mz_expr::func::variadic;
 use mz_ore::collections::CollectionExt;
 use mz_ore::str::StrExt;
 use mz_pgrepr::oid;
+use mz_repr::namespaces::MZ_CATALOG_SCHEMA;
 use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, Datum, SqlRelationType, SqlScalarBaseType, SqlScalarType};
 
-use crate::ast::{SelectStatement, Statement};
-use crate::catalog::{CatalogType, TypeCategory, TypeReference};
+use mz_sql_parser::ast::display::AstDisplay;
+use mz_sql_parser::ast::visit_mut::{self, VisitMut};
+
+use crate::ast::{
+    Expr, Function, FunctionArgs, Query, Raw, RawItemName, SelectStatement, Statement,
+    UnresolvedItemName,
+};
+use crate::catalog::{CatalogType, SessionCatalog, TypeCategory, TypeReference};
 use crate::names::{self, ResolvedItemName};
+use crate::normalize;
 use crate::plan::error::PlanError;
 use crate::plan::hir::{
     AggregateFunc, BinaryFunc, CoercibleScalarExpr, CoercibleScalarType, ColumnOrder,
@@ -492,6 +500,190 @@ fn experimental_sql_impl_table_func(
     sql_impl_table_func_inner(sql, Some(feature))
 }
 
+/// The windowing table functions, which take a relation as their first argument.
+#[derive(Clone, Copy, Debug)]
+enum WindowTableFunc {
+    /// `tumble(relation, time_column, size)`
+    Tumble,
+    /// `hop(relation, time_column, slide, size)`
+    Hop,
+    /// `session(relation, time_column, key_column, gap)`
+    Session,
+}
+
+impl WindowTableFunc {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "tumble" => Some(WindowTableFunc::Tumble),
+            "hop" => Some(WindowTableFunc::Hop),
+            "session" => Some(WindowTableFunc::Session),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            WindowTableFunc::Tumble => "tumble",
+            WindowTableFunc::Hop => "hop",
+            WindowTableFunc::Session => "session",
+        }
+    }
+
+    /// The number of column arguments following the relation.
+    fn column_params(&self) -> usize {
+        match self {
+            WindowTableFunc::Tumble | WindowTableFunc::Hop => 1,
+            WindowTableFunc::Session => 2,
+        }
+    }
+
+    /// The number of scalar arguments following the column arguments.
+    fn scalar_params(&self) -> usize {
+        match self {
+            WindowTableFunc::Tumble | WindowTableFunc::Session => 1,
+            WindowTableFunc::Hop => 2,
+        }
+    }
+
+    /// Returns the SQL the function expands to. `$n` refers to the `n`th scalar argument.
+    ///
+    /// Every row of `relation` is extended by the `window_start` and `window_end` of each window
+    /// it belongs to. Windows are half-open intervals and, for `tumble` and `hop`, aligned to the
+    /// Unix epoch.
+    fn sql(&self, relation: &str, columns: &[String]) -> String {
+        const INPUT: &str = "mz_window_input";
+        match self {
+            WindowTableFunc::Tumble => {
+                let ts = &columns[0];
+                format!(
+                    "SELECT {INPUT}.*,
+                        pg_catalog.date_bin($1, {INPUT}.{ts}, '1970-01-01') AS window_start,
+                        pg_catalog.date_bin($1, {INPUT}.{ts}, '1970-01-01') + $1 AS window_end
+                    FROM {relation} AS {INPUT}"
+                )
+            }
+            WindowTableFunc::Hop => {
+                let ts = &columns[0];
+                format!(
+                    "SELECT {INPUT}.*, hops.window_start, hops.window_start + $2 AS window_end
+                    FROM {relation} AS {INPUT},
+                        pg_catalog.generate_series(
+                            pg_catalog.date_bin($1, {INPUT}.{ts} - $2, '1970-01-01') + $1,
+                            {INPUT}.{ts},
+                            $1
+                        ) AS hops(window_start)"
+                )
+            }
+            // A session of a key lasts from its first event until `gap` after its last event, and
+            // an event within a session's bounds extends the session. The session window
+            // functions compute the sessions of each key in one reduction, which merges and
+            // splits the sessions of a key as late events arrive or are retracted.
+            WindowTableFunc::Session => {
+                let (ts, key) = (&columns[0], &columns[1]);
+                let args = format!("{INPUT}.{ts}, {INPUT}.{ts} + $1");
+                let over = format!("OVER (PARTITION BY {INPUT}.{key} ORDER BY {INPUT}.{ts})");
+                format!(
+                    "SELECT {INPUT}.*,
+                        mz_internal.mz_session_window_start({args}) {over} AS window_start,
+                        mz_internal.mz_session_window_end({args}) {over} AS window_end
+                    FROM {relation} AS {INPUT}"
+                )
+            }
+        }
+    }
+}
+
+/// Expands a call to one of the windowing table functions `tumble`, `hop`, and `session` into
+/// the query that implements it, or returns `None` if `function` does not resolve to one of the builtins.
+///
+/// Unlike other table functions, these take a relation as their first argument, which needs to
+/// be resolved like any other relation in the query. The expansion thus happens during name
+/// resolution rather than during planning. Names that resolve to any other function, e.g. a
+/// user-defined `tumble`, are left to the regular function resolution.
+pub(crate) fn expand_window_table_function(
+    catalog: &dyn SessionCatalog,
+    function: &Function<Raw>,
+) -> Option<Result<Query<Raw>, PlanError>> {
+    let RawItemName::Name(name) = &function.name else {
+        return None;
+    };
+    let name = normalize::unresolved_item_name(name.clone()).ok()?;
+    let item = catalog.resolve_function(&name).ok()?;
+    let full_name = catalog.resolve_full_name(item.name());
+    if !item.id().is_system() || full_name.schema != MZ_CATALOG_SCHEMA {
+        return None;
+    }
+    let func = WindowTableFunc::from_name(&full_name.item)?;
+    Some(expand_window_table_function_inner(func, function))
+}
+
+fn expand_window_table_function_inner(
+    func: WindowTableFunc,
+    function: &Function<Raw>,
+) -> Result<Query<Raw>, PlanError> {
+    let name = func.name();
+    let args = match &function.args {
+        FunctionArgs::Args { args, order_by } if order_by.is_empty() => args,
+        _ => sql_bail!("{name} requires a relation argument"),
+    };
+    if function.filter.is_some() || function.over.is_some() || function.distinct {
+        sql_bail!("{name} does not support FILTER, OVER, or DISTINCT");
+    }
+    let arity = 1 + func.column_params() + func.scalar_params();
+    if args.len() != arity {
+        sql_bail!("{name} expects {arity} arguments, got {}", args.len());
+    }
+
+    let relation = match &args[0] {
+        Expr::Identifier(names) => UnresolvedItemName(names.clone()).to_ast_string_stable(),
+        _ => sql_bail!("first argument of {name} must be the name of a relation"),
+    };
+    let columns = args[1..=func.column_params()]
+        .iter()
+        .map(|arg| match arg {
+            Expr::Identifier(names) if names.len() == 1 => Ok(names[0].to_ast_string_stable()),
+            _ => sql_bail!("{name} expects column names after the relation"),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let scalars = &args[1 + func.column_params()..];
+
+    let sql = func.sql(&relation, &columns);
+    let mut query = match mz_sql_parser::parser::parse_statements(&sql)
+        .map_err(|e| sql_err!("invalid arguments to {name}: {}", e.error))?
+        .into_element()
+        .ast
+    {
+        Statement::Select(SelectStatement { query, as_of: None }) => query,
+        _ => unreachable!("window table functions expand to SELECT statements"),
+    };
+
+    struct SpliceParameters<'a> {
+        args: &'a [Expr<Raw>],
+    }
+
+    impl<'ast> VisitMut<'ast, Raw> for SpliceParameters<'_> {
+        fn visit_expr_mut(&mut self, expr: &'ast mut Expr<Raw>) {
+            match expr {
+                Expr::Parameter(n) => *expr = self.args[*n - 1].clone(),
+                _ => visit_mut::visit_expr_mut(self, expr),
+            }
+        }
+    }
+
+    SpliceParameters { args: scalars }.visit_query_mut(&mut query);
+    Ok(query)
+}
+
+/// Encodes the arguments of the session window functions into a single record.
+fn session_window_args(time: HirScalarExpr, reach: HirScalarExpr) -> HirScalarExpr {
+    HirScalarExpr::call_variadic(
+        variadic::RecordCreate {
+            field_names: vec![ColumnName::from("time"), ColumnName::from("reach")],
+        },
+        vec![time, reach],
+    )
+}
+
 /// Describes a single function's implementation.
 pub struct FuncImpl<R> {
     pub oid: u32,
@@ -4177,28 +4369,31 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
     };
 
     // Add side-effecting functions, which are defined in a separate module
-    // using a restricted set of function definition features (e.g., no
-    // overloads) to make them easier to plan.
+    // using a restricted set of function definition features (e.g., only
+    // overloads on the number of arguments) to make them easier to plan.
     for sef_builtin in PG_CATALOG_SEF_BUILTINS.values() {
-        builtins.insert(
-            sef_builtin.name,
-            Func::Scalar(vec![FuncImpl {
-                oid: sef_builtin.oid,
-                params: ParamList::Exact(
-                    sef_builtin
-                        .param_types
-                        .iter()
-                        .map(|t| ParamType::from(t.clone()))
-                        .collect(),
-                ),
-                return_type: ReturnType::scalar(ParamType::from(
-                    sef_builtin.return_type.scalar_type.clone(),
-                )),
-                op: Operation::variadic(|_ecx, _e| {
-                    bail_unsupported!(format!("{} in this position", sef_builtin.name))
-                }),
-            }]),
-        );
+        let func_impl = FuncImpl {
+            oid: sef_builtin.oid,
+            params: ParamList::Exact(
+                sef_builtin
+                    .param_types
+                    .iter()
+                    .map(|t| ParamType::from(t.clone()))
+                    .collect(),
+            ),
+            return_type: ReturnType::scalar(ParamType::from(
+                sef_builtin.return_type.scalar_type.clone(),
+            )),
+            op: Operation::variadic(|_ecx, _e| {
+                bail_unsupported!(format!("{} in this position", sef_builtin.name))
+            }),
+        };
+        match builtins.get_mut(sef_builtin.name) {
+            Some(Func::Scalar(impls)) => impls.push(func_impl),
+            _ => {
+                builtins.insert(sef_builtin.name, Func::Scalar(vec![func_impl]));
+            }
+        }
     }
 
     builtins
@@ -4534,6 +4729,13 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 "has_type_privilege(current_user, $1, $2)",
             ) => Bool, 3143;
         },
+        // The windowing table functions take a relation as their first argument and are
+        // expanded during name resolution. See `expand_window_table_function`.
+        "hop" => Table {
+            params!(Any, Any, Interval, Interval) => Operation::variadic(|_ecx, _exprs| {
+                sql_bail!("hop must be called in the FROM clause with a relation argument")
+            }) => ReturnType::set_of(RecordAny), oid::FUNC_HOP_OID;
+        },
         "kafka_murmur2" => Scalar {
             params!(String) => UnaryFunc::KafkaMurmur2String(func::KafkaMurmur2String)
                 => Int32, oid::FUNC_KAFKA_MURMUR2_STRING_OID;
@@ -4751,6 +4953,11 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Bytes) => UnaryFunc::SeahashBytes(func::SeahashBytes)
                 => UInt32, oid::FUNC_SEAHASH_BYTES_OID;
         },
+        "session" => Table {
+            params!(Any, Any, Any, Interval) => Operation::variadic(|_ecx, _exprs| {
+                sql_bail!("session must be called in the FROM clause with a relation argument")
+            }) => ReturnType::set_of(RecordAny), oid::FUNC_SESSION_OID;
+        },
         "starts_with" => Scalar {
             params!(String, String) => BinaryFunc::from(func::StartsWith) => Bool, 3696;
         },
@@ -4758,6 +4965,11 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, TimestampTz) => BinaryFunc::from(func::TimezoneOffset)
                 => RecordAny, oid::FUNC_TIMEZONE_OFFSET;
         },
+        "tumble" => Table {
+            params!(Any, Any, Interval) => Operation::variadic(|_ecx, _exprs| {
+                sql_bail!("tumble must be called in the FROM clause with a relation argument")
+            }) => ReturnType::set_of(RecordAny), oid::FUNC_TUMBLE_OID;
+        },
         "try_parse_monotonic_iso8601_timestamp" => Scalar {
             params!(String) => Operation::unary(move |_ecx, e| {
                 Ok(e.call_unary(UnaryFunc::TryParseMonotonicIso8601Timestamp(
@@ -5214,6 +5426,25 @@ pub static MZ_INTERNAL_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLo
         "mz_session_id" => Scalar {
             params!() => UnmaterializableFunc::MzSessionId => Uuid, oid::FUNC_MZ_SESSION_ID_OID;
         },
+        // The session window functions implement `session`. Rows need to be ordered by their
+        // time, and their reach is their time plus the gap of the sessions. See
+        // `expand_window_table_function`.
+        "mz_session_window_end" => ValueWindow {
+            params!(Timestamp, Timestamp) => Operation::binary(|_ecx, time, reach| {
+                Ok((session_window_args(time, reach), ValueWindowFunc::SessionWindowEnd))
+            }) => Timestamp, oid::FUNC_MZ_SESSION_WINDOW_END_TS_OID;
+            params!(TimestampTz, TimestampTz) => Operation::binary(|_ecx, time, reach| {
+                Ok((session_window_args(time, reach), ValueWindowFunc::SessionWindowEnd))
+            }) => TimestampTz, oid::FUNC_MZ_SESSION_WINDOW_END_TSTZ_OID;
+        },
+        "mz_session_window_start" => ValueWindow {
+            params!(Timestamp, Timestamp) => Operation::binary(|_ecx, time, reach| {
+                Ok((session_window_args(time, reach), ValueWindowFunc::SessionWindowStart))
+            }) => Timestamp, oid::FUNC_MZ_SESSION_WINDOW_START_TS_OID;
+            params!(TimestampTz, TimestampTz) => Operation::binary(|_ecx, time, reach| {
+                Ok((session_window_args(time, reach), ValueWindowFunc::SessionWindowStart))
+            }) => TimestampTz, oid::FUNC_MZ_SESSION_WINDOW_START_TSTZ_OID;
+        },
         "mz_type_name" => Scalar {
             params!(Oid) => UnaryFunc::MzTypeName(func::MzTypeName)
                 => String, oid::FUNC_MZ_TYPE_NAME;
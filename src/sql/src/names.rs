@@ -37,15 +37,17 @@ use crate::ast::fold::{Fold, FoldNode};
 use crate::ast::visit::{Visit, VisitNode};
 use crate::ast::visit_mut::VisitMut;
 use crate::ast::{
-    self, AstInfo, Cte, CteBlock, CteMutRec, DocOnIdentifier, GrantTargetSpecification,
-    GrantTargetSpecificationInner, Ident, MutRecBlock, ObjectType, Query, Raw, RawClusterName,
-    RawDataType, RawItemName, Statement, UnresolvedItemName, UnresolvedObjectName,
+    self, AstInfo, Cte, CteBlock, CteMutRec, CteRecursive, DocOnIdentifier,
+    GrantTargetSpecification, GrantTargetSpecificationInner, Ident, MutRecBlock, ObjectType, Query,
+    Raw, RawClusterName, RawDataType, RawItemName, Statement, TableAlias, UnresolvedItemName,
+    UnresolvedObjectName,
 };
 use crate::catalog::{
     CatalogError, CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails, SessionCatalog,
 };
 use crate::normalize;
 use crate::plan::PlanError;
+use crate::session::vars;
 
 /// A fully-qualified human readable name of an item in the catalog.
 ///
@@ -1270,7 +1272,8 @@ impl From<CommentObjectId> for ObjectId {
             | CommentObjectId::Connection(item_id)
             | CommentObjectId::Type(item_id)
             | CommentObjectId::Secret(item_id)
-            | CommentObjectId::ContinualTask(item_id) => ObjectId::Item(item_id),
+            | CommentObjectId::ContinualTask(item_id)
+            | CommentObjectId::Sequence(item_id) => ObjectId::Item(item_id),
             CommentObjectId::Role(id) => ObjectId::Role(id),
             CommentObjectId::Database(id) => ObjectId::Database(id),
             CommentObjectId::Schema(id) => ObjectId::Schema(id),
@@ -1326,6 +1329,7 @@ pub enum CommentObjectId {
     Type(CatalogItemId),
     Secret(CatalogItemId),
     ContinualTask(CatalogItemId),
+    Sequence(CatalogItemId),
     Role(RoleId),
     Database(DatabaseId),
     Schema((ResolvedDatabaseSpecifier, SchemaSpecifier)),
@@ -1715,6 +1719,33 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 }
                 CteBlock::Simple(result_ctes)
             }
+            CteBlock::Recursive(ctes) => {
+                let mut result_ctes = Vec::<CteRecursive<Aug>>::new();
+
+                let initial_id = self.ctes.len();
+
+                // As in `WITH MUTUALLY RECURSIVE`, all bindings are in scope for
+                // all queries of the block. The planner rejects references to
+                // bindings that are not yet defined.
+                for (offset, cte) in ctes.iter().enumerate() {
+                    let cte_name = normalize::ident(cte.alias.name.clone());
+                    let local_id = LocalId::new(u64::cast_from(initial_id + offset));
+                    let shadowed_id = self.ctes.insert(cte_name.clone(), local_id);
+                    shadowed_cte_ids.push((cte_name, shadowed_id));
+                }
+
+                for (offset, cte) in ctes.into_iter().enumerate() {
+                    let local_id = LocalId::new(u64::cast_from(initial_id + offset));
+                    result_ctes.push(CteRecursive {
+                        alias: cte.alias,
+                        id: local_id,
+                        query: self.fold_query(cte.query),
+                        search: cte.search,
+                        cycle: cte.cycle,
+                    });
+                }
+                CteBlock::Recursive(result_ctes)
+            }
             CteBlock::MutuallyRecursive(MutRecBlock { options, ctes }) => {
                 let mut result_ctes = Vec::<CteMutRec<Aug>>::new();
 
@@ -1804,6 +1835,45 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
         mz_sql_parser::ast::fold::fold_create_continual_task_statement(self, stmt)
     }
 
+    fn fold_create_table_statement(
+        &mut self,
+        mut stmt: ast::CreateTableStatement<Raw>,
+    ) -> ast::CreateTableStatement<Aug> {
+        // Rewrite the `serial` pseudo-types into their underlying integer type
+        // plus an identity column, as PostgreSQL does.
+        for column in &mut stmt.columns {
+            let RawDataType::Other { name, typ_mod } = &column.data_type else {
+                continue;
+            };
+            let RawItemName::Name(UnresolvedItemName(parts)) = name else {
+                continue;
+            };
+            if !typ_mod.is_empty() || parts.len() != 1 {
+                continue;
+            }
+            let int_type = match parts[0].as_str() {
+                "smallserial" | "serial2" => "int2",
+                "serial" | "serial4" => "int4",
+                "bigserial" | "serial8" => "int8",
+                _ => continue,
+            };
+            column.data_type = RawDataType::Other {
+                name: RawItemName::Name(UnresolvedItemName::unqualified(Ident::new_unchecked(
+                    int_type,
+                ))),
+                typ_mod: vec![],
+            };
+            column.options.push(ast::ColumnOptionDef {
+                name: None,
+                option: ast::ColumnOption::Identity {
+                    always: false,
+                    options: vec![],
+                },
+            });
+        }
+        mz_sql_parser::ast::fold::fold_create_table_statement(self, stmt)
+    }
+
     fn fold_cte_id(&mut self, _id: <Raw as AstInfo>::CteId) -> <Aug as AstInfo>::CteId {
         panic!("this should have been handled when walking the CTE");
     }
@@ -2238,6 +2308,46 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 alias,
                 with_ordinality,
             } => {
+                if let Some(expansion) =
+                    crate::func::expand_window_table_function(self.catalog, &function)
+                {
+                    let name = match &function.name {
+                        RawItemName::Name(name) => name.0.last().expect("non-empty name"),
+                        RawItemName::Id(..) => unreachable!("only names expand"),
+                    }
+                    .to_ast_string_stable();
+                    let subquery = match expansion.and_then(|query| {
+                        if with_ordinality {
+                            sql_bail!("{name} does not support WITH ORDINALITY");
+                        }
+                        vars::ENABLE_WINDOW_TABLE_FUNCTIONS.require(self.catalog.system_vars())?;
+                        Ok(query)
+                    }) {
+                        Ok(query) => query,
+                        Err(e) => {
+                            if self.status.is_ok() {
+                                self.status = Err(e);
+                            }
+                            return Function {
+                                function: self.fold_function(function),
+                                alias: alias.map(|alias| self.fold_table_alias(alias)),
+                                with_ordinality,
+                            };
+                        }
+                    };
+                    // Like other table functions, name the relation after the function.
+                    let alias = alias.unwrap_or_else(|| TableAlias {
+                        name: Ident::new_unchecked(name),
+                        columns: vec![],
+                        strict: false,
+                    });
+                    return Derived {
+                        lateral: false,
+                        subquery: Box::new(self.fold_query(subquery)),
+                        alias: Some(self.fold_table_alias(alias)),
+                    };
+                }
+
                 match &function.name {
                     RawItemName::Name(name) => {
                         if *name == UnresolvedItemName::unqualified(ident!("values"))
@@ -2289,10 +2399,10 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
     ) -> GrantTargetSpecification<Aug> {
         match node {
             GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
+                object_type: object_type @ (ObjectType::Type | ObjectType::Func),
                 object_spec_inner: GrantTargetSpecificationInner::Objects { names },
             } => GrantTargetSpecification::Object {
-                object_type: ObjectType::Type,
+                object_type,
                 object_spec_inner: GrantTargetSpecificationInner::Objects {
                     names: names
                         .into_iter()
@@ -2301,10 +2411,11 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                                 ResolvedObjectName::Item(self.resolve_item_name_name(
                                     name,
                                     // `{GRANT|REVOKE} ... ON TYPE ...` can only
-                                    // refer to type names.
+                                    // refer to type names, and `{GRANT|REVOKE}
+                                    // ... ON FUNCTION ...` to function names.
                                     ItemResolutionConfig {
-                                        functions: false,
-                                        types: true,
+                                        functions: object_type == ObjectType::Func,
+                                        types: object_type == ObjectType::Type,
                                         relations: false,
                                     },
                                 ))
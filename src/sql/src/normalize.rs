@@ -22,8 +22,9 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     ContinualTaskStmt, CreateConnectionStatement, CreateContinualTaskStatement,
-    CreateContinualTaskSugar, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
+    CreateContinualTaskSugar, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateSecretStatement, CreateSequenceStatement,
+    CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
     CreateTableFromSourceStatement, CreateTableStatement, CreateTypeStatement, CreateViewStatement,
     CreateWebhookSourceStatement, CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior,
     MutRecBlock, Op, Query, Statement, TableFactor, TableFromSourceColumns, UnresolvedItemName,
@@ -208,6 +209,11 @@ pub fn create_statement(
                         self.ctes.push(cte.alias.name.clone());
                     }
                 }
+                CteBlock::Recursive(ctes) => {
+                    for cte in ctes.iter() {
+                        self.ctes.push(cte.alias.name.clone());
+                    }
+                }
                 CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
                     for cte in ctes.iter() {
                         self.ctes.push(cte.name.clone());
@@ -326,7 +332,7 @@ pub fn create_statement(
         Statement::CreateTable(CreateTableStatement {
             name,
             columns,
-            constraints: _,
+            constraints,
             if_not_exists,
             temporary,
             with_options: _,
@@ -340,6 +346,9 @@ pub fn create_statement(
             for c in columns {
                 normalizer.visit_column_def_mut(c);
             }
+            for c in constraints {
+                normalizer.visit_table_constraint_mut(c);
+            }
             if let Some(err) = normalizer.err {
                 return Err(err);
             }
@@ -482,6 +491,35 @@ pub fn create_statement(
                 return Err(err);
             }
         }
+        Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            if_not_exists,
+            params,
+            returns_set: _,
+            return_type,
+            body,
+        }) => {
+            *name = allocate_name(name)?;
+            let mut normalizer = QueryNormalizer::new();
+            for param in params {
+                normalizer.visit_function_parameter_mut(param);
+            }
+            normalizer.visit_data_type_mut(return_type);
+            normalizer.visit_function_body_mut(body);
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+            *if_not_exists = false;
+        }
+        Statement::CreateSequence(CreateSequenceStatement {
+            name,
+            if_not_exists,
+            options: _,
+            owned_by: _,
+        }) => {
+            *name = allocate_name(name)?;
+            *if_not_exists = false;
+        }
         Statement::CreateSecret(CreateSecretStatement {
             name,
             if_not_exists,
@@ -37,8 +37,11 @@ use ipnet::IpNet;
 use maplit::btreeset;
 use mz_adapter_types::compaction::CompactionWindow;
 use mz_controller_types::{ClusterId, ReplicaId};
-use mz_expr::{CollectionPlan, ColumnOrder, MapFilterProject, MirScalarExpr, RowSetFinishing};
+use mz_expr::{
+    CollectionPlan, ColumnOrder, EvalError, MapFilterProject, MirScalarExpr, RowSetFinishing,
+};
 use mz_ore::now::{self, NOW_ZERO};
+use mz_persist_types::parquet::CompressionFormat;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
@@ -47,8 +50,8 @@ use mz_repr::optimize::OptimizerFeatureOverrides;
 use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
-    CatalogItemId, ColumnIndex, ColumnName, Diff, GlobalId, RelationDesc, ReprColumnType, Row,
-    SqlColumnType, SqlRelationType, SqlScalarType, Timestamp, VersionedRelationDesc,
+    CatalogItemId, ColumnIndex, ColumnName, Datum, Diff, GlobalId, RelationDesc, ReprColumnType,
+    Row, RowArena, SqlColumnType, SqlRelationType, SqlScalarType, Timestamp, VersionedRelationDesc,
 };
 use mz_sql_parser::ast::{
     AlterSourceAddSubsourceOption, ClusterAlterOptionValue, ConnectionOptionName, QualifiedReplica,
@@ -57,6 +60,7 @@ use mz_sql_parser::ast::{
 };
 use mz_ssh_util::keys::SshKeyPair;
 use mz_storage_types::connections::aws::AwsConnection;
+use mz_storage_types::connections::gcs::GcsConnection;
 use mz_storage_types::connections::inline::ReferencedConnection;
 use mz_storage_types::connections::{
     AwsPrivatelinkConnection, CsrConnection, IcebergCatalogConnection, KafkaConnection,
@@ -118,6 +122,7 @@ pub use statement::ddl::{
     PlannedAlterRoleOption, PlannedRoleAttributes, PlannedRoleVariable,
     SqlServerConfigOptionExtracted,
 };
+pub use statement::notify::MAX_NOTIFY_PAYLOAD_SIZE;
 pub use statement::{
     StatementClassification, StatementContext, StatementDesc, describe, plan, plan_copy_from,
     resolve_cluster_for_materialized_view,
@@ -147,6 +152,8 @@ pub enum Plan {
     CreateNetworkPolicy(CreateNetworkPolicyPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
+    CreateFunction(CreateFunctionPlan),
+    CreateSequence(CreateSequencePlan),
     Comment(CommentPlan),
     DiscardTemp,
     DiscardAll,
@@ -170,6 +177,7 @@ pub enum Plan {
     CopyTo(CopyToPlan),
     ExplainPlan(ExplainPlanPlan),
     ExplainPushdown(ExplainPushdownPlan),
+    ExplainRecommendations(ExplainRecommendationsPlan),
     ExplainTimestamp(ExplainTimestampPlan),
     ExplainSinkSchema(ExplainSinkSchemaPlan),
     Insert(InsertPlan),
@@ -192,6 +200,8 @@ pub enum Plan {
     AlterRole(AlterRolePlan),
     AlterOwner(AlterOwnerPlan),
     AlterTableAddColumn(AlterTablePlan),
+    AlterTableAddConstraint(AlterTableAddConstraintPlan),
+    AlterTableDropConstraint(AlterTableDropConstraintPlan),
     AlterMaterializedViewApplyReplacement(AlterMaterializedViewApplyReplacementPlan),
     AlterNetworkPolicy(AlterNetworkPolicyPlan),
     Declare(DeclarePlan),
@@ -202,6 +212,11 @@ pub enum Plan {
     Execute(ExecutePlan),
     Deallocate(DeallocatePlan),
     Raise(RaisePlan),
+    Listen(ListenPlan),
+    Unlisten(UnlistenPlan),
+    Notify(NotifyPlan),
+    CreateChannel(CreateChannelPlan),
+    DropChannel(DropChannelPlan),
     GrantRole(GrantRolePlan),
     RevokeRole(RevokeRolePlan),
     GrantPrivileges(GrantPrivilegesPlan),
@@ -211,6 +226,7 @@ pub enum Plan {
     SideEffectingFunc(SideEffectingFunc),
     ValidateConnection(ValidateConnectionPlan),
     AlterRetainHistory(AlterRetainHistoryPlan),
+    AlterStorageCompression(AlterStorageCompressionPlan),
     AlterSourceTimestampInterval(AlterSourceTimestampIntervalPlan),
 }
 
@@ -255,6 +271,12 @@ impl Plan {
             StatementKind::AlterTableAddColumn => {
                 &[PlanKind::AlterNoop, PlanKind::AlterTableAddColumn]
             }
+            StatementKind::AlterTableAddConstraint => {
+                &[PlanKind::AlterNoop, PlanKind::AlterTableAddConstraint]
+            }
+            StatementKind::AlterTableDropConstraint => {
+                &[PlanKind::AlterNoop, PlanKind::AlterTableDropConstraint]
+            }
             StatementKind::AlterMaterializedViewApplyReplacement => &[
                 PlanKind::AlterNoop,
                 PlanKind::AlterMaterializedViewApplyReplacement,
@@ -268,6 +290,7 @@ impl Plan {
                 PlanKind::Subscribe,
                 PlanKind::CopyTo,
             ],
+            StatementKind::CreateChannel => &[PlanKind::CreateChannel],
             StatementKind::CreateCluster => &[PlanKind::CreateCluster],
             StatementKind::CreateClusterReplica => &[PlanKind::CreateClusterReplica],
             StatementKind::CreateConnection => &[PlanKind::CreateConnection],
@@ -287,11 +310,14 @@ impl Plan {
             StatementKind::CreateTable => &[PlanKind::CreateTable],
             StatementKind::CreateTableFromSource => &[PlanKind::CreateTable],
             StatementKind::CreateType => &[PlanKind::CreateType],
+            StatementKind::CreateFunction => &[PlanKind::CreateFunction],
+            StatementKind::CreateSequence => &[PlanKind::CreateSequence],
             StatementKind::CreateView => &[PlanKind::CreateView],
             StatementKind::Deallocate => &[PlanKind::Deallocate],
             StatementKind::Declare => &[PlanKind::Declare],
             StatementKind::Delete => &[PlanKind::ReadThenWrite],
             StatementKind::Discard => &[PlanKind::DiscardAll, PlanKind::DiscardTemp],
+            StatementKind::DropChannel => &[PlanKind::DropChannel],
             StatementKind::DropObjects => &[PlanKind::DropObjects],
             StatementKind::DropOwned => &[PlanKind::DropOwned],
             StatementKind::Execute => &[PlanKind::Execute],
@@ -299,12 +325,16 @@ impl Plan {
             StatementKind::ExplainPushdown => &[PlanKind::ExplainPushdown],
             StatementKind::ExplainAnalyzeObject => &[PlanKind::Select],
             StatementKind::ExplainAnalyzeCluster => &[PlanKind::Select],
+            StatementKind::ExplainRecommendations => &[PlanKind::ExplainRecommendations],
             StatementKind::ExplainTimestamp => &[PlanKind::ExplainTimestamp],
             StatementKind::ExplainSinkSchema => &[PlanKind::ExplainSinkSchema],
             StatementKind::Fetch => &[PlanKind::Fetch],
             StatementKind::GrantPrivileges => &[PlanKind::GrantPrivileges],
             StatementKind::GrantRole => &[PlanKind::GrantRole],
-            StatementKind::Insert => &[PlanKind::Insert],
+            StatementKind::Insert => &[PlanKind::Insert, PlanKind::ReadThenWrite],
+            StatementKind::Listen => &[PlanKind::Listen],
+            StatementKind::Merge => &[PlanKind::ReadThenWrite],
+            StatementKind::Notify => &[PlanKind::Notify],
             StatementKind::Prepare => &[PlanKind::Prepare],
             StatementKind::Raise => &[PlanKind::Raise],
             StatementKind::ReassignOwned => &[PlanKind::ReassignOwned],
@@ -325,9 +355,13 @@ impl Plan {
             ],
             StatementKind::StartTransaction => &[PlanKind::StartTransaction],
             StatementKind::Subscribe => &[PlanKind::Subscribe],
+            StatementKind::Unlisten => &[PlanKind::Unlisten],
             StatementKind::Update => &[PlanKind::ReadThenWrite],
             StatementKind::ValidateConnection => &[PlanKind::ValidateConnection],
             StatementKind::AlterRetainHistory => &[PlanKind::AlterRetainHistory],
+            StatementKind::AlterStorageCompression => {
+                &[PlanKind::AlterNoop, PlanKind::AlterStorageCompression]
+            }
         }
     }
 
@@ -350,6 +384,8 @@ impl Plan {
             Plan::CreateContinualTask(_) => "create continual task",
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
+            Plan::CreateFunction(_) => "create function",
+            Plan::CreateSequence(_) => "create sequence",
             Plan::CreateNetworkPolicy(_) => "create network policy",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
@@ -372,6 +408,7 @@ impl Plan {
                 ObjectType::Func => "drop function",
                 ObjectType::ContinualTask => "drop continual task",
                 ObjectType::NetworkPolicy => "drop network policy",
+                ObjectType::Sequence => "drop sequence",
             },
             Plan::DropOwned(_) => "drop owned",
             Plan::EmptyQuery => "do nothing",
@@ -392,6 +429,7 @@ impl Plan {
             Plan::CopyTo(_) => "copy to",
             Plan::ExplainPlan(_) => "explain plan",
             Plan::ExplainPushdown(_) => "EXPLAIN FILTER PUSHDOWN",
+            Plan::ExplainRecommendations(_) => "EXPLAIN RECOMMENDATIONS",
             Plan::ExplainTimestamp(_) => "explain timestamp",
             Plan::ExplainSinkSchema(_) => "explain schema",
             Plan::Insert(_) => "insert",
@@ -413,6 +451,7 @@ impl Plan {
                 ObjectType::Func => "alter function",
                 ObjectType::ContinualTask => "alter continual task",
                 ObjectType::NetworkPolicy => "alter network policy",
+                ObjectType::Sequence => "alter sequence",
             },
             Plan::AlterCluster(_) => "alter cluster",
             Plan::AlterClusterRename(_) => "alter cluster rename",
@@ -449,8 +488,11 @@ impl Plan {
                 ObjectType::Func => "alter function owner",
                 ObjectType::ContinualTask => "alter continual task owner",
                 ObjectType::NetworkPolicy => "alter network policy owner",
+                ObjectType::Sequence => "alter sequence owner",
             },
             Plan::AlterTableAddColumn(_) => "alter table add column",
+            Plan::AlterTableAddConstraint(_) => "alter table add constraint",
+            Plan::AlterTableDropConstraint(_) => "alter table drop constraint",
             Plan::AlterMaterializedViewApplyReplacement(_) => {
                 "alter materialized view apply replacement"
             }
@@ -461,11 +503,17 @@ impl Plan {
                 MutationKind::Insert => "insert into select",
                 MutationKind::Update => "update",
                 MutationKind::Delete => "delete",
+                MutationKind::Merge => "merge",
             },
             Plan::Prepare(_) => "prepare",
             Plan::Execute(_) => "execute",
             Plan::Deallocate(_) => "deallocate",
             Plan::Raise(_) => "raise",
+            Plan::Listen(_) => "listen",
+            Plan::Unlisten(_) => "unlisten",
+            Plan::Notify(_) => "notify",
+            Plan::CreateChannel(_) => "create channel",
+            Plan::DropChannel(_) => "drop channel",
             Plan::GrantRole(_) => "grant role",
             Plan::RevokeRole(_) => "revoke role",
             Plan::GrantPrivileges(_) => "grant privilege",
@@ -475,6 +523,7 @@ impl Plan {
             Plan::SideEffectingFunc(_) => "side effecting func",
             Plan::ValidateConnection(_) => "validate connection",
             Plan::AlterRetainHistory(_) => "alter retain history",
+            Plan::AlterStorageCompression(_) => "alter storage compression",
             Plan::AlterSourceTimestampInterval(_) => "alter source timestamp interval",
         }
     }
@@ -505,9 +554,16 @@ impl Plan {
             Plan::CopyTo(_) => true,
             Plan::ExplainPlan(_) => true,
             Plan::ExplainPushdown(_) => true,
+            Plan::ExplainRecommendations(_) => true,
             Plan::ExplainTimestamp(_) => true,
             Plan::ExplainSinkSchema(_) => true,
             Plan::ValidateConnection(_) => true,
+            // Notification channels are not durable.
+            Plan::Listen(_) => true,
+            Plan::Unlisten(_) => true,
+            Plan::Notify(_) => true,
+            Plan::CreateChannel(_) => true,
+            Plan::DropChannel(_) => true,
             _ => false,
         }
     }
@@ -735,6 +791,9 @@ pub struct CreateTablePlan {
     pub name: QualifiedItemName,
     pub table: Table,
     pub if_not_exists: bool,
+    /// The sequences backing the table's identity columns, which are created
+    /// along with the table and are owned by it.
+    pub sequences: Vec<(QualifiedItemName, Sequence)>,
 }
 
 #[derive(Debug, Clone)]
@@ -805,6 +864,20 @@ pub struct CreateTypePlan {
     pub typ: Type,
 }
 
+#[derive(Debug)]
+pub struct CreateFunctionPlan {
+    pub name: QualifiedItemName,
+    pub function: SqlFunction,
+    pub if_not_exists: bool,
+}
+
+#[derive(Debug)]
+pub struct CreateSequencePlan {
+    pub name: QualifiedItemName,
+    pub sequence: Sequence,
+    pub if_not_exists: bool,
+}
+
 #[derive(Debug)]
 pub struct DropObjectsPlan {
     /// The IDs of only the objects directly referenced in the `DROP` statement.
@@ -929,6 +1002,10 @@ pub struct SubscribePlan {
     pub up_to: Option<Timestamp>,
     pub copy_to: Option<CopyFormat>,
     pub emit_progress: bool,
+    /// If set, updates are consolidated into windows of this length before being emitted.
+    pub emit_every: Option<Duration>,
+    /// If set, the maximum rate at which updates are emitted.
+    pub max_rows_per_second: Option<u64>,
     pub output: SubscribeOutput,
 }
 
@@ -1167,6 +1244,12 @@ pub struct ExplainPushdownPlan {
     pub explainee: Explainee,
 }
 
+#[derive(Clone, Debug)]
+pub struct ExplainRecommendationsPlan {
+    /// The cluster whose logged statements to recommend indexes for.
+    pub cluster_id: ClusterId,
+}
+
 #[derive(Clone, Debug)]
 pub struct ExplainTimestampPlan {
     pub format: ExplainFormat,
@@ -1187,6 +1270,8 @@ pub struct SendDiffsPlan {
     pub kind: MutationKind,
     pub returning: Vec<(Row, NonZeroUsize)>,
     pub max_result_size: u64,
+    /// The number of affected rows, if it cannot be derived from `updates`.
+    pub affected_rows: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -1194,6 +1279,10 @@ pub struct InsertPlan {
     pub id: CatalogItemId,
     pub values: HirRelationExpr,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// The columns that default to the next value of a sequence, and the
+    /// sequence for each. `values` contains `NULL` for these columns, which
+    /// must be filled in for every inserted row.
+    pub sequence_defaults: Vec<(usize, CatalogItemId)>,
 }
 
 #[derive(Debug)]
@@ -1204,6 +1293,85 @@ pub struct ReadThenWritePlan {
     pub assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
     pub kind: MutationKind,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// The `WHEN` clauses of a `MERGE`, or the `ON CONFLICT` clause of an
+    /// `INSERT`.
+    pub merge: Option<MergePlan>,
+    /// See [`InsertPlan::sequence_defaults`]. Only set for inserts.
+    pub sequence_defaults: Vec<(usize, CatalogItemId)>,
+}
+
+/// The `WHEN` clauses of a `MERGE` statement.
+///
+/// Each row of the accompanying `selection` contains the columns of a source
+/// row, followed by the columns of a distinct matching target row, followed by
+/// the number of copies of that target row in the table. The target columns
+/// and the count are `NULL` if the source row did not match any target row.
+#[derive(Debug, Clone)]
+pub struct MergePlan {
+    /// The number of source columns in each row of the selection.
+    pub source_arity: usize,
+    /// The clauses, in the order they were specified. Only the first clause
+    /// whose condition holds applies to a row.
+    pub clauses: Vec<MergeClausePlan>,
+    /// For `INSERT ... ON CONFLICT`, the unique constraints on which rows
+    /// inserted by the statement conflict with each other. A row conflicting
+    /// with an earlier inserted row is treated as matched.
+    pub conflict_keys: Vec<TableUnique>,
+}
+
+impl MergePlan {
+    /// Returns all scalar expressions in the clauses.
+    pub fn exprs(&self) -> impl Iterator<Item = &mz_expr::MirScalarExpr> {
+        self.clauses.iter().flat_map(|clause| {
+            let action: Box<dyn Iterator<Item = _>> = match &clause.action {
+                MergeActionPlan::Update { assignments } => Box::new(assignments.values()),
+                MergeActionPlan::Insert { values } => Box::new(values.iter()),
+                MergeActionPlan::Delete | MergeActionPlan::DoNothing => {
+                    Box::new(std::iter::empty())
+                }
+            };
+            clause.condition.iter().chain(action)
+        })
+    }
+
+    /// Returns mutable references to all scalar expressions in the clauses.
+    pub fn exprs_mut(&mut self) -> impl Iterator<Item = &mut mz_expr::MirScalarExpr> {
+        self.clauses.iter_mut().flat_map(|clause| {
+            let action: Box<dyn Iterator<Item = _>> = match &mut clause.action {
+                MergeActionPlan::Update { assignments } => Box::new(assignments.values_mut()),
+                MergeActionPlan::Insert { values } => Box::new(values.iter_mut()),
+                MergeActionPlan::Delete | MergeActionPlan::DoNothing => {
+                    Box::new(std::iter::empty())
+                }
+            };
+            clause.condition.iter_mut().chain(action)
+        })
+    }
+}
+
+/// A planned `WHEN [NOT] MATCHED [AND <condition>] THEN <action>` clause.
+#[derive(Debug, Clone)]
+pub struct MergeClausePlan {
+    /// Whether this is a `WHEN MATCHED` clause.
+    pub matched: bool,
+    pub condition: Option<mz_expr::MirScalarExpr>,
+    pub action: MergeActionPlan,
+}
+
+#[derive(Debug, Clone)]
+pub enum MergeActionPlan {
+    /// Replace the matched target row. Maps target column indexes to their
+    /// new values.
+    Update {
+        assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
+    },
+    /// Delete the matched target row.
+    Delete,
+    /// Insert a new row, with one expression per target column.
+    Insert {
+        values: Vec<mz_expr::MirScalarExpr>,
+    },
+    DoNothing,
 }
 
 /// Generated by `ALTER ... IF EXISTS` if the named object did not exist.
@@ -1226,6 +1394,16 @@ pub struct AlterRetainHistoryPlan {
     pub object_type: ObjectType,
 }
 
+#[derive(Debug)]
+pub struct AlterStorageCompressionPlan {
+    pub id: CatalogItemId,
+    pub value: Option<Value>,
+    /// The compression format for newly written parts, or `None` to use the
+    /// system default.
+    pub compression: Option<CompressionFormat>,
+    pub object_type: ObjectType,
+}
+
 #[derive(Debug)]
 pub struct AlterSourceTimestampIntervalPlan {
     pub id: CatalogItemId,
@@ -1381,6 +1559,25 @@ pub struct AlterTablePlan {
     pub raw_sql_type: RawDataType,
 }
 
+#[derive(Debug, Clone)]
+pub struct AlterTableAddConstraintPlan {
+    pub relation_id: CatalogItemId,
+    /// The `CREATE TABLE` statement of the table, including the new
+    /// constraint.
+    pub create_sql: String,
+    /// The new constraint, which existing rows must satisfy.
+    pub check: TableCheck,
+}
+
+#[derive(Debug, Clone)]
+pub struct AlterTableDropConstraintPlan {
+    pub relation_id: CatalogItemId,
+    /// The `CREATE TABLE` statement of the table, without the dropped
+    /// constraint.
+    pub create_sql: String,
+    pub constraint_name: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct AlterMaterializedViewApplyReplacementPlan {
     pub id: CatalogItemId,
@@ -1431,6 +1628,38 @@ pub struct RaisePlan {
     pub severity: NoticeSeverity,
 }
 
+#[derive(Debug)]
+pub struct ListenPlan {
+    pub channel: String,
+}
+
+#[derive(Debug)]
+pub struct UnlistenPlan {
+    /// The channel to stop listening on, or `None` for all channels.
+    pub channel: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct NotifyPlan {
+    pub channel: String,
+    pub payload: String,
+}
+
+#[derive(Debug)]
+pub struct CreateChannelPlan {
+    pub channel: String,
+    /// The subscribe that follows the changes to the channel's view.
+    pub subscribe: SubscribePlan,
+    /// The description of the view, used to render notification payloads.
+    pub desc: RelationDesc,
+}
+
+#[derive(Debug)]
+pub struct DropChannelPlan {
+    pub channel: String,
+    pub if_exists: bool,
+}
+
 #[derive(Debug)]
 pub struct GrantRolePlan {
     /// The roles that are gaining members.
@@ -1511,7 +1740,11 @@ pub struct CommentPlan {
 #[derive(Clone, Debug)]
 pub enum TableDataSource {
     /// The table owns data created via INSERT/UPDATE/DELETE statements.
-    TableWrites { defaults: Vec<Expr<Aug>> },
+    TableWrites {
+        defaults: Vec<Expr<Aug>>,
+        checks: Vec<TableCheck>,
+        uniques: Vec<TableUnique>,
+    },
 
     /// The table receives its data from the identified `DataSourceDesc`.
     /// This table type does not support INSERT/UPDATE/DELETE statements.
@@ -1521,12 +1754,61 @@ pub enum TableDataSource {
     },
 }
 
+/// A `CHECK` constraint on a table.
+#[derive(Clone, Debug, Serialize)]
+pub struct TableCheck {
+    /// The name of the constraint.
+    pub name: String,
+    /// The predicate that every row of the table must satisfy, expressed in
+    /// terms of the columns of the latest version of the table.
+    pub expr: MirScalarExpr,
+}
+
+impl TableCheck {
+    /// Reports whether the row `datums` satisfies the constraint.
+    ///
+    /// As in PostgreSQL, a predicate that evaluates to `NULL` is satisfied.
+    pub fn holds<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        arena: &'a RowArena,
+    ) -> Result<bool, EvalError> {
+        Ok(self.expr.eval(datums, arena)? != Datum::False)
+    }
+}
+
+/// A `PRIMARY KEY` or `UNIQUE` constraint on a table.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TableUnique {
+    /// The columns of the constraint, in terms of the columns of the latest
+    /// version of the table.
+    pub columns: Vec<usize>,
+    /// Whether rows with a `NULL` in any of the columns never conflict, as
+    /// opposed to `NULL`s being equal to each other.
+    pub nulls_distinct: bool,
+}
+
+impl TableUnique {
+    /// Returns the values of the constraint's columns in the row `datums`,
+    /// or `None` if the row cannot conflict with any other on them.
+    pub fn key<'a>(&self, datums: &[Datum<'a>]) -> Option<Row> {
+        let key = self.columns.iter().map(|i| datums[*i]);
+        if self.nulls_distinct && key.clone().any(|datum| datum.is_null()) {
+            return None;
+        }
+        Some(Row::pack(key))
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Table {
     pub create_sql: String,
     pub desc: VersionedRelationDesc,
     pub temporary: bool,
     pub compaction_window: Option<CompactionWindow>,
+    /// Compression policy for the table's data shard, or `None` to use the
+    /// system default.
+    pub storage_compression: Option<CompressionFormat>,
     pub data_source: TableDataSource,
 }
 
@@ -1698,6 +1980,7 @@ pub enum ConnectionDetails {
     MySql(MySqlConnection<ReferencedConnection>),
     SqlServer(SqlServerConnectionDetails<ReferencedConnection>),
     IcebergCatalog(IcebergCatalogConnection<ReferencedConnection>),
+    Gcs(GcsConnection),
 }
 
 impl ConnectionDetails {
@@ -1726,6 +2009,7 @@ impl ConnectionDetails {
             ConnectionDetails::IcebergCatalog(c) => {
                 mz_storage_types::connections::Connection::IcebergCatalog(c.clone())
             }
+            ConnectionDetails::Gcs(c) => mz_storage_types::connections::Connection::Gcs(c.clone()),
         }
     }
 }
@@ -1900,6 +2184,9 @@ pub struct MaterializedView {
     pub compaction_window: Option<CompactionWindow>,
     pub refresh_schedule: Option<RefreshSchedule>,
     pub as_of: Option<Timestamp>,
+    /// Compression policy for the materialized view's data shard, or `None` to
+    /// use the system default.
+    pub storage_compression: Option<CompressionFormat>,
 }
 
 #[derive(Clone, Debug)]
@@ -1911,6 +2198,8 @@ pub struct Index {
     pub keys: Vec<mz_expr::MirScalarExpr>,
     pub compaction_window: Option<CompactionWindow>,
     pub cluster_id: ClusterId,
+    /// Whether the index's arrangement may spill its batches to disk.
+    pub spill_to_disk: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -1919,6 +2208,139 @@ pub struct Type {
     pub inner: CatalogType<IdReference>,
 }
 
+/// A user-defined function created with `CREATE FUNCTION`.
+///
+/// Calls to the function are inlined during planning.
+#[derive(Clone, Debug)]
+pub struct SqlFunction {
+    /// Parse-able SQL that is stored durably and defines this function.
+    pub create_sql: String,
+    /// The types of the function's parameters.
+    pub param_types: Vec<SqlScalarType>,
+    /// The catalog items that define the types of the function's parameters.
+    pub param_type_ids: Vec<CatalogItemId>,
+    /// The type of the function's return value, or of the elements of the returned set.
+    pub return_type: SqlScalarType,
+    /// The catalog item that defines the function's return type.
+    pub return_type_id: CatalogItemId,
+    /// Whether the function returns a set of values.
+    pub returns_set: bool,
+    /// The unoptimized high-level expression of the function body, which refers to the function's
+    /// arguments as parameters `$1`, `$2`, etc.
+    pub body: SqlFunctionBody,
+}
+
+impl SqlFunction {
+    /// The number of arguments the function takes.
+    pub fn arity(&self) -> usize {
+        self.param_types.len()
+    }
+}
+
+/// The planned body of a [`SqlFunction`].
+#[derive(Clone, Debug)]
+pub enum SqlFunctionBody {
+    /// A body of the form `SELECT <expr>`, or a call into a WebAssembly module, which is inlined
+    /// as a scalar expression.
+    Scalar(HirScalarExpr),
+    /// Any other body, which is inlined as a relation with a single column.
+    Relation(HirRelationExpr),
+}
+
+/// A sequence, created with `CREATE SEQUENCE` or implicitly for an identity
+/// column.
+#[derive(Clone, Debug)]
+pub struct Sequence {
+    /// Parse-able SQL that is stored durably and defines this sequence.
+    pub create_sql: String,
+    /// The sequence's parameters.
+    pub options: SequenceOptions,
+}
+
+/// The parameters of a [`Sequence`], which behave as in PostgreSQL.
+///
+/// The durable state of a sequence is a single `u64` that identifies the next
+/// value the sequence hands out, as produced by [`SequenceOptions::encode_state`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct SequenceOptions {
+    /// The step between consecutive values. Negative for descending sequences.
+    pub increment: i64,
+    /// The smallest value the sequence hands out.
+    pub min_value: i64,
+    /// The largest value the sequence hands out.
+    pub max_value: i64,
+    /// The first value the sequence hands out.
+    pub start: i64,
+    /// How many values to allocate durably at once.
+    pub cache: u64,
+    /// Whether the sequence wraps around once it reaches its limit.
+    pub cycle: bool,
+}
+
+impl SequenceOptions {
+    /// The durable state of a newly created sequence.
+    pub fn initial_state(&self) -> u64 {
+        self.encode_state(Some(self.start))
+    }
+
+    /// Encodes the next value the sequence hands out, or `None` if the
+    /// sequence is exhausted, as durable state.
+    ///
+    /// The state is the distance of the next value from the end of the range
+    /// that the sequence counts away from, so exhaustion is one step past the
+    /// other end. Planning rejects ranges that span all of `i64`, which is the
+    /// only case in which that doesn't fit into a `u64`.
+    pub fn encode_state(&self, next: Option<i64>) -> u64 {
+        match next {
+            None => self.max_value.abs_diff(self.min_value) + 1,
+            Some(next) if self.increment > 0 => next.abs_diff(self.min_value),
+            Some(next) => self.max_value.abs_diff(next),
+        }
+    }
+
+    /// The inverse of [`SequenceOptions::encode_state`].
+    pub fn decode_state(&self, state: u64) -> Option<i64> {
+        if state > self.max_value.abs_diff(self.min_value) {
+            return None;
+        }
+        let next = if self.increment > 0 {
+            self.min_value.checked_add_unsigned(state)
+        } else {
+            self.max_value.checked_sub_unsigned(state)
+        };
+        Some(next.expect("state within range"))
+    }
+
+    /// Returns the value that follows `value`, or `None` if the sequence is
+    /// exhausted after `value`.
+    pub fn next_value(&self, value: i64) -> Option<i64> {
+        match value.checked_add(self.increment) {
+            Some(next) if self.min_value <= next && next <= self.max_value => Some(next),
+            _ if self.cycle && self.increment > 0 => Some(self.min_value),
+            _ if self.cycle => Some(self.max_value),
+            _ => None,
+        }
+    }
+
+    /// Takes up to `count` values from the sequence in `state`, returning the
+    /// values and the sequence's new state.
+    ///
+    /// Fewer than `count` values are returned only if the sequence is
+    /// exhausted.
+    pub fn take(&self, state: u64, count: usize) -> (Vec<i64>, u64) {
+        let mut next = self.decode_state(state);
+        let mut values = Vec::with_capacity(count);
+        while values.len() < count {
+            let Some(value) = next else {
+                break;
+            };
+            values.push(value);
+            next = self.next_value(value);
+        }
+        (values, self.encode_state(next))
+    }
+}
+
 /// Specifies when a `Peek` or `Subscribe` should occur.
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 pub enum QueryWhen {
@@ -2006,6 +2428,7 @@ pub enum MutationKind {
     Insert,
     Update,
     Delete,
+    Merge,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -2027,12 +2450,26 @@ pub enum ExecuteTimeout {
 pub enum IndexOption {
     /// Configures the logical compaction window for an index.
     RetainHistory(CompactionWindow),
+    /// Configures where the index's arrangement is stored.
+    Storage(IndexStorage),
+}
+
+/// Where the arrangement backing an index is stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexStorage {
+    /// All batches of the arrangement are kept in memory.
+    Memory,
+    /// Large, merged batches of the arrangement are spilled to the replica's
+    /// scratch directory.
+    Disk,
 }
 
 #[derive(Clone, Debug)]
 pub enum TableOption {
     /// Configures the logical compaction window for a table.
     RetainHistory(CompactionWindow),
+    /// Configures the compression policy for the table's data shard.
+    StorageCompression(CompressionFormat),
 }
 
 #[derive(Clone, Debug)]
@@ -640,6 +640,8 @@ impl Display for ValueWindowFunc {
             ValueWindowFunc::Lead => write!(f, "lead"),
             ValueWindowFunc::FirstValue => write!(f, "first_value"),
             ValueWindowFunc::LastValue => write!(f, "last_value"),
+            ValueWindowFunc::SessionWindowStart => write!(f, "mz_session_window_start"),
+            ValueWindowFunc::SessionWindowEnd => write!(f, "mz_session_window_end"),
             ValueWindowFunc::Fused(funcs) => write!(f, "fused[{}]", separated(", ", funcs)),
         }
     }
@@ -731,6 +733,8 @@ pub enum ValueWindowFunc {
     Lead,
     FirstValue,
     LastValue,
+    SessionWindowStart,
+    SessionWindowEnd,
     Fused(Vec<ValueWindowFunc>),
 }
 
@@ -746,6 +750,17 @@ impl ValueWindowFunc {
             ValueWindowFunc::FirstValue | ValueWindowFunc::LastValue => {
                 input_type.scalar_type.nullable(true)
             }
+            // The input is a (time, reach) record. Sessions start at a time and end at a reach.
+            ValueWindowFunc::SessionWindowStart => {
+                input_type.scalar_type.unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(true)
+            }
+            ValueWindowFunc::SessionWindowEnd => {
+                input_type.scalar_type.unwrap_record_element_type()[1]
+                    .clone()
+                    .nullable(true)
+            }
             ValueWindowFunc::Fused(funcs) => {
                 let input_types = input_type.scalar_type.unwrap_record_element_column_type();
                 SqlScalarType::Record {
@@ -787,6 +802,14 @@ impl ValueWindowFunc {
                 order_by,
                 window_frame,
             },
+            ValueWindowFunc::SessionWindowStart => mz_expr::AggregateFunc::SessionWindow {
+                order_by,
+                bound: mz_expr::SessionWindowBound::Start,
+            },
+            ValueWindowFunc::SessionWindowEnd => mz_expr::AggregateFunc::SessionWindow {
+                order_by,
+                bound: mz_expr::SessionWindowBound::End,
+            },
             ValueWindowFunc::Fused(funcs) => mz_expr::AggregateFunc::FusedValueWindowFunc {
                 funcs: funcs
                     .into_iter()
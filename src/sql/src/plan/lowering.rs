@@ -42,7 +42,7 @@ use std::iter::repeat;
 use itertools::Itertools;
 use mz_expr::func::variadic;
 use mz_expr::visit::Visit;
-use mz_expr::{AccessStrategy, AggregateFunc, MirRelationExpr, MirScalarExpr, func};
+use mz_expr::{AccessStrategy, AggregateFunc, MirRelationExpr, MirScalarExpr, OuterJoinKind, func};
 use mz_ore::collections::CollectionExt;
 use mz_ore::stack::maybe_grow;
 use mz_repr::*;
@@ -139,6 +139,8 @@ pub struct Config {
     pub enable_new_outer_join_lowering: bool,
     /// Enable outer join lowering implemented in database-issues#7561.
     pub enable_variadic_left_join_lowering: bool,
+    /// Lower outer equijoins to `MirRelationExpr::OuterJoin`.
+    pub enable_native_outer_join: bool,
     pub enable_guard_subquery_tablefunc: bool,
     pub enable_cast_elimination: bool,
     pub enable_simplify_quantified_comparisons: bool,
@@ -149,6 +151,7 @@ impl Default for Config {
         Self {
             enable_new_outer_join_lowering: false,
             enable_variadic_left_join_lowering: false,
+            enable_native_outer_join: false,
             enable_guard_subquery_tablefunc: false,
             enable_cast_elimination: false,
             enable_simplify_quantified_comparisons: false,
@@ -161,6 +164,7 @@ impl From<&SystemVars> for Config {
         Self {
             enable_new_outer_join_lowering: vars.enable_new_outer_join_lowering(),
             enable_variadic_left_join_lowering: vars.enable_variadic_left_join_lowering(),
+            enable_native_outer_join: vars.enable_native_outer_join(),
             enable_guard_subquery_tablefunc: vars.enable_guard_subquery_tablefunc(),
             enable_cast_elimination: vars.enable_cast_elimination(),
             enable_simplify_quantified_comparisons: vars.enable_simplify_quantified_comparisons(),
@@ -2161,6 +2165,21 @@ fn attempt_outer_equijoin(
         return Ok(None);
     }
 
+    // Lower to a native outer join if possible. The outer context columns would
+    // have to be matched including nulls, which the operator does not support.
+    if context.config.enable_native_outer_join
+        && oa == 0
+        && let Some((kind, left_filter, right_filter)) = on_predicates.native_outer_join(&kind)
+    {
+        let result = left.filter(left_filter).outer_join(
+            right.filter(right_filter),
+            kind,
+            on_predicates.eq_lhs().collect(),
+            on_predicates.eq_rhs().collect(),
+        );
+        return Ok(Some(result));
+    }
+
     // If we've gotten this far, we can do the clever thing.
     // We'll want to use left and right multiple times
     let result = left.let_in(id_gen, |id_gen, get_left| {
@@ -2486,6 +2505,50 @@ impl OnPredicates {
         is_equijion
     }
 
+    /// Check if the predicates can be lowered to a [`MirRelationExpr::OuterJoin`]
+    /// of the given `kind`.
+    ///
+    /// This is the case if every predicate other than the [`OnPredicate::Eq`]
+    /// conditions can be applied to the input whose rows are never padded, as
+    /// removing rows from that input only removes matches. The local predicates
+    /// of the other input cannot be applied to it, as its unmatched rows must
+    /// still be produced. Consequences can be dropped, as keys containing nulls
+    /// never match.
+    ///
+    /// If so, returns the [`OuterJoinKind`] and the predicates to apply to the
+    /// left and right input, respectively.
+    fn native_outer_join(
+        &self,
+        kind: &JoinKind,
+    ) -> Option<(OuterJoinKind, Vec<MirScalarExpr>, Vec<MirScalarExpr>)> {
+        let kind = match kind {
+            JoinKind::LeftOuter => OuterJoinKind::Left,
+            JoinKind::RightOuter => OuterJoinKind::Right,
+            JoinKind::FullOuter => OuterJoinKind::Full,
+            JoinKind::Inner => return None,
+        };
+        let mut lhs = Vec::new();
+        let mut rhs = Vec::new();
+        for predicate in self.predicates.iter() {
+            match predicate {
+                OnPredicate::Eq(..)
+                | OnPredicate::LhsConsequence(..)
+                | OnPredicate::RhsConsequence(..) => {}
+                OnPredicate::Const(p) => match kind {
+                    OuterJoinKind::Left => rhs.push(p.clone()),
+                    OuterJoinKind::Right => lhs.push(p.clone()),
+                    OuterJoinKind::Full => return None,
+                },
+                OnPredicate::Lhs(p) if !kind.retains_left() => lhs.push(p.clone()),
+                OnPredicate::Rhs(p) if !kind.retains_right() => rhs.push(p.clone()),
+                OnPredicate::Lhs(..) | OnPredicate::Rhs(..) | OnPredicate::Theta(..) => {
+                    return None;
+                }
+            }
+        }
+        Some((kind, lhs, rhs))
+    }
+
     /// Return an [`MirRelationExpr`] list that represents the keys for the
     /// equijoin. The list will contain the outer columns as a prefix.
     fn join_keys(&self) -> JoinKeys {
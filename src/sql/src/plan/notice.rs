@@ -37,6 +37,10 @@ pub enum PlanNotice {
         column_name: String,
         object_name: String,
     },
+    ConstraintDoesNotExist {
+        constraint_name: String,
+        object_name: String,
+    },
     UpsertSinkKeyNotEnforced {
         key: Vec<ColumnName>,
         name: String,
@@ -96,6 +100,17 @@ impl fmt::Display for PlanNotice {
                     object_name.quoted()
                 )
             }
+            PlanNotice::ConstraintDoesNotExist {
+                constraint_name,
+                object_name,
+            } => {
+                write!(
+                    f,
+                    "constraint {} of relation {} does not exist, skipping",
+                    constraint_name.quoted(),
+                    object_name.quoted()
+                )
+            }
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
@@ -76,17 +76,18 @@ use mz_sql_parser::ast::visit::Visit;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     AsOf, Assignment, AstInfo, CreateWebhookSourceBody, CreateWebhookSourceCheck,
-    CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, DeleteStatement, Distinct,
-    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
-    JoinConstraint, JoinOperator, Limit, MapEntry, MutRecBlock, MutRecBlockOption,
-    MutRecBlockOptionName, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName,
-    SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor,
-    TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
-    WindowFrameBound, WindowFrameUnits, WindowSpec, visit,
+    CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, CteRecursive, DeleteStatement,
+    Distinct, Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource,
+    IsExprConstruct, Join, JoinConstraint, JoinOperator, Limit, MapEntry, MergeMatchedAction,
+    MergeNotMatchedAction, MergeStatement, MergeWhenClause, MutRecBlock, MutRecBlockOption,
+    MutRecBlockOptionName, OnConflict, OnConflictAction, OrderByExpr, Query, Select, SelectItem,
+    SelectOption, SelectOptionName, SetExpr, SetOperator, ShowStatement, SubscriptPosition,
+    TableAlias, TableFactor, TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values,
+    WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec, visit,
 };
 use mz_sql_parser::ident;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{CatalogItem, CatalogItemType, CatalogType, SessionCatalog};
 use crate::func::{self, Func, FuncSpec, TableFuncImpl};
 use crate::names::{
     Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
@@ -104,8 +105,8 @@ use crate::plan::scope::{Scope, ScopeItem, ScopeUngroupedColumn};
 use crate::plan::statement::{StatementContext, StatementDesc, show};
 use crate::plan::typeconv::{self, CastContext, plan_hypothetical_cast};
 use crate::plan::{
-    Params, PlanContext, QueryWhen, ShowCreatePlan, WebhookValidation, WebhookValidationSecret,
-    literal, transform_ast,
+    Params, PlanContext, QueryWhen, ShowCreatePlan, SqlFunction, SqlFunctionBody, TableUnique,
+    WebhookValidation, WebhookValidationSecret, literal, side_effecting_func, transform_ast,
 };
 use crate::session::vars::ENABLE_WITH_ORDINALITY_LEGACY_FALLBACK;
 use crate::session::vars::{self, FeatureFlag};
@@ -266,6 +267,12 @@ fn try_push_projection_order_by(
     }
 }
 
+/// Plans an `INSERT` into `table_name`.
+///
+/// Besides the ID of the table, the rows to insert, and the `RETURNING`
+/// clause, returns the columns whose values must be drawn from a sequence for
+/// each inserted row, along with the ID of that sequence. The planned rows
+/// contain `NULL` in those columns.
 pub fn plan_insert_query(
     scx: &StatementContext,
     table_name: ResolvedItemName,
@@ -277,6 +284,7 @@ pub fn plan_insert_query(
         CatalogItemId,
         HirRelationExpr,
         PlannedRootQuery<Vec<HirScalarExpr>>,
+        Vec<(usize, CatalogItemId)>,
     ),
     PlanError,
 > {
@@ -415,12 +423,14 @@ pub fn plan_insert_query(
     // Maps from table column index to position in the source query
     let col_to_source: BTreeMap<_, _> = ordering.iter().enumerate().map(|(a, b)| (b, a)).collect();
 
+    let mut sequence_defaults = vec![];
     let column_details = desc.iter_types().zip_eq(defaults).enumerate();
     for (col_idx, (col_typ, default)) in column_details {
         if let Some(src_idx) = col_to_source.get(&col_idx) {
             project_key.push(*src_idx);
         } else {
-            let hir = plan_default_expr(scx, &default, &col_typ.scalar_type)?;
+            let (hir, sequence) = plan_column_default(scx, &default, &col_typ.scalar_type)?;
+            sequence_defaults.extend(sequence.map(|id| (col_idx, id)));
             project_key.push(expr_arity + map_exprs.len());
             map_exprs.push(hir);
         }
@@ -480,6 +490,7 @@ pub fn plan_insert_query(
         table.id(),
         expr.map(map_exprs).project(project_key),
         returning,
+        sequence_defaults,
     ))
 }
 
@@ -492,6 +503,8 @@ pub fn plan_insert_query(
 ///   since we now return a [`MapFilterProject`].
 /// * [`MapFilterProject`] which will map and project the input data to match the shape of the
 ///   destination table.
+/// * The columns of the destination table that the [`MapFilterProject`] fills with a `NULL`
+///   placeholder, because they default to the next value of the given sequence.
 ///
 pub fn plan_copy_item(
     scx: &StatementContext,
@@ -503,6 +516,7 @@ pub fn plan_copy_item(
         RelationDesc,
         Vec<ColumnIndex>,
         Option<MapFilterProject>,
+        Vec<(usize, CatalogItemId)>,
     ),
     PlanError,
 > {
@@ -518,6 +532,7 @@ pub fn plan_copy_item(
         }
     };
     let mut ordering = Vec::with_capacity(columns.len());
+    let mut sequence_defaults = vec![];
 
     // TODO(cf2): The logic here to create the `source_desc` and the MFP are a bit duplicated and
     // should be simplified. The reason they are currently separate code paths is so we can roll
@@ -547,15 +562,17 @@ pub fn plan_copy_item(
 
         // For each column in the destination table, either project it from the source data, or provide
         // an expression to fill in a default value.
-        let column_details = table_desc.iter().zip_eq(table_defaults);
-        for ((col_name, col_type), col_default) in column_details {
+        let column_details = table_desc.iter().zip_eq(table_defaults).enumerate();
+        for (col_idx, ((col_name, col_type), col_default)) in column_details {
             let maybe_src_idx = source_column_names.iter().position(|name| name == col_name);
             if let Some(src_idx) = maybe_src_idx {
                 project_keys.push(src_idx);
             } else {
                 // If one a column from the table does not exist in the source data, then a default
                 // value will get appended to the end of the input Row from the source data.
-                let hir = plan_default_expr(scx, &col_default, &col_type.scalar_type)?;
+                let (hir, sequence) =
+                    plan_column_default(scx, &col_default, &col_type.scalar_type)?;
+                sequence_defaults.extend(sequence.map(|id| (col_idx, id)));
                 let mir = hir.lower_uncorrelated(scx.catalog.system_vars())?;
                 project_keys.push(source_column_names.len() + default_exprs.len());
                 default_exprs.push(mir);
@@ -608,7 +625,7 @@ pub fn plan_copy_item(
         RelationDesc::new(SqlRelationType::new(source_types), names)
     };
 
-    Ok((item.id(), source_desc, ordering, mfp))
+    Ok((item.id(), source_desc, ordering, mfp, sequence_defaults))
 }
 
 /// See the doc comment on [`plan_copy_item`] for the details of what this function returns.
@@ -624,6 +641,7 @@ pub fn plan_copy_from(
         RelationDesc,
         Vec<ColumnIndex>,
         Option<MapFilterProject>,
+        Vec<(usize, CatalogItemId)>,
     ),
     PlanError,
 > {
@@ -651,13 +669,16 @@ pub fn plan_copy_from(
             table_name.full_name_str()
         );
     }
-    let (id, desc, ordering, mfp) = plan_copy_item(scx, table_name, columns)?;
+    let (id, desc, ordering, mfp, sequence_defaults) = plan_copy_item(scx, table_name, columns)?;
 
-    Ok((id, desc, ordering, mfp))
+    Ok((id, desc, ordering, mfp, sequence_defaults))
 }
 
 /// Builds a plan that adds the default values for the missing columns and re-orders
 /// the datums in the given rows to match the order in the target table.
+///
+/// Columns that default to the next value of a sequence are filled with a `NULL`
+/// placeholder and returned alongside the plan, as in [`plan_insert_query`].
 pub fn plan_copy_from_rows(
     pcx: &PlanContext,
     catalog: &dyn SessionCatalog,
@@ -665,7 +686,7 @@ pub fn plan_copy_from_rows(
     target_name: String,
     columns: Vec<ColumnIndex>,
     rows: Vec<mz_repr::Row>,
-) -> Result<HirRelationExpr, PlanError> {
+) -> Result<(HirRelationExpr, Vec<(usize, CatalogItemId)>), PlanError> {
     let scx = StatementContext::new(Some(pcx), catalog);
 
     // Always copy at the latest version of the table.
@@ -707,7 +728,7 @@ pub fn plan_copy_from_rows(
     // projection).
     let default: Vec<_> = (0..desc.arity()).map(ColumnIndex::from_raw).collect();
     if columns == default {
-        return Ok(expr);
+        return Ok((expr, vec![]));
     }
 
     // Fill in any omitted columns and rearrange into correct order
@@ -717,18 +738,20 @@ pub fn plan_copy_from_rows(
     // Maps from table column index to position in the source query
     let col_to_source: BTreeMap<_, _> = columns.iter().enumerate().map(|(a, b)| (b, a)).collect();
 
+    let mut sequence_defaults = vec![];
     let column_details = desc.iter_all().zip_eq(defaults);
     for ((col_idx, _col_name, col_typ), default) in column_details {
         if let Some(src_idx) = col_to_source.get(&col_idx) {
             project_key.push(*src_idx);
         } else {
-            let hir = plan_default_expr(&scx, &default, &col_typ.scalar_type)?;
+            let (hir, sequence) = plan_column_default(&scx, &default, &col_typ.scalar_type)?;
+            sequence_defaults.extend(sequence.map(|id| (col_idx.to_raw(), id)));
             project_key.push(typ.arity() + map_exprs.len());
             map_exprs.push(hir);
         }
     }
 
-    Ok(expr.map(map_exprs).project(project_key))
+    Ok((expr.map(map_exprs).project(project_key), sequence_defaults))
 }
 
 /// Common information used for DELETE, UPDATE, and INSERT INTO ... SELECT plans.
@@ -885,6 +908,436 @@ pub fn plan_mutation_query_inner(
     })
 }
 
+/// The read portion and `WHEN` clauses of a `MERGE` statement, or of an
+/// `INSERT ... ON CONFLICT` statement.
+///
+/// See [`crate::plan::MergePlan`] for the layout of the rows produced by
+/// `selection`.
+pub struct MergeQueryPlan {
+    pub id: CatalogItemId,
+    pub selection: HirRelationExpr,
+    pub finishing: RowSetFinishing,
+    pub source_arity: usize,
+    pub clauses: Vec<MergeQueryClause>,
+    pub conflict_keys: Vec<TableUnique>,
+}
+
+pub struct MergeQueryClause {
+    pub matched: bool,
+    pub condition: Option<HirScalarExpr>,
+    pub action: MergeQueryAction,
+}
+
+pub enum MergeQueryAction {
+    Update(BTreeMap<usize, HirScalarExpr>),
+    Delete,
+    Insert(Vec<HirScalarExpr>),
+    DoNothing,
+}
+
+pub fn plan_merge_query(
+    scx: &StatementContext,
+    mut merge_stmt: MergeStatement<Aug>,
+) -> Result<MergeQueryPlan, PlanError> {
+    transform_ast::transform(scx, &mut merge_stmt)?;
+
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let MergeStatement {
+        table_name,
+        alias,
+        using,
+        on,
+        clauses,
+    } = merge_stmt;
+
+    let (id, version) = match table_name {
+        ResolvedItemName::Item { id, version, .. } => (id, version),
+        _ => sql_bail!("cannot mutate non-user table"),
+    };
+    let item = qcx.scx.get_item(&id).at_version(version);
+    if item.item_type() != CatalogItemType::Table {
+        sql_bail!(
+            "cannot mutate {} '{}'",
+            item.item_type(),
+            table_name.full_name_str()
+        );
+    }
+    let defaults = item.writable_table_details().ok_or_else(|| {
+        sql_err!(
+            "cannot mutate non-writeable table '{}'",
+            table_name.full_name_str()
+        )
+    })?;
+    if id.is_system() {
+        sql_bail!(
+            "cannot mutate system table '{}'",
+            table_name.full_name_str()
+        );
+    }
+    let desc = item.relation_desc().expect("table has desc");
+
+    let (source, source_scope) = plan_table_factor(&qcx, &using)?;
+    let source_type = qcx.relation_type(&source);
+    let source_arity = source_type.arity();
+
+    let (target, target_scope) = plan_merge_target(&qcx, table_name, alias.as_ref())?;
+
+    let product_scope = source_scope.clone().product(target_scope)?;
+    let product_type = SqlRelationType::new(
+        source_type
+            .column_types
+            .iter()
+            .cloned()
+            .chain(qcx.relation_type(&target).column_types)
+            .collect(),
+    );
+    let on = {
+        let ecx = &ExprContext {
+            qcx: &qcx,
+            name: "ON clause",
+            scope: &product_scope,
+            relation_type: &product_type,
+            allow_aggregates: false,
+            allow_subqueries: false,
+            allow_parameters: true,
+            allow_windows: false,
+        };
+        plan_expr(ecx, &on)?.type_as(ecx, &SqlScalarType::Bool)?
+    };
+    let selection = source.join(target, on, JoinKind::LeftOuter);
+
+    // `WHEN MATCHED` clauses can refer to both the source and the target,
+    // while `WHEN NOT MATCHED` clauses can only refer to the source. Because
+    // the source columns come first, expressions planned against the source
+    // alone can be evaluated against the joined rows as well.
+    let matched_ecx = &ExprContext {
+        qcx: &qcx,
+        name: "WHEN MATCHED clause",
+        scope: &product_scope,
+        relation_type: &product_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let not_matched_ecx = &ExprContext {
+        qcx: &qcx,
+        name: "WHEN NOT MATCHED clause",
+        scope: &source_scope,
+        relation_type: &source_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+
+    let mut planned_clauses = Vec::with_capacity(clauses.len());
+    for clause in clauses {
+        let (matched, condition) = match &clause {
+            MergeWhenClause::Matched { condition, .. } => (true, condition),
+            MergeWhenClause::NotMatched { condition, .. } => (false, condition),
+        };
+        let ecx = if matched {
+            matched_ecx
+        } else {
+            not_matched_ecx
+        };
+        let condition = match condition {
+            Some(condition) => Some(plan_expr(ecx, condition)?.type_as(ecx, &SqlScalarType::Bool)?),
+            None => None,
+        };
+        let action = match clause {
+            MergeWhenClause::Matched { action, .. } => match action {
+                MergeMatchedAction::Update { assignments } => {
+                    let mut sets = BTreeMap::new();
+                    for Assignment { id, value } in assignments {
+                        let name = normalize::column_name(id);
+                        let Some((idx, typ)) = desc.get_by_name(&name) else {
+                            sql_bail!("unknown column {}", name);
+                        };
+                        let expr = plan_expr(ecx, &value)?.cast_to(
+                            ecx,
+                            CastContext::Assignment,
+                            &typ.scalar_type,
+                        )?;
+                        if sets.insert(idx, expr).is_some() {
+                            sql_bail!("column {} set twice", name)
+                        }
+                    }
+                    MergeQueryAction::Update(sets)
+                }
+                MergeMatchedAction::Delete => MergeQueryAction::Delete,
+                MergeMatchedAction::DoNothing => MergeQueryAction::DoNothing,
+            },
+            MergeWhenClause::NotMatched { action, .. } => match action {
+                MergeNotMatchedAction::Insert { columns, values } => {
+                    let columns: Vec<_> = if columns.is_empty() {
+                        desc.iter_names().take(values.len()).cloned().collect()
+                    } else {
+                        columns.into_iter().map(normalize::column_name).collect()
+                    };
+                    if values.len() > columns.len() || values.len() > desc.arity() {
+                        sql_bail!("INSERT has more expressions than target columns");
+                    }
+                    if !values.is_empty() && values.len() < columns.len() {
+                        sql_bail!("INSERT has more target columns than expressions");
+                    }
+                    if let Some(dup) = columns.iter().duplicates().next() {
+                        sql_bail!("column {} specified more than once", dup.quoted());
+                    }
+                    let mut row = Vec::with_capacity(desc.arity());
+                    for ((name, typ), default) in desc.iter().zip_eq(defaults) {
+                        let expr = match columns.iter().position(|c| c == name) {
+                            Some(pos) => {
+                                if values.is_empty() {
+                                    sql_bail!("INSERT has more target columns than expressions");
+                                }
+                                plan_expr(ecx, &values[pos])?.cast_to(
+                                    ecx,
+                                    CastContext::Assignment,
+                                    &typ.scalar_type,
+                                )?
+                            }
+                            None => plan_default_expr(scx, default, &typ.scalar_type)?,
+                        };
+                        row.push(expr);
+                    }
+                    if let Some(unknown) = columns.iter().find(|c| desc.get_by_name(c).is_none()) {
+                        sql_bail!(
+                            "column {} of relation {} does not exist",
+                            unknown.quoted(),
+                            item.name().item.quoted()
+                        );
+                    }
+                    MergeQueryAction::Insert(row)
+                }
+                MergeNotMatchedAction::DoNothing => MergeQueryAction::DoNothing,
+            },
+        };
+        planned_clauses.push(MergeQueryClause {
+            matched,
+            condition,
+            action,
+        });
+    }
+
+    let finishing = RowSetFinishing {
+        order_by: vec![],
+        limit: None,
+        offset: 0,
+        project: (0..product_type.arity()).collect(),
+    };
+
+    Ok(MergeQueryPlan {
+        id,
+        selection,
+        finishing,
+        source_arity,
+        clauses: planned_clauses,
+        conflict_keys: vec![],
+    })
+}
+
+/// Plans the target table of a `MERGE` or `INSERT ... ON CONFLICT`.
+///
+/// Identical target rows are collapsed and their copies counted, so that we
+/// can detect source rows that match the same target row more than once.
+fn plan_merge_target(
+    qcx: &QueryContext,
+    table_name: ResolvedItemName,
+    alias: Option<&TableAlias>,
+) -> Result<(HirRelationExpr, Scope), PlanError> {
+    let (target, scope) = qcx.resolve_table_name(table_name)?;
+    let mut scope = plan_table_alias(scope, alias)?;
+    let arity = qcx.relation_type(&target).arity();
+    let target = target.reduce(
+        (0..arity).collect(),
+        vec![AggregateExpr {
+            func: AggregateFunc::Count,
+            expr: Box::new(HirScalarExpr::literal_true()),
+            distinct: false,
+        }],
+        None,
+    );
+    scope.items.push(ScopeItem::empty());
+    Ok((target, scope))
+}
+
+/// Plans the `ON CONFLICT` clause of an `INSERT` as a `MERGE` of the rows in
+/// `source`, which must already match the shape of the target table, into the
+/// target table.
+///
+/// Rows conflict if they agree on all columns of the conflict target, which
+/// must be a unique constraint of the table. Without a conflict target, rows
+/// conflict if they agree on any unique constraint of the table.
+pub fn plan_on_conflict_query(
+    scx: &StatementContext,
+    table_name: ResolvedItemName,
+    source: HirRelationExpr,
+    OnConflict { columns, action }: OnConflict<Aug>,
+) -> Result<MergeQueryPlan, PlanError> {
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let item = scx.get_item_by_resolved_name(&table_name)?;
+    let id = item.id();
+    let desc = item.relation_desc().expect("table has desc");
+    let arity = desc.arity();
+    let uniques = item.table_uniques();
+
+    // Without a conflict target, a proposed row conflicts with an existing row
+    // on any unique constraint, so it can match several existing rows. That's
+    // fine for DO NOTHING, but DO UPDATE must update at most one row per
+    // proposed row, so it needs a target, which names a single constraint.
+    let conflict_keys = if columns.is_empty() {
+        if matches!(action, OnConflictAction::DoUpdate { .. }) {
+            sql_bail!("ON CONFLICT DO UPDATE requires inference specification");
+        }
+        uniques.to_vec()
+    } else {
+        let mut key = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize::column_name(column);
+            match desc.get_by_name(&name) {
+                Some((idx, _)) => key.push(idx),
+                None => sql_bail!(
+                    "column {} of relation {} does not exist",
+                    name.quoted(),
+                    table_name.full_name_str().quoted()
+                ),
+            }
+        }
+        let matches_key = |unique: &TableUnique| {
+            let k: BTreeSet<_> = unique.columns.iter().collect();
+            k.len() == key.len() && key.iter().all(|c| k.contains(c))
+        };
+        match uniques.iter().find(|unique| matches_key(unique)) {
+            // Only the target constraint, even if the table has others.
+            Some(unique) => vec![unique.clone()],
+            None => {
+                sql_bail!("there is no unique constraint matching the ON CONFLICT specification")
+            }
+        }
+    };
+
+    let source_type = qcx.relation_type(&source);
+    let source_scope = Scope::from_source(
+        Some(PartialItemName {
+            database: None,
+            schema: None,
+            item: "excluded".into(),
+        }),
+        desc.iter_names(),
+    );
+    let (target, target_scope) = plan_merge_target(&qcx, table_name, None)?;
+    let product_scope = source_scope.product(target_scope)?;
+    let product_type = SqlRelationType::new(
+        source_type
+            .column_types
+            .iter()
+            .cloned()
+            .chain(qcx.relation_type(&target).column_types)
+            .collect(),
+    );
+
+    // As with unique constraints, rows with NULLs in a key never conflict,
+    // unless the constraint is NULLS NOT DISTINCT.
+    let on = HirScalarExpr::variadic_or(
+        conflict_keys
+            .iter()
+            .map(|unique| {
+                HirScalarExpr::variadic_and(
+                    unique
+                        .columns
+                        .iter()
+                        .map(|idx| {
+                            let source = HirScalarExpr::column(*idx);
+                            let target = HirScalarExpr::column(arity + idx);
+                            let eq = source.clone().call_binary(target.clone(), expr_func::Eq);
+                            if unique.nulls_distinct {
+                                eq
+                            } else {
+                                eq.or(source.call_is_null().and(target.call_is_null()))
+                            }
+                        })
+                        .collect(),
+                )
+            })
+            .collect(),
+    );
+    let selection = source.join(target, on, JoinKind::LeftOuter);
+
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "ON CONFLICT clause",
+        scope: &product_scope,
+        relation_type: &product_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let matched = match action {
+        OnConflictAction::DoNothing => MergeQueryClause {
+            matched: true,
+            condition: None,
+            action: MergeQueryAction::DoNothing,
+        },
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => {
+            let mut sets = BTreeMap::new();
+            for Assignment { id, mut value } in assignments {
+                transform_ast::transform(scx, &mut value)?;
+                let name = normalize::column_name(id);
+                let Some((idx, typ)) = desc.get_by_name(&name) else {
+                    sql_bail!("unknown column {}", name);
+                };
+                let expr = plan_expr(ecx, &value)?.cast_to(
+                    ecx,
+                    CastContext::Assignment,
+                    &typ.scalar_type,
+                )?;
+                if sets.insert(idx, expr).is_some() {
+                    sql_bail!("column {} set twice", name)
+                }
+            }
+            let condition = match selection {
+                Some(mut selection) => {
+                    transform_ast::transform(scx, &mut selection)?;
+                    Some(plan_expr(ecx, &selection)?.type_as(ecx, &SqlScalarType::Bool)?)
+                }
+                None => None,
+            };
+            MergeQueryClause {
+                matched: true,
+                condition,
+                action: MergeQueryAction::Update(sets),
+            }
+        }
+    };
+    let not_matched = MergeQueryClause {
+        matched: false,
+        condition: None,
+        action: MergeQueryAction::Insert((0..arity).map(HirScalarExpr::column).collect()),
+    };
+
+    let finishing = RowSetFinishing {
+        order_by: vec![],
+        limit: None,
+        offset: 0,
+        project: (0..product_type.arity()).collect(),
+    };
+
+    Ok(MergeQueryPlan {
+        id,
+        selection,
+        finishing,
+        source_arity: arity,
+        clauses: vec![matched, not_matched],
+        conflict_keys,
+    })
+}
+
 // Adjust `get` to perform an existential subquery on `using` accounting for
 // `selection`.
 //
@@ -1295,6 +1748,32 @@ pub fn plan_webhook_validate_using(
     Ok(validation)
 }
 
+/// Plans the default value of a column that an `INSERT` or `COPY` omits.
+///
+/// A default that draws values from a sequence is planned as a `NULL`
+/// placeholder and its sequence is returned, as the coordinator must allocate
+/// a value for every written row.
+fn plan_column_default(
+    scx: &StatementContext,
+    default: &Expr<Aug>,
+    target_ty: &SqlScalarType,
+) -> Result<(HirScalarExpr, Option<CatalogItemId>), PlanError> {
+    let Some(sequence) = side_effecting_func::sequence_default(scx, default) else {
+        return Ok((plan_default_expr(scx, default, target_ty)?, None));
+    };
+    let sequence = side_effecting_func::resolve_sequence(scx, sequence)?;
+    if !matches!(
+        target_ty,
+        SqlScalarType::Int16 | SqlScalarType::Int32 | SqlScalarType::Int64
+    ) {
+        bail_unsupported!("nextval defaults for non-integer columns");
+    }
+    Ok((
+        HirScalarExpr::literal_null(target_ty.clone()),
+        Some(sequence.id()),
+    ))
+}
+
 pub fn plan_default_expr(
     scx: &StatementContext,
     expr: &Expr<Aug>,
@@ -1418,6 +1897,48 @@ pub fn plan_index_exprs<'a>(
     Ok(out)
 }
 
+/// Plans the predicates of `CHECK` constraints on a table described by
+/// `on_desc`.
+pub fn plan_check_exprs<'a>(
+    scx: &'a StatementContext,
+    on_desc: &RelationDesc,
+    exprs: Vec<Expr<Aug>>,
+) -> Result<Vec<mz_expr::MirScalarExpr>, PlanError> {
+    let scope = Scope::from_source(None, on_desc.iter_names());
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "CHECK constraint",
+        scope: &scope,
+        relation_type: on_desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let repr_col_types: Vec<ReprColumnType> = on_desc
+        .typ()
+        .column_types
+        .iter()
+        .map(ReprColumnType::from)
+        .collect();
+    let mut out = vec![];
+    for mut expr in exprs {
+        transform_ast::transform(scx, &mut expr)?;
+        let expr = plan_expr(ecx, &expr)?.type_as(ecx, &SqlScalarType::Bool)?;
+        let mut expr = expr.lower_uncorrelated(scx.catalog.system_vars())?;
+        // Rows are checked when they are written, so the outcome must not
+        // depend on when or by whom they are written.
+        if expr.contains_temporal() || expr.contains_unmaterializable() {
+            sql_bail!("cannot use time- or session-dependent functions in CHECK constraint");
+        }
+        expr.reduce(&repr_col_types);
+        out.push(expr);
+    }
+    Ok(out)
+}
+
 fn plan_expr_or_col_index(ecx: &ExprContext, e: &Expr<Aug>) -> Result<HirScalarExpr, PlanError> {
     match check_col_index(ecx.name, e, ecx.relation_type.column_types.len())? {
         Some(column) => Ok(HirScalarExpr::column(column)),
@@ -1598,7 +2119,7 @@ fn plan_query_inner(qcx: &mut QueryContext, q: &Query<Aug>) -> Result<PlannedQue
         }
     };
 
-    // Both introduce `Let` bindings atop `result` and re-install shadowed bindings.
+    // All introduce `Let` bindings atop `result` and re-install shadowed bindings.
     match &q.ctes {
         CteBlock::Simple(_) => {
             for (id, value, shadowed_val) in cte_bindings.into_iter().rev() {
@@ -1615,6 +2136,31 @@ fn plan_query_inner(qcx: &mut QueryContext, q: &Query<Aug>) -> Result<PlannedQue
                 }
             }
         }
+        CteBlock::Recursive(_) => {
+            // Items that refer to themselves become single-binding `LetRec`s,
+            // all others plain `Let`s.
+            for (id, value, shadowed_val) in cte_bindings.into_iter().rev() {
+                if let Some(cte) = qcx.ctes.remove(&id) {
+                    planned_query.expr = if references_local(&value, id)? {
+                        HirRelationExpr::LetRec {
+                            limit: None,
+                            bindings: vec![(cte.name, id, value, cte.desc.into_typ())],
+                            body: Box::new(planned_query.expr),
+                        }
+                    } else {
+                        HirRelationExpr::Let {
+                            name: cte.name,
+                            id: id.clone(),
+                            value: Box::new(value),
+                            body: Box::new(planned_query.expr),
+                        }
+                    };
+                }
+                if let Some(shadowed_val) = shadowed_val {
+                    qcx.ctes.insert(id, shadowed_val);
+                }
+            }
+        }
         CteBlock::MutuallyRecursive(MutRecBlock { options, ctes: _ }) => {
             let MutRecBlockOptionExtracted {
                 recursion_limit,
@@ -1739,104 +2285,721 @@ pub fn plan_ctes(
                     },
                 );
 
-                result.push((cte.id, val, shadowed));
-            }
-        }
-        CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
-            // Insert column types into `qcx.ctes` first for recursive bindings.
-            for cte in ctes.iter() {
-                let cte_name = normalize::ident(cte.name.clone());
-                let mut desc_columns = Vec::with_capacity(cte.columns.capacity());
-                for column in cte.columns.iter() {
-                    desc_columns.push((
-                        normalize::column_name(column.name.clone()),
-                        SqlColumnType {
-                            scalar_type: scalar_type_from_sql(qcx.scx, &column.data_type)?,
-                            nullable: true,
-                        },
-                    ));
-                }
-                let desc = RelationDesc::from_names_and_types(desc_columns);
-                let shadowed = qcx.ctes.insert(
-                    cte.id,
-                    CteDesc {
-                        name: cte_name,
-                        desc,
-                    },
-                );
-                // Capture the prior value if it exists, so that it can be re-installed.
-                if let Some(shadowed) = shadowed {
-                    shadowed_descs.insert(cte.id, shadowed);
-                }
-            }
+                result.push((cte.id, val, shadowed));
+            }
+        }
+        CteBlock::Recursive(ctes) => {
+            // Plan CTEs in order. Each may refer to itself and to earlier CTEs,
+            // but, unlike in `WITH MUTUALLY RECURSIVE`, not to later ones.
+            for (i, cte) in ctes.iter().enumerate() {
+                let cte_name = normalize::ident(cte.alias.name.clone());
+                let mut references = CteReferenceCollector::default();
+                references.visit_query(&cte.query);
+                if let Some(later) = ctes[i + 1..]
+                    .iter()
+                    .find(|later| references.ids.contains(&later.id))
+                {
+                    sql_bail!(
+                        "WITH query {} refers to {}, which is defined later in the block; \
+                         use WITH MUTUALLY RECURSIVE for mutually recursive queries",
+                        normalize::ident_ref(&cte.alias.name).quoted(),
+                        normalize::ident_ref(&later.alias.name).quoted(),
+                    );
+                }
+
+                // Capture the prior value if it exists, so that it can be re-installed.
+                let shadowed = qcx.ctes.remove(&cte.id);
+                let (val, desc) = if references.ids.contains(&cte.id) {
+                    plan_recursive_cte(qcx, cte)?
+                } else {
+                    if cte.search.is_some() || cte.cycle.is_some() {
+                        sql_bail!(
+                            "WITH query {} is not recursive",
+                            normalize::ident_ref(&cte.alias.name).quoted()
+                        );
+                    }
+                    let (val, scope) = plan_nested_query(qcx, &cte.query)?;
+                    let typ = qcx.relation_type(&val);
+                    let mut desc = RelationDesc::new(typ, scope.column_names());
+                    plan_utils::maybe_rename_columns(
+                        format!("CTE {}", cte.alias.name),
+                        &mut desc,
+                        &cte.alias.columns,
+                    )?;
+                    (val, desc)
+                };
+                qcx.ctes.insert(
+                    cte.id,
+                    CteDesc {
+                        name: cte_name,
+                        desc,
+                    },
+                );
+
+                result.push((cte.id, val, shadowed));
+            }
+        }
+        CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
+            // Insert column types into `qcx.ctes` first for recursive bindings.
+            for cte in ctes.iter() {
+                let cte_name = normalize::ident(cte.name.clone());
+                let mut desc_columns = Vec::with_capacity(cte.columns.capacity());
+                for column in cte.columns.iter() {
+                    desc_columns.push((
+                        normalize::column_name(column.name.clone()),
+                        SqlColumnType {
+                            scalar_type: scalar_type_from_sql(qcx.scx, &column.data_type)?,
+                            nullable: true,
+                        },
+                    ));
+                }
+                let desc = RelationDesc::from_names_and_types(desc_columns);
+                let shadowed = qcx.ctes.insert(
+                    cte.id,
+                    CteDesc {
+                        name: cte_name,
+                        desc,
+                    },
+                );
+                // Capture the prior value if it exists, so that it can be re-installed.
+                if let Some(shadowed) = shadowed {
+                    shadowed_descs.insert(cte.id, shadowed);
+                }
+            }
+
+            // Plan all CTEs and validate the proposed types.
+            for cte in ctes.iter() {
+                let (val, _scope) = plan_nested_query(qcx, &cte.query)?;
+
+                let proposed_typ = qcx.ctes[&cte.id].desc.typ();
+
+                if proposed_typ.column_types.iter().any(|c| !c.nullable) {
+                    // Once WMR CTEs support NOT NULL constraints, check that
+                    // nullability of derived column types are compatible.
+                    sql_bail!(
+                        "[internal error]: WMR CTEs do not support NOT NULL constraints on proposed column types"
+                    );
+                }
+
+                if !proposed_typ.keys.is_empty() {
+                    // Once WMR CTEs support keys, check that keys exactly
+                    // overlap.
+                    sql_bail!("[internal error]: WMR CTEs do not support keys");
+                }
+
+                // Validate that the derived and proposed types are the same.
+                let derived_typ = qcx.relation_type(&val);
+
+                let type_err = |proposed_typ: &SqlRelationType, derived_typ: SqlRelationType| {
+                    let cte_name = normalize::ident(cte.name.clone());
+                    let proposed_typ = proposed_typ
+                        .column_types
+                        .iter()
+                        .map(|ty| qcx.humanize_sql_scalar_type(&ty.scalar_type, false))
+                        .collect::<Vec<_>>();
+                    let inferred_typ = derived_typ
+                        .column_types
+                        .iter()
+                        .map(|ty| qcx.humanize_sql_scalar_type(&ty.scalar_type, false))
+                        .collect::<Vec<_>>();
+                    Err(PlanError::RecursiveTypeMismatch(
+                        cte_name,
+                        proposed_typ,
+                        inferred_typ,
+                    ))
+                };
+
+                if derived_typ.column_types.len() != proposed_typ.column_types.len() {
+                    return type_err(proposed_typ, derived_typ);
+                }
+
+                // Cast derived types to proposed types or error.
+                let val = match cast_relation(
+                    qcx,
+                    // Choose `CastContext::Assignment`` because the user has
+                    // been explicit about the types they expect. Choosing
+                    // `CastContext::Implicit` is not "strong" enough to impose
+                    // typmods from proposed types onto values.
+                    CastContext::Assignment,
+                    val,
+                    proposed_typ.column_types.iter().map(|c| &c.scalar_type),
+                ) {
+                    Ok(val) => val,
+                    Err(_) => return type_err(proposed_typ, derived_typ),
+                };
+
+                result.push((cte.id, val, shadowed_descs.remove(&cte.id)));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Plans a `WITH RECURSIVE` CTE that refers to itself, which must have the
+/// form `non-recursive-term UNION [ALL] recursive-term`.
+///
+/// The column types are those of the non-recursive term. The returned
+/// description is also the one the recursive reference is planned with: all
+/// columns are nullable and there are no keys.
+fn plan_recursive_cte(
+    qcx: &mut QueryContext,
+    cte: &CteRecursive<Aug>,
+) -> Result<(HirRelationExpr, RelationDesc), PlanError> {
+    let name = normalize::ident_ref(&cte.alias.name).quoted();
+    let (all, left, right) = match &cte.query {
+        Query {
+            ctes,
+            body:
+                SetExpr::SetOperation {
+                    op: SetOperator::Union,
+                    all,
+                    left,
+                    right,
+                },
+            order_by,
+            limit,
+            offset,
+        } => {
+            if !ctes.is_empty() {
+                bail_unsupported!("WITH in a recursive query");
+            }
+            if !order_by.is_empty() {
+                bail_unsupported!("ORDER BY in a recursive query");
+            }
+            if limit.is_some() || offset.is_some() {
+                bail_unsupported!("LIMIT or OFFSET in a recursive query");
+            }
+            (*all, left, right)
+        }
+        _ => sql_bail!(
+            "recursive query {} does not have the form non-recursive-term UNION [ALL] recursive-term",
+            name
+        ),
+    };
+
+    let mut left_references = CteReferenceCollector::default();
+    left_references.visit_set_expr(left);
+    if left_references.ids.contains(&cte.id) {
+        sql_bail!(
+            "recursive reference to query {} must not appear within its non-recursive term",
+            name
+        );
+    }
+    let mut validator = RecursiveTermValidator::new(cte.id);
+    validator.visit_set_expr(right);
+    validator.finish(&cte.alias.name)?;
+
+    let nested_query = |body: SetExpr<Aug>| Query {
+        ctes: CteBlock::empty(),
+        body,
+        order_by: vec![],
+        limit: None,
+        offset: None,
+    };
+
+    // The non-recursive term determines the column types.
+    let (left_expr, left_scope) = plan_nested_query(qcx, &nested_query((**left).clone()))?;
+    let left_typ = qcx.relation_type(&left_expr);
+    let mut desc = RelationDesc::new(left_typ.clone(), left_scope.column_names());
+    plan_utils::maybe_rename_columns(
+        format!("CTE {}", cte.alias.name),
+        &mut desc,
+        &cte.alias.columns,
+    )?;
+    let arity = desc.arity();
+    let column_names: Vec<ColumnName> = desc.iter_names().cloned().collect();
+
+    // The columns added by the SEARCH and CYCLE clauses, in that order.
+    let mut extra_names: Vec<(&str, ColumnName)> = vec![];
+    if let Some(search) = &cte.search {
+        extra_names.push((
+            "search sequence",
+            normalize::column_name(search.sequence_column.clone()),
+        ));
+    }
+    if let Some(cycle) = &cte.cycle {
+        extra_names.push((
+            "cycle mark",
+            normalize::column_name(cycle.mark_column.clone()),
+        ));
+        extra_names.push((
+            "cycle path",
+            normalize::column_name(cycle.path_column.clone()),
+        ));
+    }
+    for (i, (kind, extra_name)) in extra_names.iter().enumerate() {
+        if column_names.contains(extra_name) {
+            sql_bail!(
+                "{} column name {} already used in WITH query column list",
+                kind,
+                extra_name.as_str().quoted()
+            );
+        }
+        if let Some((other_kind, _)) = extra_names[..i].iter().find(|(_, n)| n == extra_name) {
+            sql_bail!(
+                "{} column name and {} column name are the same",
+                other_kind,
+                kind
+            );
+        }
+    }
+    let extra_names: Vec<ColumnName> = extra_names.into_iter().map(|(_, n)| n).collect();
+
+    let resolve_columns = |clause: &str, idents: &[Ident]| -> Result<Vec<usize>, PlanError> {
+        let mut columns = Vec::with_capacity(idents.len());
+        for ident in idents {
+            let column_name = normalize::column_name(ident.clone());
+            let Some(i) = column_names.iter().position(|c| *c == column_name) else {
+                sql_bail!(
+                    "{} column {} not in WITH query column list",
+                    clause,
+                    column_name.as_str().quoted()
+                );
+            };
+            if columns.contains(&i) {
+                sql_bail!(
+                    "{} column {} specified more than once",
+                    clause,
+                    column_name.as_str().quoted()
+                );
+            }
+            columns.push(i);
+        }
+        Ok(columns)
+    };
+    // A single-element array holding `ROW(...)` of the given columns. The
+    // columns have the same positions in both terms.
+    let singleton_path = |columns: &[usize]| {
+        let fields: Vec<_> = columns
+            .iter()
+            .map(|i| {
+                let typ = SqlColumnType {
+                    scalar_type: left_typ.column_types[*i].scalar_type.clone(),
+                    nullable: true,
+                };
+                (column_names[*i].clone(), typ)
+            })
+            .collect();
+        let row = HirScalarExpr::call_variadic(
+            RecordCreate {
+                field_names: fields.iter().map(|(name, _)| name.clone()).collect(),
+            },
+            columns.iter().map(|i| HirScalarExpr::column(*i)).collect(),
+        );
+        let elem_type = SqlScalarType::Record {
+            fields: fields.into(),
+            custom_id: None,
+        };
+        let path = HirScalarExpr::call_variadic(ArrayCreate { elem_type }, vec![row.clone()]);
+        (row, path)
+    };
+
+    // Expressions for the added columns of the non-recursive term, and of the
+    // recursive term in terms of the columns of the row being expanded, which
+    // the recursive term selects after its own columns.
+    let mut base_extras = vec![];
+    let mut recursive_extras = vec![];
+    let mut cycle_filter = None;
+    if let Some(search) = &cte.search {
+        let columns = resolve_columns("search", &search.columns)?;
+        let old_seq = HirScalarExpr::column(arity + base_extras.len());
+        if search.breadth_first {
+            // `ROW(depth, cols...)`, which sorts by depth first.
+            let field_names: Vec<_> = iter::once(ColumnName::from("depth"))
+                .chain(columns.iter().map(|i| column_names[*i].clone()))
+                .collect();
+            let row = |depth: HirScalarExpr| {
+                HirScalarExpr::call_variadic(
+                    RecordCreate {
+                        field_names: field_names.clone(),
+                    },
+                    iter::once(depth)
+                        .chain(columns.iter().map(|i| HirScalarExpr::column(*i)))
+                        .collect(),
+                )
+            };
+            base_extras.push(row(HirScalarExpr::literal(
+                Datum::Int64(0),
+                SqlScalarType::Int64,
+            )));
+            recursive_extras.push(row(old_seq
+                .call_unary(UnaryFunc::RecordGet(expr_func::RecordGet(0)))
+                .call_binary(
+                    HirScalarExpr::literal(Datum::Int64(1), SqlScalarType::Int64),
+                    expr_func::AddInt64,
+                )));
+        } else {
+            // The array of `ROW(cols...)` along the path to the row, which
+            // sorts parents before their descendants.
+            let (_row, path) = singleton_path(&columns);
+            base_extras.push(path.clone());
+            recursive_extras.push(old_seq.call_binary(path, expr_func::ArrayArrayConcat));
+        }
+    }
+    if let Some(cycle) = &cte.cycle {
+        let columns = resolve_columns("cycle", &cycle.columns)?;
+        let (mark, default) = match &cycle.mark_values {
+            None => (
+                HirScalarExpr::literal_true(),
+                HirScalarExpr::literal_false(),
+            ),
+            Some((mark, default)) => {
+                let ecx = &ExprContext {
+                    qcx,
+                    name: "CYCLE",
+                    scope: &Scope::empty(),
+                    relation_type: &SqlRelationType::empty(),
+                    allow_aggregates: false,
+                    allow_subqueries: false,
+                    allow_parameters: false,
+                    allow_windows: false,
+                };
+                let mark = plan_expr(ecx, &Expr::Value(mark.clone()))?.type_as_any(ecx)?;
+                let default = plan_expr(ecx, &Expr::Value(default.clone()))?.type_as_any(ecx)?;
+                if !ecx.scalar_type(&mark).base_eq(&ecx.scalar_type(&default)) {
+                    sql_bail!("CYCLE mark value and default value must have the same type");
+                }
+                (mark, default)
+            }
+        };
+        let old_mark = HirScalarExpr::column(arity + base_extras.len());
+        let old_path = HirScalarExpr::column(arity + base_extras.len() + 1);
+        let (row, path) = singleton_path(&columns);
+        base_extras.push(default.clone());
+        base_extras.push(path.clone());
+        recursive_extras.push(HirScalarExpr::if_then_else(
+            row.call_binary(old_path.clone(), expr_func::ArrayContains),
+            mark.clone(),
+            default,
+        ));
+        recursive_extras.push(old_path.call_binary(path, expr_func::ArrayArrayConcat));
+        // Rows that close a cycle are emitted, but not expanded further.
+        cycle_filter = Some(
+            old_mark
+                .call_binary(mark, expr_func::Eq)
+                .call_unary(UnaryFunc::Not(expr_func::Not)),
+        );
+    }
+
+    let left_expr = left_expr.map(base_extras);
+    let binding_desc = RelationDesc::from_names_and_types(
+        column_names.iter().chain(extra_names.iter()).cloned().zip(
+            qcx.relation_type(&left_expr)
+                .column_types
+                .into_iter()
+                .map(|typ| typ.nullable(true)),
+        ),
+    );
+    qcx.ctes.insert(
+        cte.id,
+        CteDesc {
+            name: normalize::ident(cte.alias.name.clone()),
+            desc: binding_desc.clone(),
+        },
+    );
+
+    let right = if extra_names.is_empty() {
+        (**right).clone()
+    } else {
+        select_recursive_columns(right, cte.id, &cte.alias.name, &extra_names)?
+    };
+    let (right_expr, _right_scope) = plan_nested_query(qcx, &nested_query(right))?;
+    check_recursive_term(&right_expr, cte.id, &cte.alias.name)?;
+
+    let right_typ = qcx.relation_type(&right_expr);
+    let extras = extra_names.len();
+    if right_typ.arity() != arity + extras {
+        sql_bail!(
+            "each UNION query must have the same number of columns: {} vs {}",
+            arity,
+            right_typ.arity() - extras
+        );
+    }
+    let target_types: Vec<_> = left_typ
+        .column_types
+        .iter()
+        .chain(&right_typ.column_types[arity..])
+        .map(|typ| typ.scalar_type.clone())
+        .collect();
+    let mut right_expr = cast_relation(qcx, CastContext::Implicit, right_expr, &target_types)
+        .map_err(|e| {
+            sql_err!(
+                "recursive query {} column {} has type {} in non-recursive term but type {} in recursive term",
+                name,
+                e.column + 1,
+                qcx.humanize_sql_scalar_type(&e.target_type, false),
+                qcx.humanize_sql_scalar_type(&e.source_type, false),
+            )
+        })?;
+    if let Some(cycle_filter) = cycle_filter {
+        right_expr = right_expr.filter(vec![cycle_filter]);
+    }
+    let right_expr = right_expr.map(recursive_extras).project(
+        (0..arity)
+            .chain(arity + extras..arity + 2 * extras)
+            .collect(),
+    );
+
+    let mut expr = left_expr.union(right_expr);
+    if !all {
+        expr = expr.distinct();
+    }
+    Ok((expr, binding_desc))
+}
+
+/// Appends the given columns of the recursive reference to the `SELECT` list
+/// of a recursive term, qualified by the name its `FROM` clause gives the
+/// reference.
+fn select_recursive_columns(
+    right: &SetExpr<Aug>,
+    id: LocalId,
+    name: &Ident,
+    columns: &[ColumnName],
+) -> Result<SetExpr<Aug>, PlanError> {
+    let SetExpr::Select(select) = right else {
+        sql_bail!(
+            "with a SEARCH or CYCLE clause, the recursive term of query {} must be a SELECT",
+            normalize::ident_ref(name).quoted()
+        );
+    };
+    let Some(reference) = select
+        .from
+        .iter()
+        .find_map(|table| recursive_reference_name(table, id))
+    else {
+        sql_bail!(
+            "with a SEARCH or CYCLE clause, the recursive reference to query {} must be at the top level of its FROM clause",
+            normalize::ident_ref(name).quoted()
+        );
+    };
+    let mut select = select.clone();
+    select
+        .projection
+        .extend(columns.iter().map(|column| SelectItem::Expr {
+            expr: Expr::Identifier(vec![
+                reference.clone(),
+                Ident::new_unchecked(column.as_str()),
+            ]),
+            alias: None,
+        }));
+    Ok(SetExpr::Select(select))
+}
+
+/// Returns the name under which `table` exposes the CTE `id`, if it joins it
+/// directly.
+fn recursive_reference_name(table: &TableWithJoins<Aug>, id: LocalId) -> Option<Ident> {
+    iter::once(&table.relation)
+        .chain(table.joins.iter().map(|join| &join.relation))
+        .find_map(|factor| match factor {
+            TableFactor::Table {
+                name: ResolvedItemName::Cte { id: cte_id, name },
+                alias,
+            } if *cte_id == id => Some(
+                alias
+                    .as_ref()
+                    .map_or_else(|| Ident::new_unchecked(name.clone()), |a| a.name.clone()),
+            ),
+            TableFactor::NestedJoin { join, alias: None } => recursive_reference_name(join, id),
+            _ => None,
+        })
+}
+
+/// Rejects aggregation, `DISTINCT`, and `LIMIT` over the recursive reference
+/// in a recursive term. The `LetRec` a recursive CTE is planned as evaluates
+/// the recursive term against the whole result so far, whereas the SQL
+/// semantics evaluate it against the rows added by the previous iteration
+/// only. The two agree only for terms that are linear in the reference.
+fn check_recursive_term(
+    expr: &HirRelationExpr,
+    id: LocalId,
+    name: &Ident,
+) -> Result<(), PlanError> {
+    // local import to not get confused with `mz_sql_parser::ast::visit::Visit`
+    use mz_expr::visit::Visit;
+    let mut result = Ok(());
+    expr.visit_pre(&mut |e| {
+        if result.is_err() {
+            return;
+        }
+        let (input, construct) = match e {
+            HirRelationExpr::Reduce { input, .. } => (input, "aggregate functions are"),
+            HirRelationExpr::Distinct { input } => (input, "DISTINCT is"),
+            HirRelationExpr::TopK { input, .. } => (input, "ORDER BY, LIMIT, and OFFSET are"),
+            _ => return,
+        };
+        result = match references_local(input, id) {
+            Ok(true) => Err(sql_err!(
+                "{} not allowed over the recursive reference to query {}",
+                construct,
+                normalize::ident_ref(name).quoted()
+            )),
+            Ok(false) => Ok(()),
+            Err(e) => Err(e),
+        };
+    })?;
+    result
+}
 
-            // Plan all CTEs and validate the proposed types.
-            for cte in ctes.iter() {
-                let (val, _scope) = plan_nested_query(qcx, &cte.query)?;
+/// Returns whether `expr` reads the local binding `id`.
+fn references_local(expr: &HirRelationExpr, id: LocalId) -> Result<bool, PlanError> {
+    // local import to not get confused with `mz_sql_parser::ast::visit::Visit`
+    use mz_expr::visit::Visit;
+    let mut found = false;
+    expr.visit_pre(&mut |e| {
+        if let HirRelationExpr::Get {
+            id: Id::Local(local_id),
+            ..
+        } = e
+        {
+            found |= *local_id == id;
+        }
+    })?;
+    Ok(found)
+}
 
-                let proposed_typ = qcx.ctes[&cte.id].desc.typ();
+/// Collects the CTEs an AST refers to.
+#[derive(Default)]
+struct CteReferenceCollector {
+    ids: BTreeSet<LocalId>,
+}
 
-                if proposed_typ.column_types.iter().any(|c| !c.nullable) {
-                    // Once WMR CTEs support NOT NULL constraints, check that
-                    // nullability of derived column types are compatible.
-                    sql_bail!(
-                        "[internal error]: WMR CTEs do not support NOT NULL constraints on proposed column types"
-                    );
-                }
+impl Visit<'_, Aug> for CteReferenceCollector {
+    fn visit_item_name(&mut self, name: &ResolvedItemName) {
+        if let ResolvedItemName::Cte { id, .. } = name {
+            self.ids.insert(*id);
+        }
+    }
+}
 
-                if !proposed_typ.keys.is_empty() {
-                    // Once WMR CTEs support keys, check that keys exactly
-                    // overlap.
-                    sql_bail!("[internal error]: WMR CTEs do not support keys");
-                }
+/// Checks that the recursive term of a recursive CTE refers to the CTE at
+/// most once, and not from within a construct that would make the term
+/// non-linear in it. See [`check_recursive_term`].
+struct RecursiveTermValidator {
+    id: LocalId,
+    references: usize,
+    /// The innermost construct being visited that must not contain the
+    /// recursive reference.
+    context: Option<&'static str>,
+    /// The construct the recursive reference first appeared in, if any.
+    violation: Option<&'static str>,
+    window_functions: bool,
+}
 
-                // Validate that the derived and proposed types are the same.
-                let derived_typ = qcx.relation_type(&val);
+impl RecursiveTermValidator {
+    fn new(id: LocalId) -> Self {
+        RecursiveTermValidator {
+            id,
+            references: 0,
+            context: None,
+            violation: None,
+            window_functions: false,
+        }
+    }
 
-                let type_err = |proposed_typ: &SqlRelationType, derived_typ: SqlRelationType| {
-                    let cte_name = normalize::ident(cte.name.clone());
-                    let proposed_typ = proposed_typ
-                        .column_types
-                        .iter()
-                        .map(|ty| qcx.humanize_sql_scalar_type(&ty.scalar_type, false))
-                        .collect::<Vec<_>>();
-                    let inferred_typ = derived_typ
-                        .column_types
-                        .iter()
-                        .map(|ty| qcx.humanize_sql_scalar_type(&ty.scalar_type, false))
-                        .collect::<Vec<_>>();
-                    Err(PlanError::RecursiveTypeMismatch(
-                        cte_name,
-                        proposed_typ,
-                        inferred_typ,
-                    ))
-                };
+    fn within(&mut self, context: Option<&'static str>, f: impl FnOnce(&mut Self)) {
+        let prev = self.context;
+        if context.is_some() {
+            self.context = context;
+        }
+        f(self);
+        self.context = prev;
+    }
 
-                if derived_typ.column_types.len() != proposed_typ.column_types.len() {
-                    return type_err(proposed_typ, derived_typ);
-                }
+    fn finish(self, name: &Ident) -> Result<(), PlanError> {
+        let name = normalize::ident_ref(name).quoted();
+        if let Some(context) = self.violation {
+            sql_bail!(
+                "recursive reference to query {} must not appear within {}",
+                name,
+                context
+            );
+        }
+        if self.references > 1 {
+            sql_bail!(
+                "recursive reference to query {} must not appear more than once",
+                name
+            );
+        }
+        if self.window_functions {
+            bail_unsupported!("window functions in the recursive term of a recursive query");
+        }
+        Ok(())
+    }
+}
 
-                // Cast derived types to proposed types or error.
-                let val = match cast_relation(
-                    qcx,
-                    // Choose `CastContext::Assignment`` because the user has
-                    // been explicit about the types they expect. Choosing
-                    // `CastContext::Implicit` is not "strong" enough to impose
-                    // typmods from proposed types onto values.
-                    CastContext::Assignment,
-                    val,
-                    proposed_typ.column_types.iter().map(|c| &c.scalar_type),
-                ) {
-                    Ok(val) => val,
-                    Err(_) => return type_err(proposed_typ, derived_typ),
-                };
+impl<'ast> Visit<'ast, Aug> for RecursiveTermValidator {
+    fn visit_item_name(&mut self, name: &'ast ResolvedItemName) {
+        if matches!(name, ResolvedItemName::Cte { id, .. } if *id == self.id) {
+            self.references += 1;
+            if self.violation.is_none() {
+                self.violation = self.context;
+            }
+        }
+    }
 
-                result.push((cte.id, val, shadowed_descs.remove(&cte.id)));
+    fn visit_expr(&mut self, expr: &'ast Expr<Aug>) {
+        match expr {
+            Expr::Exists(_)
+            | Expr::Subquery(_)
+            | Expr::InSubquery { .. }
+            | Expr::AnySubquery { .. }
+            | Expr::AllSubquery { .. }
+            | Expr::ArraySubquery(_)
+            | Expr::ListSubquery(_)
+            | Expr::MapSubquery(_) => {
+                self.within(Some("a subquery"), |v| visit::visit_expr(v, expr))
             }
+            _ => visit::visit_expr(self, expr),
         }
     }
 
-    Ok(result)
+    fn visit_function(&mut self, func: &'ast Function<Aug>) {
+        self.window_functions |= func.over.is_some();
+        visit::visit_function(self, func);
+    }
+
+    fn visit_set_expr(&mut self, set_expr: &'ast SetExpr<Aug>) {
+        let context = match set_expr {
+            SetExpr::SetOperation {
+                op: SetOperator::Intersect,
+                ..
+            } => Some("INTERSECT"),
+            SetExpr::SetOperation {
+                op: SetOperator::Except,
+                ..
+            } => Some("EXCEPT"),
+            _ => None,
+        };
+        self.within(context, |v| visit::visit_set_expr(v, set_expr));
+    }
+
+    fn visit_table_with_joins(&mut self, table: &'ast TableWithJoins<Aug>) {
+        // A relation is null-extended if it is the right input of a left or
+        // full join, or part of the left input of a later right or full join.
+        let extended_by_later = |k: usize| {
+            table.joins[k..].iter().any(|join| {
+                matches!(
+                    join.join_operator,
+                    JoinOperator::RightOuter(_) | JoinOperator::FullOuter(_)
+                )
+            })
+        };
+        let outer_join = |nullable: bool| nullable.then_some("an outer join");
+        self.within(outer_join(extended_by_later(0)), |v| {
+            v.visit_table_factor(&table.relation)
+        });
+        for (i, join) in table.joins.iter().enumerate() {
+            let nullable = matches!(
+                join.join_operator,
+                JoinOperator::LeftOuter(_) | JoinOperator::FullOuter(_)
+            ) || extended_by_later(i + 1);
+            self.within(outer_join(nullable), |v| {
+                v.visit_table_factor(&join.relation)
+            });
+            self.visit_join_operator(&join.join_operator);
+        }
+    }
 }
 
 pub fn plan_nested_query(
@@ -3342,9 +4505,46 @@ fn plan_table_function_internal(
         item: table_name,
     });
 
-    let (expr, mut scope) = match resolve_func(ecx, name, args)? {
-        Func::Table(impls) => {
-            let tf = func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
+    enum PlannedFunc {
+        Table(func::TableFuncPlan),
+        Scalar(HirScalarExpr),
+    }
+
+    let planned = match sql_function(qcx.scx, name) {
+        Some(function) => match plan_sql_function_call(ecx, name, function, scalar_args)? {
+            SqlFunctionCall::Table(expr) => {
+                let function_ident = Ident::new(name.full_item_name().item.clone())?;
+                PlannedFunc::Table(func::TableFuncPlan {
+                    imp: TableFuncImpl::Expr(expr),
+                    column_names: vec![normalize::column_name(function_ident)],
+                })
+            }
+            SqlFunctionCall::Scalar(expr) => PlannedFunc::Scalar(expr),
+        },
+        None => match resolve_func(ecx, name, args)? {
+            Func::Table(impls) => PlannedFunc::Table(func::select_impl(
+                ecx,
+                FuncSpec::Func(name),
+                impls,
+                scalar_args,
+                vec![],
+            )?),
+            Func::Scalar(impls) => PlannedFunc::Scalar(func::select_impl(
+                ecx,
+                FuncSpec::Func(name),
+                impls,
+                scalar_args,
+                vec![],
+            )?),
+            o => sql_bail!(
+                "{} functions are not supported in functions in FROM",
+                o.class()
+            ),
+        },
+    };
+
+    let (expr, mut scope) = match planned {
+        PlannedFunc::Table(tf) => {
             let scope = Scope::from_source(scope_name.clone(), tf.column_names);
             let expr = match tf.imp {
                 TableFuncImpl::CallTable { mut func, exprs } => {
@@ -3395,8 +4595,7 @@ fn plan_table_function_internal(
             };
             (expr, scope)
         }
-        Func::Scalar(impls) => {
-            let expr = func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
+        PlannedFunc::Scalar(expr) => {
             let output = expr.typ(
                 &qcx.outer_relation_types,
                 &SqlRelationType::new(vec![]),
@@ -3426,10 +4625,6 @@ fn plan_table_function_internal(
                 scope,
             )
         }
-        o => sql_bail!(
-            "{} functions are not supported in functions in FROM",
-            o.class()
-        ),
     };
 
     if with_ordinality {
@@ -5393,6 +6588,46 @@ fn plan_function<'a>(
         distinct,
     }: &'a Function<Aug>,
 ) -> Result<HirScalarExpr, PlanError> {
+    if let Some(function) = sql_function(ecx.qcx.scx, name) {
+        let humanized_name = ecx
+            .qcx
+            .scx
+            .humanize_resolved_name(name)
+            .expect("already resolved");
+        if over.is_some() {
+            sql_bail!(
+                "OVER clause not allowed on {humanized_name}. The OVER clause can only be used with window functions (including aggregations)."
+            );
+        }
+        if *distinct {
+            sql_bail!("DISTINCT specified, but {humanized_name} is not an aggregate function");
+        }
+        if filter.is_some() {
+            sql_bail!("FILTER specified, but {humanized_name} is not an aggregate function");
+        }
+        let scalar_args = match args {
+            FunctionArgs::Star => {
+                sql_bail!("* argument is invalid with non-aggregate function {humanized_name}")
+            }
+            FunctionArgs::Args { args, order_by } => {
+                if !order_by.is_empty() {
+                    sql_bail!(
+                        "ORDER BY specified, but {humanized_name} is not an aggregate function"
+                    );
+                }
+                plan_exprs(ecx, args)?
+            }
+        };
+        return match plan_sql_function_call(ecx, name, function, scalar_args)? {
+            SqlFunctionCall::Scalar(expr) => Ok(expr),
+            SqlFunctionCall::Table(_) => sql_bail!(
+                "table functions are not allowed in {} (function {})",
+                ecx.name,
+                name
+            ),
+        };
+    }
+
     let impls = match resolve_func(ecx, name, args)? {
         Func::Table(_) => {
             sql_bail!(
@@ -5652,6 +6887,221 @@ pub fn resolve_func(
     })
 }
 
+/// Returns the definition of the SQL-language function that `name` refers to,
+/// if any.
+fn sql_function<'a>(
+    scx: &StatementContext<'a>,
+    name: &ResolvedItemName,
+) -> Option<&'a SqlFunction> {
+    match name {
+        ResolvedItemName::Item { id, .. } => scx.catalog.get_item(id).sql_function(),
+        _ => None,
+    }
+}
+
+/// The result of planning a call to a SQL-language function.
+enum SqlFunctionCall {
+    /// A call to a function that returns a single value.
+    Scalar(HirScalarExpr),
+    /// A call to a function that returns a set of values.
+    Table(HirRelationExpr),
+}
+
+/// Plans a call to a SQL-language function by inlining its body.
+///
+/// The arguments are implicitly cast to the function's parameter types and
+/// spliced into the body in place of its parameters.
+fn plan_sql_function_call(
+    ecx: &ExprContext,
+    name: &ResolvedItemName,
+    function: &SqlFunction,
+    args: Vec<CoercibleScalarExpr>,
+) -> Result<SqlFunctionCall, PlanError> {
+    let unknown_function = |args: &[CoercibleScalarExpr]| {
+        let arg_types = args
+            .iter()
+            .map(|arg| match ecx.scalar_type(arg) {
+                CoercibleScalarType::Coerced(ty) => ecx.humanize_sql_scalar_type(&ty, false),
+                CoercibleScalarType::Record(_) => "record".to_string(),
+                CoercibleScalarType::Uncoerced => "unknown".to_string(),
+            })
+            .collect();
+        PlanError::UnknownFunction {
+            name: name.to_string(),
+            arg_types,
+        }
+    };
+
+    if args.len() != function.arity() {
+        return Err(unknown_function(&args));
+    }
+    let mut exprs = Vec::with_capacity(args.len());
+    for (arg, ty) in args.iter().zip_eq(&function.param_types) {
+        match arg.clone().cast_to(ecx, CastContext::Implicit, ty) {
+            Ok(expr) => exprs.push(expr),
+            Err(_) => return Err(unknown_function(&args)),
+        }
+    }
+
+    match &function.body {
+        SqlFunctionBody::Scalar(expr) => {
+            let mut expr = expr.clone();
+            expr.splice_parameters(&exprs, 0);
+            Ok(SqlFunctionCall::Scalar(expr))
+        }
+        SqlFunctionBody::Relation(expr) if function.returns_set => {
+            let mut expr = expr.clone();
+            expr.splice_parameters(&exprs, 0);
+            Ok(SqlFunctionCall::Table(expr))
+        }
+        SqlFunctionBody::Relation(expr) => {
+            // The body is a query that produces at most one row, so inline it
+            // as a scalar subquery.
+            if !ecx.allow_subqueries {
+                sql_bail!("{} does not allow subqueries (function {})", ecx.name, name);
+            }
+            let mut expr = expr.clone().select();
+            expr.splice_parameters(&exprs, 0);
+            Ok(SqlFunctionCall::Scalar(expr))
+        }
+    }
+}
+
+/// Plans the body of a SQL-language function.
+///
+/// The body must refer to the function's arguments as parameters (`$1`, `$2`,
+/// ...) whose types are bound in `scx`. A body that is a lone `SELECT <expr>`
+/// of a function that does not return a set is planned as a scalar
+/// expression, so that calls to the function optimize exactly like the
+/// expression written in place. Any other body is planned as a relation with a
+/// single column.
+pub fn plan_sql_function_body(
+    scx: &StatementContext,
+    body: &Query<Aug>,
+    return_type: &SqlScalarType,
+    returns_set: bool,
+) -> Result<SqlFunctionBody, PlanError> {
+    let mut qcx = QueryContext::root(scx, QueryLifetime::View);
+
+    if !returns_set {
+        if let Some(expr) = scalar_function_body(scx, body) {
+            let mut expr = expr.clone();
+            transform_ast::transform(scx, &mut expr)?;
+            let ecx = ExprContext {
+                qcx: &qcx,
+                name: "function body",
+                scope: &Scope::empty(),
+                relation_type: &SqlRelationType::empty(),
+                allow_aggregates: false,
+                allow_subqueries: false,
+                allow_parameters: true,
+                allow_windows: false,
+            };
+            let expr =
+                plan_expr(&ecx, &expr)?.cast_to(&ecx, CastContext::Assignment, return_type)?;
+            return Ok(SqlFunctionBody::Scalar(expr));
+        }
+    }
+
+    let mut body = body.clone();
+    transform_ast::transform(scx, &mut body)?;
+    let (expr, scope) = plan_nested_query(&mut qcx, &body)?;
+    if scope.len() != 1 {
+        sql_bail!(
+            "function body must return exactly one column, but returns {}",
+            scope.len()
+        );
+    }
+    let relation_type = qcx.relation_type(&expr);
+    let ecx = ExprContext {
+        qcx: &qcx,
+        name: "function body",
+        scope: &scope,
+        relation_type: &relation_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let cast = typeconv::plan_cast(
+        &ecx,
+        CastContext::Assignment,
+        HirScalarExpr::column(0),
+        return_type,
+    )?;
+    Ok(SqlFunctionBody::Relation(
+        expr.map(vec![cast]).project(vec![1]),
+    ))
+}
+
+/// Returns the expression of a function body that consists of nothing but
+/// `SELECT <expr>`, where `<expr>` contains no subqueries, aggregates, window
+/// functions, or table functions.
+fn scalar_function_body<'a>(scx: &StatementContext, body: &'a Query<Aug>) -> Option<&'a Expr<Aug>> {
+    let Query {
+        ctes,
+        body: SetExpr::Select(select),
+        order_by,
+        limit: None,
+        offset: None,
+    } = body
+    else {
+        return None;
+    };
+    let Select {
+        distinct: None,
+        projection,
+        from,
+        selection: None,
+        group_by,
+        having: None,
+        qualify: None,
+        options,
+    } = &**select
+    else {
+        return None;
+    };
+    if !ctes.is_empty()
+        || !order_by.is_empty()
+        || !from.is_empty()
+        || !group_by.is_empty()
+        || !options.is_empty()
+    {
+        return None;
+    }
+    let [SelectItem::Expr { expr, .. }] = projection.as_slice() else {
+        return None;
+    };
+
+    struct ScalarVisitor<'a, 'b> {
+        scx: &'a StatementContext<'b>,
+        scalar: bool,
+    }
+
+    impl<'a, 'b, 'ast> Visit<'ast, Aug> for ScalarVisitor<'a, 'b> {
+        fn visit_query(&mut self, _: &'ast Query<Aug>) {
+            self.scalar = false;
+        }
+
+        fn visit_function(&mut self, func: &'ast Function<Aug>) {
+            if func.over.is_some() {
+                self.scalar = false;
+            } else if let Ok(item) = self.scx.get_item_by_resolved_name(&func.name) {
+                let scalar = match item.func() {
+                    Ok(f) => matches!(f, Func::Scalar(_)),
+                    Err(_) => item.sql_function().is_some_and(|f| !f.returns_set),
+                };
+                self.scalar &= scalar;
+            }
+            visit::visit_function(self, func);
+        }
+    }
+
+    let mut visitor = ScalarVisitor { scx, scalar: true };
+    visitor.visit_expr(expr);
+    visitor.scalar.then_some(expr)
+}
+
 fn plan_is_expr<'a>(
     ecx: &ExprContext,
     expr: &'a Expr<Aug>,
@@ -6293,6 +7743,16 @@ impl<'a> AggregateTableFuncVisitor<'a> {
     }
 }
 
+/// Reports whether the catalog item is a function that returns a set of rows,
+/// either a built-in table function or a SQL-language function declared with
+/// `RETURNS SETOF`.
+fn is_table_function<I: CatalogItem + ?Sized>(item: &I) -> bool {
+    match item.func() {
+        Ok(func) => matches!(func, Func::Table { .. }),
+        Err(_) => item.sql_function().is_some_and(|f| f.returns_set),
+    }
+}
+
 impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
     fn visit_function_mut(&mut self, func: &mut Function<Aug>) {
         let item = match self.scx.get_item_by_resolved_name(&func.name) {
@@ -6302,6 +7762,11 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
         };
 
         match item.func() {
+            _ if is_table_function(&*item) => {
+                self.table_disallowed_context.push("other table functions");
+                visit_mut::visit_function_mut(self, func);
+                self.table_disallowed_context.pop();
+            }
             // We don't want to collect window aggregations, because these will be handled not by
             // plan_aggregate, but by plan_function.
             Ok(Func::Aggregate { .. }) if func.over.is_none() => {
@@ -6330,11 +7795,6 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 self.within_aggregate = old_within_aggregate;
                 self.table_disallowed_context.pop();
             }
-            Ok(Func::Table { .. }) => {
-                self.table_disallowed_context.push("other table functions");
-                visit_mut::visit_function_mut(self, func);
-                self.table_disallowed_context.pop();
-            }
             _ => visit_mut::visit_function_mut(self, func),
         }
     }
@@ -6355,7 +7815,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 // and save the table func so it can be planned elsewhere.
                 let mut table_func = None;
                 if let Ok(item) = self.scx.get_item_by_resolved_name(&func.name) {
-                    if let Ok(Func::Table { .. }) = item.func() {
+                    if is_table_function(&*item) {
                         if let Some(context) = self.table_disallowed_context.last() {
                             self.err = Some(sql_err!(
                                 "table functions are not allowed in {} (function {})",
@@ -35,21 +35,26 @@ use std::sync::LazyLock;
 use enum_kinds::EnumKind;
 use itertools::Itertools;
 use mz_ore::cast::ReinterpretCast;
-use mz_ore::collections::CollectionExt;
 use mz_ore::result::ResultExt;
+use mz_ore::str::StrExt;
 use mz_repr::SqlRelationType;
-use mz_repr::{Datum, RelationDesc, RowArena, SqlColumnType, SqlScalarType};
-use mz_sql_parser::ast::{CteBlock, Expr, Function, FunctionArgs, Select, SelectItem, SetExpr};
+use mz_repr::{CatalogItemId, Datum, RelationDesc, RowArena, SqlColumnType, SqlScalarType};
+use mz_sql_parser::ast::{
+    CteBlock, Expr, Function, FunctionArgs, Select, SelectItem, SetExpr, Value,
+};
 
 use crate::ast::{Query, SelectStatement};
+use crate::catalog::{CatalogItem, CatalogItemType};
 use crate::func::Func;
 use crate::names::Aug;
+use crate::normalize;
 use crate::plan::query::{self, ExprContext, QueryLifetime};
 use crate::plan::scope::Scope;
 use crate::plan::statement::StatementContext;
 use crate::plan::typeconv::CastContext;
 use crate::plan::{HirScalarExpr, Params};
 use crate::plan::{PlanError, QueryContext};
+use crate::session::vars;
 
 /// A side-effecting function is a function whose evaluation triggers side
 /// effects.
@@ -63,6 +68,26 @@ pub enum SideEffectingFunc {
         // The ID of the connection to cancel.
         connection_id: u32,
     },
+    /// The `nextval` function.
+    SequenceNextval {
+        // The ID of the sequence to advance.
+        id: CatalogItemId,
+    },
+    /// The `currval` function.
+    SequenceCurrval {
+        // The ID of the sequence to read.
+        id: CatalogItemId,
+    },
+    /// The `setval` function.
+    SequenceSetval {
+        // The ID of the sequence to set.
+        id: CatalogItemId,
+        // The value to set the sequence to.
+        value: i64,
+        // Whether `value` has already been handed out, in which case `nextval`
+        // continues after it rather than returning it.
+        is_called: bool,
+    },
 }
 
 /// Describes a `SELECT` if it contains calls to side-effecting functions.
@@ -113,7 +138,7 @@ pub fn plan_select_if_side_effecting(
         datums.push(datum);
     }
 
-    let func = (sef_call.imp.plan_fn)(&datums);
+    let func = (sef_call.imp.plan_fn)(scx, &datums)?;
 
     Ok(Some(func))
 }
@@ -178,33 +203,29 @@ fn extract_sef_call(
         return Ok(None);
     }
 
-    // Check if the called function is a scalar function with exactly one
-    // implementation. All side-effecting functions have only a single
-    // implementation.
+    // Check if the called function is a scalar function. Side-effecting
+    // functions only overload on the number of arguments.
     let Ok(func) = scx
         .get_item_by_resolved_name(name)
         .and_then(|item| item.func().err_into())
     else {
         return Ok(None);
     };
-    let func_impl = match func {
-        Func::Scalar(impls) if impls.len() == 1 => impls.into_element(),
-        _ => return Ok(None),
-    };
-
-    // Check whether the implementation is a known side-effecting function.
-    let Some(sef_impl) = PG_CATALOG_SEF_BUILTINS.get(&func_impl.oid) else {
+    let Func::Scalar(impls) = func else {
         return Ok(None);
     };
 
-    // Check that the number of provided arguments matches the function
-    // signature.
-    if args.len() != sef_impl.param_types.len() {
-        // We return `Ok(None)` instead of an error for the same reason to let
-        // the function selection code produce the standard "no function matches
-        // the given name and argument types" error.
+    // Check whether an implementation with a matching number of arguments is a
+    // known side-effecting function. If none is, we return `Ok(None)` instead
+    // of an error to let the function selection code produce the standard "no
+    // function matches the given name and argument types" error.
+    let Some(sef_impl) = impls
+        .iter()
+        .filter_map(|func_impl| PG_CATALOG_SEF_BUILTINS.get(&func_impl.oid))
+        .find(|sef_impl| sef_impl.param_types.len() == args.len())
+    else {
         return Ok(None);
-    }
+    };
 
     // Plan and coerce all argument expressions.
     let mut args_out = vec![];
@@ -266,17 +287,23 @@ pub struct SideEffectingFuncImpl {
     pub return_type: SqlColumnType,
     /// A function that will produce a `SideEffectingFunc` given arguments
     /// that have been evaluated to `Datum`s.
-    pub plan_fn: fn(&[Datum]) -> SideEffectingFunc,
+    pub plan_fn: fn(&StatementContext, &[Datum]) -> Result<SideEffectingFunc, PlanError>,
 }
 
 /// A map of the side-effecting functions in the `pg_catalog` schema, keyed by
 /// OID.
 pub static PG_CATALOG_SEF_BUILTINS: LazyLock<BTreeMap<u32, SideEffectingFuncImpl>> =
     LazyLock::new(|| {
-        [PG_CANCEL_BACKEND]
-            .into_iter()
-            .map(|f| (f.oid, f))
-            .collect()
+        [
+            PG_CANCEL_BACKEND,
+            NEXTVAL,
+            CURRVAL,
+            SETVAL,
+            SETVAL_IS_CALLED,
+        ]
+        .into_iter()
+        .map(|f| (f.oid, f))
+        .collect()
     });
 
 // Implementations of each side-effecting function follow.
@@ -288,9 +315,154 @@ const PG_CANCEL_BACKEND: SideEffectingFuncImpl = SideEffectingFuncImpl {
     oid: 2171,
     param_types: &[SqlScalarType::Int32],
     return_type: SqlScalarType::Bool.nullable(false),
-    plan_fn: |datums| -> SideEffectingFunc {
-        SideEffectingFunc::PgCancelBackend {
+    plan_fn: |_scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        Ok(SideEffectingFunc::PgCancelBackend {
             connection_id: u32::reinterpret_cast(datums[0].unwrap_int32()),
+        })
+    },
+};
+
+/// The OID of the `nextval` function.
+pub const NEXTVAL_OID: u32 = 1574;
+
+const NEXTVAL: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "nextval",
+    oid: NEXTVAL_OID,
+    param_types: &[SqlScalarType::String],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        let sequence = sequence_arg(scx, "nextval", datums)?;
+        Ok(SideEffectingFunc::SequenceNextval { id: sequence.id() })
+    },
+};
+
+const CURRVAL: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "currval",
+    oid: 1575,
+    param_types: &[SqlScalarType::String],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        let sequence = sequence_arg(scx, "currval", datums)?;
+        Ok(SideEffectingFunc::SequenceCurrval { id: sequence.id() })
+    },
+};
+
+const SETVAL: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "setval",
+    oid: 1576,
+    param_types: &[SqlScalarType::String, SqlScalarType::Int64],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        plan_setval(scx, datums, true)
+    },
+};
+
+const SETVAL_IS_CALLED: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "setval",
+    oid: 1765,
+    param_types: &[
+        SqlScalarType::String,
+        SqlScalarType::Int64,
+        SqlScalarType::Bool,
+    ],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        if datums[2].is_null() {
+            sql_bail!("setval does not accept NULL arguments");
         }
+        plan_setval(scx, datums, datums[2].unwrap_bool())
     },
 };
+
+fn plan_setval(
+    scx: &StatementContext,
+    datums: &[Datum],
+    is_called: bool,
+) -> Result<SideEffectingFunc, PlanError> {
+    let sequence = sequence_arg(scx, "setval", datums)?;
+    if datums[1].is_null() {
+        sql_bail!("setval does not accept NULL arguments");
+    }
+    let value = datums[1].unwrap_int64();
+    let options = sequence
+        .sequence_options()
+        .expect("sequence_arg only returns sequences");
+    if value < options.min_value || value > options.max_value {
+        sql_bail!(
+            "setval: value {} is out of bounds for sequence {} ({}..{})",
+            value,
+            scx.catalog
+                .resolve_full_name(sequence.name())
+                .to_string()
+                .quoted(),
+            options.min_value,
+            options.max_value
+        );
+    }
+    Ok(SideEffectingFunc::SequenceSetval {
+        id: sequence.id(),
+        value,
+        is_called,
+    })
+}
+
+/// Resolves the sequence named by the first argument of a sequence function.
+fn sequence_arg<'a>(
+    scx: &'a StatementContext,
+    func_name: &str,
+    datums: &[Datum],
+) -> Result<&'a dyn CatalogItem, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_SEQUENCES)?;
+    if datums[0].is_null() {
+        sql_bail!("{func_name} does not accept NULL arguments");
+    }
+    resolve_sequence(scx, datums[0].unwrap_str())
+}
+
+/// Resolves `name`, which is written like a possibly qualified SQL identifier,
+/// to a sequence.
+pub(crate) fn resolve_sequence<'a>(
+    scx: &'a StatementContext,
+    name: &str,
+) -> Result<&'a dyn CatalogItem, PlanError> {
+    let name = normalize::unresolved_item_name(mz_sql_parser::parser::parse_item_name(name)?)?;
+    let item = scx.catalog.resolve_item(&name)?;
+    if item.item_type() != CatalogItemType::Sequence {
+        sql_bail!(
+            "{} is not a sequence",
+            scx.catalog
+                .resolve_full_name(item.name())
+                .to_string()
+                .quoted()
+        );
+    }
+    Ok(item)
+}
+
+/// If `expr`, the default value of a table column, draws values from a
+/// sequence, returns the name of that sequence.
+///
+/// Such defaults must consist of a single call to `nextval` with a string
+/// literal argument. They are evaluated separately for every inserted row.
+pub(crate) fn sequence_default<'a>(scx: &StatementContext, expr: &'a Expr<Aug>) -> Option<&'a str> {
+    let Expr::Function(Function {
+        name,
+        args: FunctionArgs::Args { args, order_by },
+        filter: None,
+        over: None,
+        distinct: false,
+    }) = expr
+    else {
+        return None;
+    };
+    let [Expr::Value(Value::String(sequence))] = &args[..] else {
+        return None;
+    };
+    if !order_by.is_empty() {
+        return None;
+    }
+    match scx.get_item_by_resolved_name(name).map(|item| item.func()) {
+        Ok(Ok(Func::Scalar(impls))) if impls.iter().any(|i| i.oid == NEXTVAL_OID) => Some(sequence),
+        _ => None,
+    }
+}
@@ -45,6 +45,7 @@ use crate::session::vars::FeatureFlag;
 mod acl;
 pub(crate) mod ddl;
 mod dml;
+pub(crate) mod notify;
 mod raise;
 mod scl;
 pub(crate) mod show;
@@ -132,6 +133,9 @@ pub fn describe(
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
         Statement::AlterObjectSwap(stmt) => ddl::describe_alter_object_swap(&scx, stmt)?,
         Statement::AlterRetainHistory(stmt) => ddl::describe_alter_retain_history(&scx, stmt)?,
+        Statement::AlterStorageCompression(stmt) => {
+            ddl::describe_alter_storage_compression(&scx, stmt)?
+        }
         Statement::AlterRole(stmt) => ddl::describe_alter_role(&scx, stmt)?,
         Statement::AlterSecret(stmt) => ddl::describe_alter_secret_options(&scx, stmt)?,
         Statement::AlterSetCluster(stmt) => ddl::describe_alter_set_cluster(&scx, stmt)?,
@@ -141,6 +145,12 @@ pub fn describe(
         Statement::AlterSystemReset(stmt) => ddl::describe_alter_system_reset(&scx, stmt)?,
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
         Statement::AlterTableAddColumn(stmt) => ddl::describe_alter_table_add_column(&scx, stmt)?,
+        Statement::AlterTableAddConstraint(stmt) => {
+            ddl::describe_alter_table_add_constraint(&scx, stmt)?
+        }
+        Statement::AlterTableDropConstraint(stmt) => {
+            ddl::describe_alter_table_drop_constraint(&scx, stmt)?
+        }
         Statement::AlterNetworkPolicy(stmt) => ddl::describe_alter_network_policy(&scx, stmt)?,
         Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
         Statement::CreateCluster(stmt) => ddl::describe_create_cluster(&scx, stmt)?,
@@ -160,6 +170,8 @@ pub fn describe(
             ddl::describe_create_table_from_source(&scx, stmt)?
         }
         Statement::CreateType(stmt) => ddl::describe_create_type(&scx, stmt)?,
+        Statement::CreateFunction(stmt) => ddl::describe_create_function(&scx, stmt)?,
+        Statement::CreateSequence(stmt) => ddl::describe_create_sequence(&scx, stmt)?,
         Statement::CreateView(stmt) => ddl::describe_create_view(&scx, stmt)?,
         Statement::CreateMaterializedView(stmt) => {
             ddl::describe_create_materialized_view(&scx, stmt)?
@@ -238,9 +250,13 @@ pub fn describe(
         Statement::ExplainAnalyzeCluster(stmt) => {
             dml::describe_explain_analyze_cluster(&scx, stmt)?
         }
+        Statement::ExplainRecommendations(stmt) => {
+            dml::describe_explain_recommendations(&scx, stmt)?
+        }
         Statement::ExplainTimestamp(stmt) => dml::describe_explain_timestamp(&scx, stmt)?,
         Statement::ExplainSinkSchema(stmt) => dml::describe_explain_schema(&scx, stmt)?,
         Statement::Insert(stmt) => dml::describe_insert(&scx, stmt)?,
+        Statement::Merge(stmt) => dml::describe_merge(&scx, stmt)?,
         Statement::Select(stmt) => dml::describe_select(&scx, stmt)?,
         Statement::Subscribe(stmt) => dml::describe_subscribe(&scx, stmt)?,
         Statement::Update(stmt) => dml::describe_update(&scx, stmt)?,
@@ -253,6 +269,11 @@ pub fn describe(
 
         // Other statements.
         Statement::Raise(stmt) => raise::describe_raise(&scx, stmt)?,
+        Statement::Listen(stmt) => notify::describe_listen(&scx, stmt)?,
+        Statement::Unlisten(stmt) => notify::describe_unlisten(&scx, stmt)?,
+        Statement::Notify(stmt) => notify::describe_notify(&scx, stmt)?,
+        Statement::CreateChannel(stmt) => notify::describe_create_channel(&scx, stmt)?,
+        Statement::DropChannel(stmt) => notify::describe_drop_channel(&scx, stmt)?,
         Statement::Show(ShowStatement::InspectShard(stmt)) => {
             scl::describe_inspect_shard(&scx, stmt)?
         }
@@ -331,6 +352,7 @@ pub fn plan(
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
         Statement::AlterObjectSwap(stmt) => ddl::plan_alter_object_swap(scx, stmt),
         Statement::AlterRetainHistory(stmt) => ddl::plan_alter_retain_history(scx, stmt),
+        Statement::AlterStorageCompression(stmt) => ddl::plan_alter_storage_compression(scx, stmt),
         Statement::AlterRole(stmt) => ddl::plan_alter_role(scx, stmt),
         Statement::AlterSecret(stmt) => ddl::plan_alter_secret(scx, stmt),
         Statement::AlterSetCluster(stmt) => ddl::plan_alter_item_set_cluster(scx, stmt),
@@ -340,6 +362,10 @@ pub fn plan(
         Statement::AlterSystemReset(stmt) => ddl::plan_alter_system_reset(scx, stmt),
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
         Statement::AlterTableAddColumn(stmt) => ddl::plan_alter_table_add_column(scx, stmt),
+        Statement::AlterTableAddConstraint(stmt) => ddl::plan_alter_table_add_constraint(scx, stmt),
+        Statement::AlterTableDropConstraint(stmt) => {
+            ddl::plan_alter_table_drop_constraint(scx, stmt)
+        }
         Statement::AlterNetworkPolicy(stmt) => ddl::plan_alter_network_policy(scx, stmt),
         Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
         Statement::CreateCluster(stmt) => ddl::plan_create_cluster(scx, stmt),
@@ -357,6 +383,8 @@ pub fn plan(
         Statement::CreateTable(stmt) => ddl::plan_create_table(scx, stmt),
         Statement::CreateTableFromSource(stmt) => ddl::plan_create_table_from_source(scx, stmt),
         Statement::CreateType(stmt) => ddl::plan_create_type(scx, stmt),
+        Statement::CreateFunction(stmt) => ddl::plan_create_function(scx, stmt),
+        Statement::CreateSequence(stmt) => ddl::plan_create_sequence(scx, stmt),
         Statement::CreateView(stmt) => ddl::plan_create_view(scx, stmt),
         Statement::CreateMaterializedView(stmt) => ddl::plan_create_materialized_view(scx, stmt),
         Statement::CreateContinualTask(stmt) => ddl::plan_create_continual_task(scx, stmt),
@@ -384,9 +412,11 @@ pub fn plan(
         Statement::ExplainAnalyzeCluster(stmt) => {
             dml::plan_explain_analyze_cluster(scx, stmt, params)
         }
+        Statement::ExplainRecommendations(stmt) => dml::plan_explain_recommendations(scx, stmt),
         Statement::ExplainTimestamp(stmt) => dml::plan_explain_timestamp(scx, stmt),
         Statement::ExplainSinkSchema(stmt) => dml::plan_explain_schema(scx, stmt),
         Statement::Insert(stmt) => dml::plan_insert(scx, stmt, params),
+        Statement::Merge(stmt) => dml::plan_merge(scx, stmt, params),
         Statement::Select(stmt) => dml::plan_select(scx, stmt, params, None),
         Statement::Subscribe(stmt) => dml::plan_subscribe(scx, stmt, params, None),
         Statement::Update(stmt) => dml::plan_update(scx, stmt, params),
@@ -442,6 +472,11 @@ pub fn plan(
 
         // Other statements.
         Statement::Raise(stmt) => raise::plan_raise(scx, stmt),
+        Statement::Listen(stmt) => notify::plan_listen(scx, stmt),
+        Statement::Unlisten(stmt) => notify::plan_unlisten(scx, stmt),
+        Statement::Notify(stmt) => notify::plan_notify(scx, stmt),
+        Statement::CreateChannel(stmt) => notify::plan_create_channel(scx, stmt),
+        Statement::DropChannel(stmt) => notify::plan_drop_channel(scx, stmt),
         Statement::Show(ShowStatement::InspectShard(stmt)) => scl::plan_inspect_shard(scx, stmt),
         Statement::ValidateConnection(stmt) => validate::plan_validate_connection(scx, stmt),
     };
@@ -465,7 +500,7 @@ pub fn plan_copy_from(
     target_name: String,
     columns: Vec<ColumnIndex>,
     rows: Vec<mz_repr::Row>,
-) -> Result<super::HirRelationExpr, PlanError> {
+) -> Result<(super::HirRelationExpr, Vec<(usize, CatalogItemId)>), PlanError> {
     query::plan_copy_from_rows(pcx, catalog, target_id, target_name, columns, rows)
 }
 
@@ -486,7 +521,8 @@ impl PartialEq<ObjectType> for CatalogItemType {
             | (CatalogItemType::Index, ObjectType::Index)
             | (CatalogItemType::Type, ObjectType::Type)
             | (CatalogItemType::Secret, ObjectType::Secret)
-            | (CatalogItemType::Connection, ObjectType::Connection) => true,
+            | (CatalogItemType::Connection, ObjectType::Connection)
+            | (CatalogItemType::Sequence, ObjectType::Sequence) => true,
             (_, _) => false,
         }
     }
@@ -1056,6 +1092,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterObjectSwap(_) => DDL,
             Statement::AlterNetworkPolicy(_) => DDL,
             Statement::AlterRetainHistory(_) => DDL,
+            Statement::AlterStorageCompression(_) => DDL,
             Statement::AlterRole(_) => DDL,
             Statement::AlterSecret(_) => DDL,
             Statement::AlterSetCluster(_) => DDL,
@@ -1065,6 +1102,8 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterSystemReset(_) => DDL,
             Statement::AlterSystemResetAll(_) => DDL,
             Statement::AlterTableAddColumn(_) => DDL,
+            Statement::AlterTableAddConstraint(_) => DDL,
+            Statement::AlterTableDropConstraint(_) => DDL,
             Statement::Comment(_) => DDL,
             Statement::CreateCluster(_) => DDL,
             Statement::CreateClusterReplica(_) => DDL,
@@ -1082,6 +1121,8 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::CreateTable(_) => DDL,
             Statement::CreateTableFromSource(_) => DDL,
             Statement::CreateType(_) => DDL,
+            Statement::CreateFunction(_) => DDL,
+            Statement::CreateSequence(_) => DDL,
             Statement::CreateView(_) => DDL,
             Statement::CreateMaterializedView(_) => DDL,
             Statement::CreateNetworkPolicy(_) => DDL,
@@ -1104,9 +1145,11 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::ExplainPushdown(_) => DML,
             Statement::ExplainAnalyzeObject(_) => DML,
             Statement::ExplainAnalyzeCluster(_) => DML,
+            Statement::ExplainRecommendations(_) => DML,
             Statement::ExplainTimestamp(_) => DML,
             Statement::ExplainSinkSchema(_) => DML,
             Statement::Insert(_) => DML,
+            Statement::Merge(_) => DML,
             Statement::Select(_) => DML,
             Statement::Subscribe(_) => DML,
             Statement::Update(_) => DML,
@@ -1144,6 +1187,11 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
 
             // Other statements.
             Statement::Raise(_) => Other,
+            Statement::Listen(_) => Other,
+            Statement::Unlisten(_) => Other,
+            Statement::Notify(_) => Other,
+            Statement::CreateChannel(_) => Other,
+            Statement::DropChannel(_) => Other,
             Statement::Show(ShowStatement::InspectShard(_)) => Other,
             Statement::ValidateConnection(_) => Other,
         }
@@ -620,6 +620,7 @@ fn privilege_to_acl_mode(privilege: Privilege) -> AclMode {
         Privilege::INSERT => AclMode::INSERT,
         Privilege::UPDATE => AclMode::UPDATE,
         Privilege::DELETE => AclMode::DELETE,
+        Privilege::EXECUTE => AclMode::EXECUTE,
         Privilege::USAGE => AclMode::USAGE,
         Privilege::CREATE => AclMode::CREATE,
         Privilege::CREATEROLE => AclMode::CREATE_ROLE,
@@ -680,7 +681,8 @@ pub fn plan_alter_default_privileges(
         | ObjectType::Cluster
         | ObjectType::Database
         | ObjectType::Schema
-        | ObjectType::NetworkPolicy => {}
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence => {}
     }
 
     let acl_mode = privilege_spec_to_acl_mode(
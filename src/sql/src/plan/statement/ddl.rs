@@ -12,6 +12,7 @@
 //! This module houses the handlers for statements that modify the catalog, like
 //! `ALTER`, `CREATE`, and `DROP`.
 
+use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::iter;
@@ -23,6 +24,7 @@ use mz_adapter_types::compaction::{CompactionWindow, DEFAULT_LOGICAL_COMPACTION_
 use mz_adapter_types::dyncfgs::ENABLE_MULTI_REPLICA_SOURCES;
 use mz_auth::password::Password;
 use mz_controller_types::{ClusterId, DEFAULT_REPLICA_LOGGING_INTERVAL, ReplicaId};
+use mz_expr::func::{WasmCall, WasmModule, wasm_supports_type};
 use mz_expr::{CollectionPlan, UnmaterializableFunc};
 use mz_interchange::avro::{AvroSchemaGenerator, DocTarget};
 use mz_ore::cast::{CastFrom, TryCastFrom};
@@ -30,9 +32,11 @@ use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::num::NonNeg;
 use mz_ore::soft_panic_or_log;
 use mz_ore::str::StrExt;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::bytes::ByteSize;
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::optimize::OptimizerFeatureOverrides;
 use mz_repr::refresh_schedule::{RefreshEvery, RefreshSchedule};
@@ -42,6 +46,7 @@ use mz_repr::{
     SqlColumnType, SqlRelationType, SqlScalarType, Timestamp, VersionedRelationDesc,
     preserves_order, strconv,
 };
+use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     self, AlterClusterAction, AlterClusterStatement, AlterConnectionAction, AlterConnectionOption,
     AlterConnectionOptionName, AlterConnectionStatement, AlterIndexAction, AlterIndexStatement,
@@ -49,34 +54,37 @@ use mz_sql_parser::ast::{
     AlterObjectRenameStatement, AlterObjectSwapStatement, AlterRetainHistoryStatement,
     AlterRoleOption, AlterRoleStatement, AlterSecretStatement, AlterSetClusterStatement,
     AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
-    AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, AlterTableAddColumnStatement, AvroSchema,
-    AvroSchemaOption, AvroSchemaOptionName, ClusterAlterOption, ClusterAlterOptionName,
-    ClusterAlterOptionValue, ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName,
-    ClusterFeature, ClusterFeatureName, ClusterOption, ClusterOptionName,
-    ClusterScheduleOptionValue, ColumnDef, ColumnOption, CommentObjectType, CommentStatement,
-    ConnectionOption, ConnectionOptionName, ContinualTaskOption, ContinualTaskOptionName,
-    CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionOption,
-    CreateConnectionOptionName, CreateConnectionStatement, CreateConnectionType,
-    CreateContinualTaskStatement, CreateDatabaseStatement, CreateIndexStatement,
+    AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterStorageCompressionStatement,
+    AlterSystemResetAllStatement, AlterSystemResetStatement, AlterSystemSetStatement,
+    AlterTableAddColumnStatement, AlterTableAddConstraintStatement,
+    AlterTableDropConstraintStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName,
+    ClusterAlterOption, ClusterAlterOptionName, ClusterAlterOptionValue,
+    ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName, ClusterFeature,
+    ClusterFeatureName, ClusterOption, ClusterOptionName, ClusterScheduleOptionValue, ColumnDef,
+    ColumnOption, CommentObjectType, CommentStatement, ConnectionOption, ConnectionOptionName,
+    ContinualTaskOption, ContinualTaskOptionName, CreateClusterReplicaStatement,
+    CreateClusterStatement, CreateConnectionOption, CreateConnectionOptionName,
+    CreateConnectionStatement, CreateConnectionType, CreateContinualTaskStatement,
+    CreateDatabaseStatement, CreateFunctionStatement, CreateIndexStatement,
     CreateMaterializedViewStatement, CreateNetworkPolicyStatement, CreateRoleStatement,
-    CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection, CreateSinkOption,
-    CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection, CreateSourceOption,
-    CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
+    CreateSchemaStatement, CreateSecretStatement, CreateSequenceStatement, CreateSinkConnection,
+    CreateSinkOption, CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection,
+    CreateSourceOption, CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
     CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableFromSourceStatement,
     CreateTableStatement, CreateTypeAs, CreateTypeListOption, CreateTypeListOptionName,
     CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement, CreateViewStatement,
     CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
     CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
     DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement, Expr, Format,
-    FormatSpecifier, IcebergSinkConfigOption, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption,
-    LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
-    MySqlConfigOptionName, NetworkPolicyOption, NetworkPolicyOptionName,
-    NetworkPolicyRuleDefinition, NetworkPolicyRuleOption, NetworkPolicyRuleOptionName,
-    PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica, RefreshAtOptionValue,
-    RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition, ReplicaOption,
-    ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata,
+    FormatSpecifier, FunctionBody, FunctionOption, FunctionOptionName, FunctionParameter,
+    IcebergSinkConfigOption, Ident, IfExistsBehavior, IndexOption, IndexOptionName,
+    KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption, LoadGeneratorOptionName,
+    MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName,
+    NetworkPolicyOption, NetworkPolicyOptionName, NetworkPolicyRuleDefinition,
+    NetworkPolicyRuleOption, NetworkPolicyRuleOptionName, PgConfigOption, PgConfigOptionName,
+    ProtobufSchema, QualifiedReplica, RefreshAtOptionValue, RefreshEveryOptionValue,
+    RefreshOptionValue, ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute,
+    SequenceOption, SequenceOptionName, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata,
     SqlServerConfigOption, SqlServerConfigOptionName, Statement, TableConstraint,
     TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName, TableOption,
     TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
@@ -130,9 +138,10 @@ use crate::catalog::{
 use crate::iceberg::IcebergSinkConfigOptionExtracted;
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
-    Aug, CommentObjectId, DatabaseId, DependencyIds, ObjectId, PartialItemName, QualifiedItemName,
-    ResolvedClusterName, ResolvedColumnReference, ResolvedDataType, ResolvedDatabaseSpecifier,
-    ResolvedItemName, ResolvedNetworkPolicyName, SchemaSpecifier, SystemObjectId,
+    Aug, CommentObjectId, DatabaseId, DependencyIds, FullItemName, ObjectId, PartialItemName,
+    QualifiedItemName, ResolvedClusterName, ResolvedColumnReference, ResolvedDataType,
+    ResolvedDatabaseSpecifier, ResolvedItemName, ResolvedNetworkPolicyName, SchemaSpecifier,
+    SystemObjectId,
 };
 use crate::normalize::{self, ident};
 use crate::plan::error::PlanError;
@@ -151,19 +160,22 @@ use crate::plan::{
     AlterMaterializedViewApplyReplacementPlan, AlterNetworkPolicyPlan, AlterNoopPlan,
     AlterOptionParameter, AlterRetainHistoryPlan, AlterRolePlan, AlterSchemaRenamePlan,
     AlterSchemaSwapPlan, AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan,
-    AlterSourceTimestampIntervalPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, AlterTablePlan, ClusterSchedule, CommentPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, ConnectionDetails, CreateClusterManagedPlan,
-    CreateClusterPlan, CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
-    CreateConnectionPlan, CreateContinualTaskPlan, CreateDatabasePlan, CreateIndexPlan,
+    AlterSourceTimestampIntervalPlan, AlterStorageCompressionPlan, AlterSystemResetAllPlan,
+    AlterSystemResetPlan, AlterSystemSetPlan, AlterTableAddConstraintPlan,
+    AlterTableDropConstraintPlan, AlterTablePlan, ClusterSchedule, CommentPlan,
+    ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
+    CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan,
+    CreateContinualTaskPlan, CreateDatabasePlan, CreateFunctionPlan, CreateIndexPlan,
     CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateRolePlan, CreateSchemaPlan,
-    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
-    CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan, HirRelationExpr, Index,
-    MaterializedView, NetworkPolicyRule, NetworkPolicyRuleAction, NetworkPolicyRuleDirection, Plan,
-    PlanClusterOption, PlanNotice, PolicyAddress, QueryContext, ReplicaConfig, Secret, Sink,
-    Source, Table, TableDataSource, Type, VariableValue, View, WebhookBodyFormat,
-    WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal, plan_utils, query,
-    transform_ast,
+    CreateSecretPlan, CreateSequencePlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan,
+    HirRelationExpr, HirScalarExpr, Index, IndexStorage, MaterializedView, NetworkPolicyRule,
+    NetworkPolicyRuleAction, NetworkPolicyRuleDirection, Plan, PlanClusterOption, PlanNotice,
+    PolicyAddress, QueryContext, ReplicaConfig, Secret, Sequence, SequenceOptions, Sink, Source,
+    SqlFunction, SqlFunctionBody, Table, TableCheck, TableDataSource, TableUnique, Type,
+    VariableValue, View, WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders,
+    WebhookValidation, literal, plan_utils, query, side_effecting_func, transform_ast,
 };
 use crate::session::vars::{
     self, ENABLE_CLUSTER_SCHEDULE_REFRESH, ENABLE_COLLECTION_PARTITION_BY,
@@ -281,8 +293,11 @@ pub fn describe_create_table(
 
 pub fn plan_create_table(
     scx: &StatementContext,
-    stmt: CreateTableStatement<Aug>,
+    mut stmt: CreateTableStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    let sequences = plan_identity_columns(scx, &mut stmt)?;
+    name_check_constraints(&mut stmt)?;
+
     let CreateTableStatement {
         name,
         columns,
@@ -317,6 +332,7 @@ pub fn plan_create_table(
     let mut defaults = Vec::with_capacity(columns.len());
     let mut changes = BTreeMap::new();
     let mut keys = Vec::new();
+    let mut uniques = Vec::new();
 
     for (i, c) in columns.into_iter().enumerate() {
         let aug_data_type = &c.data_type;
@@ -332,11 +348,20 @@ pub fn plan_create_table(
                     // type.
                     let mut expr = expr.clone();
                     transform_ast::transform(scx, &mut expr)?;
-                    let _ = query::plan_default_expr(scx, &expr, &ty)?;
+                    // Sequence defaults are instead checked when rows are
+                    // inserted, as the sequence of an identity column does
+                    // not exist yet when its table is planned.
+                    if side_effecting_func::sequence_default(scx, &expr).is_none() {
+                        let _ = query::plan_default_expr(scx, &expr, &ty)?;
+                    }
                     default = expr.clone();
                 }
                 ColumnOption::Unique { is_primary } => {
                     keys.push(vec![i]);
+                    uniques.push(TableUnique {
+                        columns: vec![i],
+                        nulls_distinct: true,
+                    });
                     if *is_primary {
                         nullable = false;
                     }
@@ -363,8 +388,17 @@ pub fn plan_create_table(
         defaults.push(default);
     }
 
+    let mut checks = vec![];
+    for constraint in constraints {
+        if let TableConstraint::Check { name, expr } = constraint {
+            scx.require_feature_flag(&vars::ENABLE_TABLE_CHECK_CONSTRAINTS)?;
+            let name = name.clone().expect("named by name_check_constraints");
+            checks.push((normalize::ident(name), (**expr).clone()));
+        }
+    }
+
     let mut seen_primary = false;
-    'c: for constraint in constraints {
+    for constraint in constraints {
         match constraint {
             TableConstraint::Unique {
                 name: _,
@@ -381,6 +415,11 @@ pub fn plan_create_table(
                 seen_primary = *is_primary || seen_primary;
 
                 let mut key = vec![];
+                // Non-primary key unique constraints are only keys if all of
+                // their columns are `NOT NULL` or the constraint is `NULLS NOT
+                // DISTINCT`. Otherwise they are only enforced on rows without
+                // `NULL`s.
+                let mut is_key = true;
                 for column in columns {
                     let column = normalize::column_name(column.clone());
                     match names.iter().position(|name| *name == column) {
@@ -396,9 +435,7 @@ pub fn plan_create_table(
 
                                 *nullable = false;
                             } else if !(*nulls_not_distinct || !*nullable) {
-                                // Non-primary key unique constraints are only keys if all of their
-                                // columns are `NOT NULL` or the constraint is `NULLS NOT DISTINCT`.
-                                break 'c;
+                                is_key = false;
                             }
 
                             key.push(i);
@@ -406,6 +443,13 @@ pub fn plan_create_table(
                     }
                 }
 
+                uniques.push(TableUnique {
+                    columns: key.clone(),
+                    nulls_distinct: !*nulls_not_distinct,
+                });
+                if !is_key {
+                    continue;
+                }
                 if *is_primary {
                     keys.insert(0, key);
                 } else {
@@ -418,9 +462,7 @@ pub fn plan_create_table(
                 scx.require_feature_flag(&vars::UNSAFE_ENABLE_TABLE_FOREIGN_KEY)?
             }
             TableConstraint::Check { .. } => {
-                // Check constraints are not presently enforced. We allow them
-                // with feature flags for sqllogictest's sake.
-                scx.require_feature_flag(&vars::UNSAFE_ENABLE_TABLE_CHECK_CONSTRAINT)?
+                // Planned above.
             }
         }
     }
@@ -466,6 +508,16 @@ pub fn plan_create_table(
         }
     }
 
+    // Check constraints may refer to any column of the table, including those
+    // that were added at later versions.
+    let (check_names, check_exprs): (Vec<_>, Vec<_>) = checks.into_iter().unzip();
+    let latest_desc = desc.at_version(RelationVersionSelector::Latest);
+    let checks = query::plan_check_exprs(scx, &latest_desc, check_exprs)?
+        .into_iter()
+        .zip_eq(check_names)
+        .map(|(expr, name)| TableCheck { name, expr })
+        .collect();
+
     let create_sql = normalize::create_statement(scx, Statement::CreateTable(stmt.clone()))?;
 
     // Table options should only consider the original columns, since those
@@ -477,25 +529,37 @@ pub fn plan_create_table(
     let options = plan_table_options(scx, &original_desc, with_options.clone())?;
 
     let compaction_window = options.iter().find_map(|o| {
-        #[allow(irrefutable_let_patterns)]
         if let crate::plan::TableOption::RetainHistory(lcw) = o {
             Some(lcw.clone())
         } else {
             None
         }
     });
+    let storage_compression = options.iter().find_map(|o| {
+        if let crate::plan::TableOption::StorageCompression(compression) = o {
+            Some(*compression)
+        } else {
+            None
+        }
+    });
 
     let table = Table {
         create_sql,
         desc,
         temporary,
         compaction_window,
-        data_source: TableDataSource::TableWrites { defaults },
+        storage_compression,
+        data_source: TableDataSource::TableWrites {
+            defaults,
+            checks,
+            uniques,
+        },
     };
     Ok(Plan::CreateTable(CreateTablePlan {
         name,
         table,
         if_not_exists: *if_not_exists,
+        sequences,
     }))
 }
 
@@ -725,8 +789,10 @@ pub fn plan_create_webhook_source(
                 desc: VersionedRelationDesc::new(desc),
                 temporary: false,
                 compaction_window: None,
+                storage_compression: None,
                 data_source,
             },
+            sequences: vec![],
         })
     } else {
         let data_source = DataSourceDesc::Webhook {
@@ -1990,6 +2056,7 @@ pub fn plan_create_table_from_source(
         desc: VersionedRelationDesc::new(desc),
         temporary: false,
         compaction_window,
+        storage_compression: None,
         data_source: TableDataSource::DataSource {
             desc: data_source,
             timeline,
@@ -2000,6 +2067,7 @@ pub fn plan_create_table_from_source(
         name,
         table,
         if_not_exists,
+        sequences: vec![],
     }))
 }
 
@@ -2820,8 +2888,10 @@ pub fn plan_create_materialized_view(
         partition_by,
         retain_history,
         refresh,
+        storage_compression,
         seen: _,
     }: MaterializedViewOptionExtracted = stmt.with_options.try_into()?;
+    let storage_compression = plan_storage_compression_option(scx, storage_compression)?;
 
     if let Some(partition_by) = partition_by {
         scx.require_feature_flag(&ENABLE_COLLECTION_PARTITION_BY)?;
@@ -3105,6 +3175,7 @@ pub fn plan_create_materialized_view(
             compaction_window,
             refresh_schedule,
             as_of,
+            storage_compression,
         },
         replace,
         drop_ids,
@@ -3118,7 +3189,8 @@ generate_extracted_config!(
     (AssertNotNull, Ident, AllowMultiple),
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
-    (Refresh, RefreshOptionValue<Aug>, AllowMultiple)
+    (Refresh, RefreshOptionValue<Aug>, AllowMultiple),
+    (StorageCompression, String)
 );
 
 pub fn plan_create_continual_task(
@@ -3182,7 +3254,8 @@ pub fn plan_create_continual_task(
         | CatalogItemType::Type
         | CatalogItemType::Func
         | CatalogItemType::Secret
-        | CatalogItemType::Connection => {
+        | CatalogItemType::Connection
+        | CatalogItemType::Sequence => {
             sql_bail!(
                 "CONTINUAL TASK cannot use {} as an input",
                 input.item_type()
@@ -3356,6 +3429,7 @@ pub fn plan_create_continual_task(
             compaction_window: None,
             refresh_schedule: None,
             as_of,
+            storage_compression: None,
         },
     }))
 }
@@ -3369,9 +3443,10 @@ fn continual_task_query<'a>(
             table_name: _,
             columns,
             source,
+            on_conflict,
             returning,
         }) => {
-            if !columns.is_empty() || !returning.is_empty() {
+            if !columns.is_empty() || on_conflict.is_some() || !returning.is_empty() {
                 return None;
             }
             match source {
@@ -4444,13 +4519,15 @@ pub fn plan_create_index(
 
     let create_sql = normalize::create_statement(scx, Statement::CreateIndex(stmt))?;
     let compaction_window = options.iter().find_map(|o| {
-        #[allow(irrefutable_let_patterns)]
         if let crate::plan::IndexOption::RetainHistory(lcw) = o {
             Some(lcw.clone())
         } else {
             None
         }
     });
+    let spill_to_disk = options
+        .iter()
+        .any(|o| matches!(o, crate::plan::IndexOption::Storage(IndexStorage::Disk)));
 
     Ok(Plan::CreateIndex(CreateIndexPlan {
         name: index_name,
@@ -4460,6 +4537,7 @@ pub fn plan_create_index(
             keys,
             cluster_id,
             compaction_window,
+            spill_to_disk,
         },
         if_not_exists,
     }))
@@ -4589,6 +4667,642 @@ pub fn plan_create_type(
     }))
 }
 
+pub fn describe_create_function(
+    _: &StatementContext,
+    _: CreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_function(
+    scx: &StatementContext,
+    mut stmt: CreateFunctionStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_CREATE_FUNCTION)?;
+
+    // Record which export a WASM function calls in its definition, so that
+    // renaming the function does not change the export it calls.
+    if let FunctionBody::Wasm {
+        export: export @ None,
+        ..
+    } = &mut stmt.body
+    {
+        *export = Some(normalize::unresolved_item_name(stmt.name.clone())?.item);
+    }
+
+    // Likewise record the resource limits of a WASM function, so that changes
+    // to the system defaults do not change the limits of existing functions.
+    if matches!(stmt.body, FunctionBody::Wasm { .. }) {
+        let vars = scx.catalog.system_vars();
+        let fuel = vars.max_wasm_function_fuel().to_string();
+        let memory = ByteSize::b(vars.max_wasm_function_memory()).to_string();
+        for (name, default) in [
+            (FunctionOptionName::Fuel, Value::Number(fuel)),
+            (FunctionOptionName::Memory, Value::String(memory)),
+        ] {
+            if !stmt.with_options.iter().any(|o| o.name == name) {
+                stmt.with_options.push(FunctionOption {
+                    name,
+                    value: Some(WithOptionValue::Value(default)),
+                });
+            }
+        }
+    }
+
+    let create_sql = normalize::create_statement(scx, Statement::CreateFunction(stmt.clone()))?;
+    let CreateFunctionStatement {
+        name,
+        if_not_exists,
+        params,
+        returns_set,
+        return_type,
+        body,
+        with_options,
+    } = stmt;
+    let FunctionOptionExtracted { seen, fuel, memory } = with_options.try_into()?;
+
+    /// Returns the scalar type of a function parameter or return type, along
+    /// with the ID of the catalog type that defines it.
+    fn plan_function_type(
+        scx: &StatementContext,
+        data_type: &ResolvedDataType,
+    ) -> Result<(SqlScalarType, CatalogItemId), PlanError> {
+        let id = match data_type {
+            ResolvedDataType::Named { id, .. } => *id,
+            _ => bail_unsupported!(format!(
+                "anonymous type {} in CREATE FUNCTION",
+                data_type.human_readable_name()
+            )),
+        };
+        Ok((scalar_type_from_sql(scx, data_type)?, id))
+    }
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let (false, Ok(item)) = (if_not_exists, scx.catalog.resolve_function(&partial_name)) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    let mut param_names = BTreeMap::new();
+    let mut param_types = Vec::with_capacity(params.len());
+    let mut param_type_ids = Vec::with_capacity(params.len());
+    for (i, FunctionParameter { name, data_type }) in params.into_iter().enumerate() {
+        if let Some(name) = name {
+            let name = normalize::ident(name);
+            if param_names.insert(name.clone(), i + 1).is_some() {
+                sql_bail!("parameter name {} used more than once", name.quoted());
+            }
+        }
+        let (typ, id) = plan_function_type(scx, &data_type)?;
+        param_types.push(typ);
+        param_type_ids.push(id);
+    }
+    let (return_type, return_type_id) = plan_function_type(scx, &return_type)?;
+
+    let body = match body {
+        FunctionBody::Sql(mut body) => {
+            if let Some(option) = seen.first() {
+                sql_bail!(
+                    "{} option is only supported for WASM functions",
+                    option.to_ast_string_simple()
+                );
+            }
+
+            // Named parameters are referenced in the body like columns. Rewrite
+            // them to positional parameters, which are what calls to the
+            // function splice their arguments into. As in PostgreSQL, a
+            // parameter name shadows any column of the same name; qualify the
+            // parameter with the function name, or the column with its
+            // relation name, to disambiguate.
+            FunctionParameterRewriter {
+                function_name: &name.item,
+                params: &param_names,
+            }
+            .visit_query_mut(&mut body);
+
+            let mut body_scx = scx.clone();
+            body_scx.param_types = RefCell::new(
+                param_types
+                    .iter()
+                    .cloned()
+                    .enumerate()
+                    .map(|(i, typ)| (i + 1, typ))
+                    .collect(),
+            );
+            let body = query::plan_sql_function_body(&body_scx, &body, &return_type, returns_set)?;
+            if let Some(n) = body_scx
+                .param_types
+                .borrow()
+                .keys()
+                .find(|n| **n > param_types.len())
+            {
+                return Err(PlanError::UnknownParameter(*n));
+            }
+            body
+        }
+        FunctionBody::Wasm { module, export } => {
+            let export = export.expect("export filled in above");
+            let max_fuel = fuel.expect("fuel filled in above");
+            let max_memory_bytes = memory.expect("memory filled in above").as_bytes();
+            // Only validate the limits of new functions, as the system
+            // maximums may have been lowered since existing ones were created.
+            if scx.pcx.is_some() {
+                let vars = scx.catalog.system_vars();
+                if max_fuel > vars.max_wasm_function_fuel() {
+                    sql_bail!(
+                        "FUEL ({max_fuel}) exceeds max_wasm_function_fuel ({})",
+                        vars.max_wasm_function_fuel()
+                    );
+                }
+                if max_memory_bytes > vars.max_wasm_function_memory() {
+                    sql_bail!(
+                        "MEMORY ({max_memory_bytes} bytes) exceeds max_wasm_function_memory ({} bytes)",
+                        vars.max_wasm_function_memory()
+                    );
+                }
+            }
+            plan_wasm_function_body(
+                scx,
+                &full_name,
+                &module,
+                &export,
+                &param_types,
+                &return_type,
+                returns_set,
+                max_fuel,
+                max_memory_bytes,
+            )?
+        }
+    };
+
+    Ok(Plan::CreateFunction(CreateFunctionPlan {
+        name,
+        function: SqlFunction {
+            create_sql,
+            param_types,
+            param_type_ids,
+            return_type,
+            return_type_id,
+            returns_set,
+            body,
+        },
+        if_not_exists,
+    }))
+}
+
+/// Plans the body of a `LANGUAGE WASM` function as a call into `export` of the
+/// WebAssembly `module` that passes the function's arguments through, and that
+/// may consume at most `max_fuel` and `max_memory_bytes`.
+#[allow(clippy::too_many_arguments)]
+fn plan_wasm_function_body(
+    scx: &StatementContext,
+    name: &FullItemName,
+    module: &str,
+    export: &str,
+    param_types: &[SqlScalarType],
+    return_type: &SqlScalarType,
+    returns_set: bool,
+    max_fuel: u64,
+    max_memory_bytes: u64,
+) -> Result<SqlFunctionBody, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_WASM_FUNCTIONS)?;
+    if returns_set {
+        bail_unsupported!("SETOF in WASM functions");
+    }
+    for typ in param_types.iter().chain([return_type]) {
+        if !wasm_supports_type(typ) {
+            sql_bail!(
+                "WASM functions do not support type {}",
+                scx.humanize_sql_scalar_type(typ, false)
+            );
+        }
+    }
+
+    let module = strconv::parse_bytes(module)
+        .map_err(|e| e.to_string())
+        .and_then(WasmModule::new)
+        .and_then(|module| {
+            module.check_signature(export, param_types, return_type)?;
+            Ok(module)
+        })
+        .map_err(|e| sql_err!("invalid WASM module: {e}"))?;
+
+    let call = WasmCall {
+        name: name.to_string(),
+        module,
+        export: export.into(),
+        param_types: param_types.to_vec(),
+        return_type: return_type.clone(),
+        max_fuel,
+        max_memory_bytes,
+    };
+    let args = (1..=param_types.len())
+        .map(HirScalarExpr::parameter)
+        .collect();
+    Ok(SqlFunctionBody::Scalar(HirScalarExpr::call_variadic(
+        call, args,
+    )))
+}
+
+/// Rewrites references to the named parameters of a SQL-language function
+/// into the positional parameters they correspond to.
+struct FunctionParameterRewriter<'a> {
+    function_name: &'a str,
+    params: &'a BTreeMap<String, usize>,
+}
+
+impl<'ast> VisitMut<'ast, Aug> for FunctionParameterRewriter<'_> {
+    fn visit_expr_mut(&mut self, expr: &'ast mut Expr<Aug>) {
+        if let Expr::Identifier(names) = expr {
+            let param = match names.as_slice() {
+                [name] => self.params.get(name.as_str()),
+                [function, name] if function.as_str() == self.function_name => {
+                    self.params.get(name.as_str())
+                }
+                _ => None,
+            };
+            if let Some(n) = param {
+                *expr = Expr::Parameter(*n);
+                return;
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+generate_extracted_config!(FunctionOption, (Fuel, u64), (Memory, ByteSize));
+
+generate_extracted_config!(
+    SequenceOption,
+    (Increment, i64, Default(1i64)),
+    (MinValue, i64),
+    (NoMinValue, bool, Default(false)),
+    (MaxValue, i64),
+    (NoMaxValue, bool, Default(false)),
+    (Start, i64),
+    (Cache, i64),
+    (Cycle, bool, Default(false)),
+    (NoCycle, bool, Default(false))
+);
+
+pub fn describe_create_sequence(
+    _: &StatementContext,
+    _: CreateSequenceStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_sequence(
+    scx: &StatementContext,
+    stmt: CreateSequenceStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_SEQUENCES)?;
+
+    let create_sql = normalize::create_statement(scx, Statement::CreateSequence(stmt.clone()))?;
+    let CreateSequenceStatement {
+        name,
+        if_not_exists,
+        options,
+        owned_by,
+    } = stmt;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let (false, Ok(item)) = (
+        if_not_exists,
+        scx.catalog.resolve_item_or_type(&partial_name),
+    ) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    if let Some(owned_by) = owned_by {
+        let table = scx.get_item_by_resolved_name(&owned_by.relation)?;
+        if table.item_type() != CatalogItemType::Table {
+            sql_bail!(
+                "referenced relation {} is not a table",
+                scx.catalog
+                    .resolve_full_name(table.name())
+                    .to_string()
+                    .quoted()
+            );
+        }
+        if table.name().qualifiers != name.qualifiers {
+            sql_bail!("sequence must be in same schema as table it is linked to");
+        }
+    }
+
+    let options = plan_sequence_options(options, "bigint", i64::MIN, i64::MAX, 1)?;
+
+    Ok(Plan::CreateSequence(CreateSequencePlan {
+        name,
+        sequence: Sequence {
+            create_sql,
+            options,
+        },
+        if_not_exists,
+    }))
+}
+
+/// The number of values that the sequence of an identity column caches unless
+/// told otherwise.
+///
+/// Every refill of the cache is a durable catalog write, so identity columns
+/// cache generously to keep inserts from writing to the catalog on every row.
+const IDENTITY_SEQUENCE_CACHE: i64 = 1000;
+
+/// Plans the parameters of a sequence whose values are of the integer type
+/// `type_name`, which ranges from `type_min` to `type_max`. The sequence caches
+/// `default_cache` values unless the options say otherwise.
+fn plan_sequence_options(
+    options: Vec<SequenceOption<Aug>>,
+    type_name: &str,
+    type_min: i64,
+    type_max: i64,
+    default_cache: i64,
+) -> Result<SequenceOptions, PlanError> {
+    let SequenceOptionExtracted {
+        seen: _,
+        increment,
+        min_value,
+        no_min_value,
+        max_value,
+        no_max_value,
+        start,
+        cache,
+        cycle,
+        no_cycle,
+    } = options.try_into()?;
+
+    if (min_value.is_some() && no_min_value)
+        || (max_value.is_some() && no_max_value)
+        || (cycle && no_cycle)
+    {
+        sql_bail!("conflicting or redundant options");
+    }
+    if increment == 0 {
+        sql_bail!("INCREMENT must not be zero");
+    }
+
+    let ascending = increment > 0;
+    let min_value = min_value.unwrap_or(if ascending { 1 } else { type_min });
+    let max_value = max_value.unwrap_or(if ascending { type_max } else { -1 });
+    for (option, value) in [("MINVALUE", min_value), ("MAXVALUE", max_value)] {
+        if value < type_min || value > type_max {
+            sql_bail!("{option} ({value}) is out of range for sequence data type {type_name}");
+        }
+    }
+    if min_value >= max_value {
+        sql_bail!("MINVALUE ({min_value}) must be less than MAXVALUE ({max_value})");
+    }
+    if min_value == i64::MIN && max_value == i64::MAX {
+        bail_unsupported!("sequences that span the entire bigint range");
+    }
+
+    let start = start.unwrap_or(if ascending { min_value } else { max_value });
+    if start < min_value {
+        sql_bail!("START value ({start}) cannot be less than MINVALUE ({min_value})");
+    }
+    if start > max_value {
+        sql_bail!("START value ({start}) cannot be greater than MAXVALUE ({max_value})");
+    }
+
+    let cache = cache.unwrap_or(default_cache);
+    let Ok(cache @ 1..) = u64::try_from(cache) else {
+        sql_bail!("CACHE ({cache}) must be greater than zero");
+    };
+
+    Ok(SequenceOptions {
+        increment,
+        min_value,
+        max_value,
+        start,
+        cache,
+        cycle,
+    })
+}
+
+/// Moves the column-level `CHECK` constraints of `stmt` into its table-level
+/// constraints and names all unnamed `CHECK` constraints the way PostgreSQL
+/// does, so that every constraint can be dropped by name.
+fn name_check_constraints(stmt: &mut CreateTableStatement<Aug>) -> Result<(), PlanError> {
+    let table_name = normalize::unresolved_item_name(stmt.name.clone())?.item;
+
+    // Pairs of constraints and the names to use if they are unnamed.
+    let mut constraints = vec![];
+    for column in &mut stmt.columns {
+        let column_name = normalize::column_name(column.name.clone());
+        while let Some(i) = column
+            .options
+            .iter()
+            .position(|o| matches!(o.option, ColumnOption::Check(_)))
+        {
+            let ast::ColumnOptionDef {
+                name,
+                option: ColumnOption::Check(expr),
+            } = column.options.remove(i)
+            else {
+                unreachable!("checked above");
+            };
+            let constraint = TableConstraint::Check {
+                name,
+                expr: Box::new(expr),
+            };
+            constraints.push((constraint, format!("{table_name}_{column_name}_check")));
+        }
+    }
+    for constraint in stmt.constraints.drain(..) {
+        constraints.push((constraint, format!("{table_name}_check")));
+    }
+
+    let mut taken = BTreeSet::new();
+    for (constraint, _) in &constraints {
+        if let Some(name) = constraint.name()
+            && !taken.insert(name.clone())
+        {
+            sql_bail!(
+                "constraint {} for relation {} already exists",
+                name.as_str().quoted(),
+                table_name.quoted()
+            );
+        }
+    }
+
+    for (mut constraint, default_name) in constraints {
+        if let TableConstraint::Check { name, .. } = &mut constraint
+            && name.is_none()
+        {
+            let mut candidate = Ident::new(default_name.clone())?;
+            for suffix in 1.. {
+                if !taken.contains(&candidate) {
+                    break;
+                }
+                candidate = Ident::new(format!("{default_name}{suffix}"))?;
+            }
+            taken.insert(candidate.clone());
+            *name = Some(candidate);
+        }
+        stmt.constraints.push(constraint);
+    }
+    Ok(())
+}
+
+/// Replaces the identity columns of a `CREATE TABLE` statement with columns
+/// that draw their default values from new sequences, which are returned.
+///
+/// As in PostgreSQL, the sequence of each identity column is named after the
+/// table and the column, and is owned by the column.
+fn plan_identity_columns(
+    scx: &StatementContext,
+    stmt: &mut CreateTableStatement<Aug>,
+) -> Result<Vec<(QualifiedItemName, Sequence)>, PlanError> {
+    let is_identity =
+        |o: &ast::ColumnOptionDef<Aug>| matches!(o.option, ColumnOption::Identity { .. });
+    if !stmt
+        .columns
+        .iter()
+        .any(|c| c.options.iter().any(is_identity))
+    {
+        return Ok(vec![]);
+    }
+    scx.require_feature_flag(&vars::ENABLE_SEQUENCES)?;
+    if stmt.temporary {
+        bail_unsupported!("identity columns in temporary tables");
+    }
+
+    let table_name =
+        scx.allocate_qualified_name(normalize::unresolved_item_name(stmt.name.clone())?)?;
+    let table_full_name = scx.catalog.resolve_full_name(&table_name);
+
+    let mut sequences: Vec<(QualifiedItemName, Sequence)> = vec![];
+    for column in &mut stmt.columns {
+        let Some(i) = column.options.iter().position(is_identity) else {
+            continue;
+        };
+        let ColumnOption::Identity { always, options } = column.options.remove(i).option else {
+            unreachable!("checked above");
+        };
+        let column_name = normalize::column_name(column.name.clone());
+        if column.options.iter().any(is_identity) {
+            sql_bail!(
+                "multiple identity specifications for column {} of table {}",
+                column_name.quoted(),
+                table_full_name.to_string().quoted()
+            );
+        }
+        if column
+            .options
+            .iter()
+            .any(|o| matches!(o.option, ColumnOption::Default(_)))
+        {
+            sql_bail!(
+                "both default and identity specified for column {} of table {}",
+                column_name.quoted(),
+                table_full_name.to_string().quoted()
+            );
+        }
+        if always {
+            bail_unsupported!("GENERATED ALWAYS AS IDENTITY");
+        }
+
+        let (type_name, type_min, type_max) = match scalar_type_from_sql(scx, &column.data_type)? {
+            SqlScalarType::Int16 => ("smallint", i16::MIN.into(), i16::MAX.into()),
+            SqlScalarType::Int32 => ("integer", i32::MIN.into(), i32::MAX.into()),
+            SqlScalarType::Int64 => ("bigint", i64::MIN, i64::MAX),
+            _ => sql_bail!("identity column type must be smallint, integer, or bigint"),
+        };
+        let options = plan_sequence_options(
+            options,
+            type_name,
+            type_min,
+            type_max,
+            IDENTITY_SEQUENCE_CACHE,
+        )?;
+
+        // Pick a name for the sequence that is not yet taken.
+        let mut sequence_name = QualifiedItemName {
+            qualifiers: table_name.qualifiers.clone(),
+            item: format!("{}_{}_seq", table_name.item, column_name),
+        };
+        for suffix in 1.. {
+            let partial_name = PartialItemName::from(scx.catalog.resolve_full_name(&sequence_name));
+            let taken = scx.catalog.resolve_item_or_type(&partial_name).is_ok()
+                || sequences.iter().any(|(name, _)| *name == sequence_name);
+            if !taken {
+                break;
+            }
+            sequence_name.item = format!("{}_{}_seq{}", table_name.item, column_name, suffix);
+        }
+        let sequence_full_name =
+            UnresolvedItemName::from(scx.catalog.resolve_full_name(&sequence_name));
+
+        // Record the sequence's parameters explicitly, as their defaults
+        // depend on the type of the column.
+        let number = |n: i64| Some(WithOptionValue::Value(Value::Number(n.to_string())));
+        let create_stmt = CreateSequenceStatement::<ast::Raw> {
+            name: sequence_full_name.clone(),
+            if_not_exists: false,
+            options: vec![
+                (SequenceOptionName::Increment, number(options.increment)),
+                (SequenceOptionName::MinValue, number(options.min_value)),
+                (SequenceOptionName::MaxValue, number(options.max_value)),
+                (SequenceOptionName::Start, number(options.start)),
+                (
+                    SequenceOptionName::Cache,
+                    number(options.cache.try_into().expect("planned from i64")),
+                ),
+                (
+                    if options.cycle {
+                        SequenceOptionName::Cycle
+                    } else {
+                        SequenceOptionName::NoCycle
+                    },
+                    None,
+                ),
+            ]
+            .into_iter()
+            .map(|(name, value)| SequenceOption { name, value })
+            .collect(),
+            owned_by: Some(ast::ColumnName {
+                relation: ast::RawItemName::Name(UnresolvedItemName::from(table_full_name.clone())),
+                column: column.name.clone(),
+            }),
+        };
+        let create_sql = create_stmt.to_ast_string_stable();
+
+        let default = format!(
+            "nextval({})",
+            Value::String(sequence_full_name.to_ast_string_stable()).to_ast_string_stable()
+        );
+        let (default, _) =
+            names::resolve(scx.catalog, mz_sql_parser::parser::parse_expr(&default)?)?;
+        column.options.push(ast::ColumnOptionDef {
+            name: None,
+            option: ColumnOption::NotNull,
+        });
+        column.options.push(ast::ColumnOptionDef {
+            name: None,
+            option: ColumnOption::Default(default),
+        });
+
+        sequences.push((
+            sequence_name,
+            Sequence {
+                create_sql,
+                options,
+            },
+        ));
+    }
+    Ok(sequences)
+}
+
 generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
 
 generate_extracted_config!(
@@ -5154,6 +5868,8 @@ pub fn unplan_create_cluster(
                 enable_consolidate_after_union_negate: _,
                 enable_reduce_mfp_fusion: _,
                 enable_cardinality_estimates: _,
+                enable_cost_based_join_ordering: _,
+                enable_native_outer_join: _,
                 persist_fast_path_limit: _,
                 reoptimize_imported_views,
                 enable_eager_delta_joins,
@@ -5635,11 +6351,6 @@ pub fn plan_drop_objects(
         cascade,
     }: DropObjectsStatement,
 ) -> Result<Plan, PlanError> {
-    assert_ne!(
-        object_type,
-        mz_sql_parser::ast::ObjectType::Func,
-        "rejected in parser"
-    );
     let object_type = object_type.into();
 
     let mut referenced_ids = Vec::new();
@@ -5898,7 +6609,8 @@ fn dependency_prevents_drop(object_type: ObjectType, dep: &dyn CatalogItem) -> b
         | ObjectType::Schema
         | ObjectType::Func
         | ObjectType::ContinualTask
-        | ObjectType::NetworkPolicy => match dep.item_type() {
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence => match dep.item_type() {
             CatalogItemType::Func
             | CatalogItemType::Table
             | CatalogItemType::Source
@@ -5909,7 +6621,9 @@ fn dependency_prevents_drop(object_type: ObjectType, dep: &dyn CatalogItem) -> b
             | CatalogItemType::Secret
             | CatalogItemType::Connection
             | CatalogItemType::ContinualTask => true,
-            CatalogItemType::Index => false,
+            // Indexes, and sequences owned by a table column, are dropped
+            // along with the object they depend on.
+            CatalogItemType::Index | CatalogItemType::Sequence => false,
         },
     }
 }
@@ -6229,7 +6943,11 @@ fn plan_retain_history(
     }
 }
 
-generate_extracted_config!(IndexOption, (RetainHistory, OptionalDuration));
+generate_extracted_config!(
+    IndexOption,
+    (RetainHistory, OptionalDuration),
+    (Storage, IndexStorage)
+);
 
 fn plan_index_options(
     scx: &StatementContext,
@@ -6240,12 +6958,22 @@ fn plan_index_options(
         scx.require_feature_flag(&vars::ENABLE_INDEX_OPTIONS)?;
     }
 
-    let IndexOptionExtracted { retain_history, .. }: IndexOptionExtracted = with_opts.try_into()?;
+    let IndexOptionExtracted {
+        retain_history,
+        storage,
+        ..
+    }: IndexOptionExtracted = with_opts.try_into()?;
 
-    let mut out = Vec::with_capacity(1);
+    let mut out = Vec::with_capacity(2);
     if let Some(cw) = plan_retain_history_option(scx, retain_history)? {
         out.push(crate::plan::IndexOption::RetainHistory(cw));
     }
+    if let Some(storage) = storage {
+        if storage == IndexStorage::Disk {
+            scx.require_feature_flag(&vars::ENABLE_INDEX_DISK_STORAGE)?;
+        }
+        out.push(crate::plan::IndexOption::Storage(storage));
+    }
     Ok(out)
 }
 
@@ -6253,6 +6981,7 @@ generate_extracted_config!(
     TableOption,
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
+    (StorageCompression, String),
     (RedactedTest, String)
 );
 
@@ -6264,6 +6993,7 @@ fn plan_table_options(
     let TableOptionExtracted {
         partition_by,
         retain_history,
+        storage_compression,
         redacted_test,
         ..
     }: TableOptionExtracted = with_opts.try_into()?;
@@ -6281,9 +7011,26 @@ fn plan_table_options(
     if let Some(cw) = plan_retain_history_option(scx, retain_history)? {
         out.push(crate::plan::TableOption::RetainHistory(cw));
     }
+    if let Some(compression) = plan_storage_compression_option(scx, storage_compression)? {
+        out.push(crate::plan::TableOption::StorageCompression(compression));
+    }
     Ok(out)
 }
 
+/// Plans the `STORAGE COMPRESSION` option of a table or materialized view.
+fn plan_storage_compression_option(
+    scx: &StatementContext,
+    storage_compression: Option<String>,
+) -> Result<Option<CompressionFormat>, PlanError> {
+    let Some(storage_compression) = storage_compression else {
+        return Ok(None);
+    };
+    scx.require_feature_flag(&vars::ENABLE_STORAGE_COMPRESSION_POLICY)?;
+    let compression =
+        CompressionFormat::parse(&storage_compression).map_err(|e| sql_err!("{e}"))?;
+    Ok(Some(compression))
+}
+
 pub fn plan_alter_index_options(
     scx: &mut StatementContext,
     AlterIndexStatement {
@@ -6310,6 +7057,9 @@ pub fn plan_alter_index_options(
                             None,
                         );
                     }
+                    IndexOptionName::Storage => {
+                        bail_unsupported!("ALTER INDEX ... RESET (STORAGE)");
+                    }
                 }
             }
             sql_bail!("expected option");
@@ -6330,6 +7080,9 @@ pub fn plan_alter_index_options(
                             opt.value,
                         );
                     }
+                    IndexOptionName::Storage => {
+                        bail_unsupported!("ALTER INDEX ... SET (STORAGE)");
+                    }
                 }
             }
             sql_bail!("expected option");
@@ -6635,7 +7388,8 @@ pub fn plan_alter_item_set_cluster(
         | ObjectType::Schema
         | ObjectType::Func
         | ObjectType::ContinualTask
-        | ObjectType::NetworkPolicy => {
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence => {
             bail_never_supported!(
                 format!("ALTER {object_type} SET CLUSTER"),
                 "sql/alter-set-cluster/",
@@ -7076,7 +7830,8 @@ pub fn plan_alter_object_swap(
             | ObjectType::Database
             | ObjectType::Func
             | ObjectType::ContinualTask
-            | ObjectType::NetworkPolicy,
+            | ObjectType::NetworkPolicy
+            | ObjectType::Sequence,
             _,
             _,
         ) => Err(PlanError::Unsupported {
@@ -7177,6 +7932,69 @@ fn alter_retain_history(
     }
 }
 
+pub fn describe_alter_storage_compression(
+    _: &StatementContext,
+    _: AlterStorageCompressionStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_storage_compression(
+    scx: &StatementContext,
+    AlterStorageCompressionStatement {
+        object_type,
+        if_exists,
+        name,
+        compression,
+    }: AlterStorageCompressionStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_STORAGE_COMPRESSION_POLICY)?;
+
+    let object_type = object_type.into();
+    let parsed = match &compression {
+        Some(Value::String(s)) => Some(CompressionFormat::parse(s).map_err(|e| sql_err!("{e}"))?),
+        Some(value) => sql_bail!("invalid STORAGE COMPRESSION value: {value}"),
+        // RESET reverts to the system default.
+        None => None,
+    };
+
+    match resolve_item_or_type(scx, object_type, name.clone(), if_exists)? {
+        Some(entry) => {
+            if entry.item_type() != object_type {
+                let full_name = scx.catalog.resolve_full_name(entry.name());
+                sql_bail!(
+                    "\"{}\" is a {} not a {}",
+                    full_name,
+                    entry.item_type(),
+                    format!("{object_type}").to_lowercase()
+                )
+            }
+            // The policy is recorded as an option of the `CREATE` statement,
+            // which only `CREATE TABLE` and `CREATE MATERIALIZED VIEW` have.
+            if object_type == ObjectType::Table && entry.writable_table_details().is_none() {
+                let full_name = scx.catalog.resolve_full_name(entry.name());
+                bail_unsupported!(format!(
+                    "STORAGE COMPRESSION on source-fed table \"{full_name}\""
+                ));
+            }
+            Ok(Plan::AlterStorageCompression(AlterStorageCompressionPlan {
+                id: entry.id(),
+                value: compression,
+                compression: parsed,
+                object_type,
+            }))
+        }
+        None => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: name.to_ast_string_simple(),
+                object_type,
+            });
+
+            Ok(Plan::AlterNoop(AlterNoopPlan { object_type }))
+        }
+    }
+}
+
 fn alter_source_timestamp_interval(
     scx: &StatementContext,
     if_exists: bool,
@@ -7372,6 +8190,7 @@ pub fn plan_alter_connection(
         Connection::MySql(_) => CreateConnectionType::MySql,
         Connection::SqlServer(_) => CreateConnectionType::SqlServer,
         Connection::IcebergCatalog(_) => CreateConnectionType::IcebergCatalog,
+        Connection::Gcs(_) => CreateConnectionType::Gcs,
     };
 
     // Collect all options irrespective of action taken on them.
@@ -7786,6 +8605,200 @@ pub fn plan_alter_table_add_column(
     }))
 }
 
+pub fn describe_alter_table_add_constraint(
+    _: &StatementContext,
+    _: AlterTableAddConstraintStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table_add_constraint(
+    scx: &StatementContext,
+    stmt: AlterTableAddConstraintStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let AlterTableAddConstraintStatement {
+        if_exists,
+        name,
+        constraint,
+    } = stmt;
+    let object_type = ObjectType::Table;
+
+    match &constraint {
+        TableConstraint::Check { .. } => {}
+        TableConstraint::Unique { .. } => bail_unsupported!("ALTER TABLE ... ADD UNIQUE"),
+        TableConstraint::ForeignKey { .. } => {
+            bail_unsupported!("ALTER TABLE ... ADD FOREIGN KEY")
+        }
+    }
+    scx.require_feature_flag(&vars::ENABLE_TABLE_CHECK_CONSTRAINTS)?;
+
+    let Some(item) = resolve_item_or_type(scx, object_type, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string_simple(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+
+    let mut create_stmt = table_create_statement(scx, item)?;
+    create_stmt.constraints.push(constraint);
+    // Name the new constraint now, so that we can find it in the new plan.
+    // Unnamed constraints are always named last, so it remains the last one.
+    name_check_constraints(&mut create_stmt)?;
+    let constraint_name = create_stmt
+        .constraints
+        .last()
+        .and_then(|c| c.name())
+        .map(|name| normalize::ident(name.clone()))
+        .expect("named by name_check_constraints");
+
+    let table = replan_table(scx, create_stmt)?;
+    let TableDataSource::TableWrites { checks, .. } = table.data_source else {
+        unreachable!("checked by table_create_statement");
+    };
+    let check = checks
+        .into_iter()
+        .find(|check| check.name == constraint_name)
+        .expect("planned with the table");
+
+    Ok(Plan::AlterTableAddConstraint(AlterTableAddConstraintPlan {
+        relation_id: item.id(),
+        create_sql: table.create_sql,
+        check,
+    }))
+}
+
+pub fn describe_alter_table_drop_constraint(
+    _: &StatementContext,
+    _: AlterTableDropConstraintStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table_drop_constraint(
+    scx: &StatementContext,
+    stmt: AlterTableDropConstraintStatement,
+) -> Result<Plan, PlanError> {
+    let AlterTableDropConstraintStatement {
+        if_exists,
+        name,
+        if_constraint_exists,
+        constraint_name,
+    } = stmt;
+    let object_type = ObjectType::Table;
+
+    let Some(item) = resolve_item_or_type(scx, object_type, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string_simple(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+    let item_name = scx.catalog.resolve_full_name(item.name()).item;
+
+    let mut create_stmt = table_create_statement(scx, item)?;
+    // Tables created before constraints were named may have unnamed ones.
+    name_check_constraints(&mut create_stmt)?;
+    let Some(i) = create_stmt
+        .constraints
+        .iter()
+        .position(|c| c.name() == Some(&constraint_name))
+    else {
+        let constraint_name = normalize::ident(constraint_name);
+        if if_constraint_exists {
+            scx.catalog.add_notice(PlanNotice::ConstraintDoesNotExist {
+                constraint_name,
+                object_name: item_name,
+            });
+            return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+        }
+        sql_bail!(
+            "constraint {} of relation {} does not exist",
+            constraint_name.quoted(),
+            item_name.quoted()
+        );
+    };
+    let constraint_name = normalize::ident(constraint_name);
+    if !matches!(create_stmt.constraints[i], TableConstraint::Check { .. }) {
+        bail_unsupported!("dropping UNIQUE or FOREIGN KEY constraints");
+    }
+
+    // The optimizer relies on the constraints of a table when it plans
+    // dataflows that read from it. These plans would no longer be correct once
+    // the table admits rows that violate the constraint.
+    for dependent in scx.catalog.item_dependents(item.id()) {
+        let ObjectId::Item(id) = dependent else {
+            continue;
+        };
+        let dependent = scx.catalog.get_item(&id);
+        if matches!(
+            dependent.item_type(),
+            CatalogItemType::MaterializedView
+                | CatalogItemType::Index
+                | CatalogItemType::ContinualTask
+        ) {
+            sql_bail!(
+                "cannot drop constraint {} on table {} because {} {} depends on it",
+                constraint_name.quoted(),
+                scx.catalog.minimal_qualification(item.name()),
+                dependent.item_type(),
+                scx.catalog.minimal_qualification(dependent.name()),
+            );
+        }
+    }
+
+    create_stmt.constraints.remove(i);
+    let table = replan_table(scx, create_stmt)?;
+
+    Ok(Plan::AlterTableDropConstraint(
+        AlterTableDropConstraintPlan {
+            relation_id: item.id(),
+            create_sql: table.create_sql,
+            constraint_name,
+        },
+    ))
+}
+
+/// Returns the `CREATE TABLE` statement that defines `table`, in a form that
+/// can be altered and passed to [`replan_table`].
+fn table_create_statement(
+    scx: &StatementContext,
+    table: &dyn CatalogItem,
+) -> Result<CreateTableStatement<Aug>, PlanError> {
+    if table.id().is_system() {
+        sql_bail!(
+            "cannot alter {} because it is required by the database system",
+            scx.catalog.minimal_qualification(table.name()),
+        );
+    }
+    if table.writable_table_details().is_none() {
+        bail_unsupported!("constraints on tables that are fed by sources");
+    }
+
+    let stmts = mz_sql_parser::parser::parse_statements(table.create_sql())?;
+    let [stmt]: [StatementParseResult; 1] = stmts
+        .try_into()
+        .expect("create sql of table was not exactly one statement");
+    let Statement::CreateTable(stmt) = stmt.ast else {
+        unreachable!("invalid create SQL for table item");
+    };
+    let (mut stmt, _) = crate::names::resolve(scx.catalog, stmt)?;
+    // The table already exists.
+    stmt.if_not_exists = true;
+    Ok(stmt)
+}
+
+/// Plans an altered `CREATE TABLE` statement of an existing table.
+fn replan_table(
+    scx: &StatementContext,
+    stmt: CreateTableStatement<Aug>,
+) -> Result<Table, PlanError> {
+    let Plan::CreateTable(CreateTablePlan { table, .. }) = plan_create_table(scx, stmt)? else {
+        unreachable!("invalid plan for CREATE TABLE statement");
+    };
+    Ok(table)
+}
+
 pub fn describe_alter_materialized_view_apply_replacement(
     _: &StatementContext,
     _: AlterMaterializedViewApplyReplacementStatement,
@@ -8085,6 +9098,7 @@ pub(crate) fn resolve_item_or_type<'a>(
     let name = normalize::unresolved_item_name(name)?;
     let catalog_item = match object_type {
         ObjectType::Type => scx.catalog.resolve_type(&name),
+        ObjectType::Func => scx.catalog.resolve_function(&name),
         ObjectType::Table
         | ObjectType::View
         | ObjectType::MaterializedView
@@ -8098,9 +9112,9 @@ pub(crate) fn resolve_item_or_type<'a>(
         | ObjectType::Connection
         | ObjectType::Database
         | ObjectType::Schema
-        | ObjectType::Func
         | ObjectType::ContinualTask
-        | ObjectType::NetworkPolicy => scx.catalog.resolve_item(&name),
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence => scx.catalog.resolve_item(&name),
     };
 
     match catalog_item {
@@ -29,6 +29,7 @@ use mz_ssh_util::keys::SshKeyPair;
 use mz_storage_types::connections::aws::{
     AwsAssumeRole, AwsAuth, AwsConnection, AwsConnectionReference, AwsCredentials,
 };
+use mz_storage_types::connections::gcs::GcsConnection;
 use mz_storage_types::connections::inline::ReferencedConnection;
 use mz_storage_types::connections::string_or_secret::StringOrSecret;
 use mz_storage_types::connections::{
@@ -191,6 +192,7 @@ pub(super) fn validate_options_per_connection_type(
             Url,
             Warehouse,
         ],
+        CreateConnectionType::Gcs => &[Credential, Endpoint],
     };
 
     for o in permitted_options {
@@ -668,6 +670,30 @@ impl ConnectionOptionExtracted {
 
                 ConnectionDetails::IcebergCatalog(IcebergCatalogConnection { catalog, uri })
             }
+            CreateConnectionType::Gcs => {
+                scx.require_feature_flag(&vars::ENABLE_GCS_CONNECTION)?;
+
+                let endpoint = self.endpoint.filter(|endpoint| !endpoint.is_empty());
+                if self.credential.is_none() {
+                    // Unauthenticated access is only meant for emulators. We
+                    // deliberately don't fall back to the environment's own
+                    // credentials, and don't let users point the environment
+                    // at arbitrary URLs without them.
+                    if endpoint.is_none() {
+                        sql_bail!("invalid CONNECTION: GCS connections require a CREDENTIAL");
+                    }
+                    scx.require_feature_flag(&vars::UNSAFE_ENABLE_UNAUTHENTICATED_GCS_CONNECTIONS)?;
+                }
+                if let Some(endpoint) = &endpoint {
+                    reqwest::Url::parse(endpoint)
+                        .map_err(|e| sql_err!("parsing GCS endpoint: {e}"))?;
+                }
+
+                ConnectionDetails::Gcs(GcsConnection {
+                    credential: self.credential,
+                    endpoint,
+                })
+            }
         };
 
         Ok(connection)
@@ -14,6 +14,7 @@
 
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
 
 use itertools::Itertools;
 use mz_arrow_util::builder::ArrowBuilder;
@@ -31,9 +32,10 @@ use mz_repr::{CatalogItemId, Datum, RelationDesc, Row, SqlRelationType, SqlScala
 use mz_sql_parser::ast::{
     CteBlock, ExplainAnalyzeClusterStatement, ExplainAnalyzeComputationProperties,
     ExplainAnalyzeComputationProperty, ExplainAnalyzeObjectStatement, ExplainAnalyzeProperty,
-    ExplainPlanOption, ExplainPlanOptionName, ExplainPushdownStatement, ExplainSinkSchemaFor,
-    ExplainSinkSchemaStatement, ExplainTimestampStatement, Expr, IfExistsBehavior, OrderByExpr,
-    SetExpr, SubscribeOutput, UnresolvedItemName,
+    ExplainPlanOption, ExplainPlanOptionName, ExplainPushdownStatement,
+    ExplainRecommendationsStatement, ExplainSinkSchemaFor, ExplainSinkSchemaStatement,
+    ExplainTimestampStatement, Expr, IfExistsBehavior, OrderByExpr, SetExpr, SubscribeOutput,
+    UnresolvedItemName,
 };
 use mz_sql_parser::ident;
 use mz_storage_types::sinks::{
@@ -44,9 +46,9 @@ use mz_storage_types::sinks::{
 use crate::ast::display::{AstDisplay, escaped_string_literal};
 use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
-    DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement, Query,
-    SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
-    UpdateStatement,
+    DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement,
+    MergeStatement, Query, SelectStatement, SubscribeOption, SubscribeOptionName,
+    SubscribeRelation, SubscribeStatement, UpdateStatement,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{Aug, ResolvedItemName};
@@ -58,12 +60,14 @@ use crate::plan::scope::Scope;
 use crate::plan::statement::show::ShowSelect;
 use crate::plan::statement::{StatementContext, StatementDesc, ddl};
 use crate::plan::{
-    self, CopyFromFilter, CopyToPlan, CreateSinkPlan, ExplainPushdownPlan, ExplainSinkSchemaPlan,
-    ExplainTimestampPlan, HirRelationExpr, HirScalarExpr, side_effecting_func, transform_ast,
+    self, CopyFromFilter, CopyToPlan, CreateSinkPlan, ExplainPushdownPlan,
+    ExplainRecommendationsPlan, ExplainSinkSchemaPlan, ExplainTimestampPlan, HirRelationExpr,
+    HirScalarExpr, side_effecting_func, transform_ast,
 };
 use crate::plan::{
-    CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan, PlanError,
-    QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan, query,
+    CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MergeActionPlan, MergeClausePlan,
+    MergePlan, MutationKind, Params, Plan, PlanError, QueryContext, ReadThenWritePlan, SelectPlan,
+    SubscribeFrom, SubscribePlan, query,
 };
 use crate::plan::{CopyFromSource, with_options};
 use crate::session::vars::{
@@ -82,10 +86,15 @@ pub fn describe_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
-    let (_, _, returning) = query::plan_insert_query(scx, table_name, columns, source, returning)?;
+    let (_, expr, returning, _) =
+        query::plan_insert_query(scx, table_name.clone(), columns, source, returning)?;
+    if let Some(on_conflict) = on_conflict {
+        query::plan_on_conflict_query(scx, table_name, expr, on_conflict)?;
+    }
     let desc = if returning.expr.is_empty() {
         None
     } else {
@@ -100,13 +109,13 @@ pub fn plan_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
     params: &Params,
 ) -> Result<Plan, PlanError> {
-    let (id, mut expr, returning) =
-        query::plan_insert_query(scx, table_name, columns, source, returning)?;
-    expr.bind_parameters(scx, QueryLifetime::OneShot, params)?;
+    let (id, mut expr, returning, sequence_defaults) =
+        query::plan_insert_query(scx, table_name.clone(), columns, source, returning)?;
     let returning = returning
         .expr
         .into_iter()
@@ -116,10 +125,22 @@ pub fn plan_insert(
         })
         .collect::<Result<Vec<_>, _>>()?;
 
+    if let Some(on_conflict) = on_conflict {
+        scx.require_feature_flag(&vars::ENABLE_INSERT_ON_CONFLICT)?;
+        if !sequence_defaults.is_empty() {
+            bail_unsupported!("INSERT ... ON CONFLICT into a table with sequence defaults");
+        }
+        let merge = query::plan_on_conflict_query(scx, table_name, expr, on_conflict)?;
+        return plan_merge_read_then_write(scx, MutationKind::Insert, params, merge, returning);
+    }
+
+    expr.bind_parameters(scx, QueryLifetime::OneShot, params)?;
+
     Ok(Plan::Insert(InsertPlan {
         id,
         values: expr,
         returning,
+        sequence_defaults,
     }))
 }
 
@@ -183,6 +204,89 @@ pub fn plan_read_then_write(
         assignments: assignments_outer,
         kind,
         returning: Vec::new(),
+        merge: None,
+        sequence_defaults: Vec::new(),
+    }))
+}
+
+pub fn describe_merge(
+    scx: &StatementContext,
+    stmt: MergeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    query::plan_merge_query(scx, stmt)?;
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_merge(
+    scx: &StatementContext,
+    stmt: MergeStatement<Aug>,
+    params: &Params,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_MERGE_STATEMENT)?;
+    let merge = query::plan_merge_query(scx, stmt)?;
+    plan_merge_read_then_write(scx, MutationKind::Merge, params, merge, Vec::new())
+}
+
+fn plan_merge_read_then_write(
+    scx: &StatementContext,
+    kind: MutationKind,
+    params: &Params,
+    query::MergeQueryPlan {
+        id,
+        mut selection,
+        finishing,
+        source_arity,
+        clauses,
+        conflict_keys,
+    }: query::MergeQueryPlan,
+    returning: Vec<mz_expr::MirScalarExpr>,
+) -> Result<Plan, PlanError> {
+    selection.bind_parameters(scx, QueryLifetime::OneShot, params)?;
+
+    let lower = |mut expr: HirScalarExpr| -> Result<mz_expr::MirScalarExpr, PlanError> {
+        expr.bind_parameters(scx, QueryLifetime::OneShot, params)?;
+        expr.lower_uncorrelated(scx.catalog.system_vars())
+    };
+    let mut merge_clauses = Vec::with_capacity(clauses.len());
+    for query::MergeQueryClause {
+        matched,
+        condition,
+        action,
+    } in clauses
+    {
+        let action = match action {
+            query::MergeQueryAction::Update(assignments) => MergeActionPlan::Update {
+                assignments: assignments
+                    .into_iter()
+                    .map(|(idx, set)| Ok((idx, lower(set)?)))
+                    .collect::<Result<_, PlanError>>()?,
+            },
+            query::MergeQueryAction::Delete => MergeActionPlan::Delete,
+            query::MergeQueryAction::Insert(values) => MergeActionPlan::Insert {
+                values: values.into_iter().map(lower).collect::<Result<_, _>>()?,
+            },
+            query::MergeQueryAction::DoNothing => MergeActionPlan::DoNothing,
+        };
+        merge_clauses.push(MergeClausePlan {
+            matched,
+            condition: condition.map(lower).transpose()?,
+            action,
+        });
+    }
+
+    Ok(Plan::ReadThenWrite(ReadThenWritePlan {
+        id,
+        selection,
+        finishing,
+        assignments: BTreeMap::new(),
+        kind,
+        returning,
+        merge: Some(MergePlan {
+            source_arity,
+            clauses: merge_clauses,
+            conflict_keys,
+        }),
+        sequence_defaults: Vec::new(),
     }))
 }
 
@@ -371,6 +475,20 @@ pub fn describe_explain_pushdown(
     )
 }
 
+pub fn describe_explain_recommendations(
+    _scx: &StatementContext,
+    _statement: ExplainRecommendationsStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    let relation_desc = RelationDesc::builder()
+        .with_column("Recommendation", SqlScalarType::String.nullable(false))
+        .with_column("Statements", SqlScalarType::Int64.nullable(false))
+        .with_column("Example Statement", SqlScalarType::String.nullable(false))
+        .with_column("Estimated Size", SqlScalarType::Int64.nullable(true))
+        .finish();
+
+    Ok(StatementDesc::new(Some(relation_desc)))
+}
+
 pub fn describe_explain_analyze_object(
     _scx: &StatementContext,
     statement: ExplainAnalyzeObjectStatement<Aug>,
@@ -639,6 +757,8 @@ impl TryFrom<ExplainPlanOptionExtracted> for ExplainConfig {
                 enable_consolidate_after_union_negate: Default::default(),
                 enable_reduce_mfp_fusion: Default::default(),
                 enable_cardinality_estimates: Default::default(),
+                enable_cost_based_join_ordering: Default::default(),
+                enable_native_outer_join: Default::default(),
                 persist_fast_path_limit: Default::default(),
                 reoptimize_imported_views: v.reoptimize_imported_views,
                 enable_join_prioritize_arranged: v.enable_join_prioritize_arranged,
@@ -889,6 +1009,16 @@ pub fn plan_explain_pushdown(
     Ok(Plan::ExplainPushdown(ExplainPushdownPlan { explainee }))
 }
 
+pub fn plan_explain_recommendations(
+    scx: &StatementContext,
+    statement: ExplainRecommendationsStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_EXPLAIN_RECOMMENDATIONS)?;
+    Ok(Plan::ExplainRecommendations(ExplainRecommendationsPlan {
+        cluster_id: statement.cluster.id,
+    }))
+}
+
 pub fn plan_explain_analyze_object(
     scx: &StatementContext,
     statement: ExplainAnalyzeObjectStatement<Aug>,
@@ -1513,7 +1643,13 @@ pub fn plan_explain_timestamp(
     }))
 }
 
-generate_extracted_config!(SubscribeOption, (Snapshot, bool), (Progress, bool));
+generate_extracted_config!(
+    SubscribeOption,
+    (Snapshot, bool),
+    (Progress, bool),
+    (EmitEvery, Duration),
+    (MaxRowsPerSecond, u64)
+);
 
 pub fn describe_subscribe(
     scx: &StatementContext,
@@ -1783,8 +1919,21 @@ pub fn plan_subscribe(
     };
 
     let SubscribeOptionExtracted {
-        progress, snapshot, ..
+        progress,
+        snapshot,
+        emit_every,
+        max_rows_per_second,
+        ..
     } = options.try_into()?;
+    if emit_every.is_some() || max_rows_per_second.is_some() {
+        scx.require_feature_flag(&vars::ENABLE_SUBSCRIBE_EMIT_OPTIONS)?;
+    }
+    if emit_every.is_some_and(|d| d.as_millis() == 0) {
+        sql_bail!("EMIT EVERY must be at least 1 millisecond");
+    }
+    if max_rows_per_second == Some(0) {
+        sql_bail!("MAX ROWS PER SECOND must be greater than zero");
+    }
     Ok(Plan::Subscribe(SubscribePlan {
         from,
         when,
@@ -1792,6 +1941,8 @@ pub fn plan_subscribe(
         with_snapshot: snapshot.unwrap_or(true),
         copy_to,
         emit_progress: progress.unwrap_or(false),
+        emit_every,
+        max_rows_per_second,
         output,
     }))
 }
@@ -1801,7 +1952,7 @@ pub fn describe_copy_from_table(
     table_name: <Aug as AstInfo>::ItemName,
     columns: Vec<Ident>,
 ) -> Result<StatementDesc, PlanError> {
-    let (_, desc, _, _) = query::plan_copy_from(scx, table_name, columns)?;
+    let (_, desc, _, _, _) = query::plan_copy_from(scx, table_name, columns)?;
     Ok(StatementDesc::new(Some(desc)))
 }
 
@@ -1810,7 +1961,7 @@ pub fn describe_copy_item(
     object_name: <Aug as AstInfo>::ItemName,
     columns: Vec<Ident>,
 ) -> Result<StatementDesc, PlanError> {
-    let (_, desc, _, _) = query::plan_copy_item(scx, object_name, columns)?;
+    let (_, desc, _, _, _) = query::plan_copy_item(scx, object_name, columns)?;
     Ok(StatementDesc::new(Some(desc)))
 }
 
@@ -1843,15 +1994,26 @@ fn plan_copy_to_expr(
     format: CopyFormat,
     options: CopyOptionExtracted,
 ) -> Result<Plan, PlanError> {
-    let conn_id = match options.aws_connection {
-        Some(conn_id) => CatalogItemId::from(conn_id),
-        None => sql_bail!("AWS CONNECTION is required for COPY ... TO <expr>"),
+    let conn_id = match (options.aws_connection, options.gcs_connection) {
+        (Some(conn_id), None) => CatalogItemId::from(conn_id),
+        (None, Some(conn_id)) => {
+            scx.require_feature_flag(&vars::ENABLE_GCS_CONNECTION)?;
+            CatalogItemId::from(conn_id)
+        }
+        (Some(_), Some(_)) => {
+            sql_bail!("cannot specify both AWS CONNECTION and GCS CONNECTION")
+        }
+        (None, None) => sql_bail!(
+            "AWS CONNECTION is required for COPY ... TO <expr>, or GCS CONNECTION for Google Cloud Storage"
+        ),
     };
     let connection = scx.get_item(&conn_id).connection()?;
 
-    match connection {
-        mz_storage_types::connections::Connection::Aws(_) => {}
-        _ => sql_bail!("only AWS CONNECTION is supported for COPY ... TO <expr>"),
+    match (connection, options.gcs_connection.is_some()) {
+        (mz_storage_types::connections::Connection::Aws(_), false) => {}
+        (mz_storage_types::connections::Connection::Gcs(_), true) => {}
+        (_, false) => sql_bail!("only AWS CONNECTION is supported for COPY ... TO <expr>"),
+        (_, true) => sql_bail!("GCS CONNECTION must refer to a GCS connection"),
     }
 
     let format = match format {
@@ -1956,6 +2118,9 @@ fn plan_copy_from(
             };
             let from = plan_expr(ecx, &from_expr)?.type_as(ecx, &SqlScalarType::String)?;
 
+            if options.gcs_connection.is_some() {
+                sql_bail!("GCS CONNECTION is not supported in COPY ... FROM");
+            }
             match options.aws_connection {
                 Some(conn_id) => {
                     let conn_id = CatalogItemId::from(conn_id);
@@ -2023,7 +2188,14 @@ fn plan_copy_from(
 
     let table_name_string = table_name.full_name_str();
 
-    let (id, source_desc, columns, maybe_mfp) = query::plan_copy_from(scx, table_name, columns)?;
+    let (id, source_desc, columns, maybe_mfp, sequence_defaults) =
+        query::plan_copy_from(scx, table_name, columns)?;
+
+    // Sequence defaults are filled in by the coordinator as it decodes rows,
+    // which only happens for COPY FROM STDIN.
+    if !sequence_defaults.is_empty() && !matches!(source, CopyFromSource::Stdin) {
+        bail_unsupported!("COPY FROM a URL into a table with nextval defaults");
+    }
 
     let Some(mfp) = maybe_mfp else {
         sql_bail!("[internal error] COPY FROM ... expects an MFP to be produced");
@@ -2058,6 +2230,7 @@ generate_extracted_config!(
     (Quote, String),
     (Header, bool),
     (AwsConnection, with_options::Object),
+    (GcsConnection, with_options::Object),
     (MaxFileSize, ByteSize, Default(ByteSize::mb(256))),
     (Files, Vec<String>),
     (Pattern, String)
@@ -0,0 +1,151 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Asynchronous notifications.
+//!
+//! This module houses the handlers for the `LISTEN`, `UNLISTEN`, `NOTIFY`,
+//! `CREATE CHANNEL` and `DROP CHANNEL` statements.
+//!
+//! Channels are plain names, as in PostgreSQL, and carry what `NOTIFY` sends.
+//! `CREATE CHANNEL ... ON VIEW` additionally binds a channel to a view: the
+//! channel is backed by a `SUBSCRIBE` to the view, and every change to the
+//! view is sent to the channel's listeners as a JSON diff. Bound channels are
+//! not durable and live until they are dropped or the session that created
+//! them ends.
+
+use crate::ast::{
+    CreateChannelStatement, DropChannelStatement, ListenStatement, NotifyStatement,
+    UnlistenStatement,
+};
+use crate::catalog::CatalogItemType;
+use crate::names::Aug;
+use crate::normalize;
+use crate::plan::statement::{StatementContext, StatementDesc};
+use crate::plan::{
+    CreateChannelPlan, DropChannelPlan, ListenPlan, NotifyPlan, Plan, PlanError, QueryWhen,
+    SubscribeFrom, SubscribeOutput, SubscribePlan, UnlistenPlan,
+};
+use crate::session::vars;
+
+/// The maximum size of a `NOTIFY` payload in bytes, matching PostgreSQL.
+pub const MAX_NOTIFY_PAYLOAD_SIZE: usize = 8000;
+
+pub fn describe_listen(
+    _: &StatementContext,
+    _: ListenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_listen(
+    scx: &StatementContext,
+    ListenStatement { channel }: ListenStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    Ok(Plan::Listen(ListenPlan {
+        channel: normalize::ident(channel),
+    }))
+}
+
+pub fn describe_unlisten(
+    _: &StatementContext,
+    _: UnlistenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_unlisten(
+    scx: &StatementContext,
+    UnlistenStatement { channel }: UnlistenStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    Ok(Plan::Unlisten(UnlistenPlan {
+        channel: channel.map(normalize::ident),
+    }))
+}
+
+pub fn describe_notify(
+    _: &StatementContext,
+    _: NotifyStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_notify(
+    scx: &StatementContext,
+    NotifyStatement { channel, payload }: NotifyStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    let payload = payload.unwrap_or_default();
+    if payload.len() >= MAX_NOTIFY_PAYLOAD_SIZE {
+        sql_bail!("payload string too long");
+    }
+    Ok(Plan::Notify(NotifyPlan {
+        channel: normalize::ident(channel),
+        payload,
+    }))
+}
+
+pub fn describe_create_channel(
+    _: &StatementContext,
+    _: CreateChannelStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_channel(
+    scx: &StatementContext,
+    CreateChannelStatement { channel, view }: CreateChannelStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    let item = scx.get_item_by_resolved_name(&view)?;
+    if !matches!(
+        item.item_type(),
+        CatalogItemType::View | CatalogItemType::MaterializedView
+    ) {
+        sql_bail!("'{}' is not a view", view.full_name_str());
+    }
+    let desc = item
+        .relation_desc()
+        .expect("views have a relation description")
+        .into_owned();
+    Ok(Plan::CreateChannel(CreateChannelPlan {
+        channel: normalize::ident(channel),
+        subscribe: SubscribePlan {
+            from: SubscribeFrom::Id(item.global_id()),
+            with_snapshot: false,
+            when: QueryWhen::Immediately,
+            up_to: None,
+            copy_to: None,
+            emit_progress: false,
+            emit_every: None,
+            max_rows_per_second: None,
+            output: SubscribeOutput::Diffs,
+        },
+        desc,
+    }))
+}
+
+pub fn describe_drop_channel(
+    _: &StatementContext,
+    _: DropChannelStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_channel(
+    scx: &StatementContext,
+    DropChannelStatement { if_exists, channel }: DropChannelStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    Ok(Plan::DropChannel(DropChannelPlan {
+        channel: normalize::ident(channel),
+        if_exists,
+    }))
+}
@@ -26,7 +26,7 @@ use serde::{Deserialize, Serialize};
 use crate::ast::{AstInfo, UnresolvedItemName, Value, WithOptionValue};
 use crate::catalog::SessionCatalog;
 use crate::names::{ResolvedDataType, ResolvedItemName};
-use crate::plan::{Aug, PlanError, literal};
+use crate::plan::{Aug, IndexStorage, PlanError, literal};
 
 pub trait TryFromValue<T>: Sized {
     fn try_from_value(v: T) -> Result<Self, PlanError>;
@@ -67,6 +67,33 @@ impl ImpliedValue for IcebergCatalogType {
     }
 }
 
+impl TryFromValue<WithOptionValue<Aug>> for IndexStorage {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match String::try_from_value(v)? {
+            s if s.eq_ignore_ascii_case("memory") => Ok(IndexStorage::Memory),
+            s if s.eq_ignore_ascii_case("disk") => Ok(IndexStorage::Disk),
+            _ => sql_bail!("invalid index storage: expected 'memory' or 'disk'"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::Value(Value::String(match self {
+            IndexStorage::Memory => "memory".to_string(),
+            IndexStorage::Disk => "disk".to_string(),
+        })))
+    }
+
+    fn name() -> String {
+        "index storage".to_string()
+    }
+}
+
+impl ImpliedValue for IndexStorage {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide an index storage")
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Secret(CatalogItemId);
 
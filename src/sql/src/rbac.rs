@@ -94,9 +94,10 @@ fn filter_requirements(
     rbac_requirements
 }
 
-// The default item types that most statements require USAGE privileges for.
+// The default item types that most statements require USAGE privileges for. Functions require
+// EXECUTE privileges instead.
 static DEFAULT_ITEM_USAGE: LazyLock<BTreeSet<CatalogItemType>> = LazyLock::new(|| {
-    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection}
+    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection, CatalogItemType::Func}
 });
 // CREATE statements require USAGE privileges on the default item types and USAGE privileges on
 // Types.
@@ -562,6 +563,7 @@ fn generate_rbac_requirements(
             name,
             table: _,
             if_not_exists: _,
+            sequences: _,
         }) => RbacRequirements {
             privileges: vec![(
                 SystemObjectId::Object(name.qualifiers.clone().into()),
@@ -672,6 +674,34 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        // Calls to SQL-language functions are inlined, so, like PostgreSQL's default of
+        // `SECURITY INVOKER`, the objects that the body reads are checked against the caller.
+        Plan::CreateFunction(plan::CreateFunctionPlan {
+            name,
+            function: _,
+            if_not_exists: _,
+        }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::CreateSequence(plan::CreateSequencePlan {
+            name,
+            sequence: _,
+            if_not_exists: _,
+        }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::Comment(plan::CommentPlan {
             object_id,
             sub_component: _,
@@ -830,7 +860,25 @@ fn generate_rbac_requirements(
             up_to: _,
             copy_to: _,
             emit_progress: _,
+            emit_every: _,
+            max_rows_per_second: _,
             output: _,
+        })
+        | Plan::CreateChannel(plan::CreateChannelPlan {
+            channel: _,
+            subscribe:
+                plan::SubscribePlan {
+                    from,
+                    with_snapshot: _,
+                    when: _,
+                    up_to: _,
+                    copy_to: _,
+                    emit_progress: _,
+                    emit_every: _,
+                    max_rows_per_second: _,
+                    output: _,
+                },
+            desc: _,
         }) => {
             let items = from
                 .depends_on()
@@ -952,6 +1000,19 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::ExplainRecommendations(plan::ExplainRecommendationsPlan { cluster_id }) => {
+            RbacRequirements {
+                privileges: vec![(
+                    SystemObjectId::Object(cluster_id.into()),
+                    AclMode::USAGE,
+                    role_id,
+                )],
+                item_usage: &EMPTY_ITEM_USAGE,
+                // Recommendations are derived from the statements of all users.
+                superuser_action: Some("explain recommendations".to_string()),
+                ..Default::default()
+            }
+        }
         Plan::ExplainTimestamp(plan::ExplainTimestampPlan {
             format: _,
             raw_plan,
@@ -972,6 +1033,7 @@ fn generate_rbac_requirements(
             id,
             values,
             returning,
+            sequence_defaults: _,
         }) => {
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             let mut privileges = vec![
@@ -1054,6 +1116,16 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterStorageCompression(plan::AlterStorageCompressionPlan {
+            id,
+            value: _,
+            compression: _,
+            object_type: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterSourceTimestampInterval(plan::AlterSourceTimestampIntervalPlan {
             id,
             value: _,
@@ -1280,7 +1352,13 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
-        Plan::AlterTableAddColumn(plan::AlterTablePlan { relation_id, .. }) => RbacRequirements {
+        Plan::AlterTableAddColumn(plan::AlterTablePlan { relation_id, .. })
+        | Plan::AlterTableAddConstraint(plan::AlterTableAddConstraintPlan {
+            relation_id, ..
+        })
+        | Plan::AlterTableDropConstraint(plan::AlterTableDropConstraintPlan {
+            relation_id, ..
+        }) => RbacRequirements {
             ownership: vec![ObjectId::Item(*relation_id)],
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
@@ -1304,12 +1382,23 @@ fn generate_rbac_requirements(
             assignments,
             kind,
             returning,
+            merge,
+            sequence_defaults: _,
         }) => {
-            let acl_mode = match kind {
+            let mut acl_mode = match kind {
                 MutationKind::Insert => AclMode::INSERT,
                 MutationKind::Update => AclMode::UPDATE,
                 MutationKind::Delete => AclMode::DELETE,
+                MutationKind::Merge => AclMode::empty(),
             };
+            for clause in merge.iter().flat_map(|merge| merge.clauses.iter()) {
+                acl_mode |= match clause.action {
+                    plan::MergeActionPlan::Update { .. } => AclMode::UPDATE,
+                    plan::MergeActionPlan::Delete => AclMode::DELETE,
+                    plan::MergeActionPlan::Insert { .. } => AclMode::INSERT,
+                    plan::MergeActionPlan::DoNothing => AclMode::empty(),
+                };
+            }
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             let mut privileges = vec![
                 (
@@ -1490,20 +1579,33 @@ fn generate_rbac_requirements(
                 .collect(),
             ..Default::default()
         },
-        Plan::SideEffectingFunc(func) => {
-            let role_membership = match func {
-                SideEffectingFunc::PgCancelBackend { connection_id } => active_conns
+        Plan::SideEffectingFunc(func) => match func {
+            SideEffectingFunc::PgCancelBackend { connection_id } => RbacRequirements {
+                role_membership: active_conns
                     .expect("active_conns is required for Plan::SideEffectingFunc")(
                     *connection_id
                 )
                 .map(|x| [x].into())
                 .unwrap_or_default(),
-            };
-            RbacRequirements {
-                role_membership,
                 ..Default::default()
+            },
+            SideEffectingFunc::SequenceNextval { id }
+            | SideEffectingFunc::SequenceCurrval { id }
+            | SideEffectingFunc::SequenceSetval { id, .. } => {
+                let acl_mode = match func {
+                    SideEffectingFunc::SequenceSetval { .. } => AclMode::UPDATE,
+                    _ => AclMode::USAGE,
+                };
+                let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
+                RbacRequirements {
+                    privileges: vec![
+                        (SystemObjectId::Object(schema_id), AclMode::USAGE, role_id),
+                        (SystemObjectId::Object(id.into()), acl_mode, role_id),
+                    ],
+                    ..Default::default()
+                }
             }
-        }
+        },
         Plan::ValidateConnection(plan::ValidateConnectionPlan { id, connection: _ }) => {
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             RbacRequirements {
@@ -1561,7 +1663,17 @@ fn generate_rbac_requirements(
         })
         | Plan::Execute(plan::ExecutePlan { name: _, params: _ })
         | Plan::Deallocate(plan::DeallocatePlan { name: _ })
-        | Plan::Raise(plan::RaisePlan { severity: _ }) => Default::default(),
+        | Plan::Raise(plan::RaisePlan { severity: _ })
+        | Plan::Listen(plan::ListenPlan { channel: _ })
+        | Plan::Unlisten(plan::UnlistenPlan { channel: _ })
+        | Plan::Notify(plan::NotifyPlan {
+            channel: _,
+            payload: _,
+        })
+        | Plan::DropChannel(plan::DropChannelPlan {
+            channel: _,
+            if_exists: _,
+        }) => Default::default(),
     }
 }
 
@@ -1699,7 +1811,16 @@ fn generate_read_privileges_inner(
                 CatalogItemType::Type | CatalogItemType::Secret | CatalogItemType::Connection => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::USAGE, role_id));
                 }
-                CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Func => {}
+                // SQL-language functions are inlined into the calling query, so whoever calls
+                // them must also be able to read everything they read.
+                CatalogItemType::Func if !id.is_system() => {
+                    privileges.push((SystemObjectId::Object(id.into()), AclMode::EXECUTE, role_id));
+                    views.push((item.references().items().copied(), role_id));
+                }
+                CatalogItemType::Sink
+                | CatalogItemType::Index
+                | CatalogItemType::Func
+                | CatalogItemType::Sequence => {}
             }
         }
     }
@@ -1723,11 +1844,18 @@ fn generate_usage_privileges(
     ids.items()
         .filter_map(move |id| {
             let item = catalog.get_item(id);
-            if item_types.contains(&item.item_type()) {
+            // Built-in functions are executable by everyone.
+            if item_types.contains(&item.item_type())
+                && !(item.item_type() == CatalogItemType::Func && id.is_system())
+            {
                 let schema_id = item.name().qualifiers.clone().into();
+                let acl_mode = match item.item_type() {
+                    CatalogItemType::Func => AclMode::EXECUTE,
+                    _ => AclMode::USAGE,
+                };
                 Some([
                     (SystemObjectId::Object(schema_id), AclMode::USAGE, role_id),
-                    (SystemObjectId::Object(id.into()), AclMode::USAGE, role_id),
+                    (SystemObjectId::Object(id.into()), acl_mode, role_id),
                 ])
             } else {
                 None
@@ -1829,8 +1957,9 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         SystemObjectType::Object(ObjectType::Connection) => AclMode::USAGE,
         SystemObjectType::Object(ObjectType::Database) => USAGE_CREATE_ACL_MODE,
         SystemObjectType::Object(ObjectType::Schema) => USAGE_CREATE_ACL_MODE,
-        SystemObjectType::Object(ObjectType::Func) => EMPTY_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Func) => AclMode::EXECUTE,
         SystemObjectType::Object(ObjectType::ContinualTask) => AclMode::SELECT,
+        SystemObjectType::Object(ObjectType::Sequence) => AclMode::USAGE.union(AclMode::UPDATE),
         SystemObjectType::System => ALL_SYSTEM_PRIVILEGES,
     }
 }
@@ -1860,7 +1989,8 @@ const fn default_builtin_object_acl_mode(object_type: ObjectType) -> AclMode {
         | ObjectType::Connection
         | ObjectType::Database
         | ObjectType::Func
-        | ObjectType::NetworkPolicy => AclMode::empty(),
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence => AclMode::empty(),
     }
 }
 
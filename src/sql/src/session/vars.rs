@@ -1103,6 +1103,8 @@ impl SystemVars {
             &MAX_RULES_PER_NETWORK_POLICY,
             &MAX_RESULT_SIZE,
             &MAX_COPY_FROM_ROW_SIZE,
+            &MAX_WASM_FUNCTION_FUEL,
+            &MAX_WASM_FUNCTION_MEMORY,
             &ALLOWED_CLUSTER_REPLICA_SIZES,
             &upsert_rocksdb::UPSERT_ROCKSDB_COMPACTION_STYLE,
             &upsert_rocksdb::UPSERT_ROCKSDB_OPTIMIZE_COMPACTION_MEMTABLE_BUDGET,
@@ -1649,6 +1651,17 @@ impl SystemVars {
             .as_bytes()
     }
 
+    /// Returns the value of the `max_wasm_function_fuel` configuration parameter.
+    pub fn max_wasm_function_fuel(&self) -> u64 {
+        *self.expect_value(&MAX_WASM_FUNCTION_FUEL)
+    }
+
+    /// Returns the value of the `max_wasm_function_memory` configuration parameter.
+    pub fn max_wasm_function_memory(&self) -> u64 {
+        self.expect_value::<ByteSize>(&MAX_WASM_FUNCTION_MEMORY)
+            .as_bytes()
+    }
+
     /// Returns the value of the `allowed_cluster_replica_sizes` configuration parameter.
     pub fn allowed_cluster_replica_sizes(&self) -> Vec<String> {
         self.expect_value::<Vec<Ident>>(&ALLOWED_CLUSTER_REPLICA_SIZES)
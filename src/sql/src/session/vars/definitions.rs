@@ -599,6 +599,20 @@ pub static MAX_COPY_FROM_ROW_SIZE: VarDefinition = VarDefinition::new(
     true,
 );
 
+pub static MAX_WASM_FUNCTION_FUEL: VarDefinition = VarDefinition::new(
+    "max_wasm_function_fuel",
+    value!(u64; 100_000_000),
+    "The amount of fuel, roughly the number of instructions, that a single call of a WebAssembly function may consume (Materialize).",
+    true,
+);
+
+pub static MAX_WASM_FUNCTION_MEMORY: VarDefinition = VarDefinition::new(
+    "max_wasm_function_memory",
+    value!(ByteSize; ByteSize::mb(64)),
+    "The maximum size in bytes of the memory of a single call of a WebAssembly function (Materialize).",
+    true,
+);
+
 pub static MAX_IDENTIFIER_LENGTH: VarDefinition = VarDefinition::new(
     "max_identifier_length",
     value!(usize; mz_sql_lexer::lexer::MAX_IDENTIFIER_LENGTH),
@@ -1807,6 +1821,24 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_explain_recommendations,
+        desc: "EXPLAIN RECOMMENDATIONS",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_window_table_functions,
+        desc: "the TUMBLE, HOP, and SESSION table functions",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_index_disk_storage,
+        desc: "CREATE INDEX ... WITH (STORAGE = 'disk')",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_index_options,
         desc: "INDEX OPTIONS",
@@ -1875,20 +1907,50 @@ feature_flags!(
         enable_for_item_parsing: true,
     },
     {
-        name: enable_repeat_row,
-        desc: "the repeat_row function",
+        name: enable_listen_notify,
+        desc: "LISTEN and NOTIFY statements",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_insert_on_conflict,
+        desc: "INSERT ... ON CONFLICT",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_table_key_enforcement,
+        desc: "enforcement of PRIMARY KEY and UNIQUE constraints on tables",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_read_write_transactions,
+        desc: "transactions that write after reading tables",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_storage_compression_policy,
+        desc: "the STORAGE COMPRESSION option",
         default: false,
         enable_for_item_parsing: true,
     },
     {
-        name: enable_replica_targeted_materialized_views,
-        desc: "replica-targeted materialized views",
+        name: enable_merge_statement,
+        desc: "MERGE statement",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_repeat_row,
+        desc: "the repeat_row function",
         default: false,
         enable_for_item_parsing: true,
     },
     {
-        name: unsafe_enable_table_check_constraint,
-        desc: "CREATE TABLE with a check constraint",
+        name: enable_replica_targeted_materialized_views,
+        desc: "replica-targeted materialized views",
         default: false,
         enable_for_item_parsing: true,
     },
@@ -1904,6 +1966,12 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: true,
     },
+    {
+        name: unsafe_enable_unauthenticated_gcs_connections,
+        desc: "GCS connections without a CREDENTIAL",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: unsafe_enable_unorchestrated_cluster_replicas,
         desc: "unorchestrated cluster replicas",
@@ -1922,12 +1990,30 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_subscribe_emit_options,
+        desc: "`EMIT EVERY` and `MAX ROWS PER SECOND` in SUBSCRIBE",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_cardinality_estimates,
         desc: "join planning with cardinality estimates",
         default: false,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_cost_based_join_ordering,
+        desc: "cost-based join ordering of one-shot queries using collection statistics",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_native_outer_join,
+        desc: "HIR ⇒ MIR lowering of outer equijoins to a native outer join operator",
+        default: false,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_connection_validation_syntax,
         desc: "CREATE CONNECTION .. WITH (VALIDATE) and VALIDATE CONNECTION syntax",
@@ -2169,6 +2255,30 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_create_function,
+        desc: "CREATE FUNCTION",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_sequences,
+        desc: "sequences and identity columns",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_table_check_constraints,
+        desc: "CHECK constraints on tables",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_wasm_functions,
+        desc: "CREATE FUNCTION ... LANGUAGE WASM",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_copy_from_remote,
         desc: "Whether to allow COPY FROM <url>.",
@@ -2229,6 +2339,12 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_gcs_connection,
+        desc: "Whether to enable GCS connections and COPY ... TO Google Cloud Storage.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_frontend_peek_sequencing, // currently, changes only take effect for new sessions
         desc: "Enables the new peek sequencing code, which does most of its work in the Adapter Frontend instead of the Coordinator main task.",
@@ -2281,6 +2397,8 @@ impl From<&super::SystemVars> for OptimizerFeatures {
             enable_variadic_left_join_lowering: vars.enable_variadic_left_join_lowering(),
             enable_letrec_fixpoint_analysis: vars.enable_letrec_fixpoint_analysis(),
             enable_cardinality_estimates: vars.enable_cardinality_estimates(),
+            enable_cost_based_join_ordering: vars.enable_cost_based_join_ordering(),
+            enable_native_outer_join: vars.enable_native_outer_join(),
             persist_fast_path_limit: vars.persist_fast_path_limit(),
             reoptimize_imported_views: false,
             enable_join_prioritize_arranged: vars.enable_join_prioritize_arranged(),
@@ -2325,6 +2443,8 @@ mod tests {
             enable_reduce_mfp_fusion,
             enable_variadic_left_join_lowering,
             enable_cardinality_estimates,
+            enable_cost_based_join_ordering,
+            enable_native_outer_join,
             persist_fast_path_limit,
             reoptimize_imported_views,
             enable_join_prioritize_arranged,
@@ -2355,6 +2475,8 @@ mod tests {
         set_var!(enable_reduce_mfp_fusion);
         set_var!(enable_variadic_left_join_lowering);
         set_var!(enable_cardinality_estimates);
+        set_var!(enable_cost_based_join_ordering);
+        set_var!(enable_native_outer_join);
         set_var!(persist_fast_path_limit);
         let _ = reoptimize_imported_views; // no corresponding var
         set_var!(enable_join_prioritize_arranged);
@@ -35,6 +35,7 @@ use mz_controller_types::dyncfgs::WALLCLOCK_LAG_HISTOGRAM_PERIOD_INTERVAL;
 use mz_dyncfg::ConfigSet;
 use mz_ore::soft_panic_or_log;
 use mz_persist_client::batch::ProtoBatch;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_persist_types::{Codec64, ShardId};
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::timestamp::CheckedTimestamp;
@@ -512,6 +513,21 @@ pub trait StorageController: Debug {
         register_ts: Timestamp,
     ) -> Result<(), StorageError>;
 
+    /// Sets the compression policy of the collection's data shard.
+    ///
+    /// Parts written after the returned future resolves use the given format;
+    /// existing parts are re-encoded as they get compacted. `None` reverts to
+    /// the global default. The future doesn't borrow the controller, so
+    /// callers can wait for it without blocking other work.
+    ///
+    /// Returns [`StorageError::ReadOnly`] if the controller is in read-only
+    /// mode.
+    fn set_collection_compression(
+        &self,
+        id: GlobalId,
+        compression: Option<CompressionFormat>,
+    ) -> Result<BoxFuture<Result<(), StorageError>>, StorageError>;
+
     /// Acquire an immutable reference to the export state, should it exist.
     fn export(&self, id: GlobalId) -> Result<&ExportState, StorageError>;
 
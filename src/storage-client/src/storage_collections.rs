@@ -31,7 +31,7 @@ use mz_ore::{assert_none, instrument, soft_assert_or_log};
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::cfg::USE_CRITICAL_SINCE_SNAPSHOT;
 use mz_persist_client::critical::{Opaque, SinceHandle};
-use mz_persist_client::read::{Cursor, ReadHandle};
+use mz_persist_client::read::{Cursor, LazyPartStats, ReadHandle};
 use mz_persist_client::schema::CaESchema;
 use mz_persist_client::stats::{SnapshotPartsStats, SnapshotStats};
 use mz_persist_client::write::WriteHandle;
@@ -49,6 +49,7 @@ use mz_storage_types::parameters::StorageParameters;
 use mz_storage_types::read_holds::ReadHold;
 use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sources::{GenericSourceConnection, SourceData, SourceEnvelope, Timeline};
+use mz_storage_types::stats::RelationPartStats;
 use mz_storage_types::time_dependence::{TimeDependence, TimeDependenceError};
 use mz_txn_wal::metrics::Metrics as TxnMetrics;
 use mz_txn_wal::txn_read::{DataSnapshot, TxnsRead};
@@ -167,6 +168,18 @@ pub trait StorageCollections: Debug + Sync {
     /// readable `as_of`.
     async fn snapshot_latest(&self, id: GlobalId) -> Result<Vec<Row>, StorageError>;
 
+    /// Returns whether the table `id` might have been written at some time
+    /// after `since`, considering at least all writes at or before `as_of`.
+    ///
+    /// This is answered from the txns shard, without reading the table.
+    /// Conservatively returns true when that's not possible.
+    fn table_written_since(
+        &self,
+        id: GlobalId,
+        since: Timestamp,
+        as_of: Timestamp,
+    ) -> BoxFuture<'static, Result<bool, StorageError>>;
+
     /// Returns a snapshot of the contents of collection `id` at `as_of`.
     fn snapshot_cursor(
         &self,
@@ -174,6 +187,18 @@ pub trait StorageCollections: Debug + Sync {
         as_of: Timestamp,
     ) -> BoxFuture<'static, Result<SnapshotCursor, StorageError>>;
 
+    /// Like [Self::snapshot_cursor], but skips the parts whose statistics
+    /// `should_fetch` rules out.
+    ///
+    /// The cursor can still return updates that `should_fetch` would have
+    /// ruled out, if they share a part with ones it would not have.
+    fn snapshot_cursor_filtered(
+        &self,
+        id: GlobalId,
+        as_of: Timestamp,
+        should_fetch: PartFilter,
+    ) -> BoxFuture<'static, Result<SnapshotCursor, StorageError>>;
+
     /// Generates a snapshot of the contents of collection `id` at `as_of` and
     /// streams out all of the updates in bounded memory.
     ///
@@ -304,6 +329,9 @@ pub trait StorageCollections: Debug + Sync {
 
 /// A cursor over a snapshot, allowing us to read just part of a snapshot in its
 /// consolidated form.
+/// Decides from its statistics whether a part needs to be fetched.
+pub type PartFilter = Arc<dyn Fn(&RelationPartStats) -> bool + Send + Sync>;
+
 pub struct SnapshotCursor {
     // We allocate a temporary read handle for each snapshot, and that handle needs to live at
     // least as long as the cursor itself, which holds part leases. Bundling them together!
@@ -979,6 +1007,75 @@ impl StorageCollectionsImpl {
         Ok(write.shared_upper())
     }
 
+    /// See [StorageCollections::snapshot_cursor_filtered].
+    fn snapshot_cursor_inner(
+        &self,
+        id: GlobalId,
+        as_of: Timestamp,
+        should_fetch: Option<PartFilter>,
+    ) -> BoxFuture<'static, Result<SnapshotCursor, StorageError>> {
+        let metadata = match self.collection_metadata(id) {
+            Ok(metadata) => metadata.clone(),
+            Err(e) => return async { Err(e.into()) }.boxed(),
+        };
+        let txns_read = metadata.txns_shard.as_ref().map(|txns_id| {
+            // Ensure the txn's shard the controller has is the same that this
+            // collection is registered to.
+            assert_eq!(txns_id, self.txns_read.txns_id());
+            self.txns_read.clone()
+        });
+        let persist = Arc::clone(&self.persist);
+
+        // See the comments in Self::snapshot for what's going on here.
+        async move {
+            let metrics = Arc::clone(
+                persist
+                    .open(metadata.persist_location.clone())
+                    .await
+                    .expect("invalid persist usage")
+                    .metrics(),
+            );
+            let name = id.to_string();
+            let desc = metadata.relation_desc.clone();
+            let should_fetch = |stats: Option<&LazyPartStats>| match (&should_fetch, stats) {
+                (Some(should_fetch), Some(stats)) => {
+                    let stats = stats.decode();
+                    let metrics = &metrics.pushdown.part_stats;
+                    should_fetch(&RelationPartStats::new(&name, metrics, &desc, &stats))
+                }
+                _ => true,
+            };
+            let mut handle = Self::read_handle_for_snapshot(persist, &metadata, id).await?;
+            let cursor = match txns_read {
+                None => {
+                    let cursor = handle
+                        .snapshot_cursor(Antichain::from_elem(as_of), should_fetch)
+                        .await
+                        .map_err(|_| StorageError::ReadBeforeSince(id))?;
+                    SnapshotCursor {
+                        _read_handle: handle,
+                        cursor,
+                    }
+                }
+                Some(txns_read) => {
+                    txns_read.update_gt(as_of).await;
+                    let data_snapshot = txns_read.data_snapshot(metadata.data_shard, as_of).await;
+                    let cursor = data_snapshot
+                        .snapshot_cursor(&mut handle, should_fetch)
+                        .await
+                        .map_err(|_| StorageError::ReadBeforeSince(id))?;
+                    SnapshotCursor {
+                        _read_handle: handle,
+                        cursor,
+                    }
+                }
+            };
+
+            Ok(cursor)
+        }
+        .boxed()
+    }
+
     async fn read_handle_for_snapshot(
         persist: Arc<PersistClientCache>,
         metadata: &CollectionMetadata,
@@ -1486,6 +1583,32 @@ impl StorageCollections for StorageCollectionsImpl {
         self.snapshot(id, as_of, &self.txns_read)
     }
 
+    fn table_written_since(
+        &self,
+        id: GlobalId,
+        since: Timestamp,
+        as_of: Timestamp,
+    ) -> BoxFuture<'static, Result<bool, StorageError>> {
+        let metadata = match self.collection_metadata(id) {
+            Ok(metadata) => metadata,
+            Err(e) => return async { Err(e.into()) }.boxed(),
+        };
+        let Some(txns_id) = metadata.txns_shard else {
+            // Not written through txn-wal, so we can't tell.
+            return async { Ok(true) }.boxed();
+        };
+        assert_eq!(txns_id, *self.txns_read.txns_id());
+        let txns_read = self.txns_read.clone();
+        async move {
+            txns_read.update_gt(as_of).await;
+            let written = txns_read
+                .data_written_since(metadata.data_shard, since)
+                .await;
+            Ok(written)
+        }
+        .boxed()
+    }
+
     async fn snapshot_latest(&self, id: GlobalId) -> Result<Vec<Row>, StorageError> {
         let upper = self.recent_upper(id).await?;
         let res = match upper.as_option() {
@@ -1523,49 +1646,16 @@ impl StorageCollections for StorageCollectionsImpl {
         id: GlobalId,
         as_of: Timestamp,
     ) -> BoxFuture<'static, Result<SnapshotCursor, StorageError>> {
-        let metadata = match self.collection_metadata(id) {
-            Ok(metadata) => metadata.clone(),
-            Err(e) => return async { Err(e.into()) }.boxed(),
-        };
-        let txns_read = metadata.txns_shard.as_ref().map(|txns_id| {
-            // Ensure the txn's shard the controller has is the same that this
-            // collection is registered to.
-            assert_eq!(txns_id, self.txns_read.txns_id());
-            self.txns_read.clone()
-        });
-        let persist = Arc::clone(&self.persist);
-
-        // See the comments in Self::snapshot for what's going on here.
-        async move {
-            let mut handle = Self::read_handle_for_snapshot(persist, &metadata, id).await?;
-            let cursor = match txns_read {
-                None => {
-                    let cursor = handle
-                        .snapshot_cursor(Antichain::from_elem(as_of), |_| true)
-                        .await
-                        .map_err(|_| StorageError::ReadBeforeSince(id))?;
-                    SnapshotCursor {
-                        _read_handle: handle,
-                        cursor,
-                    }
-                }
-                Some(txns_read) => {
-                    txns_read.update_gt(as_of).await;
-                    let data_snapshot = txns_read.data_snapshot(metadata.data_shard, as_of).await;
-                    let cursor = data_snapshot
-                        .snapshot_cursor(&mut handle, |_| true)
-                        .await
-                        .map_err(|_| StorageError::ReadBeforeSince(id))?;
-                    SnapshotCursor {
-                        _read_handle: handle,
-                        cursor,
-                    }
-                }
-            };
+        self.snapshot_cursor_inner(id, as_of, None)
+    }
 
-            Ok(cursor)
-        }
-        .boxed()
+    fn snapshot_cursor_filtered(
+        &self,
+        id: GlobalId,
+        as_of: Timestamp,
+        should_fetch: PartFilter,
+    ) -> BoxFuture<'static, Result<SnapshotCursor, StorageError>> {
+        self.snapshot_cursor_inner(id, as_of, Some(should_fetch))
     }
 
     fn snapshot_and_stream(
@@ -49,6 +49,7 @@ use mz_persist_client::schema::CaESchema;
 use mz_persist_client::write::WriteHandle;
 use mz_persist_client::{Diagnostics, PersistClient, PersistLocation, ShardId};
 use mz_persist_types::codec_impls::UnitSchema;
+use mz_persist_types::parquet::CompressionFormat;
 use mz_repr::adt::timestamp::CheckedTimestamp;
 use mz_repr::{Datum, Diff, GlobalId, RelationDesc, RelationVersion, Row, Timestamp};
 use mz_storage_client::client::{
@@ -2407,6 +2408,37 @@ impl StorageController for Controller {
         }
     }
 
+    fn set_collection_compression(
+        &self,
+        id: GlobalId,
+        compression: Option<CompressionFormat>,
+    ) -> Result<BoxFuture<Result<(), StorageError>>, StorageError> {
+        if self.read_only {
+            return Err(StorageError::ReadOnly);
+        }
+        let collection = self.storage_collections.collection_metadata(id)?;
+        let persist = Arc::clone(&self.persist);
+        let future = async move {
+            let client = persist
+                .open(collection.persist_location.clone())
+                .await
+                .map_err(|e| StorageError::Generic(anyhow::anyhow!(e)))?;
+            let diagnostics = Diagnostics {
+                shard_name: id.to_string(),
+                handle_purpose: "set collection compression".to_string(),
+            };
+            client
+                .set_compression::<SourceData, (), Timestamp, StorageDiff>(
+                    collection.data_shard,
+                    compression,
+                    diagnostics,
+                )
+                .await
+                .map_err(|e| StorageError::InvalidUsage(e.to_string()))
+        };
+        Ok(Box::pin(future))
+    }
+
     async fn inspect_persist_state(
         &self,
         id: GlobalId,
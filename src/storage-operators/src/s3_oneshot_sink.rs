@@ -15,17 +15,14 @@ use std::collections::btree_map::Entry;
 use std::rc::Rc;
 
 use anyhow::anyhow;
-use aws_types::sdk_config::SdkConfig;
 use differential_dataflow::Hashable;
 use futures::StreamExt;
 use mz_ore::cast::CastFrom;
 use mz_ore::error::ErrorExt;
-use mz_ore::future::InTask;
 use mz_repr::{CatalogItemId, Diff, GlobalId, Row, Timestamp};
 use mz_storage_types::connections::ConnectionContext;
-use mz_storage_types::connections::aws::AwsConnection;
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sinks::s3_oneshot_sink::S3KeyManager;
+use mz_storage_types::sinks::s3_oneshot_sink::{CopyToConnection, S3KeyManager};
 use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
 use mz_timely_util::builder_async::{
     Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
@@ -41,8 +38,12 @@ use tracing::debug;
 
 mod parquet;
 mod pgcopy;
+mod uploader;
 
-/// Copy the rows from the input collection to s3.
+use uploader::ObjectStoreClient;
+
+/// Copy the rows from the input collection to s3, or to GCS for a GCS
+/// connection.
 /// `worker_callback` is used to send the final count of rows uploaded to s3,
 /// or an error message if the operator failed. This is per-worker, and
 /// these responses are aggregated upstream by the compute client.
@@ -68,7 +69,7 @@ pub fn copy_to<'scope, F>(
     up_to: Antichain<Timestamp>,
     connection_details: S3UploadInfo,
     connection_context: ConnectionContext,
-    aws_connection: AwsConnection,
+    connection: CopyToConnection,
     sink_id: GlobalId,
     connection_id: CatalogItemId,
     params: CopyToParameters,
@@ -89,7 +90,7 @@ where
         S3SinkFormat::PgCopy(_) => render_upload_operator::<pgcopy::PgCopyUploader>(
             scope.clone(),
             connection_context.clone(),
-            aws_connection.clone(),
+            connection.clone(),
             connection_id,
             connection_details,
             sink_id,
@@ -102,7 +103,7 @@ where
         S3SinkFormat::Parquet => render_upload_operator::<parquet::ParquetUploader>(
             scope.clone(),
             connection_context.clone(),
-            aws_connection.clone(),
+            connection.clone(),
             connection_id,
             connection_details,
             sink_id,
@@ -117,7 +118,7 @@ where
     let completion_token = render_completion_operator(
         scope,
         connection_context,
-        aws_connection,
+        connection,
         connection_id,
         sink_id,
         s3_key_manager,
@@ -212,7 +213,7 @@ fn render_initialization_operator<'scope>(
 fn render_completion_operator<'scope, F>(
     scope: Scope<'scope, Timestamp>,
     connection_context: ConnectionContext,
-    aws_connection: AwsConnection,
+    connection: CopyToConnection,
     connection_id: CatalogItemId,
     sink_id: GlobalId,
     s3_key_manager: S3KeyManager,
@@ -250,11 +251,9 @@ where
 
             if is_leader {
                 debug!(%sink_id, %worker_id, "s3 leader worker completion");
-                let sdk_config = aws_connection
-                    .load_sdk_config(&connection_context, connection_id, InTask::Yes)
-                    .await?;
-
-                let client = mz_aws_util::s3::new_client(&sdk_config);
+                let client =
+                    ObjectStoreClient::load(&connection, &connection_context, connection_id)
+                        .await?;
                 let bucket = s3_key_manager.bucket.clone();
                 let incomplete_sentinel_key = s3_key_manager.incomplete_sentinel_key();
 
@@ -266,10 +265,7 @@ where
                 mz_ore::task::spawn(|| "copytos3:completion", async move {
                     debug!(%sink_id, %worker_id, "removing INCOMPLETE sentinel file");
                     client
-                        .delete_object()
-                        .bucket(bucket)
-                        .key(incomplete_sentinel_key)
-                        .send()
+                        .delete_object(bucket, incomplete_sentinel_key)
                         .await?;
                     Ok::<(), anyhow::Error>(())
                 })
@@ -295,7 +291,7 @@ where
 fn render_upload_operator<'scope, T>(
     scope: Scope<'scope, Timestamp>,
     connection_context: ConnectionContext,
-    aws_connection: AwsConnection,
+    connection: CopyToConnection,
     connection_id: CatalogItemId,
     connection_details: S3UploadInfo,
     sink_id: GlobalId,
@@ -345,9 +341,8 @@ where
 
         // fallible async block to use the `?` operator for convenience
         let res = async move {
-            let sdk_config = aws_connection
-                .load_sdk_config(&connection_context, connection_id, InTask::Yes)
-                .await?;
+            let client =
+                ObjectStoreClient::load(&connection, &connection_context, connection_id).await?;
 
             // Map of an uploader per batch.
             let mut s3_uploaders: BTreeMap<u64, T> = BTreeMap::new();
@@ -358,7 +353,7 @@ where
             // if the input is empty. See database-issue#8599.
             if worker_id == 0 {
                 let mut uploader = T::new(
-                    sdk_config.clone(),
+                    client.clone(),
                     connection_details.clone(),
                     &sink_id,
                     0,
@@ -397,7 +392,7 @@ where
                                     Entry::Vacant(entry) => {
                                         debug!(%sink_id, %worker_id, "handling batch: {}", batch);
                                         entry.insert(T::new(
-                                            sdk_config.clone(),
+                                            client.clone(),
                                             connection_details.clone(),
                                             &sink_id,
                                             batch,
@@ -444,6 +439,8 @@ pub struct CopyToParameters {
     // See the `parquet` module for more details on how this is used.
     pub arrow_builder_buffer_ratio: usize,
     // The size of each part in the multi-part upload to use when uploading files to S3.
+    // For GCS this is the chunk size of the resumable upload, rounded down to a
+    // multiple of 256 KiB.
     pub s3_multipart_part_size_bytes: usize,
 }
 
@@ -453,7 +450,7 @@ pub struct CopyToParameters {
 /// format that requires buffering a batch of rows before writing to S3.
 trait CopyToS3Uploader: Sized {
     fn new(
-        sdk_config: SdkConfig,
+        client: ObjectStoreClient,
         connection_details: S3UploadInfo,
         sink_id: &GlobalId,
         batch: u64,
@@ -9,11 +9,7 @@
 
 use std::sync::Arc;
 
-use aws_types::sdk_config::SdkConfig;
 use mz_arrow_util::builder::ArrowBuilder;
-use mz_aws_util::s3_uploader::{
-    AWS_S3_MAX_PART_COUNT, CompletedUpload, S3MultiPartUploader, S3MultiPartUploaderConfig,
-};
 use mz_ore::cast::CastFrom;
 use mz_ore::future::OreFutureExt;
 use mz_repr::{GlobalId, RelationDesc, Row};
@@ -27,6 +23,7 @@ use parquet::{
 };
 use tracing::{debug, info};
 
+use super::uploader::{CompletedUpload, FileUploader, ObjectStoreClient};
 use super::{CopyToParameters, CopyToS3Uploader};
 
 /// Set the default capacity for the array builders inside the ArrowBuilder. This is the
@@ -59,12 +56,12 @@ const DEFAULT_ARRAY_BUILDER_DATA_CAPACITY: usize = 1024;
 ///   ArrowWriter), but we also force it to flush based on data-size (see below for more details).
 ///
 /// - When a row group is written out, the active [`ParquetFile`] provides a reference to the row
-///   group buffer to its [`S3MultiPartUploader`] which will copy the data to its own buffer.
-///   If this upload buffer exceeds the configured part size limit, the [`S3MultiPartUploader`]
-///   will upload parts to S3 until the upload buffer is below the limit.
+///   group buffer to its [`FileUploader`] which will copy the data to its own buffer.
+///   If this upload buffer exceeds the configured part size limit, the [`FileUploader`]
+///   will upload parts until the upload buffer is below the limit.
 ///
 /// - When the [`ParquetUploader`] is finished, it will flush the active [`ParquetFile`] which will
-///   flush its [`ArrowBuilder`] and any open row groups to the [`S3MultiPartUploader`] and upload
+///   flush its [`ArrowBuilder`] and any open row groups to the [`FileUploader`] and upload
 ///   the remaining parts to S3.
 /// ```text
 ///       ┌───────────────┐
@@ -109,7 +106,7 @@ const DEFAULT_ARRAY_BUILDER_DATA_CAPACITY: usize = 1024;
 /// representation and because the data pages within each column in a row-group are compressed.
 /// We also don't know the exact size of the parquet metadata that will be written to the file.
 ///
-/// Therefore we don't use the S3MultiPartUploader's hard file size limit since it's difficult
+/// Therefore we don't use the uploader's hard file size limit since it's difficult
 /// to handle those errors after we've already flushed data to the ArrowWriter. Instead we
 /// implement a crude check ourselves.
 ///
@@ -139,8 +136,8 @@ pub(super) struct ParquetUploader {
     /// The desired file size. A new file upload will be started
     /// when the size exceeds this amount.
     max_file_size: u64,
-    /// The aws sdk config.
-    sdk_config: Arc<SdkConfig>,
+    /// The object store client.
+    client: Arc<ObjectStoreClient>,
     row_group_size_bytes: u64,
     arrow_builder_buffer_bytes: u64,
     /// The active parquet file being written to, stored in an option
@@ -154,7 +151,7 @@ pub(super) struct ParquetUploader {
 
 impl CopyToS3Uploader for ParquetUploader {
     fn new(
-        sdk_config: SdkConfig,
+        client: ObjectStoreClient,
         connection_details: S3UploadInfo,
         sink_id: &GlobalId,
         batch: u64,
@@ -174,7 +171,7 @@ impl CopyToS3Uploader for ParquetUploader {
         match connection_details.format {
             S3SinkFormat::Parquet => Ok(ParquetUploader {
                 desc: Arc::new(connection_details.desc),
-                sdk_config: Arc::new(sdk_config),
+                client: Arc::new(client),
                 key_manager: S3KeyManager::new(sink_id, &connection_details.uri),
                 batch,
                 max_file_size: connection_details.max_file_size,
@@ -241,7 +238,7 @@ impl ParquetUploader {
             bucket,
             object_key,
             Arc::clone(&self.desc),
-            Arc::clone(&self.sdk_config),
+            Arc::clone(&self.client),
             self.arrow_builder_buffer_bytes,
             self.row_group_size_bytes,
             u64::cast_from(self.params.s3_multipart_part_size_bytes),
@@ -254,15 +251,15 @@ impl ParquetUploader {
     }
 }
 
-/// Helper to tie the lifecycle of the `ArrowBuilder`, `ArrowWriter`, and `S3MultiPartUploader`
+/// Helper to tie the lifecycle of the `ArrowBuilder`, `ArrowWriter`, and `FileUploader`
 /// together for a single parquet file.
 struct ParquetFile {
     /// The active arrow builder.
     builder: ArrowBuilder,
     writer: ArrowWriter<Vec<u8>>,
-    // TODO: Consider implementing `tokio::io::AsyncWrite` on `S3MultiPartUploader` which would
+    // TODO: Consider implementing `tokio::io::AsyncWrite` on `FileUploader` which would
     // allow us to write directly to the uploader instead of buffering the data in a vec first.
-    uploader: S3MultiPartUploader,
+    uploader: FileUploader,
     arrow_builder_buffer_bytes: u64,
     row_group_size: u64,
     desc: Arc<RelationDesc>,
@@ -273,7 +270,7 @@ impl ParquetFile {
         bucket: String,
         key: String,
         desc: Arc<RelationDesc>,
-        sdk_config: Arc<SdkConfig>,
+        client: Arc<ObjectStoreClient>,
         arrow_builder_buffer_bytes: u64,
         row_group_size: u64,
         part_size_limit: u64,
@@ -296,22 +293,18 @@ impl ParquetFile {
 
         // TODO: Consider using an lgalloc buffer here instead of a vec
         let writer = ArrowWriter::try_new(Vec::new(), builder.schema().into(), Some(props))?;
-        let uploader = S3MultiPartUploader::try_new(
-            sdk_config.as_ref(),
-            bucket,
-            key,
-            S3MultiPartUploaderConfig {
+        let uploader = client
+            .start_upload(
+                bucket,
+                key,
                 part_size_limit,
                 // We are already enforcing the max size ourselves so we set the max size enforced
                 // by the uploader to the max file size it will allow based on the part size limit.
                 // This is known to be greater than the `MAX_S3_SINK_FILE_SIZE` enforced during
                 // sink creation.
-                file_size_limit: part_size_limit
-                    .checked_mul(AWS_S3_MAX_PART_COUNT.try_into().expect("known safe"))
-                    .expect("known safe"),
-            },
-        )
-        .await?;
+                client.max_file_size(part_size_limit),
+            )
+            .await?;
 
         Ok(Self {
             writer,
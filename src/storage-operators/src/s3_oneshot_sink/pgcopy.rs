@@ -8,10 +8,6 @@
 // by the Apache License, Version 2.0.
 
 use anyhow::anyhow;
-use aws_types::sdk_config::SdkConfig;
-use mz_aws_util::s3_uploader::{
-    CompletedUpload, S3MultiPartUploadError, S3MultiPartUploader, S3MultiPartUploaderConfig,
-};
 use mz_ore::assert_none;
 use mz_ore::cast::CastFrom;
 use mz_pgcopy::{CopyFormatParams, encode_copy_format, encode_copy_format_header};
@@ -20,6 +16,7 @@ use mz_storage_types::sinks::s3_oneshot_sink::S3KeyManager;
 use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
 use tracing::info;
 
+use super::uploader::{CompletedUpload, FileUploader, ObjectStoreClient, UploadError};
 use super::{CopyToParameters, CopyToS3Uploader};
 
 /// Required state to upload batches to S3
@@ -37,20 +34,20 @@ pub(super) struct PgCopyUploader {
     /// The desired file size. A new file upload will be started
     /// when the size exceeds this amount.
     max_file_size: u64,
-    /// The aws sdk config.
+    /// The object store client.
     /// This is an option so that we can get an owned value later to move to a
     /// spawned tokio task.
-    sdk_config: Option<SdkConfig>,
-    /// Multi-part uploader for the current file.
+    client: Option<ObjectStoreClient>,
+    /// Uploader for the current file.
     /// Keeping the uploader in an `Option` to later take owned value.
-    current_file_uploader: Option<S3MultiPartUploader>,
+    current_file_uploader: Option<FileUploader>,
     /// Upload parameters.
     params: CopyToParameters,
 }
 
 impl CopyToS3Uploader for PgCopyUploader {
     fn new(
-        sdk_config: SdkConfig,
+        client: ObjectStoreClient,
         connection_details: S3UploadInfo,
         sink_id: &GlobalId,
         batch: u64,
@@ -59,7 +56,7 @@ impl CopyToS3Uploader for PgCopyUploader {
         match connection_details.format {
             S3SinkFormat::PgCopy(format_params) => Ok(PgCopyUploader {
                 desc: connection_details.desc,
-                sdk_config: Some(sdk_config),
+                client: Some(client),
                 format: format_params,
                 key_manager: S3KeyManager::new(sink_id, &connection_details.uri),
                 batch,
@@ -93,8 +90,8 @@ impl CopyToS3Uploader for PgCopyUploader {
     }
 
     /// Appends the row to the in-progress upload where it is buffered till it reaches the configured
-    /// `part_size_limit` after which the `S3MultiPartUploader` will upload that part. In case it will
-    /// exceed the max file size of the ongoing upload, then a new `S3MultiPartUploader` for a new file will
+    /// `part_size_limit` after which the `FileUploader` will upload that part. In case it will
+    /// exceed the max file size of the ongoing upload, then a new `FileUploader` for a new file will
     /// be created and the row data will be appended there.
     async fn append_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
         let mut buf: Vec<u8> = vec![];
@@ -109,7 +106,7 @@ impl CopyToS3Uploader for PgCopyUploader {
 
         match uploader.buffer_chunk(&buf) {
             Ok(_) => Ok(()),
-            Err(S3MultiPartUploadError::UploadExceedsMaxFileLimit(_)) => {
+            Err(UploadError::UploadExceedsMaxFileLimit(_)) => {
                 // Start a multi part upload of next file.
                 self.start_new_file_upload().await?;
                 uploader = self.current_file_uploader.as_mut().expect("known exists");
@@ -137,28 +134,18 @@ impl PgCopyUploader {
                 .data_key(self.batch, self.file_index, self.format.file_extension());
         let bucket = self.key_manager.bucket.clone();
         info!("starting upload: bucket {}, key {}", &bucket, &object_key);
-        let sdk_config = self
-            .sdk_config
-            .take()
-            .expect("sdk_config should always be present");
+        let client = self.client.take().expect("client should always be present");
         let max_file_size = self.max_file_size;
-        // Moving the aws s3 calls onto tokio tasks instead of using timely runtime.
+        // Moving the object store calls onto tokio tasks instead of using timely runtime.
         let part_size_limit = u64::cast_from(self.params.s3_multipart_part_size_bytes);
         let handle = mz_ore::task::spawn(|| "s3_uploader::try_new", async move {
-            let uploader = S3MultiPartUploader::try_new(
-                &sdk_config,
-                bucket,
-                object_key,
-                S3MultiPartUploaderConfig {
-                    part_size_limit,
-                    file_size_limit: max_file_size,
-                },
-            )
-            .await;
-            (uploader, sdk_config)
+            let uploader = client
+                .start_upload(bucket, object_key, part_size_limit, max_file_size)
+                .await;
+            (uploader, client)
         });
-        let (uploader, sdk_config) = handle.await;
-        self.sdk_config = Some(sdk_config);
+        let (uploader, client) = handle.await;
+        self.client = Some(client);
         let mut uploader = uploader?;
         if self.format.requires_header() {
             let mut buf: Vec<u8> = vec![];
@@ -188,6 +175,7 @@ impl PgCopyUploader {
 #[cfg(test)]
 mod tests {
     use bytesize::ByteSize;
+    use mz_gcp_util::gcs::{GcsClient, GcsCredentials};
     use mz_pgcopy::CopyFormatParams;
     use mz_repr::{ColumnName, Datum, SqlColumnType, SqlRelationType};
     use uuid::Uuid;
@@ -229,7 +217,7 @@ mod tests {
         let column_names = vec![ColumnName::from("col1")];
         let desc = RelationDesc::new(typ, column_names.into_iter());
         let mut uploader = PgCopyUploader::new(
-            sdk_config.clone(),
+            ObjectStoreClient::S3(sdk_config.clone()),
             S3UploadInfo {
                 uri: format!("s3://{}/{}", bucket, path),
                 // this is only for testing, users will not be able to set value smaller than 16MB.
@@ -294,4 +282,85 @@ mod tests {
 
         Ok(())
     }
+
+    /// Runs against `fake-gcs-server` when `MZ_GCS_UPLOADER_TEST_ENDPOINT` is
+    /// set, as it is in `ci/test/cargo-test/mzcompose.py`.
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    async fn test_multiple_files_gcs() -> Result<(), anyhow::Error> {
+        let endpoint = match std::env::var("MZ_GCS_UPLOADER_TEST_ENDPOINT") {
+            Ok(endpoint) => endpoint,
+            Err(_) => {
+                if mz_ore::env::is_var_truthy("CI") {
+                    panic!("CI is supposed to run this test but something has gone wrong!");
+                }
+                return Ok(());
+            }
+        };
+        let client = GcsClient::new(
+            Some(endpoint),
+            GcsCredentials::Anonymous,
+            reqwest::ClientBuilder::new(),
+        )
+        .await?;
+        let bucket = "mz-test-copy-to-gcs";
+        // The bucket will already exist on repeat runs.
+        let _ = client.create_bucket("test", bucket).await;
+        let path = format!("cargo_test/{}/file", Uuid::new_v4());
+
+        let sink_id = GlobalId::User(123);
+        let batch = 456;
+        let typ: SqlRelationType = SqlRelationType::new(vec![SqlColumnType {
+            scalar_type: mz_repr::SqlScalarType::String,
+            nullable: true,
+        }]);
+        let column_names = vec![ColumnName::from("col1")];
+        let desc = RelationDesc::new(typ, column_names.into_iter());
+        let mut uploader = PgCopyUploader::new(
+            ObjectStoreClient::Gcs(client.clone()),
+            S3UploadInfo {
+                uri: format!("gs://{}/{}", bucket, path),
+                // this is only for testing, users will not be able to set value smaller than 16MB.
+                max_file_size: ByteSize::b(6).as_u64(),
+                desc,
+                format: S3SinkFormat::PgCopy(CopyFormatParams::Csv(Default::default())),
+            },
+            &sink_id,
+            batch,
+            CopyToParameters {
+                s3_multipart_part_size_bytes: 10 * 1024 * 1024,
+                arrow_builder_buffer_ratio: 100,
+                parquet_row_group_ratio: 100,
+            },
+        )?;
+        let mut row = Row::default();
+        // Even though this will exceed max_file_size, it should be successfully uploaded in a single file.
+        row.packer().push(Datum::from("1234567"));
+        uploader.append_row(&row).await?;
+
+        // Since the max_file_size is 6B, this row will be uploaded to a new file.
+        row.packer().push(Datum::Null);
+        uploader.append_row(&row).await?;
+
+        row.packer().push(Datum::from("5678"));
+        uploader.append_row(&row).await?;
+
+        uploader.finish().await?;
+
+        for (file_index, expected_body) in [(1, &b"1234567\n"[..]), (2, &b"\n5678\n"[..])] {
+            let name = format!(
+                "{}/mz-{}-batch-{:04}-{:04}.csv",
+                path, sink_id, batch, file_index
+            );
+            let body = client
+                .get_object(bucket, &name)
+                .await?
+                .expect("file exists")
+                .bytes()
+                .await?;
+            assert_eq!(body, *expected_body);
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,217 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Abstracts over the object stores the copy to operator can write to, so the
+//! format-specific uploaders don't need to care whether they write to S3 or GCS.
+
+use aws_types::sdk_config::SdkConfig;
+use mz_aws_util::s3_uploader::{
+    AWS_S3_MAX_PART_COUNT, S3MultiPartUploadError, S3MultiPartUploader, S3MultiPartUploaderConfig,
+};
+use mz_gcp_util::gcs::{GcsClient, Preconditions};
+use mz_gcp_util::gcs_uploader::{
+    GCS_MAX_OBJECT_SIZE, GcsResumableUploader, GcsResumableUploaderConfig, GcsUploadError,
+};
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_repr::CatalogItemId;
+use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::sinks::s3_oneshot_sink::CopyToConnection;
+
+/// A client for the object store named by a [`CopyToConnection`].
+#[derive(Clone, Debug)]
+pub(super) enum ObjectStoreClient {
+    S3(SdkConfig),
+    Gcs(GcsClient),
+}
+
+impl ObjectStoreClient {
+    /// Loads the client for `connection`.
+    pub(super) async fn load(
+        connection: &CopyToConnection,
+        connection_context: &ConnectionContext,
+        connection_id: CatalogItemId,
+    ) -> Result<Self, anyhow::Error> {
+        match connection {
+            CopyToConnection::Aws(aws_connection) => {
+                let sdk_config = aws_connection
+                    .load_sdk_config(connection_context, connection_id, InTask::Yes)
+                    .await?;
+                Ok(ObjectStoreClient::S3(sdk_config))
+            }
+            CopyToConnection::Gcs(gcs_connection) => {
+                let client = gcs_connection
+                    .load_client(connection_context, connection_id, InTask::Yes)
+                    .await?;
+                Ok(ObjectStoreClient::Gcs(client))
+            }
+        }
+    }
+
+    /// The largest file size the uploader can enforce for the given part size.
+    pub(super) fn max_file_size(&self, part_size_limit: u64) -> u64 {
+        match self {
+            ObjectStoreClient::S3(_) => part_size_limit
+                .checked_mul(AWS_S3_MAX_PART_COUNT.try_into().expect("known safe"))
+                .expect("known safe"),
+            ObjectStoreClient::Gcs(_) => GCS_MAX_OBJECT_SIZE.as_u64(),
+        }
+    }
+
+    /// Starts the upload of a single file.
+    pub(super) async fn start_upload(
+        &self,
+        bucket: String,
+        key: String,
+        part_size_limit: u64,
+        file_size_limit: u64,
+    ) -> Result<FileUploader, anyhow::Error> {
+        match self {
+            ObjectStoreClient::S3(sdk_config) => {
+                let uploader = S3MultiPartUploader::try_new(
+                    sdk_config,
+                    bucket,
+                    key,
+                    S3MultiPartUploaderConfig {
+                        part_size_limit,
+                        file_size_limit,
+                    },
+                )
+                .await?;
+                Ok(FileUploader::S3(uploader))
+            }
+            ObjectStoreClient::Gcs(client) => {
+                let uploader = GcsResumableUploader::try_new(
+                    client,
+                    bucket,
+                    key,
+                    GcsResumableUploaderConfig {
+                        part_size_limit,
+                        file_size_limit,
+                    },
+                )
+                .await?;
+                Ok(FileUploader::Gcs(uploader))
+            }
+        }
+    }
+
+    /// Deletes the object at `key`, succeeding if it doesn't exist.
+    pub(super) async fn delete_object(
+        &self,
+        bucket: String,
+        key: String,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            ObjectStoreClient::S3(sdk_config) => {
+                let client = mz_aws_util::s3::new_client(sdk_config);
+                client
+                    .delete_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .send()
+                    .await?;
+            }
+            ObjectStoreClient::Gcs(client) => {
+                client
+                    .delete_object(&bucket, &key, Preconditions::NONE)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Information about a completed upload after `finish` is called.
+#[derive(Debug)]
+pub(super) struct CompletedUpload {
+    pub part_count: u32,
+    pub total_bytes_uploaded: u64,
+    pub bucket: String,
+    pub key: String,
+}
+
+/// Uploads a single file to S3 or GCS.
+#[derive(Debug)]
+pub(super) enum FileUploader {
+    S3(S3MultiPartUploader),
+    Gcs(GcsResumableUploader),
+}
+
+impl FileUploader {
+    /// Adds `data` to the upload, sending parts as they fill up.
+    pub(super) fn buffer_chunk(&mut self, data: &[u8]) -> Result<(), UploadError> {
+        match self {
+            FileUploader::S3(uploader) => Ok(uploader.buffer_chunk(data)?),
+            FileUploader::Gcs(uploader) => Ok(uploader.buffer_chunk(data)?),
+        }
+    }
+
+    /// Uploads any remaining buffered data and completes the upload.
+    pub(super) async fn finish(self) -> Result<CompletedUpload, UploadError> {
+        match self {
+            FileUploader::S3(uploader) => {
+                let res = uploader.finish().await?;
+                Ok(CompletedUpload {
+                    part_count: res.part_count,
+                    total_bytes_uploaded: res.total_bytes_uploaded,
+                    bucket: res.bucket,
+                    key: res.key,
+                })
+            }
+            FileUploader::Gcs(uploader) => {
+                let res = uploader.finish().await?;
+                Ok(CompletedUpload {
+                    part_count: res.chunk_count,
+                    total_bytes_uploaded: res.total_bytes_uploaded,
+                    bucket: res.bucket,
+                    key: res.name,
+                })
+            }
+        }
+    }
+
+    /// Returns the number of bytes added till now.
+    pub(super) fn added_bytes(&self) -> u64 {
+        match self {
+            FileUploader::S3(uploader) => uploader.added_bytes(),
+            FileUploader::Gcs(uploader) => uploader.added_bytes(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(super) enum UploadError {
+    #[error("upload will exceed configured file_size_limit: {} bytes", .0)]
+    UploadExceedsMaxFileLimit(u64),
+    #[error("{}", .0.display_with_causes())]
+    Other(#[from] anyhow::Error),
+}
+
+impl From<S3MultiPartUploadError> for UploadError {
+    fn from(err: S3MultiPartUploadError) -> Self {
+        match err {
+            S3MultiPartUploadError::UploadExceedsMaxFileLimit(limit) => {
+                UploadError::UploadExceedsMaxFileLimit(limit)
+            }
+            err => UploadError::Other(err.into()),
+        }
+    }
+}
+
+impl From<GcsUploadError> for UploadError {
+    fn from(err: GcsUploadError) -> Self {
+        match err {
+            GcsUploadError::UploadExceedsMaxFileLimit(limit) => {
+                UploadError::UploadExceedsMaxFileLimit(limit)
+            }
+            err => UploadError::Other(err.into()),
+        }
+    }
+}
@@ -61,6 +61,7 @@ use crate::configuration::StorageConfiguration;
 use crate::connections::aws::{
     AwsAuth, AwsConnection, AwsConnectionReference, AwsConnectionValidationError,
 };
+use crate::connections::gcs::GcsConnection;
 use crate::connections::string_or_secret::StringOrSecret;
 use crate::controller::AlterError;
 use crate::dyncfgs::{
@@ -71,6 +72,7 @@ use crate::dyncfgs::{
 use crate::errors::{ContextCreationError, CsrConnectError};
 
 pub mod aws;
+pub mod gcs;
 pub mod inline;
 pub mod string_or_secret;
 
@@ -259,6 +261,7 @@ pub enum Connection<C: ConnectionAccess = InlinedConnection> {
     MySql(MySqlConnection<C>),
     SqlServer(SqlServerConnectionDetails<C>),
     IcebergCatalog(IcebergCatalogConnection<C>),
+    Gcs(GcsConnection),
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<Connection, R>
@@ -279,6 +282,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<Connection, R>
             Connection::IcebergCatalog(iceberg) => {
                 Connection::IcebergCatalog(iceberg.into_inline_connection(r))
             }
+            Connection::Gcs(gcs) => Connection::Gcs(gcs),
         }
     }
 }
@@ -296,6 +300,7 @@ impl<C: ConnectionAccess> Connection<C> {
             Connection::MySql(conn) => conn.validate_by_default(),
             Connection::SqlServer(conn) => conn.validate_by_default(),
             Connection::IcebergCatalog(conn) => conn.validate_by_default(),
+            Connection::Gcs(conn) => conn.validate_by_default(),
         }
     }
 }
@@ -323,6 +328,7 @@ impl Connection<InlinedConnection> {
                 conn.validate(id, storage_configuration).await?;
             }
             Connection::IcebergCatalog(conn) => conn.validate(id, storage_configuration).await?,
+            Connection::Gcs(conn) => conn.validate(id, storage_configuration).await?,
         }
         Ok(())
     }
@@ -376,6 +382,13 @@ impl Connection<InlinedConnection> {
         }
     }
 
+    pub fn unwrap_gcs(self) -> GcsConnection {
+        match self {
+            Self::Gcs(conn) => conn,
+            o => unreachable!("{o:?} is not a GCS connection"),
+        }
+    }
+
     pub fn unwrap_iceberg_catalog(self) -> <InlinedConnection as ConnectionAccess>::IcebergCatalog {
         match self {
             Self::IcebergCatalog(conn) => conn,
@@ -433,6 +446,7 @@ impl<C: ConnectionAccess> AlterCompatible for Connection<C> {
             (Self::Kafka(s), Self::Kafka(o)) => s.alter_compatible(id, o),
             (Self::Postgres(s), Self::Postgres(o)) => s.alter_compatible(id, o),
             (Self::MySql(s), Self::MySql(o)) => s.alter_compatible(id, o),
+            (Self::Gcs(s), Self::Gcs(o)) => s.alter_compatible(id, o),
             _ => {
                 tracing::warn!(
                     "Connection incompatible:\nself:\n{:#?}\n\nother\n{:#?}",
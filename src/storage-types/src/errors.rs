@@ -873,6 +873,7 @@ mod columnation {
                         EvalError::RedactError(x) => {
                             EvalError::RedactError(self.string_region.copy(x))
                         }
+                        EvalError::WasmError(x) => EvalError::WasmError(self.string_region.copy(x)),
                     };
                     let reference = self.eval_error_region.copy_iter(once(err));
                     let boxed = unsafe { Box::from_raw(reference.as_mut_ptr()) };
@@ -10,15 +10,28 @@
 use std::str::FromStr;
 
 use anyhow::anyhow;
+use bytes::Bytes;
 use http::Uri;
+use mz_gcp_util::gcs::Preconditions;
 use mz_ore::future::InTask;
 use mz_repr::{CatalogItemId, GlobalId};
+use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
 use crate::connections::ConnectionContext;
 use crate::connections::aws::AwsConnection;
+use crate::connections::gcs::GcsConnection;
 use crate::sinks::S3UploadInfo;
 
+/// The object store that a oneshot copy to operation writes to.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum CopyToConnection {
+    /// S3, or any other store speaking the S3 API.
+    Aws(AwsConnection),
+    /// Google Cloud Storage, using its native JSON API.
+    Gcs(GcsConnection),
+}
+
 /// Performs preflight checks for a copy to operation.
 ///
 /// Checks the S3 path for the sink to ensure it's empty (aside from files
@@ -34,6 +47,37 @@ use crate::sinks::S3UploadInfo;
 /// having to listen for a single event (a PutObject sentinel would trigger once
 /// for each replica).
 pub async fn preflight(
+    connection_context: ConnectionContext,
+    connection: &CopyToConnection,
+    connection_details: &S3UploadInfo,
+    connection_id: CatalogItemId,
+    sink_id: GlobalId,
+) -> Result<(), anyhow::Error> {
+    match connection {
+        CopyToConnection::Aws(aws_connection) => {
+            preflight_s3(
+                connection_context,
+                aws_connection,
+                connection_details,
+                connection_id,
+                sink_id,
+            )
+            .await
+        }
+        CopyToConnection::Gcs(gcs_connection) => {
+            preflight_gcs(
+                connection_context,
+                gcs_connection,
+                connection_details,
+                connection_id,
+                sink_id,
+            )
+            .await
+        }
+    }
+}
+
+async fn preflight_s3(
     connection_context: ConnectionContext,
     aws_connection: &AwsConnection,
     connection_details: &S3UploadInfo,
@@ -103,8 +147,73 @@ pub async fn preflight(
     Ok::<(), anyhow::Error>(())
 }
 
-/// Helper to manage object keys created by this sink based on the S3 URI provided
-/// by the user and the GlobalId that identifies this copy-to-s3 sink.
+/// The GCS counterpart of [`preflight_s3`], performing the same checks through
+/// the native GCS API.
+async fn preflight_gcs(
+    connection_context: ConnectionContext,
+    gcs_connection: &GcsConnection,
+    connection_details: &S3UploadInfo,
+    connection_id: CatalogItemId,
+    sink_id: GlobalId,
+) -> Result<(), anyhow::Error> {
+    info!(%sink_id, "gcs copy to initialization");
+
+    let key_manager = S3KeyManager::new(&sink_id, &connection_details.uri);
+
+    let client = gcs_connection
+        .load_client(&connection_context, connection_id, InTask::Yes)
+        .await?;
+    let bucket = key_manager.bucket.clone();
+    let path_prefix = key_manager.path_prefix().to_string();
+
+    let existing = client
+        .list_objects(&bucket, &path_prefix, false, None)
+        .await?;
+    if !existing.items.is_empty() {
+        Err(anyhow!(
+            "GCS bucket path is not empty, contains {}{} objects",
+            existing.items.len(),
+            if existing.next_page_token.is_some() {
+                " or more"
+            } else {
+                ""
+            }
+        ))?;
+    }
+
+    // Confirm we have delete permissions before proceeding by trying to delete
+    // a known non-existent object. GCS reports a missing object as a 404, which
+    // the client maps to `false`, and missing permissions as a 403.
+    match client
+        .delete_object(
+            &bucket,
+            &key_manager.data_key(0, 0, "delete_object_test"),
+            Preconditions::NONE,
+        )
+        .await
+    {
+        Ok(_) => {}
+        Err(err) if err.status() == Some(reqwest::StatusCode::FORBIDDEN) => {
+            Err(anyhow!("AccessDenied error when deleting objects: {}", err))?
+        }
+        Err(err) => Err(anyhow!("Error when deleting objects: {}", err))?,
+    }
+
+    debug!(%sink_id, "uploading INCOMPLETE sentinel file");
+    client
+        .insert_object(
+            &bucket,
+            &key_manager.incomplete_sentinel_key(),
+            Bytes::new(),
+            Preconditions::NONE,
+        )
+        .await?;
+
+    Ok::<(), anyhow::Error>(())
+}
+
+/// Helper to manage object keys created by this sink based on the S3 (or GCS) URI
+/// provided by the user and the GlobalId that identifies this copy-to-s3 sink.
 /// Since there may be multiple compute replicas running their own copy of this sink
 /// we need to ensure the S3 keys are consistent such that we can detect when objects
 /// were created by an instance of this sink or not.
@@ -1536,6 +1536,7 @@ impl ColumnDecoder<SourceData> for SourceDataColumnarDecoder {
             SourceDataRowColumnarDecoder::EmptyRow => StructStats {
                 len,
                 cols: BTreeMap::default(),
+                filters: BTreeMap::default(),
             },
         };
         let row_stats = ColumnarStats {
@@ -1558,6 +1559,20 @@ impl ColumnDecoder<SourceData> for SourceDataColumnarDecoder {
         StructStats {
             len,
             cols: stats.into_iter().map(|(name, s)| (name, s)).collect(),
+            filters: BTreeMap::default(),
+        }
+    }
+
+    fn add_filters(&self, stats: &mut StructStats, max_bytes: usize) {
+        let SourceDataRowColumnarDecoder::Row(decoder) = &self.row_decoder else {
+            return;
+        };
+        let ok_stats = stats
+            .cols
+            .get_mut(SourceDataColumnarEncoder::OK_COLUMN_NAME)
+            .map(|col| &mut col.values);
+        if let Some(ColumnStatKinds::Struct(ok_stats)) = ok_stats {
+            decoder.add_filters(ok_stats, max_bytes);
         }
     }
 }
@@ -9,7 +9,9 @@
 
 //! Types and traits that connect up our mz-repr types with the stats that persist maintains.
 
-use mz_expr::{ColumnSpecs, Interpreter, MapFilterProject, ResultSpec, UnmaterializableFunc};
+use mz_expr::{
+    ColumnSpecs, Interpreter, MapFilterProject, MirScalarExpr, ResultSpec, UnmaterializableFunc,
+};
 use mz_persist_types::stats::{
     BytesStats, ColumnStatKinds, JsonStats, PartStats, PartStatsMetrics,
 };
@@ -60,7 +62,50 @@ impl RelationPartStats<'_> {
             ranges.push_column(pos, result_spec);
         }
         let result = ranges.mfp_filter(mfp).range;
-        result.may_contain(Datum::True) || result.may_fail()
+        if result.may_fail() {
+            return true;
+        }
+        if !result.may_contain(Datum::True) {
+            return false;
+        }
+        // The ranges can't rule out a point lookup on a column with many
+        // distinct values, but the part's membership filters might.
+        !self.filters_exclude(mfp)
+    }
+
+    /// Returns true if one of the predicates of `mfp` is an equality between a
+    /// column and a literal that the membership filters for this part prove no
+    /// row can satisfy.
+    fn filters_exclude(&self, mfp: &MapFilterProject) -> bool {
+        let Some(ok_stats) = self.stats.key.cols.get("ok") else {
+            return false;
+        };
+        let ColumnStatKinds::Struct(ok_stats) = &ok_stats.values else {
+            panic!("'ok' column stats should be a struct")
+        };
+        if ok_stats.filters.is_empty() {
+            return false;
+        }
+
+        for (pos, (_idx, name, typ)) in self.desc.iter_all().enumerate() {
+            let Some(filter) = ok_stats.filters.get(name.as_str()) else {
+                continue;
+            };
+            let column = MirScalarExpr::column(pos);
+            for (_, predicate) in mfp.predicates.iter() {
+                let Some((literal, _inverted)) = predicate.expr_eq_literal(&column) else {
+                    continue;
+                };
+                let datum = literal.unpack_first();
+                let Some(key) = mz_repr::stats::filter_key(datum, &typ.scalar_type) else {
+                    continue;
+                };
+                if !filter.may_contain(&key) {
+                    return true;
+                }
+            }
+        }
+        false
     }
 
     fn json_spec<'a>(len: usize, stats: &'a JsonStats, arena: &'a RowArena) -> ResultSpec<'a> {
@@ -193,6 +238,46 @@ impl RelationPartStats<'_> {
         num_oks.map(|num_oks| num_results - num_oks)
     }
 
+    /// Estimates the number of distinct non-null values of a column in this part.
+    ///
+    /// This uses the membership filter of the column if the part has one, and otherwise the
+    /// width of the value range of integer columns.
+    pub fn col_distinct_count(&self, idx: &ColumnIndex) -> Option<usize> {
+        let name = self.desc.get_name_idx(idx);
+        let typ = self.desc.get_type(idx);
+
+        let ok_stats = self.stats.key.cols.get("ok")?;
+        let ColumnStatKinds::Struct(ok_stats) = &ok_stats.values else {
+            panic!("'ok' column stats should be a struct")
+        };
+        let col_stats = ok_stats.cols.get(name.as_str())?;
+        let null_count = col_stats.nulls.as_ref().map_or(0, |nulls| nulls.count);
+        let non_null_count = self.len()?.saturating_sub(null_count);
+        if non_null_count == 0 {
+            return Some(0);
+        }
+
+        let estimate = if let Some(filter) = ok_stats.filters.get(name.as_str()) {
+            filter.estimated_len()?
+        } else {
+            let arena = RowArena::new();
+            let (min, max) =
+                mz_repr::stats::col_values(&typ.scalar_type, &col_stats.values, &arena)?;
+            let int = |datum: Datum| match datum {
+                Datum::Int16(x) => Some(i128::from(x)),
+                Datum::Int32(x) => Some(i128::from(x)),
+                Datum::Int64(x) => Some(i128::from(x)),
+                Datum::UInt16(x) => Some(i128::from(x)),
+                Datum::UInt32(x) => Some(i128::from(x)),
+                Datum::UInt64(x) => Some(i128::from(x)),
+                _ => None,
+            };
+            let width = int(max)? - int(min)?;
+            usize::try_from(width).ok()?.saturating_add(1)
+        };
+        Some(estimate.min(non_null_count))
+    }
+
     fn col_values<'a>(&'a self, idx: &ColumnIndex, arena: &'a RowArena) -> Option<ResultSpec<'a>> {
         let name = self.desc.get_name_idx(idx);
         let typ = self.desc.get_type(idx);
@@ -225,12 +310,15 @@ impl RelationPartStats<'_> {
 #[cfg(test)]
 mod tests {
     use arrow::array::AsArray;
+    use mz_expr::func;
     use mz_ore::metrics::MetricsRegistry;
     use mz_persist_types::codec_impls::UnitSchema;
     use mz_persist_types::columnar::{ColumnDecoder, Schema};
     use mz_persist_types::part::PartBuilder;
     use mz_persist_types::stats::PartStats;
-    use mz_repr::{Datum, RelationDesc, Row, RowArena, SqlColumnType, SqlScalarType};
+    use mz_repr::{
+        Datum, RelationDesc, ReprScalarType, Row, RowArena, SqlColumnType, SqlScalarType,
+    };
     use mz_repr::{SqlRelationType, arb_datum_for_column};
     use proptest::prelude::*;
     use proptest::strategy::ValueTree;
@@ -274,6 +362,105 @@ mod tests {
         Ok(())
     }
 
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // slow
+    fn filters_point_lookup() {
+        let desc = RelationDesc::builder()
+            .with_column("id", SqlScalarType::Int64.nullable(false))
+            .with_column("val", SqlScalarType::String.nullable(true))
+            .with_key(vec![0])
+            .finish();
+
+        let mut builder = PartBuilder::new(&desc, &UnitSchema);
+        for id in (0..100).map(|x| x * 10) {
+            let row = Row::pack_slice(&[Datum::Int64(id), Datum::String("a")]);
+            builder.push(&SourceData(Ok(row)), &(), 1u64, 1i64);
+        }
+        let part = builder.finish();
+
+        let key_col = part.key.as_struct();
+        let decoder =
+            <RelationDesc as Schema<SourceData>>::decoder(&desc, key_col.clone()).expect("success");
+        let mut key_stats = decoder.stats();
+        decoder.add_filters(&mut key_stats, 1024);
+
+        let metrics = PartStatsMetrics::new(&MetricsRegistry::new());
+        let stats = RelationPartStats {
+            name: "test",
+            metrics: &metrics,
+            stats: &PartStats { key: key_stats },
+            desc: &desc,
+        };
+        let may_match = |id: i64| {
+            let literal = MirScalarExpr::literal_ok(Datum::Int64(id), ReprScalarType::Int64);
+            let predicate = MirScalarExpr::column(0).call_binary(literal, func::Eq);
+            let mfp = MapFilterProject::new(desc.arity()).filter([predicate]);
+            stats.may_match_mfp(ResultSpec::anything(), &mfp)
+        };
+
+        // Every present key must match.
+        for id in (0..100).map(|x| x * 10) {
+            assert!(may_match(id));
+        }
+        // Absent keys inside the min/max range can't be ruled out by the
+        // ranges, but the filter catches all but the occasional false positive.
+        let absent: Vec<_> = (0..990).filter(|x| x % 10 != 0).collect();
+        let matched = absent.iter().filter(|id| may_match(**id)).count();
+        assert!(matched < absent.len() / 10, "{matched}");
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // slow
+    fn distinct_count_estimates() {
+        let desc = RelationDesc::builder()
+            .with_column("id", SqlScalarType::Int64.nullable(false))
+            .with_column("grp", SqlScalarType::Int32.nullable(true))
+            .with_column("val", SqlScalarType::String.nullable(true))
+            .with_key(vec![0])
+            .finish();
+
+        let mut builder = PartBuilder::new(&desc, &UnitSchema);
+        for id in 0..1000 {
+            let grp = if id % 2 == 0 {
+                Datum::Int32(id % 50)
+            } else {
+                Datum::Null
+            };
+            let row = Row::pack_slice(&[Datum::Int64(id * 10), grp, Datum::String("a")]);
+            builder.push(&SourceData(Ok(row)), &(), 1u64, 1i64);
+        }
+        let part = builder.finish();
+
+        let key_col = part.key.as_struct();
+        let decoder =
+            <RelationDesc as Schema<SourceData>>::decoder(&desc, key_col.clone()).expect("success");
+        let mut key_stats = decoder.stats();
+        decoder.add_filters(&mut key_stats, 4096);
+
+        let metrics = PartStatsMetrics::new(&MetricsRegistry::new());
+        let stats = RelationPartStats {
+            name: "test",
+            metrics: &metrics,
+            stats: &PartStats { key: key_stats },
+            desc: &desc,
+        };
+        let distinct_count = |name: &str| {
+            let (idx, _, _) = desc
+                .iter_all()
+                .find(|(_, col_name, _)| col_name.as_str() == name)
+                .expect("column exists");
+            stats.col_distinct_count(idx)
+        };
+
+        // The key column has a membership filter, which counts within a few percent.
+        let id = distinct_count("id").expect("estimate");
+        assert!((950..=1000).contains(&id), "{id}");
+        // The value range of an integer column bounds its distinct values.
+        assert_eq!(distinct_count("grp"), Some(49));
+        // Without a filter, we know nothing about other columns.
+        assert_eq!(distinct_count("val"), None);
+    }
+
     fn scalar_type_stats_roundtrip(scalar_type: SqlScalarType) {
         // Non-nullable version of the column.
         let column_type = scalar_type.clone().nullable(false);
@@ -10,7 +10,7 @@
 //! Unified configuration for timestamp oracles.
 //!
 //! This module provides a [`TimestampOracleConfig`] enum that can hold
-//! configuration for either a Postgres-backed or FoundationDB-backed
+//! configuration for a Postgres-backed, FoundationDB-backed, or SQLite-backed
 //! timestamp oracle, allowing the choice of backend to be made at startup time.
 
 use std::sync::Arc;
@@ -27,6 +27,7 @@ use crate::metrics::Metrics;
 use crate::postgres_oracle::{
     PostgresTimestampOracle, PostgresTimestampOracleConfig, TimestampOracleParameters,
 };
+use crate::sqlite_oracle::{SqliteTimestampOracle, SqliteTimestampOracleConfig};
 
 /// Unified configuration for timestamp oracles.
 ///
@@ -39,6 +40,8 @@ pub enum TimestampOracleConfig {
     /// Use a FoundationDB-backed timestamp oracle.
     #[cfg(feature = "foundationdb")]
     Fdb(FdbTimestampOracleConfig),
+    /// Use an embedded SQLite-backed timestamp oracle.
+    Sqlite(SqliteTimestampOracleConfig),
 }
 
 impl TimestampOracleConfig {
@@ -47,6 +50,7 @@ impl TimestampOracleConfig {
     /// The backend is determined by the URL scheme:
     /// - `postgres://` or `postgresql://` -> Postgres-backed oracle
     /// - `foundationdb://` -> FoundationDB-backed oracle
+    /// - `sqlite://` -> SQLite-backed oracle
     ///
     /// Returns an error if the URL scheme is not recognized.
     pub fn from_url(
@@ -62,10 +66,13 @@ impl TimestampOracleConfig {
             "foundationdb" => {
                 anyhow::bail!("FoundationDB timestamp oracle is not supported on this platform")
             }
+            "sqlite" => Ok(TimestampOracleConfig::Sqlite(
+                SqliteTimestampOracleConfig::new(url, metrics_registry)?,
+            )),
             _ => {
                 anyhow::bail!(
                     "unsupported timestamp oracle URL scheme: '{}'. \
-                     Supported schemes: postgres, postgresql, foundationdb",
+                     Supported schemes: postgres, postgresql, foundationdb, sqlite",
                     scheme
                 )
             }
@@ -89,6 +96,7 @@ impl TimestampOracleConfig {
             TimestampOracleConfig::Postgres(config) => Arc::clone(config.metrics()),
             #[cfg(feature = "foundationdb")]
             TimestampOracleConfig::Fdb(config) => Arc::clone(config.metrics()),
+            TimestampOracleConfig::Sqlite(config) => Arc::clone(config.metrics()),
         }
     }
 
@@ -124,6 +132,18 @@ impl TimestampOracleConfig {
                 .expect("failed to open FdbTimestampOracle");
                 Arc::new(fdb_oracle)
             }
+            TimestampOracleConfig::Sqlite(config) => {
+                let sqlite_oracle = SqliteTimestampOracle::open(
+                    config.clone(),
+                    timeline,
+                    initially,
+                    now_fn,
+                    read_only,
+                )
+                .await
+                .expect("failed to open SqliteTimestampOracle");
+                Arc::new(sqlite_oracle)
+            }
         }
     }
 
@@ -139,6 +159,9 @@ impl TimestampOracleConfig {
             TimestampOracleConfig::Fdb(config) => {
                 FdbTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
             }
+            TimestampOracleConfig::Sqlite(config) => {
+                SqliteTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
+            }
         }
     }
 
@@ -147,7 +170,6 @@ impl TimestampOracleConfig {
     /// This is a no-op for non-Postgres backends.
     pub fn apply_parameters(&self, params: TimestampOracleParameters) {
         // Only the Postgres oracle supports parameters for now.
-        #[allow(irrefutable_let_patterns)]
         if let TimestampOracleConfig::Postgres(pg_config) = self {
             params.apply(pg_config)
         }
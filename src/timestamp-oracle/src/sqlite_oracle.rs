@@ -0,0 +1,438 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A timestamp oracle backed by an embedded SQLite database, for single-node
+//! deployments without an external metadata database.
+//!
+//! The table and queries mirror the Postgres oracle. Each statement runs in
+//! its own transaction, which SQLite serializes with the database lock, so
+//! oracle operations are linearized across processes sharing the file.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use mz_ore::instrument;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::url::SensitiveUrl;
+use mz_repr::Timestamp;
+use rusqlite::{Connection, params};
+use tracing::{debug, info};
+
+use crate::metrics::Metrics;
+use crate::postgres_oracle::retry_fallible;
+use crate::{GenericNowFn, TimestampOracle, WriteTimestamp};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS timestamp_oracle (
+    timeline TEXT NOT NULL,
+    read_ts INTEGER NOT NULL,
+    write_ts INTEGER NOT NULL,
+    PRIMARY KEY(timeline)
+)
+";
+
+/// How long to wait for another connection to release the database lock
+/// before failing an operation.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A [`TimestampOracle`] backed by SQLite.
+#[derive(Debug)]
+pub struct SqliteTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp>,
+{
+    timeline: String,
+    next: N,
+    conn: Arc<Mutex<Connection>>,
+    metrics: Arc<Metrics>,
+    /// A read-only timestamp oracle is NOT allowed to do operations that change
+    /// the backing SQLite state.
+    read_only: bool,
+}
+
+/// Configuration to open an SQLite-backed implementation of
+/// [`TimestampOracle`].
+#[derive(Clone, Debug)]
+pub struct SqliteTimestampOracleConfig {
+    path: PathBuf,
+    metrics: Arc<Metrics>,
+}
+
+impl SqliteTimestampOracleConfig {
+    /// Returns a new instance of [`SqliteTimestampOracleConfig`] for the
+    /// database file in the path of a `sqlite://` url.
+    pub fn new(
+        url: &SensitiveUrl,
+        metrics_registry: &MetricsRegistry,
+    ) -> Result<Self, anyhow::Error> {
+        let path = url.path();
+        if path.is_empty() {
+            anyhow::bail!(
+                "sqlite timestamp oracle url must include a path: {}",
+                url.as_str()
+            );
+        }
+        let metrics = Arc::new(Metrics::new(metrics_registry));
+        Ok(SqliteTimestampOracleConfig {
+            path: PathBuf::from(path),
+            metrics,
+        })
+    }
+
+    /// Returns the metrics associated with this config.
+    pub(crate) fn metrics(&self) -> &Arc<Metrics> {
+        &self.metrics
+    }
+
+    fn connect(&self) -> Result<Connection, anyhow::Error> {
+        let conn = Connection::open(&self.path)?;
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+        let journal_mode: String =
+            conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get(0))?;
+        if !journal_mode.eq_ignore_ascii_case("wal") {
+            return Err(anyhow!(
+                "unable to enable WAL mode for {}: {}",
+                self.path.display(),
+                journal_mode
+            ));
+        }
+        conn.pragma_update(None, "synchronous", "FULL")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(conn)
+    }
+}
+
+/// Runs `f` with exclusive access to `conn` on the blocking thread pool.
+async fn run<R, F>(conn: &Arc<Mutex<Connection>>, f: F) -> Result<R, anyhow::Error>
+where
+    R: Send + 'static,
+    F: FnOnce(&Connection) -> Result<R, anyhow::Error> + Send + 'static,
+{
+    let conn = Arc::clone(conn);
+    mz_ore::task::spawn_blocking(
+        || "timestamp_oracle::sqlite",
+        move || {
+            let conn = conn.lock().expect("lock poisoned");
+            f(&conn)
+        },
+    )
+    .await
+}
+
+fn ts_to_sql(ts: Timestamp) -> Result<i64, anyhow::Error> {
+    i64::try_from(u64::from(ts)).map_err(|_| anyhow!("timestamp out of range: {ts}"))
+}
+
+fn ts_from_sql(ts: i64) -> Result<Timestamp, anyhow::Error> {
+    u64::try_from(ts)
+        .map(Timestamp::from)
+        .map_err(|_| anyhow!("invalid timestamp: {ts}"))
+}
+
+impl<N> SqliteTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    /// Open an SQLite [`TimestampOracle`] instance with `config`, for the
+    /// timeline named `timeline`. `next` generates new timestamps when invoked.
+    /// Timestamps that are returned are made durable and will never retract.
+    pub async fn open(
+        config: SqliteTimestampOracleConfig,
+        timeline: String,
+        initially: Timestamp,
+        next: N,
+        read_only: bool,
+    ) -> Result<Self, anyhow::Error> {
+        info!(config = ?config, "opening SqliteTimestampOracle");
+
+        let metrics = Arc::clone(&config.metrics);
+        let conn = retry_fallible(&metrics.retries.open, || {
+            let config = config.clone();
+            let timeline = timeline.clone();
+            async move {
+                mz_ore::task::spawn_blocking(
+                    || "timestamp_oracle::sqlite::open",
+                    move || {
+                        let conn = config.connect()?;
+                        // Create a row for our timeline, if it doesn't exist,
+                        // so that the other operations can assume it's there.
+                        let initially = ts_to_sql(initially)?;
+                        conn.execute(
+                            "INSERT INTO timestamp_oracle (timeline, read_ts, write_ts)
+                             VALUES (?1, ?2, ?2)
+                             ON CONFLICT (timeline) DO NOTHING",
+                            params![timeline, initially],
+                        )?;
+                        Ok(conn)
+                    },
+                )
+                .await
+            }
+        })
+        .await;
+
+        let oracle = SqliteTimestampOracle {
+            timeline,
+            next,
+            conn: Arc::new(Mutex::new(conn)),
+            metrics,
+            read_only,
+        };
+
+        // Forward timestamps to what we're given from outside. Remember, the
+        // above will only create the row at the initial timestamp if it didn't
+        // exist before.
+        if !read_only {
+            TimestampOracle::apply_write(&oracle, initially).await;
+        }
+
+        Ok(oracle)
+    }
+
+    /// Returns a `Vec` of all known timelines along with their current greatest
+    /// timestamp (max of read_ts and write_ts).
+    ///
+    /// For use when initializing another [`TimestampOracle`] implementation
+    /// from another oracle's state.
+    pub async fn get_all_timelines(
+        config: SqliteTimestampOracleConfig,
+    ) -> Result<Vec<(String, Timestamp)>, anyhow::Error> {
+        let metrics = Arc::clone(&config.metrics);
+        let result = retry_fallible(&metrics.retries.get_all_timelines, || {
+            let config = config.clone();
+            async move {
+                mz_ore::task::spawn_blocking(
+                    || "timestamp_oracle::sqlite::get_all_timelines",
+                    move || {
+                        let conn = config.connect()?;
+                        let mut statement = conn.prepare(
+                            "SELECT timeline, MAX(read_ts, write_ts) FROM timestamp_oracle",
+                        )?;
+                        let rows = statement.query_map([], |row| {
+                            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+                        })?;
+                        let mut result = Vec::new();
+                        for row in rows {
+                            let (timeline, ts) = row?;
+                            result.push((timeline, ts_from_sql(ts)?));
+                        }
+                        Ok(result)
+                    },
+                )
+                .await
+            }
+        })
+        .await;
+        Ok(result)
+    }
+
+    #[mz_ore::instrument(name = "oracle::write_ts")]
+    async fn fallible_write_ts(&self) -> Result<WriteTimestamp<Timestamp>, anyhow::Error> {
+        if self.read_only {
+            panic!("attempting write_ts in read-only mode");
+        }
+
+        let proposed_next_ts = self.next.now();
+        let proposed = ts_to_sql(proposed_next_ts)?;
+        let timeline = self.timeline.clone();
+        let write_ts = run(&self.conn, move |conn| {
+            let write_ts: i64 = conn.query_row(
+                "UPDATE timestamp_oracle SET write_ts = MAX(write_ts + 1, ?2)
+                 WHERE timeline = ?1
+                 RETURNING write_ts",
+                params![timeline, proposed],
+                |row| row.get(0),
+            )?;
+            ts_from_sql(write_ts)
+        })
+        .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            proposed_next_ts = ?proposed_next_ts,
+            "returning from write_ts()");
+
+        let advance_to = write_ts.step_forward();
+
+        Ok(WriteTimestamp {
+            timestamp: write_ts,
+            advance_to,
+        })
+    }
+
+    #[mz_ore::instrument(name = "oracle::peek_write_ts")]
+    async fn fallible_peek_write_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let timeline = self.timeline.clone();
+        let write_ts = run(&self.conn, move |conn| {
+            let write_ts: i64 = conn.query_row(
+                "SELECT write_ts FROM timestamp_oracle WHERE timeline = ?1",
+                params![timeline],
+                |row| row.get(0),
+            )?;
+            ts_from_sql(write_ts)
+        })
+        .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            "returning from peek_write_ts()");
+
+        Ok(write_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::read_ts")]
+    async fn fallible_read_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let timeline = self.timeline.clone();
+        let read_ts = run(&self.conn, move |conn| {
+            let read_ts: i64 = conn.query_row(
+                "SELECT read_ts FROM timestamp_oracle WHERE timeline = ?1",
+                params![timeline],
+                |row| row.get(0),
+            )?;
+            ts_from_sql(read_ts)
+        })
+        .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            read_ts = ?read_ts,
+            "returning from read_ts()");
+
+        Ok(read_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::apply_write")]
+    async fn fallible_apply_write(&self, write_ts: Timestamp) -> Result<(), anyhow::Error> {
+        if self.read_only {
+            panic!("attempting apply_write in read-only mode");
+        }
+
+        let timeline = self.timeline.clone();
+        let ts = ts_to_sql(write_ts)?;
+        run(&self.conn, move |conn| {
+            conn.execute(
+                "UPDATE timestamp_oracle
+                 SET write_ts = MAX(write_ts, ?2), read_ts = MAX(read_ts, ?2)
+                 WHERE timeline = ?1",
+                params![timeline, ts],
+            )?;
+            Ok(())
+        })
+        .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            "returning from apply_write()");
+
+        Ok(())
+    }
+}
+
+// A wrapper around the `fallible_` methods that adds operation metrics and
+// retries.
+#[async_trait]
+impl<N> TimestampOracle<Timestamp> for SqliteTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    #[instrument]
+    async fn write_ts(&self) -> WriteTimestamp<Timestamp> {
+        let metrics = &self.metrics.retries.write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .write_ts
+                .run_op(|| self.fallible_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn peek_write_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.peek_write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .peek_write_ts
+                .run_op(|| self.fallible_peek_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn read_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.read_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .read_ts
+                .run_op(|| self.fallible_read_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn apply_write(&self, write_ts: Timestamp) {
+        let metrics = &self.metrics.retries.apply_write;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .apply_write
+                .run_op(|| self.fallible_apply_write(write_ts))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::now::NowFn;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `sqlite3_open_v2`
+    async fn test_sqlite_timestamp_oracle() -> Result<(), anyhow::Error> {
+        let temp_dir = tempfile::tempdir()?;
+        let url = format!("sqlite://{}", temp_dir.path().join("oracle.db").display());
+        let config = SqliteTimestampOracleConfig::new(&url.parse()?, &MetricsRegistry::new())?;
+
+        crate::tests::timestamp_oracle_impl_test(|timeline, now_fn, initial_ts| {
+            let config = config.clone();
+            async move {
+                let oracle = SqliteTimestampOracle::open(
+                    config, timeline, initial_ts, now_fn, false, /* read-only */
+                )
+                .await
+                .expect("failed to open SqliteTimestampOracle");
+
+                let arced_oracle: Arc<dyn TimestampOracle<Timestamp> + Send + Sync> =
+                    Arc::new(oracle);
+
+                arced_oracle
+            }
+        })
+        .await?;
+
+        let timelines = SqliteTimestampOracle::<NowFn>::get_all_timelines(config).await?;
+        assert!(!timelines.is_empty());
+
+        Ok(())
+    }
+}
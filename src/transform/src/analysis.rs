@@ -832,7 +832,7 @@ mod non_negative {
                 MirRelationExpr::Threshold { .. } => true,
                 // Reduce errors on negative input.
                 MirRelationExpr::Reduce { .. } => true,
-                MirRelationExpr::Join { .. } => {
+                MirRelationExpr::Join { .. } | MirRelationExpr::OuterJoin { .. } => {
                     // If all inputs are non-negative, the join is non-negative.
                     depends
                         .children_of_rev(index, expr.children().count())
@@ -1140,6 +1140,13 @@ mod column_names {
                     inputs: _,
                     equivalences: _,
                     implementation: _,
+                }
+                | OuterJoin {
+                    left: _,
+                    right: _,
+                    kind: _,
+                    left_key: _,
+                    right_key: _,
                 } => {
                     let mut input_results = depends
                         .children_of_rev(index, expr.children().count())
@@ -1933,6 +1940,50 @@ mod cardinality {
 
                     self.join(equivalences, implementation, unique_columns, input_results)
                 }
+                OuterJoin {
+                    kind,
+                    left_key,
+                    right_key,
+                    ..
+                } => {
+                    let right = index - 1;
+                    let left = right - sizes[right];
+                    let left_arity = arity[left];
+
+                    // Estimate the inner join of the inputs on their keys.
+                    let mut unique_columns = BTreeMap::new();
+                    for (idx, child, key_offset) in [(0, left, 0), (1, right, left_arity)] {
+                        for key in &keys[child] {
+                            if key.len() == 1 {
+                                unique_columns.insert(key_offset + key[0], idx);
+                            }
+                        }
+                    }
+                    let equivalences = left_key
+                        .iter()
+                        .zip(right_key.iter())
+                        .map(|(l, r)| {
+                            let mut r = r.clone();
+                            r.visit_columns(|c| *c += left_arity);
+                            vec![l.clone(), r]
+                        })
+                        .collect();
+                    let mut estimate = self.join(
+                        &equivalences,
+                        &JoinImplementation::Unimplemented,
+                        unique_columns,
+                        vec![results[left], results[right]],
+                    );
+
+                    // The output contains at least the records of the inputs that are retained.
+                    if kind.retains_left() {
+                        estimate = CardinalityEstimate::max(estimate, results[left]);
+                    }
+                    if kind.retains_right() {
+                        estimate = CardinalityEstimate::max(estimate, results[right]);
+                    }
+                    estimate
+                }
                 Reduce {
                     group_key,
                     expected_group_size,
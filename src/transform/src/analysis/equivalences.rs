@@ -21,7 +21,7 @@ use std::fmt::Formatter;
 use itertools::Itertools;
 use mz_expr::canonicalize::{UnionFind, canonicalize_equivalence_classes};
 use mz_expr::explain::{HumanizedExplain, HumanizerMode};
-use mz_expr::{AggregateFunc, Id, MirRelationExpr, MirScalarExpr};
+use mz_expr::{AggregateFunc, Id, MirRelationExpr, MirScalarExpr, OuterJoinKind};
 use mz_ore::str::{bracketed, separated};
 use mz_repr::{Datum, ReprColumnType, ReprScalarType};
 
@@ -201,6 +201,28 @@ impl Analysis for Equivalences {
                     .map(|e| e.classes.extend(equivalences.iter().cloned()));
                 result
             }
+            MirRelationExpr::OuterJoin { kind, .. } => {
+                // The equivalences of an input only hold if none of its columns are padded with
+                // nulls, and the join keys only match for some records.
+                let mut children = depends.children_of_rev(index, 2).collect::<Vec<_>>();
+                children.reverse();
+                let (left, right) = (children[0], children[1]);
+                match kind {
+                    OuterJoinKind::Left => results[left].clone(),
+                    OuterJoinKind::Right => {
+                        let left_arity = depends.results::<Arity>()[left];
+                        let right_arity = depends.results::<Arity>()[right];
+                        let mut equivalences = results[right].clone();
+                        if let Some(equivalences) = &mut equivalences {
+                            let permutation =
+                                (left_arity..(left_arity + right_arity)).collect::<Vec<_>>();
+                            equivalences.permute(&permutation);
+                        }
+                        equivalences
+                    }
+                    OuterJoinKind::Full => Some(EquivalenceClasses::default()),
+                }
+            }
             MirRelationExpr::Reduce {
                 group_key,
                 aggregates,
@@ -82,6 +82,8 @@ impl Analysis for Monotonic {
             // If neither limit nor offset are set, the TopK stage will eventually be optimized out.
             MirRelationExpr::TopK { .. } => false,
             MirRelationExpr::Negate { .. } => false,
+            // Records without a match are retracted once a match arrives.
+            MirRelationExpr::OuterJoin { .. } => false,
             MirRelationExpr::Filter { predicates, .. } => {
                 let is_monotonic = results[index - 1];
                 // Temporal predicates can introduce non-monotonicity, as they
@@ -128,6 +128,24 @@ impl crate::Transform for ReduceScalars {
                     equivalences.sort();
                     equivalences.dedup();
                 }
+                MirRelationExpr::OuterJoin {
+                    left_key,
+                    right_key,
+                    ..
+                } => {
+                    // Keys reference the columns of their own input.
+                    let children = view.children_rev();
+                    for (key, child) in [right_key, left_key].into_iter().zip_eq(children) {
+                        let input_type: &Vec<ReprColumnType> = child
+                            .value::<ReprRelationType>()
+                            .expect("ReprRelationType required")
+                            .as_ref()
+                            .unwrap();
+                        for expr in key.iter_mut() {
+                            expr.reduce(input_type);
+                        }
+                    }
+                }
                 MirRelationExpr::Reduce {
                     group_key,
                     aggregates,
@@ -58,11 +58,39 @@ impl crate::Transform for ColumnKnowledge {
     fn actually_perform_transform(
         &self,
         expr: &mut MirRelationExpr,
-        _: &mut TransformCtx,
+        ctx: &mut TransformCtx,
     ) -> Result<(), TransformError> {
+        // Seed the knowledge about global collections with the constraints
+        // that are known to hold for all of their rows.
+        let mut knowledge = BTreeMap::new();
+        expr.visit_pre(|e| {
+            if let MirRelationExpr::Get {
+                id: id @ mz_expr::Id::Global(gid),
+                typ,
+                ..
+            } = e
+                && !knowledge.contains_key(id)
+            {
+                let constraints = ctx.indexes.constraints_on(*gid);
+                if !constraints.is_empty() {
+                    let mut get_knowledge: Vec<_> =
+                        typ.column_types.iter().map(DatumKnowledge::from).collect();
+                    for predicate in constraints.iter() {
+                        // Constraints only rule out `false`, so we can learn
+                        // from those that cannot evaluate to `null`.
+                        if predicate.support().iter().all(|c| *c < typ.arity())
+                            && !predicate.typ(&typ.column_types).nullable
+                        {
+                            learn_from_predicate(predicate, &mut get_knowledge);
+                        }
+                    }
+                    knowledge.insert(id.clone(), get_knowledge);
+                }
+            }
+        });
         let mut knowledge_stack = Vec::<DatumKnowledge>::new();
         let result = self
-            .harvest(expr, &mut BTreeMap::new(), &mut knowledge_stack)
+            .harvest(expr, &mut knowledge, &mut knowledge_stack)
             .map(|_| ());
         mz_repr::explain::trace_plan(&*expr);
         result
@@ -258,55 +286,8 @@ impl ColumnKnowledge {
                             knowledge_stack,
                         )?;
                     }
-                    // If any predicate tests a column for equality, truth, or is_null, we learn stuff.
                     for predicate in predicates.iter() {
-                        // Equality tests allow us to unify the column knowledge of each input.
-                        if let MirScalarExpr::CallBinary {
-                            func: mz_expr::BinaryFunc::Eq(_),
-                            expr1,
-                            expr2,
-                        } = predicate
-                        {
-                            // Collect knowledge about the inputs (for columns and literals).
-                            let mut knowledge = DatumKnowledge::top();
-                            if let MirScalarExpr::Column(c, _) = &**expr1 {
-                                knowledge.meet_assign(&input_knowledge[*c]);
-                            }
-                            if let MirScalarExpr::Column(c, _) = &**expr2 {
-                                knowledge.meet_assign(&input_knowledge[*c]);
-                            }
-
-                            // Absorb literal knowledge about columns.
-                            if let MirScalarExpr::Literal(..) = &**expr1 {
-                                knowledge.meet_assign(&DatumKnowledge::from(&**expr1));
-                            }
-                            if let MirScalarExpr::Literal(..) = &**expr2 {
-                                knowledge.meet_assign(&DatumKnowledge::from(&**expr2));
-                            }
-
-                            // Write back unified knowledge to each column.
-                            if let MirScalarExpr::Column(c, _) = &**expr1 {
-                                input_knowledge[*c].meet_assign(&knowledge);
-                            }
-                            if let MirScalarExpr::Column(c, _) = &**expr2 {
-                                input_knowledge[*c].meet_assign(&knowledge);
-                            }
-                        }
-                        if let MirScalarExpr::CallUnary {
-                            func: UnaryFunc::Not(func::Not),
-                            expr,
-                        } = predicate
-                        {
-                            if let MirScalarExpr::CallUnary {
-                                func: UnaryFunc::IsNull(func::IsNull),
-                                expr,
-                            } = &**expr
-                            {
-                                if let MirScalarExpr::Column(c, _) = &**expr {
-                                    input_knowledge[*c].meet_assign(&DatumKnowledge::any(false));
-                                }
-                            }
-                        }
+                        learn_from_predicate(predicate, &mut input_knowledge);
                     }
 
                     Ok(input_knowledge)
@@ -369,6 +350,40 @@ impl ColumnKnowledge {
 
                     Ok(knowledges)
                 }
+                MirRelationExpr::OuterJoin {
+                    left,
+                    right,
+                    kind,
+                    left_key,
+                    right_key,
+                } => {
+                    let mut sides = Vec::new();
+                    for (input, key, padded) in [
+                        (left, left_key, kind.retains_right()),
+                        (right, right_key, kind.retains_left()),
+                    ] {
+                        let mut knowledges = self.harvest(input, knowledge, knowledge_stack)?;
+                        // Do not propagate error literals beyond join inputs, for the same
+                        // reasons as for `Join`.
+                        for knowledge in knowledges.iter_mut() {
+                            if let DatumKnowledge::Lit { value: Err(_), .. } = knowledge {
+                                knowledge.join_assign(&DatumKnowledge::any(false));
+                            }
+                        }
+                        let input_col_types: Vec<ReprColumnType> = input.typ().column_types;
+                        for expr in key.iter_mut() {
+                            optimize(expr, &input_col_types, &knowledges, knowledge_stack)?;
+                        }
+                        // Columns padded with nulls may be null, whatever we know about them.
+                        if padded {
+                            for knowledge in knowledges.iter_mut() {
+                                knowledge.join_assign(&DatumKnowledge::any(true));
+                            }
+                        }
+                        sides.push(knowledges);
+                    }
+                    Ok(sides.concat())
+                }
                 MirRelationExpr::Reduce {
                     input,
                     group_key,
@@ -748,6 +763,59 @@ impl DatumKnowledge {
     }
 }
 
+/// Refines `knowledge` under the assumption that `predicate` evaluates to `true`.
+///
+/// If the predicate tests a column for equality, truth, or is_null, we learn stuff.
+fn learn_from_predicate(predicate: &MirScalarExpr, knowledge: &mut [DatumKnowledge]) {
+    // Equality tests allow us to unify the column knowledge of each input.
+    if let MirScalarExpr::CallBinary {
+        func: mz_expr::BinaryFunc::Eq(_),
+        expr1,
+        expr2,
+    } = predicate
+    {
+        // Collect knowledge about the inputs (for columns and literals).
+        let mut unified = DatumKnowledge::top();
+        if let MirScalarExpr::Column(c, _) = &**expr1 {
+            unified.meet_assign(&knowledge[*c]);
+        }
+        if let MirScalarExpr::Column(c, _) = &**expr2 {
+            unified.meet_assign(&knowledge[*c]);
+        }
+
+        // Absorb literal knowledge about columns.
+        if let MirScalarExpr::Literal(..) = &**expr1 {
+            unified.meet_assign(&DatumKnowledge::from(&**expr1));
+        }
+        if let MirScalarExpr::Literal(..) = &**expr2 {
+            unified.meet_assign(&DatumKnowledge::from(&**expr2));
+        }
+
+        // Write back unified knowledge to each column.
+        if let MirScalarExpr::Column(c, _) = &**expr1 {
+            knowledge[*c].meet_assign(&unified);
+        }
+        if let MirScalarExpr::Column(c, _) = &**expr2 {
+            knowledge[*c].meet_assign(&unified);
+        }
+    }
+    if let MirScalarExpr::CallUnary {
+        func: UnaryFunc::Not(func::Not),
+        expr,
+    } = predicate
+    {
+        if let MirScalarExpr::CallUnary {
+            func: UnaryFunc::IsNull(func::IsNull),
+            expr,
+        } = &**expr
+        {
+            if let MirScalarExpr::Column(c, _) = &**expr {
+                knowledge[*c].meet_assign(&DatumKnowledge::any(false));
+            }
+        }
+    }
+}
+
 /// Attempts to optimize
 ///
 /// `knowledge_stack` is a pre-allocated vector but is expected not to contain any elements.
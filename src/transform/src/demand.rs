@@ -280,6 +280,33 @@ impl Demand {
 
                     Ok(())
                 }
+                MirRelationExpr::OuterJoin {
+                    left,
+                    right,
+                    kind: _,
+                    left_key,
+                    right_key,
+                } => {
+                    // The keys determine which records match, and so are always demanded.
+                    let left_arity = left.arity();
+                    let mut left_columns = BTreeSet::new();
+                    let mut right_columns = BTreeSet::new();
+                    for column in columns {
+                        if column < left_arity {
+                            left_columns.insert(column);
+                        } else {
+                            right_columns.insert(column - left_arity);
+                        }
+                    }
+                    for key in left_key.iter() {
+                        key.support_into(&mut left_columns);
+                    }
+                    for key in right_key.iter() {
+                        key.support_into(&mut right_columns);
+                    }
+                    self.action(left, left_columns, gets)?;
+                    self.action(right, right_columns, gets)
+                }
                 MirRelationExpr::Reduce {
                     input,
                     group_key,
@@ -544,6 +544,31 @@ impl EquivalencePropagation {
                 );
                 equivalences.clone_from(&extracted_equivalences);
             }
+            MirRelationExpr::OuterJoin {
+                left, right, kind, ..
+            } => {
+                // Rows of a preserved input only contribute to their own output rows, and those
+                // rows may be dropped if they violate `outer_equivalences`. Rows of a padded input
+                // may be the reason other rows are not padded, and cannot be dropped.
+                let mut children_rev = derived.children_rev();
+                let right_view = children_rev.next().unwrap();
+                let left_view = children_rev.next().unwrap();
+                let left_arity = *left_view.value::<Arity>().expect("Arity required");
+                let right_arity = *right_view.value::<Arity>().expect("Arity required");
+
+                let mut left_equivalences = EquivalenceClasses::default();
+                let mut right_equivalences = EquivalenceClasses::default();
+                if !kind.retains_right() {
+                    left_equivalences = outer_equivalences.clone();
+                    left_equivalences.project(0..left_arity);
+                }
+                if !kind.retains_left() {
+                    right_equivalences = outer_equivalences;
+                    right_equivalences.project(left_arity..(left_arity + right_arity));
+                }
+                self.apply(left, left_view, left_equivalences, get_equivalences, ctx);
+                self.apply(right, right_view, right_equivalences, get_equivalences, ctx);
+            }
             MirRelationExpr::Reduce {
                 input,
                 group_key,
@@ -287,6 +287,43 @@ impl FoldConstants {
                     };
                 }
             }
+            MirRelationExpr::OuterJoin {
+                left, right, kind, ..
+            } => {
+                let (left_empty, right_empty) = (left.is_empty(), right.is_empty());
+                if (left_empty || right_empty)
+                    && (left_empty || !kind.retains_left())
+                    && (right_empty || !kind.retains_right())
+                {
+                    relation.take_safely(Some(relation_type.clone()));
+                } else if let Some(e) = left.as_const_err().or_else(|| right.as_const_err()) {
+                    *relation = MirRelationExpr::Constant {
+                        rows: Err(e.clone()),
+                        typ: relation_type.clone(),
+                    };
+                } else if left_empty || right_empty {
+                    // One input is empty and the other is preserved: every output row is padded.
+                    let left_arity = left.arity();
+                    let right_arity = relation_type.arity() - left_arity;
+                    let padding = |range: std::ops::Range<usize>| {
+                        relation_type.column_types[range]
+                            .iter()
+                            .map(|typ| MirScalarExpr::literal_null(typ.scalar_type.clone()))
+                            .collect::<Vec<_>>()
+                    };
+                    *relation = if right_empty {
+                        let nulls = padding(left_arity..(left_arity + right_arity));
+                        left.take_dangerous().map(nulls)
+                    } else {
+                        let nulls = padding(0..left_arity);
+                        right.take_dangerous().map(nulls).project(
+                            (right_arity..(right_arity + left_arity))
+                                .chain(0..right_arity)
+                                .collect(),
+                        )
+                    };
+                }
+            }
             MirRelationExpr::Join {
                 inputs,
                 equivalences,
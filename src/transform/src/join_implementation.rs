@@ -30,6 +30,7 @@ use mz_ore::{soft_assert_or_log, soft_panic_or_log};
 use mz_repr::optimize::OptimizerFeatures;
 
 use crate::analysis::{Cardinality, DerivedBuilder};
+use crate::join_implementation::cost::JoinStatistics;
 use crate::join_implementation::index_map::IndexMap;
 use crate::predicate_pushdown::PredicatePushdown;
 use crate::{StatisticsOracle, TransformCtx, TransformError};
@@ -213,6 +214,7 @@ impl JoinImplementation {
             let mut available_arrangements = vec![Vec::new(); inputs.len()];
             let mut filters = Vec::with_capacity(inputs.len());
             let mut cardinalities = Vec::with_capacity(inputs.len());
+            let mut distinct_counts = Vec::with_capacity(inputs.len());
 
             // We figure out what predicates from mfp_above could be pushed to which input.
             // We won't actually push these down now; this just informs FilterCharacteristics.
@@ -279,7 +281,8 @@ impl JoinImplementation {
                 characteristics |= push_down_characteristics;
 
                 // Estimate cardinality
-                if features.enable_cardinality_estimates {
+                if features.enable_cardinality_estimates || features.enable_cost_based_join_ordering
+                {
                     let mut builder = DerivedBuilder::new(features);
                     // TODO(mgree): it would be good to not have to copy the statistics here
                     builder.require(Cardinality::with_stats(stats.as_map()));
@@ -292,6 +295,9 @@ impl JoinImplementation {
                 } else {
                     cardinalities.push(None);
                 }
+                if features.enable_cost_based_join_ordering {
+                    distinct_counts.push(cost::distinct_counts(input, &project, stats));
+                }
 
                 filters.push(characteristics);
 
@@ -358,6 +364,14 @@ impl JoinImplementation {
                 });
             }
 
+            // With cost-based join ordering, we estimate the cost of join plans from the input
+            // statistics, provided that we have a cardinality estimate for every input.
+            let statistics = if features.enable_cost_based_join_ordering {
+                JoinStatistics::new(&cardinalities, distinct_counts, &unique_keys)
+            } else {
+                None
+            };
+
             let old_implementation = implementation.clone();
             let num_inputs = inputs.len();
             // We've already planned a differential join... should we replace it with a delta join?
@@ -375,13 +389,14 @@ impl JoinImplementation {
                 }
 
                 // Only plan a delta join if it's no new arrangements (beyond what differential planned).
-                if let Ok((delta_query_plan, 0)) = delta_queries::plan(
+                if let Ok((delta_query_plan, 0, _)) = delta_queries::plan(
                     relation,
                     &input_mapper,
                     &available_arrangements,
                     &unique_keys,
                     &cardinalities,
                     &filters,
+                    statistics.as_ref(),
                     features,
                 ) {
                     tracing::debug!(plan = ?delta_query_plan, "replacing differential join with delta join");
@@ -394,16 +409,18 @@ impl JoinImplementation {
             // To have reached here, we must be in our first run of join planning.
             //
             // We plan a differential join first.
-            let (differential_query_plan, differential_new_arrangements) = differential::plan(
-                relation,
-                &input_mapper,
-                &available_arrangements,
-                &unique_keys,
-                &cardinalities,
-                &filters,
-                features,
-            )
-            .expect("Failed to produce a differential join plan");
+            let (differential_query_plan, differential_new_arrangements, differential_cost) =
+                differential::plan(
+                    relation,
+                    &input_mapper,
+                    &available_arrangements,
+                    &unique_keys,
+                    &cardinalities,
+                    &filters,
+                    statistics.as_ref(),
+                    features,
+                )
+                .expect("Failed to produce a differential join plan");
 
             // Binary joins _must_ be differential. We won't plan a delta join.
             if num_inputs <= 2 {
@@ -438,7 +455,10 @@ impl JoinImplementation {
             //   (a) all the arrangements already exist, or
             //   (b) both:
             //       (i) we wouldn't create more arrangements than a differential join would
-            //       (ii) `enable_eager_delta_joins` is on
+            //       (ii) `enable_eager_delta_joins` is on, or
+            //   (c) we have statistics for all inputs, and the new input arrangements of the delta
+            //       query are estimated to be no larger than the new input and intermediate
+            //       arrangements of the differential join.
             //
             // A differential join of k relations requires k-2 arrangements of intermediate
             // results (plus k arrangements of the inputs).
@@ -466,10 +486,11 @@ impl JoinImplementation {
                 &unique_keys,
                 &cardinalities,
                 &filters,
+                statistics.as_ref(),
                 features,
             ) {
                 // If delta plan's inputs need no new arrangements, pick the delta plan.
-                Ok((delta_query_plan, 0)) => {
+                Ok((delta_query_plan, 0, _)) => {
                     soft_assert_or_log!(
                         matches!(old_implementation, Unimplemented | Differential(..)),
                         "delta query plans should not be planned twice"
@@ -481,14 +502,31 @@ impl JoinImplementation {
                     *relation = delta_query_plan;
                 }
                 // If the delta plan needs new arrangements, compare with the differential plan.
-                Ok((delta_query_plan, delta_new_arrangements)) => {
+                Ok((delta_query_plan, delta_new_arrangements, delta_cost)) => {
                     tracing::debug!(
                         delta_new_arrangements = delta_new_arrangements,
                         differential_new_arrangements = differential_new_arrangements,
+                        ?delta_cost,
+                        ?differential_cost,
                         "comparing delta and differential joins",
                     );
 
-                    if features.enable_eager_delta_joins
+                    if let (Some(delta_cost), Some(differential_cost)) =
+                        (delta_cost, differential_cost)
+                    {
+                        // If we have statistics, pick the plan that is estimated to be cheaper.
+                        if delta_cost <= differential_cost {
+                            tracing::debug!(
+                                plan = ?delta_query_plan,
+                                "picking delta query plan (cost-based)");
+                            *relation = delta_query_plan;
+                        } else {
+                            tracing::debug!(
+                                plan = ?differential_query_plan,
+                                "picking differential query plan (cost-based)");
+                            *relation = differential_query_plan;
+                        }
+                    } else if features.enable_eager_delta_joins
                         && delta_new_arrangements <= differential_new_arrangements
                     {
                         // If we're eagerly planning delta joins, pick the delta plan if it's more economical.
@@ -521,6 +559,17 @@ impl JoinImplementation {
                     *relation = differential_query_plan;
                 }
             }
+        } else if let MirRelationExpr::OuterJoin {
+            left,
+            right,
+            left_key,
+            right_key,
+            ..
+        } = relation
+        {
+            // Outer joins are binary, and always read each input from an arrangement by its key.
+            implement_outer_join_arrangement(left, left_key);
+            implement_outer_join_arrangement(right, right_key);
         }
         Ok(())
     }
@@ -585,9 +634,11 @@ mod delta_queries {
     use mz_repr::optimize::OptimizerFeatures;
 
     use crate::TransformError;
+    use crate::join_implementation::cost::JoinStatistics;
 
     /// Creates a delta query plan, and any predicates that need to be lifted.
-    /// It also returns the number of new arrangements necessary for this plan.
+    /// It also returns the number of new arrangements necessary for this plan, and its estimated
+    /// cost if `statistics` are available.
     ///
     /// The method returns `Err` if any errors occur during planning.
     pub fn plan(
@@ -597,8 +648,9 @@ mod delta_queries {
         unique_keys: &[Vec<Vec<usize>>],
         cardinalities: &[Option<usize>],
         filters: &[FilterCharacteristics],
+        statistics: Option<&JoinStatistics>,
         optimizer_features: &OptimizerFeatures,
-    ) -> Result<(MirRelationExpr, usize), TransformError> {
+    ) -> Result<(MirRelationExpr, usize, Option<f64>), TransformError> {
         let mut new_join = join.clone();
 
         if let MirRelationExpr::Join {
@@ -615,9 +667,12 @@ mod delta_queries {
                 cardinalities,
                 filters,
                 input_mapper,
+                statistics,
                 optimizer_features.enable_join_prioritize_arranged,
             )?;
 
+            let cost = statistics.map(|statistics| statistics.delta_cost(&orders));
+
             // Count new arrangements.
             let new_arrangements: usize = orders
                 .iter()
@@ -659,7 +714,7 @@ mod delta_queries {
             super::install_lifted_mfp(&mut new_join, lifted_mfp)?;
 
             // Hooray done!
-            Ok((new_join, new_arrangements))
+            Ok((new_join, new_arrangements, cost))
         } else {
             Err(TransformError::Internal(String::from(
                 "delta_queries::plan call on non-join expression",
@@ -671,15 +726,18 @@ mod delta_queries {
 mod differential {
     use std::collections::BTreeSet;
 
+    use itertools::Itertools;
     use mz_expr::{JoinImplementation, JoinInputMapper, MirRelationExpr, MirScalarExpr};
     use mz_ore::soft_assert_eq_or_log;
     use mz_repr::optimize::OptimizerFeatures;
 
     use crate::TransformError;
     use crate::join_implementation::FilterCharacteristics;
+    use crate::join_implementation::cost::JoinStatistics;
 
     /// Creates a linear differential plan, and any predicates that need to be lifted.
-    /// It also returns the number of new arrangements necessary for this plan.
+    /// It also returns the number of new arrangements necessary for this plan, and its estimated
+    /// cost if `statistics` are available.
     pub fn plan(
         join: &MirRelationExpr,
         input_mapper: &JoinInputMapper,
@@ -687,8 +745,9 @@ mod differential {
         unique_keys: &[Vec<Vec<usize>>],
         cardinalities: &[Option<usize>],
         filters: &[FilterCharacteristics],
+        statistics: Option<&JoinStatistics>,
         optimizer_features: &OptimizerFeatures,
-    ) -> Result<(MirRelationExpr, usize), TransformError> {
+    ) -> Result<(MirRelationExpr, usize, Option<f64>), TransformError> {
         let mut new_join = join.clone();
 
         if let MirRelationExpr::Join {
@@ -713,6 +772,7 @@ mod differential {
                 cardinalities,
                 filters,
                 input_mapper,
+                statistics,
                 optimizer_features.enable_join_prioritize_arranged,
             )?;
 
@@ -749,14 +809,37 @@ mod differential {
             });
 
             // `orders` has one order for each starting collection, and now we have to choose one
-            // from these. First, we find the worst `Characteristics` inside each order, and then we
-            // find the best one among these across all orders, which goes into
+            // from these. If we have statistics, we choose the order with the lowest estimated
+            // cost, breaking ties by the characteristics of the orders.
+            //
+            // Otherwise, we find the worst `Characteristics` inside each order, and then we find
+            // the best one among these across all orders, which goes into
             // `max_min_characteristics`.
             let max_min_characteristics = orders
                 .iter()
                 .flat_map(|order| order.iter().map(|(c, _, _)| c.clone()).min())
                 .max();
-            let mut order = if let Some(max_min_characteristics) = max_min_characteristics {
+            let mut cost = None;
+            let mut order = if let Some(statistics) = statistics {
+                let costs = orders
+                    .iter()
+                    .map(|o| statistics.differential_cost(o))
+                    .collect::<Vec<_>>();
+                let min_cost = costs.iter().copied().fold(f64::INFINITY, f64::min);
+                cost = Some(min_cost);
+                orders
+                    .into_iter()
+                    .zip_eq(costs)
+                    .filter(|(_, cost)| *cost == min_cost)
+                    .map(|(o, _)| o)
+                    .max_by_key(|o| o.clone())
+                    .ok_or_else(|| {
+                        TransformError::Internal(String::from("could not find cheapest order"))
+                    })?
+                    .into_iter()
+                    .map(|(c, key, r)| (r, key, Some(c)))
+                    .collect::<Vec<_>>()
+            } else if let Some(max_min_characteristics) = max_min_characteristics {
                 orders
                     .into_iter()
                     .filter(|o| {
@@ -819,7 +902,7 @@ mod differential {
             super::install_lifted_mfp(&mut new_join, lifted_mfp)?;
 
             // Hooray done!
-            Ok((new_join, new_arrangements))
+            Ok((new_join, new_arrangements, cost))
         } else {
             Err(TransformError::Internal(String::from(
                 "differential::plan call on non-join expression.",
@@ -828,6 +911,168 @@ mod differential {
     }
 }
 
+mod cost {
+    use std::collections::BTreeSet;
+
+    use mz_expr::{Id, JoinInputCharacteristics, MirRelationExpr, MirScalarExpr};
+    use mz_ore::cast::CastLossy;
+
+    use crate::StatisticsOracle;
+
+    /// The number of distinct values we assume a join key has if we know nothing about it.
+    ///
+    /// This is the same default that PostgreSQL uses for columns without statistics.
+    const DEFAULT_DISTINCT_COUNT: usize = 200;
+
+    /// Statistics about the inputs of a join, used to estimate the cost of join plans.
+    ///
+    /// Costs are measured in the number of records that a plan newly arranges, which is
+    /// what dominates both the memory footprint and the hydration time of a join.
+    #[derive(Debug)]
+    pub struct JoinStatistics<'a> {
+        /// Estimated number of records of each input.
+        cardinalities: Vec<usize>,
+        /// Estimated number of distinct values of each column of each input, if known.
+        distinct_counts: Vec<Vec<Option<usize>>>,
+        /// Unique keys of each input.
+        unique_keys: &'a [Vec<Vec<usize>>],
+    }
+
+    impl<'a> JoinStatistics<'a> {
+        /// Creates join statistics, or returns `None` if the cardinality of some input is unknown.
+        pub fn new(
+            cardinalities: &[Option<usize>],
+            distinct_counts: Vec<Vec<Option<usize>>>,
+            unique_keys: &'a [Vec<Vec<usize>>],
+        ) -> Option<Self> {
+            let cardinalities = cardinalities.iter().copied().collect::<Option<Vec<_>>>()?;
+            Some(Self {
+                cardinalities,
+                distinct_counts,
+                unique_keys,
+            })
+        }
+
+        /// Estimates the number of records of `input` that match a record on `key`.
+        ///
+        /// Without distinct counts for any of the key columns, we assume that the key has
+        /// [`DEFAULT_DISTINCT_COUNT`] distinct values, rather than that it is a key.
+        pub fn fanout(&self, input: usize, key: &[MirScalarExpr]) -> f64 {
+            let cardinality = f64::cast_lossy(self.cardinalities[input]);
+            if key.is_empty() {
+                return cardinality;
+            }
+            let is_unique = self.unique_keys[input].iter().any(|cols| {
+                cols.iter()
+                    .all(|c| key.contains(&MirScalarExpr::column(*c)))
+            });
+            if is_unique {
+                return cardinality.min(1.0);
+            }
+            let distinct_counts = key
+                .iter()
+                .filter_map(|k| match k {
+                    MirScalarExpr::Column(c, _) => {
+                        self.distinct_counts.get(input)?.get(*c).copied().flatten()
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let distinct = if distinct_counts.is_empty() {
+                f64::cast_lossy(DEFAULT_DISTINCT_COUNT)
+            } else {
+                distinct_counts
+                    .into_iter()
+                    .map(|n| f64::cast_lossy(n.max(1)))
+                    .product::<f64>()
+            };
+            let distinct = distinct.min(cardinality);
+            if distinct > 0.0 {
+                cardinality / distinct
+            } else {
+                0.0
+            }
+        }
+
+        /// Estimates the cost of a linear differential join that follows `order`.
+        ///
+        /// This is the size of the intermediate results that the join arranges (all but the
+        /// last one), plus the size of the inputs that need to be newly arranged.
+        pub fn differential_cost(
+            &self,
+            order: &[(JoinInputCharacteristics, Vec<MirScalarExpr>, usize)],
+        ) -> f64 {
+            let mut cost = self.new_arrangements_cost(order.iter());
+            let Some((_, _, start)) = order.first() else {
+                return cost;
+            };
+            let mut size = f64::cast_lossy(self.cardinalities[*start]);
+            for (_, key, input) in order.iter().skip(1).take(order.len().saturating_sub(2)) {
+                size *= self.fanout(*input, key);
+                cost += size;
+            }
+            cost
+        }
+
+        /// Estimates the cost of a delta join that follows `orders`.
+        ///
+        /// This is the size of the inputs that need to be newly arranged; delta joins do not
+        /// arrange intermediate results.
+        pub fn delta_cost(
+            &self,
+            orders: &[Vec<(JoinInputCharacteristics, Vec<MirScalarExpr>, usize)>],
+        ) -> f64 {
+            self.new_arrangements_cost(orders.iter().flat_map(|o| o.iter().skip(1)))
+        }
+
+        /// Sums the cardinalities of the distinct unarranged `(input, key)` pairs in `order`.
+        fn new_arrangements_cost<'b>(
+            &self,
+            order: impl Iterator<Item = &'b (JoinInputCharacteristics, Vec<MirScalarExpr>, usize)>,
+        ) -> f64 {
+            order
+                .filter(|(c, _, _)| !c.arranged())
+                .map(|(_, key, input)| (*input, key))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|(input, _)| f64::cast_lossy(self.cardinalities[input]))
+                .sum()
+        }
+    }
+
+    /// Looks up distinct count estimates for the columns of a join input.
+    ///
+    /// `input` is the input with its surrounding mfp removed, and `project` is the projection of
+    /// that mfp. We only know distinct counts for columns of global collections.
+    pub fn distinct_counts(
+        input: &MirRelationExpr,
+        project: &[usize],
+        stats: &dyn StatisticsOracle,
+    ) -> Vec<Option<usize>> {
+        let input = match input {
+            MirRelationExpr::ArrangeBy { input, .. } => &**input,
+            input => input,
+        };
+        match input {
+            MirRelationExpr::Get {
+                id: Id::Global(id),
+                typ,
+                ..
+            } => project
+                .iter()
+                .map(|c| {
+                    if *c < typ.arity() {
+                        stats.distinct_count_estimate(*id, *c)
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+            _ => vec![None; project.len()],
+        }
+    }
+}
+
 /// Modify `inputs` to ensure specified arrangements are available.
 ///
 /// Lift filter predicates when all needed arrangements are otherwise available.
@@ -926,6 +1171,20 @@ fn implement_arrangements<'a>(
     )
 }
 
+/// Ensures that `input` to an outer join is arranged by `key`.
+///
+/// If `input` is already an `ArrangeBy` (for example one installed by a previous run, or one
+/// marking an existing index), `key` is added to its keys rather than wrapping it again.
+fn implement_outer_join_arrangement(input: &mut MirRelationExpr, key: &[MirScalarExpr]) {
+    if let MirRelationExpr::ArrangeBy { keys, .. } = input {
+        if !keys.iter().any(|k| k == key) {
+            keys.push(key.to_vec());
+        }
+    } else {
+        *input = input.take_dangerous().arrange_by(&[key.to_vec()]);
+    }
+}
+
 /// This function continues the surgery that `implement_arrangements` started.
 ///
 /// (In theory, this function could be merged into `implement_arrangements`, but it would be a bit
@@ -1018,6 +1277,7 @@ fn optimize_orders(
     cardinalities: &[Option<usize>],     // cardinalities of input relations
     filters: &[FilterCharacteristics],   // filter characteristics per input
     input_mapper: &JoinInputMapper,      // join helper
+    statistics: Option<&JoinStatistics>, // statistics of input relations, if complete
     enable_join_prioritize_arranged: bool,
 ) -> Result<Vec<Vec<(JoinInputCharacteristics, Vec<MirScalarExpr>, usize)>>, TransformError> {
     let mut orderer = Orderer::new(
@@ -1027,6 +1287,7 @@ fn optimize_orders(
         cardinalities,
         filters,
         input_mapper,
+        statistics,
         enable_join_prioritize_arranged,
     );
     (0..available.len())
@@ -1042,6 +1303,7 @@ struct Orderer<'a> {
     cardinalities: &'a [Option<usize>],
     filters: &'a [FilterCharacteristics],
     input_mapper: &'a JoinInputMapper,
+    statistics: Option<&'a JoinStatistics<'a>>,
     reverse_equivalences: Vec<Vec<(usize, usize)>>,
     unique_arrangement: Vec<Vec<bool>>,
 
@@ -1064,6 +1326,7 @@ impl<'a> Orderer<'a> {
         cardinalities: &'a [Option<usize>],
         filters: &'a [FilterCharacteristics],
         input_mapper: &'a JoinInputMapper,
+        statistics: Option<&'a JoinStatistics<'a>>,
         enable_join_prioritize_arranged: bool,
     ) -> Self {
         let inputs = arrangements.len();
@@ -1101,6 +1364,7 @@ impl<'a> Orderer<'a> {
             cardinalities,
             filters,
             input_mapper,
+            statistics,
             reverse_equivalences,
             unique_arrangement,
             order,
@@ -1172,7 +1436,10 @@ impl<'a> Orderer<'a> {
         if self.inputs > 1 {
             self.order_input(start);
             while self.order.len() < self.inputs - 1 {
-                let (characteristics, key, input) = self.priority_queue.pop().unwrap();
+                let (characteristics, key, input) = match self.statistics {
+                    Some(statistics) => self.pop_cheapest(statistics),
+                    None => self.priority_queue.pop().unwrap(),
+                };
                 // put the tuple into `self.order` unless the tuple with the same
                 // input is already in `self.order`. For all inputs other than
                 // start, `self.placed[input]` is an indication of whether a
@@ -1261,6 +1528,34 @@ impl<'a> Orderer<'a> {
         Ok(std::mem::replace(&mut self.order, Vec::new()))
     }
 
+    /// Removes the candidate with the smallest estimated fanout from the priority queue.
+    ///
+    /// The intermediate result of the join grows by the fanout of the next input, so this
+    /// greedily keeps the intermediate results small. Ties are broken in priority order.
+    fn pop_cheapest(
+        &mut self,
+        statistics: &JoinStatistics,
+    ) -> (JoinInputCharacteristics, Vec<MirScalarExpr>, usize) {
+        let mut candidates = std::mem::take(&mut self.priority_queue).into_vec();
+        candidates.retain(|(_, _, input)| !self.placed[*input]);
+        let position = candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                let (_, a_key, a_input) = a;
+                let (_, b_key, b_input) = b;
+                statistics
+                    .fanout(*a_input, a_key)
+                    .total_cmp(&statistics.fanout(*b_input, b_key))
+                    .then_with(|| b.cmp(a))
+            })
+            .map(|(position, _)| position)
+            .expect("every unplaced input has a cross join candidate");
+        let cheapest = candidates.swap_remove(position);
+        self.priority_queue = candidates.into();
+        cheapest
+    }
+
     /// Introduces a specific input and keys to the order, along with its characteristics.
     ///
     /// This method places a next element in the order, and updates the associated state
@@ -1360,3 +1655,164 @@ impl<'a> Orderer<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use mz_expr::JoinImplementation::DeltaQuery;
+    use mz_repr::{GlobalId, ReprColumnType, ReprRelationType, ReprScalarType};
+
+    use super::*;
+    use crate::{EmptyIndexOracle, StatisticsOracle};
+
+    #[derive(Debug)]
+    struct TestStatisticsOracle {
+        cardinalities: BTreeMap<GlobalId, usize>,
+        distinct_counts: BTreeMap<(GlobalId, usize), usize>,
+    }
+
+    impl StatisticsOracle for TestStatisticsOracle {
+        fn cardinality_estimate(&self, id: GlobalId) -> Option<usize> {
+            self.cardinalities.get(&id).copied()
+        }
+
+        fn as_map(&self) -> BTreeMap<GlobalId, usize> {
+            self.cardinalities.clone()
+        }
+
+        fn distinct_count_estimate(&self, id: GlobalId, column: usize) -> Option<usize> {
+            self.distinct_counts.get(&(id, column)).copied()
+        }
+    }
+
+    fn int64_type(arity: usize) -> ReprRelationType {
+        ReprRelationType::new(vec![
+            ReprColumnType {
+                scalar_type: ReprScalarType::Int64,
+                nullable: false,
+            };
+            arity
+        ])
+    }
+
+    #[mz_ore::test]
+    fn fanout_estimates() {
+        let unique_keys = vec![vec![], vec![vec![0]]];
+        let distinct_counts = vec![vec![Some(10), None], vec![Some(100)]];
+        let statistics =
+            JoinStatistics::new(&[Some(1000), Some(100)], distinct_counts, &unique_keys).unwrap();
+
+        // Cross joins match every record.
+        assert_eq!(statistics.fanout(0, &[]), 1000.0);
+        // Known distinct counts divide the records evenly.
+        assert_eq!(statistics.fanout(0, &[MirScalarExpr::column(0)]), 100.0);
+        // Unknown distinct counts fall back to a default, rather than being assumed to be keys.
+        assert_eq!(statistics.fanout(0, &[MirScalarExpr::column(1)]), 5.0);
+        // Inputs with fewer records than the default distinct count match at most one record.
+        assert_eq!(statistics.fanout(1, &[MirScalarExpr::column(1)]), 1.0);
+        // Unique keys match at most one record.
+        assert_eq!(statistics.fanout(1, &[MirScalarExpr::column(0)]), 1.0);
+
+        // Without cardinalities for all inputs, there are no statistics.
+        assert!(JoinStatistics::new(&[Some(1000), None], vec![], &unique_keys).is_none());
+    }
+
+    /// A large table `a` joins a large table `b` on a low-cardinality column, and `b` joins a
+    /// small dimension table `c` on its key. The cheapest plan starts with `c`, which keeps the
+    /// intermediate result small, and only then joins `a`.
+    #[mz_ore::test]
+    fn cost_based_join_order() {
+        let (a, b, c) = (GlobalId::User(1), GlobalId::User(2), GlobalId::User(3));
+        let stats = TestStatisticsOracle {
+            cardinalities: BTreeMap::from([(a, 1_000_000), (b, 1_000_000), (c, 10)]),
+            distinct_counts: BTreeMap::from([
+                ((a, 0), 10),
+                ((b, 0), 10),
+                ((b, 1), 1_000_000),
+                ((c, 0), 10),
+            ]),
+        };
+        let relation = MirRelationExpr::join(
+            vec![
+                MirRelationExpr::global_get(a, int64_type(1)),
+                MirRelationExpr::global_get(b, int64_type(2)),
+                MirRelationExpr::global_get(c, int64_type(1).with_key(vec![0])),
+            ],
+            vec![vec![(0, 0), (1, 0)], vec![(1, 1), (2, 0)]],
+        );
+
+        let mut features = OptimizerFeatures::default();
+        features.enable_cost_based_join_ordering = true;
+        assert_eq!(
+            differential_join_order(relation, &stats, &features),
+            (2, vec![1, 0])
+        );
+    }
+
+    /// A large fact table `a` joins a medium table `b` on a column of few distinct values, and
+    /// `b` joins a small table `c` on a more selective column. None of the inputs have unique
+    /// keys, so the heuristic orders the inputs as they appear in the join. The cost model instead
+    /// starts with `c`, and only joins `a` once the intermediate result is small.
+    #[mz_ore::test]
+    fn cost_based_join_order_overrides_heuristic() {
+        let (a, b, c) = (GlobalId::User(1), GlobalId::User(2), GlobalId::User(3));
+        let stats = TestStatisticsOracle {
+            cardinalities: BTreeMap::from([(a, 1_000_000), (b, 1_000), (c, 10)]),
+            distinct_counts: BTreeMap::from([
+                ((a, 0), 10),
+                ((b, 0), 1_000),
+                ((b, 1), 100),
+                ((c, 0), 10),
+            ]),
+        };
+        let relation = MirRelationExpr::join(
+            vec![
+                MirRelationExpr::global_get(a, int64_type(1)),
+                MirRelationExpr::global_get(b, int64_type(2)),
+                MirRelationExpr::global_get(c, int64_type(1)),
+            ],
+            vec![vec![(0, 0), (1, 0)], vec![(1, 1), (2, 0)]],
+        );
+
+        let mut features = OptimizerFeatures::default();
+        assert_eq!(
+            differential_join_order(relation.clone(), &stats, &features),
+            (0, vec![1, 2])
+        );
+        features.enable_cost_based_join_ordering = true;
+        assert_eq!(
+            differential_join_order(relation, &stats, &features),
+            (2, vec![1, 0])
+        );
+    }
+
+    /// Plans `relation`, which must become a differential join, and returns its starting input
+    /// and the order of the remaining inputs.
+    fn differential_join_order(
+        mut relation: MirRelationExpr,
+        stats: &dyn StatisticsOracle,
+        features: &OptimizerFeatures,
+    ) -> (usize, Vec<usize>) {
+        JoinImplementation::default()
+            .action_recursive(
+                &mut relation,
+                &mut IndexMap::new(&EmptyIndexOracle),
+                stats,
+                features,
+            )
+            .unwrap();
+
+        let MirRelationExpr::Join { implementation, .. } = &relation else {
+            panic!("expected a join, got {relation:?}");
+        };
+        match implementation {
+            Differential((start, _, _), order) => {
+                let order = order.iter().map(|(input, _, _)| *input).collect::<Vec<_>>();
+                (*start, order)
+            }
+            DeltaQuery(_) => panic!("expected a differential join, got {implementation:?}"),
+            _ => panic!("unexpected join implementation {implementation:?}"),
+        }
+    }
+}
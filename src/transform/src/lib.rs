@@ -361,6 +361,17 @@ pub trait IndexOracle: fmt::Debug {
         &self,
         id: GlobalId,
     ) -> Box<dyn Iterator<Item = (GlobalId, &[MirScalarExpr])> + '_>;
+
+    /// Returns predicates that are known not to evaluate to `false` for any
+    /// row of the identified collection, e.g., the `CHECK` constraints of a
+    /// table.
+    ///
+    /// The predicates may evaluate to `null` for some rows. Callers that want
+    /// to treat a predicate as known to be `true` must first establish that
+    /// it cannot evaluate to `null`.
+    fn constraints_on(&self, _id: GlobalId) -> Vec<MirScalarExpr> {
+        Vec::new()
+    }
 }
 
 /// An [`IndexOracle`] that knows about no indexes.
@@ -376,6 +387,40 @@ impl IndexOracle for EmptyIndexOracle {
     }
 }
 
+/// An [`IndexOracle`] that knows about hypothetical indexes, in addition to the
+/// indexes that another [`IndexOracle`] knows about.
+///
+/// This is used to determine how a query would be optimized if the
+/// hypothetical indexes existed.
+#[derive(Debug)]
+pub struct HypotheticalIndexOracle<'a> {
+    /// The oracle for the indexes that exist.
+    pub existing: &'a dyn IndexOracle,
+    /// The hypothetical indexes, as triples of index id, indexed collection
+    /// id, and key.
+    pub hypothetical: &'a [(GlobalId, GlobalId, Vec<MirScalarExpr>)],
+}
+
+impl IndexOracle for HypotheticalIndexOracle<'_> {
+    fn indexes_on(
+        &self,
+        id: GlobalId,
+    ) -> Box<dyn Iterator<Item = (GlobalId, &[MirScalarExpr])> + '_> {
+        Box::new(
+            self.existing.indexes_on(id).chain(
+                self.hypothetical
+                    .iter()
+                    .filter(move |(_idx_id, on_id, _key)| *on_id == id)
+                    .map(|(idx_id, _on_id, key)| (*idx_id, key.as_slice())),
+            ),
+        )
+    }
+
+    fn constraints_on(&self, id: GlobalId) -> Vec<MirScalarExpr> {
+        self.existing.constraints_on(id)
+    }
+}
+
 /// A trait for a type that can estimate statistics about a given `GlobalId`
 pub trait StatisticsOracle: fmt::Debug + Send {
     /// Returns a cardinality estimate for the given identifier
@@ -385,6 +430,14 @@ pub trait StatisticsOracle: fmt::Debug + Send {
 
     /// Returns a map from identifiers to sizes
     fn as_map(&self) -> BTreeMap<GlobalId, usize>;
+
+    /// Returns an estimate of the number of distinct non-null values in the given column of the
+    /// collection backing `id`
+    ///
+    /// Returning `None` means "no estimate".
+    fn distinct_count_estimate(&self, _id: GlobalId, _column: usize) -> Option<usize> {
+        None
+    }
 }
 
 /// A [`StatisticsOracle`] that knows nothing and can give no estimates.
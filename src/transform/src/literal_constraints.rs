@@ -330,26 +330,10 @@ impl LiteralConstraints {
                                 _ => unreachable!(), // `usable_subset` would make the index usable.
                             };
 
-                            // Let's come up with a recommendation for what columns to index:
-                            // Intersect literal constraints across all OR args. (Which might
-                            // include columns that are NOT in this index, and therefore not in
-                            // `usable_subset`.)
-                            let recommended_key = or_args
-                                .iter()
-                                .map(|or_arg| {
-                                    let and_args = or_arg.and_or_args(And.into());
-                                    and_args
-                                        .iter()
-                                        .filter_map(|and_arg| and_arg.any_expr_eq_literal())
-                                        .collect::<BTreeSet<_>>()
-                                })
-                                .reduce(|fields1, fields2| {
-                                    fields1.intersection(&fields2).cloned().collect()
-                                })
-                                // The unwrap is safe because above we checked `!or_args.is_empty()`
-                                .unwrap()
-                                .into_iter()
-                                .collect_vec();
+                            // Let's come up with a recommendation for what columns to index.
+                            // (Which might include columns that are NOT in this index, and
+                            // therefore not in `usable_subset`.)
+                            let recommended_key = Self::recommended_key(&or_args);
 
                             transform_ctx.df_meta.push_optimizer_notice_dedup(
                                 IndexTooWideForLiteralConstraints {
@@ -370,6 +354,66 @@ impl LiteralConstraints {
         result
     }
 
+    /// Recommends a key for an index that would make the literal constraints of the given OR args
+    /// usable: Intersects the literal constraints across all OR args.
+    ///
+    /// Returns an empty key if there is no literal constraint that all OR args have in common.
+    fn recommended_key(or_args: &[MirScalarExpr]) -> Vec<MirScalarExpr> {
+        or_args
+            .iter()
+            .map(|or_arg| {
+                let and_args = or_arg.and_or_args(And.into());
+                and_args
+                    .iter()
+                    .filter_map(|and_arg| and_arg.any_expr_eq_literal())
+                    .collect::<BTreeSet<_>>()
+            })
+            .reduce(|fields1, fields2| fields1.intersection(&fields2).cloned().collect())
+            .unwrap_or_default()
+            .into_iter()
+            .collect_vec()
+    }
+
+    /// Recommends indexes that would allow `LiteralConstraints` to speed up the literal
+    /// constraints of MFPs on top of Gets in `relation`, regardless of the indexes that exist.
+    ///
+    /// Returns the ids of the collections to index, together with the recommended key. This is
+    /// the same key that [`IndexTooWideForLiteralConstraints`] notices recommend.
+    pub fn recommend_index_keys(
+        relation: &MirRelationExpr,
+    ) -> Result<Vec<(GlobalId, Vec<MirScalarExpr>)>, RecursionLimitError> {
+        fn recommend(
+            relation: &mut MirRelationExpr,
+            recommendations: &mut Vec<(GlobalId, Vec<MirScalarExpr>)>,
+        ) -> Result<(), RecursionLimitError> {
+            let mut mfp = MapFilterProject::extract_non_errors_from_expr_mut(relation);
+            relation.try_visit_mut_children(|e| recommend(e, recommendations))?;
+
+            if let MirRelationExpr::Get {
+                id: Id::Global(id), ..
+            } = *relation
+            {
+                // The same preparation as in `action`.
+                LiteralConstraints::inline_literal_constraints(&mut mfp);
+                LiteralConstraints::list_of_predicates_to_and_of_predicates(&mut mfp);
+                LiteralConstraints::distribute_and_over_or(&mut mfp)?;
+                LiteralConstraints::unary_and(&mut mfp);
+                LiteralConstraints::remove_impossible_or_args(&mut mfp)?;
+
+                let or_args = LiteralConstraints::get_or_args(&mfp);
+                let key = LiteralConstraints::recommended_key(&or_args);
+                if !key.is_empty() && !recommendations.contains(&(id, key.clone())) {
+                    recommendations.push((id, key));
+                }
+            }
+            Ok(())
+        }
+
+        let mut recommendations = Vec::new();
+        recommend(&mut relation.clone(), &mut recommendations)?;
+        Ok(recommendations)
+    }
+
     /// Removes the expressions that [LiteralConstraints::detect_literal_constraints] found, if
     /// possible. Returns whether it removed anything.
     /// For example, if the key of the detected literal constraint is just `f1`, and we have the
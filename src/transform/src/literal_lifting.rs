@@ -559,6 +559,39 @@ impl LiteralLifting {
                     }
                     Ok(Vec::new())
                 }
+                MirRelationExpr::OuterJoin {
+                    left,
+                    right,
+                    kind: _,
+                    left_key,
+                    right_key,
+                } => {
+                    // Literals cannot be lifted through an outer join, as the columns of a
+                    // padded input may be null rather than the literal. We inline them into
+                    // the join keys and re-install them on their inputs, beneath any
+                    // arrangement so that it can still be reused.
+                    for (input, key) in [(left, left_key), (right, right_key)] {
+                        let literals = self.action(input, gets)?;
+                        if !literals.is_empty() {
+                            let input_arity = input.arity();
+                            for expr in key.iter_mut() {
+                                expr.visit_mut_post(&mut |e| {
+                                    if let MirScalarExpr::Column(c, _) = e {
+                                        if *c >= input_arity {
+                                            *e = literals[*c - input_arity].clone();
+                                        }
+                                    }
+                                })?;
+                            }
+                            if let MirRelationExpr::ArrangeBy { input, .. } = &mut **input {
+                                **input = input.take_dangerous().map(literals);
+                            } else {
+                                **input = input.take_dangerous().map(literals);
+                            }
+                        }
+                    }
+                    Ok(Vec::new())
+                }
                 MirRelationExpr::Reduce {
                     input,
                     group_key,
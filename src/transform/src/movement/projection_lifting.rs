@@ -254,6 +254,55 @@ impl ProjectionLifting {
                     }
                     Ok(())
                 }
+                MirRelationExpr::OuterJoin {
+                    left,
+                    right,
+                    kind: _,
+                    left_key,
+                    right_key,
+                } => {
+                    self.action(left, gets)?;
+                    self.action(right, gets)?;
+
+                    // Projections commute with padding records with nulls, so we can lift them
+                    // from both inputs.
+                    let mut projection = Vec::new();
+                    let left_arity =
+                        if let MirRelationExpr::Project { input, outputs } = &mut **left {
+                            for key in left_key.iter_mut() {
+                                key.permute(outputs);
+                            }
+                            projection.extend(outputs.iter().cloned());
+                            let arity = input.arity();
+                            **left = input.take_dangerous();
+                            arity
+                        } else {
+                            let arity = left.arity();
+                            projection.extend(0..arity);
+                            arity
+                        };
+                    let right_arity =
+                        if let MirRelationExpr::Project { input, outputs } = &mut **right {
+                            for key in right_key.iter_mut() {
+                                key.permute(outputs);
+                            }
+                            projection.extend(outputs.iter().map(|c| left_arity + *c));
+                            let arity = input.arity();
+                            **right = input.take_dangerous();
+                            arity
+                        } else {
+                            let arity = right.arity();
+                            projection.extend(left_arity..(left_arity + arity));
+                            arity
+                        };
+
+                    // Don't add the identity permutation as a projection.
+                    let arity = left_arity + right_arity;
+                    if projection.len() != arity || (0..arity).any(|i| projection[i] != i) {
+                        *relation = relation.take_dangerous().project(projection);
+                    }
+                    Ok(())
+                }
                 MirRelationExpr::Reduce {
                     input,
                     group_key,
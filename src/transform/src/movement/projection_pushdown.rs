@@ -275,6 +275,52 @@ impl ProjectionPushdown {
 
                     columns_to_pushdown.into_iter().collect()
                 }
+                MirRelationExpr::OuterJoin {
+                    left,
+                    right,
+                    kind: _,
+                    left_key,
+                    right_key,
+                } if self.include_joins => {
+                    let left_arity = left.arity();
+                    let mut left_columns = BTreeSet::new();
+                    let mut right_columns = BTreeSet::new();
+                    for c in desired_projection.iter() {
+                        if *c < left_arity {
+                            left_columns.insert(*c);
+                        } else {
+                            right_columns.insert(*c - left_arity);
+                        }
+                    }
+                    // The keys impose internal demand for columns.
+                    for key in left_key.iter() {
+                        key.support_into(&mut left_columns);
+                    }
+                    for key in right_key.iter() {
+                        key.support_into(&mut right_columns);
+                    }
+
+                    reverse_permute(left_key.iter_mut(), left_columns.iter());
+                    reverse_permute(right_key.iter_mut(), right_columns.iter());
+
+                    let left_columns = left_columns.into_iter().collect::<Vec<_>>();
+                    let right_columns = right_columns.into_iter().collect::<Vec<_>>();
+                    self.action(left, &left_columns, gets)?;
+                    self.action(right, &right_columns, gets)?;
+
+                    left_columns
+                        .into_iter()
+                        .chain(right_columns.into_iter().map(|c| c + left_arity))
+                        .collect()
+                }
+                // Skip outer joins if `self.include_joins` is turned off.
+                MirRelationExpr::OuterJoin { left, right, .. } => {
+                    let left_arity = left.arity();
+                    let right_arity = right.arity();
+                    self.action(left, &(0..left_arity).collect(), gets)?;
+                    self.action(right, &(0..right_arity).collect(), gets)?;
+                    (0..left_arity + right_arity).collect()
+                }
                 MirRelationExpr::FlatMap { input, func, exprs } => {
                     let inner_arity = input.arity();
                     // A FlatMap which returns zero rows acts like a filter
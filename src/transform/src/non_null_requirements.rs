@@ -257,6 +257,26 @@ impl NonNullRequirements {
                     }
                     Ok(())
                 }
+                MirRelationExpr::OuterJoin {
+                    left, right, kind, ..
+                } => {
+                    // Only an input whose rows are preserved can have its rows removed,
+                    // as rows of an input that may be padded decide which rows are padded.
+                    let left_arity = left.arity();
+                    let mut left_columns = BTreeSet::new();
+                    let mut right_columns = BTreeSet::new();
+                    for column in columns {
+                        if column < left_arity {
+                            if !kind.retains_right() {
+                                left_columns.insert(column);
+                            }
+                        } else if !kind.retains_left() {
+                            right_columns.insert(column - left_arity);
+                        }
+                    }
+                    self.action(left, left_columns, gets)?;
+                    self.action(right, right_columns, gets)
+                }
                 MirRelationExpr::Reduce {
                     input,
                     group_key,
@@ -444,6 +444,49 @@ impl PredicatePushdown {
                             }
                             *relation = result;
                         }
+                        MirRelationExpr::OuterJoin {
+                            left, right, kind, ..
+                        } => {
+                            // Predicates may only be pushed at an input whose rows are
+                            // preserved, as removing rows from an input that may be padded
+                            // could turn matched output rows into padded ones.
+                            let left_arity = left.arity();
+                            let mut retain = Vec::new();
+                            let mut push_left = Vec::new();
+                            let mut push_right = Vec::new();
+                            for predicate in predicates.drain(..) {
+                                // Do not push down literal errors unless it is only errors.
+                                if predicate.is_literal_err() && !all_errors {
+                                    retain.push(predicate);
+                                    continue;
+                                }
+                                let support = predicate.support();
+                                if !kind.retains_right() && support.iter().all(|c| *c < left_arity)
+                                {
+                                    push_left.push(predicate);
+                                } else if !kind.retains_left()
+                                    && support.iter().all(|c| *c >= left_arity)
+                                {
+                                    let mut predicate = predicate;
+                                    predicate.visit_columns(|c| *c -= left_arity);
+                                    push_right.push(predicate);
+                                } else {
+                                    retain.push(predicate);
+                                }
+                            }
+                            if !push_left.is_empty() {
+                                **left = left.take_dangerous().filter(push_left);
+                            }
+                            if !push_right.is_empty() {
+                                **right = right.take_dangerous().filter(push_right);
+                            }
+
+                            // Recursively descend on the outer join
+                            self.action(input, get_predicates)?;
+
+                            // remove all predicates that were pushed down from the current Filter node
+                            *predicates = retain;
+                        }
                         MirRelationExpr::FlatMap { input, .. } => {
                             let (mut retained, pushdown) =
                                 Self::push_filters_through_flat_map(predicates, input.arity());
@@ -341,6 +341,14 @@ impl RedundantJoin {
 
                 MirRelationExpr::Constant { .. } => Ok(Vec::new()),
 
+                MirRelationExpr::OuterJoin { left, right, .. } => {
+                    // Padded columns are not drawn from any source, and we do not
+                    // attempt to track the provenance of the non-padded ones.
+                    self.action(left, ctx)?;
+                    self.action(right, ctx)?;
+                    Ok(Vec::new())
+                }
+
                 MirRelationExpr::Reduce {
                     input,
                     group_key,
@@ -1175,6 +1175,64 @@ impl Typecheck {
 
                 Ok(t_in_global)
             }
+            OuterJoin {
+                left,
+                right,
+                kind,
+                left_key,
+                right_key,
+            } => {
+                let mut t_left = tc.typecheck(left, ctx)?;
+                let mut t_right = tc.typecheck(right, ctx)?;
+
+                if left_key.len() != right_key.len() {
+                    return Err(TypeError::BadJoinEquivalence {
+                        source: expr,
+                        got: Vec::new(),
+                        message: format!(
+                            "outer join keys have different lengths ({} and {})",
+                            left_key.len(),
+                            right_key.len()
+                        ),
+                    });
+                }
+
+                // Note: the keys have input-local column references
+                for (l, r) in left_key.iter().zip_eq(right_key.iter()) {
+                    let t_l = tc.typecheck_scalar(l, expr, &t_left)?;
+                    let t_r = tc.typecheck_scalar(r, expr, &t_right)?;
+
+                    let diffs = scalar_subtype_difference(&t_r.scalar_type, &t_l.scalar_type);
+                    if !diffs.is_empty() {
+                        return Err(TypeError::MismatchColumn {
+                            source: expr,
+                            got: t_r,
+                            expected: t_l,
+                            diffs,
+                            message: "outer join keys have different scalar types".to_string(),
+                        });
+                    }
+
+                    if self.disallow_dummy && (l.contains_dummy() || r.contains_dummy()) {
+                        return Err(TypeError::DisallowedDummy { source: expr });
+                    }
+                }
+
+                // Columns of an input that may be padded become nullable.
+                if kind.retains_right() {
+                    for col in t_left.iter_mut() {
+                        col.nullable = true;
+                    }
+                }
+                if kind.retains_left() {
+                    for col in t_right.iter_mut() {
+                        col.nullable = true;
+                    }
+                }
+                t_left.extend(t_right);
+
+                Ok(t_left)
+            }
             Reduce {
                 input,
                 group_key,
@@ -1462,6 +1520,10 @@ impl Typecheck {
                         tc.collect_recursive_variable_types(input, ids, ctx)?;
                     }
                 }
+                OuterJoin { left, right, .. } => {
+                    tc.collect_recursive_variable_types(left, ids, ctx)?;
+                    tc.collect_recursive_variable_types(right, ids, ctx)?;
+                }
                 Union { base, inputs } => {
                     tc.collect_recursive_variable_types(base, ids, ctx)?;
 
@@ -276,6 +276,37 @@ impl<T: Timestamp + Lattice + TotalOrder + StepForward + Codec64 + Sync> TxnsCac
         ret
     }
 
+    /// Returns whether a txn might have written to a data shard at some time
+    /// in `(since, progress)`.
+    ///
+    /// This is used to validate optimistic transactions: if this returns false,
+    /// the contents of the data shard at `since` are also its contents as of
+    /// any time < progress.
+    ///
+    /// The cache only knows about the latest write >= `init_ts`, so this
+    /// conservatively returns true when `since` is before it.
+    ///
+    /// Callers must first wait for [`TxnsCache::update_gt`] with the same or
+    /// later timestamp to return. Panics otherwise.
+    pub fn data_written_since(&self, data_id: &ShardId, since: &T) -> bool {
+        self.assert_only_data_id(data_id);
+        assert!(self.progress_exclusive > *since);
+        if since < &self.init_ts {
+            return true;
+        }
+        let latest_write = self
+            .datas
+            .get(data_id)
+            .and_then(|data_times| data_times.writes.back());
+        debug!(
+            "data_written_since {:.9} since={:?} latest_write={:?}",
+            data_id.to_string(),
+            since,
+            latest_write,
+        );
+        latest_write.is_some_and(|latest_write| latest_write > since)
+    }
+
     // TODO(jkosh44) This method can likely be simplified to return
     // DataRemapEntry directly.
     /// Returns the next action to take when iterating a Listen on a data shard.
@@ -1458,6 +1489,31 @@ mod tests {
         assert_eq!(snap.latest_write, Some(5));
     }
 
+    #[mz_ore::test]
+    fn data_written_since() {
+        let d0 = ShardId::new();
+        let d1 = ShardId::new();
+        let mut c = TxnsCacheState::new(ShardId::new(), 2, None);
+        c.push_register(d0, 2, 1, 2);
+        c.push_register(d1, 2, 1, 2);
+        c.push_append(d0, vec![5], 5, 1);
+        c.progress_exclusive = 6;
+
+        // Before init_ts, we can't tell.
+        assert!(c.data_written_since(&d1, &1));
+        assert!(!c.data_written_since(&d1, &2));
+
+        assert!(c.data_written_since(&d0, &4));
+        assert!(!c.data_written_since(&d0, &5));
+        assert!(!c.data_written_since(&d1, &4));
+
+        // Applying and tidying the write doesn't forget it.
+        c.push_append(d0, vec![5], 8, -1);
+        c.progress_exclusive = 9;
+        assert!(c.data_written_since(&d0, &4));
+        assert!(!c.data_written_since(&d0, &5));
+    }
+
     // Regression test for a bug where we were sorting TxnEvents by the
     // compacted timestamp instead of the original one when applying them to a
     // cache. This caused them to be applied in a surprising order (e.g. forget
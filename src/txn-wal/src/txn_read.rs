@@ -396,6 +396,12 @@ impl<T: Timestamp + Lattice + Codec64 + Sync> TxnsRead<T> {
             .await
     }
 
+    /// See [crate::txn_cache::TxnsCacheState::data_written_since].
+    pub async fn data_written_since(&self, data_id: ShardId, since: T) -> bool {
+        self.send(|tx| TxnsReadCmd::DataWrittenSince { data_id, since, tx })
+            .await
+    }
+
     /// Initiate a subscription to `data_id`.
     ///
     /// Returns a channel that [`DataRemapEntry`]s are sent over.
@@ -508,6 +514,11 @@ enum TxnsReadCmd<T> {
         as_of: T,
         tx: oneshot::Sender<DataSnapshot<T>>,
     },
+    DataWrittenSince {
+        data_id: ShardId,
+        since: T,
+        tx: oneshot::Sender<bool>,
+    },
     DataSubscribe {
         data_id: ShardId,
         as_of: T,
@@ -693,6 +704,10 @@ where
                     let res = self.cache.data_snapshot(data_id, as_of.clone());
                     let _ = tx.send(res);
                 }
+                TxnsReadCmd::DataWrittenSince { data_id, since, tx } => {
+                    let res = self.cache.data_written_since(&data_id, &since);
+                    let _ = tx.send(res);
+                }
                 TxnsReadCmd::DataSubscribe { data_id, as_of, tx } => {
                     let mut subscribe = self.cache.data_subscribe(data_id, as_of.clone());
                     let snapshot = subscribe.snapshot.take();